@@ -11,6 +11,11 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::i18n::{get_string_with_args, lookup_string, Locale};
+
+/// Number of contacts that unlocks the contact-count milestone.
+pub const CONTACT_MILESTONE: usize = 10;
+
 /// Types of aha moments that can be triggered
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AhaMomentType {
@@ -24,18 +29,61 @@ pub enum AhaMomentType {
     FirstUpdateReceived,
     /// Shown when first outbound update is delivered
     FirstOutboundDelivered,
+    /// Shown when the contact list first reaches [`CONTACT_MILESTONE`] contacts
+    ContactMilestone,
+    /// Shown when the first contact's fingerprint is verified
+    FirstVerifiedContact,
+    /// Shown when the own card first reaches 100% completeness
+    CardComplete,
 }
 
 impl AhaMomentType {
-    /// Get the user-facing title for this moment
-    pub fn title(&self) -> &'static str {
+    /// Get the key used for this moment's translations
+    pub fn key(&self) -> &'static str {
         match self {
+            AhaMomentType::CardCreationComplete => "card_creation_complete",
+            AhaMomentType::FirstEdit => "first_edit",
+            AhaMomentType::FirstContactAdded => "first_contact_added",
+            AhaMomentType::FirstUpdateReceived => "first_update_received",
+            AhaMomentType::FirstOutboundDelivered => "first_outbound_delivered",
+            AhaMomentType::ContactMilestone => "contact_milestone",
+            AhaMomentType::FirstVerifiedContact => "first_verified_contact",
+            AhaMomentType::CardComplete => "card_complete",
+        }
+    }
+
+    /// Get the title in the given locale, falling back to English
+    pub fn title_for_locale(&self, locale: Locale) -> String {
+        if *self == AhaMomentType::ContactMilestone {
+            let count = CONTACT_MILESTONE.to_string();
+            return get_string_with_args(
+                locale,
+                "aha.contact_milestone.title",
+                &[("count", &count)],
+            );
+        }
+        lookup_string(locale, &format!("aha.{}.title", self.key())).unwrap_or_else(|| self.title())
+    }
+
+    /// Get the message in the given locale, falling back to English
+    pub fn message_for_locale(&self, locale: Locale) -> String {
+        lookup_string(locale, &format!("aha.{}.message", self.key()))
+            .unwrap_or_else(|| self.message().to_string())
+    }
+
+    /// Get the user-facing title for this moment
+    pub fn title(&self) -> String {
+        let title = match self {
             AhaMomentType::CardCreationComplete => "Your card is ready",
             AhaMomentType::FirstEdit => "Nice edit!",
             AhaMomentType::FirstContactAdded => "First contact added!",
             AhaMomentType::FirstUpdateReceived => "You received an update!",
             AhaMomentType::FirstOutboundDelivered => "Update delivered!",
-        }
+            AhaMomentType::ContactMilestone => return self.title_for_locale(Locale::English),
+            AhaMomentType::FirstVerifiedContact => "First verified contact!",
+            AhaMomentType::CardComplete => "Your card is complete!",
+        };
+        title.to_string()
     }
 
     /// Get the user-facing message for this moment
@@ -54,6 +102,15 @@ impl AhaMomentType {
                 "This is the magic - they updated, you see it instantly."
             }
             AhaMomentType::FirstOutboundDelivered => "Your contacts now have your latest info.",
+            AhaMomentType::ContactMilestone => {
+                "Your network is growing. Every one of them gets your updates automatically."
+            }
+            AhaMomentType::FirstVerifiedContact => {
+                "You confirmed it's really them. Verified contacts are marked in your list."
+            }
+            AhaMomentType::CardComplete => {
+                "Everyone you exchange with gets your name, photo, contact details and links."
+            }
         }
     }
 
@@ -65,6 +122,9 @@ impl AhaMomentType {
             AhaMomentType::FirstContactAdded => true,
             AhaMomentType::FirstUpdateReceived => true,
             AhaMomentType::FirstOutboundDelivered => false,
+            AhaMomentType::ContactMilestone => true,
+            AhaMomentType::FirstVerifiedContact => false,
+            AhaMomentType::CardComplete => true,
         }
     }
 
@@ -76,6 +136,9 @@ impl AhaMomentType {
             AhaMomentType::FirstContactAdded,
             AhaMomentType::FirstUpdateReceived,
             AhaMomentType::FirstOutboundDelivered,
+            AhaMomentType::ContactMilestone,
+            AhaMomentType::FirstVerifiedContact,
+            AhaMomentType::CardComplete,
         ]
     }
 }
@@ -107,12 +170,42 @@ impl AhaMoment {
     }
 
     /// Get the title for display
-    pub fn title(&self) -> &str {
+    pub fn title(&self) -> String {
         self.moment_type.title()
     }
 
+    /// Get the title for display in the given locale
+    pub fn title_for_locale(&self, locale: Locale) -> String {
+        self.moment_type.title_for_locale(locale)
+    }
+
     /// Get the message for display, potentially customized with context
     pub fn message(&self) -> String {
+        self.message_for_locale(Locale::English)
+    }
+
+    /// Get the message for display in the given locale, falling back to English
+    pub fn message_for_locale(&self, locale: Locale) -> String {
+        let arg = match (&self.moment_type, &self.context) {
+            (AhaMomentType::FirstContactAdded | AhaMomentType::FirstUpdateReceived, Some(name)) => {
+                Some(("name", name))
+            }
+            (AhaMomentType::FirstOutboundDelivered, Some(count)) => Some(("count", count)),
+            _ => None,
+        };
+
+        let Some((arg_name, value)) = arg else {
+            return self.moment_type.message_for_locale(locale);
+        };
+        let key = format!("aha.{}.with_context", self.moment_type.key());
+        match lookup_string(locale, &key) {
+            Some(template) => template.replace(&format!("{{{}}}", arg_name), value),
+            None => self.english_message(),
+        }
+    }
+
+    /// English source message, customized with context
+    fn english_message(&self) -> String {
         match (&self.moment_type, &self.context) {
             (AhaMomentType::FirstContactAdded, Some(name)) => {
                 format!(
@@ -188,6 +281,37 @@ impl AhaMomentTracker {
         }
     }
 
+    /// Trigger milestone moments whose thresholds have been crossed.
+    ///
+    /// Each milestone fires only the first time its threshold is reached;
+    /// returns the moments newly unlocked by this call.
+    pub fn check_milestones(
+        &mut self,
+        contact_count: usize,
+        verified_count: usize,
+    ) -> Vec<AhaMoment> {
+        let mut unlocked = Vec::new();
+        if contact_count >= CONTACT_MILESTONE {
+            unlocked.extend(self.try_trigger(AhaMomentType::ContactMilestone));
+        }
+        if verified_count >= 1 {
+            unlocked.extend(self.try_trigger(AhaMomentType::FirstVerifiedContact));
+        }
+        unlocked
+    }
+
+    /// Trigger the card-complete moment the first time the own card is complete.
+    pub fn check_card_completeness(
+        &mut self,
+        report: &crate::contact_card::CompletenessReport,
+    ) -> Option<AhaMoment> {
+        if report.is_complete() {
+            self.try_trigger(AhaMomentType::CardComplete)
+        } else {
+            None
+        }
+    }
+
     /// Get count of seen moments
     pub fn seen_count(&self) -> usize {
         self.seen.len()
@@ -221,7 +345,7 @@ mod tests {
     #[test]
     fn test_moment_type_all() {
         let all = AhaMomentType::all();
-        assert_eq!(all.len(), 5);
+        assert_eq!(all.len(), 8);
     }
 
     #[test]
@@ -236,7 +360,7 @@ mod tests {
     fn test_tracker_initial_state() {
         let tracker = AhaMomentTracker::new();
         assert_eq!(tracker.seen_count(), 0);
-        assert_eq!(tracker.total_count(), 5);
+        assert_eq!(tracker.total_count(), 8);
     }
 
     #[test]
@@ -295,6 +419,34 @@ mod tests {
         assert!(moment.message().contains("5 contacts"));
     }
 
+    #[test]
+    fn test_contact_milestone_title_is_localized() {
+        let moment = AhaMomentType::ContactMilestone;
+        assert_eq!(moment.title(), "10 contacts!");
+        assert_eq!(moment.title_for_locale(Locale::German), "10 Kontakte!");
+        assert_eq!(moment.title_for_locale(Locale::French), "10 contacts !");
+        assert_eq!(moment.title_for_locale(Locale::Spanish), "¡10 contactos!");
+    }
+
+    #[test]
+    fn test_message_for_locale() {
+        let moment = AhaMoment::new(AhaMomentType::CardCreationComplete);
+        assert_eq!(moment.message_for_locale(Locale::English), moment.message());
+        assert_eq!(
+            moment.title_for_locale(Locale::German),
+            "Deine Karte ist bereit"
+        );
+
+        let moment =
+            AhaMoment::with_context(AhaMomentType::FirstUpdateReceived, "Alice".to_string());
+        let german = moment.message_for_locale(Locale::German);
+        assert!(german.contains("Alice"));
+        assert_ne!(german, moment.message());
+
+        // Locales without translations fall back to English
+        assert_eq!(moment.message_for_locale(Locale::French), moment.message());
+    }
+
     #[test]
     fn test_tracker_serialization() {
         let mut tracker = AhaMomentTracker::new();
@@ -309,6 +461,35 @@ mod tests {
         assert!(!restored.has_seen(AhaMomentType::FirstContactAdded));
     }
 
+    #[test]
+    fn test_contact_milestone_fires_once() {
+        let mut tracker = AhaMomentTracker::new();
+
+        assert!(tracker
+            .check_milestones(CONTACT_MILESTONE - 1, 0)
+            .is_empty());
+
+        let unlocked = tracker.check_milestones(CONTACT_MILESTONE, 0);
+        assert_eq!(unlocked.len(), 1);
+        assert_eq!(unlocked[0].moment_type, AhaMomentType::ContactMilestone);
+
+        assert!(tracker.check_milestones(CONTACT_MILESTONE, 0).is_empty());
+        assert!(tracker
+            .check_milestones(CONTACT_MILESTONE + 5, 0)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_verified_milestone() {
+        let mut tracker = AhaMomentTracker::new();
+
+        let unlocked = tracker.check_milestones(3, 1);
+        assert_eq!(unlocked.len(), 1);
+        assert_eq!(unlocked[0].moment_type, AhaMomentType::FirstVerifiedContact);
+
+        assert!(tracker.check_milestones(3, 2).is_empty());
+    }
+
     #[test]
     fn test_tracker_reset() {
         let mut tracker = AhaMomentTracker::new();
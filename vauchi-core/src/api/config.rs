@@ -7,12 +7,15 @@
 //! Configuration types for the Vauchi API layer.
 
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
 use crate::crypto::SymmetricKey;
 use crate::network::{ProxyConfig, RelayClientConfig, TransportConfig};
 
+use super::telemetry::{noop_telemetry, Telemetry};
+
 /// Configuration for Vauchi instance.
 #[derive(Debug, Clone)]
 pub struct VauchiConfig {
@@ -37,6 +40,9 @@ pub struct VauchiConfig {
 
     /// Recovery configuration for social key recovery.
     pub recovery: RecoveryConfig,
+
+    /// Telemetry hook for non-identifying metrics (no-op by default).
+    pub telemetry: Arc<dyn Telemetry>,
 }
 
 impl Default for VauchiConfig {
@@ -49,6 +55,7 @@ impl Default for VauchiConfig {
             storage_key: None,
             delivery_receipts_enabled: true,
             recovery: RecoveryConfig::default(),
+            telemetry: noop_telemetry(),
         }
     }
 }
@@ -80,6 +87,12 @@ impl VauchiConfig {
         self.storage_key = Some(key);
         self
     }
+
+    /// Installs a telemetry hook.
+    pub fn with_telemetry(mut self, telemetry: Arc<dyn Telemetry>) -> Self {
+        self.telemetry = telemetry;
+        self
+    }
 }
 
 /// Relay server configuration.
@@ -178,6 +191,12 @@ pub struct SyncConfig {
 
     /// Maximum pending updates before forcing sync.
     pub max_pending_updates: usize,
+
+    /// Pad outgoing updates to size buckets to hide how much changed.
+    ///
+    /// Costs bandwidth. Applies only to contacts whose exchange advertised
+    /// that they strip padding.
+    pub pad_updates: bool,
 }
 
 impl Default for SyncConfig {
@@ -186,6 +205,7 @@ impl Default for SyncConfig {
             auto_sync: true,
             sync_interval_ms: 60_000, // 1 minute
             max_pending_updates: 50,
+            pad_updates: false,
         }
     }
 }
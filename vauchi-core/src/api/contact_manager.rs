@@ -66,6 +66,8 @@ impl<'a> ContactManager<'a> {
     }
 
     /// Adds a field to the user's own card.
+    ///
+    /// The field starts with the configured default visibility.
     pub fn add_field_to_own_card(&self, field: ContactField) -> VauchiResult<()> {
         let mut card = self
             .storage
@@ -75,6 +77,7 @@ impl<'a> ContactManager<'a> {
         card.add_field(field.clone())
             .map_err(|e| VauchiError::InvalidState(e.to_string()))?;
         self.storage.save_own_card(&card)?;
+        self.storage.apply_default_field_visibility(field.id())?;
 
         self.events.dispatch(VauchiEvent::OwnCardUpdated {
             changed_fields: vec![field.label().to_string()],
@@ -84,8 +87,10 @@ impl<'a> ContactManager<'a> {
     }
 
     /// Removes a field from the user's own card by label.
+    ///
+    /// The field's label and per-contact visibility entries go with it.
     pub fn remove_field_from_own_card(&self, label: &str) -> VauchiResult<bool> {
-        let mut card = self
+        let card = self
             .storage
             .load_own_card()?
             .ok_or(VauchiError::IdentityNotInitialized)?;
@@ -101,12 +106,27 @@ impl<'a> ContactManager<'a> {
             return Ok(false);
         };
 
-        card.remove_field(&field_id)
-            .map_err(|_| VauchiError::InvalidState("Field not found".into()))?;
+        self.remove_field_from_own_card_by_id(&field_id)
+    }
 
-        self.storage.save_own_card(&card)?;
+    /// Removes a field from the user's own card by field ID.
+    ///
+    /// Returns false if the card has no field with this ID.
+    pub fn remove_field_from_own_card_by_id(&self, field_id: &str) -> VauchiResult<bool> {
+        let card = self
+            .storage
+            .load_own_card()?
+            .ok_or(VauchiError::IdentityNotInitialized)?;
+
+        let Some(label) = card.field(field_id).map(|f| f.label().to_string()) else {
+            return Ok(false);
+        };
+
+        if !self.storage.remove_own_field(field_id)? {
+            return Err(VauchiError::InvalidState("Field not found".into()));
+        }
         self.events.dispatch(VauchiEvent::OwnCardUpdated {
-            changed_fields: vec![label.to_string()],
+            changed_fields: vec![label],
         });
 
         Ok(true)
@@ -170,6 +190,8 @@ impl<'a> ContactManager<'a> {
     }
 
     /// Updates an existing contact.
+    ///
+    /// No `ContactUpdated` event is dispatched for muted contacts.
     pub fn update_contact(&self, contact: &Contact) -> VauchiResult<Vec<String>> {
         let contact_id = contact.id().to_string();
 
@@ -182,7 +204,7 @@ impl<'a> ContactManager<'a> {
 
         self.storage.save_contact(contact)?;
 
-        if !changed_fields.is_empty() {
+        if !changed_fields.is_empty() && !contact.is_muted() {
             self.events.dispatch(VauchiEvent::ContactUpdated {
                 contact_id,
                 changed_fields: changed_fields.clone(),
@@ -205,6 +227,14 @@ impl<'a> ContactManager<'a> {
         Ok(existed)
     }
 
+    /// Mutes or unmutes change notifications for a contact.
+    pub fn set_muted(&self, id: &str, muted: bool) -> VauchiResult<()> {
+        let mut contact = self.get_contact_required(id)?;
+        contact.set_muted(muted);
+        self.storage.save_contact(&contact)?;
+        Ok(())
+    }
+
     /// Marks a contact's fingerprint as verified.
     pub fn verify_fingerprint(&self, id: &str) -> VauchiResult<()> {
         let mut contact = self.get_contact_required(id)?;
@@ -367,6 +397,34 @@ mod tests {
         assert_eq!(loaded.fields().len(), 0);
     }
 
+    #[test]
+    fn test_own_card_remove_field_by_id() {
+        let storage = create_test_storage();
+        let mut dispatcher = EventDispatcher::new();
+        let changed = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let seen = changed.clone();
+        let handler = Arc::new(super::super::events::CallbackHandler::new(move |event| {
+            if let VauchiEvent::OwnCardUpdated { changed_fields } = event {
+                seen.lock().unwrap().extend(changed_fields);
+            }
+        }));
+        dispatcher.add_handler(handler);
+
+        let manager = ContactManager::new(&storage, Arc::new(dispatcher));
+        let mut card = ContactCard::new("Test User");
+        let field = ContactField::new(FieldType::Email, "email", "test@example.com");
+        let field_id = field.id().to_string();
+        card.add_field(field).unwrap();
+        storage.save_own_card(&card).unwrap();
+
+        assert!(manager.remove_field_from_own_card_by_id(&field_id).unwrap());
+        assert!(!manager.remove_field_from_own_card_by_id(&field_id).unwrap());
+
+        assert_eq!(*changed.lock().unwrap(), vec!["email".to_string()]);
+        assert!(manager.get_own_card().unwrap().unwrap().fields().is_empty());
+    }
+
     #[test]
     fn test_contact_operations() {
         let storage = create_test_storage();
@@ -8,6 +8,7 @@
 
 use thiserror::Error;
 
+use crate::contact::ContactError;
 use crate::contact_card::ValidationError;
 use crate::exchange::ExchangeError;
 use crate::network::NetworkError;
@@ -42,6 +43,10 @@ pub enum VauchiError {
     #[error("network error: {0}")]
     Network(#[from] NetworkError),
 
+    /// Contact could not be created.
+    #[error("contact error: {0}")]
+    Contact(#[from] ContactError),
+
     /// Contact not found.
     #[error("contact not found: {0}")]
     ContactNotFound(String),
@@ -70,8 +70,9 @@ pub fn export_all_data(storage: &Storage) -> Result<GdprExport, crate::storage::
         .map(|d| d.as_secs())
         .unwrap_or(0);
 
-    // Export contacts
-    let contacts = storage.list_contacts()?;
+    // Export contacts, by ID so unchanged data exports identically
+    let mut contacts = storage.list_contacts()?;
+    contacts.sort_by(|a, b| a.id().cmp(b.id()));
     let gdpr_contacts: Vec<GdprContact> = contacts
         .iter()
         .map(|c| {
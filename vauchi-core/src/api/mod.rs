@@ -44,6 +44,7 @@
 //! - [`events`] - Event system for callbacks
 //! - [`contact_manager`] - High-level contact operations
 //! - [`sync_controller`] - Sync and network orchestration
+//! - [`telemetry`] - Optional privacy-preserving metrics hook
 //! - [`vauchi`] - Main Vauchi orchestrator
 
 #[cfg(feature = "testing")]
@@ -86,6 +87,11 @@ pub mod sync_controller;
 #[cfg(not(feature = "testing"))]
 mod sync_controller;
 
+#[cfg(feature = "testing")]
+pub mod telemetry;
+#[cfg(not(feature = "testing"))]
+mod telemetry;
+
 #[cfg(feature = "testing")]
 pub mod vauchi;
 #[cfg(not(feature = "testing"))]
@@ -106,5 +112,8 @@ pub use contact_manager::ContactManager;
 // Sync Controller
 pub use sync_controller::{SyncController, SyncResult};
 
+// Telemetry
+pub use telemetry::{noop_telemetry, NoopTelemetry, Telemetry, TelemetryEvent};
+
 // Vauchi
 pub use vauchi::{Vauchi, VauchiBuilder};
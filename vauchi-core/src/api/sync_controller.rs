@@ -8,6 +8,7 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use crate::crypto::ratchet::DoubleRatchetState;
 use crate::network::{ConnectionState, RelayClient, Transport};
@@ -18,6 +19,7 @@ use crate::sync::{DeviceSyncOrchestrator, SyncManager, SyncState};
 use super::config::SyncConfig;
 use super::error::{VauchiError, VauchiResult};
 use super::events::{EventDispatcher, VauchiEvent};
+use super::telemetry::{noop_telemetry, Telemetry, TelemetryEvent};
 
 /// Result of a sync cycle.
 #[derive(Debug, Default)]
@@ -46,6 +48,7 @@ pub struct SyncController<'a, T: Transport> {
     sync_manager: SyncManager<'a>,
     config: SyncConfig,
     events: Arc<EventDispatcher>,
+    telemetry: Arc<dyn Telemetry>,
     /// Ratchet states per contact for encryption
     ratchets: HashMap<String, DoubleRatchetState>,
     /// Connection state tracking
@@ -65,11 +68,18 @@ impl<'a, T: Transport> SyncController<'a, T> {
             sync_manager: SyncManager::new(storage),
             config,
             events,
+            telemetry: noop_telemetry(),
             ratchets: HashMap::new(),
             last_connection_state: ConnectionState::Disconnected,
         }
     }
 
+    /// Installs a telemetry hook for sync timings and counts.
+    pub fn with_telemetry(mut self, telemetry: Arc<dyn Telemetry>) -> Self {
+        self.telemetry = telemetry;
+        self
+    }
+
     /// Connects to the relay server.
     pub fn connect(&mut self) -> VauchiResult<()> {
         self.relay.connect()?;
@@ -116,6 +126,22 @@ impl<'a, T: Transport> SyncController<'a, T> {
     /// This processes pending updates, sends them through the relay,
     /// and handles acknowledgments.
     pub fn sync(&mut self) -> VauchiResult<SyncResult> {
+        let started = Instant::now();
+        let result = self.run_sync_cycle();
+
+        let duration_ms = started.elapsed().as_millis() as u64;
+        self.telemetry.record(match &result {
+            Ok(r) => TelemetryEvent::SyncCompleted {
+                duration_ms,
+                messages_sent: r.sent as u32,
+                messages_received: r.acknowledged as u32,
+            },
+            Err(_) => TelemetryEvent::SyncFailed { duration_ms },
+        });
+        result
+    }
+
+    fn run_sync_cycle(&mut self) -> VauchiResult<SyncResult> {
         if !self.is_connected() {
             return Err(VauchiError::Network(
                 crate::network::NetworkError::NotConnected,
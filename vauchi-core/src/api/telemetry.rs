@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Telemetry Hook
+//!
+//! Optional, privacy-preserving metrics for embedders. Core never logs or
+//! sends these itself; it only hands them to the installed [`Telemetry`].
+//!
+//! Events carry counts and timings only. They must never contain personal
+//! data: no contact or message IDs, public keys, names, field values,
+//! relay URLs, or error messages (which may quote any of these).
+
+use std::fmt;
+use std::sync::Arc;
+
+/// A non-identifying telemetry event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TelemetryEvent {
+    /// A sync cycle finished.
+    SyncCompleted {
+        /// Wall-clock duration of the cycle.
+        duration_ms: u64,
+        /// Messages sent to the relay.
+        messages_sent: u32,
+        /// Messages received or acknowledged.
+        messages_received: u32,
+    },
+
+    /// A sync cycle failed.
+    SyncFailed {
+        /// Wall-clock duration until the failure.
+        duration_ms: u64,
+    },
+
+    /// A contact exchange completed.
+    ExchangeSucceeded,
+
+    /// A contact exchange failed.
+    ExchangeFailed,
+
+    /// An incoming message could not be decrypted.
+    DecryptionFailed,
+}
+
+/// Receiver for telemetry events.
+///
+/// Implementations must not block; they are called inline on the hot path.
+pub trait Telemetry: Send + Sync {
+    /// Records an event.
+    fn record(&self, event: TelemetryEvent);
+}
+
+impl fmt::Debug for dyn Telemetry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Telemetry")
+    }
+}
+
+/// Telemetry that discards every event (the default).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopTelemetry;
+
+impl Telemetry for NoopTelemetry {
+    fn record(&self, _event: TelemetryEvent) {}
+}
+
+/// Returns the default telemetry, which discards every event.
+pub fn noop_telemetry() -> Arc<dyn Telemetry> {
+    Arc::new(NoopTelemetry)
+}
@@ -20,6 +20,7 @@ use super::config::VauchiConfig;
 use super::contact_manager::ContactManager;
 use super::error::{VauchiError, VauchiResult};
 use super::events::{EventDispatcher, EventHandler, VauchiEvent};
+use super::telemetry::{Telemetry, TelemetryEvent};
 
 /// Main Vauchi orchestrator.
 ///
@@ -237,6 +238,12 @@ impl<T: Transport> Vauchi<T> {
         manager.remove_field_from_own_card(label)
     }
 
+    /// Removes a field from the user's own card by field ID.
+    pub fn remove_own_field_by_id(&self, field_id: &str) -> VauchiResult<bool> {
+        let manager = ContactManager::new(&self.storage, self.events.clone());
+        manager.remove_field_from_own_card_by_id(field_id)
+    }
+
     // === Contact Operations ===
 
     /// Gets a contact by ID.
@@ -264,9 +271,21 @@ impl<T: Transport> Vauchi<T> {
     }
 
     /// Adds a new contact from an exchange.
+    ///
+    /// Fails with [`crate::contact::ContactError::SelfContact`] if the contact
+    /// has our own public key.
     pub fn add_contact(&self, contact: Contact) -> VauchiResult<()> {
+        if let Some(identity) = &self.identity {
+            crate::contact::check_not_self(contact.public_key(), identity.signing_public_key())?;
+        }
         let manager = ContactManager::new(&self.storage, self.events.clone());
-        manager.add_contact(contact)
+        let result = manager.add_contact(contact);
+        self.config.telemetry.record(if result.is_ok() {
+            TelemetryEvent::ExchangeSucceeded
+        } else {
+            TelemetryEvent::ExchangeFailed
+        });
+        result
     }
 
     /// Removes a contact by ID.
@@ -281,6 +300,15 @@ impl<T: Transport> Vauchi<T> {
         Ok(())
     }
 
+    /// Mutes or unmutes change notifications for a contact.
+    ///
+    /// Updates from a muted contact are still applied, but no
+    /// `ContactUpdated` event is dispatched for them.
+    pub fn set_contact_muted(&self, id: &str, muted: bool) -> VauchiResult<()> {
+        let manager = ContactManager::new(&self.storage, self.events.clone());
+        manager.set_muted(id, muted)
+    }
+
     /// Verifies a contact's fingerprint.
     pub fn verify_contact_fingerprint(&self, id: &str) -> VauchiResult<()> {
         let manager = ContactManager::new(&self.storage, self.events.clone());
@@ -356,7 +384,6 @@ impl<T: Transport> Vauchi<T> {
         new_card: &ContactCard,
     ) -> VauchiResult<usize> {
         use crate::storage::{PendingUpdate, UpdateStatus};
-        use crate::sync::delta::CardDelta;
 
         let identity = self
             .identity
@@ -367,6 +394,10 @@ impl<T: Transport> Vauchi<T> {
         let mut queued = 0;
 
         for contact in contacts {
+            if !contact.should_send_updates() {
+                continue;
+            }
+
             // Skip contacts without ratchet (not yet synced)
             let (mut ratchet, is_initiator) = match self.storage.load_ratchet_state(contact.id())? {
                 Some(r) => r,
@@ -374,13 +405,14 @@ impl<T: Transport> Vauchi<T> {
             };
 
             // Compute delta
-            let delta = CardDelta::compute(old_card, new_card);
+            let delta = old_card.diff(new_card);
             if delta.is_empty() {
                 continue;
             }
 
-            // Filter delta based on visibility rules for this contact
-            let mut delta = delta.filter_for_contact(contact.id(), contact.visibility_rules());
+            // Project delta onto the fields this contact is allowed to see
+            let visible = self.visible_field_ids(&contact, old_card, new_card)?;
+            let mut delta = delta.project(&visible);
             if delta.is_empty() {
                 continue;
             }
@@ -389,8 +421,12 @@ impl<T: Transport> Vauchi<T> {
             delta.sign(identity);
 
             // Serialize delta
-            let delta_bytes = serde_json::to_vec(&delta)
-                .map_err(|e| VauchiError::Serialization(e.to_string()))?;
+            let delta_bytes = delta.to_wire();
+            let delta_bytes = if self.config.sync.pad_updates && contact.accepts_padding() {
+                crate::crypto::padding::pad(&delta_bytes)
+            } else {
+                delta_bytes
+            };
 
             // Encrypt with ratchet
             let ratchet_msg = ratchet
@@ -425,11 +461,34 @@ impl<T: Transport> Vauchi<T> {
         Ok(queued)
     }
 
+    /// Returns the IDs of fields in either card that are visible to a contact.
+    fn visible_field_ids(
+        &self,
+        contact: &Contact,
+        old_card: &ContactCard,
+        new_card: &ContactCard,
+    ) -> VauchiResult<std::collections::HashSet<String>> {
+        let visibility = self.storage.load_field_visibility(contact)?;
+
+        Ok(old_card
+            .fields()
+            .iter()
+            .chain(new_card.fields())
+            .map(|field| field.id())
+            .filter(|id| visibility.can_see(id))
+            .map(|id| id.to_string())
+            .collect())
+    }
+
     /// Processes an encrypted card update from a contact.
     ///
     /// 1. Decrypts the update using the contact's ratchet
     /// 2. Verifies the signature using the contact's public key
     /// 3. Applies the delta to the contact's card
+    /// 4. Dispatches `ContactUpdated`, unless the contact is muted
+    ///
+    /// An update in a newer delta format fails without advancing the
+    /// ratchet, so it can be processed again after upgrading.
     ///
     /// Returns a list of changed field labels.
     pub fn process_card_update(
@@ -438,7 +497,7 @@ impl<T: Transport> Vauchi<T> {
         encrypted: &[u8],
     ) -> VauchiResult<Vec<String>> {
         use crate::crypto::ratchet::RatchetMessage;
-        use crate::sync::delta::CardDelta;
+        use crate::sync::delta::{CardDelta, DeltaError};
 
         // Load contact
         let mut contact = self
@@ -454,17 +513,26 @@ impl<T: Transport> Vauchi<T> {
 
         let ratchet_msg: RatchetMessage = serde_json::from_slice(encrypted)
             .map_err(|e| VauchiError::Serialization(e.to_string()))?;
-        let delta_bytes = ratchet
-            .decrypt(&ratchet_msg)
-            .map_err(|e| VauchiError::Crypto(format!("{:?}", e)))?;
-
-        // Save updated ratchet state
-        self.storage
-            .save_ratchet_state(contact_id, &ratchet, is_initiator)?;
+        let delta_bytes = ratchet.decrypt(&ratchet_msg).map_err(|e| {
+            self.config
+                .telemetry
+                .record(TelemetryEvent::DecryptionFailed);
+            VauchiError::Crypto(format!("{:?}", e))
+        })?;
+
+        // Parse delta (senders may pad updates to hide their size)
+        let delta = match crate::crypto::padding::strip_padding(delta_bytes) {
+            Ok(delta_bytes) => CardDelta::from_wire(&delta_bytes),
+            Err(e) => Err(DeltaError::Malformed(e.to_string())),
+        };
 
-        // Parse delta
-        let delta: CardDelta = serde_json::from_slice(&delta_bytes)
-            .map_err(|e| VauchiError::Serialization(e.to_string()))?;
+        // Save updated ratchet state, unless the delta is in a newer format:
+        // then the update stays readable once this app is upgraded
+        if !matches!(delta, Err(DeltaError::UnsupportedFormat { .. })) {
+            self.storage
+                .save_ratchet_state(contact_id, &ratchet, is_initiator)?;
+        }
+        let delta = delta.map_err(|e| VauchiError::Serialization(e.to_string()))?;
 
         // Verify signature with contact's public key
         if !delta.verify(contact.public_key()) {
@@ -484,6 +552,13 @@ impl<T: Transport> Vauchi<T> {
         contact.update_card(new_card);
         self.storage.save_contact(&contact)?;
 
+        if !changed.is_empty() && !contact.is_muted() {
+            self.events.dispatch(VauchiEvent::ContactUpdated {
+                contact_id: contact_id.to_string(),
+                changed_fields: changed.clone(),
+            });
+        }
+
         Ok(changed)
     }
 
@@ -525,6 +600,11 @@ impl<T: Transport> Vauchi<T> {
         &self.events
     }
 
+    /// Returns the installed telemetry hook.
+    pub fn telemetry(&self) -> &Arc<dyn Telemetry> {
+        &self.config.telemetry
+    }
+
     // === Visibility Labels ===
 
     /// Lists all visibility labels.
@@ -555,6 +635,19 @@ impl<T: Transport> Vauchi<T> {
         Ok(self.storage.load_label(label_id)?)
     }
 
+    /// Sets the visibility given to fields added to the own card.
+    pub fn set_default_field_visibility(
+        &self,
+        policy: &crate::contact::DefaultFieldVisibility,
+    ) -> VauchiResult<()> {
+        Ok(self.storage.set_default_field_visibility(policy)?)
+    }
+
+    /// Gets the visibility given to fields added to the own card.
+    pub fn default_field_visibility(&self) -> VauchiResult<crate::contact::DefaultFieldVisibility> {
+        Ok(self.storage.load_default_field_visibility()?)
+    }
+
     /// Adds a contact to a visibility label.
     pub fn add_contact_to_label(&self, label_id: &str, contact_id: &str) -> VauchiResult<()> {
         Ok(self.storage.add_contact_to_label(label_id, contact_id)?)
@@ -626,36 +719,22 @@ impl<T: Transport> Vauchi<T> {
     /// Returns visibility determined by (in priority order):
     /// 1. Per-contact override (if set)
     /// 2. Label membership (visible if contact is in any label that shows this field)
-    /// 3. Contact's VisibilityRules (the default field visibility)
+    /// 3. Contact's VisibilityRules
+    /// 4. The field's default visibility
     pub fn get_effective_field_visibility(
         &self,
         contact_id: &str,
         field_id: &str,
     ) -> VauchiResult<bool> {
-        // Load the contact's visibility rules as fallback
         let contact = self
             .storage
             .load_contact(contact_id)?
             .ok_or_else(|| VauchiError::NotFound(format!("contact: {}", contact_id)))?;
 
-        // Check per-contact override first
-        let overrides = self.storage.load_contact_overrides(contact_id)?;
-        if let Some(&is_visible) = overrides.get(field_id) {
-            return Ok(is_visible);
-        }
-
-        // Check if any label containing this contact shows this field
-        let labels = self.storage.get_labels_for_contact(contact_id)?;
-        for label in labels {
-            if label.is_field_visible(field_id) {
-                return Ok(true);
-            }
-        }
-
-        // Fall back to contact's default visibility rules
-        // Note: The visibility rules determine what this contact can see of *our* card
-        // We use their contact_id to check if they're in the allowed list
-        Ok(contact.visibility_rules().can_see(field_id, contact_id))
+        Ok(self
+            .storage
+            .load_field_visibility(&contact)?
+            .can_see(field_id))
     }
 
     // === Field Validation Operations ===
@@ -719,7 +798,7 @@ impl<T: Transport> Vauchi<T> {
     /// Gets the validation status for a contact's field.
     ///
     /// Returns aggregated validation information including count, trust level,
-    /// and whether the current user has validated this field.
+    /// issuer attestations, and whether the current user has validated this field.
     pub fn get_field_validation_status(
         &self,
         contact_id: &str,
@@ -739,12 +818,20 @@ impl<T: Transport> Vauchi<T> {
         // Get blocked contacts (empty for now, could be extended)
         let blocked = std::collections::HashSet::new();
 
+        let attestations = self
+            .storage
+            .load_issuer_attestations_for_field(contact_id, field_id)?;
+        let thresholds = crate::social::TrustThresholds::default();
+
         let status = crate::social::ValidationStatus::from_validations(
             &validations,
             field_value,
             my_id.as_deref(),
             &blocked,
-        );
+            &std::collections::HashSet::new(),
+            &thresholds,
+        )
+        .with_issuer_attestations(&attestations, contact_id, field_id, &thresholds);
 
         Ok(status)
     }
@@ -965,6 +1052,12 @@ impl<T: Transport> VauchiBuilder<T> {
         self
     }
 
+    /// Installs a telemetry hook.
+    pub fn telemetry(mut self, telemetry: Arc<dyn Telemetry>) -> Self {
+        self.config.telemetry = telemetry;
+        self
+    }
+
     /// Sets an existing identity.
     pub fn identity(mut self, identity: Identity) -> Self {
         self.identity = Some(identity);
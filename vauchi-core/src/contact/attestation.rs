@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Verification Attestations
+//!
+//! A signed bundle in which an attester (e.g. an event organizer) vouches
+//! for the public keys of many people at once. Importing a bundle from a
+//! trusted attester marks the listed contacts as verified without each
+//! fingerprint having to be compared in person.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use thiserror::Error;
+
+use crate::crypto::{PublicKey, Signature, SigningKeyPair};
+
+/// Domain separator mixed into attestation signatures.
+const ATTESTATION_DOMAIN: &[u8] = b"VAUCHI_VERIFICATION_ATTESTATION";
+
+/// Attestation-related errors.
+#[derive(Error, Debug)]
+pub enum AttestationError {
+    #[error("Invalid attestation data format")]
+    InvalidFormat,
+
+    #[error("Attestation has invalid signature")]
+    InvalidSignature,
+
+    #[error("Attestation signed by an untrusted key")]
+    UntrustedAttester,
+}
+
+/// A signed list of public keys the attester has verified.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct VerificationAttestation {
+    /// The attester's Ed25519 public key.
+    #[serde_as(as = "[_; 32]")]
+    attester_pk: [u8; 32],
+    /// Public keys of the verified people.
+    subject_keys: Vec<[u8; 32]>,
+    /// Unix timestamp when the attestation was created.
+    timestamp: u64,
+    /// Signature over (domain || attester_pk || subject_keys || timestamp).
+    #[serde_as(as = "[_; 64]")]
+    signature: [u8; 64],
+}
+
+impl VerificationAttestation {
+    /// Creates a signed attestation for the given subject keys.
+    pub fn create(subject_keys: Vec<[u8; 32]>, attester_keypair: &SigningKeyPair) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+
+        let attester_pk = *attester_keypair.public_key().as_bytes();
+        let data = Self::build_sign_data(&attester_pk, &subject_keys, timestamp);
+        let signature = attester_keypair.sign(&data);
+
+        Self {
+            attester_pk,
+            subject_keys,
+            timestamp,
+            signature: *signature.as_bytes(),
+        }
+    }
+
+    /// Builds the data to be signed/verified.
+    fn build_sign_data(
+        attester_pk: &[u8; 32],
+        subject_keys: &[[u8; 32]],
+        timestamp: u64,
+    ) -> Vec<u8> {
+        let mut data =
+            Vec::with_capacity(ATTESTATION_DOMAIN.len() + 32 + subject_keys.len() * 32 + 8);
+        data.extend_from_slice(ATTESTATION_DOMAIN);
+        data.extend_from_slice(attester_pk);
+        for key in subject_keys {
+            data.extend_from_slice(key);
+        }
+        data.extend_from_slice(&timestamp.to_le_bytes());
+        data
+    }
+
+    /// Returns the attester's public key.
+    pub fn attester_pk(&self) -> &[u8; 32] {
+        &self.attester_pk
+    }
+
+    /// Returns the attested public keys.
+    pub fn subject_keys(&self) -> &[[u8; 32]] {
+        &self.subject_keys
+    }
+
+    /// Returns the attestation timestamp.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Verifies the attestation signature against the embedded attester key.
+    pub fn verify(&self) -> bool {
+        let data = Self::build_sign_data(&self.attester_pk, &self.subject_keys, self.timestamp);
+        let public_key = PublicKey::from_bytes(self.attester_pk);
+        let signature = Signature::from_bytes(self.signature);
+        public_key.verify(&data, &signature)
+    }
+
+    /// Verifies the signature and checks the attester is one of `trusted_keys`.
+    pub fn verify_trusted(&self, trusted_keys: &[[u8; 32]]) -> Result<(), AttestationError> {
+        if !self.verify() {
+            return Err(AttestationError::InvalidSignature);
+        }
+        if !trusted_keys.contains(&self.attester_pk) {
+            return Err(AttestationError::UntrustedAttester);
+        }
+        Ok(())
+    }
+
+    /// Serializes the attestation to bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("Serialization should not fail")
+    }
+
+    /// Deserializes an attestation from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AttestationError> {
+        bincode::deserialize(bytes).map_err(|_| AttestationError::InvalidFormat)
+    }
+
+    /// Replaces the subject keys for testing purposes (to test tamper detection).
+    #[doc(hidden)]
+    pub fn set_subject_keys_for_testing(&mut self, subject_keys: Vec<[u8; 32]>) {
+        self.subject_keys = subject_keys;
+    }
+}
@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Invite Suggestions
+//!
+//! Ranks native address book entries by how many of their social handles
+//! also appear on exchanged contacts' cards. Matching is done locally;
+//! nothing leaves the device.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::contact::Contact;
+use crate::contact_card::FieldType;
+use crate::storage::AddressBookEntry;
+
+/// A native address book entry suggested for an invite.
+#[derive(Debug, Clone)]
+pub struct InviteSuggestion {
+    /// The address book entry.
+    pub entry: AddressBookEntry,
+    /// Number of social handles shared with exchanged contacts' cards.
+    pub score: u32,
+    /// IDs of the exchanged contacts whose cards share a handle.
+    pub matched_contact_ids: Vec<String>,
+}
+
+/// Suggests address book entries to invite, best matches first.
+///
+/// Entries with an email or phone number found on an exchanged contact's
+/// card are taken to be that contact and skipped. The rest are ranked by
+/// score, then by name; entries with no matches are still returned, last.
+pub fn suggest_invites(
+    entries: &[AddressBookEntry],
+    contacts: &[Contact],
+) -> Vec<InviteSuggestion> {
+    let mut exchanged: HashSet<String> = HashSet::new();
+    let mut handles: HashMap<String, Vec<&str>> = HashMap::new();
+    for contact in contacts {
+        for field in contact.card().fields() {
+            match field.field_type() {
+                FieldType::Email => exchanged.extend(normalize_email(field.value())),
+                FieldType::Phone => exchanged.extend(normalize_phone(field.value())),
+                FieldType::Social => {
+                    if let Some(handle) = normalize_handle(field.value()) {
+                        handles.entry(handle).or_default().push(contact.id());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut suggestions: Vec<InviteSuggestion> = entries
+        .iter()
+        .filter(|entry| {
+            !entry
+                .emails
+                .iter()
+                .filter_map(|v| normalize_email(v))
+                .chain(entry.phones.iter().filter_map(|v| normalize_phone(v)))
+                .any(|key| exchanged.contains(&key))
+        })
+        .map(|entry| {
+            let keys: HashSet<String> = entry
+                .social_handles
+                .iter()
+                .filter_map(|v| normalize_handle(v))
+                .collect();
+
+            let mut score = 0u32;
+            let mut matched_contact_ids = Vec::new();
+            for key in &keys {
+                if let Some(ids) = handles.get(key) {
+                    score += 1;
+                    matched_contact_ids.extend(ids.iter().map(|id| id.to_string()));
+                }
+            }
+            matched_contact_ids.sort();
+            matched_contact_ids.dedup();
+
+            InviteSuggestion {
+                entry: entry.clone(),
+                score,
+                matched_contact_ids,
+            }
+        })
+        .collect();
+
+    suggestions.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.entry.display_name.cmp(&b.entry.display_name))
+    });
+    suggestions
+}
+
+/// Lowercases an email address.
+fn normalize_email(value: &str) -> Option<String> {
+    let value = value.trim().to_lowercase();
+    value.contains('@').then(|| format!("email:{}", value))
+}
+
+/// Keeps only the digits of a phone number.
+fn normalize_phone(value: &str) -> Option<String> {
+    let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+    (digits.len() >= 6).then(|| format!("phone:{}", digits))
+}
+
+/// Lowercases a social handle and drops a leading '@'.
+fn normalize_handle(value: &str) -> Option<String> {
+    let handle = value.trim().trim_start_matches('@').to_lowercase();
+    (!handle.is_empty()).then(|| format!("social:{}", handle))
+}
@@ -17,6 +17,9 @@ pub const MAX_LABELS: usize = 50;
 /// Suggested default labels for new users.
 pub const SUGGESTED_LABELS: &[&str] = &["Family", "Friends", "Professional"];
 
+/// Maximum length of a label icon key.
+pub const MAX_LABEL_ICON_LEN: usize = 50;
+
 /// Error type for label operations.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LabelError {
@@ -28,6 +31,10 @@ pub enum LabelError {
     MaxLabelsReached,
     /// Invalid label name.
     InvalidName(String),
+    /// Color is not a `#RGB`, `#RRGGBB` or `#RRGGBBAA` hex string.
+    InvalidColor(String),
+    /// Icon key is empty or too long.
+    InvalidIcon(String),
 }
 
 impl std::fmt::Display for LabelError {
@@ -39,12 +46,34 @@ impl std::fmt::Display for LabelError {
                 write!(f, "Maximum number of labels reached ({})", MAX_LABELS)
             }
             LabelError::InvalidName(msg) => write!(f, "Invalid label name: {}", msg),
+            LabelError::InvalidColor(color) => write!(f, "Invalid label color: {}", color),
+            LabelError::InvalidIcon(icon) => write!(f, "Invalid label icon: {}", icon),
         }
     }
 }
 
 impl std::error::Error for LabelError {}
 
+/// Checks that a label color is a `#RGB`, `#RRGGBB` or `#RRGGBBAA` hex string.
+pub fn validate_label_color(color: &str) -> Result<(), LabelError> {
+    let valid = color.strip_prefix('#').is_some_and(|hex| {
+        matches!(hex.len(), 3 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    });
+    if valid {
+        Ok(())
+    } else {
+        Err(LabelError::InvalidColor(color.to_string()))
+    }
+}
+
+/// Checks that a label icon key is non-empty and not too long.
+pub fn validate_label_icon(icon: &str) -> Result<(), LabelError> {
+    if icon.trim().is_empty() || icon.len() > MAX_LABEL_ICON_LEN {
+        return Err(LabelError::InvalidIcon(icon.to_string()));
+    }
+    Ok(())
+}
+
 /// A visibility label for organizing contacts.
 ///
 /// Labels allow grouping contacts and assigning field visibility to groups.
@@ -64,6 +93,12 @@ pub struct VisibilityLabel {
     created_at: u64,
     /// Timestamp when the label was last modified.
     modified_at: u64,
+    /// Display color as a hex string (e.g. "#FF8800").
+    #[serde(default)]
+    color: Option<String>,
+    /// Icon key understood by the UI.
+    #[serde(default)]
+    icon: Option<String>,
 }
 
 impl VisibilityLabel {
@@ -81,6 +116,8 @@ impl VisibilityLabel {
             visible_fields: HashSet::new(),
             created_at: now,
             modified_at: now,
+            color: None,
+            icon: None,
         }
     }
 
@@ -100,9 +137,18 @@ impl VisibilityLabel {
             visible_fields,
             created_at,
             modified_at,
+            color: None,
+            icon: None,
         }
     }
 
+    /// Attaches a stored color and icon, without validating them.
+    pub fn with_style(mut self, color: Option<String>, icon: Option<String>) -> Self {
+        self.color = color;
+        self.icon = icon;
+        self
+    }
+
     /// Returns the label ID.
     pub fn id(&self) -> &str {
         &self.id
@@ -194,6 +240,30 @@ impl VisibilityLabel {
         self.touch();
     }
 
+    /// Returns the display color, if set.
+    pub fn color(&self) -> Option<&str> {
+        self.color.as_deref()
+    }
+
+    /// Returns the icon key, if set.
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    /// Sets the display color and icon; `None` clears them.
+    pub fn set_style(&mut self, color: Option<&str>, icon: Option<&str>) -> Result<(), LabelError> {
+        if let Some(color) = color {
+            validate_label_color(color)?;
+        }
+        if let Some(icon) = icon {
+            validate_label_icon(icon)?;
+        }
+        self.color = color.map(str::to_string);
+        self.icon = icon.map(str::to_string);
+        self.touch();
+        Ok(())
+    }
+
     /// Returns the creation timestamp.
     pub fn created_at(&self) -> u64 {
         self.created_at
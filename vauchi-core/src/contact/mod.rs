@@ -7,6 +7,8 @@
 //! Represents contacts obtained through exchange, with shared encryption keys
 //! and visibility rules.
 
+pub mod attestation;
+pub mod invite;
 pub mod labels;
 pub mod merge;
 
@@ -15,13 +17,42 @@ pub mod visibility;
 #[cfg(not(feature = "testing"))]
 mod visibility;
 
-pub use labels::{LabelError, LabelManager, VisibilityLabel, MAX_LABELS, SUGGESTED_LABELS};
-pub use visibility::{FieldVisibility, VisibilityRules};
+pub use attestation::{AttestationError, VerificationAttestation};
+pub use invite::{suggest_invites, InviteSuggestion};
+pub use labels::{
+    validate_label_color, validate_label_icon, LabelError, LabelManager, VisibilityLabel,
+    MAX_LABELS, MAX_LABEL_ICON_LEN, SUGGESTED_LABELS,
+};
+pub use visibility::{
+    ContactFieldVisibility, DefaultFieldVisibility, FieldVisibility, VisibilityRules,
+};
 
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::contact_card::ContactCard;
 use crate::crypto::SymmetricKey;
+use crate::identity::{KeyRotationError, KeyRotationProof};
+
+/// Errors when creating or re-keying a contact.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ContactError {
+    #[error("Contact public key matches our own identity")]
+    SelfContact,
+}
+
+/// Rejects a contact public key equal to our own signing key.
+///
+/// Such a contact would share our ID and could only come from scanning our
+/// own QR code or from a forged message.
+pub fn check_not_self(
+    public_key: &[u8; 32],
+    own_public_key: &[u8; 32],
+) -> Result<(), ContactError> {
+    if public_key == own_public_key {
+        return Err(ContactError::SelfContact);
+    }
+    Ok(())
+}
 
 /// A contact obtained through exchange.
 ///
@@ -42,6 +73,10 @@ pub struct Contact {
     exchange_timestamp: u64,
     /// Whether the user manually verified their fingerprint
     fingerprint_verified: bool,
+    /// Unix timestamp of the last fingerprint verification, if known
+    verified_at: Option<u64>,
+    /// ID of the attester who vouched for this contact, if verified via attestation
+    verified_by: Option<String>,
     /// Our visibility rules for this contact (what they can see of our card)
     visibility_rules: VisibilityRules,
     /// Whether this contact is hidden from the main contact list.
@@ -50,6 +85,18 @@ pub struct Contact {
     /// Whether this contact is blocked.
     /// Blocked contacts don't receive updates and their updates are ignored.
     blocked: bool,
+    /// Whether this contact is pinned to the top of the contact list.
+    /// Local-only: never synced to other devices or the contact.
+    pinned: bool,
+    /// Whether change notifications for this contact are suppressed.
+    /// Updates are still received and applied. Local-only.
+    muted: bool,
+    /// Whether this contact was imported from an export rather than exchanged.
+    /// Reference contacts have no usable shared key and never sync.
+    reference: bool,
+    /// Whether this contact advertised that it reads padded updates.
+    /// Learned from its exchange message; older clients never set it.
+    accepts_padding: bool,
 }
 
 impl Contact {
@@ -74,12 +121,39 @@ impl Contact {
             shared_key,
             exchange_timestamp,
             fingerprint_verified: false,
+            verified_at: None,
+            verified_by: None,
             visibility_rules: VisibilityRules::new(),
             hidden: false,
             blocked: false,
+            pinned: false,
+            muted: false,
+            reference: false,
+            accepts_padding: false,
         }
     }
 
+    /// Creates a reference contact from an exported card.
+    ///
+    /// The shared key is random and never used: no updates are sent to or
+    /// accepted from the contact until a real exchange replaces it.
+    pub fn from_reference(public_key: [u8; 32], card: ContactCard) -> Self {
+        let mut contact = Self::from_exchange(public_key, card, SymmetricKey::generate());
+        contact.reference = true;
+        contact
+    }
+
+    /// Creates a new contact from exchange data, refusing our own public key.
+    pub fn try_from_exchange(
+        public_key: [u8; 32],
+        card: ContactCard,
+        shared_key: SymmetricKey,
+        own_public_key: &[u8; 32],
+    ) -> Result<Self, ContactError> {
+        check_not_self(&public_key, own_public_key)?;
+        Ok(Self::from_exchange(public_key, card, shared_key))
+    }
+
     /// Creates a contact from device sync data.
     ///
     /// Used when syncing contacts to a new device.
@@ -126,9 +200,15 @@ impl Contact {
             shared_key,
             exchange_timestamp,
             fingerprint_verified,
+            verified_at: None,
+            verified_by: None,
             visibility_rules,
             hidden,
             blocked,
+            pinned: false,
+            muted: false,
+            reference: false,
+            accepts_padding: false,
         }
     }
 
@@ -167,9 +247,41 @@ impl Contact {
         self.fingerprint_verified
     }
 
-    /// Marks the fingerprint as verified.
+    /// Marks the fingerprint as verified now.
     pub fn mark_fingerprint_verified(&mut self) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+        self.mark_fingerprint_verified_at(now);
+    }
+
+    /// Marks the fingerprint as verified at the given Unix timestamp.
+    pub fn mark_fingerprint_verified_at(&mut self, timestamp: u64) {
         self.fingerprint_verified = true;
+        self.verified_at = Some(timestamp);
+        self.verified_by = None;
+    }
+
+    /// Marks the fingerprint as verified on the word of a trusted attester.
+    pub fn mark_fingerprint_verified_by(&mut self, attester_id: &str, timestamp: u64) {
+        self.mark_fingerprint_verified_at(timestamp);
+        self.verified_by = Some(attester_id.to_string());
+    }
+
+    /// Returns when the fingerprint was last verified.
+    ///
+    /// Returns `None` if the contact is unverified, or was verified
+    /// before verification dates were recorded.
+    pub fn verified_at(&self) -> Option<u64> {
+        self.verified_at
+    }
+
+    /// Returns the ID of the attester who vouched for this contact.
+    ///
+    /// Returns `None` if the fingerprint was verified in person (or not at all).
+    pub fn verified_by(&self) -> Option<&str> {
+        self.verified_by.as_deref()
     }
 
     /// Returns a reference to the visibility rules.
@@ -188,6 +300,14 @@ impl Contact {
         self.card = card;
     }
 
+    /// Replaces the shared secret after a re-key.
+    ///
+    /// Unlike a recovery, the contact keeps its identity, card and
+    /// fingerprint verification.
+    pub fn rekey(&mut self, new_shared_key: SymmetricKey) {
+        self.shared_key = new_shared_key;
+    }
+
     /// Accepts a recovery, updating the contact's public key and shared secret.
     ///
     /// This is called when the user accepts a recovery proof from this contact.
@@ -197,6 +317,8 @@ impl Contact {
         self.id = hex::encode(new_public_key);
         self.shared_key = new_shared_key;
         self.fingerprint_verified = false;
+        self.verified_at = None;
+        self.verified_by = None;
         // Update exchange timestamp to mark when recovery was accepted
         self.exchange_timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -204,6 +326,18 @@ impl Contact {
             .as_secs();
     }
 
+    /// Accepts a recovery, refusing a new public key equal to our own.
+    pub fn try_accept_recovery(
+        &mut self,
+        new_public_key: [u8; 32],
+        new_shared_key: SymmetricKey,
+        own_public_key: &[u8; 32],
+    ) -> Result<(), ContactError> {
+        check_not_self(&new_public_key, own_public_key)?;
+        self.accept_recovery(new_public_key, new_shared_key);
+        Ok(())
+    }
+
     /// Accepts a recovery with a new contact card.
     ///
     /// This is called when the recovering contact also provides an updated card.
@@ -217,6 +351,23 @@ impl Contact {
         self.update_card(new_card);
     }
 
+    /// Follows a signing key rotation announced by this contact.
+    ///
+    /// The proof must start from the contact's current key and verify.
+    /// Unlike a recovery, the old key vouches for the new one, so the
+    /// shared secret, card and fingerprint verification are kept. The
+    /// contact's ID changes with its key.
+    pub fn apply_key_rotation(&mut self, proof: &KeyRotationProof) -> Result<(), KeyRotationError> {
+        if proof.old_public_key() != &self.public_key {
+            return Err(KeyRotationError::KeyMismatch);
+        }
+        proof.verify()?;
+
+        self.public_key = *proof.new_public_key();
+        self.id = hex::encode(self.public_key);
+        Ok(())
+    }
+
     /// Updates the contact's display name.
     pub fn set_display_name(
         &mut self,
@@ -240,6 +391,22 @@ impl Contact {
             .to_uppercase()
     }
 
+    /// Returns a 60-digit numeric safety number shared by both parties.
+    ///
+    /// Follows the Signal scheme: each public key is hashed into a 30-digit
+    /// fingerprint, and the two fingerprints are concatenated in sorted key
+    /// order so that both sides compute the same number.
+    pub fn safety_number(&self, own_pk: &[u8; 32]) -> String {
+        let ours = safety_number_part(own_pk);
+        let theirs = safety_number_part(&self.public_key);
+
+        if own_pk <= &self.public_key {
+            format!("{}{}", ours, theirs)
+        } else {
+            format!("{}{}", theirs, ours)
+        }
+    }
+
     // ========================================
     // Hidden Contacts (Plausible Deniability)
     // ========================================
@@ -300,6 +467,58 @@ impl Contact {
         self.blocked = blocked;
     }
 
+    // ========================================
+    // Pinned Contacts
+    // ========================================
+
+    /// Returns whether this contact is pinned to the top of the contact list.
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// Sets the pinned status.
+    pub fn set_pinned(&mut self, pinned: bool) {
+        self.pinned = pinned;
+    }
+
+    // ========================================
+    // Muted Contacts
+    // ========================================
+
+    /// Returns whether change notifications for this contact are muted.
+    ///
+    /// Unlike blocking, updates from a muted contact are still applied;
+    /// only the update notifications are suppressed. Removals are still
+    /// reported.
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    /// Sets the muted status.
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+    }
+
+    /// Returns whether this contact was imported rather than exchanged.
+    pub fn is_reference(&self) -> bool {
+        self.reference
+    }
+
+    /// Sets the reference status.
+    pub fn set_reference(&mut self, reference: bool) {
+        self.reference = reference;
+    }
+
+    /// Returns whether updates to this contact may be padded.
+    pub fn accepts_padding(&self) -> bool {
+        self.accepts_padding
+    }
+
+    /// Sets whether this contact reads padded updates.
+    pub fn set_accepts_padding(&mut self, accepts_padding: bool) {
+        self.accepts_padding = accepts_padding;
+    }
+
     /// Returns true if this contact should be visible in the main contact list.
     ///
     /// A contact is visible if it's not hidden.
@@ -317,8 +536,36 @@ impl Contact {
 
     /// Returns true if updates should be sent to this contact.
     ///
-    /// Updates are not sent to blocked contacts.
+    /// Updates are not sent to blocked or reference contacts.
     pub fn should_send_updates(&self) -> bool {
-        !self.blocked
+        !self.blocked && !self.reference
     }
 }
+
+/// Number of hash iterations per safety number half (matches Signal).
+const SAFETY_NUMBER_ITERATIONS: usize = 5200;
+
+/// Version prefix mixed into safety number hashing.
+const SAFETY_NUMBER_VERSION: [u8; 2] = [0, 0];
+
+/// Computes one 30-digit half of a safety number for a public key.
+///
+/// The key is hashed iteratively with SHA-512; the first 30 bytes of the
+/// result are split into six 5-byte chunks, each reduced to 5 decimal digits.
+fn safety_number_part(public_key: &[u8; 32]) -> String {
+    use ring::digest::{digest, SHA512};
+
+    let mut hash = [SAFETY_NUMBER_VERSION.as_slice(), public_key.as_slice()].concat();
+    for _ in 0..SAFETY_NUMBER_ITERATIONS {
+        let input = [hash.as_slice(), public_key.as_slice()].concat();
+        hash = digest(&SHA512, &input).as_ref().to_vec();
+    }
+
+    hash[..30]
+        .chunks(5)
+        .map(|chunk| {
+            let value = chunk.iter().fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+            format!("{:05}", value % 100_000)
+        })
+        .collect()
+}
@@ -21,6 +21,18 @@ pub enum FieldVisibility {
     Nobody,
 }
 
+/// Visibility given to a field when it is first added to your card.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DefaultFieldVisibility {
+    /// New fields are visible to everyone
+    #[default]
+    Everyone,
+    /// New fields stay hidden until shared explicitly
+    Nobody,
+    /// New fields are visible to contacts in these labels (by label ID)
+    Labels(HashSet<String>),
+}
+
 /// Visibility rules for all fields in a contact card.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct VisibilityRules {
@@ -45,6 +57,11 @@ impl VisibilityRules {
             .unwrap_or(&FieldVisibility::Everyone)
     }
 
+    /// Sets the visibility for a field.
+    pub fn set(&mut self, field_id: &str, visibility: FieldVisibility) {
+        self.rules.insert(field_id.to_string(), visibility);
+    }
+
     /// Sets visibility for a field to everyone.
     pub fn set_everyone(&mut self, field_id: &str) {
         self.rules
@@ -70,10 +87,17 @@ impl VisibilityRules {
 
     /// Checks if a specific contact can see a field.
     pub fn can_see(&self, field_id: &str, contact_id: &str) -> bool {
-        match self.get(field_id) {
-            FieldVisibility::Everyone => true,
-            FieldVisibility::Contacts(allowed) => allowed.contains(contact_id),
-            FieldVisibility::Nobody => false,
+        self.can_see_or(field_id, contact_id, true)
+    }
+
+    /// Checks if a specific contact can see a field, using `default` when
+    /// no rule is set for the field.
+    pub fn can_see_or(&self, field_id: &str, contact_id: &str, default: bool) -> bool {
+        match self.rules.get(field_id) {
+            None => default,
+            Some(FieldVisibility::Everyone) => true,
+            Some(FieldVisibility::Contacts(allowed)) => allowed.contains(contact_id),
+            Some(FieldVisibility::Nobody) => false,
         }
     }
 
@@ -86,3 +110,52 @@ impl VisibilityRules {
             .collect()
     }
 }
+
+/// Everything that decides which of our fields one contact may see.
+///
+/// Precedence: per-contact overrides, then labels, then the contact's
+/// visibility rules, then the field's default.
+#[derive(Clone, Debug)]
+pub struct ContactFieldVisibility {
+    contact_id: String,
+    rules: VisibilityRules,
+    overrides: HashMap<String, bool>,
+    label_fields: HashSet<String>,
+    hidden_by_default: HashSet<String>,
+}
+
+impl ContactFieldVisibility {
+    /// Creates the visibility view for a contact.
+    ///
+    /// `label_fields` are the fields shown by any label the contact is in.
+    pub fn new(
+        contact_id: &str,
+        rules: VisibilityRules,
+        overrides: HashMap<String, bool>,
+        label_fields: HashSet<String>,
+        hidden_by_default: HashSet<String>,
+    ) -> Self {
+        ContactFieldVisibility {
+            contact_id: contact_id.to_string(),
+            rules,
+            overrides,
+            label_fields,
+            hidden_by_default,
+        }
+    }
+
+    /// Checks if the contact can see a field.
+    pub fn can_see(&self, field_id: &str) -> bool {
+        match self.overrides.get(field_id) {
+            Some(&is_visible) => is_visible,
+            None => {
+                self.label_fields.contains(field_id)
+                    || self.rules.can_see_or(
+                        field_id,
+                        &self.contact_id,
+                        !self.hidden_by_default.contains(field_id),
+                    )
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Card Completeness
+//!
+//! Scores how filled-in a card is, for onboarding progress indicators.
+//! Each [`CompletenessItem`] counts equally and the percentage is rounded
+//! down, so the same card always yields the same score.
+
+use super::{ContactCard, FieldType};
+
+/// Something a complete card has.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompletenessItem {
+    /// A non-blank display name.
+    DisplayName,
+    /// At least one phone number or email address.
+    ContactMethod,
+    /// An avatar image.
+    Photo,
+    /// At least one social network link.
+    SocialLink,
+}
+
+impl CompletenessItem {
+    /// All items, in the order they are reported.
+    pub fn all() -> &'static [CompletenessItem] {
+        &[
+            CompletenessItem::DisplayName,
+            CompletenessItem::ContactMethod,
+            CompletenessItem::Photo,
+            CompletenessItem::SocialLink,
+        ]
+    }
+
+    /// Returns true if the card has this item.
+    fn is_present(&self, card: &ContactCard) -> bool {
+        match self {
+            CompletenessItem::DisplayName => !card.display_name().trim().is_empty(),
+            CompletenessItem::ContactMethod => card.fields().iter().any(|f| {
+                matches!(f.field_type(), FieldType::Phone | FieldType::Email)
+                    && !f.value().trim().is_empty()
+            }),
+            CompletenessItem::Photo => card.avatar().is_some_and(|a| !a.is_empty()),
+            CompletenessItem::SocialLink => card
+                .fields()
+                .iter()
+                .any(|f| f.field_type() == FieldType::Social && !f.value().trim().is_empty()),
+        }
+    }
+}
+
+/// Result of [`ContactCard::completeness`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompletenessReport {
+    /// Share of items present, 0-100.
+    pub percent: u8,
+    /// Items the card is still missing, in [`CompletenessItem::all`] order.
+    pub missing: Vec<CompletenessItem>,
+}
+
+impl CompletenessReport {
+    /// Returns true if nothing is missing.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl ContactCard {
+    /// Scores how complete this card is.
+    pub fn completeness(&self) -> CompletenessReport {
+        let items = CompletenessItem::all();
+        let missing: Vec<CompletenessItem> = items
+            .iter()
+            .filter(|item| !item.is_present(self))
+            .copied()
+            .collect();
+
+        let present = items.len() - missing.len();
+        let percent = (present * 100 / items.len()) as u8;
+
+        CompletenessReport { percent, missing }
+    }
+}
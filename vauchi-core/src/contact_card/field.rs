@@ -7,6 +7,7 @@
 //! Handles individual contact fields like phone, email, social media, etc.
 
 use super::ValidationError;
+use crate::content::{render_plain, sanitize_markdown};
 use serde::{Deserialize, Serialize};
 
 /// Maximum length for field values.
@@ -26,6 +27,23 @@ pub enum FieldType {
     Address,
     Website,
     Custom,
+    /// Short formatted text (e.g. a bio), stored as sanitized Markdown.
+    RichText,
+}
+
+impl FieldType {
+    /// Stable name of the type, the same as its serialized form.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FieldType::Phone => "Phone",
+            FieldType::Email => "Email",
+            FieldType::Social => "Social",
+            FieldType::Address => "Address",
+            FieldType::Website => "Website",
+            FieldType::Custom => "Custom",
+            FieldType::RichText => "RichText",
+        }
+    }
 }
 
 /// Returns the current Unix timestamp in seconds.
@@ -36,6 +54,14 @@ fn now_timestamp() -> u64 {
         .as_secs()
 }
 
+/// Sanitizes rich text values; other field types are stored verbatim.
+fn sanitize_value(field_type: &FieldType, value: &str) -> String {
+    match field_type {
+        FieldType::RichText => sanitize_markdown(value),
+        _ => value.to_string(),
+    }
+}
+
 /// A single contact field (phone, email, etc.).
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ContactField {
@@ -50,6 +76,9 @@ pub struct ContactField {
     /// Timestamp of the last update (Unix seconds). Defaults to 0 for backward compatibility.
     #[serde(default)]
     updated_at: u64,
+    /// Whether this is the main field of its type, e.g. the main email.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    is_primary: bool,
 }
 
 impl ContactField {
@@ -63,12 +92,15 @@ impl ContactField {
             .expose();
         let id = hex::encode(random_bytes);
 
+        let value = sanitize_value(&field_type, value);
+
         ContactField {
             id,
             field_type,
             label: label.to_string(),
-            value: value.to_string(),
+            value,
             updated_at: now_timestamp(),
+            is_primary: false,
         }
     }
 
@@ -97,17 +129,43 @@ impl ContactField {
         &self.value
     }
 
+    /// Returns true if this is the main field of its type.
+    pub fn is_primary(&self) -> bool {
+        self.is_primary
+    }
+
+    /// Marks or unmarks this as the main field of its type.
+    ///
+    /// Only the card can keep other fields of the type from also being
+    /// primary; see `ContactCard::set_primary_field`.
+    pub(crate) fn set_primary(&mut self, is_primary: bool) {
+        self.is_primary = is_primary;
+    }
+
     /// Returns the timestamp of the last update (Unix seconds).
     pub fn updated_at(&self) -> u64 {
         self.updated_at
     }
 
     /// Sets the field value and updates the timestamp.
+    ///
+    /// Rich text values are sanitized before they are stored.
     pub fn set_value(&mut self, value: &str) {
-        self.value = value.to_string();
+        self.value = sanitize_value(&self.field_type, value);
         self.updated_at = now_timestamp();
     }
 
+    /// Returns the value as plain text, e.g. for list previews.
+    ///
+    /// Rich text is rendered without Markdown syntax; other values are
+    /// returned as-is.
+    pub fn render_plain(&self) -> String {
+        match self.field_type {
+            FieldType::RichText => render_plain(&self.value),
+            _ => self.value.clone(),
+        }
+    }
+
     /// Validates the field value based on its type.
     pub fn validate(&self) -> Result<(), ValidationError> {
         // Check max length
@@ -121,6 +179,7 @@ impl ContactField {
         match self.field_type {
             FieldType::Phone => self.validate_phone(),
             FieldType::Email => self.validate_email(),
+            FieldType::RichText => self.validate_rich_text(),
             _ => Ok(()), // Other types accept any value
         }
     }
@@ -147,6 +206,15 @@ impl ContactField {
         Ok(())
     }
 
+    /// Rejects rich text that sanitizing would change, such as a value
+    /// received from a peer that never went through `new`/`set_value`.
+    fn validate_rich_text(&self) -> Result<(), ValidationError> {
+        if sanitize_markdown(&self.value) != self.value {
+            return Err(ValidationError::UnsafeContent);
+        }
+        Ok(())
+    }
+
     /// Validates email format.
     fn validate_email(&self) -> Result<(), ValidationError> {
         let value = &self.value;
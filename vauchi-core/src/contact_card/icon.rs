@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Field Icons
+//!
+//! Picks a stable icon identifier for each field, so every UI shows the
+//! same icon for the same field and social networks look alike across
+//! platforms. UIs map the identifiers to their own assets and fall back
+//! to the generic ones for identifiers they do not know.
+
+use super::{ContactField, FieldType};
+use crate::social::SocialNetworkRegistry;
+
+/// Icon for social fields whose network is not in the registry.
+pub const GENERIC_SOCIAL_ICON: &str = "generic-social";
+
+impl ContactField {
+    /// Returns the identifier of the icon to show next to this field.
+    ///
+    /// Social fields use the icon of the network named by their label,
+    /// or the network's ID if it has no icon of its own; unknown networks
+    /// get `GENERIC_SOCIAL_ICON`. Custom fields use the icon of the type
+    /// their value looks like.
+    pub fn icon_key(&self, registry: &SocialNetworkRegistry) -> String {
+        let effective_type = if self.field_type() == FieldType::Custom {
+            self.detect_value_type().unwrap_or(FieldType::Custom)
+        } else {
+            self.field_type()
+        };
+
+        let key = match effective_type {
+            FieldType::Phone => "phone",
+            FieldType::Email => "email",
+            FieldType::Address => "address",
+            FieldType::Website => "website",
+            FieldType::Custom => "custom",
+            FieldType::RichText => "text",
+            FieldType::Social => {
+                return match registry.get(self.label().trim()) {
+                    Some(network) => network.icon().unwrap_or(network.id()).to_string(),
+                    None => GENERIC_SOCIAL_ICON.to_string(),
+                };
+            }
+        };
+        key.to_string()
+    }
+}
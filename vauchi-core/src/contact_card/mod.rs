@@ -6,11 +6,26 @@
 //!
 //! Handles contact card creation, fields, and validation.
 
+#[cfg(feature = "testing")]
+pub mod completeness;
+#[cfg(not(feature = "testing"))]
+mod completeness;
+
 #[cfg(feature = "testing")]
 pub mod field;
 #[cfg(not(feature = "testing"))]
 mod field;
 
+#[cfg(feature = "testing")]
+pub mod icon;
+#[cfg(not(feature = "testing"))]
+mod icon;
+
+#[cfg(feature = "testing")]
+pub mod public;
+#[cfg(not(feature = "testing"))]
+mod public;
+
 #[cfg(feature = "testing")]
 pub mod uri;
 #[cfg(not(feature = "testing"))]
@@ -23,14 +38,23 @@ mod validation;
 
 pub mod vcard;
 
+pub use crate::content::{render_plain, sanitize_markdown};
+pub use completeness::{CompletenessItem, CompletenessReport};
 pub use field::{ContactField, FieldType};
-pub use uri::{is_allowed_scheme, is_blocked_scheme, is_safe_url, ContactAction};
+pub use icon::GENERIC_SOCIAL_ICON;
+pub use public::{
+    is_valid_public_handle, PublicCard, PublicCardRemoval, MAX_HANDLE_LENGTH, MIN_HANDLE_LENGTH,
+};
+pub use uri::{is_allowed_scheme, is_blocked_scheme, is_safe_url, ContactAction, SafeFieldValue};
 pub use validation::ValidationError;
 
+use ring::digest::{Context, SHA256};
 use ring::rand::SystemRandom;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::sync::CardDelta;
+
 /// Maximum number of fields per contact card.
 pub const MAX_FIELDS: usize = 25;
 
@@ -62,6 +86,8 @@ pub enum ContactCardError {
     Serialization(String),
     #[error("Validation error: {0}")]
     Validation(#[from] ValidationError),
+    #[error("Invalid public handle: {0}")]
+    InvalidHandle(String),
 }
 
 /// A user's contact card containing personal information fields.
@@ -128,7 +154,17 @@ impl ContactCard {
         &mut self.fields
     }
 
+    /// Returns the field with the given ID.
+    ///
+    /// Labels are display-only and may repeat; IDs identify fields uniquely.
+    pub fn field(&self, field_id: &str) -> Option<&ContactField> {
+        self.fields.iter().find(|f| f.id() == field_id)
+    }
+
     /// Adds a field to the card.
+    ///
+    /// A field that arrives marked primary replaces the card's primary
+    /// field of its type.
     pub fn add_field(&mut self, field: ContactField) -> Result<(), ContactCardError> {
         if self.fields.len() >= MAX_FIELDS {
             return Err(ContactCardError::MaxFieldsReached);
@@ -137,10 +173,47 @@ impl ContactCard {
         // Validate the field before adding
         field.validate()?;
 
+        if field.is_primary() {
+            self.clear_primary_field(&field.field_type());
+        }
         self.fields.push(field);
         Ok(())
     }
 
+    /// Makes a field the main one of its type, e.g. the main email.
+    ///
+    /// At most one field per type is primary; the previous one is unset.
+    pub fn set_primary_field(&mut self, field_id: &str) -> Result<(), ContactCardError> {
+        let field_type = self
+            .field(field_id)
+            .ok_or(ContactCardError::FieldNotFound)?
+            .field_type();
+
+        self.clear_primary_field(&field_type);
+        if let Some(field) = self.fields.iter_mut().find(|f| f.id() == field_id) {
+            field.set_primary(true);
+        }
+        Ok(())
+    }
+
+    /// Returns the primary field of a type, if one is set.
+    pub fn primary_field(&self, field_type: &FieldType) -> Option<&ContactField> {
+        self.fields
+            .iter()
+            .find(|f| f.is_primary() && f.field_type() == *field_type)
+    }
+
+    /// Leaves no field of a type marked primary.
+    pub fn clear_primary_field(&mut self, field_type: &FieldType) {
+        for field in self
+            .fields
+            .iter_mut()
+            .filter(|f| f.field_type() == *field_type)
+        {
+            field.set_primary(false);
+        }
+    }
+
     /// Updates a field's value by ID.
     pub fn update_field_value(
         &mut self,
@@ -174,6 +247,35 @@ impl ContactCard {
         Ok(())
     }
 
+    /// Sets the value of the field with this type and label, adding the
+    /// field if there is none.
+    ///
+    /// Matches the first such field, as labels may repeat. Returns true if
+    /// a field was created and false if an existing one was updated.
+    pub fn upsert_field(
+        &mut self,
+        field_type: FieldType,
+        label: &str,
+        value: &str,
+    ) -> Result<bool, ContactCardError> {
+        let existing = self
+            .fields
+            .iter()
+            .find(|f| f.field_type() == field_type && f.label() == label)
+            .map(|f| f.id().to_string());
+
+        match existing {
+            Some(field_id) => {
+                self.update_field_value(&field_id, value)?;
+                Ok(false)
+            }
+            None => {
+                self.add_field(ContactField::new(field_type, label, value))?;
+                Ok(true)
+            }
+        }
+    }
+
     /// Removes a field from the card by ID.
     pub fn remove_field(&mut self, field_id: &str) -> Result<(), ContactCardError> {
         let index = self
@@ -252,4 +354,48 @@ impl ContactCard {
     pub fn clear_avatar(&mut self) {
         self.avatar = None;
     }
+
+    // === Change Detection ===
+
+    /// Returns a SHA-256 hash of the card's content.
+    ///
+    /// Covers the display name, avatar, and each field's ID, type, label,
+    /// value and primary flag. The card ID, field timestamps and field order are left
+    /// out, so a card hashes the same after a serialization round trip or
+    /// a reorder.
+    pub fn content_hash(&self) -> [u8; 32] {
+        fn put(ctx: &mut Context, bytes: &[u8]) {
+            ctx.update(&(bytes.len() as u64).to_be_bytes());
+            ctx.update(bytes);
+        }
+
+        let mut ctx = Context::new(&SHA256);
+        put(&mut ctx, self.display_name.as_bytes());
+        put(&mut ctx, self.avatar.as_deref().unwrap_or_default());
+
+        let mut fields: Vec<&ContactField> = self.fields.iter().collect();
+        fields.sort_by(|a, b| a.id().cmp(b.id()));
+        for field in fields {
+            put(&mut ctx, field.id().as_bytes());
+            put(&mut ctx, field.field_type().as_str().as_bytes());
+            put(&mut ctx, field.label().as_bytes());
+            put(&mut ctx, field.value().as_bytes());
+            // Only hashed when set, so hashes of existing cards are unchanged
+            if field.is_primary() {
+                put(&mut ctx, b"primary");
+            }
+        }
+
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(ctx.finish().as_ref());
+        hash
+    }
+
+    /// Computes the delta that turns this card into `other`.
+    ///
+    /// The delta is unsigned and empty when nothing sendable changed.
+    /// Avatars and field order are not carried by deltas.
+    pub fn diff(&self, other: &ContactCard) -> CardDelta {
+        CardDelta::compute(self, other)
+    }
 }
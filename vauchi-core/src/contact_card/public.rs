@@ -0,0 +1,250 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Public Card Permalinks
+//!
+//! A user can publish part of their card under a handle they choose, and
+//! the relay serves it to anyone at a stable URL, e.g. for a link printed
+//! on a business card. Publishing again replaces the card.
+//!
+//! The card is signed by the owner's identity key, so a viewer can check
+//! it was not altered by the relay. The relay binds a handle to the key
+//! that first published it; later publications and removals must be
+//! signed by the same key and be newer than the one they replace.
+//!
+//! Everything is JSON with hex-encoded keys, so a browser can verify a
+//! card without Vauchi's serialization.
+
+use serde::{Deserialize, Serialize};
+
+use super::{ContactCard, ContactCardError};
+use crate::crypto::{PublicKey, Signature};
+use crate::Identity;
+
+/// Domain separator mixed into public card signatures.
+const PUBLIC_CARD_DOMAIN: &[u8] = b"VAUCHI_PUBLIC_CARD";
+
+/// Domain separator mixed into removal signatures.
+const PUBLIC_CARD_REMOVAL_DOMAIN: &[u8] = b"VAUCHI_PUBLIC_CARD_REMOVAL";
+
+/// Shortest allowed handle.
+pub const MIN_HANDLE_LENGTH: usize = 3;
+
+/// Longest allowed handle.
+pub const MAX_HANDLE_LENGTH: usize = 32;
+
+/// Checks that `handle` can be used in a permalink.
+///
+/// Handles are 3 to 32 lowercase ASCII letters, digits and inner hyphens,
+/// so they read the same everywhere and cannot imitate one another with
+/// look-alike characters.
+pub fn is_valid_public_handle(handle: &str) -> bool {
+    (MIN_HANDLE_LENGTH..=MAX_HANDLE_LENGTH).contains(&handle.len())
+        && handle
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        && !handle.starts_with('-')
+        && !handle.ends_with('-')
+}
+
+/// A card published under a handle, signed by its owner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicCard {
+    /// Handle the card is published under.
+    handle: String,
+    /// Owner's Ed25519 public key (hex-encoded).
+    owner_id: String,
+    /// Unix timestamp of publication.
+    published_at: u64,
+    /// The published card, as JSON. Signed as-is.
+    card_json: String,
+    /// Signature over all of the above by the owner (hex-encoded).
+    signature: String,
+}
+
+impl PublicCard {
+    /// Signs `card` for publication under `handle`.
+    ///
+    /// `card` should already be reduced to the fields meant for the public.
+    pub fn create(
+        identity: &Identity,
+        handle: &str,
+        card: &ContactCard,
+        published_at: u64,
+    ) -> Result<Self, ContactCardError> {
+        if !is_valid_public_handle(handle) {
+            return Err(ContactCardError::InvalidHandle(handle.to_string()));
+        }
+        card.validate_size()?;
+        let card_json = serde_json::to_string(card)
+            .map_err(|e| ContactCardError::Serialization(e.to_string()))?;
+
+        let owner_id = hex::encode(identity.signing_public_key());
+        let signature = identity.sign(&Self::signable_bytes(
+            handle,
+            &owner_id,
+            published_at,
+            &card_json,
+        ));
+        Ok(PublicCard {
+            handle: handle.to_string(),
+            owner_id,
+            published_at,
+            card_json,
+            signature: hex::encode(signature.as_bytes()),
+        })
+    }
+
+    fn signable_bytes(handle: &str, owner_id: &str, published_at: u64, card_json: &str) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(PUBLIC_CARD_DOMAIN);
+        for field in [handle, owner_id] {
+            data.extend_from_slice(&(field.len() as u64).to_le_bytes());
+            data.extend_from_slice(field.as_bytes());
+        }
+        data.extend_from_slice(&published_at.to_le_bytes());
+        data.extend_from_slice(&(card_json.len() as u64).to_le_bytes());
+        data.extend_from_slice(card_json.as_bytes());
+        data
+    }
+
+    /// Returns the handle.
+    pub fn handle(&self) -> &str {
+        &self.handle
+    }
+
+    /// Returns the owner's ID (hex-encoded public key).
+    pub fn owner_id(&self) -> &str {
+        &self.owner_id
+    }
+
+    /// Returns the publication timestamp.
+    pub fn published_at(&self) -> u64 {
+        self.published_at
+    }
+
+    /// Decodes the published card.
+    ///
+    /// Does not check the signature; see `verify`.
+    pub fn card(&self) -> Result<ContactCard, ContactCardError> {
+        serde_json::from_str(&self.card_json)
+            .map_err(|e| ContactCardError::Serialization(e.to_string()))
+    }
+
+    /// Verifies the owner's signature and the handle.
+    pub fn verify(&self) -> bool {
+        is_valid_public_handle(&self.handle)
+            && verify_hex(
+                &self.owner_id,
+                &self.signature,
+                &Self::signable_bytes(
+                    &self.handle,
+                    &self.owner_id,
+                    self.published_at,
+                    &self.card_json,
+                ),
+            )
+    }
+
+    /// Serializes the publication to JSON bytes.
+    pub fn to_json(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("Serialization should not fail")
+    }
+
+    /// Deserializes a publication from JSON bytes.
+    pub fn from_json(bytes: &[u8]) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+
+    /// Replaces the card JSON for testing purposes (to test tamper detection).
+    #[doc(hidden)]
+    pub fn set_card_json_for_testing(&mut self, card_json: &str) {
+        self.card_json = card_json.to_string();
+    }
+}
+
+/// An owner's signed request to take down the card under a handle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicCardRemoval {
+    /// Handle to take down.
+    handle: String,
+    /// Owner's Ed25519 public key (hex-encoded).
+    owner_id: String,
+    /// Unix timestamp of the request.
+    removed_at: u64,
+    /// Signature over all of the above by the owner (hex-encoded).
+    signature: String,
+}
+
+impl PublicCardRemoval {
+    /// Signs a removal of the card under `handle`.
+    pub fn create(identity: &Identity, handle: &str, removed_at: u64) -> Self {
+        let owner_id = hex::encode(identity.signing_public_key());
+        let signature = identity.sign(&Self::signable_bytes(handle, &owner_id, removed_at));
+        PublicCardRemoval {
+            handle: handle.to_string(),
+            owner_id,
+            removed_at,
+            signature: hex::encode(signature.as_bytes()),
+        }
+    }
+
+    fn signable_bytes(handle: &str, owner_id: &str, removed_at: u64) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(PUBLIC_CARD_REMOVAL_DOMAIN);
+        for field in [handle, owner_id] {
+            data.extend_from_slice(&(field.len() as u64).to_le_bytes());
+            data.extend_from_slice(field.as_bytes());
+        }
+        data.extend_from_slice(&removed_at.to_le_bytes());
+        data
+    }
+
+    /// Returns the handle.
+    pub fn handle(&self) -> &str {
+        &self.handle
+    }
+
+    /// Returns the owner's ID (hex-encoded public key).
+    pub fn owner_id(&self) -> &str {
+        &self.owner_id
+    }
+
+    /// Returns the request timestamp.
+    pub fn removed_at(&self) -> u64 {
+        self.removed_at
+    }
+
+    /// Verifies the owner's signature.
+    pub fn verify(&self) -> bool {
+        verify_hex(
+            &self.owner_id,
+            &self.signature,
+            &Self::signable_bytes(&self.handle, &self.owner_id, self.removed_at),
+        )
+    }
+
+    /// Serializes the removal to JSON bytes.
+    pub fn to_json(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("Serialization should not fail")
+    }
+
+    /// Deserializes a removal from JSON bytes.
+    pub fn from_json(bytes: &[u8]) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+}
+
+fn verify_hex(owner_id: &str, signature: &str, message: &[u8]) -> bool {
+    let (Ok(owner), Ok(signature)) = (hex::decode(owner_id), hex::decode(signature)) else {
+        return false;
+    };
+    let (Ok(owner), Ok(signature)) = (
+        <[u8; 32]>::try_from(owner.as_slice()),
+        <[u8; 64]>::try_from(signature.as_slice()),
+    ) else {
+        return false;
+    };
+    PublicKey::from_bytes(owner).verify(message, &Signature::from_bytes(signature))
+}
@@ -26,6 +26,15 @@ pub enum ContactAction {
     CopyToClipboard,
 }
 
+/// A field value checked before it is copied or shown as a link.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SafeFieldValue {
+    /// The value, defanged when unsafe so pasting it does not open anything.
+    pub value: String,
+    /// Whether the value can be used as-is.
+    pub is_safe: bool,
+}
+
 /// Allowed URI schemes (security whitelist).
 const ALLOWED_SCHEMES: &[&str] = &["tel", "mailto", "sms", "https", "http", "geo"];
 
@@ -93,6 +102,26 @@ fn extract_scheme(uri: &str) -> Option<&str> {
     uri.split(':').next()
 }
 
+/// Whether a character is invisible or reorders text, which lets a value
+/// display differently from what it contains.
+fn is_deceptive_char(c: char) -> bool {
+    c.is_control()
+        || matches!(
+            c,
+            '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}' | '\u{FEFF}'
+        )
+}
+
+/// Makes a value inert: deceptive characters become visible and the scheme
+/// separator is broken, e.g. `javascript[:]alert(1)`.
+fn defang(value: &str) -> String {
+    let visible: String = value
+        .chars()
+        .map(|c| if is_deceptive_char(c) { '\u{FFFD}' } else { c })
+        .collect();
+    visible.replacen(':', "[:]", 1)
+}
+
 /// Social network URL templates.
 /// Maps lowercase label names to their profile URL templates.
 fn social_url_template(label: &str) -> Option<&'static str> {
@@ -165,6 +194,7 @@ impl ContactField {
             FieldType::Social => self.social_to_uri(value),
             FieldType::Address => Some(format!("geo:0,0?q={}", url_encode(value))),
             FieldType::Custom => None, // No heuristic match, no URI
+            FieldType::RichText => None,
         }
     }
 
@@ -217,6 +247,47 @@ impl ContactField {
         Some(template.replace("{username}", &username))
     }
 
+    /// Returns the value to copy or display, checked for deceptive links.
+    ///
+    /// Website and social values (and custom values that look like a URL)
+    /// are unsafe if they carry a scheme `is_safe_url` rejects, or contain
+    /// invisible or text-reordering characters. Unsafe values come back
+    /// defanged so the UI can show them with a warning. Other field types
+    /// are returned unchanged.
+    pub fn safe_display_value(&self) -> SafeFieldValue {
+        let value = self.value().trim();
+
+        let effective_type = if self.field_type() == FieldType::Custom {
+            self.detect_value_type().unwrap_or(FieldType::Custom)
+        } else {
+            self.field_type()
+        };
+
+        let is_safe = match effective_type {
+            FieldType::Website => {
+                !value.chars().any(is_deceptive_char)
+                    && self
+                        .website_to_uri(value)
+                        .is_some_and(|uri| is_safe_url(&uri))
+            }
+            // Plain handles are fine; anything with a scheme must be a safe URL
+            FieldType::Social => {
+                !value.chars().any(is_deceptive_char)
+                    && (!value.contains(':') || is_safe_url(value))
+            }
+            _ => true,
+        };
+
+        SafeFieldValue {
+            value: if is_safe {
+                self.value().to_string()
+            } else {
+                defang(value)
+            },
+            is_safe,
+        }
+    }
+
     /// Get the primary action for this field.
     pub fn to_action(&self) -> ContactAction {
         let value = self.value().trim();
@@ -243,7 +314,7 @@ impl ContactField {
                 }
             }
             FieldType::Address => ContactAction::OpenMap(value.to_string()),
-            FieldType::Custom => ContactAction::CopyToClipboard,
+            FieldType::Custom | FieldType::RichText => ContactAction::CopyToClipboard,
         }
     }
 
@@ -20,4 +20,6 @@ pub enum ValidationError {
     ValueTooLong { max: usize },
     #[error("Value cannot be empty")]
     EmptyValue,
+    #[error("Value contains unsafe markup or links")]
+    UnsafeContent,
 }
@@ -53,6 +53,13 @@ pub fn export_vcard(card: &ContactCard) -> String {
                     escape_vcard(field.value())
                 ));
             }
+            FieldType::RichText => {
+                lines.push(format!(
+                    "NOTE;TYPE={}:{}",
+                    escape_vcard(field.label()),
+                    escape_vcard(&field.render_plain())
+                ));
+            }
         }
     }
 
@@ -0,0 +1,309 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Markdown Content
+//!
+//! Sanitizes user-supplied Markdown, such as the value of a
+//! `FieldType::RichText` card field, and renders it as plain text for
+//! previews. Raw HTML is removed entirely (including the contents of
+//! `<script>` and `<style>` elements), and links or images whose target
+//! fails [`is_safe_url`] are reduced to their text.
+
+use crate::contact_card::is_safe_url;
+
+/// HTML elements whose contents are dropped along with the tags.
+const DROPPED_ELEMENTS: &[&str] = &["script", "style"];
+
+/// How deeply links may be nested inside link text.
+///
+/// Deeper link text loses its brackets, which bounds the recursion.
+const MAX_LINK_NESTING: usize = 8;
+
+/// Sanitizes Markdown for storage in a rich text field.
+///
+/// Safe Markdown passes through unchanged, and sanitizing is idempotent:
+/// removing one tag or link can expose another, so passes repeat until the
+/// text stops changing. Every pass only removes text, so this terminates.
+///
+/// # Examples
+///
+/// ```
+/// use vauchi_core::content::sanitize_markdown;
+///
+/// assert_eq!(sanitize_markdown("**Hi** [me](https://example.com)"), "**Hi** [me](https://example.com)");
+/// assert_eq!(sanitize_markdown("[click](javascript:alert(1))"), "click");
+/// assert_eq!(sanitize_markdown("hi<script>alert(1)</script>"), "hi");
+/// ```
+pub fn sanitize_markdown(input: &str) -> String {
+    let mut current = sanitize_once(input);
+    loop {
+        let next = sanitize_once(&current);
+        if next == current {
+            return current;
+        }
+        current = next;
+    }
+}
+
+/// Runs one pass of HTML, link and reference definition removal.
+fn sanitize_once(input: &str) -> String {
+    let without_html = strip_html(input);
+    let with_safe_links = rewrite_links(
+        &without_html,
+        &mut |_, text, url| {
+            if is_safe_url(url) {
+                None
+            } else {
+                Some(text.to_string())
+            }
+        },
+        0,
+    );
+
+    with_safe_links
+        .lines()
+        .filter(|line| match reference_definition_url(line) {
+            Some(url) => is_safe_url(url),
+            None => true,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders Markdown as plain text for previews.
+///
+/// The input is sanitized first; headings, quotes, list markers, emphasis
+/// and link syntax are removed, and blank lines are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use vauchi_core::content::render_plain;
+///
+/// assert_eq!(render_plain("# About\n**Rust** dev, see [site](https://example.com)"), "About\nRust dev, see site");
+/// ```
+pub fn render_plain(markdown: &str) -> String {
+    let sanitized = sanitize_markdown(markdown);
+    let without_links = rewrite_links(&sanitized, &mut |_, text, _| Some(text.to_string()), 0);
+
+    without_links
+        .lines()
+        .filter(|line| reference_definition_url(line).is_none())
+        .map(|line| strip_emphasis(strip_block_markers(line.trim())))
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Removes HTML tags, keeping Markdown autolinks (`<https://...>`) to safe URLs.
+fn strip_html(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        // `a < b` is text, not a tag
+        let starts_tag = after
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!');
+        if !starts_tag {
+            out.push('<');
+            rest = after;
+            continue;
+        }
+
+        let Some(end) = after.find('>') else {
+            // Unterminated tag: drop the bracket so it can't open one later
+            rest = after;
+            continue;
+        };
+        let tag = &after[..end];
+        rest = &after[end + 1..];
+
+        if is_autolink(tag) {
+            if is_safe_url(tag) {
+                out.push('<');
+                out.push_str(tag);
+                out.push('>');
+            }
+            continue;
+        }
+
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/' || c == '>')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        if DROPPED_ELEMENTS.contains(&name.as_str()) {
+            let closing = format!("</{}", name);
+            rest = match rest.to_ascii_lowercase().find(&closing) {
+                Some(pos) => match rest[pos..].find('>') {
+                    Some(close_end) => &rest[pos + close_end + 1..],
+                    None => "",
+                },
+                None => "",
+            };
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Returns true if the bracketed text is a Markdown autolink rather than HTML.
+fn is_autolink(tag: &str) -> bool {
+    !tag.contains(char::is_whitespace) && tag.contains(':')
+}
+
+/// Rewrites every inline link and image, including links nested in link text.
+///
+/// `replace(is_image, text, url)` receives the already rewritten link text
+/// and returns the replacement, or `None` to keep the link syntax.
+fn rewrite_links(
+    input: &str,
+    replace: &mut dyn FnMut(bool, &str, &str) -> Option<String>,
+    depth: usize,
+) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(open) = rest.find('[') {
+        let parsed = closing_bracket(&rest[open + 1..]).and_then(|close| {
+            let text = &rest[open + 1..open + 1 + close];
+            let after = &rest[open + 1 + close + 1..];
+            let target = after.strip_prefix('(')?;
+            let end = closing_paren(target)?;
+            let consumed = open + 1 + close + 1 + 1 + end + 1;
+            Some((text, &target[..end], consumed))
+        });
+
+        let Some((text, target, consumed)) = parsed else {
+            out.push_str(&rest[..open + 1]);
+            rest = &rest[open + 1..];
+            continue;
+        };
+
+        let is_image = open > 0 && rest.as_bytes()[open - 1] == b'!';
+        let prefix_end = if is_image { open - 1 } else { open };
+        out.push_str(&rest[..prefix_end]);
+
+        let text = if depth < MAX_LINK_NESTING {
+            rewrite_links(text, replace, depth + 1)
+        } else {
+            text.replace(['[', ']'], "")
+        };
+        // Drop an optional title: [text](url "title")
+        let url = target.split_whitespace().next().unwrap_or("");
+        match replace(is_image, &text, url) {
+            Some(replacement) => out.push_str(&replacement),
+            None => {
+                if is_image {
+                    out.push('!');
+                }
+                out.push('[');
+                out.push_str(&text);
+                out.push_str("](");
+                out.push_str(target);
+                out.push(')');
+            }
+        }
+        rest = &rest[consumed..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Finds the `]` closing link text, allowing balanced brackets inside.
+fn closing_bracket(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in text.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth == 0 => return Some(i),
+            ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Finds the `)` closing a link target, allowing balanced parentheses inside.
+fn closing_paren(target: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in target.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the URL of a reference-style link definition (`[id]: url`).
+fn reference_definition_url(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    let rest = line.strip_prefix('[')?;
+    let close = rest.find(']')?;
+    let url = rest[close + 1..].strip_prefix(':')?;
+    url.split_whitespace().next().or(Some(""))
+}
+
+/// Removes heading, quote and list markers from the start of a line.
+fn strip_block_markers(line: &str) -> &str {
+    let mut line = line;
+    loop {
+        let trimmed = line
+            .strip_prefix('#')
+            .map(|l| l.trim_start_matches('#'))
+            .or_else(|| line.strip_prefix('>'))
+            .or_else(|| line.strip_prefix("- "))
+            .or_else(|| line.strip_prefix("* "))
+            .or_else(|| line.strip_prefix("+ "))
+            .or_else(|| strip_ordered_marker(line));
+        match trimmed {
+            Some(next) => line = next.trim_start(),
+            None => return line,
+        }
+    }
+}
+
+/// Strips an ordered list marker such as `1. `.
+fn strip_ordered_marker(line: &str) -> Option<&str> {
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..].strip_prefix(". ")
+}
+
+/// Removes emphasis, strikethrough and code markers.
+///
+/// Underscores inside words (`snake_case`) are kept.
+fn strip_emphasis(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut out = String::with_capacity(line.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        match c {
+            '*' | '`' | '~' => {}
+            '_' => {
+                let prev_word = i > 0 && chars[i - 1].is_alphanumeric();
+                let next_word = chars.get(i + 1).is_some_and(|n| n.is_alphanumeric());
+                if prev_word && next_word {
+                    out.push(c);
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
@@ -13,12 +13,15 @@
 //!
 //! Content is verified using SHA-256 checksums and cached locally.
 //! Bundled content serves as fallback when remote content is unavailable.
+//!
+//! Also sanitizes user-supplied Markdown before it is stored or shown.
 
 mod cache;
 mod config;
 mod fetcher;
 mod integrity;
 mod manager;
+mod markdown;
 mod types;
 
 pub use cache::{CacheError, ContentCache};
@@ -26,6 +29,7 @@ pub use config::ContentConfig;
 pub use fetcher::{ContentFetcher, FetchError};
 pub use integrity::{compute_checksum, verify_checksum, IntegrityError};
 pub use manager::{ApplyResult, ContentError, ContentManager, LocaleStrings, NetworkEntry};
+pub use markdown::{render_plain, sanitize_markdown};
 pub use types::{
     ContentEntry, ContentIndex, ContentManifest, ContentType, FileEntry, LocalesEntry, UpdateStatus,
 };
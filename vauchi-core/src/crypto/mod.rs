@@ -5,6 +5,7 @@
 pub mod chain;
 pub mod encryption;
 pub mod kdf;
+pub mod padding;
 pub mod password_kdf;
 pub mod ratchet;
 pub mod signing;
@@ -12,6 +13,7 @@ pub mod signing;
 pub use chain::{ChainError, ChainKey, MessageKey};
 pub use encryption::{decrypt, encrypt, SymmetricKey};
 pub use kdf::{KDFError, HKDF};
+pub use padding::PaddingError;
 pub use password_kdf::{derive_key_argon2id, derive_key_pbkdf2, PasswordKdfError};
 pub use ratchet::{DoubleRatchetState, RatchetError, RatchetMessage};
 pub use signing::{PublicKey, Signature, SigningKeyPair};
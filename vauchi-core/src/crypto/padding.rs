@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Message Padding
+//!
+//! Pads plaintexts to power-of-two buckets before encryption so the relay
+//! cannot tell a one-field update from a full card by ciphertext size.
+//!
+//! Padded format: `PADDING_TAG (1 byte) || length (4 bytes BE) || plaintext || zeros`
+//!
+//! Unpadded payloads are JSON and never start with `PADDING_TAG`, so
+//! receivers can strip padding without knowing whether the sender used it.
+
+use thiserror::Error;
+
+/// Marker byte for padded payloads.
+const PADDING_TAG: u8 = 0x00;
+
+/// Size of the padding header (tag + length).
+const PADDING_HEADER_SIZE: usize = 5;
+
+/// Smallest bucket size in bytes.
+pub const MIN_PADDING_BUCKET: usize = 256;
+
+/// Padding error types.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PaddingError {
+    #[error("Padded payload is malformed")]
+    Malformed,
+}
+
+/// Pads a plaintext to the next power-of-two bucket (at least `MIN_PADDING_BUCKET`).
+pub fn pad(plaintext: &[u8]) -> Vec<u8> {
+    let content_len = PADDING_HEADER_SIZE + plaintext.len();
+    let bucket = content_len.next_power_of_two().max(MIN_PADDING_BUCKET);
+
+    let mut padded = Vec::with_capacity(bucket);
+    padded.push(PADDING_TAG);
+    padded.extend_from_slice(&(plaintext.len() as u32).to_be_bytes());
+    padded.extend_from_slice(plaintext);
+    padded.resize(bucket, 0);
+    padded
+}
+
+/// Returns true if the payload carries the padding header.
+pub fn is_padded(data: &[u8]) -> bool {
+    data.first() == Some(&PADDING_TAG)
+}
+
+/// Strips padding added by [`pad`].
+pub fn unpad(data: &[u8]) -> Result<Vec<u8>, PaddingError> {
+    if !is_padded(data) || data.len() < PADDING_HEADER_SIZE {
+        return Err(PaddingError::Malformed);
+    }
+
+    let len_bytes: [u8; 4] = data[1..PADDING_HEADER_SIZE]
+        .try_into()
+        .map_err(|_| PaddingError::Malformed)?;
+    let len = u32::from_be_bytes(len_bytes) as usize;
+
+    data.get(PADDING_HEADER_SIZE..PADDING_HEADER_SIZE + len)
+        .map(|plaintext| plaintext.to_vec())
+        .ok_or(PaddingError::Malformed)
+}
+
+/// Strips padding if present, otherwise returns the payload unchanged.
+pub fn strip_padding(data: Vec<u8>) -> Result<Vec<u8>, PaddingError> {
+    if is_padded(&data) {
+        unpad(&data)
+    } else {
+        Ok(data)
+    }
+}
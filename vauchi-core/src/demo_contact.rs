@@ -12,6 +12,8 @@
 use serde::{Deserialize, Serialize};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::i18n::{lookup_string, Locale};
+
 /// Demo contact display name
 pub const DEMO_CONTACT_NAME: &str = "Vauchi Tips";
 
@@ -34,6 +36,22 @@ pub struct DemoTip {
     pub content: String,
 }
 
+impl DemoTip {
+    /// Get a copy of this tip translated to the given locale.
+    ///
+    /// Falls back to the English text for any missing translation.
+    pub fn localized(&self, locale: Locale) -> DemoTip {
+        DemoTip {
+            id: self.id.clone(),
+            category: self.category,
+            title: lookup_string(locale, &format!("demo.{}.title", self.id))
+                .unwrap_or_else(|| self.title.clone()),
+            content: lookup_string(locale, &format!("demo.{}.content", self.id))
+                .unwrap_or_else(|| self.content.clone()),
+        }
+    }
+}
+
 /// Categories of demo tips
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DemoTipCategory {
@@ -113,6 +131,17 @@ pub fn get_demo_tips() -> Vec<DemoTip> {
     ]
 }
 
+/// Core actions a user can practice while the demo contact is active
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DemoAction {
+    /// Exchanged cards with someone
+    Exchanged,
+    /// Verified a contact's fingerprint
+    Verified,
+    /// Hid a field from a contact
+    HidField,
+}
+
 /// State of the demo contact
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct DemoContactState {
@@ -130,6 +159,15 @@ pub struct DemoContactState {
     pub shown_tip_ids: Vec<String>,
     /// Number of updates sent
     pub update_count: u32,
+    /// Whether the user has exchanged cards
+    #[serde(default)]
+    pub exchanged: bool,
+    /// Whether the user has verified a contact
+    #[serde(default)]
+    pub verified: bool,
+    /// Whether the user has hidden a field from a contact
+    #[serde(default)]
+    pub hid_a_field: bool,
 }
 
 impl DemoContactState {
@@ -148,6 +186,9 @@ impl DemoContactState {
             last_update_timestamp: now,
             shown_tip_ids: vec!["tip-share".to_string()],
             update_count: 0,
+            exchanged: false,
+            verified: false,
+            hid_a_field: false,
         }
     }
 
@@ -200,6 +241,30 @@ impl DemoContactState {
         Some(tip)
     }
 
+    /// Record that the user performed a core action.
+    ///
+    /// Auto-removes the demo contact once all core actions are done.
+    /// Returns true if this call removed the demo contact.
+    pub fn mark_action(&mut self, action: DemoAction) -> bool {
+        match action {
+            DemoAction::Exchanged => self.exchanged = true,
+            DemoAction::Verified => self.verified = true,
+            DemoAction::HidField => self.hid_a_field = true,
+        }
+
+        if self.is_active && self.has_completed_core_actions() {
+            self.auto_remove();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether the user has performed every core action
+    pub fn has_completed_core_actions(&self) -> bool {
+        self.exchanged && self.verified && self.hid_a_field
+    }
+
     /// Dismiss the demo contact
     pub fn dismiss(&mut self) {
         self.is_active = false;
@@ -242,6 +307,11 @@ pub fn generate_demo_contact_card(tip: &DemoTip) -> DemoContactCard {
     }
 }
 
+/// Generate a demo contact card with the tip translated to the given locale
+pub fn generate_demo_contact_card_for_locale(tip: &DemoTip, locale: Locale) -> DemoContactCard {
+    generate_demo_contact_card(&tip.localized(locale))
+}
+
 /// Demo contact card representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DemoContactCard {
@@ -331,6 +401,30 @@ mod tests {
         assert!(state.auto_removed);
     }
 
+    #[test]
+    fn test_demo_state_mark_actions_auto_removes() {
+        let mut state = DemoContactState::new_active();
+
+        assert!(!state.mark_action(DemoAction::Exchanged));
+        assert!(!state.mark_action(DemoAction::HidField));
+        assert!(state.is_active);
+
+        assert!(state.mark_action(DemoAction::Verified));
+        assert!(!state.is_active);
+        assert!(state.auto_removed);
+        assert!(state.has_completed_core_actions());
+    }
+
+    #[test]
+    fn test_demo_state_legacy_json_without_actions() {
+        let json = r#"{"is_active":true,"was_dismissed":false,"auto_removed":false,
+            "current_tip_index":0,"last_update_timestamp":0,"shown_tip_ids":[],"update_count":0}"#;
+        let state = DemoContactState::from_json(json).unwrap();
+
+        assert!(state.is_active);
+        assert!(!state.has_completed_core_actions());
+    }
+
     #[test]
     fn test_demo_state_restore() {
         let mut state = DemoContactState::new_active();
@@ -366,6 +460,33 @@ mod tests {
         assert!(!card.tip_content.is_empty());
     }
 
+    #[test]
+    fn test_generate_demo_card_for_locale() {
+        let tips = get_demo_tips();
+        let english = generate_demo_contact_card(&tips[0]);
+
+        let same = generate_demo_contact_card_for_locale(&tips[0], Locale::English);
+        assert_eq!(same.tip_title, english.tip_title);
+        assert_eq!(same.tip_content, english.tip_content);
+
+        let german = generate_demo_contact_card_for_locale(&tips[0], Locale::German);
+        assert_eq!(german.tip_title, "Teile deine Karte");
+        assert_eq!(german.tip_category, english.tip_category);
+    }
+
+    #[test]
+    fn test_every_tip_has_german_translation() {
+        for tip in get_demo_tips() {
+            let german = tip.localized(Locale::German);
+            assert_ne!(german.title, tip.title, "missing title for {}", tip.id);
+            assert_ne!(
+                german.content, tip.content,
+                "missing content for {}",
+                tip.id
+            );
+        }
+    }
+
     #[test]
     fn test_update_due_initial() {
         let state = DemoContactState::new_active();
@@ -13,6 +13,7 @@ use ring::rand::SystemRandom;
 use std::time::{SystemTime, UNIX_EPOCH};
 use zeroize::Zeroize;
 
+use super::qr::{render_qr_string, QrErrorCorrection};
 use super::ExchangeError;
 use crate::crypto::{decrypt, encrypt, PublicKey, Signature, SymmetricKey};
 use crate::identity::{DeviceInfo, DeviceRegistry, Identity};
@@ -107,7 +108,16 @@ impl DeviceLinkQR {
             .expect("Time went backwards")
             .as_secs();
 
-        now > self.timestamp + LINK_QR_EXPIRY_SECONDS
+        self.is_expired_at(now, 0)
+    }
+
+    /// Checks if the QR code has expired at `now`, allowing for up to
+    /// `skew_tolerance_secs` of clock difference between the two devices.
+    pub fn is_expired_at(&self, now: u64, skew_tolerance_secs: u64) -> bool {
+        now > self
+            .timestamp
+            .saturating_add(LINK_QR_EXPIRY_SECONDS)
+            .saturating_add(skew_tolerance_secs)
     }
 
     /// Verifies the signature on the QR code.
@@ -195,16 +205,8 @@ impl DeviceLinkQR {
 
     /// Generates an actual QR code image as a string representation.
     pub fn to_qr_image_string(&self) -> String {
-        use qrcode::QrCode;
-
-        let data = self.to_data_string();
-        let code = QrCode::new(&data).expect("QR generation should not fail");
-
-        code.render()
-            .light_color(' ')
-            .dark_color('█')
-            .quiet_zone(false)
-            .build()
+        render_qr_string(&self.to_data_string(), QrErrorCorrection::default())
+            .expect("QR generation should not fail")
     }
 }
 
@@ -11,10 +11,14 @@
 use serde::{Deserialize, Serialize};
 
 use super::{ExchangeError, X3DHKeyPair, X3DH};
-use crate::crypto::{decrypt, encrypt, SymmetricKey};
+use crate::contact_card::ContactCard;
+use crate::crypto::{decrypt, encrypt, PublicKey, Signature, SigningKeyPair, SymmetricKey};
+
+/// Domain separation prefix for exchange card signatures.
+const CARD_SIGNATURE_CONTEXT: &[u8] = b"vauchi-exchange-card-v1";
 
 /// Serde helper for 32-byte arrays (base64 encoded).
-mod bytes_array_32 {
+pub(super) mod bytes_array_32 {
     use base64::Engine;
     use serde::{Deserialize, Deserializer, Serializer};
 
@@ -71,6 +75,16 @@ struct ExchangePayload {
     exchange_key: [u8; 32],
     /// Sender's display name.
     display_name: String,
+    /// Sender's card as JSON, projected to the fields the recipient may see.
+    /// Kept as the exact bytes signed, and only parsed once verified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    card_bytes: Option<Vec<u8>>,
+    /// Ed25519 signature over `card_bytes` by `identity_key` (64 bytes).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    card_signature: Option<Vec<u8>>,
+    /// Whether the sender reads padded updates. Absent from older clients.
+    #[serde(default)]
+    accepts_padding: bool,
 }
 
 /// Decrypted exchange message payload.
@@ -82,6 +96,10 @@ pub struct DecryptedExchangePayload {
     pub exchange_key: [u8; 32],
     /// Sender's display name.
     pub display_name: String,
+    /// Sender's card with only the fields shared with us, if included.
+    pub card: Option<ContactCard>,
+    /// Whether the sender reads padded updates.
+    pub accepts_padding: bool,
 }
 
 impl EncryptedExchangeMessage {
@@ -104,17 +122,69 @@ impl EncryptedExchangeMessage {
         their_public: &[u8; 32],
         our_identity_key: &[u8; 32],
         our_display_name: &str,
+    ) -> Result<(Self, SymmetricKey), ExchangeError> {
+        // Create the payload to encrypt (includes our X3DH public key for responses)
+        Self::create_payload(
+            our_keys,
+            their_public,
+            ExchangePayload {
+                identity_key: *our_identity_key,
+                exchange_key: *our_keys.public_key(),
+                display_name: our_display_name.to_string(),
+                card_bytes: None,
+                card_signature: None,
+                accepts_padding: true,
+            },
+        )
+    }
+
+    /// Creates an encrypted exchange message that also carries a card.
+    ///
+    /// Used for exchange responses so the initiator receives the
+    /// responder's shared fields immediately. The card is signed with
+    /// `our_signing_keys`, whose public key is sent as the identity key,
+    /// so the recipient can tie the card content to that identity. The
+    /// caller is responsible for projecting `our_card` to the fields the
+    /// recipient may see.
+    pub fn create_with_card(
+        our_keys: &X3DHKeyPair,
+        their_public: &[u8; 32],
+        our_signing_keys: &SigningKeyPair,
+        our_display_name: &str,
+        our_card: Option<&ContactCard>,
+    ) -> Result<(Self, SymmetricKey), ExchangeError> {
+        let our_identity_key = *our_signing_keys.public_key().as_bytes();
+        let card_bytes = our_card
+            .map(|card| serde_json::to_vec(card).map_err(|_| ExchangeError::SerializationFailed))
+            .transpose()?;
+        let card_signature = card_bytes.as_ref().map(|bytes| {
+            let message = card_signing_message(&our_identity_key, bytes);
+            our_signing_keys.sign(&message).as_bytes().to_vec()
+        });
+
+        Self::create_payload(
+            our_keys,
+            their_public,
+            ExchangePayload {
+                identity_key: our_identity_key,
+                exchange_key: *our_keys.public_key(),
+                display_name: our_display_name.to_string(),
+                card_bytes,
+                card_signature,
+                accepts_padding: true,
+            },
+        )
+    }
+
+    /// Encrypts a payload for the recipient's X3DH public key.
+    fn create_payload(
+        our_keys: &X3DHKeyPair,
+        their_public: &[u8; 32],
+        payload: ExchangePayload,
     ) -> Result<(Self, SymmetricKey), ExchangeError> {
         // Perform X3DH key agreement to get shared secret and ephemeral key
         let (shared_secret, ephemeral_public_key) = X3DH::initiate(our_keys, their_public)?;
 
-        // Create the payload to encrypt (includes our X3DH public key for responses)
-        let payload = ExchangePayload {
-            identity_key: *our_identity_key,
-            exchange_key: *our_keys.public_key(),
-            display_name: our_display_name.to_string(),
-        };
-
         // Serialize payload to JSON
         let payload_bytes =
             serde_json::to_vec(&payload).map_err(|_| ExchangeError::SerializationFailed)?;
@@ -143,6 +213,11 @@ impl EncryptedExchangeMessage {
     /// # Returns
     /// A tuple of (DecryptedExchangePayload, shared_secret) containing the sender's
     /// identity key, exchange key, and display name.
+    ///
+    /// # Errors
+    /// A card without a signature fails with `UnsignedCard`, and one whose
+    /// signature does not match the sender's identity key fails with
+    /// `InvalidCardSignature`.
     pub fn decrypt(
         &self,
         our_keys: &X3DHKeyPair,
@@ -159,11 +234,26 @@ impl EncryptedExchangeMessage {
         let payload: ExchangePayload = serde_json::from_slice(&payload_bytes)
             .map_err(|_| ExchangeError::SerializationFailed)?;
 
+        let card = payload
+            .card_bytes
+            .map(|bytes| {
+                verify_card_signature(
+                    &payload.identity_key,
+                    &bytes,
+                    payload.card_signature.as_deref(),
+                )?;
+                serde_json::from_slice::<ContactCard>(&bytes)
+                    .map_err(|_| ExchangeError::SerializationFailed)
+            })
+            .transpose()?;
+
         Ok((
             DecryptedExchangePayload {
                 identity_key: payload.identity_key,
                 exchange_key: payload.exchange_key,
                 display_name: payload.display_name,
+                card,
+                accepts_padding: payload.accepts_padding,
             },
             shared_secret,
         ))
@@ -190,3 +280,36 @@ impl EncryptedExchangeMessage {
         serde_json::from_slice(bytes).map_err(|_| ExchangeError::SerializationFailed)
     }
 }
+
+/// Builds the message signed for an exchange card.
+///
+/// Format: `CARD_SIGNATURE_CONTEXT || identity_key || card JSON`, with the
+/// card JSON exactly as transmitted.
+fn card_signing_message(identity_key: &[u8; 32], card_bytes: &[u8]) -> Vec<u8> {
+    let mut message =
+        Vec::with_capacity(CARD_SIGNATURE_CONTEXT.len() + identity_key.len() + card_bytes.len());
+    message.extend_from_slice(CARD_SIGNATURE_CONTEXT);
+    message.extend_from_slice(identity_key);
+    message.extend_from_slice(card_bytes);
+    message
+}
+
+/// Verifies that transmitted card bytes were signed by the sender's
+/// identity key.
+fn verify_card_signature(
+    identity_key: &[u8; 32],
+    card_bytes: &[u8],
+    signature: Option<&[u8]>,
+) -> Result<(), ExchangeError> {
+    let signature = signature.ok_or(ExchangeError::UnsignedCard)?;
+    let signature: [u8; 64] = signature
+        .try_into()
+        .map_err(|_| ExchangeError::InvalidCardSignature)?;
+
+    let message = card_signing_message(identity_key, card_bytes);
+    if PublicKey::from_bytes(*identity_key).verify(&message, &Signature::from_bytes(signature)) {
+        Ok(())
+    } else {
+        Err(ExchangeError::InvalidCardSignature)
+    }
+}
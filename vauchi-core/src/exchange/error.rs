@@ -6,6 +6,8 @@
 
 use thiserror::Error;
 
+use super::qr::QrErrorCorrection;
+
 /// Errors that can occur during contact exchange.
 #[derive(Error, Debug, Clone)]
 pub enum ExchangeError {
@@ -66,6 +68,12 @@ pub enum ExchangeError {
     #[error("Identity mismatch: signing key does not match QR public key")]
     IdentityMismatch,
 
+    #[error("Exchange card is not signed")]
+    UnsignedCard,
+
+    #[error("Exchange card signature is invalid")]
+    InvalidCardSignature,
+
     #[error("Stale prekey, retrying")]
     StalePrekey,
 
@@ -81,9 +89,18 @@ pub enum ExchangeError {
     #[error("Insufficient storage space")]
     InsufficientStorage,
 
+    #[error("Exchange password does not match")]
+    PasswordMismatch,
+
     #[error("Numeric code mismatch")]
     NumericCodeMismatch,
 
     #[error("Fingerprint verification required")]
     FingerprintRequired,
+
+    #[error("QR data does not fit at error correction level {0:?}; try a lower level")]
+    QRDataTooLong(QrErrorCorrection),
+
+    #[error("Intro note too long: {0} bytes")]
+    IntroNoteTooLong(usize),
 }
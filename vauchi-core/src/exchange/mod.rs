@@ -5,7 +5,7 @@
 //! Contact Exchange Module
 //!
 //! Handles peer-to-peer contact exchange via QR codes, audio proximity,
-//! and X3DH key agreement.
+//! shared passwords, and X3DH key agreement.
 
 #[cfg(feature = "testing")]
 pub mod audio;
@@ -32,6 +32,11 @@ pub mod error;
 #[cfg(not(feature = "testing"))]
 mod error;
 
+#[cfg(feature = "testing")]
+pub mod password;
+#[cfg(not(feature = "testing"))]
+mod password;
+
 #[cfg(feature = "testing")]
 pub mod proximity;
 #[cfg(not(feature = "testing"))]
@@ -42,6 +47,11 @@ pub mod qr;
 #[cfg(not(feature = "testing"))]
 mod qr;
 
+#[cfg(feature = "testing")]
+pub mod rekey;
+#[cfg(not(feature = "testing"))]
+mod rekey;
+
 #[cfg(feature = "testing")]
 pub mod session;
 #[cfg(not(feature = "testing"))]
@@ -74,10 +84,17 @@ pub use nfc::{
     create_nfc_tag, parse_nfc_payload, Introduction, NfcError, NfcTagCreationResult, NfcTagMode,
     NfcTagPayload, ParsedNfcPayload, ProtectedNfcTagPayload,
 };
+pub use password::{
+    PasswordExchangeConfirmation, PasswordExchangeMessage, PasswordExchangeOffer, PasswordKey,
+    PendingConfirmation,
+};
 pub use proximity::{
     ManualConfirmationVerifier, MockProximityVerifier, ProximityError, ProximityVerifier,
 };
-pub use qr::{check_clock_drift, ExchangeQR};
+pub use qr::{
+    check_clock_drift, render_qr_string, ExchangeQR, QrErrorCorrection, MAX_INTRO_NOTE_LEN,
+};
+pub use rekey::{RekeyMessage, RekeyRequest, RekeyResponse, REKEY_REQUEST_MAX_AGE_SECS};
 pub use session::{
     DefaultPlatformCallbacks, DuplicateAction, ExchangeEvent, ExchangeMode,
     ExchangePlatformCallbacks, ExchangeRole, ExchangeSession, ExchangeState,
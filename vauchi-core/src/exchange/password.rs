@@ -0,0 +1,372 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Password-Protected Exchange
+//!
+//! Remote contact exchange bound to a short password both parties enter.
+//! Both sides derive a [`PasswordKey`] from the password with Argon2id,
+//! salted by the offer and bound to the initiator's keys. The X3DH secret
+//! of the exchange is mixed with it, and both sides confirm the exchange
+//! message with an HMAC under that key, so someone relaying the exchange
+//! without the password cannot substitute their own keys.
+//!
+//! This is not a PAKE: whoever sees both the offer and the answer can test
+//! passwords offline. Argon2id makes each guess expensive, but the password
+//! should not be trivially guessable.
+//!
+//! Flow:
+//! 1. The initiator derives its password key and shares a
+//!    [`PasswordExchangeOffer`] over any channel (chat, email).
+//! 2. The responder answers with a [`PasswordExchangeMessage`] sent via
+//!    the relay, carrying its exchange message encrypted under the
+//!    password key and its confirmation MAC.
+//! 3. The initiator checks the MAC before accepting the contact, and
+//!    answers with a [`PasswordExchangeConfirmation`] either way.
+//! 4. The responder keeps the contact only if that confirmation verifies
+//!    against its [`PendingConfirmation`].
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use ring::{digest, hmac};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+use super::encrypted_message::bytes_array_32;
+use super::{DecryptedExchangePayload, EncryptedExchangeMessage, ExchangeError, X3DHKeyPair};
+use crate::crypto::{derive_key_argon2id, SymmetricKey, HKDF};
+
+/// Magic bytes identifying a password exchange offer.
+const OFFER_MAGIC: &[u8; 4] = b"WBPX";
+
+/// Current offer format version.
+const OFFER_VERSION: u8 = 2;
+
+/// Length of the Argon2id salt carried by an offer.
+const SALT_LEN: usize = 16;
+
+/// MAGIC(4) + version(1) + identity_key(32) + exchange_key(32) + salt(16)
+const OFFER_LEN: usize = 4 + 1 + 32 + 32 + SALT_LEN;
+
+/// HKDF info for the confirmation keys.
+const CONFIRMATION_INFO: &[u8] = b"vauchi-password-confirmation-v2";
+
+/// HKDF info for binding the X3DH secret to the password key.
+const BINDING_INFO: &[u8] = b"vauchi-password-exchange-v2";
+
+/// HKDF info for the key encrypting the responder's exchange message.
+const SEALING_INFO: &[u8] = b"vauchi-password-sealing-v2";
+
+/// Keys both parties derive from the exchange password and an offer.
+///
+/// Both sides hold the same keys only if they entered the same password.
+pub struct PasswordKey {
+    binding: [u8; 32],
+    sealing: [u8; 32],
+    initiator_mac: [u8; 32],
+    responder_mac: [u8; 32],
+}
+
+impl PasswordKey {
+    /// Derives the keys for `offer` from `password`.
+    ///
+    /// Runs Argon2id, so this takes a noticeable moment.
+    pub fn derive(offer: &PasswordExchangeOffer, password: &str) -> Result<Self, ExchangeError> {
+        let master = derive_key_argon2id(password.as_bytes(), &offer.salt)
+            .map_err(|_| ExchangeError::CryptoError)?;
+        let context = offer.context();
+
+        let (initiator_mac, responder_mac) =
+            HKDF::derive_key_pair(Some(&context), master.as_bytes(), CONFIRMATION_INFO);
+        Ok(PasswordKey {
+            binding: HKDF::derive_key(Some(&context), master.as_bytes(), BINDING_INFO),
+            sealing: HKDF::derive_key(Some(&context), master.as_bytes(), SEALING_INFO),
+            initiator_mac,
+            responder_mac,
+        })
+    }
+
+    /// Mixes an X3DH secret with the password key.
+    fn bind(&self, x3dh_secret: &SymmetricKey) -> SymmetricKey {
+        SymmetricKey::from_bytes(HKDF::derive_key(
+            Some(&self.binding),
+            x3dh_secret.as_bytes(),
+            BINDING_INFO,
+        ))
+    }
+
+    /// Encrypts `data` so only a holder of the password key can read it.
+    fn seal(&self, data: &[u8]) -> Result<Vec<u8>, ExchangeError> {
+        crate::crypto::encrypt(&SymmetricKey::from_bytes(self.sealing), data)
+            .map_err(|_| ExchangeError::CryptoError)
+    }
+
+    /// Decrypts data sealed by the other party.
+    ///
+    /// Fails with `PasswordMismatch` if the passwords differed.
+    fn unseal(&self, data: &[u8]) -> Result<Vec<u8>, ExchangeError> {
+        crate::crypto::decrypt(&SymmetricKey::from_bytes(self.sealing), data)
+            .map_err(|_| ExchangeError::PasswordMismatch)
+    }
+}
+
+impl Drop for PasswordKey {
+    fn drop(&mut self) {
+        self.binding.zeroize();
+        self.sealing.zeroize();
+        self.initiator_mac.zeroize();
+        self.responder_mac.zeroize();
+    }
+}
+
+/// The initiator's half of a password exchange, shared out of band.
+#[derive(Debug, Clone)]
+pub struct PasswordExchangeOffer {
+    identity_key: [u8; 32],
+    exchange_key: [u8; 32],
+    salt: [u8; SALT_LEN],
+}
+
+impl PasswordExchangeOffer {
+    /// Creates an offer for our keys with a fresh random salt.
+    pub fn new(identity_key: &[u8; 32], exchange_key: &[u8; 32]) -> Self {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        PasswordExchangeOffer {
+            identity_key: *identity_key,
+            exchange_key: *exchange_key,
+            salt,
+        }
+    }
+
+    /// Returns the initiator's signing/identity public key.
+    pub fn identity_key(&self) -> &[u8; 32] {
+        &self.identity_key
+    }
+
+    /// Returns the initiator's X3DH public key.
+    pub fn exchange_key(&self) -> &[u8; 32] {
+        &self.exchange_key
+    }
+
+    /// Returns the key derivation context binding both of the initiator's
+    /// keys: `identity_key || exchange_key`.
+    pub fn context(&self) -> Vec<u8> {
+        let mut context = Vec::with_capacity(64);
+        context.extend_from_slice(&self.identity_key);
+        context.extend_from_slice(&self.exchange_key);
+        context
+    }
+
+    /// Encodes the offer for sharing.
+    pub fn to_data_string(&self) -> String {
+        // Format: base64(MAGIC || version || identity_key || exchange_key || salt)
+        let mut data = Vec::with_capacity(OFFER_LEN);
+        data.extend_from_slice(OFFER_MAGIC);
+        data.push(OFFER_VERSION);
+        data.extend_from_slice(&self.identity_key);
+        data.extend_from_slice(&self.exchange_key);
+        data.extend_from_slice(&self.salt);
+        BASE64.encode(&data)
+    }
+
+    /// Decodes an offer.
+    pub fn from_data_string(data: &str) -> Result<Self, ExchangeError> {
+        let bytes = BASE64
+            .decode(data.trim())
+            .map_err(|_| ExchangeError::InvalidQRFormat)?;
+        if bytes.len() < 5 || &bytes[0..4] != OFFER_MAGIC {
+            return Err(ExchangeError::InvalidQRFormat);
+        }
+        if bytes[4] != OFFER_VERSION {
+            return Err(ExchangeError::InvalidProtocolVersion);
+        }
+        if bytes.len() != OFFER_LEN {
+            return Err(ExchangeError::InvalidQRFormat);
+        }
+
+        let key_at = |offset: usize| -> [u8; 32] {
+            bytes[offset..offset + 32]
+                .try_into()
+                .expect("offer length checked")
+        };
+        Ok(PasswordExchangeOffer {
+            identity_key: key_at(5),
+            exchange_key: key_at(37),
+            salt: bytes[69..].try_into().expect("offer length checked"),
+        })
+    }
+}
+
+/// The responder's answer to a password exchange offer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordExchangeMessage {
+    /// Responder's confirmation MAC over `exchange`.
+    #[serde(with = "bytes_array_32")]
+    pub confirmation: [u8; 32],
+    /// Serialized [`EncryptedExchangeMessage`] for the initiator, encrypted
+    /// under the password key.
+    pub exchange: Vec<u8>,
+}
+
+impl PasswordExchangeMessage {
+    /// Answers an offer with our identity.
+    ///
+    /// Returns the message, the contact's shared secret, which binds the
+    /// X3DH secret to the password, and the check for the confirmation the
+    /// initiator must send back. The secret only matches the initiator's,
+    /// and the confirmation only verifies, if both used the same password.
+    pub fn create(
+        offer: &PasswordExchangeOffer,
+        password: &str,
+        our_keys: &X3DHKeyPair,
+        our_identity_key: &[u8; 32],
+        our_display_name: &str,
+    ) -> Result<(Self, SymmetricKey, PendingConfirmation), ExchangeError> {
+        let key = PasswordKey::derive(offer, password)?;
+
+        let (exchange, x3dh_secret) = EncryptedExchangeMessage::create(
+            our_keys,
+            &offer.exchange_key,
+            our_identity_key,
+            our_display_name,
+        )?;
+        let exchange = key.seal(&exchange.to_bytes())?;
+
+        let transcript = transcript(&exchange);
+        let pending = PendingConfirmation {
+            mac_key: key.initiator_mac,
+            transcript,
+        };
+        Ok((
+            PasswordExchangeMessage {
+                confirmation: sign_confirmation(&key.responder_mac, &transcript),
+                exchange,
+            },
+            key.bind(&x3dh_secret),
+            pending,
+        ))
+    }
+
+    /// Returns our confirmation for the responder.
+    ///
+    /// Send it back whether or not [`open`](Self::open) succeeds, so the
+    /// responder learns the outcome.
+    pub fn confirm(&self, key: &PasswordKey) -> PasswordExchangeConfirmation {
+        PasswordExchangeConfirmation {
+            mac: sign_confirmation(&key.initiator_mac, &transcript(&self.exchange)),
+        }
+    }
+
+    /// Verifies and decrypts the answer to our offer.
+    ///
+    /// # Errors
+    /// Fails with `PasswordMismatch` if the responder used a different
+    /// password or the message was tampered with.
+    pub fn open(
+        &self,
+        key: &PasswordKey,
+        our_keys: &X3DHKeyPair,
+    ) -> Result<(DecryptedExchangePayload, SymmetricKey), ExchangeError> {
+        verify_confirmation(
+            &key.responder_mac,
+            &transcript(&self.exchange),
+            &self.confirmation,
+        )?;
+
+        let exchange = key.unseal(&self.exchange)?;
+        let (payload, x3dh_secret) =
+            EncryptedExchangeMessage::from_bytes(&exchange)?.decrypt(our_keys)?;
+        Ok((payload, key.bind(&x3dh_secret)))
+    }
+
+    /// Serializes the message to bytes for wire transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap_or_default()
+    }
+
+    /// Deserializes a message from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ExchangeError> {
+        serde_json::from_slice(bytes).map_err(|_| ExchangeError::SerializationFailed)
+    }
+}
+
+/// The initiator's confirmation, sent back to the responder via the relay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PasswordExchangeConfirmation {
+    /// Initiator's confirmation MAC over the answer's exchange message.
+    #[serde(with = "bytes_array_32")]
+    pub mac: [u8; 32],
+}
+
+impl PasswordExchangeConfirmation {
+    /// Serializes the confirmation to bytes for wire transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap_or_default()
+    }
+
+    /// Deserializes a confirmation from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ExchangeError> {
+        serde_json::from_slice(bytes).map_err(|_| ExchangeError::SerializationFailed)
+    }
+}
+
+/// What the responder keeps to check the initiator's confirmation.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PendingConfirmation {
+    #[serde(with = "bytes_array_32")]
+    mac_key: [u8; 32],
+    #[serde(with = "bytes_array_32")]
+    transcript: [u8; 32],
+}
+
+impl PendingConfirmation {
+    /// Verifies the initiator's confirmation.
+    ///
+    /// # Errors
+    /// Fails with `PasswordMismatch` if the initiator used a different
+    /// password.
+    pub fn verify(&self, confirmation: &PasswordExchangeConfirmation) -> Result<(), ExchangeError> {
+        verify_confirmation(&self.mac_key, &self.transcript, &confirmation.mac)
+    }
+
+    /// Serializes the check for storage.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap_or_default()
+    }
+
+    /// Deserializes a stored check.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ExchangeError> {
+        serde_json::from_slice(bytes).map_err(|_| ExchangeError::SerializationFailed)
+    }
+}
+
+impl Drop for PendingConfirmation {
+    fn drop(&mut self) {
+        self.mac_key.zeroize();
+    }
+}
+
+/// SHA-256 of the sealed exchange message, which both MACs cover.
+fn transcript(exchange: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out.copy_from_slice(digest::digest(&digest::SHA256, exchange).as_ref());
+    out
+}
+
+/// HMAC-SHA256 of `data` under a confirmation key.
+fn sign_confirmation(mac_key: &[u8; 32], data: &[u8]) -> [u8; 32] {
+    let tag = hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, mac_key), data);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(tag.as_ref());
+    out
+}
+
+/// Checks an HMAC-SHA256 tag in constant time.
+fn verify_confirmation(mac_key: &[u8; 32], data: &[u8], tag: &[u8]) -> Result<(), ExchangeError> {
+    hmac::verify(&hmac::Key::new(hmac::HMAC_SHA256, mac_key), data, tag)
+        .map_err(|_| ExchangeError::PasswordMismatch)
+}
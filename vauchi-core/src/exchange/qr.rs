@@ -24,6 +24,59 @@ const QR_EXPIRY_SECONDS: u64 = 300;
 /// QR code magic bytes to identify Vauchi QR codes.
 const MAGIC: &[u8; 4] = b"WBEX";
 
+/// Maximum length of an intro note in bytes.
+pub const MAX_INTRO_NOTE_LEN: usize = 140;
+
+/// Length of the fixed part of the encoded QR data, before any intro note.
+/// MAGIC(4) + version(1) + pubkey(32) + exchange_key(32) + token(32) + challenge(16) + timestamp(8) + sig(64)
+const FIXED_DATA_LEN: usize = 189;
+
+/// QR error-correction level.
+///
+/// Higher levels tolerate more damage (e.g. glare on glossy prints) but
+/// produce denser codes that hold less data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QrErrorCorrection {
+    /// Recovers about 7% of the code.
+    Low,
+    /// Recovers about 15% of the code.
+    #[default]
+    Medium,
+    /// Recovers about 25% of the code.
+    Quartile,
+    /// Recovers about 30% of the code.
+    High,
+}
+
+impl QrErrorCorrection {
+    fn ec_level(self) -> qrcode::EcLevel {
+        match self {
+            QrErrorCorrection::Low => qrcode::EcLevel::L,
+            QrErrorCorrection::Medium => qrcode::EcLevel::M,
+            QrErrorCorrection::Quartile => qrcode::EcLevel::Q,
+            QrErrorCorrection::High => qrcode::EcLevel::H,
+        }
+    }
+}
+
+/// Renders data as a QR code string using block characters.
+///
+/// Returns `ExchangeError::QRDataTooLong` if the data does not fit at the
+/// requested error-correction level.
+pub fn render_qr_string(data: &str, level: QrErrorCorrection) -> Result<String, ExchangeError> {
+    use qrcode::QrCode;
+
+    let code = QrCode::with_error_correction_level(data, level.ec_level())
+        .map_err(|_| ExchangeError::QRDataTooLong(level))?;
+
+    Ok(code
+        .render()
+        .light_color(' ')
+        .dark_color('█')
+        .quiet_zone(false)
+        .build())
+}
+
 /// Exchange QR code data structure.
 ///
 /// Contains all information needed to initiate a contact exchange.
@@ -41,6 +94,8 @@ pub struct ExchangeQR {
     audio_challenge: [u8; 16],
     /// Unix timestamp when QR was generated
     timestamp: u64,
+    /// Optional context note from the initiator ("Met at the Rust meetup")
+    intro_note: Option<String>,
     /// Signature over the above fields
     signature: [u8; 64],
 }
@@ -56,6 +111,26 @@ impl ExchangeQR {
         Self::generate_with_timestamp(identity, timestamp)
     }
 
+    /// Generates a new exchange QR code carrying an intro note.
+    ///
+    /// The note is signed along with the rest of the QR and stored by the
+    /// scanning side as its initial personal note for the new contact.
+    /// Returns `ExchangeError::IntroNoteTooLong` if the note exceeds
+    /// [`MAX_INTRO_NOTE_LEN`] bytes.
+    pub fn generate_with_note(identity: &Identity, note: &str) -> Result<Self, ExchangeError> {
+        let note = note.trim();
+        if note.len() > MAX_INTRO_NOTE_LEN {
+            return Err(ExchangeError::IntroNoteTooLong(note.len()));
+        }
+
+        let mut qr = Self::generate(identity);
+        if !note.is_empty() {
+            qr.intro_note = Some(note.to_string());
+            qr.signature = *identity.sign(&qr.signed_message()).as_bytes();
+        }
+        Ok(qr)
+    }
+
     /// Generates a QR code with a specific timestamp (for testing).
     pub fn generate_with_timestamp(identity: &Identity, timestamp: u64) -> Self {
         use ring::rand::SystemRandom;
@@ -80,27 +155,20 @@ impl ExchangeQR {
             .try_into()
             .expect("Exchange key should be 32 bytes");
 
-        // Create message to sign (all fields except signature)
-        let mut message = Vec::new();
-        message.push(PROTOCOL_VERSION);
-        message.extend_from_slice(&public_key);
-        message.extend_from_slice(&exchange_key);
-        message.extend_from_slice(&exchange_token);
-        message.extend_from_slice(&audio_challenge);
-        message.extend_from_slice(&timestamp.to_be_bytes());
-
-        // Sign the message
-        let signature = identity.sign(&message);
-
-        ExchangeQR {
+        let mut qr = ExchangeQR {
             version: PROTOCOL_VERSION,
             public_key,
             exchange_key,
             exchange_token,
             audio_challenge,
             timestamp,
-            signature: *signature.as_bytes(),
-        }
+            intro_note: None,
+            signature: [0u8; 64],
+        };
+
+        // Sign all fields except the signature
+        qr.signature = *identity.sign(&qr.signed_message()).as_bytes();
+        qr
     }
 
     /// Returns the Ed25519 signing public key (for identity verification).
@@ -128,6 +196,11 @@ impl ExchangeQR {
         self.timestamp
     }
 
+    /// Returns the initiator's intro note, if any.
+    pub fn intro_note(&self) -> Option<&str> {
+        self.intro_note.as_deref()
+    }
+
     /// Checks if the QR code has expired.
     pub fn is_expired(&self) -> bool {
         let now = SystemTime::now()
@@ -135,12 +208,32 @@ impl ExchangeQR {
             .expect("Time went backwards")
             .as_secs();
 
-        now > self.timestamp + QR_EXPIRY_SECONDS
+        self.is_expired_at(now, 0)
+    }
+
+    /// Checks if the QR code has expired at `now`, allowing for up to
+    /// `skew_tolerance_secs` of clock difference between the two devices.
+    pub fn is_expired_at(&self, now: u64, skew_tolerance_secs: u64) -> bool {
+        now > self
+            .timestamp
+            .saturating_add(QR_EXPIRY_SECONDS)
+            .saturating_add(skew_tolerance_secs)
     }
 
     /// Verifies the signature on the QR code.
     pub fn verify_signature(&self) -> bool {
-        // Reconstruct the signed message
+        // Create public key for verification
+        let public_key = PublicKey::from_bytes(self.public_key);
+        let signature = Signature::from_bytes(self.signature);
+
+        public_key.verify(&self.signed_message(), &signature)
+    }
+
+    /// Builds the message covered by the signature.
+    ///
+    /// The intro note is only appended when present, so QR codes without a
+    /// note sign the same bytes as before notes existed.
+    fn signed_message(&self) -> Vec<u8> {
         let mut message = Vec::new();
         message.push(self.version);
         message.extend_from_slice(&self.public_key);
@@ -148,17 +241,16 @@ impl ExchangeQR {
         message.extend_from_slice(&self.exchange_token);
         message.extend_from_slice(&self.audio_challenge);
         message.extend_from_slice(&self.timestamp.to_be_bytes());
-
-        // Create public key for verification
-        let public_key = PublicKey::from_bytes(self.public_key);
-        let signature = Signature::from_bytes(self.signature);
-
-        public_key.verify(&message, &signature)
+        if let Some(note) = &self.intro_note {
+            message.push(note.len() as u8);
+            message.extend_from_slice(note.as_bytes());
+        }
+        message
     }
 
     /// Encodes the QR data to a string for embedding in QR code.
     pub fn to_data_string(&self) -> String {
-        // Format: base64(MAGIC || version || public_key || exchange_key || token || challenge || timestamp || signature)
+        // Format: base64(MAGIC || version || public_key || exchange_key || token || challenge || timestamp || signature [|| note_len || note])
         let mut data = Vec::new();
         data.extend_from_slice(MAGIC);
         data.push(self.version);
@@ -168,6 +260,10 @@ impl ExchangeQR {
         data.extend_from_slice(&self.audio_challenge);
         data.extend_from_slice(&self.timestamp.to_be_bytes());
         data.extend_from_slice(&self.signature);
+        if let Some(note) = &self.intro_note {
+            data.push(note.len() as u8);
+            data.extend_from_slice(note.as_bytes());
+        }
 
         BASE64.encode(&data)
     }
@@ -179,8 +275,7 @@ impl ExchangeQR {
             .map_err(|_| ExchangeError::InvalidQRFormat)?;
 
         // Check minimum length for v2 format
-        // MAGIC(4) + version(1) + pubkey(32) + exchange_key(32) + token(32) + challenge(16) + timestamp(8) + sig(64) = 189
-        if bytes.len() < 189 {
+        if bytes.len() < FIXED_DATA_LEN {
             return Err(ExchangeError::InvalidQRFormat);
         }
 
@@ -216,10 +311,12 @@ impl ExchangeQR {
                 .map_err(|_| ExchangeError::InvalidQRFormat)?,
         );
 
-        let signature: [u8; 64] = bytes[125..189]
+        let signature: [u8; 64] = bytes[125..FIXED_DATA_LEN]
             .try_into()
             .map_err(|_| ExchangeError::InvalidQRFormat)?;
 
+        let intro_note = parse_intro_note(&bytes[FIXED_DATA_LEN..])?;
+
         let qr = ExchangeQR {
             version,
             public_key,
@@ -227,6 +324,7 @@ impl ExchangeQR {
             exchange_token,
             audio_challenge,
             timestamp,
+            intro_note,
             signature,
         };
 
@@ -240,17 +338,32 @@ impl ExchangeQR {
 
     /// Generates an actual QR code image as a string representation.
     pub fn to_qr_image_string(&self) -> String {
-        use qrcode::QrCode;
+        self.to_qr_image_string_with_level(QrErrorCorrection::default())
+            .expect("QR generation should not fail")
+    }
 
-        let data = self.to_data_string();
-        let code = QrCode::new(&data).expect("QR generation should not fail");
+    /// Generates the QR code image string at the given error-correction level.
+    pub fn to_qr_image_string_with_level(
+        &self,
+        level: QrErrorCorrection,
+    ) -> Result<String, ExchangeError> {
+        render_qr_string(&self.to_data_string(), level)
+    }
+}
 
-        code.render()
-            .light_color(' ')
-            .dark_color('█')
-            .quiet_zone(false)
-            .build()
+/// Parses the optional `note_len || note` trailer after the signature.
+fn parse_intro_note(trailer: &[u8]) -> Result<Option<String>, ExchangeError> {
+    let Some((&len, note)) = trailer.split_first() else {
+        return Ok(None);
+    };
+    let len = len as usize;
+    if len == 0 || len > MAX_INTRO_NOTE_LEN || note.len() != len {
+        return Err(ExchangeError::InvalidQRFormat);
     }
+
+    String::from_utf8(note.to_vec())
+        .map(Some)
+        .map_err(|_| ExchangeError::InvalidQRFormat)
 }
 
 /// Maximum allowed clock drift in seconds between local time and QR timestamp.
@@ -0,0 +1,248 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Contact Re-key
+//!
+//! Replaces the shared secret with an existing contact by running a fresh
+//! X3DH over the relay. The requester sends its X3DH public key; the
+//! contact answers with an ephemeral key and both derive the new secret.
+//! Both messages are signed with the sender's identity key, and the
+//! response is bound to the request, so the relay cannot substitute keys.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::{ExchangeError, X3DH};
+use crate::crypto::{PublicKey, Signature, SymmetricKey};
+use crate::identity::Identity;
+
+/// Domain separation prefix for re-key request signatures.
+const REQUEST_CONTEXT: &[u8] = b"vauchi-rekey-request-v1";
+
+/// Domain separation prefix for re-key response signatures.
+const RESPONSE_CONTEXT: &[u8] = b"vauchi-rekey-response-v1";
+
+/// How long a re-key request can be answered (7 days).
+pub const REKEY_REQUEST_MAX_AGE_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// How far in the future a request may be dated, to absorb clock drift.
+const MAX_CLOCK_DRIFT_SECS: u64 = 5 * 60;
+
+/// A re-key message, sent in place of a ratchet message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "rekey", rename_all = "snake_case")]
+pub enum RekeyMessage {
+    /// Asks the contact to run a fresh X3DH.
+    Request(RekeyRequest),
+    /// Answers a request with an ephemeral key.
+    Response(RekeyResponse),
+}
+
+impl RekeyMessage {
+    /// Serializes the message to bytes for wire transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap_or_default()
+    }
+
+    /// Deserializes a message from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ExchangeError> {
+        serde_json::from_slice(bytes).map_err(|_| ExchangeError::SerializationFailed)
+    }
+}
+
+/// Request to replace the shared secret with a contact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RekeyRequest {
+    /// Requester's signing/identity public key.
+    identity_key: [u8; 32],
+    /// Requester's X3DH public key.
+    exchange_key: [u8; 32],
+    /// When the request was made (Unix seconds).
+    timestamp: u64,
+    /// Ed25519 signature by `identity_key`.
+    signature: Vec<u8>,
+}
+
+impl RekeyRequest {
+    /// Creates a signed request from our identity.
+    pub fn create(identity: &Identity) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+        Self::create_with_timestamp(identity, timestamp)
+    }
+
+    /// Creates a signed request with a specific timestamp.
+    /// Used for testing timestamp validation.
+    #[doc(hidden)]
+    pub fn create_with_timestamp(identity: &Identity, timestamp: u64) -> Self {
+        let identity_key = *identity.signing_public_key();
+        let exchange_key = *identity.x3dh_keypair().public_key();
+        let signature = identity.sign(&Self::signed_bytes(&identity_key, &exchange_key, timestamp));
+
+        RekeyRequest {
+            identity_key,
+            exchange_key,
+            timestamp,
+            signature: signature.as_bytes().to_vec(),
+        }
+    }
+
+    /// Returns the requester's X3DH public key.
+    pub fn exchange_key(&self) -> &[u8; 32] {
+        &self.exchange_key
+    }
+
+    /// Returns when the request was made.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Checks that the request comes from `sender` and can still be
+    /// answered at `now`.
+    ///
+    /// # Errors
+    /// - `IdentityMismatch` if the request is from someone else
+    /// - `InvalidSignature` if the signature does not verify
+    /// - `TokenExpired` if the request is older than
+    ///   [`REKEY_REQUEST_MAX_AGE_SECS`] or dated in the future
+    pub fn verify(&self, sender: &[u8; 32], now: u64) -> Result<(), ExchangeError> {
+        if &self.identity_key != sender {
+            return Err(ExchangeError::IdentityMismatch);
+        }
+        let message = Self::signed_bytes(&self.identity_key, &self.exchange_key, self.timestamp);
+        if !verify_signature(&self.identity_key, &message, &self.signature) {
+            return Err(ExchangeError::InvalidSignature);
+        }
+        if now.saturating_sub(self.timestamp) > REKEY_REQUEST_MAX_AGE_SECS
+            || self.timestamp > now.saturating_add(MAX_CLOCK_DRIFT_SECS)
+        {
+            return Err(ExchangeError::TokenExpired);
+        }
+        Ok(())
+    }
+
+    /// Answers the request, returning the response and the new shared secret.
+    ///
+    /// The caller must [`verify`](Self::verify) the request first. The
+    /// answering side becomes the ratchet initiator, with the requester's
+    /// [`exchange_key`](Self::exchange_key) as the remote DH key.
+    pub fn accept(
+        &self,
+        identity: &Identity,
+    ) -> Result<(RekeyResponse, SymmetricKey), ExchangeError> {
+        let (shared_secret, ephemeral_key) =
+            X3DH::initiate(&identity.x3dh_keypair(), &self.exchange_key)?;
+
+        let identity_key = *identity.signing_public_key();
+        let signature = identity.sign(&RekeyResponse::signed_bytes(
+            &identity_key,
+            &self.exchange_key,
+            self.timestamp,
+            &ephemeral_key,
+        ));
+
+        Ok((
+            RekeyResponse {
+                identity_key,
+                ephemeral_key,
+                request_timestamp: self.timestamp,
+                signature: signature.as_bytes().to_vec(),
+            },
+            shared_secret,
+        ))
+    }
+
+    fn signed_bytes(identity_key: &[u8; 32], exchange_key: &[u8; 32], timestamp: u64) -> Vec<u8> {
+        let mut data = Vec::with_capacity(REQUEST_CONTEXT.len() + 72);
+        data.extend_from_slice(REQUEST_CONTEXT);
+        data.extend_from_slice(identity_key);
+        data.extend_from_slice(exchange_key);
+        data.extend_from_slice(&timestamp.to_be_bytes());
+        data
+    }
+}
+
+/// Answer to a [`RekeyRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RekeyResponse {
+    /// Responder's signing/identity public key.
+    identity_key: [u8; 32],
+    /// Ephemeral X3DH public key for the new secret.
+    ephemeral_key: [u8; 32],
+    /// Timestamp of the request being answered.
+    request_timestamp: u64,
+    /// Ed25519 signature by `identity_key`.
+    signature: Vec<u8>,
+}
+
+impl RekeyResponse {
+    /// Returns the timestamp of the request being answered.
+    pub fn request_timestamp(&self) -> u64 {
+        self.request_timestamp
+    }
+
+    /// Completes our re-key request, returning the new shared secret.
+    ///
+    /// `sender` is the contact the request went to, and `request_timestamp`
+    /// the timestamp of our outstanding request. The requesting side
+    /// becomes the ratchet responder, using our X3DH keypair.
+    ///
+    /// # Errors
+    /// - `IdentityMismatch` if the response is from someone else
+    /// - `InvalidState` if it answers a different request
+    /// - `InvalidSignature` if the signature does not verify
+    pub fn complete(
+        &self,
+        identity: &Identity,
+        sender: &[u8; 32],
+        request_timestamp: u64,
+    ) -> Result<SymmetricKey, ExchangeError> {
+        if &self.identity_key != sender {
+            return Err(ExchangeError::IdentityMismatch);
+        }
+        if self.request_timestamp != request_timestamp {
+            return Err(ExchangeError::InvalidState(
+                "re-key response does not match the pending request".into(),
+            ));
+        }
+
+        let our_x3dh = identity.x3dh_keypair();
+        let message = Self::signed_bytes(
+            &self.identity_key,
+            our_x3dh.public_key(),
+            self.request_timestamp,
+            &self.ephemeral_key,
+        );
+        if !verify_signature(&self.identity_key, &message, &self.signature) {
+            return Err(ExchangeError::InvalidSignature);
+        }
+
+        X3DH::respond(&our_x3dh, &self.identity_key, &self.ephemeral_key)
+    }
+
+    fn signed_bytes(
+        identity_key: &[u8; 32],
+        request_exchange_key: &[u8; 32],
+        request_timestamp: u64,
+        ephemeral_key: &[u8; 32],
+    ) -> Vec<u8> {
+        let mut data = Vec::with_capacity(RESPONSE_CONTEXT.len() + 104);
+        data.extend_from_slice(RESPONSE_CONTEXT);
+        data.extend_from_slice(identity_key);
+        data.extend_from_slice(request_exchange_key);
+        data.extend_from_slice(&request_timestamp.to_be_bytes());
+        data.extend_from_slice(ephemeral_key);
+        data
+    }
+}
+
+fn verify_signature(identity_key: &[u8; 32], message: &[u8], signature: &[u8]) -> bool {
+    let Ok(signature) = <[u8; 64]>::try_from(signature) else {
+        return false;
+    };
+    PublicKey::from_bytes(*identity_key).verify(message, &Signature::from_bytes(signature))
+}
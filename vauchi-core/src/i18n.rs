@@ -116,6 +116,14 @@ pub fn get_string(locale: Locale, key: &str) -> String {
     format!("Missing: {}", key)
 }
 
+/// Look up a string in a single locale, without falling back to English
+///
+/// Used by modules that keep their English source text next to the code
+/// (demo tips, aha moments) and only need translations from here.
+pub fn lookup_string(locale: Locale, key: &str) -> Option<String> {
+    get_strings_for_locale(locale).remove(key)
+}
+
 /// Get a localized string with argument interpolation
 pub fn get_string_with_args(locale: Locale, key: &str, args: &[(&str, &str)]) -> String {
     let mut result = get_string(locale, key);
@@ -212,6 +220,90 @@ fn english_strings() -> HashMap<String, String> {
         "Network error. Please check your connection.".into(),
     );
     m.insert("error.validation".into(), "Please check your input".into());
+    m.insert(
+        "error.not_set_up".into(),
+        "Create your identity to get started.".into(),
+    );
+    m.insert(
+        "error.already_set_up".into(),
+        "This device is already set up.".into(),
+    );
+    m.insert(
+        "error.contact_not_found".into(),
+        "This contact no longer exists.".into(),
+    );
+    m.insert(
+        "error.invalid_qr".into(),
+        "This isn't a Vauchi QR code.".into(),
+    );
+    m.insert(
+        "error.exchange_failed".into(),
+        "Couldn't exchange cards. Please try again.".into(),
+    );
+    m.insert(
+        "error.sync_failed".into(),
+        "Couldn't update your contacts. Please try again later.".into(),
+    );
+    m.insert(
+        "error.storage".into(),
+        "Couldn't save your data. Please try again.".into(),
+    );
+    m.insert(
+        "error.offline".into(),
+        "Couldn't connect. Check your internet.".into(),
+    );
+    m.insert(
+        "error.insecure_connection".into(),
+        "The connection isn't secure, so it was stopped.".into(),
+    );
+    m.insert(
+        "error.field_not_found".into(),
+        "This field no longer exists.".into(),
+    );
+    m.insert(
+        "error.already_verified".into(),
+        "You've already verified this field.".into(),
+    );
+    m.insert(
+        "error.claim_expired".into(),
+        "This recovery request has expired.".into(),
+    );
+    m.insert(
+        "error.clock".into(),
+        "Your device's date and time seem wrong. Please check them.".into(),
+    );
+    m.insert(
+        "error.rate_limited".into(),
+        "Too many attempts. Please wait a moment.".into(),
+    );
+    m.insert(
+        "error.too_many_labels".into(),
+        "You can have at most {max} labels.".into(),
+    );
+    m.insert(
+        "error.too_many_contacts".into(),
+        "You can have at most {max} contacts.".into(),
+    );
+    m.insert(
+        "error.duplicate_label".into(),
+        "A label with this name already exists.".into(),
+    );
+    m.insert(
+        "error.self_contact".into(),
+        "You can't add yourself as a contact.".into(),
+    );
+    m.insert(
+        "error.self_vouching".into(),
+        "You can't vouch for your own recovery.".into(),
+    );
+    m.insert(
+        "error.duplicate_voucher".into(),
+        "This contact has already vouched.".into(),
+    );
+    m.insert(
+        "error.voucher_mismatch".into(),
+        "This voucher is for a different claim.".into(),
+    );
 
     // Updates
     m.insert(
@@ -228,6 +320,12 @@ fn english_strings() -> HashMap<String, String> {
     m.insert("card.edit".into(), "Edit Card".into());
     m.insert("card.share".into(), "Share Card".into());
 
+    // Aha moments
+    m.insert(
+        "aha.contact_milestone.title".into(),
+        "{count} contacts!".into(),
+    );
+
     m
 }
 
@@ -312,6 +410,90 @@ fn german_strings() -> HashMap<String, String> {
         "error.validation".into(),
         "Bitte überprüfen Sie Ihre Eingabe".into(),
     );
+    m.insert(
+        "error.not_set_up".into(),
+        "Erstellen Sie Ihre Identität, um loszulegen.".into(),
+    );
+    m.insert(
+        "error.already_set_up".into(),
+        "Dieses Gerät ist bereits eingerichtet.".into(),
+    );
+    m.insert(
+        "error.contact_not_found".into(),
+        "Dieser Kontakt existiert nicht mehr.".into(),
+    );
+    m.insert(
+        "error.invalid_qr".into(),
+        "Dies ist kein Vauchi-QR-Code.".into(),
+    );
+    m.insert(
+        "error.exchange_failed".into(),
+        "Karten konnten nicht ausgetauscht werden. Bitte versuchen Sie es erneut.".into(),
+    );
+    m.insert(
+        "error.sync_failed".into(),
+        "Kontakte konnten nicht aktualisiert werden. Bitte versuchen Sie es später erneut.".into(),
+    );
+    m.insert(
+        "error.storage".into(),
+        "Daten konnten nicht gespeichert werden. Bitte versuchen Sie es erneut.".into(),
+    );
+    m.insert(
+        "error.offline".into(),
+        "Keine Verbindung. Bitte prüfen Sie Ihre Internetverbindung.".into(),
+    );
+    m.insert(
+        "error.insecure_connection".into(),
+        "Die Verbindung ist nicht sicher und wurde abgebrochen.".into(),
+    );
+    m.insert(
+        "error.field_not_found".into(),
+        "Dieses Feld existiert nicht mehr.".into(),
+    );
+    m.insert(
+        "error.already_verified".into(),
+        "Sie haben dieses Feld bereits bestätigt.".into(),
+    );
+    m.insert(
+        "error.claim_expired".into(),
+        "Diese Wiederherstellungsanfrage ist abgelaufen.".into(),
+    );
+    m.insert(
+        "error.clock".into(),
+        "Datum und Uhrzeit Ihres Geräts scheinen falsch zu sein. Bitte prüfen Sie sie.".into(),
+    );
+    m.insert(
+        "error.rate_limited".into(),
+        "Zu viele Versuche. Bitte warten Sie einen Moment.".into(),
+    );
+    m.insert(
+        "error.too_many_labels".into(),
+        "Sie können höchstens {max} Labels haben.".into(),
+    );
+    m.insert(
+        "error.too_many_contacts".into(),
+        "Sie können höchstens {max} Kontakte haben.".into(),
+    );
+    m.insert(
+        "error.duplicate_label".into(),
+        "Ein Label mit diesem Namen existiert bereits.".into(),
+    );
+    m.insert(
+        "error.self_contact".into(),
+        "Sie können sich nicht selbst als Kontakt hinzufügen.".into(),
+    );
+    m.insert(
+        "error.self_vouching".into(),
+        "Sie können nicht für Ihre eigene Wiederherstellung bürgen.".into(),
+    );
+    m.insert(
+        "error.duplicate_voucher".into(),
+        "Dieser Kontakt hat bereits gebürgt.".into(),
+    );
+    m.insert(
+        "error.voucher_mismatch".into(),
+        "Diese Bürgschaft gehört zu einer anderen Anfrage.".into(),
+    );
 
     // Updates
     m.insert(
@@ -328,6 +510,108 @@ fn german_strings() -> HashMap<String, String> {
     m.insert("card.edit".into(), "Karte bearbeiten".into());
     m.insert("card.share".into(), "Karte teilen".into());
 
+    // Aha moments
+    m.insert(
+        "aha.card_creation_complete.title".into(),
+        "Deine Karte ist bereit".into(),
+    );
+    m.insert(
+        "aha.card_creation_complete.message".into(),
+        "Wer deinen QR-Code scannt, hat immer deine aktuellen Daten.".into(),
+    );
+    m.insert("aha.first_edit.title".into(), "Gut bearbeitet!".into());
+    m.insert(
+        "aha.first_edit.message".into(),
+        "Wer deine Karte hätte, würde diese Änderung sofort sehen.".into(),
+    );
+    m.insert(
+        "aha.first_contact_added.title".into(),
+        "Erster Kontakt hinzugefügt!".into(),
+    );
+    m.insert(
+        "aha.first_contact_added.message".into(),
+        "Wenn dieser Kontakt seine Karte ändert, siehst du die Änderung automatisch.".into(),
+    );
+    m.insert("aha.first_contact_added.with_context".into(), "Du hast jetzt die Karte von {name}. Wenn sie sich ändert, siehst du die Änderung automatisch.".into());
+    m.insert(
+        "aha.first_update_received.title".into(),
+        "Du hast ein Update erhalten!".into(),
+    );
+    m.insert(
+        "aha.first_update_received.message".into(),
+        "Das ist der Clou: Sie ändern etwas, du siehst es sofort.".into(),
+    );
+    m.insert(
+        "aha.first_update_received.with_context".into(),
+        "{name} hat die Karte aktualisiert und du siehst es sofort!".into(),
+    );
+    m.insert(
+        "aha.first_outbound_delivered.title".into(),
+        "Update zugestellt!".into(),
+    );
+    m.insert(
+        "aha.first_outbound_delivered.message".into(),
+        "Deine Kontakte haben jetzt deine aktuellen Daten.".into(),
+    );
+    m.insert(
+        "aha.first_outbound_delivered.with_context".into(),
+        "Dein Update wurde an {count} Kontakte zugestellt.".into(),
+    );
+    m.insert(
+        "aha.contact_milestone.title".into(),
+        "{count} Kontakte!".into(),
+    );
+    m.insert(
+        "aha.contact_milestone.message".into(),
+        "Dein Netzwerk wächst. Alle erhalten deine Updates automatisch.".into(),
+    );
+    m.insert(
+        "aha.first_verified_contact.title".into(),
+        "Erster verifizierter Kontakt!".into(),
+    );
+    m.insert("aha.first_verified_contact.message".into(), "Du hast bestätigt, dass es wirklich diese Person ist. Verifizierte Kontakte sind in deiner Liste markiert.".into());
+    m.insert(
+        "aha.card_complete.title".into(),
+        "Deine Karte ist vollständig!".into(),
+    );
+    m.insert(
+        "aha.card_complete.message".into(),
+        "Alle, mit denen du tauschst, erhalten deinen Namen, dein Foto, deine Kontaktdaten und Links.".into(),
+    );
+
+    // Demo tips
+    m.insert("demo.tip-share.title".into(), "Teile deine Karte".into());
+    m.insert("demo.tip-share.content".into(), "Öffne den Tab Austausch und zeige deinen QR-Code. Die andere Person scannt ihn und ihr seid verbunden!".into());
+    m.insert(
+        "demo.tip-privacy.title".into(),
+        "Deine Privatsphäre zählt".into(),
+    );
+    m.insert("demo.tip-privacy.content".into(), "Deine Daten landen nie auf unseren Servern. Alles ist Ende-zu-Ende verschlüsselt und nur Personen, mit denen du getauscht hast, sehen deine Daten.".into());
+    m.insert(
+        "demo.tip-updates.title".into(),
+        "Automatische Updates".into(),
+    );
+    m.insert("demo.tip-updates.content".into(), "Wenn du deine Karte änderst, sehen alle, die sie haben, die Änderung automatisch. Kein erneutes Senden nötig!".into());
+    m.insert(
+        "demo.tip-recovery.title".into(),
+        "Soziale Wiederherstellung".into(),
+    );
+    m.insert("demo.tip-recovery.content".into(), "Handy verloren? Deine Kontakte können für dich bürgen. Triff 3 oder mehr vertraute Kontakte persönlich und sie helfen, deine Identität wiederherzustellen.".into());
+    m.insert(
+        "demo.tip-visibility.title".into(),
+        "Bestimme, wer was sieht".into(),
+    );
+    m.insert("demo.tip-visibility.content".into(), "Mit Sichtbarkeits-Labels steuerst du, was verschiedene Gruppen sehen. Familie sieht vielleicht deine Adresse, Kollegen nur berufliche Daten.".into());
+    m.insert("demo.tip-qr.title".into(), "Austausch vor Ort".into());
+    m.insert("demo.tip-qr.content".into(), "Für einen Austausch musst du persönlich anwesend sein. Das verhindert Spam und stellt sicher, dass du nur mit Menschen verbunden bist, die du getroffen hast.".into());
+    m.insert("demo.tip-edit.title".into(), "Jederzeit bearbeiten".into());
+    m.insert("demo.tip-edit.content".into(), "Neue Telefonnummer? Aktualisiere sie einfach in deiner Karte. Alle, die deine Karte haben, sehen die neue Nummer automatisch.".into());
+    m.insert(
+        "demo.tip-multi-device.title".into(),
+        "Mehrere Geräte".into(),
+    );
+    m.insert("demo.tip-multi-device.content".into(), "Nutze Vauchi auf Handy und Tablet. Verknüpfe Geräte in den Einstellungen und deine Daten bleiben überall synchron.".into());
+
     m
 }
 
@@ -412,6 +696,90 @@ fn french_strings() -> HashMap<String, String> {
         "error.validation".into(),
         "Veuillez vérifier votre saisie".into(),
     );
+    m.insert(
+        "error.not_set_up".into(),
+        "Créez votre identité pour commencer.".into(),
+    );
+    m.insert(
+        "error.already_set_up".into(),
+        "Cet appareil est déjà configuré.".into(),
+    );
+    m.insert(
+        "error.contact_not_found".into(),
+        "Ce contact n'existe plus.".into(),
+    );
+    m.insert(
+        "error.invalid_qr".into(),
+        "Ce n'est pas un code QR Vauchi.".into(),
+    );
+    m.insert(
+        "error.exchange_failed".into(),
+        "Impossible d'échanger les cartes. Veuillez réessayer.".into(),
+    );
+    m.insert(
+        "error.sync_failed".into(),
+        "Impossible de mettre à jour vos contacts. Veuillez réessayer plus tard.".into(),
+    );
+    m.insert(
+        "error.storage".into(),
+        "Impossible d'enregistrer vos données. Veuillez réessayer.".into(),
+    );
+    m.insert(
+        "error.offline".into(),
+        "Connexion impossible. Vérifiez votre accès à Internet.".into(),
+    );
+    m.insert(
+        "error.insecure_connection".into(),
+        "La connexion n'est pas sécurisée, elle a été interrompue.".into(),
+    );
+    m.insert(
+        "error.field_not_found".into(),
+        "Ce champ n'existe plus.".into(),
+    );
+    m.insert(
+        "error.already_verified".into(),
+        "Vous avez déjà vérifié ce champ.".into(),
+    );
+    m.insert(
+        "error.claim_expired".into(),
+        "Cette demande de récupération a expiré.".into(),
+    );
+    m.insert(
+        "error.clock".into(),
+        "La date et l'heure de votre appareil semblent incorrectes. Veuillez les vérifier.".into(),
+    );
+    m.insert(
+        "error.rate_limited".into(),
+        "Trop de tentatives. Veuillez patienter un instant.".into(),
+    );
+    m.insert(
+        "error.too_many_labels".into(),
+        "Vous pouvez avoir au maximum {max} étiquettes.".into(),
+    );
+    m.insert(
+        "error.too_many_contacts".into(),
+        "Vous pouvez avoir au maximum {max} contacts.".into(),
+    );
+    m.insert(
+        "error.duplicate_label".into(),
+        "Une étiquette portant ce nom existe déjà.".into(),
+    );
+    m.insert(
+        "error.self_contact".into(),
+        "Vous ne pouvez pas vous ajouter comme contact.".into(),
+    );
+    m.insert(
+        "error.self_vouching".into(),
+        "Vous ne pouvez pas vous porter garant de votre propre récupération.".into(),
+    );
+    m.insert(
+        "error.duplicate_voucher".into(),
+        "Ce contact s'est déjà porté garant.".into(),
+    );
+    m.insert(
+        "error.voucher_mismatch".into(),
+        "Cette garantie concerne une autre demande.".into(),
+    );
 
     // Updates
     m.insert(
@@ -428,6 +796,12 @@ fn french_strings() -> HashMap<String, String> {
     m.insert("card.edit".into(), "Modifier la carte".into());
     m.insert("card.share".into(), "Partager la carte".into());
 
+    // Aha moments
+    m.insert(
+        "aha.contact_milestone.title".into(),
+        "{count} contacts !".into(),
+    );
+
     m
 }
 
@@ -512,6 +886,90 @@ fn spanish_strings() -> HashMap<String, String> {
         "error.validation".into(),
         "Por favor, revisa tu entrada".into(),
     );
+    m.insert(
+        "error.not_set_up".into(),
+        "Crea tu identidad para empezar.".into(),
+    );
+    m.insert(
+        "error.already_set_up".into(),
+        "Este dispositivo ya está configurado.".into(),
+    );
+    m.insert(
+        "error.contact_not_found".into(),
+        "Este contacto ya no existe.".into(),
+    );
+    m.insert(
+        "error.invalid_qr".into(),
+        "Este no es un código QR de Vauchi.".into(),
+    );
+    m.insert(
+        "error.exchange_failed".into(),
+        "No se pudieron intercambiar las tarjetas. Inténtalo de nuevo.".into(),
+    );
+    m.insert(
+        "error.sync_failed".into(),
+        "No se pudieron actualizar tus contactos. Inténtalo más tarde.".into(),
+    );
+    m.insert(
+        "error.storage".into(),
+        "No se pudieron guardar tus datos. Inténtalo de nuevo.".into(),
+    );
+    m.insert(
+        "error.offline".into(),
+        "No se pudo conectar. Comprueba tu conexión a internet.".into(),
+    );
+    m.insert(
+        "error.insecure_connection".into(),
+        "La conexión no es segura, así que se detuvo.".into(),
+    );
+    m.insert(
+        "error.field_not_found".into(),
+        "Este campo ya no existe.".into(),
+    );
+    m.insert(
+        "error.already_verified".into(),
+        "Ya has verificado este campo.".into(),
+    );
+    m.insert(
+        "error.claim_expired".into(),
+        "Esta solicitud de recuperación ha caducado.".into(),
+    );
+    m.insert(
+        "error.clock".into(),
+        "La fecha y la hora de tu dispositivo parecen incorrectas. Compruébalas.".into(),
+    );
+    m.insert(
+        "error.rate_limited".into(),
+        "Demasiados intentos. Espera un momento.".into(),
+    );
+    m.insert(
+        "error.too_many_labels".into(),
+        "Puedes tener como máximo {max} etiquetas.".into(),
+    );
+    m.insert(
+        "error.too_many_contacts".into(),
+        "Puedes tener como máximo {max} contactos.".into(),
+    );
+    m.insert(
+        "error.duplicate_label".into(),
+        "Ya existe una etiqueta con este nombre.".into(),
+    );
+    m.insert(
+        "error.self_contact".into(),
+        "No puedes añadirte a ti mismo como contacto.".into(),
+    );
+    m.insert(
+        "error.self_vouching".into(),
+        "No puedes avalar tu propia recuperación.".into(),
+    );
+    m.insert(
+        "error.duplicate_voucher".into(),
+        "Este contacto ya ha avalado.".into(),
+    );
+    m.insert(
+        "error.voucher_mismatch".into(),
+        "Este aval es para otra solicitud.".into(),
+    );
 
     // Updates
     m.insert(
@@ -528,6 +986,12 @@ fn spanish_strings() -> HashMap<String, String> {
     m.insert("card.edit".into(), "Editar tarjeta".into());
     m.insert("card.share".into(), "Compartir tarjeta".into());
 
+    // Aha moments
+    m.insert(
+        "aha.contact_milestone.title".into(),
+        "¡{count} contactos!".into(),
+    );
+
     m
 }
 
@@ -586,6 +1050,15 @@ mod tests {
         assert_eq!(s, "5 contacts");
     }
 
+    #[test]
+    fn test_lookup_string_no_fallback() {
+        assert_eq!(
+            lookup_string(Locale::German, "welcome.title"),
+            Some("Willkommen bei Vauchi".to_string())
+        );
+        assert_eq!(lookup_string(Locale::French, "demo.tip-share.title"), None);
+    }
+
     #[test]
     fn test_available_locales() {
         let locales = get_available_locales();
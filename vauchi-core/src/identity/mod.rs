@@ -9,6 +9,7 @@
 //!
 //! Backup format versions:
 //! - v2 (current): Argon2id KDF + XChaCha20-Poly1305 encryption
+//! - v3 (deniable): two v2-style slots, one of which may hold a decoy identity
 //! - v1 / legacy: PBKDF2 KDF + AES-256-GCM encryption
 
 #[cfg(feature = "testing")]
@@ -17,15 +18,21 @@ pub mod backup;
 mod backup;
 pub mod device;
 pub mod password;
+#[cfg(feature = "testing")]
+pub mod rotation;
+#[cfg(not(feature = "testing"))]
+mod rotation;
 
 pub use backup::IdentityBackup;
 pub use device::{
     check_identity_collision, BroadcastDevice, DeviceError, DeviceInfo, DeviceRegistry,
     DeviceRevocationCertificate, RegisteredDevice, RegistryBroadcast, MAX_DEVICES,
 };
+pub use rotation::{KeyRotationError, KeyRotationProof};
 
 use crate::crypto::{
-    decrypt, derive_key_argon2id, derive_key_pbkdf2, encrypt, Signature, SigningKeyPair, HKDF,
+    decrypt, derive_key_argon2id, derive_key_pbkdf2, encrypt, Signature, SigningKeyPair,
+    SymmetricKey, HKDF,
 };
 use crate::exchange::X3DHKeyPair;
 use ring::rand::SystemRandom;
@@ -43,11 +50,30 @@ pub enum IdentityError {
     BackupFailed,
     #[error("Invalid backup or wrong password")]
     RestoreFailed,
+    #[error("Backup was created by a newer version of Vauchi (format {0}), please update")]
+    UnsupportedBackupVersion(u8),
+    #[error("Decoy password must differ from the backup password")]
+    DecoyPasswordReused,
 }
 
 /// Backup format version byte for Argon2id + XChaCha20.
 const BACKUP_VERSION_V2: u8 = 0x02;
 
+/// Backup format version byte for two-slot deniable backups.
+const BACKUP_VERSION_DENIABLE: u8 = 0x03;
+
+/// Highest version byte this version can read.
+const LATEST_BACKUP_VERSION: u8 = BACKUP_VERSION_DENIABLE;
+
+/// Version bytes reserved for future backup formats.
+///
+/// Legacy backups start with a random salt, so a byte in this range may
+/// also be an old backup; those are tried as legacy before being refused.
+const FUTURE_BACKUP_VERSIONS: std::ops::RangeInclusive<u8> = (LATEST_BACKUP_VERSION + 1)..=0x0F;
+
+/// Fixed plaintext size of each deniable backup slot.
+const DENIABLE_PLAINTEXT_SIZE: usize = 512;
+
 /// PBKDF2 iterations for legacy key derivation.
 const PBKDF2_ITERATIONS: u32 = 100_000;
 
@@ -85,11 +111,21 @@ impl Identity {
             .expect("System RNG should not fail")
             .expose();
 
-        Self::from_seed(master_seed, display_name.to_string())
+        Self::from_master_seed(master_seed, display_name.to_string())
+    }
+
+    /// Creates an identity from a fixed seed, so tests can reproduce the
+    /// same key material on every run.
+    ///
+    /// Only built with the `testing` feature: real identities must always
+    /// come from the system RNG.
+    #[cfg(feature = "testing")]
+    pub fn from_seed(display_name: &str, seed: [u8; 32]) -> Self {
+        Self::from_master_seed(seed, display_name.to_string())
     }
 
     /// Creates an identity from an existing seed with default device index 0.
-    fn from_seed(master_seed: [u8; 32], display_name: String) -> Self {
+    fn from_master_seed(master_seed: [u8; 32], display_name: String) -> Self {
         Self::from_seed_with_device(master_seed, display_name, 0, "Primary Device".to_string())
     }
 
@@ -195,6 +231,35 @@ impl Identity {
         &self.signing_keypair
     }
 
+    /// Rotates to a fresh signing key, returning the proof for contacts.
+    ///
+    /// A new master seed is generated, so the exchange key and device keys
+    /// change too; the display name and this device's index and name are
+    /// kept. Devices linked under the old seed must be linked again.
+    pub fn rotate_signing_key(&mut self) -> KeyRotationProof {
+        let rng = SystemRandom::new();
+        let master_seed = ring::rand::generate::<[u8; 32]>(&rng)
+            .expect("System RNG should not fail")
+            .expose();
+
+        let rotated = Self::from_seed_with_device(
+            master_seed,
+            self.display_name.clone(),
+            self.device_info.device_index(),
+            self.device_info.device_name().to_string(),
+        );
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+        let proof =
+            KeyRotationProof::create(&self.signing_keypair, &rotated.signing_keypair, timestamp);
+
+        // Dropping the old identity zeroizes its seed
+        *self = rotated;
+        proof
+    }
+
     /// Returns the device info for this device.
     pub fn device_info(&self) -> &DeviceInfo {
         &self.device_info
@@ -264,6 +329,75 @@ impl Identity {
         // Validate password strength using zxcvbn
         password::validate_password(password)?;
 
+        let sealed = self.seal_backup(password, None)?;
+
+        // Backup format: version_byte || salt (16 bytes) || ciphertext
+        let mut backup_data = Vec::with_capacity(1 + sealed.len());
+        backup_data.push(BACKUP_VERSION_V2);
+        backup_data.extend_from_slice(&sealed);
+
+        Ok(IdentityBackup::new(backup_data))
+    }
+
+    /// Exports identity as a deniable backup with an optional decoy identity.
+    ///
+    /// The backup holds two fixed-size slots in random order. One is this
+    /// identity under `password`; the other is the decoy under its own
+    /// password, or random filler when no decoy is given. Without a
+    /// password the slots are indistinguishable, so the backup does not
+    /// reveal whether a decoy exists. [`Identity::import_backup`] returns
+    /// whichever identity the entered password unlocks, so the decoy
+    /// password must differ from `password`.
+    ///
+    /// Backup format: `version_byte (0x03) || slot || slot`,
+    /// each slot being `salt (16 bytes) || ciphertext` of a padded plaintext.
+    pub fn export_deniable_backup(
+        &self,
+        password: &str,
+        decoy: Option<(&Identity, &str)>,
+    ) -> Result<IdentityBackup, IdentityError> {
+        password::validate_password(password)?;
+
+        let primary = self.seal_backup(password, Some(DENIABLE_PLAINTEXT_SIZE))?;
+        let secondary = match decoy {
+            Some((_, decoy_password)) if decoy_password == password => {
+                return Err(IdentityError::DecoyPasswordReused);
+            }
+            Some((decoy, decoy_password)) => {
+                password::validate_password(decoy_password)?;
+                decoy.seal_backup(decoy_password, Some(DENIABLE_PLAINTEXT_SIZE))?
+            }
+            None => Self::filler_slot()?,
+        };
+        debug_assert_eq!(primary.len(), secondary.len());
+
+        let rng = SystemRandom::new();
+        let [order] = ring::rand::generate::<[u8; 1]>(&rng)
+            .map_err(|_| IdentityError::BackupFailed)?
+            .expose();
+        let (first, second) = if order & 1 == 0 {
+            (primary, secondary)
+        } else {
+            (secondary, primary)
+        };
+
+        let mut backup_data = Vec::with_capacity(1 + first.len() + second.len());
+        backup_data.push(BACKUP_VERSION_DENIABLE);
+        backup_data.extend_from_slice(&first);
+        backup_data.extend_from_slice(&second);
+
+        Ok(IdentityBackup::new(backup_data))
+    }
+
+    /// Encrypts the backup plaintext, returning `salt (16 bytes) || ciphertext`.
+    ///
+    /// With `padded_len`, the plaintext is zero-padded to that size so the
+    /// ciphertext length does not depend on the names it contains.
+    fn seal_backup(
+        &self,
+        password: &str,
+        padded_len: Option<usize>,
+    ) -> Result<Vec<u8>, IdentityError> {
         // Generate random salt
         let rng = SystemRandom::new();
         let salt = ring::rand::generate::<[u8; 16]>(&rng)
@@ -292,24 +426,53 @@ impl Identity {
         plaintext.extend_from_slice(&device_name_len);
         plaintext.extend_from_slice(device_name_bytes);
 
+        if let Some(padded_len) = padded_len {
+            if plaintext.len() > padded_len {
+                plaintext.zeroize();
+                return Err(IdentityError::BackupFailed);
+            }
+            // Trailing bytes are ignored when parsing
+            plaintext.resize(padded_len, 0);
+        }
+
         // Encrypt the data (uses XChaCha20-Poly1305)
-        let ciphertext =
-            encrypt(&encryption_key, &plaintext).map_err(|_| IdentityError::BackupFailed)?;
+        let result = encrypt(&encryption_key, &plaintext);
+        plaintext.zeroize();
+        let ciphertext = result.map_err(|_| IdentityError::BackupFailed)?;
+
+        let mut sealed = Vec::with_capacity(16 + ciphertext.len());
+        sealed.extend_from_slice(&salt);
+        sealed.extend_from_slice(&ciphertext);
+        Ok(sealed)
+    }
 
-        // Backup format: version_byte || salt (16 bytes) || ciphertext
-        let mut backup_data = Vec::with_capacity(1 + 16 + ciphertext.len());
-        backup_data.push(BACKUP_VERSION_V2);
-        backup_data.extend_from_slice(&salt);
-        backup_data.extend_from_slice(&ciphertext);
+    /// Builds an unused deniable slot that looks like a sealed identity.
+    fn filler_slot() -> Result<Vec<u8>, IdentityError> {
+        let rng = SystemRandom::new();
+        let salt = ring::rand::generate::<[u8; 16]>(&rng)
+            .map_err(|_| IdentityError::BackupFailed)?
+            .expose();
 
-        Ok(IdentityBackup::new(backup_data))
+        // Same cipher and length as a real slot, under a key nobody keeps
+        let key = SymmetricKey::generate();
+        let ciphertext = encrypt(&key, &[0u8; DENIABLE_PLAINTEXT_SIZE])
+            .map_err(|_| IdentityError::BackupFailed)?;
+
+        let mut slot = Vec::with_capacity(16 + ciphertext.len());
+        slot.extend_from_slice(&salt);
+        slot.extend_from_slice(&ciphertext);
+        Ok(slot)
     }
 
     /// Imports identity from encrypted backup.
     ///
     /// Auto-detects backup version:
     /// - v2 (0x02): Argon2id + XChaCha20-Poly1305
+    /// - deniable (0x03): two v2 slots
     /// - v1/legacy: PBKDF2 + AES-256-GCM (tagged or untagged)
+    ///
+    /// A backup tagged with a newer format version fails with
+    /// `UnsupportedBackupVersion` rather than being misread.
     pub fn import_backup(backup: &IdentityBackup, password: &str) -> Result<Self, IdentityError> {
         let data = backup.as_bytes();
 
@@ -319,6 +482,13 @@ impl Identity {
 
         match data[0] {
             BACKUP_VERSION_V2 => Self::import_backup_v2(&data[1..], password),
+            BACKUP_VERSION_DENIABLE => Self::import_backup_deniable(&data[1..], password)
+                // A legacy backup whose salt happens to start with the tag
+                .or_else(|_| Self::import_backup_legacy(data, password)),
+            version if FUTURE_BACKUP_VERSIONS.contains(&version) => {
+                Self::import_backup_legacy(data, password)
+                    .map_err(|_| IdentityError::UnsupportedBackupVersion(version))
+            }
             _ => Self::import_backup_legacy(data, password),
         }
     }
@@ -347,6 +517,19 @@ impl Identity {
         Self::parse_backup_plaintext(&plaintext)
     }
 
+    /// Imports a deniable backup by trying the password against both slots.
+    ///
+    /// Data format: `slot || slot`, each slot being `salt (16 bytes) || ciphertext`
+    fn import_backup_deniable(data: &[u8], password: &str) -> Result<Self, IdentityError> {
+        if data.is_empty() || data.len() & 1 == 1 {
+            return Err(IdentityError::RestoreFailed);
+        }
+
+        let (first, second) = data.split_at(data.len() / 2);
+        Self::import_backup_v2(first, password)
+            .or_else(|_| Self::import_backup_v2(second, password))
+    }
+
     /// Imports legacy backup (PBKDF2 + AES-256-GCM).
     ///
     /// Data format: `salt (16 bytes) || ciphertext`
@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Signing Key Rotation
+//!
+//! Moves an identity to a new signing key while proving to contacts that
+//! the new key belongs to the same person. The old key signs the
+//! transition, and the new key countersigns it to prove possession, so a
+//! contact can follow the rotation without a new exchange.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::crypto::{PublicKey, Signature, SigningKeyPair};
+
+/// Domain separation prefix for rotation signatures.
+const ROTATION_CONTEXT: &[u8] = b"vauchi-key-rotation-v1";
+
+/// Key rotation errors.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum KeyRotationError {
+    #[error("Rotation proof signature is invalid")]
+    InvalidSignature,
+    #[error("Rotation proof does not change the key")]
+    SameKey,
+    #[error("Rotation proof is for a different key")]
+    KeyMismatch,
+    #[error("Rotation proof is malformed")]
+    Malformed,
+}
+
+/// Proof that an identity moved from one signing key to another.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyRotationProof {
+    /// Signing public key before the rotation.
+    old_public_key: [u8; 32],
+    /// Signing public key after the rotation.
+    new_public_key: [u8; 32],
+    /// When the rotation happened (Unix seconds).
+    timestamp: u64,
+    /// Signature over the transition by the old key.
+    old_signature: Vec<u8>,
+    /// Signature over the transition by the new key.
+    new_signature: Vec<u8>,
+}
+
+impl KeyRotationProof {
+    /// Creates a proof signed by both the old and the new keypair.
+    pub(crate) fn create(old: &SigningKeyPair, new: &SigningKeyPair, timestamp: u64) -> Self {
+        let old_public_key = *old.public_key().as_bytes();
+        let new_public_key = *new.public_key().as_bytes();
+        let message = Self::signed_bytes(&old_public_key, &new_public_key, timestamp);
+
+        KeyRotationProof {
+            old_public_key,
+            new_public_key,
+            timestamp,
+            old_signature: old.sign(&message).as_bytes().to_vec(),
+            new_signature: new.sign(&message).as_bytes().to_vec(),
+        }
+    }
+
+    /// Returns the signing public key before the rotation.
+    pub fn old_public_key(&self) -> &[u8; 32] {
+        &self.old_public_key
+    }
+
+    /// Returns the signing public key after the rotation.
+    pub fn new_public_key(&self) -> &[u8; 32] {
+        &self.new_public_key
+    }
+
+    /// Returns when the rotation happened.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Verifies that both keys signed the transition.
+    pub fn verify(&self) -> Result<(), KeyRotationError> {
+        if self.old_public_key == self.new_public_key {
+            return Err(KeyRotationError::SameKey);
+        }
+
+        let message =
+            Self::signed_bytes(&self.old_public_key, &self.new_public_key, self.timestamp);
+        for (key, signature) in [
+            (&self.old_public_key, &self.old_signature),
+            (&self.new_public_key, &self.new_signature),
+        ] {
+            let signature = <[u8; 64]>::try_from(signature.as_slice())
+                .map_err(|_| KeyRotationError::InvalidSignature)?;
+            if !PublicKey::from_bytes(*key).verify(&message, &Signature::from_bytes(signature)) {
+                return Err(KeyRotationError::InvalidSignature);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Serializes the proof to bytes for transmission.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap_or_default()
+    }
+
+    /// Deserializes a proof from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, KeyRotationError> {
+        serde_json::from_slice(bytes).map_err(|_| KeyRotationError::Malformed)
+    }
+
+    fn signed_bytes(
+        old_public_key: &[u8; 32],
+        new_public_key: &[u8; 32],
+        timestamp: u64,
+    ) -> Vec<u8> {
+        let mut data = Vec::with_capacity(ROTATION_CONTEXT.len() + 72);
+        data.extend_from_slice(ROTATION_CONTEXT);
+        data.extend_from_slice(old_public_key);
+        data.extend_from_slice(new_public_key);
+        data.extend_from_slice(&timestamp.to_be_bytes());
+        data
+    }
+}
@@ -22,6 +22,11 @@ pub mod identity;
 #[cfg(any(feature = "network-native-tls", feature = "network-rustls"))]
 pub mod network;
 pub mod recovery;
+#[cfg(all(
+    feature = "rest",
+    any(feature = "network-native-tls", feature = "network-rustls")
+))]
+pub mod rest;
 pub mod social;
 pub mod storage;
 pub mod sync;
@@ -31,21 +36,23 @@ pub use aha_moments::{AhaMoment, AhaMomentTracker, AhaMomentType};
 #[cfg(any(feature = "network-native-tls", feature = "network-rustls"))]
 pub use api::{Vauchi, VauchiBuilder, VauchiConfig, VauchiError, VauchiEvent, VauchiResult};
 pub use contact::{
-    Contact, FieldVisibility, LabelError, LabelManager, VisibilityLabel, VisibilityRules,
-    MAX_LABELS, SUGGESTED_LABELS,
+    suggest_invites, AttestationError, Contact, ContactError, ContactFieldVisibility,
+    DefaultFieldVisibility, FieldVisibility, InviteSuggestion, LabelError, LabelManager,
+    VerificationAttestation, VisibilityLabel, VisibilityRules, MAX_LABELS, SUGGESTED_LABELS,
 };
 pub use contact_card::{
-    is_allowed_scheme, is_blocked_scheme, is_safe_url, ContactCard, ContactField, FieldType,
-    ValidationError,
+    is_allowed_scheme, is_blocked_scheme, is_safe_url, CompletenessItem, CompletenessReport,
+    ContactCard, ContactField, FieldType, PublicCard, PublicCardRemoval, ValidationError,
 };
 pub use crypto::{decrypt, encrypt, PublicKey, Signature, SigningKeyPair, SymmetricKey};
 pub use demo_contact::{
-    generate_demo_contact_card, get_demo_tips, DemoContactCard, DemoContactState, DemoTip,
-    DemoTipCategory, DEMO_CONTACT_ID, DEMO_CONTACT_NAME,
+    generate_demo_contact_card, generate_demo_contact_card_for_locale, get_demo_tips, DemoAction,
+    DemoContactCard, DemoContactState, DemoTip, DemoTipCategory, DEMO_CONTACT_ID,
+    DEMO_CONTACT_NAME,
 };
 pub use exchange::{
     EncryptedExchangeMessage, ExchangeError, ExchangeEvent, ExchangeQR, ExchangeSession,
-    MockProximityVerifier, ProximityError, ProximityVerifier, X3DHKeyPair, X3DH,
+    MockProximityVerifier, ProximityError, ProximityVerifier, QrErrorCorrection, X3DHKeyPair, X3DH,
 };
 pub use help::{get_faq_by_id, get_faqs, get_faqs_by_category, search_faqs, FaqItem, HelpCategory};
 pub use i18n::{
@@ -63,10 +70,13 @@ pub use recovery::{
     RecoveryVoucher, VerificationResult,
 };
 pub use social::{
-    calculate_trust_weight, check_sybil_resistance, filter_blocked_validations, ProfileValidation,
-    SocialNetwork, SocialNetworkRegistry, TrustLevel, ValidationStatus,
+    calculate_trust_weight, check_sybil_resistance, filter_blocked_validations, IssuerAttestation,
+    ProfileValidation, SocialNetwork, SocialNetworkRegistry, TrustLevel, TrustThresholds,
+    ValidationBundle, ValidationRateLimiter, ValidationStatus,
+};
+pub use storage::{
+    AddressBookEntry, PendingExchange, PendingUpdate, Storage, StorageError, UpdateStatus,
 };
-pub use storage::{PendingUpdate, Storage, StorageError, UpdateStatus};
 pub use sync::{CardDelta, DeltaError, FieldChange, SyncError, SyncManager, SyncState};
 pub use theme::{
     get_bundled_themes, get_theme_by_id, validate_hex_color, Theme, ThemeColors, ThemeError,
@@ -0,0 +1,268 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! LAN Transport
+//!
+//! Direct device-to-device transport over TCP for devices on the same
+//! network, so nearby peers can sync without reaching a relay. Frames use
+//! the same length-prefixed encoding as relay messages.
+//!
+//! The TCP stream itself is not encrypted: updates are already end-to-end
+//! encrypted with the Double Ratchet, exactly as when they pass through a
+//! relay. Peer discovery (mDNS, BLE) is left to the platform, which passes
+//! the peer's address as `tcp://host:port`.
+
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use super::error::NetworkError;
+use super::message::{AckStatus, Acknowledgment, EncryptedUpdate, MessageEnvelope, MessagePayload};
+use super::protocol::{
+    create_envelope, decode_message, encode_message, read_frame_length, FRAME_HEADER_SIZE,
+    MAX_MESSAGE_SIZE,
+};
+use super::transport::{ConnectionState, Transport, TransportConfig, TransportResult};
+
+/// TCP transport to a peer on the local network.
+///
+/// Implements [`Transport`], so it can back a `RelayClient` or
+/// `SyncController` in place of the WebSocket relay transport.
+pub struct LanTransport {
+    stream: Option<TcpStream>,
+    state: ConnectionState,
+}
+
+impl LanTransport {
+    /// Creates a disconnected LAN transport.
+    pub fn new() -> Self {
+        LanTransport {
+            stream: None,
+            state: ConnectionState::Disconnected,
+        }
+    }
+
+    /// Wraps an already-connected stream (e.g. one accepted by [`LanListener`]).
+    pub fn from_stream(stream: TcpStream, io_timeout: Duration) -> TransportResult<Self> {
+        stream
+            .set_read_timeout(Some(io_timeout))
+            .map_err(|e| NetworkError::ConnectionFailed(e.to_string()))?;
+        stream
+            .set_write_timeout(Some(io_timeout))
+            .map_err(|e| NetworkError::ConnectionFailed(e.to_string()))?;
+
+        Ok(LanTransport {
+            stream: Some(stream),
+            state: ConnectionState::Connected,
+        })
+    }
+
+    /// Resolves a `tcp://host:port` (or bare `host:port`) peer address.
+    fn parse_addr(url: &str) -> Result<SocketAddr, NetworkError> {
+        let host_port = url.strip_prefix("tcp://").unwrap_or(url);
+        host_port
+            .to_socket_addrs()
+            .map_err(|e| NetworkError::ConnectionFailed(format!("Invalid peer address: {}", e)))?
+            .next()
+            .ok_or_else(|| NetworkError::ConnectionFailed(format!("Unresolvable peer: {}", url)))
+    }
+
+    /// Marks the connection as closed.
+    fn closed(&mut self) -> NetworkError {
+        self.stream = None;
+        self.state = ConnectionState::Disconnected;
+        NetworkError::ConnectionClosed
+    }
+
+    /// Drops a connection whose framing can no longer be trusted, such as
+    /// one that timed out partway through a frame.
+    fn reset(&mut self, error: NetworkError) -> NetworkError {
+        if let Some(stream) = self.stream.take() {
+            let _ = stream.shutdown(std::net::Shutdown::Both);
+        }
+        self.state = ConnectionState::Disconnected;
+        error
+    }
+}
+
+impl Default for LanTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for LanTransport {
+    fn connect(&mut self, config: &TransportConfig) -> TransportResult<()> {
+        if matches!(self.state, ConnectionState::Connected) {
+            return Ok(());
+        }
+
+        self.state = ConnectionState::Connecting;
+
+        let addr = Self::parse_addr(&config.server_url).inspect_err(|_| {
+            self.state = ConnectionState::Disconnected;
+        })?;
+        let stream =
+            TcpStream::connect_timeout(&addr, Duration::from_millis(config.connect_timeout_ms))
+                .map_err(|e| {
+                    self.state = ConnectionState::Disconnected;
+                    NetworkError::ConnectionFailed(e.to_string())
+                })?;
+
+        *self = Self::from_stream(stream, Duration::from_millis(config.io_timeout_ms))
+            .inspect_err(|_| {
+                self.state = ConnectionState::Disconnected;
+            })?;
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> TransportResult<()> {
+        if let Some(stream) = self.stream.take() {
+            let _ = stream.shutdown(std::net::Shutdown::Both); // Ignore errors on close
+        }
+        self.state = ConnectionState::Disconnected;
+        Ok(())
+    }
+
+    fn state(&self) -> ConnectionState {
+        self.state.clone()
+    }
+
+    fn send(&mut self, message: &MessageEnvelope) -> TransportResult<()> {
+        let stream = self.stream.as_mut().ok_or(NetworkError::NotConnected)?;
+
+        let encoded = encode_message(message)?;
+        match stream.write_all(&encoded).and_then(|_| stream.flush()) {
+            Ok(()) => Ok(()),
+            Err(e) if matches!(e.kind(), ErrorKind::BrokenPipe | ErrorKind::ConnectionReset) => {
+                Err(self.closed())
+            }
+            Err(e) => Err(NetworkError::SendFailed(e.to_string())),
+        }
+    }
+
+    fn receive(&mut self) -> TransportResult<Option<MessageEnvelope>> {
+        let stream = self.stream.as_mut().ok_or(NetworkError::NotConnected)?;
+
+        // Read the header by hand: `read_exact` would not say how much it
+        // consumed before a timeout
+        let mut header = [0u8; FRAME_HEADER_SIZE];
+        let mut filled = 0;
+        while filled < FRAME_HEADER_SIZE {
+            match stream.read(&mut header[filled..]) {
+                Ok(0) => return Err(self.closed()),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e)
+                    if filled == 0
+                        && matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) =>
+                {
+                    // No message available (timeout)
+                    return Ok(None);
+                }
+                // Past the first byte the stream is mid-frame; anything
+                // read after this would be misaligned
+                Err(e) => return Err(self.reset(NetworkError::ReceiveFailed(e.to_string()))),
+            }
+        }
+
+        let len = read_frame_length(&header);
+        if len > MAX_MESSAGE_SIZE {
+            return Err(self.reset(NetworkError::InvalidMessage(format!(
+                "Message too large: {} bytes (max {})",
+                len, MAX_MESSAGE_SIZE
+            ))));
+        }
+
+        let mut payload = vec![0u8; len];
+        match stream.read_exact(&mut payload) {
+            Ok(()) => Ok(Some(decode_message(&payload)?)),
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => Err(self.closed()),
+            Err(e) => Err(self.reset(NetworkError::ReceiveFailed(e.to_string()))),
+        }
+    }
+
+    fn has_pending(&self) -> bool {
+        // TCP doesn't provide a cheap non-blocking check
+        // Return false; caller should use receive() with timeout
+        false
+    }
+}
+
+/// Accepts direct connections from peers on the local network.
+pub struct LanListener {
+    listener: TcpListener,
+    io_timeout: Duration,
+}
+
+impl LanListener {
+    /// Listens on the given address (e.g. `0.0.0.0:0` for any free port).
+    pub fn bind(addr: &str, io_timeout: Duration) -> TransportResult<Self> {
+        let listener =
+            TcpListener::bind(addr).map_err(|e| NetworkError::ConnectionFailed(e.to_string()))?;
+        Ok(LanListener {
+            listener,
+            io_timeout,
+        })
+    }
+
+    /// Returns the address peers should connect to.
+    pub fn local_addr(&self) -> TransportResult<SocketAddr> {
+        self.listener
+            .local_addr()
+            .map_err(|e| NetworkError::ConnectionFailed(e.to_string()))
+    }
+
+    /// Blocks until a peer connects.
+    pub fn accept(&self) -> TransportResult<LanTransport> {
+        let (stream, _) = self
+            .listener
+            .accept()
+            .map_err(|e| NetworkError::ConnectionFailed(e.to_string()))?;
+        LanTransport::from_stream(stream, self.io_timeout)
+    }
+}
+
+/// Receives updates sent directly by a peer and acknowledges them.
+///
+/// This is the receiving side of a direct sync: the peer's `RelayClient`
+/// sends updates and waits for acknowledgments, as it would with a relay.
+/// Updates addressed to someone other than `our_id` are rejected with a
+/// `Failed` acknowledgment. Returns once the peer goes quiet or disconnects.
+pub fn receive_direct_updates<T: Transport>(
+    transport: &mut T,
+    our_id: &str,
+) -> TransportResult<Vec<EncryptedUpdate>> {
+    let mut updates = Vec::new();
+
+    loop {
+        let envelope = match transport.receive() {
+            Ok(Some(envelope)) => envelope,
+            Ok(None) | Err(NetworkError::ConnectionClosed) => break,
+            Err(e) => return Err(e),
+        };
+
+        let MessagePayload::EncryptedUpdate(update) = envelope.payload else {
+            continue;
+        };
+
+        let (status, error) = if update.recipient_id == our_id {
+            (AckStatus::ReceivedByRecipient, None)
+        } else {
+            (AckStatus::Failed, Some("Wrong recipient".to_string()))
+        };
+        let ack = create_envelope(MessagePayload::Acknowledgment(Acknowledgment {
+            message_id: envelope.message_id,
+            status,
+            error,
+        }));
+        transport.send(&ack)?;
+
+        if status == AckStatus::ReceivedByRecipient {
+            updates.push(update);
+        }
+    }
+
+    Ok(updates)
+}
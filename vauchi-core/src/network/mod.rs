@@ -11,6 +11,7 @@
 //!
 //! The network layer consists of:
 //! - **Transport trait**: Platform-agnostic interface for network I/O
+//! - **LAN transport**: Direct TCP sync between peers on the same network
 //! - **Message types**: Wire protocol for relay communication
 //! - **Protocol layer**: Message serialization and framing
 //! - **Connection manager**: Automatic reconnection and handshake
@@ -46,6 +47,11 @@ pub mod error;
 #[cfg(not(feature = "testing"))]
 mod error;
 
+#[cfg(feature = "testing")]
+pub mod lan;
+#[cfg(not(feature = "testing"))]
+mod lan;
+
 #[cfg(feature = "testing")]
 pub mod message;
 #[cfg(not(feature = "testing"))]
@@ -109,6 +115,9 @@ pub use mock::MockTransport;
 // WebSocket transport for production
 pub use websocket::WebSocketTransport;
 
+// Direct peer transport on the local network
+pub use lan::{receive_direct_updates, LanListener, LanTransport};
+
 // Connection management
 pub use connection::ConnectionManager;
 
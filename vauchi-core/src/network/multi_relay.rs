@@ -245,17 +245,34 @@ impl RelayHealth {
 
     /// Record a successful operation
     pub fn record_success(&mut self, relay: &str) {
+        self.record_success_at(relay, Instant::now());
+    }
+
+    /// Record a successful operation that happened at `at`
+    pub fn record_success_at(&mut self, relay: &str, at: Instant) {
         let state = self.states.entry(relay.to_string()).or_default();
         state.failure_count = 0;
         state.last_failure = None;
-        state.last_success = Some(Instant::now());
+        state.last_success = Some(at);
     }
 
     /// Record a failed operation
     pub fn record_failure(&mut self, relay: &str) {
+        self.record_failure_at(relay, Instant::now());
+    }
+
+    /// Record a failed operation that happened at `at`
+    pub fn record_failure_at(&mut self, relay: &str, at: Instant) {
         let state = self.states.entry(relay.to_string()).or_default();
         state.failure_count += 1;
-        state.last_failure = Some(Instant::now());
+        state.last_failure = Some(at);
+    }
+
+    /// Get the number of consecutive failures for a relay
+    pub fn failure_count(&self, relay: &str) -> u32 {
+        self.states
+            .get(relay)
+            .map_or(0, |state| state.failure_count)
     }
 
     /// Check if a relay is considered healthy
@@ -293,6 +310,11 @@ impl RelayHealth {
 
     /// Get remaining cooldown time for a relay
     pub fn cooldown_remaining(&self, relay: &str) -> Duration {
+        self.cooldown_remaining_at(relay, Instant::now())
+    }
+
+    /// Get the cooldown time for a relay remaining at `now`
+    pub fn cooldown_remaining_at(&self, relay: &str, now: Instant) -> Duration {
         match self.states.get(relay) {
             None => Duration::ZERO,
             Some(state) => {
@@ -303,7 +325,7 @@ impl RelayHealth {
                     None => Duration::ZERO,
                     Some(last_failure) => {
                         let cooldown = self.calculate_cooldown(state.failure_count);
-                        let elapsed = Instant::now().duration_since(last_failure);
+                        let elapsed = now.saturating_duration_since(last_failure);
                         cooldown.saturating_sub(elapsed)
                     }
                 }
@@ -496,6 +518,8 @@ mod tests {
 
         health.record_failure("relay2");
         assert!(!health.is_healthy("relay2"));
+        assert_eq!(health.failure_count("relay2"), 1);
+        assert_eq!(health.failure_count("relay1"), 0);
     }
 
     #[test]
@@ -13,6 +13,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::crypto::{PublicKey, Signature, SigningKeyPair};
+
 /// Protocol version for simple messages.
 pub const SIMPLE_PROTOCOL_VERSION: u8 = 1;
 
@@ -42,6 +44,22 @@ pub enum SimplePayload {
     DeviceSyncMessage(SimpleDeviceSyncMessage),
     /// Acknowledgment for device sync messages.
     DeviceSyncAck(SimpleDeviceSyncAck),
+    /// Store a recovery proof in the relay's recovery storage.
+    RecoveryProofStore(SimpleRecoveryProofStore),
+    /// Request the recovery proof stored for a lost identity.
+    RecoveryProofQuery(SimpleRecoveryProofQuery),
+    /// Relay response to a recovery proof query.
+    RecoveryProofResponse(SimpleRecoveryProofResponse),
+    /// Relay-signed receipt for a message it accepted (optional, relay-dependent).
+    DeliveryReceipt(SimpleDeliveryReceipt),
+    /// Ask the relay what became of messages the client sent.
+    DeliveryStatusQuery(SimpleDeliveryStatusQuery),
+    /// Relay response to a delivery status query.
+    DeliveryStatusResponse(SimpleDeliveryStatusResponse),
+    /// Publish or replace a card at a public permalink.
+    PublicCardPublish(SimplePublicCardPublish),
+    /// Take down the card at a public permalink.
+    PublicCardUnpublish(SimplePublicCardUnpublish),
     /// Unknown message type (for forward compatibility).
     #[serde(other)]
     Unknown,
@@ -80,6 +98,13 @@ pub struct SimpleHandshake {
     /// Optional device ID for inter-device sync (hex-encoded, 64 chars = 32 bytes).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub device_id: Option<String>,
+    /// Sync cursor: ID of the last message the client processed.
+    ///
+    /// Relays that support cursors deliver only messages queued after it.
+    /// Relays that don't, or that no longer hold that message, deliver
+    /// everything pending.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
 }
 
 /// Legacy exchange message format (plaintext, for backward compatibility).
@@ -231,3 +256,215 @@ pub fn create_device_sync_ack(message_id: &str, synced_version: u64) -> SimpleEn
         synced_version,
     }))
 }
+
+/// Recovery proof published to the relay, keyed by the lost identity's public key.
+///
+/// The relay keeps one proof per publishing client, so a second claimant
+/// cannot overwrite an existing proof.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimpleRecoveryProofStore {
+    /// Lost identity's public key (hex-encoded).
+    pub old_pk: String,
+    /// Serialized `RecoveryProof`.
+    pub proof: Vec<u8>,
+    /// Unix timestamp after which the relay may discard the proof.
+    pub expires_at: u64,
+}
+
+/// Query for the recovery proof of a lost identity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimpleRecoveryProofQuery {
+    /// Lost identity's public key (hex-encoded).
+    pub old_pk: String,
+}
+
+/// Relay response to a recovery proof query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimpleRecoveryProofResponse {
+    /// Lost identity's public key (hex-encoded).
+    pub old_pk: String,
+    /// Serialized `RecoveryProof`s stored for this key that have not expired.
+    pub proofs: Vec<Vec<u8>>,
+}
+
+/// Create a recovery proof store envelope.
+pub fn create_recovery_proof_store(
+    old_pk: &str,
+    proof: Vec<u8>,
+    expires_at: u64,
+) -> SimpleEnvelope {
+    create_simple_envelope(SimplePayload::RecoveryProofStore(
+        SimpleRecoveryProofStore {
+            old_pk: old_pk.to_string(),
+            proof,
+            expires_at,
+        },
+    ))
+}
+
+/// Create a recovery proof query envelope.
+pub fn create_recovery_proof_query(old_pk: &str) -> SimpleEnvelope {
+    create_simple_envelope(SimplePayload::RecoveryProofQuery(
+        SimpleRecoveryProofQuery {
+            old_pk: old_pk.to_string(),
+        },
+    ))
+}
+
+/// Domain separation tag for relay delivery receipt signatures.
+const DELIVERY_RECEIPT_CONTEXT: &[u8] = b"vauchi-delivery-receipt-v1";
+
+/// Relay-signed proof that a message was accepted for a recipient.
+///
+/// Carries no message content. Relays that have a receipt key configured
+/// send one back to the sender for every stored update; clients verify it
+/// against the relay public key they pinned.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimpleDeliveryReceipt {
+    /// ID of the envelope the relay accepted.
+    pub message_id: String,
+    /// Recipient the message was stored for.
+    pub recipient_id: String,
+    /// Relay clock at acceptance (Unix seconds).
+    pub server_timestamp: u64,
+    /// Ed25519 signature by the relay receipt key (64 bytes).
+    pub signature: Vec<u8>,
+}
+
+impl SimpleDeliveryReceipt {
+    /// Signs a receipt with the relay's receipt key.
+    pub fn sign(
+        relay_key: &SigningKeyPair,
+        message_id: &str,
+        recipient_id: &str,
+        server_timestamp: u64,
+    ) -> Self {
+        let signature = relay_key.sign(&Self::signed_bytes(
+            message_id,
+            recipient_id,
+            server_timestamp,
+        ));
+        SimpleDeliveryReceipt {
+            message_id: message_id.to_string(),
+            recipient_id: recipient_id.to_string(),
+            server_timestamp,
+            signature: signature.as_bytes().to_vec(),
+        }
+    }
+
+    /// Returns true if the receipt was signed by `relay_public_key`.
+    pub fn verify(&self, relay_public_key: &PublicKey) -> bool {
+        let Ok(bytes) = <[u8; 64]>::try_from(self.signature.as_slice()) else {
+            return false;
+        };
+        relay_public_key.verify(
+            &Self::signed_bytes(&self.message_id, &self.recipient_id, self.server_timestamp),
+            &Signature::from_bytes(bytes),
+        )
+    }
+
+    fn signed_bytes(message_id: &str, recipient_id: &str, server_timestamp: u64) -> Vec<u8> {
+        let mut data = Vec::with_capacity(
+            DELIVERY_RECEIPT_CONTEXT.len() + 16 + message_id.len() + recipient_id.len(),
+        );
+        data.extend_from_slice(DELIVERY_RECEIPT_CONTEXT);
+        for field in [message_id, recipient_id] {
+            data.extend_from_slice(&(field.len() as u32).to_be_bytes());
+            data.extend_from_slice(field.as_bytes());
+        }
+        data.extend_from_slice(&server_timestamp.to_be_bytes());
+        data
+    }
+}
+
+/// Create a delivery receipt envelope signed by the relay.
+pub fn create_delivery_receipt(
+    relay_key: &SigningKeyPair,
+    message_id: &str,
+    recipient_id: &str,
+    server_timestamp: u64,
+) -> SimpleEnvelope {
+    create_simple_envelope(SimplePayload::DeliveryReceipt(SimpleDeliveryReceipt::sign(
+        relay_key,
+        message_id,
+        recipient_id,
+        server_timestamp,
+    )))
+}
+
+/// Query for the delivery status of messages the client sent.
+///
+/// Relays answer from their confirmation log, and only for messages the
+/// querying client sent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimpleDeliveryStatusQuery {
+    /// IDs of the envelopes to look up.
+    pub message_ids: Vec<String>,
+}
+
+/// Relay response to a delivery status query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimpleDeliveryStatusResponse {
+    /// One entry per queried message ID.
+    pub statuses: Vec<SimpleMessageStatus>,
+}
+
+/// What the relay knows about one message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimpleMessageStatus {
+    pub message_id: String,
+    pub state: SimpleMessageState,
+}
+
+/// Delivery state of a message as recorded by the relay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimpleMessageState {
+    /// Held by the relay, not yet confirmed by the recipient.
+    Stored,
+    /// The recipient acknowledged the message.
+    Delivered,
+    /// Not in the relay's log, e.g. never received or already purged.
+    Unknown,
+}
+
+/// Create a delivery status query envelope.
+pub fn create_delivery_status_query(message_ids: Vec<String>) -> SimpleEnvelope {
+    create_simple_envelope(SimplePayload::DeliveryStatusQuery(
+        SimpleDeliveryStatusQuery { message_ids },
+    ))
+}
+
+/// A card to serve read-only over HTTP under its handle.
+///
+/// The relay accepts it only if the signature verifies, and the handle is
+/// unclaimed or was claimed by the same owner with an older publication.
+/// It serves the `PublicCard` JSON unchanged so viewers can verify it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimplePublicCardPublish {
+    /// `PublicCard` JSON.
+    pub card: Vec<u8>,
+}
+
+/// Request to stop serving a public card.
+///
+/// The relay honours it only if signed by the handle's owner and newer
+/// than the current publication; the handle then stays reserved for them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimplePublicCardUnpublish {
+    /// `PublicCardRemoval` JSON.
+    pub removal: Vec<u8>,
+}
+
+/// Create a public card publish envelope.
+pub fn create_public_card_publish(card: Vec<u8>) -> SimpleEnvelope {
+    create_simple_envelope(SimplePayload::PublicCardPublish(SimplePublicCardPublish {
+        card,
+    }))
+}
+
+/// Create a public card unpublish envelope.
+pub fn create_public_card_unpublish(removal: Vec<u8>) -> SimpleEnvelope {
+    create_simple_envelope(SimplePayload::PublicCardUnpublish(
+        SimplePublicCardUnpublish { removal },
+    ))
+}
@@ -69,6 +69,23 @@ pub enum RecoveryError {
 
     #[error("Rate limit exceeded: too many recovery claims in the current window")]
     RateLimitExceeded,
+
+    #[error("Voucher timestamp is outside the 48 hour recovery window")]
+    StaleVoucher,
+
+    #[error("Recovery timestamp is too far in the future")]
+    FutureTimestamp,
+}
+
+/// How far in the future a claim or voucher timestamp may be, to absorb
+/// clock drift between devices, before it is rejected.
+pub const MAX_CLOCK_DRIFT_SECS: u64 = 5 * 60;
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs()
 }
 
 // =============================================================================
@@ -221,7 +238,19 @@ impl RecoveryClaim {
             .expect("Time went backwards")
             .as_secs();
 
-        now.saturating_sub(self.timestamp) > Self::MAX_AGE_SECS
+        self.is_expired_at(now, 0)
+    }
+
+    /// Checks if this claim has expired at `now`, allowing for up to
+    /// `skew_tolerance_secs` of clock difference with the claimant.
+    pub fn is_expired_at(&self, now: u64, skew_tolerance_secs: u64) -> bool {
+        now.saturating_sub(self.timestamp) > Self::MAX_AGE_SECS.saturating_add(skew_tolerance_secs)
+    }
+
+    /// Returns true if the claim is dated more than [`MAX_CLOCK_DRIFT_SECS`]
+    /// after `now`. Such a claim would never expire.
+    pub fn is_future_dated_at(&self, now: u64) -> bool {
+        self.timestamp > now.saturating_add(MAX_CLOCK_DRIFT_SECS)
     }
 
     /// Returns the old (lost) public key.
@@ -300,10 +329,13 @@ impl RecoveryVoucher {
     ///
     /// Validates that:
     /// - The claim is not expired (less than 48 hours old)
+    /// - The claim is not dated in the future (beyond clock drift)
     /// - The voucher is not self-vouching (voucher_pk != new_pk)
     ///
     /// # Errors
     /// - `ClaimExpired` if the claim is older than 48 hours
+    /// - `FutureTimestamp` if the claim is dated more than
+    ///   [`MAX_CLOCK_DRIFT_SECS`] in the future
     /// - `SelfVouching` if the voucher's public key matches the new identity
     pub fn create_from_claim(
         claim: &RecoveryClaim,
@@ -313,6 +345,10 @@ impl RecoveryVoucher {
             return Err(RecoveryError::ClaimExpired);
         }
 
+        if claim.is_future_dated_at(now_secs()) {
+            return Err(RecoveryError::FutureTimestamp);
+        }
+
         // Prevent self-vouching
         if voucher_keypair.public_key().as_bytes() == claim.new_pk() {
             return Err(RecoveryError::SelfVouching);
@@ -332,6 +368,18 @@ impl RecoveryVoucher {
             .expect("Time went backwards")
             .as_secs();
 
+        Self::create_with_timestamp(old_pk, new_pk, voucher_keypair, timestamp)
+    }
+
+    /// Creates a signed voucher with a specific timestamp.
+    /// Used for testing timestamp validation.
+    #[doc(hidden)]
+    pub fn create_with_timestamp(
+        old_pk: &[u8; 32],
+        new_pk: &[u8; 32],
+        voucher_keypair: &SigningKeyPair,
+        timestamp: u64,
+    ) -> Self {
         let voucher_pk = *voucher_keypair.public_key().as_bytes();
 
         // Build data to sign
@@ -382,6 +430,12 @@ impl RecoveryVoucher {
         self.timestamp
     }
 
+    /// Returns true if the voucher is dated more than
+    /// [`MAX_CLOCK_DRIFT_SECS`] after `now`.
+    pub fn is_future_dated_at(&self, now: u64) -> bool {
+        self.timestamp > now.saturating_add(MAX_CLOCK_DRIFT_SECS)
+    }
+
     /// Verifies the voucher signature.
     pub fn verify(&self) -> bool {
         let data =
@@ -499,6 +553,26 @@ impl RecoveryProof {
         self.threshold
     }
 
+    /// Returns the Unix timestamp after which the proof is no longer valid.
+    pub fn expires_at(&self) -> u64 {
+        self.expires_at
+    }
+
+    /// Returns true if the proof has expired.
+    pub fn is_expired(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+        self.is_expired_at(now, 0)
+    }
+
+    /// Returns true if the proof has expired at `now`, allowing for up to
+    /// `skew_tolerance_secs` of clock difference with the proof's creator.
+    pub fn is_expired_at(&self, now: u64, skew_tolerance_secs: u64) -> bool {
+        now > self.expires_at.saturating_add(skew_tolerance_secs)
+    }
+
     /// Returns the number of vouchers.
     pub fn voucher_count(&self) -> usize {
         self.vouchers.len()
@@ -516,6 +590,10 @@ impl RecoveryProof {
     /// - `InvalidSignature` if voucher signature is invalid
     /// - `DuplicateVoucher` if voucher from same contact already exists
     /// - `SelfVouching` if voucher is from the recovering identity
+    /// - `FutureTimestamp` if voucher is dated more than
+    ///   [`MAX_CLOCK_DRIFT_SECS`] in the future
+    /// - `StaleVoucher` if voucher was signed more than 48 hours away from
+    ///   the proof's creation, e.g. an old voucher being replayed
     pub fn add_voucher(&mut self, voucher: RecoveryVoucher) -> Result<(), RecoveryError> {
         // Verify keys match
         if voucher.old_pk() != &self.old_pk || voucher.new_pk() != &self.new_pk {
@@ -532,6 +610,15 @@ impl RecoveryProof {
             return Err(RecoveryError::InvalidSignature);
         }
 
+        if voucher.is_future_dated_at(now_secs()) {
+            return Err(RecoveryError::FutureTimestamp);
+        }
+
+        // Reject vouchers from outside this recovery's window
+        if voucher.timestamp().abs_diff(self.created_at) > RecoveryClaim::MAX_AGE_SECS {
+            return Err(RecoveryError::StaleVoucher);
+        }
+
         // Check for duplicate
         if self
             .vouchers
@@ -552,6 +639,7 @@ impl RecoveryProof {
         }
 
         // Check for duplicates
+        let now = now_secs();
         let mut seen_vouchers = HashSet::new();
         for voucher in &self.vouchers {
             if !seen_vouchers.insert(voucher.voucher_pk) {
@@ -563,6 +651,9 @@ impl RecoveryProof {
             if voucher.old_pk != self.old_pk || voucher.new_pk != self.new_pk {
                 return Err(RecoveryError::MismatchedKeys);
             }
+            if voucher.is_future_dated_at(now) {
+                return Err(RecoveryError::FutureTimestamp);
+            }
         }
 
         Ok(())
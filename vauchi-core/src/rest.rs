@@ -0,0 +1,339 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! REST Facade
+//!
+//! A small HTTP API over [`Vauchi`] for server-side integrations, such as
+//! an organization provisioning cards for its members. Enabled with the
+//! `rest` feature; off by default.
+//!
+//! Every request must carry `Authorization: Bearer <token>` with the token
+//! given to [`router`]. The router does not terminate TLS; put it behind a
+//! proxy that does.
+//!
+//! | Method | Path                 | Action                            |
+//! |--------|----------------------|-----------------------------------|
+//! | POST   | `/identity`          | Create the identity               |
+//! | GET    | `/identity`          | Get the public ID                 |
+//! | GET    | `/card`              | Get the own card                  |
+//! | POST   | `/card/fields`       | Add a field                       |
+//! | PUT    | `/card/fields/{id}`  | Change a field's label or value   |
+//! | DELETE | `/card/fields/{id}`  | Remove a field                    |
+//! | GET    | `/contacts`          | List contacts                     |
+//! | POST   | `/sync`              | Run one sync cycle with the relay |
+
+use std::sync::{Arc, Mutex};
+
+use axum::extract::{Path, Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post, put};
+use axum::{Json, Router};
+use ring::digest;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{SyncController, Vauchi, VauchiError};
+use crate::contact_card::{ContactField, FieldType};
+use crate::network::{RelayClient, Transport};
+
+struct RestState<T: Transport> {
+    vauchi: Mutex<Vauchi<T>>,
+    /// SHA-256 of the bearer token.
+    token_hash: digest::Digest,
+}
+
+type SharedState<T> = Arc<RestState<T>>;
+
+/// Builds the REST router over `vauchi`, requiring `token` on every request.
+///
+/// `POST /sync` connects to the configured relay with a transport made by
+/// `T::default()`.
+pub fn router<T>(vauchi: Vauchi<T>, token: impl Into<String>) -> Router
+where
+    T: Transport + Default + Send + 'static,
+{
+    let state = Arc::new(RestState {
+        vauchi: Mutex::new(vauchi),
+        token_hash: digest::digest(&digest::SHA256, token.into().as_bytes()),
+    });
+
+    Router::new()
+        .route(
+            "/identity",
+            post(create_identity::<T>).get(get_identity::<T>),
+        )
+        .route("/card", get(get_card::<T>))
+        .route("/card/fields", post(add_field::<T>))
+        .route(
+            "/card/fields/{id}",
+            put(update_field::<T>).delete(remove_field::<T>),
+        )
+        .route("/contacts", get(list_contacts::<T>))
+        .route("/sync", post(sync::<T>))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_token::<T>,
+        ))
+        .with_state(state)
+}
+
+/// Rejects requests without the expected bearer token.
+///
+/// Compares digests rather than the tokens themselves, so the comparison
+/// time does not reveal how much of a guessed token was right.
+async fn require_token<T: Transport>(
+    State(state): State<SharedState<T>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let presented = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match presented {
+        Some(token)
+            if digest::digest(&digest::SHA256, token.as_bytes()).as_ref()
+                == state.token_hash.as_ref() =>
+        {
+            next.run(request).await
+        }
+        _ => StatusCode::UNAUTHORIZED.into_response(),
+    }
+}
+
+/// An error response: a status code and a JSON `{"error": ...}` body.
+struct RestError(StatusCode, String);
+
+impl From<VauchiError> for RestError {
+    fn from(err: VauchiError) -> Self {
+        let status = match &err {
+            VauchiError::IdentityNotInitialized | VauchiError::AlreadyInitialized => {
+                StatusCode::CONFLICT
+            }
+            VauchiError::ContactNotFound(_) | VauchiError::NotFound(_) => StatusCode::NOT_FOUND,
+            VauchiError::Validation(_) | VauchiError::InvalidState(_) => StatusCode::BAD_REQUEST,
+            VauchiError::Network(_) => StatusCode::BAD_GATEWAY,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        RestError(status, err.to_string())
+    }
+}
+
+impl IntoResponse for RestError {
+    fn into_response(self) -> Response {
+        (self.0, Json(ErrorBody { error: self.1 })).into_response()
+    }
+}
+
+type RestResult<T> = Result<T, RestError>;
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+#[derive(Deserialize)]
+struct CreateIdentityRequest {
+    display_name: String,
+}
+
+#[derive(Serialize)]
+struct IdentityResponse {
+    public_id: String,
+}
+
+#[derive(Serialize)]
+struct CardResponse {
+    display_name: String,
+    fields: Vec<ContactField>,
+}
+
+#[derive(Deserialize)]
+struct AddFieldRequest {
+    field_type: FieldType,
+    label: String,
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct UpdateFieldRequest {
+    label: Option<String>,
+    value: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ContactSummary {
+    id: String,
+    display_name: String,
+    fingerprint_verified: bool,
+}
+
+#[derive(Serialize)]
+struct SyncResponse {
+    sent: usize,
+    acknowledged: usize,
+    failed: usize,
+    timed_out: usize,
+}
+
+fn lock<T: Transport>(state: &RestState<T>) -> std::sync::MutexGuard<'_, Vauchi<T>> {
+    // A handler that panicked left nothing half-written worth refusing over;
+    // storage changes are transactional.
+    state
+        .vauchi
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Runs `f` on the locked instance on tokio's blocking thread pool.
+///
+/// Storage and relay calls block, and requests queue on the lock while one
+/// runs; neither may tie up the async workers.
+async fn with_vauchi<T, R>(
+    state: SharedState<T>,
+    f: impl FnOnce(&mut Vauchi<T>) -> RestResult<R> + Send + 'static,
+) -> RestResult<R>
+where
+    T: Transport + Send + 'static,
+    R: Send + 'static,
+{
+    tokio::task::spawn_blocking(move || f(&mut lock(&state)))
+        .await
+        .map_err(|e| RestError(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+}
+
+async fn create_identity<T: Transport + Send + 'static>(
+    State(state): State<SharedState<T>>,
+    Json(request): Json<CreateIdentityRequest>,
+) -> RestResult<(StatusCode, Json<IdentityResponse>)> {
+    let public_id = with_vauchi(state, move |vauchi| {
+        vauchi.create_identity(&request.display_name)?;
+        Ok(vauchi.public_id()?)
+    })
+    .await?;
+    Ok((StatusCode::CREATED, Json(IdentityResponse { public_id })))
+}
+
+async fn get_identity<T: Transport + Send + 'static>(
+    State(state): State<SharedState<T>>,
+) -> RestResult<Json<IdentityResponse>> {
+    let public_id = with_vauchi(state, |vauchi| Ok(vauchi.public_id()?)).await?;
+    Ok(Json(IdentityResponse { public_id }))
+}
+
+async fn get_card<T: Transport + Send + 'static>(
+    State(state): State<SharedState<T>>,
+) -> RestResult<Json<CardResponse>> {
+    let card = with_vauchi(state, |vauchi| Ok(vauchi.own_card()?))
+        .await?
+        .ok_or(VauchiError::IdentityNotInitialized)?;
+    Ok(Json(CardResponse {
+        display_name: card.display_name().to_string(),
+        fields: card.fields().to_vec(),
+    }))
+}
+
+async fn add_field<T: Transport + Send + 'static>(
+    State(state): State<SharedState<T>>,
+    Json(request): Json<AddFieldRequest>,
+) -> RestResult<(StatusCode, Json<ContactField>)> {
+    let field = ContactField::new(request.field_type, &request.label, &request.value);
+    field.validate().map_err(VauchiError::from)?;
+    let added = field.clone();
+    with_vauchi(state, move |vauchi| Ok(vauchi.add_own_field(added)?)).await?;
+    Ok((StatusCode::CREATED, Json(field)))
+}
+
+async fn update_field<T: Transport + Send + 'static>(
+    State(state): State<SharedState<T>>,
+    Path(field_id): Path<String>,
+    Json(request): Json<UpdateFieldRequest>,
+) -> RestResult<Json<ContactField>> {
+    let field = with_vauchi(state, move |vauchi| {
+        let mut card = vauchi
+            .own_card()?
+            .ok_or(VauchiError::IdentityNotInitialized)?;
+        if card.field(&field_id).is_none() {
+            return Err(VauchiError::NotFound(format!("field {field_id}")).into());
+        }
+
+        if let Some(label) = &request.label {
+            card.update_field_label(&field_id, label)
+                .map_err(|e| VauchiError::InvalidState(e.to_string()))?;
+        }
+        if let Some(value) = &request.value {
+            card.update_field_value(&field_id, value)
+                .map_err(|e| VauchiError::InvalidState(e.to_string()))?;
+        }
+        vauchi.update_own_card(&card)?;
+
+        Ok(card
+            .field(&field_id)
+            .cloned()
+            .expect("field was checked above"))
+    })
+    .await?;
+    Ok(Json(field))
+}
+
+async fn remove_field<T: Transport + Send + 'static>(
+    State(state): State<SharedState<T>>,
+    Path(field_id): Path<String>,
+) -> RestResult<StatusCode> {
+    let id = field_id.clone();
+    if with_vauchi(state, move |vauchi| Ok(vauchi.remove_own_field_by_id(&id)?)).await? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(VauchiError::NotFound(format!("field {field_id}")).into())
+    }
+}
+async fn list_contacts<T: Transport + Send + 'static>(
+    State(state): State<SharedState<T>>,
+) -> RestResult<Json<Vec<ContactSummary>>> {
+    let contacts = with_vauchi(state, |vauchi| Ok(vauchi.list_contacts()?)).await?;
+    Ok(Json(
+        contacts
+            .iter()
+            .map(|contact| ContactSummary {
+                id: contact.id().to_string(),
+                display_name: contact.display_name().to_string(),
+                fingerprint_verified: contact.is_fingerprint_verified(),
+            })
+            .collect(),
+    ))
+}
+
+async fn sync<T: Transport + Default + Send + 'static>(
+    State(state): State<SharedState<T>>,
+) -> RestResult<Json<SyncResponse>> {
+    let result = with_vauchi(state, |vauchi| {
+        let relay = RelayClient::new(
+            T::default(),
+            vauchi.config().relay.to_relay_client_config(),
+            vauchi.public_id()?,
+        );
+        let mut controller = SyncController::new(
+            relay,
+            vauchi.storage(),
+            vauchi.config().sync.clone(),
+            vauchi.events().clone(),
+        );
+
+        controller.connect()?;
+        let result = controller.sync();
+        let _ = controller.disconnect();
+        Ok(result?)
+    })
+    .await?;
+
+    Ok(Json(SyncResponse {
+        sent: result.sent,
+        acknowledged: result.acknowledged,
+        failed: result.failed,
+        timed_out: result.timed_out,
+    }))
+}
@@ -0,0 +1,168 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Issuer Attestations
+//!
+//! Beyond peer validations, an authoritative issuer (an employer, a
+//! university) can sign that a field belongs to a user: "this work email
+//! is Alice's". One issuer attestation weighs as much as several peer
+//! validations.
+//!
+//! The attestation covers the issuer's key, the holder's key, the field
+//! and a hash of its value, so it cannot be moved to another holder and
+//! stops counting once the value changes. The issuer's name is chosen by
+//! the issuer; apps show it next to the field so the user can judge it.
+
+use ring::digest;
+use serde::{Deserialize, Serialize};
+
+use super::validation::signature_serde;
+use crate::Identity;
+
+/// Domain separator mixed into issuer attestation signatures.
+const ATTESTATION_DOMAIN: &[u8] = b"VAUCHI_ISSUER_ATTESTATION";
+
+/// How many peer validations one issuer attestation counts as.
+pub const ISSUER_ATTESTATION_WEIGHT: usize = 3;
+
+/// An issuer's signed statement that a field value belongs to a holder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuerAttestation {
+    /// The issuer's Ed25519 public key.
+    issuer_pk: [u8; 32],
+    /// The issuer's display name, e.g. "ACME Corp".
+    issuer_name: String,
+    /// The holder's Ed25519 public key.
+    subject_pk: [u8; 32],
+    /// Name of the attested field (e.g. "work").
+    field_id: String,
+    /// SHA-256 of the attested field value.
+    value_hash: [u8; 32],
+    /// Unix timestamp when the attestation was issued.
+    issued_at: u64,
+    /// Signature over all of the above by the issuer.
+    #[serde(with = "signature_serde")]
+    signature: [u8; 64],
+}
+
+impl IssuerAttestation {
+    /// Creates an attestation, signed by `issuer`, that `field_value` in
+    /// the holder's field `field_id` belongs to the holder.
+    pub fn create(
+        issuer: &Identity,
+        issuer_name: &str,
+        subject_pk: &[u8; 32],
+        field_id: &str,
+        field_value: &str,
+    ) -> Self {
+        let mut attestation = Self {
+            issuer_pk: *issuer.signing_public_key(),
+            issuer_name: issuer_name.to_string(),
+            subject_pk: *subject_pk,
+            field_id: field_id.to_string(),
+            value_hash: hash_value(field_value),
+            issued_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("system time before UNIX epoch")
+                .as_secs(),
+            signature: [0u8; 64],
+        };
+        attestation.signature = *issuer.sign(&attestation.signable_bytes()).as_bytes();
+        attestation
+    }
+
+    fn signable_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(ATTESTATION_DOMAIN);
+        data.extend_from_slice(&self.issuer_pk);
+        data.extend_from_slice(&(self.issuer_name.len() as u64).to_le_bytes());
+        data.extend_from_slice(self.issuer_name.as_bytes());
+        data.extend_from_slice(&self.subject_pk);
+        data.extend_from_slice(&(self.field_id.len() as u64).to_le_bytes());
+        data.extend_from_slice(self.field_id.as_bytes());
+        data.extend_from_slice(&self.value_hash);
+        data.extend_from_slice(&self.issued_at.to_le_bytes());
+        data
+    }
+
+    /// Returns the issuer's public key.
+    pub fn issuer_pk(&self) -> &[u8; 32] {
+        &self.issuer_pk
+    }
+
+    /// Returns the issuer's ID (hex-encoded public key).
+    pub fn issuer_id(&self) -> String {
+        hex::encode(self.issuer_pk)
+    }
+
+    /// Returns the issuer's display name.
+    pub fn issuer_name(&self) -> &str {
+        &self.issuer_name
+    }
+
+    /// Returns the holder's contact ID (hex-encoded public key).
+    pub fn subject_id(&self) -> String {
+        hex::encode(self.subject_pk)
+    }
+
+    /// Returns the name of the attested field.
+    pub fn field_id(&self) -> &str {
+        &self.field_id
+    }
+
+    /// Returns the issue timestamp.
+    pub fn issued_at(&self) -> u64 {
+        self.issued_at
+    }
+
+    /// Verifies the issuer's signature.
+    ///
+    /// A holder cannot attest their own fields, so attestations whose
+    /// issuer is the holder never verify.
+    pub fn verify(&self) -> bool {
+        use crate::crypto::{PublicKey, Signature};
+
+        if self.issuer_pk == self.subject_pk {
+            return false;
+        }
+        let public_key = PublicKey::from_bytes(self.issuer_pk);
+        public_key.verify(
+            &self.signable_bytes(),
+            &Signature::from_bytes(self.signature),
+        )
+    }
+
+    /// Checks whether this attestation is about `field_value` in the field
+    /// `field_id` of the contact `subject_id`.
+    ///
+    /// Does not check the signature; see `verify`.
+    pub fn matches(&self, subject_id: &str, field_id: &str, field_value: &str) -> bool {
+        self.subject_id() == subject_id
+            && self.field_id == field_id
+            && self.value_hash == hash_value(field_value)
+    }
+
+    /// Serializes the attestation to bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("Serialization should not fail")
+    }
+
+    /// Deserializes an attestation from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bincode::deserialize(bytes).ok()
+    }
+
+    /// Replaces the issuer name for testing purposes (to test tamper detection).
+    #[doc(hidden)]
+    pub fn set_issuer_name_for_testing(&mut self, issuer_name: &str) {
+        self.issuer_name = issuer_name.to_string();
+    }
+}
+
+fn hash_value(field_value: &str) -> [u8; 32] {
+    let hash = digest::digest(&digest::SHA256, field_value.as_bytes());
+    hash.as_ref()
+        .try_into()
+        .expect("SHA-256 digests are 32 bytes")
+}
@@ -7,6 +7,12 @@
 //! This module provides:
 //! - A registry of known social networks with profile URL templates
 //! - Crowd-sourced validation of social profile ownership
+//! - Attestations of field ownership by authoritative issuers
+
+#[cfg(feature = "testing")]
+pub mod issuer;
+#[cfg(not(feature = "testing"))]
+mod issuer;
 
 #[cfg(feature = "testing")]
 pub mod registry;
@@ -18,8 +24,9 @@ pub mod validation;
 #[cfg(not(feature = "testing"))]
 mod validation;
 
+pub use issuer::{IssuerAttestation, ISSUER_ATTESTATION_WEIGHT};
 pub use registry::{SocialNetwork, SocialNetworkRegistry};
 pub use validation::{
     calculate_trust_weight, check_sybil_resistance, filter_blocked_validations, ProfileValidation,
-    TrustLevel, ValidationStatus,
+    TrustLevel, TrustThresholds, ValidationBundle, ValidationRateLimiter, ValidationStatus,
 };
@@ -32,10 +32,13 @@
 //! - **Low Confidence** (1 validation): Yellow indicator
 //! - **Partial Confidence** (2-4 validations): Light green indicator
 //! - **High Confidence** (5+ validations): Green indicator
+//!
+//! Issuer attestations (see `issuer`) count as several validations each.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
+use super::issuer::{IssuerAttestation, ISSUER_ATTESTATION_WEIGHT};
 use crate::Identity;
 
 /// A validation record for a social profile field.
@@ -230,6 +233,24 @@ impl TrustLevel {
     }
 }
 
+/// User-configurable requirements for showing a field as trusted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustThresholds {
+    /// Independent validations required for a field to be trusted.
+    pub min_validations: usize,
+    /// Whether a validator verified in person counts as two validations.
+    pub in_person_counts_double: bool,
+}
+
+impl Default for TrustThresholds {
+    fn default() -> Self {
+        Self {
+            min_validations: 5,
+            in_person_counts_double: false,
+        }
+    }
+}
+
 /// Aggregated validation status for a social field.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationStatus {
@@ -243,6 +264,12 @@ pub struct ValidationStatus {
     pub validated_by_me: bool,
     /// Current field value (validations are invalidated if this changes).
     pub field_value: String,
+    /// Whether the validations meet the trust thresholds.
+    pub trusted: bool,
+    /// Combined weight of the counted validations and issuer attestations.
+    pub weight: usize,
+    /// Names of issuers attesting the current value.
+    pub issuers: Vec<String>,
 }
 
 impl ValidationStatus {
@@ -254,15 +281,23 @@ impl ValidationStatus {
             validator_ids: Vec::new(),
             validated_by_me: false,
             field_value: field_value.to_string(),
+            trusted: false,
+            weight: 0,
+            issuers: Vec::new(),
         }
     }
 
     /// Updates the status from a list of validations.
+    ///
+    /// `in_person_ids` lists validators whose fingerprint was verified in
+    /// person; they count double if `thresholds` says so.
     pub fn from_validations(
         validations: &[ProfileValidation],
         field_value: &str,
         my_id: Option<&str>,
         blocked_ids: &HashSet<String>,
+        in_person_ids: &HashSet<String>,
+        thresholds: &TrustThresholds,
     ) -> Self {
         // Filter to valid validations (matching field value, not blocked)
         let valid_validations: Vec<_> = validations
@@ -282,17 +317,78 @@ impl ValidationStatus {
             .map(|id| validator_ids.contains(&id.to_string()))
             .unwrap_or(false);
 
+        let weight: usize = validator_ids
+            .iter()
+            .map(|id| {
+                if thresholds.in_person_counts_double && in_person_ids.contains(id) {
+                    2
+                } else {
+                    1
+                }
+            })
+            .sum();
+        let trusted = count > 0 && weight >= thresholds.min_validations;
+
         Self {
             count,
             trust_level,
             validator_ids,
             validated_by_me,
             field_value: field_value.to_string(),
+            trusted,
+            weight,
+            issuers: Vec::new(),
         }
     }
 
+    /// Adds issuer attestations of the field `field_id` of `contact_id`.
+    ///
+    /// Only attestations with a valid signature that cover the current
+    /// value count, once per issuer. Each counts as
+    /// `ISSUER_ATTESTATION_WEIGHT` validations towards the trust level and
+    /// thresholds.
+    pub fn with_issuer_attestations(
+        mut self,
+        attestations: &[IssuerAttestation],
+        contact_id: &str,
+        field_id: &str,
+        thresholds: &TrustThresholds,
+    ) -> Self {
+        let mut issuer_ids = HashSet::new();
+        for attestation in attestations {
+            if attestation.matches(contact_id, field_id, &self.field_value)
+                && attestation.verify()
+                && issuer_ids.insert(attestation.issuer_id())
+            {
+                self.issuers.push(attestation.issuer_name().to_string());
+            }
+        }
+
+        let issuer_weight = self.issuers.len() * ISSUER_ATTESTATION_WEIGHT;
+        self.weight += issuer_weight;
+        self.trust_level = TrustLevel::from_count(self.count + issuer_weight);
+        self.trusted = self.weight > 0 && self.weight >= thresholds.min_validations;
+        self
+    }
+
     /// Formats a display string for the validation status.
     pub fn display(&self, known_names: &std::collections::HashMap<String, String>) -> String {
+        if self.issuers.is_empty() {
+            return self.display_validators(known_names);
+        }
+
+        let attested = format!("Attested by {}", self.issuers.join(", "));
+        if self.count == 0 {
+            attested
+        } else {
+            format!("{}; {}", attested, self.display_validators(known_names))
+        }
+    }
+
+    fn display_validators(
+        &self,
+        known_names: &std::collections::HashMap<String, String>,
+    ) -> String {
         if self.count == 0 {
             return "Not verified".to_string();
         }
@@ -430,8 +526,187 @@ pub fn calculate_trust_weight(
     }
 }
 
+// =============================================================================
+// Rate Limiting
+// =============================================================================
+
+/// Length of a validation rate limit window in seconds (one day).
+const VALIDATION_WINDOW_SECS: u64 = 86_400;
+
+/// Rate limiter for validation creation.
+///
+/// Caps how many validations one validator can create per day, so a
+/// compromised account cannot flood the trust graph with attestations.
+#[derive(Debug, Clone)]
+pub struct ValidationRateLimiter {
+    /// Maximum number of validations allowed per day.
+    pub max_validations_per_day: u32,
+}
+
+impl Default for ValidationRateLimiter {
+    fn default() -> Self {
+        Self {
+            max_validations_per_day: Self::MAX_VALIDATIONS_PER_DAY,
+        }
+    }
+}
+
+impl ValidationRateLimiter {
+    /// Highest daily limit allowed; also the default.
+    pub const MAX_VALIDATIONS_PER_DAY: u32 = 50;
+
+    /// Creates a new rate limiter with the specified max validations per day.
+    ///
+    /// Limits above `MAX_VALIDATIONS_PER_DAY` are capped to it.
+    pub fn new(max_validations_per_day: u32) -> Self {
+        Self {
+            max_validations_per_day: max_validations_per_day.min(Self::MAX_VALIDATIONS_PER_DAY),
+        }
+    }
+
+    /// Checks if a new validation is within the rate limit.
+    ///
+    /// # Arguments
+    /// * `count` - Number of validations already created in the current window
+    /// * `window_start` - Unix timestamp when the current window started
+    pub fn check_rate_limit(&self, count: u32, window_start: u64) -> bool {
+        Self::window_expired(window_start) || count < self.max_validations_per_day
+    }
+
+    /// Returns the `(count, window_start)` to store after creating a validation.
+    ///
+    /// Starts a new window if the current one has expired.
+    pub fn record(&self, count: u32, window_start: u64) -> (u32, u64) {
+        if Self::window_expired(window_start) {
+            (1, now_secs())
+        } else {
+            (count + 1, window_start)
+        }
+    }
+
+    fn window_expired(window_start: u64) -> bool {
+        now_secs().saturating_sub(window_start) >= VALIDATION_WINDOW_SECS
+    }
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs()
+}
+
+// =============================================================================
+// Validation Bundles
+// =============================================================================
+
+/// Domain separator mixed into validation bundle signatures.
+const BUNDLE_DOMAIN: &[u8] = b"VAUCHI_VALIDATION_BUNDLE";
+
+/// A validator's validations, signed as a whole so they can be shared.
+///
+/// Each validation keeps its own signature; the bundle signature also
+/// covers the set, so validations cannot be dropped or added in transit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationBundle {
+    /// The validator's Ed25519 public key.
+    validator_pk: [u8; 32],
+    /// Validations made by the validator.
+    validations: Vec<ProfileValidation>,
+    /// Unix timestamp when the bundle was created.
+    timestamp: u64,
+    /// Signature over (domain || validator_pk || validations || timestamp).
+    #[serde(with = "signature_serde")]
+    signature: [u8; 64],
+}
+
+impl ValidationBundle {
+    /// Creates a bundle of `validations` signed by `identity`.
+    pub fn create(identity: &Identity, validations: Vec<ProfileValidation>) -> Self {
+        let validator_pk = *identity.signing_public_key();
+        let timestamp = now_secs();
+        let data = Self::build_sign_data(&validator_pk, &validations, timestamp);
+        let signature = identity.sign(&data);
+
+        Self {
+            validator_pk,
+            validations,
+            timestamp,
+            signature: *signature.as_bytes(),
+        }
+    }
+
+    fn build_sign_data(
+        validator_pk: &[u8; 32],
+        validations: &[ProfileValidation],
+        timestamp: u64,
+    ) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(BUNDLE_DOMAIN);
+        data.extend_from_slice(validator_pk);
+        for validation in validations {
+            let signable = validation.signable_bytes();
+            data.extend_from_slice(&(signable.len() as u64).to_le_bytes());
+            data.extend_from_slice(&signable);
+            data.extend_from_slice(validation.signature());
+        }
+        data.extend_from_slice(&timestamp.to_le_bytes());
+        data
+    }
+
+    /// Returns the validator's public key.
+    pub fn validator_pk(&self) -> &[u8; 32] {
+        &self.validator_pk
+    }
+
+    /// Returns the bundled validations.
+    pub fn validations(&self) -> &[ProfileValidation] {
+        &self.validations
+    }
+
+    /// Returns the bundle timestamp.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Verifies the bundle signature and every validation in it.
+    ///
+    /// Each validation must name the bundle's validator and carry a valid
+    /// signature from that validator.
+    pub fn verify(&self) -> bool {
+        use crate::crypto::{PublicKey, Signature};
+
+        let data = Self::build_sign_data(&self.validator_pk, &self.validations, self.timestamp);
+        let public_key = PublicKey::from_bytes(self.validator_pk);
+        if !public_key.verify(&data, &Signature::from_bytes(self.signature)) {
+            return false;
+        }
+
+        let validator_id = hex::encode(self.validator_pk);
+        self.validations
+            .iter()
+            .all(|v| v.validator_id() == validator_id && v.verify(&self.validator_pk))
+    }
+
+    /// Serializes the bundle to bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("Serialization should not fail")
+    }
+
+    /// Deserializes a bundle from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bincode::deserialize(bytes).ok()
+    }
+
+    /// Replaces the validations for testing purposes (to test tamper detection).
+    #[doc(hidden)]
+    pub fn set_validations_for_testing(&mut self, validations: Vec<ProfileValidation>) {
+        self.validations = validations;
+    }
+}
+
 /// Custom serde for fixed-size signature arrays.
-mod signature_serde {
+pub(super) mod signature_serde {
     use serde::{Deserialize, Deserializer, Serializer};
 
     pub fn serialize<S>(bytes: &[u8; 64], serializer: S) -> Result<S::Ok, S::Error>
@@ -0,0 +1,94 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Address book storage operations.
+//!
+//! Entries imported from the platform's native address book. They have no
+//! shared key or ratchet and are kept apart from exchanged contacts; they
+//! only serve to suggest who to invite.
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+use super::{Storage, StorageError};
+
+/// A contact imported from the native address book.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressBookEntry {
+    /// Platform identifier of the entry (stable across imports).
+    pub native_id: String,
+    /// Display name.
+    pub display_name: String,
+    /// Email addresses.
+    #[serde(default)]
+    pub emails: Vec<String>,
+    /// Phone numbers.
+    #[serde(default)]
+    pub phones: Vec<String>,
+    /// Social network handles.
+    #[serde(default)]
+    pub social_handles: Vec<String>,
+}
+
+impl Storage {
+    // === Address Book Operations ===
+
+    /// Saves address book entries, replacing earlier imports with the same native ID.
+    ///
+    /// Entries are encrypted, since they hold the same kind of data as cards.
+    pub fn save_address_book_entries(
+        &self,
+        entries: &[AddressBookEntry],
+    ) -> Result<usize, StorageError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        for entry in entries {
+            let entry_json = serde_json::to_vec(entry)
+                .map_err(|e| StorageError::Serialization(e.to_string()))?;
+            let entry_encrypted = crate::crypto::encrypt(&self.encryption_key, &entry_json)
+                .map_err(|e| StorageError::Encryption(e.to_string()))?;
+
+            self.conn.execute(
+                "INSERT OR REPLACE INTO address_book (native_id, entry_encrypted, imported_at)
+                 VALUES (?1, ?2, ?3)",
+                params![entry.native_id, entry_encrypted, now as i64],
+            )?;
+        }
+
+        Ok(entries.len())
+    }
+
+    /// Lists all address book entries, ordered by display name.
+    pub fn list_address_book_entries(&self) -> Result<Vec<AddressBookEntry>, StorageError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT entry_encrypted FROM address_book")?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, Vec<u8>>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut entries = Vec::with_capacity(rows.len());
+        for entry_encrypted in rows {
+            let entry_json = crate::crypto::decrypt(&self.encryption_key, &entry_encrypted)
+                .map_err(|e| StorageError::Encryption(e.to_string()))?;
+            let entry: AddressBookEntry = serde_json::from_slice(&entry_json)
+                .map_err(|e| StorageError::Serialization(e.to_string()))?;
+            entries.push(entry);
+        }
+        entries.sort_by(|a, b| a.display_name.cmp(&b.display_name));
+
+        Ok(entries)
+    }
+
+    /// Deletes all address book entries.
+    ///
+    /// Returns the number of deleted entries.
+    pub fn clear_address_book(&self) -> Result<usize, StorageError> {
+        let deleted = self.conn.execute("DELETE FROM address_book", [])?;
+        Ok(deleted)
+    }
+}
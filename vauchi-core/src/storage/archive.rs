@@ -0,0 +1,313 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Full database archive for device migration.
+//!
+//! Unlike the identity backup, the archive carries every table: contacts,
+//! ratchet states, labels, queues and so on. Values in `*_encrypted`
+//! columns are decrypted on export and re-encrypted under the target
+//! storage key on import, and the whole dump is sealed with a key derived
+//! from a password, so the archive does not depend on the source device's
+//! storage key.
+
+use ring::rand::SystemRandom;
+use rusqlite::types::{Value, ValueRef};
+use serde::{Deserialize, Serialize};
+
+use super::{Storage, StorageError};
+use crate::crypto::{decrypt, derive_key_argon2id, encrypt};
+
+/// Archive format version (Argon2id + XChaCha20-Poly1305 over bincode).
+const ARCHIVE_VERSION: u8 = 1;
+
+/// Length of the Argon2id salt prepended to the ciphertext.
+const ARCHIVE_SALT_LEN: usize = 16;
+
+/// Suffix of columns holding values encrypted with the storage key.
+pub(super) const ENCRYPTED_COLUMN_SUFFIX: &str = "_encrypted";
+
+/// A single SQLite value in the archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) enum ArchiveValue {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+/// The rows of one table.
+#[derive(Debug, Serialize, Deserialize)]
+struct ArchiveTable {
+    name: String,
+    columns: Vec<String>,
+    rows: Vec<Vec<ArchiveValue>>,
+}
+
+/// Plaintext contents of an archive.
+#[derive(Debug, Serialize, Deserialize)]
+struct ArchiveDump {
+    schema_version: u32,
+    tables: Vec<ArchiveTable>,
+}
+
+impl From<ValueRef<'_>> for ArchiveValue {
+    fn from(value: ValueRef<'_>) -> Self {
+        match value {
+            ValueRef::Null => ArchiveValue::Null,
+            ValueRef::Integer(i) => ArchiveValue::Integer(i),
+            ValueRef::Real(r) => ArchiveValue::Real(r),
+            ValueRef::Text(t) => ArchiveValue::Text(String::from_utf8_lossy(t).into_owned()),
+            ValueRef::Blob(b) => ArchiveValue::Blob(b.to_vec()),
+        }
+    }
+}
+
+impl From<ArchiveValue> for Value {
+    fn from(value: ArchiveValue) -> Self {
+        match value {
+            ArchiveValue::Null => Value::Null,
+            ArchiveValue::Integer(i) => Value::Integer(i),
+            ArchiveValue::Real(r) => Value::Real(r),
+            ArchiveValue::Text(t) => Value::Text(t),
+            ArchiveValue::Blob(b) => Value::Blob(b),
+        }
+    }
+}
+
+/// Table and column names come from the archive, so only plain identifiers
+/// are accepted before they are spliced into SQL.
+pub(super) fn is_identifier(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl Storage {
+    // === Archive Operations ===
+
+    /// Exports the whole database as an archive encrypted with `password`.
+    ///
+    /// The password must pass the same strength check as identity backups.
+    /// Rows are sealed in a stable order, so exporting unchanged data twice
+    /// seals identical contents, although each archive is encrypted with a
+    /// fresh salt and nonce.
+    pub fn export_encrypted_archive(&self, password: &str) -> Result<Vec<u8>, StorageError> {
+        crate::identity::password::validate_password(password)
+            .map_err(|e| StorageError::InvalidData(e.to_string()))?;
+
+        let dump = ArchiveDump {
+            schema_version: self.schema_version()?,
+            tables: self.dump_tables()?,
+        };
+        let plaintext =
+            bincode::serialize(&dump).map_err(|e| StorageError::Serialization(e.to_string()))?;
+
+        let rng = SystemRandom::new();
+        let salt = ring::rand::generate::<[u8; ARCHIVE_SALT_LEN]>(&rng)
+            .map_err(|_| StorageError::Encryption("Failed to generate salt".to_string()))?
+            .expose();
+        let key = derive_key_argon2id(password.as_bytes(), &salt)
+            .map_err(|e| StorageError::Encryption(e.to_string()))?;
+        let ciphertext =
+            encrypt(&key, &plaintext).map_err(|e| StorageError::Encryption(e.to_string()))?;
+
+        let mut archive = Vec::with_capacity(1 + ARCHIVE_SALT_LEN + ciphertext.len());
+        archive.push(ARCHIVE_VERSION);
+        archive.extend_from_slice(&salt);
+        archive.extend_from_slice(&ciphertext);
+        Ok(archive)
+    }
+
+    /// Restores an archive produced by [`Storage::export_encrypted_archive`].
+    ///
+    /// Intended for a fresh database: fails with `AlreadyExists` if an
+    /// identity is already stored. Every table in the archive replaces the
+    /// local table's contents, all within one transaction. Archives from a
+    /// newer schema version are rejected.
+    pub fn import_encrypted_archive(
+        &self,
+        data: &[u8],
+        password: &str,
+    ) -> Result<(), StorageError> {
+        if data.len() < 1 + ARCHIVE_SALT_LEN || data[0] != ARCHIVE_VERSION {
+            return Err(StorageError::InvalidData(
+                "Not a recognized archive".to_string(),
+            ));
+        }
+
+        let salt = &data[1..1 + ARCHIVE_SALT_LEN];
+        let key = derive_key_argon2id(password.as_bytes(), salt)
+            .map_err(|e| StorageError::Encryption(e.to_string()))?;
+        let plaintext = decrypt(&key, &data[1 + ARCHIVE_SALT_LEN..]).map_err(|_| {
+            StorageError::Encryption("Invalid archive or wrong password".to_string())
+        })?;
+        let dump: ArchiveDump = bincode::deserialize(&plaintext)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+
+        let current_version = self.schema_version()?;
+        if dump.schema_version > current_version {
+            return Err(StorageError::InvalidData(format!(
+                "Archive schema v{} is newer than this database (v{})",
+                dump.schema_version, current_version
+            )));
+        }
+
+        if self.load_identity()?.is_some() {
+            return Err(StorageError::AlreadyExists("Identity".to_string()));
+        }
+
+        let tx = self.transaction()?;
+        // Tables are restored by name, not dependency order
+        self.conn.execute_batch("PRAGMA defer_foreign_keys = ON")?;
+        for table in dump.tables {
+            self.restore_table(table)?;
+        }
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Reads every user table, decrypting `*_encrypted` columns.
+    fn dump_tables(&self) -> Result<Vec<ArchiveTable>, StorageError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT name FROM sqlite_master
+             WHERE type = 'table' AND name NOT LIKE 'sqlite_%' AND name != 'schema_version'
+             ORDER BY name",
+        )?;
+        let names = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut tables = Vec::with_capacity(names.len());
+        for name in names {
+            let mut stmt = self.conn.prepare(&format!(
+                "SELECT * FROM \"{}\" ORDER BY {}",
+                name,
+                self.stable_order(&name)?
+            ))?;
+            let columns: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+
+            let mut rows = Vec::new();
+            let mut query = stmt.query([])?;
+            while let Some(row) = query.next()? {
+                let mut values = Vec::with_capacity(columns.len());
+                for (i, column) in columns.iter().enumerate() {
+                    let value = ArchiveValue::from(row.get_ref(i)?);
+                    values.push(match value {
+                        ArchiveValue::Blob(b) if column.ends_with(ENCRYPTED_COLUMN_SUFFIX) => {
+                            ArchiveValue::Blob(
+                                decrypt(&self.encryption_key, &b)
+                                    .map_err(|e| StorageError::Encryption(e.to_string()))?,
+                            )
+                        }
+                        other => other,
+                    });
+                }
+                rows.push(values);
+            }
+
+            tables.push(ArchiveTable {
+                name,
+                columns,
+                rows,
+            });
+        }
+
+        Ok(tables)
+    }
+
+    /// Builds an `ORDER BY` list that sorts a table's rows the same way on
+    /// every export: by primary key, then by the remaining columns.
+    ///
+    /// Encrypted columns are left out, as their ciphertext changes each
+    /// time a row is saved.
+    fn stable_order(&self, table: &str) -> Result<String, StorageError> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!("PRAGMA table_info(\"{}\")", table))?;
+        let mut columns = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(1)?, row.get::<_, i64>(5)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        columns.retain(|(name, _)| !name.ends_with(ENCRYPTED_COLUMN_SUFFIX));
+        // Primary key columns first, in key order; pk is 0 for the others
+        columns.sort_by_key(|(_, pk)| if *pk > 0 { *pk } else { i64::MAX });
+
+        if columns.is_empty() {
+            return Ok("rowid".to_string());
+        }
+        Ok(columns
+            .iter()
+            .map(|(name, _)| format!("\"{}\"", name))
+            .collect::<Vec<_>>()
+            .join(", "))
+    }
+
+    /// Replaces a table's contents with archived rows, re-encrypting
+    /// `*_encrypted` columns under this storage's key.
+    fn restore_table(&self, table: ArchiveTable) -> Result<(), StorageError> {
+        if !is_identifier(&table.name) || !table.columns.iter().all(|c| is_identifier(c)) {
+            return Err(StorageError::InvalidData(format!(
+                "Invalid table in archive: {}",
+                table.name
+            )));
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare(&format!("PRAGMA table_info(\"{}\")", table.name))?;
+        let local_columns = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(missing) = table.columns.iter().find(|c| !local_columns.contains(c)) {
+            return Err(StorageError::InvalidData(format!(
+                "Unknown column in archive: {}.{}",
+                table.name, missing
+            )));
+        }
+
+        self.conn
+            .execute(&format!("DELETE FROM \"{}\"", table.name), [])?;
+
+        let column_list = table
+            .columns
+            .iter()
+            .map(|c| format!("\"{}\"", c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let placeholders = (1..=table.columns.len())
+            .map(|i| format!("?{}", i))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut insert = self.conn.prepare(&format!(
+            "INSERT INTO \"{}\" ({}) VALUES ({})",
+            table.name, column_list, placeholders
+        ))?;
+
+        for row in table.rows {
+            if row.len() != table.columns.len() {
+                return Err(StorageError::InvalidData(format!(
+                    "Malformed row in archive table {}",
+                    table.name
+                )));
+            }
+
+            let mut values = Vec::with_capacity(row.len());
+            for (value, column) in row.into_iter().zip(&table.columns) {
+                values.push(match value {
+                    ArchiveValue::Blob(b) if column.ends_with(ENCRYPTED_COLUMN_SUFFIX) => {
+                        Value::Blob(
+                            encrypt(&self.encryption_key, &b)
+                                .map_err(|e| StorageError::Encryption(e.to_string()))?,
+                        )
+                    }
+                    other => Value::from(other),
+                });
+            }
+            insert.execute(rusqlite::params_from_iter(values))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Clock watermark storage operations.
+//!
+//! Keeps the latest local time ever observed. A device clock that later
+//! reads earlier than this has been set back, and anything it timestamps
+//! could sort before artifacts it already issued.
+
+use rusqlite::params;
+
+use super::{Storage, StorageError};
+
+impl Storage {
+    /// Records `now` as observed and checks it against the watermark.
+    ///
+    /// Returns the watermark if `now` is more than `tolerance` seconds
+    /// behind it; the watermark is then left unchanged. Otherwise the
+    /// watermark advances to `now` (if later) and `None` is returned.
+    pub fn observe_clock(&self, now: u64, tolerance: u64) -> Result<Option<u64>, StorageError> {
+        if let Some(max_seen) = self.load_clock_watermark()? {
+            if now.saturating_add(tolerance) < max_seen {
+                return Ok(Some(max_seen));
+            }
+        }
+
+        self.conn.execute(
+            "INSERT INTO clock_watermark (id, max_seen) VALUES (1, ?1)
+             ON CONFLICT(id) DO UPDATE SET max_seen = MAX(max_seen, excluded.max_seen)",
+            params![now as i64],
+        )?;
+
+        Ok(None)
+    }
+
+    /// Loads the latest local time observed, if any.
+    pub fn load_clock_watermark(&self) -> Result<Option<u64>, StorageError> {
+        let result = self.conn.query_row(
+            "SELECT max_seen FROM clock_watermark WHERE id = 1",
+            [],
+            |row| row.get::<_, i64>(0),
+        );
+
+        match result {
+            Ok(max_seen) => Ok(Some(max_seen as u64)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(StorageError::Database(e)),
+        }
+    }
+
+    /// Replaces the watermark with `now`, accepting the current clock.
+    pub fn reset_clock_watermark(&self, now: u64) -> Result<(), StorageError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO clock_watermark (id, max_seen) VALUES (1, ?1)",
+            params![now as i64],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Single-contact export for handing a contact to another device or person.
+//!
+//! The export carries the contact's public key, card and personal note,
+//! sealed with a key derived from a password. It never carries the shared
+//! key or ratchet state, so an imported contact is a reference only: it
+//! shows the card but does not sync until a real exchange replaces it.
+
+use ring::rand::SystemRandom;
+use serde::{Deserialize, Serialize};
+
+use super::{Storage, StorageError};
+use crate::contact::Contact;
+use crate::contact_card::ContactCard;
+use crate::crypto::{decrypt, derive_key_argon2id, encrypt};
+
+/// Export format version (Argon2id + XChaCha20-Poly1305 over JSON).
+const CONTACT_EXPORT_VERSION: u8 = 1;
+
+/// Length of the Argon2id salt prepended to the ciphertext.
+const CONTACT_EXPORT_SALT_LEN: usize = 16;
+
+/// Plaintext contents of a contact export.
+#[derive(Debug, Serialize, Deserialize)]
+struct ContactExport {
+    public_key: [u8; 32],
+    card: ContactCard,
+    note: Option<String>,
+}
+
+impl Storage {
+    // === Single Contact Export ===
+
+    /// Exports one contact, encrypted with `password`.
+    ///
+    /// The password must pass the same strength check as identity backups.
+    pub fn export_contact(&self, id: &str, password: &str) -> Result<Vec<u8>, StorageError> {
+        crate::identity::password::validate_password(password)
+            .map_err(|e| StorageError::InvalidData(e.to_string()))?;
+
+        let contact = self
+            .load_contact(id)?
+            .ok_or_else(|| StorageError::NotFound(format!("Contact not found: {}", id)))?;
+        let note = match self.load_personal_notes(id)? {
+            Some(encrypted) => Some(
+                String::from_utf8(
+                    decrypt(&self.encryption_key, &encrypted)
+                        .map_err(|e| StorageError::Encryption(e.to_string()))?,
+                )
+                .map_err(|e| StorageError::Serialization(e.to_string()))?,
+            ),
+            None => None,
+        };
+        let export = ContactExport {
+            public_key: *contact.public_key(),
+            card: contact.card().clone(),
+            note,
+        };
+        let plaintext =
+            serde_json::to_vec(&export).map_err(|e| StorageError::Serialization(e.to_string()))?;
+
+        let rng = SystemRandom::new();
+        let salt = ring::rand::generate::<[u8; CONTACT_EXPORT_SALT_LEN]>(&rng)
+            .map_err(|_| StorageError::Encryption("Failed to generate salt".to_string()))?
+            .expose();
+        let key = derive_key_argon2id(password.as_bytes(), &salt)
+            .map_err(|e| StorageError::Encryption(e.to_string()))?;
+        let ciphertext =
+            encrypt(&key, &plaintext).map_err(|e| StorageError::Encryption(e.to_string()))?;
+
+        let mut data = Vec::with_capacity(1 + CONTACT_EXPORT_SALT_LEN + ciphertext.len());
+        data.push(CONTACT_EXPORT_VERSION);
+        data.extend_from_slice(&salt);
+        data.extend_from_slice(&ciphertext);
+        Ok(data)
+    }
+
+    /// Imports a contact produced by [`Storage::export_contact`] as a
+    /// reference contact.
+    ///
+    /// Fails with `AlreadyExists` if the contact is already stored, and
+    /// with `InvalidData` if the export is of `own_public_key`.
+    pub fn import_contact(
+        &self,
+        data: &[u8],
+        password: &str,
+        own_public_key: &[u8; 32],
+    ) -> Result<Contact, StorageError> {
+        if data.len() < 1 + CONTACT_EXPORT_SALT_LEN || data[0] != CONTACT_EXPORT_VERSION {
+            return Err(StorageError::InvalidData(
+                "Not a recognized contact export".to_string(),
+            ));
+        }
+
+        let salt = &data[1..1 + CONTACT_EXPORT_SALT_LEN];
+        let key = derive_key_argon2id(password.as_bytes(), salt)
+            .map_err(|e| StorageError::Encryption(e.to_string()))?;
+        let plaintext = decrypt(&key, &data[1 + CONTACT_EXPORT_SALT_LEN..]).map_err(|_| {
+            StorageError::Encryption("Invalid contact export or wrong password".to_string())
+        })?;
+        let export: ContactExport = serde_json::from_slice(&plaintext)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+
+        crate::contact::check_not_self(&export.public_key, own_public_key)
+            .map_err(|e| StorageError::InvalidData(e.to_string()))?;
+        let contact = Contact::from_reference(export.public_key, export.card);
+        if self.load_contact(contact.id())?.is_some() {
+            return Err(StorageError::AlreadyExists(format!(
+                "Contact {}",
+                contact.id()
+            )));
+        }
+
+        let tx = self.transaction()?;
+        self.save_contact(&contact)?;
+        if let Some(note) = export.note {
+            let encrypted = encrypt(&self.encryption_key, note.as_bytes())
+                .map_err(|e| StorageError::Encryption(e.to_string()))?;
+            self.save_personal_notes(contact.id(), &encrypted)?;
+        }
+        tx.commit()?;
+
+        Ok(contact)
+    }
+}
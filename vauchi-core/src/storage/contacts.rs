@@ -10,6 +10,13 @@ use super::{Storage, StorageError};
 use crate::contact::Contact;
 use crate::contact_card::ContactCard;
 use crate::crypto::SymmetricKey;
+use crate::sync::CardDelta;
+
+/// Default maximum number of contacts.
+///
+/// A safety valve against exchange floods filling the disk, set well above
+/// any realistic address book.
+pub const DEFAULT_MAX_CONTACTS: usize = 10_000;
 
 /// Internal struct for database row data.
 #[allow(dead_code)] // Fields are used via destructuring in row_to_contact
@@ -24,14 +31,30 @@ pub(super) struct ContactRow {
     pub fingerprint_verified: i32,
     pub blocked: i32,
     pub hidden: i32,
+    /// Pinned flag (stored in the `favorite` column).
     pub favorite: i32,
+    pub muted: i32,
+    pub is_reference: i32,
+    pub accepts_padding: i32,
+    pub verified_at: Option<i64>,
+    pub verified_by: Option<String>,
 }
 
 impl Storage {
     // === Contact Operations ===
 
     /// Saves a contact to storage.
+    ///
+    /// Updating an existing contact always succeeds; adding a new one fails
+    /// with `StorageError::ContactLimitReached` once the contact limit is hit.
     pub fn save_contact(&self, contact: &Contact) -> Result<(), StorageError> {
+        if !self.contact_exists(contact.id())? {
+            let limit = self.get_contact_limit()?;
+            if self.count_contacts()? >= limit {
+                return Err(StorageError::ContactLimitReached(limit));
+            }
+        }
+
         // Serialize and encrypt the contact card
         let card_json = serde_json::to_vec(contact.card())
             .map_err(|e| StorageError::Serialization(e.to_string()))?;
@@ -51,8 +74,10 @@ impl Storage {
             "INSERT OR REPLACE INTO contacts
              (id, public_key, display_name, card_encrypted, shared_key_encrypted,
               visibility_rules_json, exchange_timestamp, fingerprint_verified, last_sync_at,
-              blocked, hidden, favorite)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+              blocked, hidden, favorite, verified_at, verified_by, muted, is_reference,
+              accepts_padding)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16,
+                     ?17)",
             params![
                 contact.id(),
                 contact.public_key().as_slice(),
@@ -65,7 +90,12 @@ impl Storage {
                 Option::<i64>::None,
                 contact.is_blocked() as i32,
                 contact.is_hidden() as i32,
-                0i32, // favorite: not yet on Contact struct, default to false
+                contact.is_pinned() as i32,
+                contact.verified_at().map(|t| t as i64),
+                contact.verified_by(),
+                contact.is_muted() as i32,
+                contact.is_reference() as i32,
+                contact.accepts_padding() as i32,
             ],
         )?;
 
@@ -77,7 +107,8 @@ impl Storage {
         let mut stmt = self.conn.prepare(
             "SELECT id, public_key, display_name, card_encrypted, shared_key_encrypted,
                     visibility_rules_json, exchange_timestamp, fingerprint_verified,
-                    blocked, hidden, favorite
+                    blocked, hidden, favorite, verified_at, verified_by, muted, is_reference,
+                    accepts_padding
              FROM contacts WHERE id = ?1",
         )?;
 
@@ -94,6 +125,11 @@ impl Storage {
                 blocked: row.get(8)?,
                 hidden: row.get(9)?,
                 favorite: row.get(10)?,
+                verified_at: row.get(11)?,
+                verified_by: row.get(12)?,
+                muted: row.get(13)?,
+                is_reference: row.get(14)?,
+                accepts_padding: row.get::<_, Option<i32>>(15)?.unwrap_or(0),
             })
         });
 
@@ -104,13 +140,14 @@ impl Storage {
         }
     }
 
-    /// Lists all contacts.
+    /// Lists all contacts, pinned contacts first, then by display name.
     pub fn list_contacts(&self) -> Result<Vec<Contact>, StorageError> {
         let mut stmt = self.conn.prepare(
             "SELECT id, public_key, display_name, card_encrypted, shared_key_encrypted,
                     visibility_rules_json, exchange_timestamp, fingerprint_verified,
-                    blocked, hidden, favorite
-             FROM contacts ORDER BY display_name",
+                    blocked, hidden, favorite, verified_at, verified_by, muted, is_reference,
+                    accepts_padding
+             FROM contacts ORDER BY favorite DESC, display_name",
         )?;
 
         let rows = stmt.query_map([], |row| {
@@ -126,6 +163,11 @@ impl Storage {
                 blocked: row.get(8)?,
                 hidden: row.get(9)?,
                 favorite: row.get(10)?,
+                verified_at: row.get(11)?,
+                verified_by: row.get(12)?,
+                muted: row.get(13)?,
+                is_reference: row.get(14)?,
+                accepts_padding: row.get::<_, Option<i32>>(15)?.unwrap_or(0),
             })
         })?;
 
@@ -139,19 +181,143 @@ impl Storage {
     }
 
     /// Deletes a contact by ID.
+    ///
+    /// Leaves a tombstone retaining the contact and its ratchet, so the
+    /// deletion can be undone with `restore_deleted_contact` and a later
+    /// exchange with the same public key can be recognized as a reconnection.
+    /// Tombstones past their retention period are purged first.
     pub fn delete_contact(&self, id: &str) -> Result<bool, StorageError> {
+        let tx = self.transaction()?;
+        self.purge_expired_contact_tombstones()?;
+        self.record_contact_tombstone(id)?;
+
         // Also delete associated ratchet state
         self.conn.execute(
             "DELETE FROM contact_ratchets WHERE contact_id = ?1",
             params![id],
         )?;
+        self.delete_contact_tags(id)?;
+        self.delete_pending_exchange(id)?;
+        self.delete_pending_rekey(id)?;
+        self.delete_expected_confirmation(id)?;
 
         let rows_affected = self
             .conn
             .execute("DELETE FROM contacts WHERE id = ?1", params![id])?;
+        tx.commit()?;
         Ok(rows_affected > 0)
     }
 
+    /// Moves a stored contact to its new ID after its public key changed.
+    ///
+    /// `contact` carries the new key and ID; `old_id` is where it is stored
+    /// now. Its ratchet, queued updates, labels, overrides and tags move
+    /// with it, in one transaction.
+    pub fn move_contact(&self, old_id: &str, contact: &Contact) -> Result<(), StorageError> {
+        let new_id = contact.id();
+        let tx = self.transaction()?;
+
+        if new_id != old_id {
+            if self.load_contact(old_id)?.is_none() {
+                return Err(StorageError::NotFound(format!("Contact {}", old_id)));
+            }
+            if self.load_contact(new_id)?.is_some() {
+                return Err(StorageError::AlreadyExists(format!("Contact {}", new_id)));
+            }
+
+            // The ratchet references the contact; check once both have moved
+            self.conn.execute_batch("PRAGMA defer_foreign_keys = ON")?;
+            self.conn.execute(
+                "UPDATE contacts SET id = ?1 WHERE id = ?2",
+                params![new_id, old_id],
+            )?;
+            for table in [
+                "contact_ratchets",
+                "pending_updates",
+                "contact_sync_timestamps",
+                "contact_visibility_overrides",
+                "contact_tags",
+                "field_validations",
+                "replay_nonces",
+                "pending_rekeys",
+            ] {
+                self.conn.execute(
+                    &format!("UPDATE {} SET contact_id = ?1 WHERE contact_id = ?2", table),
+                    params![new_id, old_id],
+                )?;
+            }
+            for mut label in self.load_all_labels()? {
+                if label.remove_contact(old_id) {
+                    label.add_contact(new_id);
+                    self.save_label(&label)?;
+                }
+            }
+        }
+
+        self.save_contact(contact)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Lists contacts whose fingerprint is (or is not) verified, pinned
+    /// first, then by display name.
+    pub fn list_contacts_by_verification(
+        &self,
+        verified: bool,
+    ) -> Result<Vec<Contact>, StorageError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id FROM contacts WHERE fingerprint_verified = ?1
+             ORDER BY favorite DESC, display_name",
+        )?;
+        let ids = stmt
+            .query_map(params![verified], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+
+        let mut contacts = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(contact) = self.load_contact(&id)? {
+                contacts.push(contact);
+            }
+        }
+        Ok(contacts)
+    }
+
+    /// Lists contacts that were never verified, followed by those whose
+    /// verification is older than `older_than_days`.
+    pub fn contacts_needing_attention(
+        &self,
+        older_than_days: u32,
+    ) -> Result<Vec<Contact>, StorageError> {
+        let mut contacts = self.list_contacts_by_verification(false)?;
+        contacts.extend(self.contacts_needing_reverification(older_than_days)?);
+        Ok(contacts)
+    }
+
+    /// Lists verified contacts whose last verification is older than `older_than_days`.
+    ///
+    /// Contacts verified before verification dates were recorded are included,
+    /// since their verification age is unknown. Unverified contacts are not.
+    pub fn contacts_needing_reverification(
+        &self,
+        older_than_days: u32,
+    ) -> Result<Vec<Contact>, StorageError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system time before UNIX epoch")
+            .as_secs();
+        let cutoff = now.saturating_sub(u64::from(older_than_days) * 86_400);
+
+        Ok(self
+            .list_contacts()?
+            .into_iter()
+            .filter(|c| c.is_fingerprint_verified())
+            .filter(|c| match c.verified_at() {
+                Some(verified_at) => verified_at < cutoff,
+                None => true,
+            })
+            .collect())
+    }
+
     // === Personal Notes Operations ===
 
     /// Saves encrypted personal notes for a contact.
@@ -257,21 +423,43 @@ impl Storage {
     /// Returns the maximum number of contacts allowed.
     ///
     /// Reads from the `contact_limits` table (created by migration v4).
-    /// Returns 500 as the default if no limit has been configured.
+    /// Returns [`DEFAULT_MAX_CONTACTS`] if no limit has been configured.
     pub fn get_contact_limit(&self) -> Result<usize, StorageError> {
         let result = self.conn.query_row(
             "SELECT max_contacts FROM contact_limits WHERE id = 1",
             [],
-            |row| row.get::<_, i64>(0),
+            |row| row.get::<_, Option<i64>>(0),
         );
 
         match result {
-            Ok(limit) => Ok(limit as usize),
-            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(500), // Default limit
+            Ok(Some(limit)) => Ok(limit as usize),
+            Ok(None) | Err(rusqlite::Error::QueryReturnedNoRows) => Ok(DEFAULT_MAX_CONTACTS),
             Err(e) => Err(StorageError::Database(e)),
         }
     }
 
+    /// Sets the maximum number of contacts allowed.
+    ///
+    /// Existing contacts are kept when the limit is lowered below the
+    /// current count; only new contacts are rejected.
+    pub fn set_contact_limit(&self, limit: usize) -> Result<(), StorageError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO contact_limits (id, max_contacts) VALUES (1, ?1)",
+            params![limit as i64],
+        )?;
+        Ok(())
+    }
+
+    /// Returns true if a contact with the given ID is stored.
+    fn contact_exists(&self, id: &str) -> Result<bool, StorageError> {
+        let exists: bool = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM contacts WHERE id = ?1)",
+            params![id],
+            |row| row.get(0),
+        )?;
+        Ok(exists)
+    }
+
     /// Converts a database row to a Contact.
     pub(super) fn row_to_contact(&self, row: ContactRow) -> Result<Contact, StorageError> {
         // Decrypt card
@@ -303,7 +491,7 @@ impl Storage {
         };
 
         // Create contact with all persisted fields
-        let contact = Contact::from_sync_data_full(
+        let mut contact = Contact::from_sync_data_full(
             public_key,
             card,
             shared_key,
@@ -313,6 +501,21 @@ impl Storage {
             row.hidden != 0,
             row.blocked != 0,
         );
+        if let Some(verified_at) = row
+            .verified_at
+            .filter(|_| contact.is_fingerprint_verified())
+        {
+            match row.verified_by {
+                Some(attester_id) => {
+                    contact.mark_fingerprint_verified_by(&attester_id, verified_at as u64)
+                }
+                None => contact.mark_fingerprint_verified_at(verified_at as u64),
+            }
+        }
+        contact.set_pinned(row.favorite != 0);
+        contact.set_muted(row.muted != 0);
+        contact.set_reference(row.is_reference != 0);
+        contact.set_accepts_padding(row.accepts_padding != 0);
 
         Ok(contact)
     }
@@ -356,6 +559,76 @@ impl Storage {
         }
     }
 
+    /// Saves unpublished edits to the own card.
+    ///
+    /// The draft is kept apart from the live card, so syncs keep sending
+    /// the live card until the draft is published.
+    pub fn save_card_draft(&self, card: &ContactCard) -> Result<(), StorageError> {
+        let card_json =
+            serde_json::to_string(card).map_err(|e| StorageError::Serialization(e.to_string()))?;
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system time before UNIX epoch")
+            .as_secs();
+
+        self.conn.execute(
+            "INSERT OR REPLACE INTO own_card_draft (id, card_json, updated_at) VALUES (1, ?1, ?2)",
+            params![card_json, now as i64],
+        )?;
+
+        Ok(())
+    }
+
+    /// Loads the own-card draft, if any.
+    pub fn load_card_draft(&self) -> Result<Option<ContactCard>, StorageError> {
+        let result = self.conn.query_row(
+            "SELECT card_json FROM own_card_draft WHERE id = 1",
+            [],
+            |row| row.get::<_, String>(0),
+        );
+
+        match result {
+            Ok(json) => {
+                let card = serde_json::from_str(&json)
+                    .map_err(|e| StorageError::Serialization(e.to_string()))?;
+                Ok(Some(card))
+            }
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(StorageError::Database(e)),
+        }
+    }
+
+    /// Deletes the own-card draft.
+    ///
+    /// Returns true if there was one.
+    pub fn discard_card_draft(&self) -> Result<bool, StorageError> {
+        let rows_affected = self
+            .conn
+            .execute("DELETE FROM own_card_draft WHERE id = 1", [])?;
+        Ok(rows_affected > 0)
+    }
+
+    /// Replaces the own card with the draft.
+    ///
+    /// Returns the unsigned delta from the previous live card to the
+    /// published one, for broadcasting, or `None` if there was no draft.
+    pub fn publish_card_draft(&self) -> Result<Option<CardDelta>, StorageError> {
+        let Some(draft) = self.load_card_draft()? else {
+            return Ok(None);
+        };
+        let live = self
+            .load_own_card()?
+            .ok_or_else(|| StorageError::NotFound("Own card".to_string()))?;
+
+        let tx = self.transaction()?;
+        self.save_own_card(&draft)?;
+        self.discard_card_draft()?;
+        tx.commit()?;
+
+        Ok(Some(live.diff(&draft)))
+    }
+
     // === Sync Timestamp Operations ===
 
     /// Sets the last sync timestamp for a contact.
@@ -8,6 +8,10 @@ use rusqlite::params;
 
 use super::error::{DeliveryRecord, DeliveryStatus};
 use super::{Storage, StorageError};
+#[cfg(any(feature = "network-native-tls", feature = "network-rustls"))]
+use crate::crypto::PublicKey;
+#[cfg(any(feature = "network-native-tls", feature = "network-rustls"))]
+use crate::network::simple_message::SimpleDeliveryReceipt;
 
 impl Storage {
     // === Delivery Records Operations ===
@@ -184,6 +188,94 @@ impl Storage {
     }
 }
 
+// === Delivery Receipts ===
+
+#[cfg(any(feature = "network-native-tls", feature = "network-rustls"))]
+impl Storage {
+    /// Stores a relay-signed delivery receipt.
+    ///
+    /// The receipt must verify against `relay_public_key`, the relay key the
+    /// caller pinned, so a forged receipt can never take a message's slot.
+    /// The first valid receipt for a message wins; returns false for later
+    /// ones.
+    ///
+    /// # Errors
+    /// Fails with `InvalidData` if the signature does not verify.
+    pub fn save_delivery_receipt(
+        &self,
+        receipt: &SimpleDeliveryReceipt,
+        relay_public_key: &PublicKey,
+        received_at: u64,
+    ) -> Result<bool, StorageError> {
+        if !receipt.verify(relay_public_key) {
+            return Err(StorageError::InvalidData(
+                "Delivery receipt signature invalid".to_string(),
+            ));
+        }
+
+        let rows_affected = self.conn.execute(
+            "INSERT OR IGNORE INTO delivery_receipts
+             (message_id, recipient_id, server_timestamp, signature, received_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                receipt.message_id,
+                receipt.recipient_id,
+                receipt.server_timestamp as i64,
+                receipt.signature,
+                received_at as i64,
+            ],
+        )?;
+        Ok(rows_affected > 0)
+    }
+
+    /// Loads the most recently received delivery receipt and the local
+    /// time it was received at.
+    pub fn load_latest_delivery_receipt(
+        &self,
+    ) -> Result<Option<(SimpleDeliveryReceipt, u64)>, StorageError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT message_id, recipient_id, server_timestamp, signature, received_at
+             FROM delivery_receipts ORDER BY received_at DESC, rowid DESC LIMIT 1",
+        )?;
+
+        let mut rows = stmt.query([])?;
+        match rows.next()? {
+            Some(row) => Ok(Some((
+                SimpleDeliveryReceipt {
+                    message_id: row.get(0)?,
+                    recipient_id: row.get(1)?,
+                    server_timestamp: row.get::<_, i64>(2)? as u64,
+                    signature: row.get(3)?,
+                },
+                row.get::<_, i64>(4)? as u64,
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// Loads the delivery receipt for a message, if the relay sent one.
+    pub fn load_delivery_receipt(
+        &self,
+        message_id: &str,
+    ) -> Result<Option<SimpleDeliveryReceipt>, StorageError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT message_id, recipient_id, server_timestamp, signature
+             FROM delivery_receipts WHERE message_id = ?1",
+        )?;
+
+        let mut rows = stmt.query(params![message_id])?;
+        match rows.next()? {
+            Some(row) => Ok(Some(SimpleDeliveryReceipt {
+                message_id: row.get(0)?,
+                recipient_id: row.get(1)?,
+                server_timestamp: row.get::<_, i64>(2)? as u64,
+                signature: row.get(3)?,
+            })),
+            None => Ok(None),
+        }
+    }
+}
+
 /// Converts DeliveryStatus to database representation.
 fn status_to_db(status: &DeliveryStatus) -> (&'static str, Option<String>) {
     match status {
@@ -6,11 +6,16 @@
 
 use thiserror::Error;
 
+use crate::contact::LabelError;
+
 /// Storage error types.
 #[derive(Error, Debug)]
 pub enum StorageError {
     #[error("Database error: {0}")]
-    Database(#[from] rusqlite::Error),
+    Database(#[source] rusqlite::Error),
+
+    #[error("Storage is read-only")]
+    ReadOnly,
 
     #[error("Serialization error: {0}")]
     Serialization(String),
@@ -29,6 +34,24 @@ pub enum StorageError {
 
     #[error("Migration error: {0}")]
     Migration(String),
+
+    #[error("Label error: {0}")]
+    Label(LabelError),
+
+    #[error("Contact limit reached: {0}")]
+    ContactLimitReached(usize),
+}
+
+impl From<rusqlite::Error> for StorageError {
+    fn from(err: rusqlite::Error) -> Self {
+        match err {
+            // Writes on a connection from `Storage::open_read_only`
+            rusqlite::Error::SqliteFailure(e, _) if e.code == rusqlite::ErrorCode::ReadOnly => {
+                StorageError::ReadOnly
+            }
+            e => StorageError::Database(e),
+        }
+    }
 }
 
 /// Pending update status.
@@ -51,6 +74,28 @@ pub struct PendingUpdate {
     pub status: UpdateStatus,
 }
 
+/// Where a stale outbound update got stuck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleUpdateState {
+    /// Still in the local queue; never reached the relay.
+    NeverSent,
+    /// Reached the relay but the recipient never confirmed receipt.
+    NotDelivered,
+}
+
+/// An outbound update that has been waiting for delivery too long.
+#[derive(Debug, Clone)]
+pub struct StaleUpdate {
+    /// Pending update ID or delivery record message ID.
+    pub id: String,
+    /// Recipient's contact ID.
+    pub contact_id: String,
+    /// When the update was created.
+    pub created_at: u64,
+    /// Whether the update was sent at all.
+    pub state: StaleUpdateState,
+}
+
 /// Delivery status for tracking message delivery progression.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DeliveryStatus {
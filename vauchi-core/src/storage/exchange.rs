@@ -0,0 +1,315 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Exchange delivery storage operations.
+//!
+//! Tracks the exchange message sent to a new contact after scanning their
+//! QR code. The contact is saved before the message goes out, so an
+//! interrupted send would otherwise leave the peer unaware of the exchange.
+//!
+//! Also tracks outstanding re-key requests to existing contacts, and the
+//! confirmations still expected for contacts added by a password exchange.
+
+use rusqlite::params;
+
+use super::{Storage, StorageError};
+
+/// An outbound exchange message and its delivery state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingExchange {
+    /// Contact the exchange message is for.
+    pub contact_id: String,
+    /// Serialized exchange message.
+    pub message: Vec<u8>,
+    /// When the exchange was started (Unix seconds).
+    pub created_at: u64,
+    /// Number of send attempts so far.
+    pub attempts: u32,
+    /// When the relay accepted the message, if it has.
+    pub delivered_at: Option<u64>,
+}
+
+impl PendingExchange {
+    /// Returns true once the relay has accepted the message.
+    pub fn is_delivered(&self) -> bool {
+        self.delivered_at.is_some()
+    }
+}
+
+/// Raw `pending_exchanges` row: (contact_id, message_encrypted, created_at, attempts, delivered_at).
+type PendingExchangeRow = (String, Option<Vec<u8>>, i64, u32, Option<i64>);
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+impl Storage {
+    // === Pending Exchange Operations ===
+
+    /// Saves the exchange message for a contact, before it is sent.
+    ///
+    /// Replaces any earlier message for the same contact.
+    pub fn save_pending_exchange(
+        &self,
+        contact_id: &str,
+        message: &[u8],
+    ) -> Result<(), StorageError> {
+        let message_encrypted = crate::crypto::encrypt(&self.encryption_key, message)
+            .map_err(|e| StorageError::Encryption(e.to_string()))?;
+
+        self.conn.execute(
+            "INSERT OR REPLACE INTO pending_exchanges
+             (contact_id, message_encrypted, created_at, attempts, delivered_at)
+             VALUES (?1, ?2, ?3, 0, NULL)",
+            params![contact_id, message_encrypted, now_secs() as i64],
+        )?;
+
+        Ok(())
+    }
+
+    /// Loads the exchange message for a contact.
+    pub fn load_pending_exchange(
+        &self,
+        contact_id: &str,
+    ) -> Result<Option<PendingExchange>, StorageError> {
+        let result = self.conn.query_row(
+            "SELECT contact_id, message_encrypted, created_at, attempts, delivered_at
+             FROM pending_exchanges WHERE contact_id = ?1",
+            [contact_id],
+            Self::pending_exchange_row,
+        );
+
+        match result {
+            Ok(row) => Ok(Some(self.decrypt_pending_exchange(row)?)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(StorageError::Database(e)),
+        }
+    }
+
+    /// Lists exchange messages the relay has not accepted yet, oldest first.
+    pub fn list_undelivered_exchanges(&self) -> Result<Vec<PendingExchange>, StorageError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT contact_id, message_encrypted, created_at, attempts, delivered_at
+             FROM pending_exchanges WHERE delivered_at IS NULL
+             ORDER BY created_at, contact_id",
+        )?;
+        let rows = stmt
+            .query_map([], Self::pending_exchange_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.into_iter()
+            .map(|row| self.decrypt_pending_exchange(row))
+            .collect()
+    }
+
+    /// Counts a send attempt for a contact's exchange message.
+    pub fn record_exchange_attempt(&self, contact_id: &str) -> Result<(), StorageError> {
+        let updated = self.conn.execute(
+            "UPDATE pending_exchanges SET attempts = attempts + 1 WHERE contact_id = ?1",
+            [contact_id],
+        )?;
+        if updated == 0 {
+            return Err(StorageError::NotFound(format!(
+                "Pending exchange for {}",
+                contact_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Marks a contact's exchange message as accepted by the relay.
+    ///
+    /// The stored message is dropped, since it is no longer needed.
+    pub fn mark_exchange_delivered(&self, contact_id: &str) -> Result<(), StorageError> {
+        let updated = self.conn.execute(
+            "UPDATE pending_exchanges SET delivered_at = ?1, message_encrypted = NULL
+             WHERE contact_id = ?2",
+            params![now_secs() as i64, contact_id],
+        )?;
+        if updated == 0 {
+            return Err(StorageError::NotFound(format!(
+                "Pending exchange for {}",
+                contact_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Deletes the exchange record for a contact.
+    pub fn delete_pending_exchange(&self, contact_id: &str) -> Result<bool, StorageError> {
+        let deleted = self.conn.execute(
+            "DELETE FROM pending_exchanges WHERE contact_id = ?1",
+            [contact_id],
+        )?;
+        Ok(deleted > 0)
+    }
+
+    fn pending_exchange_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<PendingExchangeRow> {
+        Ok((
+            row.get(0)?,
+            row.get(1)?,
+            row.get(2)?,
+            row.get(3)?,
+            row.get(4)?,
+        ))
+    }
+
+    fn decrypt_pending_exchange(
+        &self,
+        (contact_id, message_encrypted, created_at, attempts, delivered_at): PendingExchangeRow,
+    ) -> Result<PendingExchange, StorageError> {
+        let message = match message_encrypted {
+            Some(encrypted) => crate::crypto::decrypt(&self.encryption_key, &encrypted)
+                .map_err(|e| StorageError::Encryption(e.to_string()))?,
+            None => Vec::new(),
+        };
+
+        Ok(PendingExchange {
+            contact_id,
+            message,
+            created_at: created_at as u64,
+            attempts,
+            delivered_at: delivered_at.map(|t| t as u64),
+        })
+    }
+
+    // === Pending Re-key Operations ===
+
+    /// Records an outstanding re-key request to a contact.
+    ///
+    /// Replaces any earlier request to the same contact.
+    pub fn save_pending_rekey(
+        &self,
+        contact_id: &str,
+        requested_at: u64,
+    ) -> Result<(), StorageError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO pending_rekeys (contact_id, requested_at) VALUES (?1, ?2)",
+            params![contact_id, requested_at as i64],
+        )?;
+        Ok(())
+    }
+
+    /// Returns when the outstanding re-key request to a contact was made.
+    pub fn load_pending_rekey(&self, contact_id: &str) -> Result<Option<u64>, StorageError> {
+        let result = self.conn.query_row(
+            "SELECT requested_at FROM pending_rekeys WHERE contact_id = ?1",
+            params![contact_id],
+            |row| row.get::<_, i64>(0),
+        );
+
+        match result {
+            Ok(requested_at) => Ok(Some(requested_at as u64)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(StorageError::Database(e)),
+        }
+    }
+
+    /// Clears the outstanding re-key request to a contact.
+    ///
+    /// Returns true if there was one.
+    pub fn delete_pending_rekey(&self, contact_id: &str) -> Result<bool, StorageError> {
+        let removed = self.conn.execute(
+            "DELETE FROM pending_rekeys WHERE contact_id = ?1",
+            params![contact_id],
+        )?;
+        Ok(removed > 0)
+    }
+
+    // === Password Confirmation Operations ===
+
+    /// Records the confirmation a password exchange initiator must send
+    /// back. The contact stays unconfirmed until it arrives.
+    pub fn save_expected_confirmation(
+        &self,
+        contact_id: &str,
+        confirmation: &[u8],
+    ) -> Result<(), StorageError> {
+        let expected_encrypted = crate::crypto::encrypt(&self.encryption_key, confirmation)
+            .map_err(|e| StorageError::Encryption(e.to_string()))?;
+
+        self.conn.execute(
+            "INSERT OR REPLACE INTO password_confirmations
+             (contact_id, expected_encrypted, created_at)
+             VALUES (?1, ?2, ?3)",
+            params![contact_id, expected_encrypted, now_secs() as i64],
+        )?;
+        Ok(())
+    }
+
+    /// Loads the confirmation expected for an unconfirmed contact.
+    pub fn load_expected_confirmation(
+        &self,
+        contact_id: &str,
+    ) -> Result<Option<Vec<u8>>, StorageError> {
+        let result = self.conn.query_row(
+            "SELECT expected_encrypted FROM password_confirmations WHERE contact_id = ?1",
+            params![contact_id],
+            |row| row.get::<_, Vec<u8>>(0),
+        );
+
+        match result {
+            Ok(encrypted) => crate::crypto::decrypt(&self.encryption_key, &encrypted)
+                .map(Some)
+                .map_err(|e| StorageError::Encryption(e.to_string())),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(StorageError::Database(e)),
+        }
+    }
+
+    /// Marks a contact as confirmed.
+    ///
+    /// Returns true if it was waiting for a confirmation.
+    pub fn delete_expected_confirmation(&self, contact_id: &str) -> Result<bool, StorageError> {
+        let removed = self.conn.execute(
+            "DELETE FROM password_confirmations WHERE contact_id = ?1",
+            params![contact_id],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// Drops a contact whose password exchange failed confirmation.
+    ///
+    /// Unlike [`Storage::delete_contact`], keeps no tombstone: the contact
+    /// was never confirmed, so there is nothing to restore. Returns false
+    /// if the contact was not waiting for a confirmation.
+    pub fn discard_unconfirmed_contact(&self, contact_id: &str) -> Result<bool, StorageError> {
+        let tx = self.transaction()?;
+        if !self.delete_expected_confirmation(contact_id)? {
+            return Ok(false);
+        }
+        self.delete_contact(contact_id)?;
+        self.conn.execute(
+            "DELETE FROM contact_tombstones WHERE contact_id = ?1",
+            params![contact_id],
+        )?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    /// Drops unconfirmed contacts whose confirmation was not received
+    /// before `before` (Unix seconds).
+    ///
+    /// Returns the number of contacts dropped.
+    pub fn discard_expired_confirmations(&self, before: u64) -> Result<usize, StorageError> {
+        let expired = {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT contact_id FROM password_confirmations WHERE created_at < ?1")?;
+            let rows = stmt.query_map(params![before as i64], |row| row.get::<_, String>(0))?;
+            rows.collect::<Result<Vec<_>, _>>()?
+        };
+
+        let mut discarded = 0;
+        for contact_id in expired {
+            if self.discard_unconfirmed_contact(&contact_id)? {
+                discarded += 1;
+            }
+        }
+        Ok(discarded)
+    }
+}
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Inbound message storage operations.
+//!
+//! Remembers the IDs of relay messages that have already been processed so
+//! that redelivered messages are not applied twice, and the per-relay sync
+//! cursor that lets a relay skip them altogether.
+
+use rusqlite::params;
+
+use super::{Storage, StorageError};
+
+impl Storage {
+    // === Processed Message Operations ===
+
+    /// Records that an inbound message has been processed.
+    ///
+    /// Returns `true` if the ID was new, `false` if it had been seen before.
+    pub fn mark_message_processed(&self, message_id: &str) -> Result<bool, StorageError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system time before UNIX epoch")
+            .as_secs();
+
+        let inserted = self.conn.execute(
+            "INSERT OR IGNORE INTO processed_messages (message_id, processed_at)
+             VALUES (?1, ?2)",
+            params![message_id, now as i64],
+        )?;
+
+        Ok(inserted > 0)
+    }
+
+    /// Checks whether an inbound message has already been processed.
+    pub fn is_message_processed(&self, message_id: &str) -> Result<bool, StorageError> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM processed_messages WHERE message_id = ?1",
+            params![message_id],
+            |row| row.get(0),
+        )?;
+
+        Ok(count > 0)
+    }
+
+    /// Forgets processed message IDs recorded before `before` (Unix seconds).
+    ///
+    /// Returns the number of entries removed.
+    pub fn prune_processed_messages(&self, before: u64) -> Result<usize, StorageError> {
+        let removed = self.conn.execute(
+            "DELETE FROM processed_messages WHERE processed_at < ?1",
+            params![before as i64],
+        )?;
+
+        Ok(removed)
+    }
+
+    // === Sync Cursor Operations ===
+
+    /// Saves the ID of the last message processed from a relay.
+    pub fn save_sync_cursor(&self, relay_url: &str, message_id: &str) -> Result<(), StorageError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system time before UNIX epoch")
+            .as_secs();
+
+        self.conn.execute(
+            "INSERT OR REPLACE INTO sync_cursors (relay_url, message_id, updated_at)
+             VALUES (?1, ?2, ?3)",
+            params![relay_url, message_id, now as i64],
+        )?;
+
+        Ok(())
+    }
+
+    /// Loads the ID of the last message processed from a relay, if any.
+    pub fn load_sync_cursor(&self, relay_url: &str) -> Result<Option<String>, StorageError> {
+        let result = self.conn.query_row(
+            "SELECT message_id FROM sync_cursors WHERE relay_url = ?1",
+            params![relay_url],
+            |row| row.get(0),
+        );
+
+        match result {
+            Ok(message_id) => Ok(Some(message_id)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(StorageError::Database(e)),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Storage integrity self-check.
+
+use super::{Storage, StorageError};
+use crate::contact_card::ContactCard;
+
+/// A stored value that could not be decrypted or parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityFailure {
+    /// Table the row belongs to.
+    pub table: String,
+    /// Primary key of the row (contact ID for contact data).
+    pub row_id: String,
+    /// Which value failed (e.g. "card", "shared_key").
+    pub item: String,
+    /// Why it failed.
+    pub reason: String,
+}
+
+/// Result of [`Storage::verify_integrity`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// Messages from SQLite's `PRAGMA integrity_check` ("ok" when healthy).
+    pub sqlite_messages: Vec<String>,
+    /// Number of encrypted values checked.
+    pub items_checked: usize,
+    /// Values that failed to decrypt or parse.
+    pub failures: Vec<IntegrityFailure>,
+}
+
+impl IntegrityReport {
+    /// Returns true if SQLite reported no problems.
+    pub fn sqlite_ok(&self) -> bool {
+        self.sqlite_messages.len() == 1 && self.sqlite_messages[0] == "ok"
+    }
+
+    /// Returns true if the database and every checked value are intact.
+    pub fn is_ok(&self) -> bool {
+        self.sqlite_ok() && self.failures.is_empty()
+    }
+
+    fn record(&mut self, table: &str, row_id: &str, item: &str, result: Result<(), String>) {
+        self.items_checked += 1;
+        if let Err(reason) = result {
+            self.failures.push(IntegrityFailure {
+                table: table.to_string(),
+                row_id: row_id.to_string(),
+                item: item.to_string(),
+                reason,
+            });
+        }
+    }
+}
+
+impl Storage {
+    // === Integrity Operations ===
+
+    /// Checks that every contact card, shared key and ratchet state decrypts.
+    ///
+    /// Failing rows are collected in the report rather than aborting the
+    /// check, so a single corrupted row does not hide the state of the rest.
+    /// Also runs SQLite's `PRAGMA integrity_check`.
+    pub fn verify_integrity(&self) -> Result<IntegrityReport, StorageError> {
+        let mut report = IntegrityReport::default();
+
+        let mut stmt = self.conn.prepare("PRAGMA integrity_check")?;
+        report.sqlite_messages = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, card_encrypted, shared_key_encrypted FROM contacts ORDER BY id")?;
+        let contacts = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Vec<u8>>(1)?,
+                    row.get::<_, Vec<u8>>(2)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (id, card_encrypted, shared_key_encrypted) in contacts {
+            let card = self.decrypt_value(&card_encrypted).and_then(|json| {
+                serde_json::from_slice::<ContactCard>(&json)
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            });
+            report.record("contacts", &id, "card", card);
+
+            let shared_key = self.decrypt_value(&shared_key_encrypted).and_then(|key| {
+                if key.len() == 32 {
+                    Ok(())
+                } else {
+                    Err("Invalid key length".to_string())
+                }
+            });
+            report.record("contacts", &id, "shared_key", shared_key);
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT contact_id, ratchet_state_encrypted FROM contact_ratchets ORDER BY contact_id",
+        )?;
+        let ratchets = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (contact_id, state_encrypted) in ratchets {
+            let state = self.decrypt_value(&state_encrypted).and_then(|json| {
+                let serialized: crate::crypto::ratchet::SerializedRatchetState =
+                    serde_json::from_slice(&json).map_err(|e| e.to_string())?;
+                crate::crypto::ratchet::DoubleRatchetState::deserialize(serialized)
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            });
+            report.record("contact_ratchets", &contact_id, "ratchet_state", state);
+        }
+
+        Ok(report)
+    }
+
+    fn decrypt_value(&self, encrypted: &[u8]) -> Result<Vec<u8>, String> {
+        crate::crypto::decrypt(&self.encryption_key, encrypted).map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Storage encryption key rotation.
+
+use rusqlite::params;
+
+use super::archive::ENCRYPTED_COLUMN_SUFFIX;
+use super::{Storage, StorageError};
+use crate::crypto::{decrypt, encrypt, SymmetricKey};
+
+impl Storage {
+    // === Key Rotation Operations ===
+
+    /// Re-encrypts every encrypted column under `new_key`.
+    ///
+    /// Covers every `*_encrypted` column: cards, shared keys, ratchet
+    /// states, notes, avatars, the identity backup and pending exchanges.
+    /// Runs in one transaction, so a failure leaves the database readable
+    /// under `old_key`. Afterwards this connection uses `new_key`; other
+    /// open connections must be reopened with it.
+    ///
+    /// # Errors
+    /// Fails with `Encryption` if `old_key` is not the key this storage was
+    /// opened with, or if a value does not decrypt under it.
+    pub fn rotate_encryption_key(
+        &mut self,
+        old_key: &SymmetricKey,
+        new_key: &SymmetricKey,
+    ) -> Result<(), StorageError> {
+        if old_key.as_bytes() != self.encryption_key.as_bytes() {
+            return Err(StorageError::Encryption(
+                "Old key does not match the storage key".to_string(),
+            ));
+        }
+
+        let tx = self.transaction()?;
+        for (table, column) in self.encrypted_columns()? {
+            let mut stmt = self.conn.prepare(&format!(
+                "SELECT rowid, \"{}\" FROM \"{}\" WHERE \"{}\" IS NOT NULL",
+                column, table, column
+            ))?;
+            let rows = stmt
+                .query_map([], |row| {
+                    Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let mut update = self.conn.prepare(&format!(
+                "UPDATE \"{}\" SET \"{}\" = ?1 WHERE rowid = ?2",
+                table, column
+            ))?;
+            for (rowid, encrypted) in rows {
+                let plaintext = decrypt(old_key, &encrypted).map_err(|e| {
+                    StorageError::Encryption(format!("{}.{}: {}", table, column, e))
+                })?;
+                let encrypted = encrypt(new_key, &plaintext)
+                    .map_err(|e| StorageError::Encryption(e.to_string()))?;
+                update.execute(params![encrypted, rowid])?;
+            }
+        }
+        tx.commit()?;
+
+        self.encryption_key = new_key.clone();
+        Ok(())
+    }
+
+    /// Lists `(table, column)` for every encrypted column in the schema.
+    fn encrypted_columns(&self) -> Result<Vec<(String, String)>, StorageError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT m.name, c.name FROM sqlite_master m, pragma_table_info(m.name) c
+             WHERE m.type = 'table' AND m.name NOT LIKE 'sqlite_%'
+             ORDER BY m.name, c.cid",
+        )?;
+        let columns = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .filter(|column| {
+                column
+                    .as_ref()
+                    .map_or(true, |(_, name)| name.ends_with(ENCRYPTED_COLUMN_SUFFIX))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(columns)
+    }
+}
@@ -8,7 +8,10 @@
 
 use std::collections::{HashMap, HashSet};
 
-use crate::contact::{LabelManager, VisibilityLabel};
+use crate::contact::{
+    Contact, ContactFieldVisibility, DefaultFieldVisibility, LabelError, LabelManager,
+    VisibilityLabel,
+};
 
 use super::{Storage, StorageError};
 
@@ -22,8 +25,8 @@ impl Storage {
 
         self.conn.execute(
             "INSERT OR REPLACE INTO visibility_labels
-             (id, name, contacts_json, visible_fields_json, created_at, modified_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+             (id, name, contacts_json, visible_fields_json, created_at, modified_at, color, icon)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
             (
                 label.id(),
                 label.name(),
@@ -31,6 +34,8 @@ impl Storage {
                 &fields_json,
                 label.created_at() as i64,
                 label.modified_at() as i64,
+                label.color(),
+                label.icon(),
             ),
         )?;
 
@@ -40,7 +45,8 @@ impl Storage {
     /// Loads a visibility label by ID.
     pub fn load_label(&self, label_id: &str) -> Result<VisibilityLabel, StorageError> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, name, contacts_json, visible_fields_json, created_at, modified_at
+            "SELECT id, name, contacts_json, visible_fields_json, created_at, modified_at,
+                    color, icon
              FROM visibility_labels WHERE id = ?1",
         )?;
 
@@ -51,6 +57,8 @@ impl Storage {
             let fields_json: String = row.get(3)?;
             let created_at: i64 = row.get(4)?;
             let modified_at: i64 = row.get(5)?;
+            let color: Option<String> = row.get(6)?;
+            let icon: Option<String> = row.get(7)?;
 
             Ok((
                 id,
@@ -59,6 +67,8 @@ impl Storage {
                 fields_json,
                 created_at,
                 modified_at,
+                color,
+                icon,
             ))
         })?;
 
@@ -74,13 +84,15 @@ impl Storage {
             visible_fields,
             label.4 as u64,
             label.5 as u64,
-        ))
+        )
+        .with_style(label.6, label.7))
     }
 
     /// Loads all visibility labels.
     pub fn load_all_labels(&self) -> Result<Vec<VisibilityLabel>, StorageError> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, name, contacts_json, visible_fields_json, created_at, modified_at
+            "SELECT id, name, contacts_json, visible_fields_json, created_at, modified_at,
+                    color, icon
              FROM visibility_labels ORDER BY name",
         )?;
 
@@ -91,6 +103,8 @@ impl Storage {
             let fields_json: String = row.get(3)?;
             let created_at: i64 = row.get(4)?;
             let modified_at: i64 = row.get(5)?;
+            let color: Option<String> = row.get(6)?;
+            let icon: Option<String> = row.get(7)?;
 
             Ok((
                 id,
@@ -99,6 +113,8 @@ impl Storage {
                 fields_json,
                 created_at,
                 modified_at,
+                color,
+                icon,
             ))
         })?;
 
@@ -110,19 +126,32 @@ impl Storage {
             let visible_fields: HashSet<String> = serde_json::from_str(&row.3)
                 .map_err(|e| StorageError::Serialization(e.to_string()))?;
 
-            labels.push(VisibilityLabel::from_storage(
-                row.0,
-                row.1,
-                contacts,
-                visible_fields,
-                row.4 as u64,
-                row.5 as u64,
-            ));
+            labels.push(
+                VisibilityLabel::from_storage(
+                    row.0,
+                    row.1,
+                    contacts,
+                    visible_fields,
+                    row.4 as u64,
+                    row.5 as u64,
+                )
+                .with_style(row.6, row.7),
+            );
         }
 
         Ok(labels)
     }
 
+    /// Returns the number of stored labels.
+    pub fn count_labels(&self) -> Result<usize, StorageError> {
+        let count = self
+            .conn
+            .query_row("SELECT COUNT(*) FROM visibility_labels", [], |row| {
+                row.get::<_, i64>(0)
+            })?;
+        Ok(count as usize)
+    }
+
     /// Deletes a visibility label.
     pub fn delete_label(&self, label_id: &str) -> Result<(), StorageError> {
         let changes = self
@@ -290,6 +319,15 @@ impl Storage {
     ///
     /// Returns the created label.
     pub fn create_label(&self, name: &str) -> Result<VisibilityLabel, StorageError> {
+        let tx = self.transaction()?;
+        let label = self.insert_new_label(name)?;
+        tx.commit()?;
+
+        Ok(label)
+    }
+
+    /// Validates and inserts a new label; callers own the transaction.
+    fn insert_new_label(&self, name: &str) -> Result<VisibilityLabel, StorageError> {
         // Validate name
         let name = name.trim();
         if name.is_empty() {
@@ -304,28 +342,15 @@ impl Storage {
         }
 
         // Check for duplicate
-        let existing = self.conn.query_row(
-            "SELECT COUNT(*) FROM visibility_labels WHERE name = ?1",
-            [name],
-            |row| row.get::<_, i32>(0),
-        )?;
-
-        if existing > 0 {
-            return Err(StorageError::AlreadyExists(format!("Label: {}", name)));
+        if self.label_name_taken(name, None)? {
+            return Err(StorageError::Label(LabelError::DuplicateName(
+                name.to_string(),
+            )));
         }
 
         // Check max labels
-        let count = self
-            .conn
-            .query_row("SELECT COUNT(*) FROM visibility_labels", [], |row| {
-                row.get::<_, i32>(0)
-            })?;
-
-        if count >= crate::contact::MAX_LABELS as i32 {
-            return Err(StorageError::Serialization(format!(
-                "Maximum number of labels reached ({})",
-                crate::contact::MAX_LABELS
-            )));
+        if self.count_labels()? >= crate::contact::MAX_LABELS {
+            return Err(StorageError::Label(LabelError::MaxLabelsReached));
         }
 
         // Create and save
@@ -335,7 +360,57 @@ impl Storage {
         Ok(label)
     }
 
+    /// Returns true if another label already uses `name`, ignoring case.
+    fn label_name_taken(&self, name: &str, except_id: Option<&str>) -> Result<bool, StorageError> {
+        let existing = self.conn.query_row(
+            "SELECT COUNT(*) FROM visibility_labels
+             WHERE name = ?1 COLLATE NOCASE AND id IS NOT ?2",
+            (name, except_id),
+            |row| row.get::<_, i32>(0),
+        )?;
+
+        Ok(existing > 0)
+    }
+
+    /// Creates a label with a display color and icon.
+    ///
+    /// The color must be a hex string such as `#FF8800`.
+    pub fn create_label_with_style(
+        &self,
+        name: &str,
+        color: Option<&str>,
+        icon: Option<&str>,
+    ) -> Result<VisibilityLabel, StorageError> {
+        // A rejected style must not leave an unstyled label behind
+        let tx = self.transaction()?;
+        let mut label = self.insert_new_label(name)?;
+        label
+            .set_style(color, icon)
+            .map_err(|e| StorageError::InvalidData(e.to_string()))?;
+        self.save_label(&label)?;
+        tx.commit()?;
+
+        Ok(label)
+    }
+
+    /// Updates a label's display color and icon; `None` clears them.
+    pub fn update_label_style(
+        &self,
+        label_id: &str,
+        color: Option<&str>,
+        icon: Option<&str>,
+    ) -> Result<(), StorageError> {
+        let mut label = self.load_label(label_id)?;
+        label
+            .set_style(color, icon)
+            .map_err(|e| StorageError::InvalidData(e.to_string()))?;
+        self.save_label(&label)
+    }
+
     /// Renames a label in storage.
+    ///
+    /// Fails with `LabelError::DuplicateName` if another label already has
+    /// the name, compared case-insensitively.
     pub fn rename_label(&self, label_id: &str, new_name: &str) -> Result<(), StorageError> {
         let new_name = new_name.trim();
 
@@ -351,15 +426,13 @@ impl Storage {
             ));
         }
 
-        // Check for duplicate (excluding this label)
-        let existing = self.conn.query_row(
-            "SELECT COUNT(*) FROM visibility_labels WHERE name = ?1 AND id != ?2",
-            (new_name, label_id),
-            |row| row.get::<_, i32>(0),
-        )?;
+        let tx = self.transaction()?;
 
-        if existing > 0 {
-            return Err(StorageError::AlreadyExists(format!("Label: {}", new_name)));
+        // Check for duplicate (excluding this label)
+        if self.label_name_taken(new_name, Some(label_id))? {
+            return Err(StorageError::Label(LabelError::DuplicateName(
+                new_name.to_string(),
+            )));
         }
 
         // Update
@@ -377,6 +450,7 @@ impl Storage {
             return Err(StorageError::NotFound(format!("Label: {}", label_id)));
         }
 
+        tx.commit()?;
         Ok(())
     }
 
@@ -416,6 +490,62 @@ impl Storage {
         Ok(())
     }
 
+    /// Adds several contacts to a label in one transaction.
+    ///
+    /// All contacts must exist; otherwise nothing is changed. Returns the
+    /// number of contacts that were not already in the label.
+    pub fn add_contacts_to_label(
+        &self,
+        label_id: &str,
+        contact_ids: &[String],
+    ) -> Result<usize, StorageError> {
+        self.update_label_members(label_id, contact_ids, |label, id| label.add_contact(id))
+    }
+
+    /// Removes several contacts from a label in one transaction.
+    ///
+    /// All contacts must exist; otherwise nothing is changed. Returns the
+    /// number of contacts that were in the label.
+    pub fn remove_contacts_from_label(
+        &self,
+        label_id: &str,
+        contact_ids: &[String],
+    ) -> Result<usize, StorageError> {
+        self.update_label_members(label_id, contact_ids, |label, id| label.remove_contact(id))
+    }
+
+    fn update_label_members(
+        &self,
+        label_id: &str,
+        contact_ids: &[String],
+        apply: impl Fn(&mut VisibilityLabel, &str) -> bool,
+    ) -> Result<usize, StorageError> {
+        let tx = self.transaction()?;
+
+        let mut label = self.load_label(label_id)?;
+        for contact_id in contact_ids {
+            let exists: bool = self.conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM contacts WHERE id = ?1)",
+                [contact_id],
+                |row| row.get(0),
+            )?;
+            if !exists {
+                return Err(StorageError::NotFound(format!("Contact {}", contact_id)));
+            }
+        }
+
+        let changed = contact_ids
+            .iter()
+            .filter(|contact_id| apply(&mut label, contact_id))
+            .count();
+        if changed > 0 {
+            self.save_label(&label)?;
+        }
+
+        tx.commit()?;
+        Ok(changed)
+    }
+
     /// Removes a contact from all labels in storage.
     ///
     /// Call this when deleting a contact.
@@ -458,6 +588,16 @@ impl Storage {
         Ok(())
     }
 
+    /// Gets the IDs of fields shared with a label's members, sorted.
+    pub fn fields_shared_with_label(&self, label_id: &str) -> Result<Vec<String>, StorageError> {
+        let label = self.load_label(label_id)?;
+
+        let mut field_ids: Vec<String> = label.visible_fields().iter().cloned().collect();
+        field_ids.sort();
+
+        Ok(field_ids)
+    }
+
     /// Gets all labels that contain a specific contact.
     pub fn get_labels_for_contact(
         &self,
@@ -470,11 +610,206 @@ impl Storage {
             .filter(|l| l.contains_contact(contact_id))
             .collect())
     }
+
+    /// Loads what a contact may see of the own card.
+    pub fn load_field_visibility(
+        &self,
+        contact: &Contact,
+    ) -> Result<ContactFieldVisibility, StorageError> {
+        let label_fields = self
+            .get_labels_for_contact(contact.id())?
+            .iter()
+            .flat_map(|label| label.visible_fields().iter().cloned())
+            .collect();
+
+        Ok(ContactFieldVisibility::new(
+            contact.id(),
+            contact.visibility_rules().clone(),
+            self.load_contact_overrides(contact.id())?,
+            label_fields,
+            self.load_default_hidden_fields()?,
+        ))
+    }
+
+    // === Field Removal ===
+
+    /// Removes a field from the own card along with its visibility entries.
+    ///
+    /// The card is saved and the field dropped from every label and
+    /// per-contact override in one transaction. Returns false if the own
+    /// card has no field with this ID.
+    pub fn remove_own_field(&self, field_id: &str) -> Result<bool, StorageError> {
+        let tx = self.transaction()?;
+
+        let Some(mut card) = self.load_own_card()? else {
+            return Ok(false);
+        };
+        if card.remove_field(field_id).is_err() {
+            return Ok(false);
+        }
+        self.save_own_card(&card)?;
+        self.retain_field_visibility(|id| id != field_id)?;
+
+        tx.commit()?;
+        Ok(true)
+    }
+
+    /// Deletes visibility entries for fields no longer on the own card.
+    ///
+    /// Cleans up label and override entries left behind by fields removed
+    /// before removal cleaned up after itself. Does nothing without an own
+    /// card. Returns the number of entries deleted.
+    pub fn prune_orphaned_visibility(&self) -> Result<usize, StorageError> {
+        let tx = self.transaction()?;
+
+        let Some(card) = self.load_own_card()? else {
+            return Ok(0);
+        };
+        let field_ids: HashSet<&str> = card.fields().iter().map(|f| f.id()).collect();
+        let pruned = self.retain_field_visibility(|id| field_ids.contains(id))?;
+
+        tx.commit()?;
+        Ok(pruned)
+    }
+
+    /// Drops label, override and hidden-default entries for fields rejected
+    /// by `keep`; callers own the transaction. Returns the number of entries
+    /// deleted.
+    fn retain_field_visibility(&self, keep: impl Fn(&str) -> bool) -> Result<usize, StorageError> {
+        let mut deleted = 0;
+
+        for mut label in self.load_all_labels()? {
+            let stale: Vec<String> = label
+                .visible_fields()
+                .iter()
+                .filter(|id| !keep(id))
+                .cloned()
+                .collect();
+            if stale.is_empty() {
+                continue;
+            }
+            for field_id in &stale {
+                label.remove_visible_field(field_id);
+            }
+            self.save_label(&label)?;
+            deleted += stale.len();
+        }
+
+        for (contact_id, overrides) in self.load_all_contact_overrides()? {
+            for field_id in overrides.keys().filter(|id| !keep(id)) {
+                self.delete_contact_override(&contact_id, field_id)?;
+                deleted += 1;
+            }
+        }
+
+        for field_id in self.load_default_hidden_fields()? {
+            if !keep(&field_id) {
+                self.conn.execute(
+                    "DELETE FROM hidden_default_fields WHERE field_id = ?1",
+                    [&field_id],
+                )?;
+                deleted += 1;
+            }
+        }
+
+        Ok(deleted)
+    }
+
+    // === Default Field Visibility ===
+
+    /// Sets the visibility applied to fields added to the own card.
+    ///
+    /// Label-based policies must reference existing labels.
+    pub fn set_default_field_visibility(
+        &self,
+        policy: &DefaultFieldVisibility,
+    ) -> Result<(), StorageError> {
+        if let DefaultFieldVisibility::Labels(label_ids) = policy {
+            let labels = self.load_all_labels()?;
+            if let Some(missing) = label_ids
+                .iter()
+                .find(|id| !labels.iter().any(|l| l.id() == id.as_str()))
+            {
+                return Err(StorageError::NotFound(format!("Label {}", missing)));
+            }
+        }
+
+        let json = serde_json::to_string(policy)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+        self.conn.execute(
+            "INSERT OR REPLACE INTO default_field_visibility (id, policy_json) VALUES (1, ?1)",
+            [&json],
+        )?;
+
+        Ok(())
+    }
+
+    /// Loads the visibility applied to new fields (everyone if never set).
+    pub fn load_default_field_visibility(&self) -> Result<DefaultFieldVisibility, StorageError> {
+        let result = self.conn.query_row(
+            "SELECT policy_json FROM default_field_visibility WHERE id = 1",
+            [],
+            |row| row.get::<_, String>(0),
+        );
+
+        match result {
+            Ok(json) => {
+                serde_json::from_str(&json).map_err(|e| StorageError::Serialization(e.to_string()))
+            }
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(DefaultFieldVisibility::default()),
+            Err(e) => Err(StorageError::Database(e)),
+        }
+    }
+
+    /// Applies the default visibility policy to a newly added field.
+    ///
+    /// Under a nobody or label policy the field is hidden by default, so
+    /// contacts without a rule for it, including ones added later, do not
+    /// see it. For a label policy the field is also marked visible in each
+    /// of those labels, whose membership is checked when the card is
+    /// shared. Labels deleted since the policy was set are ignored.
+    pub fn apply_default_field_visibility(&self, field_id: &str) -> Result<(), StorageError> {
+        let policy = self.load_default_field_visibility()?;
+        if policy == DefaultFieldVisibility::Everyone {
+            return Ok(());
+        }
+
+        let tx = self.transaction()?;
+
+        if let DefaultFieldVisibility::Labels(label_ids) = policy {
+            for mut label in self.load_all_labels()? {
+                if label_ids.contains(label.id()) && label.add_visible_field(field_id) {
+                    self.save_label(&label)?;
+                }
+            }
+        }
+        self.conn.execute(
+            "INSERT OR IGNORE INTO hidden_default_fields (field_id) VALUES (?1)",
+            [field_id],
+        )?;
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Loads the IDs of fields hidden from contacts without a rule for them.
+    pub fn load_default_hidden_fields(&self) -> Result<HashSet<String>, StorageError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT field_id FROM hidden_default_fields")?;
+        let field_ids = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        Ok(field_ids)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::contact::Contact;
+    use crate::contact_card::{ContactCard, ContactField, FieldType};
     use crate::crypto::SymmetricKey;
 
     fn test_storage() -> Storage {
@@ -605,6 +940,23 @@ mod tests {
         assert_eq!(loaded.name(), "New Label");
     }
 
+    #[test]
+    fn test_create_label_beyond_max_fails() {
+        let storage = test_storage();
+
+        for i in 0..crate::contact::MAX_LABELS {
+            storage.create_label(&format!("Label {}", i)).unwrap();
+        }
+        assert_eq!(storage.count_labels().unwrap(), crate::contact::MAX_LABELS);
+
+        let result = storage.create_label("One Too Many");
+        assert!(matches!(
+            result,
+            Err(StorageError::Label(LabelError::MaxLabelsReached))
+        ));
+        assert_eq!(storage.count_labels().unwrap(), crate::contact::MAX_LABELS);
+    }
+
     #[test]
     fn test_create_duplicate_label() {
         let storage = test_storage();
@@ -612,7 +964,36 @@ mod tests {
         storage.create_label("Unique").unwrap();
         let result = storage.create_label("Unique");
 
-        assert!(matches!(result, Err(StorageError::AlreadyExists(_))));
+        assert!(matches!(
+            result,
+            Err(StorageError::Label(LabelError::DuplicateName(_)))
+        ));
+
+        let result = storage.create_label("UNIQUE");
+        assert!(matches!(
+            result,
+            Err(StorageError::Label(LabelError::DuplicateName(_)))
+        ));
+    }
+
+    #[test]
+    fn test_rename_label_rejects_duplicate_name() {
+        let storage = test_storage();
+
+        storage.create_label("Family").unwrap();
+        let work = storage.create_label("Work").unwrap();
+
+        let result = storage.rename_label(work.id(), "family");
+        assert!(matches!(
+            result,
+            Err(StorageError::Label(LabelError::DuplicateName(_)))
+        ));
+        assert_eq!(storage.load_label(work.id()).unwrap().name(), "Work");
+
+        // Changing only the case of its own name is allowed
+        storage.rename_label(work.id(), "WORK").unwrap();
+        storage.rename_label(work.id(), "Colleagues").unwrap();
+        assert_eq!(storage.load_label(work.id()).unwrap().name(), "Colleagues");
     }
 
     #[test]
@@ -674,6 +1055,28 @@ mod tests {
         assert!(overrides.is_empty());
     }
 
+    #[test]
+    fn test_fields_shared_with_label() {
+        let storage = test_storage();
+        let label = storage.create_label("Family").unwrap();
+        assert!(storage
+            .fields_shared_with_label(label.id())
+            .unwrap()
+            .is_empty());
+
+        storage
+            .set_label_field_visibility(label.id(), "phone", true)
+            .unwrap();
+        storage
+            .set_label_field_visibility(label.id(), "email", true)
+            .unwrap();
+
+        assert_eq!(
+            storage.fields_shared_with_label(label.id()).unwrap(),
+            vec!["email".to_string(), "phone".to_string()]
+        );
+    }
+
     #[test]
     fn test_set_label_field_visibility() {
         let storage = test_storage();
@@ -722,4 +1125,185 @@ mod tests {
         assert!(names.contains(&"Family"));
         assert!(names.contains(&"Friends"));
     }
+
+    #[test]
+    fn test_remove_own_field_clears_its_visibility() {
+        let storage = test_storage();
+        let mut card = ContactCard::new("Alice");
+        let email = ContactField::new(FieldType::Email, "work", "alice@work.example");
+        let phone = ContactField::new(FieldType::Phone, "mobile", "+41 79 000 00 00");
+        let (email_id, phone_id) = (email.id().to_string(), phone.id().to_string());
+        card.add_field(email).unwrap();
+        card.add_field(phone).unwrap();
+        storage.save_own_card(&card).unwrap();
+
+        let label = storage.create_label("Work").unwrap();
+        storage
+            .set_label_field_visibility(label.id(), &email_id, true)
+            .unwrap();
+        storage
+            .set_label_field_visibility(label.id(), &phone_id, true)
+            .unwrap();
+        storage
+            .save_contact_override("bob-id", &email_id, false)
+            .unwrap();
+        storage
+            .save_contact_override("bob-id", &phone_id, true)
+            .unwrap();
+        storage
+            .set_default_field_visibility(&DefaultFieldVisibility::Nobody)
+            .unwrap();
+        storage.apply_default_field_visibility(&email_id).unwrap();
+
+        assert!(storage.remove_own_field(&email_id).unwrap());
+        assert!(!storage.remove_own_field(&email_id).unwrap());
+
+        let label = storage.load_label(label.id()).unwrap();
+        assert!(!label.is_field_visible(&email_id));
+        assert!(label.is_field_visible(&phone_id));
+        let overrides = storage.load_contact_overrides("bob-id").unwrap();
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides.get(&phone_id), Some(&true));
+        assert!(storage.load_default_hidden_fields().unwrap().is_empty());
+        assert_eq!(storage.load_own_card().unwrap().unwrap().fields().len(), 1);
+    }
+
+    #[test]
+    fn test_prune_orphaned_visibility() {
+        let storage = test_storage();
+        assert_eq!(storage.prune_orphaned_visibility().unwrap(), 0);
+
+        let mut card = ContactCard::new("Alice");
+        let phone = ContactField::new(FieldType::Phone, "mobile", "+41 79 000 00 00");
+        let phone_id = phone.id().to_string();
+        card.add_field(phone).unwrap();
+        storage.save_own_card(&card).unwrap();
+
+        // Entries for a field removed before cleanup existed
+        let label = storage.create_label("Work").unwrap();
+        storage
+            .set_label_field_visibility(label.id(), &phone_id, true)
+            .unwrap();
+        storage
+            .set_label_field_visibility(label.id(), "gone", true)
+            .unwrap();
+        storage
+            .save_contact_override("bob-id", "gone", true)
+            .unwrap();
+        storage
+            .save_contact_override("carol-id", "gone", false)
+            .unwrap();
+
+        assert_eq!(storage.prune_orphaned_visibility().unwrap(), 3);
+        assert_eq!(storage.prune_orphaned_visibility().unwrap(), 0);
+
+        let label = storage.load_label(label.id()).unwrap();
+        assert_eq!(label.visible_fields().len(), 1);
+        assert!(label.is_field_visible(&phone_id));
+        assert!(storage.load_all_contact_overrides().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_add_contacts_to_label_in_bulk() {
+        let storage = test_storage();
+        let ids: Vec<String> = ["Alice", "Bob", "Carol"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let contact = Contact::from_exchange(
+                    [i as u8 + 1; 32],
+                    ContactCard::new(name),
+                    SymmetricKey::generate(),
+                );
+                storage.save_contact(&contact).unwrap();
+                contact.id().to_string()
+            })
+            .collect();
+        let label = storage.create_label("Friends").unwrap();
+
+        assert_eq!(storage.add_contacts_to_label(label.id(), &ids).unwrap(), 3);
+        let loaded = storage.load_label(label.id()).unwrap();
+        assert_eq!(loaded.contact_count(), 3);
+        assert!(ids.iter().all(|id| loaded.contains_contact(id)));
+
+        // Re-adding is a no-op
+        assert_eq!(storage.add_contacts_to_label(label.id(), &ids).unwrap(), 0);
+
+        assert_eq!(
+            storage
+                .remove_contacts_from_label(label.id(), &ids[..2])
+                .unwrap(),
+            2
+        );
+        let loaded = storage.load_label(label.id()).unwrap();
+        assert_eq!(loaded.contact_count(), 1);
+        assert!(loaded.contains_contact(&ids[2]));
+    }
+
+    #[test]
+    fn test_add_contacts_to_label_unknown_contact_changes_nothing() {
+        let storage = test_storage();
+        let label = storage.create_label("Friends").unwrap();
+        let contact = Contact::from_exchange(
+            [1u8; 32],
+            ContactCard::new("Alice"),
+            SymmetricKey::generate(),
+        );
+        storage.save_contact(&contact).unwrap();
+
+        let ids = vec![contact.id().to_string(), "missing".to_string()];
+        let result = storage.add_contacts_to_label(label.id(), &ids);
+
+        assert!(matches!(result, Err(StorageError::NotFound(_))));
+        assert_eq!(storage.load_label(label.id()).unwrap().contact_count(), 0);
+    }
+
+    #[test]
+    fn test_label_style() {
+        let storage = test_storage();
+
+        let plain = storage.create_label("Family").unwrap();
+        assert_eq!(plain.color(), None);
+        assert_eq!(plain.icon(), None);
+
+        let work = storage
+            .create_label_with_style("Work", Some("#1E88E5"), Some("briefcase"))
+            .unwrap();
+        let loaded = storage.load_label(work.id()).unwrap();
+        assert_eq!(loaded.color(), Some("#1E88E5"));
+        assert_eq!(loaded.icon(), Some("briefcase"));
+
+        storage
+            .update_label_style(plain.id(), Some("#f80"), None)
+            .unwrap();
+        let labels = storage.load_all_labels().unwrap();
+        let family = labels.iter().find(|l| l.name() == "Family").unwrap();
+        assert_eq!(family.color(), Some("#f80"));
+        assert_eq!(family.icon(), None);
+
+        // Clearing the style
+        storage.update_label_style(work.id(), None, None).unwrap();
+        let loaded = storage.load_label(work.id()).unwrap();
+        assert_eq!(loaded.color(), None);
+        assert_eq!(loaded.icon(), None);
+    }
+
+    #[test]
+    fn test_label_style_rejects_invalid_color() {
+        let storage = test_storage();
+
+        for color in ["red", "#12345", "#GGGGGG", "1E88E5"] {
+            let result = storage.create_label_with_style("Work", Some(color), None);
+            assert!(matches!(result, Err(StorageError::InvalidData(_))));
+        }
+        // No half-created label is left behind
+        assert!(storage.load_all_labels().unwrap().is_empty());
+
+        let label = storage.create_label("Family").unwrap();
+        assert!(matches!(
+            storage.update_label_style(label.id(), Some("blue"), None),
+            Err(StorageError::InvalidData(_))
+        ));
+        assert_eq!(storage.load_label(label.id()).unwrap().color(), None);
+    }
 }
@@ -195,6 +195,121 @@ pub fn all_migrations() -> Vec<Migration> {
             name: "recovery_tables",
             action: MigrationAction::Sql(MIGRATION_V8_RECOVERY),
         },
+        Migration {
+            version: 9,
+            name: "contact_verified_at",
+            action: MigrationAction::Sql(MIGRATION_V9_VERIFIED_AT),
+        },
+        Migration {
+            version: 10,
+            name: "contact_verified_by",
+            action: MigrationAction::Sql(MIGRATION_V10_VERIFIED_BY),
+        },
+        Migration {
+            version: 11,
+            name: "processed_messages",
+            action: MigrationAction::Sql(MIGRATION_V11_PROCESSED_MESSAGES),
+        },
+        Migration {
+            version: 12,
+            name: "contact_tags",
+            action: MigrationAction::Sql(MIGRATION_V12_CONTACT_TAGS),
+        },
+        Migration {
+            version: 13,
+            name: "validation_rate_limits",
+            action: MigrationAction::Sql(MIGRATION_V13_VALIDATION_RATE_LIMITS),
+        },
+        Migration {
+            version: 14,
+            name: "address_book",
+            action: MigrationAction::Sql(MIGRATION_V14_ADDRESS_BOOK),
+        },
+        Migration {
+            version: 15,
+            name: "default_field_visibility",
+            action: MigrationAction::Sql(MIGRATION_V15_DEFAULT_FIELD_VISIBILITY),
+        },
+        Migration {
+            version: 16,
+            name: "pending_exchanges",
+            action: MigrationAction::Sql(MIGRATION_V16_PENDING_EXCHANGES),
+        },
+        Migration {
+            version: 17,
+            name: "label_style",
+            action: MigrationAction::Sql(MIGRATION_V17_LABEL_STYLE),
+        },
+        Migration {
+            version: 18,
+            name: "contact_limit_default",
+            action: MigrationAction::Sql(MIGRATION_V18_CONTACT_LIMIT_DEFAULT),
+        },
+        Migration {
+            version: 19,
+            name: "contact_tombstones",
+            action: MigrationAction::Sql(MIGRATION_V19_CONTACT_TOMBSTONES),
+        },
+        Migration {
+            version: 20,
+            name: "delivery_receipts",
+            action: MigrationAction::Sql(MIGRATION_V20_DELIVERY_RECEIPTS),
+        },
+        Migration {
+            version: 21,
+            name: "contact_muted",
+            action: MigrationAction::Sql(MIGRATION_V21_CONTACT_MUTED),
+        },
+        Migration {
+            version: 22,
+            name: "sync_cursors",
+            action: MigrationAction::Sql(MIGRATION_V22_SYNC_CURSORS),
+        },
+        Migration {
+            version: 23,
+            name: "pending_rekeys",
+            action: MigrationAction::Sql(MIGRATION_V23_PENDING_REKEYS),
+        },
+        Migration {
+            version: 24,
+            name: "reference_contacts",
+            action: MigrationAction::Sql(MIGRATION_V24_REFERENCE_CONTACTS),
+        },
+        Migration {
+            version: 25,
+            name: "own_card_draft",
+            action: MigrationAction::Sql(MIGRATION_V25_OWN_CARD_DRAFT),
+        },
+        Migration {
+            version: 26,
+            name: "issuer_attestations",
+            action: MigrationAction::Sql(MIGRATION_V26_ISSUER_ATTESTATIONS),
+        },
+        Migration {
+            version: 27,
+            name: "clock_watermark",
+            action: MigrationAction::Sql(MIGRATION_V27_CLOCK_WATERMARK),
+        },
+        Migration {
+            version: 28,
+            name: "public_card",
+            action: MigrationAction::Sql(MIGRATION_V28_PUBLIC_CARD),
+        },
+        Migration {
+            version: 29,
+            name: "usage_counters",
+            action: MigrationAction::Sql(MIGRATION_V29_USAGE_COUNTERS),
+        },
+        Migration {
+            version: 30,
+            name: "contact_accepts_padding",
+            action: MigrationAction::Sql(MIGRATION_V30_CONTACT_ACCEPTS_PADDING),
+        },
+        Migration {
+            version: 31,
+            name: "settings",
+            action: MigrationAction::Sql(MIGRATION_V31_SETTINGS),
+        },
     ]
 }
 
@@ -496,10 +611,10 @@ const MIGRATION_V4_CONTACT_ENHANCEMENTS: &str = "
 
     CREATE TABLE IF NOT EXISTS contact_limits (
         id INTEGER PRIMARY KEY CHECK (id = 1),
-        max_contacts INTEGER DEFAULT 500
+        max_contacts INTEGER DEFAULT 10000
     );
 
-    INSERT OR IGNORE INTO contact_limits (id, max_contacts) VALUES (1, 500);
+    INSERT OR IGNORE INTO contact_limits (id, max_contacts) VALUES (1, 10000);
 ";
 
 /// Migration v5: GDPR consent records and audit log.
@@ -554,3 +669,224 @@ const MIGRATION_V8_RECOVERY: &str = "
         window_start INTEGER NOT NULL
     );
 ";
+
+/// Migration v9: Timestamp of the last fingerprint verification per contact.
+const MIGRATION_V9_VERIFIED_AT: &str = "
+    ALTER TABLE contacts ADD COLUMN verified_at INTEGER;
+";
+
+/// Migration v10: Attester who vouched for a contact's verification, if any.
+const MIGRATION_V10_VERIFIED_BY: &str = "
+    ALTER TABLE contacts ADD COLUMN verified_by TEXT;
+";
+
+/// Migration v11: IDs of inbound relay messages that were already processed.
+const MIGRATION_V11_PROCESSED_MESSAGES: &str = "
+    CREATE TABLE IF NOT EXISTS processed_messages (
+        message_id TEXT PRIMARY KEY,
+        processed_at INTEGER NOT NULL
+    );
+";
+
+/// Migration v12: Free-text contact tags, independent of visibility labels.
+const MIGRATION_V12_CONTACT_TAGS: &str = "
+    CREATE TABLE IF NOT EXISTS contact_tags (
+        contact_id TEXT NOT NULL,
+        tag TEXT NOT NULL,
+        PRIMARY KEY (contact_id, tag)
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_contact_tags_tag ON contact_tags(tag);
+";
+
+/// Migration v13: Per-validator validation creation counts, and the user's
+/// own daily cap on them.
+const MIGRATION_V13_VALIDATION_RATE_LIMITS: &str = "
+    CREATE TABLE IF NOT EXISTS validation_rate_limits (
+        validator_id TEXT PRIMARY KEY,
+        validation_count INTEGER NOT NULL DEFAULT 0,
+        window_start INTEGER NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS validation_daily_limit (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        max_per_day INTEGER NOT NULL
+    );
+";
+
+/// Migration v14: Entries imported from the native address book.
+const MIGRATION_V14_ADDRESS_BOOK: &str = "
+    CREATE TABLE IF NOT EXISTS address_book (
+        native_id TEXT PRIMARY KEY,
+        entry_encrypted BLOB NOT NULL,
+        imported_at INTEGER NOT NULL
+    );
+";
+
+/// Migration v15: Visibility policy applied to newly added fields.
+///
+/// Fields added under a non-everyone policy are recorded as hidden, so
+/// contacts without a rule of their own do not see them, including contacts
+/// added later.
+const MIGRATION_V15_DEFAULT_FIELD_VISIBILITY: &str = "
+    CREATE TABLE IF NOT EXISTS default_field_visibility (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        policy_json TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS hidden_default_fields (
+        field_id TEXT PRIMARY KEY
+    );
+";
+
+/// Migration v16: Delivery state of outbound exchange messages; a message is
+/// cleared to NULL once delivered. Also the confirmations still expected from
+/// password exchange initiators, by contact.
+const MIGRATION_V16_PENDING_EXCHANGES: &str = "
+    CREATE TABLE IF NOT EXISTS pending_exchanges (
+        contact_id TEXT PRIMARY KEY,
+        message_encrypted BLOB,
+        created_at INTEGER NOT NULL,
+        attempts INTEGER NOT NULL DEFAULT 0,
+        delivered_at INTEGER
+    );
+
+    CREATE TABLE IF NOT EXISTS password_confirmations (
+        contact_id TEXT PRIMARY KEY,
+        expected_encrypted BLOB NOT NULL,
+        created_at INTEGER NOT NULL
+    );
+";
+
+/// Migration v17: Optional display color and icon for visibility labels.
+const MIGRATION_V17_LABEL_STYLE: &str = "
+    ALTER TABLE visibility_labels ADD COLUMN color TEXT;
+    ALTER TABLE visibility_labels ADD COLUMN icon TEXT;
+";
+
+/// Migration v18: Raise the contact limit column default now that the limit
+/// is enforced. Stored limits are kept as they are.
+const MIGRATION_V18_CONTACT_LIMIT_DEFAULT: &str = "
+    CREATE TABLE contact_limits_v18 (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        max_contacts INTEGER DEFAULT 10000
+    );
+    INSERT INTO contact_limits_v18 (id, max_contacts)
+    SELECT id, max_contacts FROM contact_limits;
+    DROP TABLE contact_limits;
+    ALTER TABLE contact_limits_v18 RENAME TO contact_limits;
+";
+
+/// Migration v19: Tombstones for deleted contacts, each retaining the
+/// contact's row and ratchet as one encrypted blob so the deletion can be
+/// undone.
+const MIGRATION_V19_CONTACT_TOMBSTONES: &str = "
+    CREATE TABLE IF NOT EXISTS contact_tombstones (
+        contact_id TEXT PRIMARY KEY,
+        deleted_at INTEGER NOT NULL,
+        contact_encrypted BLOB NOT NULL
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_tombstone_deleted_at ON contact_tombstones(deleted_at);
+";
+
+/// Migration v20: Relay-signed delivery receipts, kept alongside delivery records.
+const MIGRATION_V20_DELIVERY_RECEIPTS: &str = "
+    CREATE TABLE IF NOT EXISTS delivery_receipts (
+        message_id TEXT PRIMARY KEY,
+        recipient_id TEXT NOT NULL,
+        server_timestamp INTEGER NOT NULL,
+        signature BLOB NOT NULL,
+        received_at INTEGER NOT NULL
+    );
+";
+
+/// Migration v21: Per-contact mute flag for change notifications.
+const MIGRATION_V21_CONTACT_MUTED: &str = "
+    ALTER TABLE contacts ADD COLUMN muted INTEGER DEFAULT 0;
+";
+
+/// Migration v22: Per-relay sync cursors (last processed message ID).
+const MIGRATION_V22_SYNC_CURSORS: &str = "
+    CREATE TABLE IF NOT EXISTS sync_cursors (
+        relay_url TEXT PRIMARY KEY,
+        message_id TEXT NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+";
+
+/// Migration v23: Outstanding re-key requests, by contact.
+const MIGRATION_V23_PENDING_REKEYS: &str = "
+    CREATE TABLE IF NOT EXISTS pending_rekeys (
+        contact_id TEXT PRIMARY KEY,
+        requested_at INTEGER NOT NULL
+    );
+";
+
+/// Migration v24: Flag for contacts imported from an export rather than exchanged.
+const MIGRATION_V24_REFERENCE_CONTACTS: &str = "
+    ALTER TABLE contacts ADD COLUMN is_reference INTEGER DEFAULT 0;
+";
+
+/// Migration v25: Unpublished edits to the own card.
+const MIGRATION_V25_OWN_CARD_DRAFT: &str = "
+    CREATE TABLE IF NOT EXISTS own_card_draft (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        card_json TEXT NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+";
+
+/// Migration v26: Issuer attestations of contacts' fields.
+const MIGRATION_V26_ISSUER_ATTESTATIONS: &str = "
+    CREATE TABLE IF NOT EXISTS issuer_attestations (
+        id TEXT PRIMARY KEY,
+        contact_id TEXT NOT NULL,
+        field_id TEXT NOT NULL,
+        issuer_id TEXT NOT NULL,
+        attestation BLOB NOT NULL,
+        UNIQUE(contact_id, field_id, issuer_id)
+    );
+    CREATE INDEX IF NOT EXISTS idx_issuer_attestation_field
+        ON issuer_attestations(contact_id, field_id);
+";
+
+/// Migration v27: Latest local time seen, to detect the clock going back.
+const MIGRATION_V27_CLOCK_WATERMARK: &str = "
+    CREATE TABLE IF NOT EXISTS clock_watermark (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        max_seen INTEGER NOT NULL
+    );
+";
+
+/// Migration v28: The card published at a public permalink, if any.
+const MIGRATION_V28_PUBLIC_CARD: &str = "
+    CREATE TABLE IF NOT EXISTS public_card (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        handle TEXT NOT NULL,
+        label_id TEXT NOT NULL,
+        card_hash BLOB NOT NULL,
+        published_at INTEGER NOT NULL
+    );
+";
+
+/// Migration v29: Local usage counters, never synced.
+const MIGRATION_V29_USAGE_COUNTERS: &str = "
+    CREATE TABLE IF NOT EXISTS usage_counters (
+        counter TEXT PRIMARY KEY,
+        count INTEGER NOT NULL
+    );
+";
+
+/// Migration v30: Whether a contact advertised that it reads padded updates.
+const MIGRATION_V30_CONTACT_ACCEPTS_PADDING: &str = "
+    ALTER TABLE contacts ADD COLUMN accepts_padding INTEGER DEFAULT 0;
+";
+
+/// Migration v31: User preferences kept across restarts, as JSON by key.
+const MIGRATION_V31_SETTINGS: &str = "
+    CREATE TABLE IF NOT EXISTS settings (
+        key TEXT PRIMARY KEY,
+        value_json TEXT NOT NULL
+    );
+";
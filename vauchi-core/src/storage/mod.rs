@@ -7,11 +7,31 @@
 //! Provides encrypted local storage for contacts, identity, and sync state.
 //! Uses SQLite with application-level encryption for sensitive data.
 
+#[cfg(feature = "testing")]
+pub mod address_book;
+#[cfg(not(feature = "testing"))]
+mod address_book;
+
+#[cfg(feature = "testing")]
+pub mod archive;
+#[cfg(not(feature = "testing"))]
+mod archive;
+
+#[cfg(feature = "testing")]
+pub mod clock;
+#[cfg(not(feature = "testing"))]
+mod clock;
+
 #[cfg(feature = "testing")]
 pub mod consent;
 #[cfg(not(feature = "testing"))]
 mod consent;
 
+#[cfg(feature = "testing")]
+pub mod contact_export;
+#[cfg(not(feature = "testing"))]
+mod contact_export;
+
 #[cfg(feature = "testing")]
 pub mod contacts;
 #[cfg(not(feature = "testing"))]
@@ -27,16 +47,41 @@ pub mod error;
 #[cfg(not(feature = "testing"))]
 mod error;
 
+#[cfg(feature = "testing")]
+pub mod exchange;
+#[cfg(not(feature = "testing"))]
+mod exchange;
+
 #[cfg(feature = "testing")]
 pub mod identity;
 #[cfg(not(feature = "testing"))]
 mod identity;
 
+#[cfg(feature = "testing")]
+pub mod inbox;
+#[cfg(not(feature = "testing"))]
+mod inbox;
+
+#[cfg(feature = "testing")]
+pub mod integrity;
+#[cfg(not(feature = "testing"))]
+mod integrity;
+
+#[cfg(feature = "testing")]
+pub mod key_rotation;
+#[cfg(not(feature = "testing"))]
+mod key_rotation;
+
 #[cfg(feature = "testing")]
 pub mod labels;
 #[cfg(not(feature = "testing"))]
 mod labels;
 
+#[cfg(feature = "testing")]
+pub mod public_card;
+#[cfg(not(feature = "testing"))]
+mod public_card;
+
 #[cfg(feature = "testing")]
 pub mod pending;
 #[cfg(not(feature = "testing"))]
@@ -72,6 +117,26 @@ pub mod recovery;
 #[cfg(not(feature = "testing"))]
 mod recovery;
 
+#[cfg(feature = "testing")]
+pub mod settings;
+#[cfg(not(feature = "testing"))]
+mod settings;
+
+#[cfg(feature = "testing")]
+pub mod tags;
+#[cfg(not(feature = "testing"))]
+mod tags;
+
+#[cfg(feature = "testing")]
+pub mod tombstones;
+#[cfg(not(feature = "testing"))]
+mod tombstones;
+
+#[cfg(feature = "testing")]
+pub mod usage;
+#[cfg(not(feature = "testing"))]
+mod usage;
+
 #[cfg(feature = "testing")]
 pub mod ux;
 #[cfg(not(feature = "testing"))]
@@ -80,20 +145,35 @@ mod ux;
 pub mod migration;
 pub mod secure;
 
+pub use address_book::AddressBookEntry;
+pub use contacts::DEFAULT_MAX_CONTACTS;
 pub use error::{
     DeliveryRecord, DeliveryStatus, DeliverySummary, DeviceDeliveryRecord, DeviceDeliveryStatus,
-    OfflineQueue, PendingUpdate, RetryEntry, RetryQueue, StorageError, UpdateStatus,
+    OfflineQueue, PendingUpdate, RetryEntry, RetryQueue, StaleUpdate, StaleUpdateState,
+    StorageError, UpdateStatus,
 };
+pub use exchange::PendingExchange;
+pub use integrity::{IntegrityFailure, IntegrityReport};
+pub use public_card::PublicCardPublication;
 pub use secure::{FileKeyStorage, SecureStorage};
+pub use tombstones::{ContactTombstone, CONTACT_TOMBSTONE_RETENTION_SECS};
+pub use usage::{UsageCounter, UsageStats};
 
 #[cfg(feature = "secure-storage")]
 pub use secure::PlatformKeyring;
 
-use rusqlite::Connection;
+use rusqlite::{Connection, OpenFlags};
 use std::path::Path;
+use std::time::Duration;
 
 use crate::crypto::SymmetricKey;
 
+/// How long a connection waits on a lock held by another connection
+/// before failing with "database is locked".
+///
+/// Long enough to ride out a background sync write overlapping a UI read.
+pub const DEFAULT_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// SQLite-based storage implementation.
 ///
 /// Stores data in a local SQLite database with application-level encryption
@@ -106,16 +186,69 @@ pub struct Storage {
 
 impl Storage {
     /// Opens or creates a storage database at the given path.
+    ///
+    /// The database uses WAL journaling so readers do not block the writer,
+    /// and waits up to [`DEFAULT_BUSY_TIMEOUT`] for locks held by other
+    /// connections.
     pub fn open<P: AsRef<Path>>(
         path: P,
         encryption_key: SymmetricKey,
+    ) -> Result<Self, StorageError> {
+        Self::open_with_busy_timeout(path, encryption_key, DEFAULT_BUSY_TIMEOUT)
+    }
+
+    /// Opens or creates a storage database with a custom busy timeout.
+    pub fn open_with_busy_timeout<P: AsRef<Path>>(
+        path: P,
+        encryption_key: SymmetricKey,
+        busy_timeout: Duration,
     ) -> Result<Self, StorageError> {
         let conn = Connection::open(path)?;
+        conn.busy_timeout(busy_timeout)?;
+        // Returns the resulting mode as a row, so it can't go through execute
+        conn.query_row("PRAGMA journal_mode = WAL", [], |row| {
+            row.get::<_, String>(0)
+        })?;
         let storage = Storage {
             conn,
             encryption_key,
         };
         storage.run_migrations()?;
+        storage.purge_expired_contact_tombstones()?;
+        Ok(storage)
+    }
+
+    /// Opens an existing storage database without write access.
+    ///
+    /// Write methods fail with `StorageError::ReadOnly`, which makes this
+    /// safe for diagnostics and exports. Migrations are not run, so the
+    /// database must already be at the current schema version.
+    pub fn open_read_only<P: AsRef<Path>>(
+        path: P,
+        encryption_key: SymmetricKey,
+    ) -> Result<Self, StorageError> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        conn.busy_timeout(DEFAULT_BUSY_TIMEOUT)?;
+        let storage = Storage {
+            conn,
+            encryption_key,
+        };
+
+        let latest = migration::all_migrations()
+            .last()
+            .map(|m| m.version)
+            .unwrap_or(0);
+        let current = storage.schema_version()?;
+        if current < latest {
+            return Err(StorageError::Migration(format!(
+                "Read-only storage is at schema v{}, expected v{}",
+                current, latest
+            )));
+        }
+
         Ok(storage)
     }
 
@@ -140,4 +273,54 @@ impl Storage {
     pub fn schema_version(&self) -> Result<u32, StorageError> {
         migration::MigrationRunner::current_version(&self.conn)
     }
+
+    /// Runs `f` atomically: its writes are kept only if it returns `Ok`.
+    ///
+    /// Calls may nest, and `f` may call storage operations that are atomic
+    /// on their own.
+    pub fn atomically<T, E>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<T, E>
+    where
+        E: From<StorageError>,
+    {
+        let tx = self.transaction()?;
+        let result = f()?;
+        tx.commit()?;
+        Ok(result)
+    }
+
+    /// Starts a transaction, nested in the open one if there is one.
+    pub(crate) fn transaction(&self) -> Result<StorageTransaction<'_>, StorageError> {
+        self.conn.execute_batch("SAVEPOINT storage_tx")?;
+        Ok(StorageTransaction {
+            conn: &self.conn,
+            committed: false,
+        })
+    }
+}
+
+/// A transaction that rolls back unless committed.
+///
+/// Backed by a savepoint rather than `BEGIN`, so transactions nest.
+pub(crate) struct StorageTransaction<'a> {
+    conn: &'a Connection,
+    committed: bool,
+}
+
+impl StorageTransaction<'_> {
+    /// Keeps the transaction's writes.
+    pub(crate) fn commit(mut self) -> Result<(), StorageError> {
+        self.conn.execute_batch("RELEASE storage_tx")?;
+        self.committed = true;
+        Ok(())
+    }
+}
+
+impl Drop for StorageTransaction<'_> {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = self
+                .conn
+                .execute_batch("ROLLBACK TO storage_tx; RELEASE storage_tx");
+        }
+    }
 }
@@ -6,7 +6,7 @@
 
 use rusqlite::params;
 
-use super::error::{PendingUpdate, UpdateStatus};
+use super::error::{PendingUpdate, StaleUpdate, StaleUpdateState, UpdateStatus};
 use super::{Storage, StorageError};
 
 impl Storage {
@@ -213,6 +213,25 @@ impl Storage {
         Ok(rows_affected > 0)
     }
 
+    /// Cancels a queued update, along with any retry and delivery records
+    /// kept under its ID.
+    ///
+    /// Returns true if the update was still queued.
+    pub fn cancel_pending_update(&self, id: &str) -> Result<bool, StorageError> {
+        let tx = self.transaction()?;
+
+        let removed = self.delete_pending_update(id)?;
+        for table in ["retry_entries", "delivery_records", "device_deliveries"] {
+            self.conn.execute(
+                &format!("DELETE FROM {} WHERE message_id = ?1", table),
+                params![id],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(removed)
+    }
+
     /// Counts all pending updates across all contacts.
     pub fn count_all_pending_updates(&self) -> Result<usize, StorageError> {
         let count: i64 =
@@ -221,6 +240,21 @@ impl Storage {
         Ok(count as usize)
     }
 
+    /// Counts pending updates that a sync would send.
+    ///
+    /// Updates for blocked and reference contacts stay frozen in the queue
+    /// and are left out.
+    pub fn count_deliverable_pending_updates(&self) -> Result<usize, StorageError> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM pending_updates p
+             JOIN contacts c ON c.id = p.contact_id
+             WHERE COALESCE(c.blocked, 0) = 0 AND COALESCE(c.is_reference, 0) = 0",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(count as usize)
+    }
+
     /// Deletes all pending updates for a contact.
     ///
     /// Returns the number of deleted updates.
@@ -282,4 +316,46 @@ impl Storage {
         rows.collect::<Result<Vec<_>, _>>()
             .map_err(StorageError::Database)
     }
+
+    /// Gets outbound updates that have waited longer than `older_than_days`.
+    ///
+    /// Queued updates are reported as never sent. Delivery records that
+    /// reached the relay but were never confirmed by the recipient are
+    /// reported as not delivered, unless the update is still queued.
+    pub fn get_stale_updates(
+        &self,
+        older_than_days: u32,
+    ) -> Result<Vec<StaleUpdate>, StorageError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let cutoff = now.saturating_sub(older_than_days as u64 * 86400);
+
+        let mut stmt = self.conn.prepare(
+            "SELECT id, contact_id, created_at, 0 FROM pending_updates WHERE created_at < ?1
+             UNION ALL
+             SELECT message_id, recipient_id, created_at, status != 'queued' FROM delivery_records
+             WHERE status IN ('queued', 'sent', 'stored') AND created_at < ?1
+             AND message_id NOT IN (SELECT id FROM pending_updates)
+             ORDER BY created_at",
+        )?;
+
+        let rows = stmt.query_map(params![cutoff as i64], |row| {
+            let sent: bool = row.get(3)?;
+            Ok(StaleUpdate {
+                id: row.get(0)?,
+                contact_id: row.get(1)?,
+                created_at: row.get::<_, i64>(2)? as u64,
+                state: if sent {
+                    StaleUpdateState::NotDelivered
+                } else {
+                    StaleUpdateState::NeverSent
+                },
+            })
+        })?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(StorageError::Database)
+    }
 }
@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Public card storage operations.
+//!
+//! Remembers which handle and label the card was last published with, so
+//! it can be republished when the card changes.
+
+use rusqlite::params;
+
+use super::{Storage, StorageError};
+
+/// The card currently published at a public permalink.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicCardPublication {
+    /// Handle the card is published under.
+    pub handle: String,
+    /// Label whose visible fields are published.
+    pub label_id: String,
+    /// `content_hash` of the card as last published.
+    pub card_hash: [u8; 32],
+    /// When the card was last published (Unix seconds).
+    pub published_at: u64,
+}
+
+impl Storage {
+    /// Records a publication, replacing any earlier one.
+    pub fn save_public_card(
+        &self,
+        publication: &PublicCardPublication,
+    ) -> Result<(), StorageError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO public_card (id, handle, label_id, card_hash, published_at)
+             VALUES (1, ?1, ?2, ?3, ?4)",
+            params![
+                publication.handle,
+                publication.label_id,
+                publication.card_hash.as_slice(),
+                publication.published_at as i64
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Loads the current publication, if the card is published.
+    pub fn load_public_card(&self) -> Result<Option<PublicCardPublication>, StorageError> {
+        let result = self.conn.query_row(
+            "SELECT handle, label_id, card_hash, published_at FROM public_card WHERE id = 1",
+            [],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Vec<u8>>(2)?,
+                    row.get::<_, i64>(3)?,
+                ))
+            },
+        );
+
+        let (handle, label_id, card_hash, published_at) = match result {
+            Ok(row) => row,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(None),
+            Err(e) => return Err(StorageError::Database(e)),
+        };
+        let card_hash = card_hash
+            .try_into()
+            .map_err(|_| StorageError::InvalidData("public card hash is not 32 bytes".into()))?;
+
+        Ok(Some(PublicCardPublication {
+            handle,
+            label_id,
+            card_hash,
+            published_at: published_at as u64,
+        }))
+    }
+
+    /// Forgets the publication. Returns true if there was one.
+    pub fn clear_public_card(&self) -> Result<bool, StorageError> {
+        let deleted = self
+            .conn
+            .execute("DELETE FROM public_card WHERE id = 1", [])?;
+        Ok(deleted > 0)
+    }
+}
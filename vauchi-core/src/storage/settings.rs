@@ -0,0 +1,45 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Settings storage operations.
+//!
+//! User preferences that must survive a restart, stored as JSON under a
+//! key chosen by the caller.
+
+use rusqlite::params;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::{Storage, StorageError};
+
+impl Storage {
+    /// Saves a setting, replacing any previous value under `key`.
+    pub fn save_setting<T: Serialize>(&self, key: &str, value: &T) -> Result<(), StorageError> {
+        let value_json =
+            serde_json::to_string(value).map_err(|e| StorageError::Serialization(e.to_string()))?;
+
+        self.conn.execute(
+            "INSERT OR REPLACE INTO settings (key, value_json) VALUES (?1, ?2)",
+            params![key, value_json],
+        )?;
+        Ok(())
+    }
+
+    /// Loads the setting saved under `key`, if any.
+    pub fn load_setting<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, StorageError> {
+        let result = self.conn.query_row(
+            "SELECT value_json FROM settings WHERE key = ?1",
+            params![key],
+            |row| row.get::<_, String>(0),
+        );
+
+        match result {
+            Ok(json) => serde_json::from_str(&json)
+                .map(Some)
+                .map_err(|e| StorageError::Serialization(e.to_string())),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(StorageError::Database(e)),
+        }
+    }
+}
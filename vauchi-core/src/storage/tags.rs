@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Contact tag storage operations.
+//!
+//! Tags are free-text categories for organizing contacts. Unlike
+//! visibility labels they never affect what is shared or synced.
+
+use rusqlite::params;
+
+use super::{Storage, StorageError};
+use crate::contact::Contact;
+
+/// Maximum length of a tag in characters.
+const MAX_TAG_LENGTH: usize = 50;
+
+/// Trims and validates a tag.
+fn normalize_tag(tag: &str) -> Result<&str, StorageError> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err(StorageError::InvalidData("Tag cannot be empty".to_string()));
+    }
+    if tag.chars().count() > MAX_TAG_LENGTH {
+        return Err(StorageError::InvalidData(format!(
+            "Tag cannot exceed {} characters",
+            MAX_TAG_LENGTH
+        )));
+    }
+    Ok(tag)
+}
+
+impl Storage {
+    // === Contact Tag Operations ===
+
+    /// Tags a contact.
+    ///
+    /// Returns `true` if the tag was added, `false` if the contact already had it.
+    pub fn add_contact_tag(&self, contact_id: &str, tag: &str) -> Result<bool, StorageError> {
+        let tag = normalize_tag(tag)?;
+        let inserted = self.conn.execute(
+            "INSERT OR IGNORE INTO contact_tags (contact_id, tag) VALUES (?1, ?2)",
+            params![contact_id, tag],
+        )?;
+        Ok(inserted > 0)
+    }
+
+    /// Removes a tag from a contact.
+    ///
+    /// Returns `true` if the contact had the tag.
+    pub fn remove_contact_tag(&self, contact_id: &str, tag: &str) -> Result<bool, StorageError> {
+        let removed = self.conn.execute(
+            "DELETE FROM contact_tags WHERE contact_id = ?1 AND tag = ?2",
+            params![contact_id, tag.trim()],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// Returns a contact's tags in alphabetical order.
+    pub fn get_contact_tags(&self, contact_id: &str) -> Result<Vec<String>, StorageError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT tag FROM contact_tags WHERE contact_id = ?1 ORDER BY tag")?;
+        let tags = stmt
+            .query_map(params![contact_id], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+        Ok(tags)
+    }
+
+    /// Lists all contacts with the given tag, pinned first, then by display name.
+    pub fn list_contacts_by_tag(&self, tag: &str) -> Result<Vec<Contact>, StorageError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT c.id FROM contacts c
+             JOIN contact_tags t ON t.contact_id = c.id
+             WHERE t.tag = ?1
+             ORDER BY c.favorite DESC, c.display_name",
+        )?;
+        let ids = stmt
+            .query_map(params![tag.trim()], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+
+        let mut contacts = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(contact) = self.load_contact(&id)? {
+                contacts.push(contact);
+            }
+        }
+        Ok(contacts)
+    }
+
+    /// Removes all tags from a contact.
+    pub fn delete_contact_tags(&self, contact_id: &str) -> Result<(), StorageError> {
+        self.conn.execute(
+            "DELETE FROM contact_tags WHERE contact_id = ?1",
+            params![contact_id],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tombstones for deleted contacts.
+//!
+//! Deleting a contact moves its row and ratchet state into
+//! `contact_tombstones` as one blob, encrypted under the storage key. The
+//! rows are kept column by column, so columns added to `contacts` later are
+//! carried without touching the tombstone schema. Within
+//! [`CONTACT_TOMBSTONE_RETENTION_SECS`] the deletion can be undone; after
+//! that the tombstone is purged, the next time storage is opened or a
+//! contact is deleted, and the contact is gone for good.
+
+use rusqlite::params;
+use rusqlite::types::Value;
+use serde::{Deserialize, Serialize};
+
+use super::archive::{is_identifier, ArchiveValue, ENCRYPTED_COLUMN_SUFFIX};
+use super::{Storage, StorageError};
+use crate::crypto::{decrypt, encrypt};
+
+/// How long a deleted contact can be restored before its tombstone is purged.
+pub const CONTACT_TOMBSTONE_RETENTION_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// A recently deleted contact.
+#[derive(Debug, Clone)]
+pub struct ContactTombstone {
+    /// The deleted contact's ID (hex public key).
+    pub contact_id: String,
+    /// Display name at the time of deletion.
+    pub display_name: String,
+    /// When the contact was deleted.
+    pub deleted_at: u64,
+}
+
+/// One stored row, by column. Values of `*_encrypted` columns are held
+/// decrypted, as the whole tombstone is encrypted.
+#[derive(Debug, Serialize, Deserialize)]
+struct TombstoneRow {
+    columns: Vec<String>,
+    values: Vec<ArchiveValue>,
+}
+
+/// Contents of a tombstone: the contact's row and its ratchet, if any.
+#[derive(Debug, Serialize, Deserialize)]
+struct TombstoneContents {
+    contact: TombstoneRow,
+    ratchet: Option<TombstoneRow>,
+}
+
+impl TombstoneRow {
+    /// Value of a text column, if present.
+    fn text(&self, column: &str) -> Option<&str> {
+        let i = self.columns.iter().position(|c| c == column)?;
+        match &self.values[i] {
+            ArchiveValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system time before UNIX epoch")
+        .as_secs()
+}
+
+impl Storage {
+    // === Contact Tombstone Operations ===
+
+    /// Copies a contact and its ratchet state into a tombstone.
+    ///
+    /// Does nothing if the contact does not exist. Callers delete the
+    /// contact afterwards.
+    pub(super) fn record_contact_tombstone(&self, id: &str) -> Result<(), StorageError> {
+        let Some(contact) = self.read_row("contacts", "id", id)? else {
+            return Ok(());
+        };
+        let contents = TombstoneContents {
+            contact,
+            ratchet: self.read_row("contact_ratchets", "contact_id", id)?,
+        };
+        let plaintext = bincode::serialize(&contents)
+            .map_err(|e| StorageError::Serialization(e.to_string()))?;
+        let contact_encrypted = encrypt(&self.encryption_key, &plaintext)
+            .map_err(|e| StorageError::Encryption(e.to_string()))?;
+
+        self.conn.execute(
+            "INSERT OR REPLACE INTO contact_tombstones (contact_id, deleted_at, contact_encrypted)
+             VALUES (?1, ?2, ?3)",
+            params![id, now() as i64, contact_encrypted],
+        )?;
+        Ok(())
+    }
+
+    /// Returns true if a contact with this ID was deleted and its tombstone
+    /// has not been purged yet.
+    pub fn was_contact_deleted(&self, id: &str) -> Result<bool, StorageError> {
+        let exists: bool = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM contact_tombstones WHERE contact_id = ?1)",
+            params![id],
+            |row| row.get(0),
+        )?;
+        Ok(exists)
+    }
+
+    /// Lists tombstones of deleted contacts, most recent first.
+    ///
+    /// Contacts that have been re-added since are left out.
+    pub fn list_contact_tombstones(&self) -> Result<Vec<ContactTombstone>, StorageError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT contact_id, deleted_at, contact_encrypted
+             FROM contact_tombstones
+             WHERE contact_id NOT IN (SELECT id FROM contacts)
+             ORDER BY deleted_at DESC",
+        )?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)? as u64,
+                    row.get::<_, Vec<u8>>(2)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.into_iter()
+            .map(|(contact_id, deleted_at, contact_encrypted)| {
+                let contents = self.open_tombstone(&contact_encrypted)?;
+                Ok(ContactTombstone {
+                    contact_id,
+                    display_name: contents
+                        .contact
+                        .text("display_name")
+                        .unwrap_or_default()
+                        .to_string(),
+                    deleted_at,
+                })
+            })
+            .collect()
+    }
+
+    /// Restores a deleted contact from its tombstone.
+    ///
+    /// Returns `false` if there is no tombstone within the retention
+    /// period, or if the contact has been re-added since. Tags and
+    /// undelivered exchange messages are not restored.
+    pub fn restore_deleted_contact(&self, id: &str) -> Result<bool, StorageError> {
+        let cutoff = now().saturating_sub(CONTACT_TOMBSTONE_RETENTION_SECS);
+
+        let tx = self.transaction()?;
+        let result = self.conn.query_row(
+            "SELECT contact_encrypted FROM contact_tombstones
+             WHERE contact_id = ?1 AND deleted_at >= ?2
+               AND contact_id NOT IN (SELECT id FROM contacts)",
+            params![id, cutoff as i64],
+            |row| row.get::<_, Vec<u8>>(0),
+        );
+        let contact_encrypted = match result {
+            Ok(blob) => blob,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(false),
+            Err(e) => return Err(StorageError::Database(e)),
+        };
+        let contents = self.open_tombstone(&contact_encrypted)?;
+
+        self.insert_row("contacts", contents.contact)?;
+        if let Some(ratchet) = contents.ratchet {
+            self.insert_row("contact_ratchets", ratchet)?;
+        }
+        self.conn.execute(
+            "DELETE FROM contact_tombstones WHERE contact_id = ?1",
+            params![id],
+        )?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    /// Purges tombstones that have outlived the retention period.
+    ///
+    /// Runs when storage is opened and on every contact deletion, so
+    /// expired contact data does not linger until the UI lists it.
+    pub(super) fn purge_expired_contact_tombstones(&self) -> Result<usize, StorageError> {
+        self.purge_contact_tombstones(now())
+    }
+
+    /// Purges tombstones older than the retention period as of `now`.
+    ///
+    /// Returns the number of purged tombstones.
+    pub fn purge_contact_tombstones(&self, now: u64) -> Result<usize, StorageError> {
+        let cutoff = now.saturating_sub(CONTACT_TOMBSTONE_RETENTION_SECS);
+        let rows_affected = self.conn.execute(
+            "DELETE FROM contact_tombstones WHERE deleted_at < ?1",
+            params![cutoff as i64],
+        )?;
+        Ok(rows_affected)
+    }
+
+    /// Decrypts and parses a tombstone blob.
+    fn open_tombstone(&self, contact_encrypted: &[u8]) -> Result<TombstoneContents, StorageError> {
+        let plaintext = decrypt(&self.encryption_key, contact_encrypted)
+            .map_err(|e| StorageError::Encryption(e.to_string()))?;
+        bincode::deserialize(&plaintext).map_err(|e| StorageError::Serialization(e.to_string()))
+    }
+
+    /// Reads the row of `table` whose `key` column equals `id`, decrypting
+    /// `*_encrypted` columns.
+    fn read_row(
+        &self,
+        table: &str,
+        key: &str,
+        id: &str,
+    ) -> Result<Option<TombstoneRow>, StorageError> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT * FROM \"{}\" WHERE \"{}\" = ?1",
+            table, key
+        ))?;
+        let columns: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+
+        let mut query = stmt.query(params![id])?;
+        let Some(row) = query.next()? else {
+            return Ok(None);
+        };
+        let mut values = Vec::with_capacity(columns.len());
+        for (i, column) in columns.iter().enumerate() {
+            values.push(match ArchiveValue::from(row.get_ref(i)?) {
+                ArchiveValue::Blob(b) if column.ends_with(ENCRYPTED_COLUMN_SUFFIX) => {
+                    ArchiveValue::Blob(
+                        decrypt(&self.encryption_key, &b)
+                            .map_err(|e| StorageError::Encryption(e.to_string()))?,
+                    )
+                }
+                other => other,
+            });
+        }
+        Ok(Some(TombstoneRow { columns, values }))
+    }
+
+    /// Inserts a row read by `read_row`, re-encrypting `*_encrypted` columns.
+    fn insert_row(&self, table: &str, row: TombstoneRow) -> Result<(), StorageError> {
+        if !row.columns.iter().all(|c| is_identifier(c)) || row.columns.len() != row.values.len() {
+            return Err(StorageError::InvalidData(format!(
+                "Malformed tombstone row for {}",
+                table
+            )));
+        }
+
+        let column_list = row
+            .columns
+            .iter()
+            .map(|c| format!("\"{}\"", c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let placeholders = (1..=row.columns.len())
+            .map(|i| format!("?{}", i))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut values = Vec::with_capacity(row.values.len());
+        for (value, column) in row.values.into_iter().zip(&row.columns) {
+            values.push(match value {
+                ArchiveValue::Blob(b) if column.ends_with(ENCRYPTED_COLUMN_SUFFIX) => Value::Blob(
+                    encrypt(&self.encryption_key, &b)
+                        .map_err(|e| StorageError::Encryption(e.to_string()))?,
+                ),
+                other => Value::from(other),
+            });
+        }
+
+        self.conn.execute(
+            &format!(
+                "INSERT OR REPLACE INTO \"{}\" ({}) VALUES ({})",
+                table, column_list, placeholders
+            ),
+            rusqlite::params_from_iter(values),
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Usage counter storage operations.
+//!
+//! Counts a few milestones for the user's own curiosity. The counters live
+//! only in the local database; they are never synced, exported or sent.
+
+use rusqlite::params;
+
+use super::{Storage, StorageError};
+
+/// Something the user did that is worth counting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UsageCounter {
+    /// An exchange saved a contact.
+    ExchangesCompleted,
+    /// A scanned QR code could not be read as an exchange code.
+    FailedQrScans,
+    /// A contact's fingerprint was verified.
+    ContactsVerified,
+}
+
+impl UsageCounter {
+    fn key(&self) -> &'static str {
+        match self {
+            UsageCounter::ExchangesCompleted => "exchanges_completed",
+            UsageCounter::FailedQrScans => "failed_qr_scans",
+            UsageCounter::ContactsVerified => "contacts_verified",
+        }
+    }
+}
+
+/// Totals of all usage counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UsageStats {
+    /// Exchanges that saved a contact.
+    pub exchanges_completed: u64,
+    /// QR codes that could not be read.
+    pub failed_qr_scans: u64,
+    /// Contacts whose fingerprint was verified.
+    pub contacts_verified: u64,
+}
+
+impl Storage {
+    /// Adds `amount` to a usage counter.
+    pub fn increment_usage_counter(
+        &self,
+        counter: UsageCounter,
+        amount: u64,
+    ) -> Result<(), StorageError> {
+        self.conn.execute(
+            "INSERT INTO usage_counters (counter, count) VALUES (?1, ?2)
+             ON CONFLICT(counter) DO UPDATE SET count = count + ?2",
+            params![counter.key(), amount as i64],
+        )?;
+        Ok(())
+    }
+
+    /// Loads all usage counters; counters never incremented are zero.
+    pub fn load_usage_stats(&self) -> Result<UsageStats, StorageError> {
+        let count = |counter: UsageCounter| -> Result<u64, StorageError> {
+            let result = self.conn.query_row(
+                "SELECT count FROM usage_counters WHERE counter = ?1",
+                params![counter.key()],
+                |row| row.get::<_, i64>(0),
+            );
+            match result {
+                Ok(count) => Ok(count as u64),
+                Err(rusqlite::Error::QueryReturnedNoRows) => Ok(0),
+                Err(e) => Err(StorageError::Database(e)),
+            }
+        };
+
+        Ok(UsageStats {
+            exchanges_completed: count(UsageCounter::ExchangesCompleted)?,
+            failed_qr_scans: count(UsageCounter::FailedQrScans)?,
+            contacts_verified: count(UsageCounter::ContactsVerified)?,
+        })
+    }
+}
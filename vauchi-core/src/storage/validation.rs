@@ -7,7 +7,8 @@
 use rusqlite::params;
 
 use super::{Storage, StorageError};
-use crate::social::ProfileValidation;
+use crate::social::{IssuerAttestation, ProfileValidation, ValidationBundle};
+use crate::Identity;
 
 impl Storage {
     /// Saves a field validation to storage.
@@ -220,4 +221,171 @@ impl Storage {
 
         Ok(count > 0)
     }
+
+    // === Validation Bundle Operations ===
+
+    /// Exports all validations made by `identity` as a signed bundle.
+    pub fn export_my_validations_signed(
+        &self,
+        identity: &Identity,
+    ) -> Result<ValidationBundle, StorageError> {
+        let validator_id = hex::encode(identity.signing_public_key());
+        let validations = self.load_validations_by_validator(&validator_id)?;
+        Ok(ValidationBundle::create(identity, validations))
+    }
+
+    /// Imports a validator's signed bundle as received validations.
+    ///
+    /// The bundle is rejected as a whole if its signature or that of any
+    /// validation in it does not verify. Returns the number stored.
+    pub fn import_validations_bundle(
+        &self,
+        bundle: &ValidationBundle,
+    ) -> Result<usize, StorageError> {
+        if !bundle.verify() {
+            return Err(StorageError::InvalidData(
+                "validation bundle signature is invalid".into(),
+            ));
+        }
+
+        for validation in bundle.validations() {
+            self.save_validation(validation)?;
+        }
+
+        Ok(bundle.validations().len())
+    }
+
+    // === Issuer Attestation Operations ===
+
+    /// Saves an issuer attestation, replacing any earlier one from the
+    /// same issuer for the same field.
+    ///
+    /// Rejects attestations whose signature does not verify.
+    pub fn save_issuer_attestation(
+        &self,
+        attestation: &IssuerAttestation,
+    ) -> Result<(), StorageError> {
+        if !attestation.verify() {
+            return Err(StorageError::InvalidData(
+                "issuer attestation signature is invalid".into(),
+            ));
+        }
+
+        let contact_id = attestation.subject_id();
+        let issuer_id = attestation.issuer_id();
+        let id = format!("{}:{}:{}", contact_id, attestation.field_id(), issuer_id);
+
+        self.conn.execute(
+            "INSERT OR REPLACE INTO issuer_attestations
+             (id, contact_id, field_id, issuer_id, attestation)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                id,
+                contact_id,
+                attestation.field_id(),
+                issuer_id,
+                attestation.to_bytes(),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Loads the issuer attestations of a contact's field.
+    ///
+    /// Attestations of earlier values are included; see
+    /// `IssuerAttestation::matches`.
+    pub fn load_issuer_attestations_for_field(
+        &self,
+        contact_id: &str,
+        field_id: &str,
+    ) -> Result<Vec<IssuerAttestation>, StorageError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT attestation FROM issuer_attestations
+             WHERE contact_id = ?1 AND field_id = ?2",
+        )?;
+
+        let rows = stmt.query_map(params![contact_id, field_id], |row| {
+            row.get::<_, Vec<u8>>(0)
+        })?;
+
+        let mut attestations = Vec::new();
+        for bytes in rows {
+            let attestation = IssuerAttestation::from_bytes(&bytes?)
+                .ok_or_else(|| StorageError::InvalidData("invalid issuer attestation".into()))?;
+            attestations.push(attestation);
+        }
+
+        Ok(attestations)
+    }
+
+    // === Validation Rate Limit Operations ===
+
+    /// Checks the validation rate limit for a validator.
+    ///
+    /// Returns `(count, window_start)` for the validator's current window,
+    /// or `(0, 0)` if no rate limit record exists.
+    pub fn check_validation_rate_limit(
+        &self,
+        validator_id: &str,
+    ) -> Result<(u32, u64), StorageError> {
+        let result = self.conn.query_row(
+            "SELECT validation_count, window_start
+             FROM validation_rate_limits
+             WHERE validator_id = ?1",
+            params![validator_id],
+            |row| {
+                let count: i32 = row.get(0)?;
+                let window_start: i64 = row.get(1)?;
+                Ok((count as u32, window_start as u64))
+            },
+        );
+
+        match result {
+            Ok(record) => Ok(record),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok((0, 0)),
+            Err(e) => Err(StorageError::Database(e)),
+        }
+    }
+
+    /// Updates (or inserts) the validation rate limit for a validator.
+    pub fn update_validation_rate_limit(
+        &self,
+        validator_id: &str,
+        count: u32,
+        window_start: u64,
+    ) -> Result<(), StorageError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO validation_rate_limits
+             (validator_id, validation_count, window_start)
+             VALUES (?1, ?2, ?3)",
+            params![validator_id, count as i32, window_start as i64],
+        )?;
+
+        Ok(())
+    }
+    /// Saves the daily cap on validations this user creates.
+    pub fn save_validation_daily_limit(&self, max_per_day: u32) -> Result<(), StorageError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO validation_daily_limit (id, max_per_day) VALUES (1, ?1)",
+            params![max_per_day as i64],
+        )?;
+
+        Ok(())
+    }
+
+    /// Loads the daily cap on validations this user creates, if one was set.
+    pub fn load_validation_daily_limit(&self) -> Result<Option<u32>, StorageError> {
+        let result = self.conn.query_row(
+            "SELECT max_per_day FROM validation_daily_limit WHERE id = 1",
+            [],
+            |row| row.get::<_, i64>(0),
+        );
+
+        match result {
+            Ok(max_per_day) => Ok(Some(max_per_day as u32)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(StorageError::Database(e)),
+        }
+    }
 }
@@ -7,6 +7,14 @@
 //! Provides efficient delta-based updates that only transmit changed fields
 //! rather than the entire contact card. Includes signature verification
 //! to ensure authenticity of updates.
+//!
+//! On the wire a delta is a JSON object tagged with a `format` version
+//! ahead of the delta's own fields, which follow in declaration order.
+//! Deltas from before the tag was introduced are read as format 1;
+//! anything newer than this build understands is rejected rather than
+//! half-parsed.
+
+use std::collections::HashSet;
 
 use ring::rand::SecureRandom;
 use serde::{Deserialize, Serialize};
@@ -32,8 +40,17 @@ pub enum DeltaError {
 
     #[error("Compression error: {0}")]
     CompressionError(String),
+
+    #[error("Unsupported delta format {actual} (supported up to {supported})")]
+    UnsupportedFormat { supported: u32, actual: u32 },
+
+    #[error("Malformed delta: {0}")]
+    Malformed(String),
 }
 
+/// Current wire format of serialized deltas.
+pub const DELTA_FORMAT_VERSION: u32 = 1;
+
 /// A delta update containing only changed fields.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CardDelta {
@@ -65,11 +82,44 @@ pub enum FieldChange {
     DisplayNameChanged { new_name: String },
 }
 
+impl FieldChange {
+    /// Ordering within a computed delta: the display name first, then by
+    /// field id, with a replaced field's removal ahead of its addition.
+    fn sort_key(&self) -> (Option<&str>, u8) {
+        match self {
+            FieldChange::DisplayNameChanged { .. } => (None, 0),
+            FieldChange::Removed { field_id } => (Some(field_id), 1),
+            FieldChange::Modified { field_id, .. } => (Some(field_id), 2),
+            FieldChange::Added { field } => (Some(field.id()), 3),
+        }
+    }
+}
+
 /// Returns a zero nonce for deserializing legacy deltas without a nonce field.
 fn default_nonce() -> [u8; 32] {
     [0u8; 32]
 }
 
+/// Returns the format of deltas serialized before the format tag existed.
+fn legacy_format() -> u32 {
+    1
+}
+
+/// Wire form of a delta: the format tag followed by the delta's fields.
+#[derive(Serialize)]
+struct WireDelta<'a> {
+    format: u32,
+    #[serde(flatten)]
+    delta: &'a CardDelta,
+}
+
+/// The format tag alone, read before committing to a full parse.
+#[derive(Deserialize)]
+struct WireFormat {
+    #[serde(default = "legacy_format")]
+    format: u32,
+}
+
 impl CardDelta {
     /// Computes the delta between two card states.
     ///
@@ -97,8 +147,20 @@ impl CardDelta {
         for (id, old_field) in &old_fields {
             match new_fields.get(id) {
                 Some(new_field) => {
-                    // Field exists in both - check if modified
-                    if old_field.value() != new_field.value() {
+                    // Field exists in both - check if modified. Changes carry
+                    // only values, so a new label, type or primary flag
+                    // replaces the field.
+                    if old_field.label() != new_field.label()
+                        || old_field.field_type() != new_field.field_type()
+                        || old_field.is_primary() != new_field.is_primary()
+                    {
+                        changes.push(FieldChange::Removed {
+                            field_id: id.to_string(),
+                        });
+                        changes.push(FieldChange::Added {
+                            field: (*new_field).clone(),
+                        });
+                    } else if old_field.value() != new_field.value() {
                         changes.push(FieldChange::Modified {
                             field_id: id.to_string(),
                             new_value: new_field.value().to_string(),
@@ -123,11 +185,23 @@ impl CardDelta {
             }
         }
 
+        // Map iteration order is random; sort so equal inputs give equal
+        // deltas
+        changes.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .map(|d| d.as_secs())
             .unwrap_or(0);
 
+        Self::from_changes(changes, 1, now)
+    }
+
+    /// Creates an unsigned delta carrying the given changes.
+    ///
+    /// For updates that are not a diff between two cards. A fresh random
+    /// nonce is generated; the changes are applied in the order given.
+    pub fn from_changes(changes: Vec<FieldChange>, version: u32, timestamp: u64) -> Self {
         // Generate random nonce for replay detection
         let mut nonce = [0u8; 32];
         ring::rand::SystemRandom::new()
@@ -135,8 +209,8 @@ impl CardDelta {
             .expect("System RNG should not fail");
 
         CardDelta {
-            version: 1, // Will be set properly during signing
-            timestamp: now,
+            version,
+            timestamp,
             changes,
             nonce,
             signature: [0u8; 64], // Will be set during signing
@@ -229,6 +303,23 @@ impl CardDelta {
         contact_id: &str,
         rules: &crate::contact::VisibilityRules,
     ) -> Self {
+        self.retain_fields(|field_id| rules.can_see(field_id, contact_id))
+    }
+
+    /// Projects this delta onto the set of fields a recipient may see.
+    ///
+    /// Changes to fields whose IDs are not in `visible_field_ids` are
+    /// dropped, so a field hidden from a contact never leaves the device
+    /// for that contact. Display name changes are always kept.
+    pub fn project(&self, visible_field_ids: &HashSet<String>) -> Self {
+        self.retain_fields(|field_id| visible_field_ids.contains(field_id))
+    }
+
+    /// Returns a copy of this delta keeping only field changes accepted by `is_visible`.
+    fn retain_fields<F>(&self, is_visible: F) -> Self
+    where
+        F: Fn(&str) -> bool,
+    {
         let filtered_changes: Vec<FieldChange> = self
             .changes
             .iter()
@@ -236,10 +327,10 @@ impl CardDelta {
                 match change {
                     // Display name changes are always visible
                     FieldChange::DisplayNameChanged { .. } => true,
-                    // For field changes, check visibility rules
-                    FieldChange::Added { field } => rules.can_see(field.id(), contact_id),
-                    FieldChange::Modified { field_id, .. } => rules.can_see(field_id, contact_id),
-                    FieldChange::Removed { field_id } => rules.can_see(field_id, contact_id),
+                    // For field changes, check visibility
+                    FieldChange::Added { field } => is_visible(field.id()),
+                    FieldChange::Modified { field_id, .. } => is_visible(field_id),
+                    FieldChange::Removed { field_id } => is_visible(field_id),
                 }
             })
             .cloned()
@@ -254,6 +345,32 @@ impl CardDelta {
         }
     }
 
+    /// Serializes the delta to its versioned wire form.
+    pub fn to_wire(&self) -> Vec<u8> {
+        serde_json::to_vec(&WireDelta {
+            format: DELTA_FORMAT_VERSION,
+            delta: self,
+        })
+        .expect("Serialization should not fail")
+    }
+
+    /// Parses a delta from its wire form.
+    ///
+    /// Untagged deltas from older peers are accepted as format 1. Deltas
+    /// in a newer format fail with `UnsupportedFormat`, so callers can
+    /// tell "peer needs a newer app" apart from corrupt data.
+    pub fn from_wire(bytes: &[u8]) -> Result<Self, DeltaError> {
+        let wire: WireFormat =
+            serde_json::from_slice(bytes).map_err(|e| DeltaError::Malformed(e.to_string()))?;
+        if wire.format > DELTA_FORMAT_VERSION {
+            return Err(DeltaError::UnsupportedFormat {
+                supported: DELTA_FORMAT_VERSION,
+                actual: wire.format,
+            });
+        }
+        serde_json::from_slice(bytes).map_err(|e| DeltaError::Malformed(e.to_string()))
+    }
+
     /// Compresses a payload using DEFLATE compression.
     ///
     /// Useful for reducing the size of delta payloads before transmission.
@@ -13,7 +13,7 @@ pub mod device_sync;
 pub mod merkle;
 pub mod state;
 
-pub use delta::{CardDelta, DeltaError, FieldChange};
+pub use delta::{CardDelta, DeltaError, FieldChange, DELTA_FORMAT_VERSION};
 pub use device_orchestrator::DeviceSyncOrchestrator;
 pub use device_sync::{
     validate_timestamp, ContactSyncData, DeviceSyncError, DeviceSyncPayload, InterDeviceSyncState,
@@ -136,15 +136,14 @@ impl<'a> SyncManager<'a> {
         new_card: &ContactCard,
     ) -> Result<String, SyncError> {
         // Compute delta
-        let delta = CardDelta::compute(old_card, new_card);
+        let delta = old_card.diff(new_card);
 
         if delta.changes.is_empty() {
             return Err(SyncError::NoChanges);
         }
 
         // Serialize delta
-        let payload =
-            serde_json::to_vec(&delta).map_err(|e| SyncError::Serialization(e.to_string()))?;
+        let payload = delta.to_wire();
 
         let now = current_timestamp();
 
@@ -344,7 +343,7 @@ impl<'a> SyncManager<'a> {
         let mut highest_version = 0u32;
 
         for update in &card_updates {
-            if let Ok(delta) = serde_json::from_slice::<CardDelta>(&update.payload) {
+            if let Ok(delta) = CardDelta::from_wire(&update.payload) {
                 highest_version = highest_version.max(delta.version);
                 merged_changes.extend(delta.changes);
             }
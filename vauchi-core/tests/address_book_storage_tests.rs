@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::address_book
+
+use vauchi_core::crypto::SymmetricKey;
+use vauchi_core::storage::{AddressBookEntry, Storage};
+
+fn entry(native_id: &str, name: &str) -> AddressBookEntry {
+    AddressBookEntry {
+        native_id: native_id.to_string(),
+        display_name: name.to_string(),
+        emails: vec![format!("{}@example.com", name.to_lowercase())],
+        phones: vec!["+1 555 0100".to_string()],
+        social_handles: Vec::new(),
+    }
+}
+
+#[test]
+fn test_save_and_list_address_book_entries() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+
+    let saved = storage
+        .save_address_book_entries(&[entry("2", "Bob"), entry("1", "Alice")])
+        .unwrap();
+    assert_eq!(saved, 2);
+
+    let entries = storage.list_address_book_entries().unwrap();
+    assert_eq!(entries, vec![entry("1", "Alice"), entry("2", "Bob")]);
+
+    // Address book entries are not contacts
+    assert_eq!(storage.list_contacts().unwrap().len(), 0);
+
+    assert_eq!(storage.clear_address_book().unwrap(), 2);
+    assert!(storage.list_address_book_entries().unwrap().is_empty());
+}
@@ -284,13 +284,41 @@ fn test_full_user_journey_aha_moments() {
     assert!(outbound.is_some());
     assert_eq!(tracker.seen_count(), 5);
 
+    // Step 7: User verifies Bob and keeps growing their network
+    let verified = tracker.check_milestones(1, 1);
+    assert_eq!(verified.len(), 1);
+    assert_eq!(tracker.seen_count(), 6);
+    let milestone = tracker.check_milestones(10, 1);
+    assert_eq!(milestone.len(), 1);
+    assert_eq!(tracker.seen_count(), 7);
+
+    // Step 8: User fills in the rest of their card
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "Email",
+        "alice@example.com",
+    ))
+    .unwrap();
+    card.add_field(ContactField::new(
+        FieldType::Social,
+        "Mastodon",
+        "@alice@example.social",
+    ))
+    .unwrap();
+    card.set_avatar(vec![0x89, 0x50, 0x4e, 0x47]).unwrap();
+    assert!(tracker
+        .check_card_completeness(&card.completeness())
+        .is_some());
+    assert_eq!(tracker.seen_count(), 8);
+
     // All aha moments have been seen
     assert_eq!(tracker.seen_count(), tracker.total_count());
 
-    // Step 7: App restart - verify persistence
+    // Step 9: App restart - verify persistence
     let json = tracker.to_json().unwrap();
     let restored = AhaMomentTracker::from_json(&json).unwrap();
-    assert_eq!(restored.seen_count(), 5);
+    assert_eq!(restored.seen_count(), 8);
 
     // No more aha moments should trigger
     for moment_type in AhaMomentType::all() {
@@ -211,6 +211,46 @@ fn test_sync_controller_sync_contact_with_ratchet() {
     assert_eq!(result.sent, 0);
 }
 
+/// Telemetry that keeps every event for inspection.
+#[derive(Default)]
+struct RecordingTelemetry {
+    events: std::sync::Mutex<Vec<TelemetryEvent>>,
+}
+
+impl Telemetry for RecordingTelemetry {
+    fn record(&self, event: TelemetryEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+#[test]
+fn test_sync_controller_records_telemetry() {
+    let storage = create_test_storage();
+    let relay = create_test_relay();
+    let events = Arc::new(EventDispatcher::new());
+    let telemetry = Arc::new(RecordingTelemetry::default());
+
+    let mut controller = SyncController::new(relay, &storage, SyncConfig::default(), events)
+        .with_telemetry(telemetry.clone());
+
+    // Not connected yet
+    assert!(controller.sync().is_err());
+    controller.connect().unwrap();
+    controller.sync().unwrap();
+
+    let recorded = telemetry.events.lock().unwrap();
+    assert_eq!(recorded.len(), 2);
+    assert!(matches!(recorded[0], TelemetryEvent::SyncFailed { .. }));
+    assert!(matches!(
+        recorded[1],
+        TelemetryEvent::SyncCompleted {
+            messages_sent: 0,
+            messages_received: 0,
+            ..
+        }
+    ));
+}
+
 // ============================================================
 // Phase 7: Device Sync Integration Tests (TDD)
 // ============================================================
@@ -120,6 +120,32 @@ fn test_vauchi_contact_operations() {
     assert_eq!(wb.contact_count().unwrap(), 0);
 }
 
+#[test]
+fn test_vauchi_add_self_as_contact_fails() {
+    let mut wb = create_test_vauchi();
+    wb.create_identity("Alice").unwrap();
+    let own_key = *wb.identity().unwrap().signing_public_key();
+
+    let contact =
+        Contact::from_exchange(own_key, ContactCard::new("Alice"), SymmetricKey::generate());
+    let result = wb.add_contact(contact);
+
+    assert!(matches!(
+        result,
+        Err(VauchiError::Contact(ContactError::SelfContact))
+    ));
+    assert_eq!(wb.contact_count().unwrap(), 0);
+
+    // The same key is refused when a contact recovers onto it
+    let mut bob =
+        Contact::from_exchange([1u8; 32], ContactCard::new("Bob"), SymmetricKey::generate());
+    assert_eq!(
+        bob.try_accept_recovery(own_key, SymmetricKey::generate(), &own_key),
+        Err(ContactError::SelfContact)
+    );
+    assert_eq!(bob.public_key(), &[1u8; 32]);
+}
+
 #[test]
 fn test_vauchi_verify_fingerprint() {
     let wb = create_test_vauchi();
@@ -357,6 +383,55 @@ fn test_propagate_partial_visibility() {
     assert_eq!(pending.len(), 1);
 }
 
+#[test]
+fn test_propagate_respects_contact_override() {
+    use vauchi_core::exchange::X3DHKeyPair;
+
+    let mut wb = create_test_vauchi();
+    wb.create_identity("Alice").unwrap();
+
+    let email_field = ContactField::new(FieldType::Email, "email", "alice@company.com");
+    let email_field_id = email_field.id().to_string();
+
+    // Bob has the email hidden via a per-contact override, Carol does not
+    let bob = Contact::from_exchange([1u8; 32], ContactCard::new("Bob"), SymmetricKey::generate());
+    let carol = Contact::from_exchange(
+        [2u8; 32],
+        ContactCard::new("Carol"),
+        SymmetricKey::generate(),
+    );
+    let bob_id = bob.id().to_string();
+    let carol_id = carol.id().to_string();
+    wb.add_contact(bob).unwrap();
+    wb.add_contact(carol).unwrap();
+
+    for id in [&bob_id, &carol_id] {
+        let their_dh = X3DHKeyPair::generate();
+        wb.create_ratchet_as_initiator(id, &SymmetricKey::generate(), *their_dh.public_key())
+            .unwrap();
+    }
+
+    wb.set_contact_visibility_override(&bob_id, &email_field_id, false)
+        .unwrap();
+
+    let old_card = wb.own_card().unwrap().unwrap();
+    let mut new_card = old_card.clone();
+    let _ = new_card.add_field(email_field);
+
+    let queued = wb.propagate_card_update(&old_card, &new_card).unwrap();
+    assert_eq!(queued, 1, "Only Carol should receive the new field");
+
+    assert!(wb
+        .storage()
+        .get_pending_updates(&bob_id)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        wb.storage().get_pending_updates(&carol_id).unwrap().len(),
+        1
+    );
+}
+
 #[test]
 fn test_process_incoming_card_update() {
     use vauchi_core::crypto::ratchet::DoubleRatchetState;
@@ -425,6 +500,173 @@ fn test_process_incoming_card_update() {
     assert!(bob_card.fields().iter().any(|f| f.label() == "work"));
 }
 
+#[test]
+fn test_process_padded_card_update() {
+    use vauchi_core::crypto::padding;
+    use vauchi_core::crypto::ratchet::DoubleRatchetState;
+    use vauchi_core::exchange::X3DHKeyPair;
+    use vauchi_core::sync::delta::CardDelta;
+    use vauchi_core::Identity;
+
+    let mut alice_wb = create_test_vauchi();
+    alice_wb.create_identity("Alice").unwrap();
+
+    let bob_identity = Identity::create("Bob");
+    let bob_dh = X3DHKeyPair::generate();
+    let shared_secret = SymmetricKey::generate();
+
+    let contact = Contact::from_exchange(
+        *bob_identity.signing_public_key(),
+        ContactCard::new("Bob"),
+        shared_secret.clone(),
+    );
+    let bob_id = contact.id().to_string();
+    alice_wb.add_contact(contact).unwrap();
+    alice_wb
+        .create_ratchet_as_responder(
+            &bob_id,
+            &shared_secret,
+            X3DHKeyPair::from_bytes(bob_dh.secret_bytes()),
+        )
+        .unwrap();
+
+    let mut bob_ratchet =
+        DoubleRatchetState::initialize_initiator(&shared_secret, *bob_dh.public_key());
+    let mut new_card = ContactCard::new("Bob");
+    let _ = new_card.add_field(ContactField::new(
+        FieldType::Email,
+        "work",
+        "bob@company.com",
+    ));
+    let mut delta = CardDelta::compute(&ContactCard::new("Bob"), &new_card);
+    delta.sign(&bob_identity);
+
+    // Bob pads the update before encrypting it
+    let padded = padding::pad(&serde_json::to_vec(&delta).unwrap());
+    let ratchet_msg = bob_ratchet.encrypt(&padded).unwrap();
+    let encrypted = serde_json::to_vec(&ratchet_msg).unwrap();
+
+    let changed = alice_wb.process_card_update(&bob_id, &encrypted).unwrap();
+    assert!(changed.iter().any(|f| f == "work"));
+}
+
+#[test]
+fn test_muted_contact_update_applied_without_notification() {
+    use std::sync::{Arc, Mutex};
+    use vauchi_core::crypto::ratchet::DoubleRatchetState;
+    use vauchi_core::exchange::X3DHKeyPair;
+    use vauchi_core::sync::delta::CardDelta;
+    use vauchi_core::Identity;
+
+    let mut alice_wb = create_test_vauchi();
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = events.clone();
+    alice_wb.add_event_handler(Arc::new(CallbackHandler::new(move |event| {
+        recorded.lock().unwrap().push(event);
+    })));
+    alice_wb.create_identity("Alice").unwrap();
+
+    let bob_identity = Identity::create("Bob");
+    let bob_dh = X3DHKeyPair::generate();
+    let shared_secret = SymmetricKey::generate();
+
+    let contact = Contact::from_exchange(
+        *bob_identity.signing_public_key(),
+        ContactCard::new("Bob"),
+        shared_secret.clone(),
+    );
+    let bob_id = contact.id().to_string();
+    alice_wb.add_contact(contact).unwrap();
+    alice_wb
+        .create_ratchet_as_responder(
+            &bob_id,
+            &shared_secret,
+            X3DHKeyPair::from_bytes(bob_dh.secret_bytes()),
+        )
+        .unwrap();
+    alice_wb.set_contact_muted(&bob_id, true).unwrap();
+    assert!(alice_wb.get_contact(&bob_id).unwrap().unwrap().is_muted());
+
+    let mut bob_ratchet =
+        DoubleRatchetState::initialize_initiator(&shared_secret, *bob_dh.public_key());
+    let old_card = ContactCard::new("Bob");
+    let mut new_card = ContactCard::new("Bob");
+    let _ = new_card.add_field(ContactField::new(
+        FieldType::Email,
+        "work",
+        "bob@company.com",
+    ));
+    let mut delta = CardDelta::compute(&old_card, &new_card);
+    delta.sign(&bob_identity);
+    let ratchet_msg = bob_ratchet
+        .encrypt(&serde_json::to_vec(&delta).unwrap())
+        .unwrap();
+    let encrypted = serde_json::to_vec(&ratchet_msg).unwrap();
+
+    events.lock().unwrap().clear();
+    let changed = alice_wb.process_card_update(&bob_id, &encrypted).unwrap();
+
+    // The update is applied...
+    assert!(changed.iter().any(|f| f == "work"));
+    let bob = alice_wb.get_contact(&bob_id).unwrap().unwrap();
+    assert!(bob.card().fields().iter().any(|f| f.label() == "work"));
+    // ...but no notification is emitted
+    assert!(events.lock().unwrap().is_empty());
+
+    // Removals are still reported for muted contacts
+    alice_wb.remove_contact(&bob_id).unwrap();
+    assert!(matches!(
+        events.lock().unwrap().as_slice(),
+        [VauchiEvent::ContactRemoved { contact_id }] if *contact_id == bob_id
+    ));
+}
+
+/// Propagates adding `field` and returns the queued ciphertext size.
+fn padded_update_size(field: ContactField) -> usize {
+    use vauchi_core::crypto::ratchet::RatchetMessage;
+    use vauchi_core::exchange::X3DHKeyPair;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let mut config = VauchiConfig::with_storage_path(dir.path().join("vauchi.db"));
+    config.sync.pad_updates = true;
+    let mut wb: Vauchi<MockTransport> = VauchiBuilder::new().config(config).build().unwrap();
+    wb.create_identity("Alice").unwrap();
+
+    let mut contact =
+        Contact::from_exchange([1u8; 32], ContactCard::new("Bob"), SymmetricKey::generate());
+    contact.set_accepts_padding(true);
+    let contact_id = contact.id().to_string();
+    wb.add_contact(contact).unwrap();
+    let their_dh = X3DHKeyPair::generate();
+    wb.create_ratchet_as_initiator(
+        &contact_id,
+        &SymmetricKey::generate(),
+        *their_dh.public_key(),
+    )
+    .unwrap();
+
+    let old_card = wb.own_card().unwrap().unwrap();
+    let mut new_card = old_card.clone();
+    let _ = new_card.add_field(field);
+    assert_eq!(wb.propagate_card_update(&old_card, &new_card).unwrap(), 1);
+
+    let pending = wb.storage().get_pending_updates(&contact_id).unwrap();
+    let message: RatchetMessage = serde_json::from_slice(&pending[0].payload).unwrap();
+    message.ciphertext.len()
+}
+
+#[test]
+fn test_propagate_pads_updates_when_enabled() {
+    // A short and a longer update land in the same size bucket
+    let short = padded_update_size(ContactField::new(FieldType::Email, "a", "a@b.c"));
+    let long = padded_update_size(ContactField::new(
+        FieldType::Email,
+        "work",
+        "a.much.longer.address@example.com",
+    ));
+    assert_eq!(short, long);
+}
+
 #[test]
 fn test_update_display_name() {
     let mut wb = create_test_vauchi();
@@ -536,3 +778,53 @@ fn test_process_update_rejects_invalid_signature() {
     let result = alice_wb.process_card_update(&bob_id, &encrypted);
     assert!(matches!(result, Err(VauchiError::SignatureInvalid)));
 }
+
+#[test]
+fn test_process_update_in_newer_format_can_be_retried() {
+    use vauchi_core::crypto::ratchet::DoubleRatchetState;
+    use vauchi_core::exchange::X3DHKeyPair;
+    use vauchi_core::sync::delta::{CardDelta, DELTA_FORMAT_VERSION};
+    use vauchi_core::Identity;
+
+    let mut alice_wb = create_test_vauchi();
+    alice_wb.create_identity("Alice").unwrap();
+
+    let bob_identity = Identity::create("Bob");
+    let bob_dh = X3DHKeyPair::generate();
+    let shared_secret = SymmetricKey::generate();
+    let contact = Contact::from_exchange(
+        *bob_identity.signing_public_key(),
+        ContactCard::new("Bob"),
+        shared_secret.clone(),
+    );
+    let bob_id = contact.id().to_string();
+    alice_wb.add_contact(contact).unwrap();
+    alice_wb
+        .create_ratchet_as_responder(
+            &bob_id,
+            &shared_secret,
+            X3DHKeyPair::from_bytes(bob_dh.secret_bytes()),
+        )
+        .unwrap();
+
+    let mut bob_ratchet =
+        DoubleRatchetState::initialize_initiator(&shared_secret, *bob_dh.public_key());
+    let mut delta = CardDelta::compute(&ContactCard::new("Bob"), &ContactCard::new("Robert"));
+    delta.sign(&bob_identity);
+    let mut wire: serde_json::Value = serde_json::from_slice(&delta.to_wire()).unwrap();
+    wire["format"] = serde_json::json!(DELTA_FORMAT_VERSION + 1);
+    let ratchet_msg = bob_ratchet
+        .encrypt(&serde_json::to_vec(&wire).unwrap())
+        .unwrap();
+    let encrypted = serde_json::to_vec(&ratchet_msg).unwrap();
+
+    // The ratchet did not move on, so a retry decrypts again and fails the
+    // same way instead of with a consumed message key
+    for _ in 0..2 {
+        let result = alice_wb.process_card_update(&bob_id, &encrypted);
+        assert!(matches!(
+            result,
+            Err(VauchiError::Serialization(ref e)) if e.contains("Unsupported delta format")
+        ));
+    }
+}
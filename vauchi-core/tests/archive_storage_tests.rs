@@ -0,0 +1,143 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::archive
+
+use vauchi_core::contact::Contact;
+use vauchi_core::contact_card::ContactCard;
+use vauchi_core::crypto::ratchet::DoubleRatchetState;
+use vauchi_core::crypto::{decrypt, derive_key_argon2id, SymmetricKey};
+use vauchi_core::exchange::X3DHKeyPair;
+use vauchi_core::storage::{Storage, StorageError};
+
+const PASSWORD: &str = "correct-horse-battery-staple-migration";
+
+fn create_contact(name: &str) -> Contact {
+    let mut public_key = [0u8; 32];
+    public_key[..name.len()].copy_from_slice(name.as_bytes());
+    Contact::from_exchange(public_key, ContactCard::new(name), SymmetricKey::generate())
+}
+
+#[test]
+fn test_archive_roundtrip_preserves_contact_ratchet_and_labels() {
+    let source = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    source.save_identity(b"identity-backup", "Me").unwrap();
+
+    let alice = create_contact("Alice");
+    source.save_contact(&alice).unwrap();
+
+    let shared_secret = SymmetricKey::generate();
+    let alice_dh = X3DHKeyPair::generate();
+    let ratchet = DoubleRatchetState::initialize_initiator(&shared_secret, *alice_dh.public_key());
+    source
+        .save_ratchet_state(alice.id(), &ratchet, true)
+        .unwrap();
+
+    let family = source.create_label("Family").unwrap();
+    source
+        .add_contact_to_label(family.id(), alice.id())
+        .unwrap();
+    source
+        .set_label_field_visibility(family.id(), "phone", true)
+        .unwrap();
+
+    let archive = source.export_encrypted_archive(PASSWORD).unwrap();
+
+    // A different device has a different storage key
+    let target = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    target.import_encrypted_archive(&archive, PASSWORD).unwrap();
+
+    let (identity, name) = target.load_identity().unwrap().unwrap();
+    assert_eq!(identity, b"identity-backup");
+    assert_eq!(name, "Me");
+
+    let restored = target.load_contact(alice.id()).unwrap().unwrap();
+    assert_eq!(restored.display_name(), "Alice");
+    assert_eq!(
+        restored.shared_key().as_bytes(),
+        alice.shared_key().as_bytes()
+    );
+
+    // The restored ratchet still talks to the peer's side
+    let (mut restored_ratchet, is_initiator) =
+        target.load_ratchet_state(alice.id()).unwrap().unwrap();
+    assert!(is_initiator);
+    let mut peer = DoubleRatchetState::initialize_responder(&shared_secret, alice_dh);
+    let message = restored_ratchet.encrypt(b"hello again").unwrap();
+    assert_eq!(peer.decrypt(&message).unwrap(), b"hello again");
+
+    let label = target.load_label(family.id()).unwrap();
+    assert_eq!(label.name(), "Family");
+    assert!(label.contains_contact(alice.id()));
+    assert_eq!(
+        target.fields_shared_with_label(family.id()).unwrap(),
+        vec!["phone".to_string()]
+    );
+    assert!(target.verify_integrity().unwrap().is_ok());
+}
+
+/// Opens an archive's sealed contents (format: version, 16-byte salt, ciphertext).
+fn archive_contents(archive: &[u8]) -> Vec<u8> {
+    let key = derive_key_argon2id(PASSWORD.as_bytes(), &archive[1..17]).unwrap();
+    decrypt(&key, &archive[17..]).unwrap()
+}
+
+#[test]
+fn test_archive_of_unchanged_data_is_identical() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    storage.save_identity(b"identity-backup", "Me").unwrap();
+    let alice = create_contact("Alice");
+    let bob = create_contact("Bob");
+    storage.save_contact(&alice).unwrap();
+    storage.save_contact(&bob).unwrap();
+    let family = storage.create_label("Family").unwrap();
+    storage.create_label("Work").unwrap();
+    storage.add_contact_to_label(family.id(), bob.id()).unwrap();
+    storage
+        .add_contact_to_label(family.id(), alice.id())
+        .unwrap();
+
+    let first = storage.export_encrypted_archive(PASSWORD).unwrap();
+
+    // Saving Alice again moves her row to the end of the table
+    storage.save_contact(&alice).unwrap();
+    let second = storage.export_encrypted_archive(PASSWORD).unwrap();
+
+    assert_ne!(first, second);
+    assert_eq!(archive_contents(&first), archive_contents(&second));
+}
+
+#[test]
+fn test_archive_wrong_password_fails() {
+    let source = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    source.save_contact(&create_contact("Alice")).unwrap();
+    let archive = source.export_encrypted_archive(PASSWORD).unwrap();
+
+    let target = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let result = target.import_encrypted_archive(&archive, "wrong-password-entirely");
+
+    assert!(matches!(result, Err(StorageError::Encryption(_))));
+    assert!(target.list_contacts().unwrap().is_empty());
+}
+
+#[test]
+fn test_archive_import_refuses_existing_identity() {
+    let source = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    source.save_identity(b"identity-backup", "Me").unwrap();
+    let archive = source.export_encrypted_archive(PASSWORD).unwrap();
+
+    let target = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    target.save_identity(b"other-identity", "Other").unwrap();
+
+    let result = target.import_encrypted_archive(&archive, PASSWORD);
+    assert!(matches!(result, Err(StorageError::AlreadyExists(_))));
+}
+
+#[test]
+fn test_archive_export_rejects_weak_password() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+
+    let result = storage.export_encrypted_archive("password");
+    assert!(matches!(result, Err(StorageError::InvalidData(_))));
+}
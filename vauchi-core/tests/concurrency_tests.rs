@@ -14,6 +14,7 @@
 use rand::Rng;
 use std::sync::{Arc, Barrier};
 use std::thread;
+use std::time::{Duration, Instant};
 use tempfile::tempdir;
 use vauchi_core::contact::Contact;
 use vauchi_core::crypto::SymmetricKey;
@@ -255,6 +256,66 @@ fn test_sequential_writers_file_based() {
     assert_eq!(contacts.len(), 50);
 }
 
+#[test]
+fn test_wal_concurrent_read_write_within_busy_timeout() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("wal.db");
+    let key = SymmetricKey::generate();
+
+    {
+        let storage = Storage::open(&db_path, key.clone()).unwrap();
+        storage.save_contact(&create_test_contact("Seed")).unwrap();
+    }
+
+    let conn = rusqlite::Connection::open(&db_path).unwrap();
+    let mode: String = conn
+        .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(mode, "wal");
+    drop(conn);
+
+    let timeout = Duration::from_secs(2);
+    let barrier = Arc::new(Barrier::new(2));
+
+    let writer = {
+        let path = db_path.clone();
+        let key = key.clone();
+        let barrier = Arc::clone(&barrier);
+        thread::spawn(move || {
+            let storage = Storage::open_with_busy_timeout(&path, key, timeout).unwrap();
+            barrier.wait();
+            for i in 0..20 {
+                let contact = create_test_contact(&format!("Writer {}", i));
+                storage.save_contact(&contact).unwrap();
+            }
+        })
+    };
+
+    let reader = {
+        let path = db_path.clone();
+        let key = key.clone();
+        let barrier = Arc::clone(&barrier);
+        thread::spawn(move || {
+            let storage = Storage::open_with_busy_timeout(&path, key, timeout).unwrap();
+            barrier.wait();
+            let mut last = 0;
+            for _ in 0..20 {
+                let count = storage.list_contacts().unwrap().len();
+                assert!(count >= last, "Reader saw contacts disappear");
+                last = count;
+            }
+        })
+    };
+
+    let started = Instant::now();
+    writer.join().expect("Writer panicked");
+    reader.join().expect("Reader panicked");
+    assert!(started.elapsed() < timeout * 5);
+
+    let storage = Storage::open(&db_path, key).unwrap();
+    assert_eq!(storage.list_contacts().unwrap().len(), 21);
+}
+
 // =============================================================================
 // READ-AFTER-WRITE CONSISTENCY TESTS
 // =============================================================================
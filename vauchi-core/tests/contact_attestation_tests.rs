@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for contact::attestation
+
+use vauchi_core::*;
+
+#[test]
+fn test_attestation_create_and_verify() {
+    let attester = SigningKeyPair::generate();
+    let subjects = vec![[1u8; 32], [2u8; 32]];
+
+    let attestation = VerificationAttestation::create(subjects.clone(), &attester);
+
+    assert!(attestation.verify());
+    assert_eq!(attestation.attester_pk(), attester.public_key().as_bytes());
+    assert_eq!(attestation.subject_keys(), subjects.as_slice());
+}
+
+#[test]
+fn test_attestation_tampered_subjects_fail_verification() {
+    let attester = SigningKeyPair::generate();
+    let mut attestation = VerificationAttestation::create(vec![[1u8; 32]], &attester);
+
+    attestation.set_subject_keys_for_testing(vec![[9u8; 32]]);
+
+    assert!(!attestation.verify());
+    assert!(matches!(
+        attestation.verify_trusted(&[*attester.public_key().as_bytes()]),
+        Err(AttestationError::InvalidSignature)
+    ));
+}
+
+#[test]
+fn test_attestation_untrusted_attester() {
+    let attester = SigningKeyPair::generate();
+    let other = SigningKeyPair::generate();
+    let attestation = VerificationAttestation::create(vec![[1u8; 32]], &attester);
+
+    assert!(matches!(
+        attestation.verify_trusted(&[*other.public_key().as_bytes()]),
+        Err(AttestationError::UntrustedAttester)
+    ));
+    assert!(attestation
+        .verify_trusted(&[*attester.public_key().as_bytes()])
+        .is_ok());
+}
+
+#[test]
+fn test_attestation_bytes_roundtrip() {
+    let attester = SigningKeyPair::generate();
+    let attestation = VerificationAttestation::create(vec![[1u8; 32], [2u8; 32]], &attester);
+
+    let restored = VerificationAttestation::from_bytes(&attestation.to_bytes()).unwrap();
+
+    assert!(restored.verify());
+    assert_eq!(restored.timestamp(), attestation.timestamp());
+    assert_eq!(restored.subject_keys(), attestation.subject_keys());
+    assert!(matches!(
+        VerificationAttestation::from_bytes(&[0u8; 3]),
+        Err(AttestationError::InvalidFormat)
+    ));
+}
+
+#[test]
+fn test_contact_verified_by_attester() {
+    let mut contact = Contact::from_exchange(
+        [3u8; 32],
+        ContactCard::new("Bob"),
+        crypto::SymmetricKey::generate(),
+    );
+
+    contact.mark_fingerprint_verified_by("organizer", 1_700_000_000);
+    assert!(contact.is_fingerprint_verified());
+    assert_eq!(contact.verified_by(), Some("organizer"));
+    assert_eq!(contact.verified_at(), Some(1_700_000_000));
+
+    // Verifying in person replaces the attestation
+    contact.mark_fingerprint_verified();
+    assert_eq!(contact.verified_by(), None);
+}
@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for contact_card::completeness
+
+use vauchi_core::contact_card::{CompletenessItem, ContactCard, ContactField, FieldType};
+
+#[test]
+fn test_empty_card_scores_zero() {
+    let report = ContactCard::new("  ").completeness();
+
+    assert_eq!(report.percent, 0);
+    assert_eq!(report.missing, CompletenessItem::all());
+    assert!(!report.is_complete());
+}
+
+#[test]
+fn test_partial_card_lists_missing_items() {
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Phone,
+        "Mobile",
+        "+41 79 123 45 67",
+    ))
+    .unwrap();
+    card.add_field(ContactField::new(
+        FieldType::Website,
+        "Blog",
+        "https://alice.example",
+    ))
+    .unwrap();
+
+    let report = card.completeness();
+
+    assert_eq!(report.percent, 50);
+    assert_eq!(
+        report.missing,
+        vec![CompletenessItem::Photo, CompletenessItem::SocialLink]
+    );
+}
+
+#[test]
+fn test_complete_card_scores_hundred() {
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "Work",
+        "alice@example.com",
+    ))
+    .unwrap();
+    card.add_field(ContactField::new(FieldType::Social, "GitHub", "alice"))
+        .unwrap();
+    card.set_avatar(vec![0x89, 0x50, 0x4e, 0x47]).unwrap();
+
+    let report = card.completeness();
+
+    assert_eq!(report.percent, 100);
+    assert!(report.missing.is_empty());
+    assert!(report.is_complete());
+}
+
+#[test]
+fn test_empty_avatar_does_not_count_as_photo() {
+    let mut card = ContactCard::new("Alice");
+    card.set_avatar(Vec::new()).unwrap();
+
+    let report = card.completeness();
+
+    assert_eq!(report.percent, 25);
+    assert!(report.missing.contains(&CompletenessItem::Photo));
+}
@@ -73,6 +73,25 @@ fn test_update_field_label_not_found() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_duplicate_labels_resolved_by_id() {
+    let mut card = ContactCard::new("Test");
+    card.add_field(ContactField::new(FieldType::Email, "work", "a@test.com"))
+        .unwrap();
+    card.add_field(ContactField::new(FieldType::Email, "work", "b@test.com"))
+        .unwrap();
+    let second_id = card.fields()[1].id().to_string();
+
+    card.update_field_value(&second_id, "c@test.com").unwrap();
+    assert_eq!(card.field(&second_id).unwrap().value(), "c@test.com");
+    assert_eq!(card.fields()[0].value(), "a@test.com");
+
+    card.remove_field(&second_id).unwrap();
+    assert!(card.field(&second_id).is_none());
+    assert_eq!(card.fields().len(), 1);
+    assert_eq!(card.fields()[0].value(), "a@test.com");
+}
+
 #[test]
 fn test_remove_field_not_found() {
     let mut card = ContactCard::new("Test");
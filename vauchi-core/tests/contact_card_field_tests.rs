@@ -15,6 +15,22 @@ fn test_create_field() {
     assert_eq!(field.value(), "+1-555-1234");
 }
 
+#[test]
+fn test_field_type_name_matches_serialized_form() {
+    for field_type in [
+        FieldType::Phone,
+        FieldType::Email,
+        FieldType::Social,
+        FieldType::Address,
+        FieldType::Website,
+        FieldType::Custom,
+        FieldType::RichText,
+    ] {
+        let json = serde_json::to_string(&field_type).unwrap();
+        assert_eq!(json, format!("\"{}\"", field_type.as_str()));
+    }
+}
+
 #[test]
 fn test_validate_valid_phone() {
     let field = ContactField::new(FieldType::Phone, "Test", "+1-555-123-4567");
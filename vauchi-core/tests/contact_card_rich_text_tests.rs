@@ -0,0 +1,57 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for FieldType::RichText fields
+
+use vauchi_core::contact_card::{ContactField, FieldType, ValidationError};
+
+#[test]
+fn test_rich_text_field_sanitizes_value() {
+    let mut field = ContactField::new(
+        FieldType::RichText,
+        "Bio",
+        "**Hi** [x](javascript:alert(1))<script>bad()</script>",
+    );
+    assert_eq!(field.value(), "**Hi** x");
+    assert_eq!(field.render_plain(), "Hi x");
+    assert!(field.validate().is_ok());
+
+    field.set_value("<iframe src=https://evil.example></iframe>_fine_");
+    assert_eq!(field.value(), "_fine_");
+    assert_eq!(field.render_plain(), "fine");
+}
+
+#[test]
+fn test_rich_text_validation_rejects_unsanitized_value() {
+    // A field deserialized from a peer's card skips `new`/`set_value`
+    let field = ContactField::new(FieldType::RichText, "Bio", "placeholder");
+    let json = serde_json::to_string(&field)
+        .unwrap()
+        .replace("placeholder", "[x](javascript:alert(1))");
+    let field: ContactField = serde_json::from_str(&json).unwrap();
+
+    assert!(matches!(
+        field.validate(),
+        Err(ValidationError::UnsafeContent)
+    ));
+}
+
+#[test]
+fn test_rich_text_built_by_new_passes_validation() {
+    for value in [
+        "<<b>img src=x onerror=alert(1)>",
+        "<<script>x</script>script>alert(1)</script>",
+        "[[t](https://a.com)](javascript:alert(1))",
+    ] {
+        let field = ContactField::new(FieldType::RichText, "Bio", value);
+        assert!(field.validate().is_ok(), "{value}");
+    }
+}
+
+#[test]
+fn test_render_plain_leaves_other_field_types_alone() {
+    let field = ContactField::new(FieldType::Custom, "Note", "**not markdown**");
+
+    assert_eq!(field.render_plain(), "**not markdown**");
+}
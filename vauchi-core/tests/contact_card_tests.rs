@@ -5,6 +5,7 @@
 //! Tests for contact_card
 //! Extracted from mod.rs
 
+use vauchi_core::contact_card::ContactCardError;
 use vauchi_core::*;
 
 #[test]
@@ -25,3 +26,180 @@ fn test_add_and_remove_field() {
     card.remove_field(&field_id).unwrap();
     assert!(card.fields().is_empty());
 }
+
+#[test]
+fn test_upsert_field_creates_then_updates() {
+    let mut card = ContactCard::new("Test");
+
+    let created = card
+        .upsert_field(FieldType::Email, "Work", "old@test.com")
+        .unwrap();
+    assert!(created);
+    let field_id = card.fields()[0].id().to_string();
+
+    let created = card
+        .upsert_field(FieldType::Email, "Work", "new@test.com")
+        .unwrap();
+    assert!(!created);
+    assert_eq!(card.fields().len(), 1);
+    assert_eq!(card.fields()[0].id(), field_id);
+    assert_eq!(card.fields()[0].value(), "new@test.com");
+
+    // Same label with another type is a different field
+    let created = card
+        .upsert_field(FieldType::Phone, "Work", "+41 79 000 00 00")
+        .unwrap();
+    assert!(created);
+    assert_eq!(card.fields().len(), 2);
+}
+
+#[test]
+fn test_identical_cards_hash_equal_and_diff_empty() {
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "Work",
+        "alice@work.example",
+    ))
+    .unwrap();
+    card.add_field(ContactField::new(
+        FieldType::Phone,
+        "Mobile",
+        "+41 79 000 00 00",
+    ))
+    .unwrap();
+
+    let json = serde_json::to_string(&card).unwrap();
+    let mut copy: ContactCard = serde_json::from_str(&json).unwrap();
+    assert_eq!(card.content_hash(), copy.content_hash());
+    assert!(card.diff(&copy).is_empty());
+
+    // Field order is not content
+    let ids: Vec<String> = card.fields().iter().map(|f| f.id().to_string()).collect();
+    copy.reorder_fields(&[ids[1].as_str(), ids[0].as_str()])
+        .unwrap();
+    assert_eq!(card.content_hash(), copy.content_hash());
+    assert!(card.diff(&copy).is_empty());
+}
+
+#[test]
+fn test_single_field_change_yields_one_change_delta() {
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "Work",
+        "alice@work.example",
+    ))
+    .unwrap();
+    card.add_field(ContactField::new(
+        FieldType::Phone,
+        "Mobile",
+        "+41 79 000 00 00",
+    ))
+    .unwrap();
+    let email_id = card.fields()[0].id().to_string();
+
+    let mut changed = card.clone();
+    changed
+        .update_field_value(&email_id, "alice@new.example")
+        .unwrap();
+    assert_ne!(card.content_hash(), changed.content_hash());
+
+    let delta = card.diff(&changed);
+    assert_eq!(
+        delta.changes,
+        vec![FieldChange::Modified {
+            field_id: email_id.clone(),
+            new_value: "alice@new.example".to_string(),
+        }]
+    );
+
+    // A relabelled field is carried too, replacing the field
+    let mut relabelled = card.clone();
+    relabelled
+        .update_field_label(&email_id, "Personal")
+        .unwrap();
+    assert_ne!(card.content_hash(), relabelled.content_hash());
+    let mut applied = card.clone();
+    card.diff(&relabelled).apply(&mut applied).unwrap();
+    assert_eq!(applied.content_hash(), relabelled.content_hash());
+}
+
+#[test]
+fn test_primary_field_is_unique_per_type() {
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "Work",
+        "a@work.example",
+    ))
+    .unwrap();
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "Home",
+        "a@home.example",
+    ))
+    .unwrap();
+    card.add_field(ContactField::new(
+        FieldType::Phone,
+        "Mobile",
+        "+41 79 000 00 00",
+    ))
+    .unwrap();
+    let work_id = card.fields()[0].id().to_string();
+    let home_id = card.fields()[1].id().to_string();
+    let phone_id = card.fields()[2].id().to_string();
+    assert!(card.primary_field(&FieldType::Email).is_none());
+
+    card.set_primary_field(&work_id).unwrap();
+    card.set_primary_field(&phone_id).unwrap();
+    assert_eq!(card.primary_field(&FieldType::Email).unwrap().id(), work_id);
+
+    // Marking another email unsets the first, other types are untouched
+    card.set_primary_field(&home_id).unwrap();
+    assert_eq!(card.primary_field(&FieldType::Email).unwrap().id(), home_id);
+    assert!(!card.field(&work_id).unwrap().is_primary());
+    assert_eq!(
+        card.primary_field(&FieldType::Phone).unwrap().id(),
+        phone_id
+    );
+
+    card.clear_primary_field(&FieldType::Email);
+    assert!(card.primary_field(&FieldType::Email).is_none());
+    assert!(card.primary_field(&FieldType::Phone).is_some());
+
+    assert!(matches!(
+        card.set_primary_field("missing"),
+        Err(ContactCardError::FieldNotFound)
+    ));
+}
+
+#[test]
+fn test_primary_flag_changes_hash_and_syncs() {
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "Work",
+        "a@work.example",
+    ))
+    .unwrap();
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "Home",
+        "a@home.example",
+    ))
+    .unwrap();
+    let home_id = card.fields()[1].id().to_string();
+
+    let mut changed = card.clone();
+    changed.set_primary_field(&home_id).unwrap();
+    assert_ne!(card.content_hash(), changed.content_hash());
+
+    let mut applied = card.clone();
+    card.diff(&changed).apply(&mut applied).unwrap();
+    assert_eq!(applied.content_hash(), changed.content_hash());
+    assert_eq!(
+        applied.primary_field(&FieldType::Email).unwrap().value(),
+        "a@home.example"
+    );
+}
@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::contact_export
+
+use vauchi_core::contact::Contact;
+use vauchi_core::contact_card::{ContactCard, ContactField, FieldType};
+use vauchi_core::crypto::{encrypt, SymmetricKey};
+use vauchi_core::storage::{Storage, StorageError};
+
+const PASSWORD: &str = "correct-horse-battery-staple-handoff";
+
+/// Public key of the importing identity.
+const OWN_KEY: [u8; 32] = [1u8; 32];
+
+#[test]
+fn test_contact_export_roundtrip_preserves_card_as_reference() {
+    let source_key = SymmetricKey::generate();
+    let source = Storage::in_memory(source_key.clone()).unwrap();
+
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "Work",
+        "alice@work.example",
+    ))
+    .unwrap();
+    card.add_field(ContactField::new(
+        FieldType::Phone,
+        "Mobile",
+        "+41 79 000 00 00",
+    ))
+    .unwrap();
+    let alice = Contact::from_exchange([7u8; 32], card.clone(), SymmetricKey::generate());
+    source.save_contact(&alice).unwrap();
+    source
+        .save_personal_notes(
+            alice.id(),
+            &encrypt(&source_key, b"Met at the conference").unwrap(),
+        )
+        .unwrap();
+
+    let data = source.export_contact(alice.id(), PASSWORD).unwrap();
+
+    // A different device has a different storage key
+    let target_key = SymmetricKey::generate();
+    let target = Storage::in_memory(target_key.clone()).unwrap();
+    let imported = target.import_contact(&data, PASSWORD, &OWN_KEY).unwrap();
+
+    assert_eq!(imported.id(), alice.id());
+    assert!(imported.is_reference());
+    assert!(!imported.should_send_updates());
+    assert_ne!(
+        imported.shared_key().as_bytes(),
+        alice.shared_key().as_bytes()
+    );
+
+    let stored = target.load_contact(alice.id()).unwrap().unwrap();
+    assert!(stored.is_reference());
+    assert_eq!(stored.card().content_hash(), card.content_hash());
+    let note = target.load_personal_notes(alice.id()).unwrap().unwrap();
+    assert_eq!(
+        vauchi_core::crypto::decrypt(&target_key, &note).unwrap(),
+        b"Met at the conference"
+    );
+    assert!(target.load_ratchet_state(alice.id()).unwrap().is_none());
+
+    // Importing twice is refused
+    assert!(matches!(
+        target.import_contact(&data, PASSWORD, &OWN_KEY),
+        Err(StorageError::AlreadyExists(_))
+    ));
+}
+
+#[test]
+fn test_contact_export_rejects_wrong_password_and_unknown_contact() {
+    let source = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = Contact::from_exchange(
+        [7u8; 32],
+        ContactCard::new("Alice"),
+        SymmetricKey::generate(),
+    );
+    source.save_contact(&alice).unwrap();
+
+    assert!(matches!(
+        source.export_contact("missing", PASSWORD),
+        Err(StorageError::NotFound(_))
+    ));
+    assert!(source.export_contact(alice.id(), "weak").is_err());
+
+    let data = source.export_contact(alice.id(), PASSWORD).unwrap();
+    let target = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    assert!(matches!(
+        target.import_contact(&data, "correct-horse-battery-staple-other", &OWN_KEY),
+        Err(StorageError::Encryption(_))
+    ));
+    assert!(matches!(
+        target.import_contact(b"not an export", PASSWORD, &OWN_KEY),
+        Err(StorageError::InvalidData(_))
+    ));
+    // Nobody imports themselves
+    assert!(matches!(
+        target.import_contact(&data, PASSWORD, alice.public_key()),
+        Err(StorageError::InvalidData(_))
+    ));
+    assert!(target.list_contacts().unwrap().is_empty());
+}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for contact::invite
+
+use vauchi_core::contact::suggest_invites;
+use vauchi_core::{AddressBookEntry, Contact, ContactCard, ContactField, FieldType, SymmetricKey};
+
+fn contact_with_fields(name: &str, fields: &[(FieldType, &str)]) -> Contact {
+    let mut card = ContactCard::new(name);
+    for (i, (field_type, value)) in fields.iter().enumerate() {
+        card.add_field(ContactField::new(
+            field_type.clone(),
+            &format!("f{}", i),
+            value,
+        ))
+        .unwrap();
+    }
+    let mut public_key = [0u8; 32];
+    public_key[..name.len()].copy_from_slice(name.as_bytes());
+    Contact::from_exchange(public_key, card, SymmetricKey::generate())
+}
+
+fn entry(name: &str, emails: &[&str], phones: &[&str], handles: &[&str]) -> AddressBookEntry {
+    let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+    AddressBookEntry {
+        native_id: name.to_lowercase(),
+        display_name: name.to_string(),
+        emails: strings(emails),
+        phones: strings(phones),
+        social_handles: strings(handles),
+    }
+}
+
+#[test]
+fn test_suggest_invites_ranks_shared_handles() {
+    let bob = contact_with_fields(
+        "Bob",
+        &[
+            (FieldType::Social, "@carol_dev"),
+            (FieldType::Social, "@team_acme"),
+        ],
+    );
+
+    let entries = vec![
+        entry("Dave", &[], &[], &[]),
+        entry("Erin", &[], &[], &["team_acme"]),
+        entry("Carol", &[], &[], &["Carol_Dev", "@TEAM_ACME"]),
+    ];
+
+    let suggestions = suggest_invites(&entries, std::slice::from_ref(&bob));
+    let ranked: Vec<(&str, u32)> = suggestions
+        .iter()
+        .map(|s| (s.entry.display_name.as_str(), s.score))
+        .collect();
+    assert_eq!(ranked, vec![("Carol", 2), ("Erin", 1), ("Dave", 0)]);
+    assert_eq!(
+        suggestions[0].matched_contact_ids,
+        vec![bob.id().to_string()]
+    );
+    assert!(suggestions[2].matched_contact_ids.is_empty());
+}
+
+#[test]
+fn test_suggest_invites_skips_exchanged_contacts() {
+    let bob = contact_with_fields(
+        "Bob",
+        &[
+            (FieldType::Email, "Bob@Example.com"),
+            (FieldType::Phone, "+1 (555) 010-0200"),
+        ],
+    );
+    let entries = vec![
+        entry("Robert", &["bob@example.com"], &[], &[]),
+        entry("Bobby", &[], &["15550100200"], &[]),
+        entry("Bob", &["other.bob@example.com"], &[], &[]),
+    ];
+
+    // Matched on identifiers, not names
+    let suggestions = suggest_invites(&entries, &[bob]);
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].entry.emails, vec!["other.bob@example.com"]);
+}
@@ -35,6 +35,20 @@ fn test_fingerprint_verification() {
     assert!(contact.is_fingerprint_verified());
 }
 
+#[test]
+fn test_fingerprint_verification_records_timestamp() {
+    let mut contact = create_test_contact();
+    assert_eq!(contact.verified_at(), None);
+
+    contact.mark_fingerprint_verified();
+    assert!(contact.verified_at().is_some());
+
+    // Accepting a recovery resets verification, including its date
+    contact.accept_recovery([9u8; 32], SymmetricKey::generate());
+    assert!(!contact.is_fingerprint_verified());
+    assert_eq!(contact.verified_at(), None);
+}
+
 #[test]
 fn test_fingerprint_format() {
     let contact = create_test_contact();
@@ -46,6 +60,39 @@ fn test_fingerprint_format() {
     assert_eq!(fp, fp.to_uppercase());
 }
 
+#[test]
+fn test_safety_number_matches_for_both_parties() {
+    let alice_pk = [1u8; 32];
+    let bob_pk = [2u8; 32];
+
+    // Alice's view of Bob, and Bob's view of Alice
+    let bob_as_contact =
+        Contact::from_exchange(bob_pk, ContactCard::new("Bob"), SymmetricKey::generate());
+    let alice_as_contact = Contact::from_exchange(
+        alice_pk,
+        ContactCard::new("Alice"),
+        SymmetricKey::generate(),
+    );
+
+    let alice_sees = bob_as_contact.safety_number(&alice_pk);
+    let bob_sees = alice_as_contact.safety_number(&bob_pk);
+
+    assert_eq!(alice_sees, bob_sees);
+    assert_eq!(alice_sees.len(), 60);
+    assert!(alice_sees.chars().all(|c| c.is_ascii_digit()));
+}
+
+#[test]
+fn test_safety_number_differs_per_pair() {
+    let contact =
+        Contact::from_exchange([2u8; 32], ContactCard::new("Bob"), SymmetricKey::generate());
+
+    assert_ne!(
+        contact.safety_number(&[1u8; 32]),
+        contact.safety_number(&[3u8; 32])
+    );
+}
+
 #[test]
 fn test_visibility_rules() {
     let mut contact = create_test_contact();
@@ -0,0 +1,107 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for content::markdown
+
+use vauchi_core::content::{render_plain, sanitize_markdown};
+
+#[test]
+fn test_safe_markdown_passes_unchanged() {
+    let bio = "## About me\n\n**Rust** developer, *coffee* fan.\n\n- Talks: [blog](https://example.com/blog \"Blog\")\n- Mail: <mailto:me@example.com>\n\n![avatar](https://example.com/me.png)\n\n1 < 2 and snake_case_name";
+
+    assert_eq!(sanitize_markdown(bio), bio);
+}
+
+#[test]
+fn test_unsafe_links_are_reduced_to_text() {
+    assert_eq!(
+        sanitize_markdown("see [my site](javascript:alert(document.cookie)) now"),
+        "see my site now"
+    );
+    assert_eq!(
+        sanitize_markdown("![pic](data:image/svg+xml;base64,PHN2Zz4=)"),
+        "pic"
+    );
+    assert_eq!(sanitize_markdown("<javascript:alert(1)>"), "");
+    assert_eq!(
+        sanitize_markdown("Hello\n[x]: vbscript:msgbox(1)\nBye"),
+        "Hello\nBye"
+    );
+}
+
+#[test]
+fn test_html_is_stripped() {
+    assert_eq!(
+        sanitize_markdown("Hi <b>there</b><script>alert('x')</script>!"),
+        "Hi there!"
+    );
+    assert_eq!(
+        sanitize_markdown("<img src=x onerror=alert(1)>text"),
+        "text"
+    );
+    assert_eq!(
+        sanitize_markdown("a<STYLE>body{display:none}</STYLE>b"),
+        "ab"
+    );
+    assert_eq!(
+        sanitize_markdown("open <script never closed"),
+        "open script never closed"
+    );
+}
+
+#[test]
+fn test_render_plain_removes_markdown_syntax() {
+    let bio = "# Alice\n\n> **Rust** dev at [Acme](https://acme.example)\n\n- likes `tea`\n- ~~hates~~ snake_case";
+
+    assert_eq!(
+        render_plain(bio),
+        "Alice\nRust dev at Acme\nlikes tea\nhates snake_case"
+    );
+}
+
+#[test]
+fn test_tags_exposed_by_stripping_are_removed() {
+    assert_eq!(sanitize_markdown("<<b>img src=x onerror=alert(1)>"), "");
+    assert_eq!(
+        sanitize_markdown("<<script>x</script>script>alert(1)</script>"),
+        ""
+    );
+}
+
+#[test]
+fn test_nested_link_targets_are_checked() {
+    assert_eq!(
+        sanitize_markdown("[[t](https://a.com)](javascript:alert(1))"),
+        "[t](https://a.com)"
+    );
+    assert_eq!(
+        sanitize_markdown("[[t](javascript:alert(1))](https://a.com)"),
+        "[t](https://a.com)"
+    );
+    assert_eq!(
+        sanitize_markdown("[![badge](https://a.com/b.png)](https://a.com)"),
+        "[![badge](https://a.com/b.png)](https://a.com)"
+    );
+    assert_eq!(render_plain("[[t](https://a.com)](https://b.com)"), "t");
+}
+
+#[test]
+fn test_sanitize_is_idempotent() {
+    let deep = format!("{}x{}", "[".repeat(50), "](javascript:alert(1))".repeat(50));
+    for input in [
+        "<<b>img src=x onerror=alert(1)>",
+        "<<script>x</script>script>alert(1)</script>",
+        "[[t](https://a.com)](javascript:alert(1))",
+        "<<<b>b>script>alert(1)<</b>/script>",
+        "[x](<javascript:alert(1)>)",
+        "open <script never closed",
+        deep.as_str(),
+    ] {
+        let once = sanitize_markdown(input);
+        assert_eq!(sanitize_markdown(&once), once, "{input}");
+        assert!(!once.contains("](javascript:"), "{input}");
+        assert!(!once.contains("<javascript:"), "{input}");
+        assert!(!once.to_ascii_lowercase().contains("<script"), "{input}");
+    }
+}
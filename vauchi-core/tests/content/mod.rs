@@ -10,5 +10,6 @@ mod cache_tests;
 mod config_tests;
 mod integrity_tests;
 mod manager_tests;
+mod markdown_tests;
 mod registry_integration_tests;
 mod types_tests;
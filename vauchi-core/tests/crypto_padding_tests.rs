@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for crypto::padding
+
+use vauchi_core::crypto::padding::*;
+use vauchi_core::crypto::{decrypt, encrypt, SymmetricKey};
+
+#[test]
+fn test_pad_unpad_roundtrip() {
+    for len in [0, 1, 250, 251, 252, 1000, 5000] {
+        let plaintext = vec![b'{'; len];
+        let padded = pad(&plaintext);
+        assert!(is_padded(&padded));
+        assert_eq!(unpad(&padded).unwrap(), plaintext);
+    }
+}
+
+#[test]
+fn test_padded_sizes_land_on_buckets() {
+    assert_eq!(pad(b"").len(), MIN_PADDING_BUCKET);
+    assert_eq!(pad(&[1u8; 251]).len(), 256);
+    assert_eq!(pad(&[1u8; 252]).len(), 512);
+    assert_eq!(pad(&[1u8; 1000]).len(), 1024);
+    assert_eq!(pad(&[1u8; 5000]).len(), 8192);
+
+    for len in [0, 17, 300, 4000] {
+        let size = pad(&vec![1u8; len]).len();
+        assert!(size.is_power_of_two());
+    }
+}
+
+#[test]
+fn test_small_and_large_updates_indistinguishable_in_bucket() {
+    let key = SymmetricKey::generate();
+    let small = encrypt(&key, &pad(br#"{"name":"A"}"#)).unwrap();
+    let large = encrypt(&key, &pad(&[b'x'; 200])).unwrap();
+    assert_eq!(small.len(), large.len());
+
+    let decrypted = decrypt(&key, &small).unwrap();
+    assert_eq!(unpad(&decrypted).unwrap(), br#"{"name":"A"}"#);
+}
+
+#[test]
+fn test_strip_padding_passes_unpadded_through() {
+    let json = br#"{"changes":[]}"#.to_vec();
+    assert!(!is_padded(&json));
+    assert_eq!(strip_padding(json.clone()).unwrap(), json);
+    assert_eq!(strip_padding(pad(&json)).unwrap(), json);
+}
+
+#[test]
+fn test_unpad_rejects_malformed() {
+    assert_eq!(unpad(b"{}"), Err(PaddingError::Malformed));
+    assert_eq!(unpad(&[0, 0, 0]), Err(PaddingError::Malformed));
+
+    // Declared length longer than the payload
+    let mut padded = pad(b"hello");
+    padded[1..5].copy_from_slice(&10_000u32.to_be_bytes());
+    assert_eq!(unpad(&padded), Err(PaddingError::Malformed));
+}
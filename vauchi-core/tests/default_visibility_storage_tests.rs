@@ -0,0 +1,159 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::labels default field visibility
+
+use std::collections::HashSet;
+
+use vauchi_core::contact::{Contact, DefaultFieldVisibility};
+use vauchi_core::contact_card::ContactCard;
+use vauchi_core::crypto::SymmetricKey;
+use vauchi_core::storage::{Storage, StorageError};
+
+fn create_contact(name: &str) -> Contact {
+    let mut public_key = [0u8; 32];
+    public_key[..name.len()].copy_from_slice(name.as_bytes());
+    Contact::from_exchange(public_key, ContactCard::new(name), SymmetricKey::generate())
+}
+
+fn can_see(storage: &Storage, contact: &Contact, field_id: &str) -> bool {
+    let contact = storage.load_contact(contact.id()).unwrap().unwrap();
+    storage
+        .load_field_visibility(&contact)
+        .unwrap()
+        .can_see(field_id)
+}
+
+#[test]
+fn test_default_field_visibility_defaults_to_everyone() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = create_contact("Alice");
+    storage.save_contact(&alice).unwrap();
+
+    assert_eq!(
+        storage.load_default_field_visibility().unwrap(),
+        DefaultFieldVisibility::Everyone
+    );
+
+    storage.apply_default_field_visibility("new-field").unwrap();
+    assert!(can_see(&storage, &alice, "new-field"));
+}
+
+#[test]
+fn test_default_field_visibility_nobody_hides_from_all_contacts() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = create_contact("Alice");
+    let bob = create_contact("Bob");
+    storage.save_contact(&alice).unwrap();
+    storage.save_contact(&bob).unwrap();
+
+    storage
+        .set_default_field_visibility(&DefaultFieldVisibility::Nobody)
+        .unwrap();
+    storage.apply_default_field_visibility("new-field").unwrap();
+
+    assert!(!can_see(&storage, &alice, "new-field"));
+    assert!(!can_see(&storage, &bob, "new-field"));
+    // Existing fields keep their visibility
+    assert!(can_see(&storage, &alice, "old-field"));
+}
+
+#[test]
+fn test_default_field_visibility_labels_share_with_members() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = create_contact("Alice");
+    let bob = create_contact("Bob");
+    storage.save_contact(&alice).unwrap();
+    storage.save_contact(&bob).unwrap();
+
+    let work = storage.create_label("Work").unwrap();
+    storage.add_contact_to_label(work.id(), alice.id()).unwrap();
+
+    let policy = DefaultFieldVisibility::Labels(HashSet::from([work.id().to_string()]));
+    storage.set_default_field_visibility(&policy).unwrap();
+    assert_eq!(storage.load_default_field_visibility().unwrap(), policy);
+
+    storage.apply_default_field_visibility("new-field").unwrap();
+
+    assert!(can_see(&storage, &alice, "new-field"));
+    assert!(!can_see(&storage, &bob, "new-field"));
+    assert!(storage
+        .load_label(work.id())
+        .unwrap()
+        .is_field_visible("new-field"));
+}
+
+#[test]
+fn test_default_field_visibility_nobody_hides_from_later_contacts() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    storage
+        .set_default_field_visibility(&DefaultFieldVisibility::Nobody)
+        .unwrap();
+    storage.apply_default_field_visibility("new-field").unwrap();
+
+    let carol = create_contact("Carol");
+    storage.save_contact(&carol).unwrap();
+
+    assert!(!can_see(&storage, &carol, "new-field"));
+}
+
+#[test]
+fn test_default_field_visibility_labels_follow_membership() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = create_contact("Alice");
+    storage.save_contact(&alice).unwrap();
+
+    let work = storage.create_label("Work").unwrap();
+    storage.add_contact_to_label(work.id(), alice.id()).unwrap();
+    let policy = DefaultFieldVisibility::Labels(HashSet::from([work.id().to_string()]));
+    storage.set_default_field_visibility(&policy).unwrap();
+    storage.apply_default_field_visibility("new-field").unwrap();
+
+    // Contacts added to the label later see the field
+    let carol = create_contact("Carol");
+    storage.save_contact(&carol).unwrap();
+    assert!(!can_see(&storage, &carol, "new-field"));
+    storage.add_contact_to_label(work.id(), carol.id()).unwrap();
+    assert!(can_see(&storage, &carol, "new-field"));
+
+    // Contacts removed from the label stop seeing it
+    storage
+        .remove_contact_from_label(work.id(), alice.id())
+        .unwrap();
+    assert!(!can_see(&storage, &alice, "new-field"));
+}
+
+#[test]
+fn test_contact_override_beats_hidden_default() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = create_contact("Alice");
+    let bob = create_contact("Bob");
+    storage.save_contact(&alice).unwrap();
+    storage.save_contact(&bob).unwrap();
+
+    storage
+        .set_default_field_visibility(&DefaultFieldVisibility::Nobody)
+        .unwrap();
+    storage.apply_default_field_visibility("new-field").unwrap();
+    storage
+        .save_contact_override(alice.id(), "new-field", true)
+        .unwrap();
+
+    assert!(can_see(&storage, &alice, "new-field"));
+    assert!(!can_see(&storage, &bob, "new-field"));
+}
+
+#[test]
+fn test_default_field_visibility_rejects_unknown_label() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let policy = DefaultFieldVisibility::Labels(HashSet::from(["missing".to_string()]));
+
+    let result = storage.set_default_field_visibility(&policy);
+
+    assert!(matches!(result, Err(StorageError::NotFound(_))));
+    assert_eq!(
+        storage.load_default_field_visibility().unwrap(),
+        DefaultFieldVisibility::Everyone
+    );
+}
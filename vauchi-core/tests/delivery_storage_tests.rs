@@ -6,8 +6,9 @@
 //!
 //! Traces to: features/message_delivery.feature
 
-use vauchi_core::crypto::SymmetricKey;
-use vauchi_core::storage::{DeliveryRecord, DeliveryStatus, Storage};
+use vauchi_core::crypto::{SigningKeyPair, SymmetricKey};
+use vauchi_core::network::simple_message::SimpleDeliveryReceipt;
+use vauchi_core::storage::{DeliveryRecord, DeliveryStatus, Storage, StorageError};
 
 fn test_storage() -> Storage {
     let key = SymmetricKey::generate();
@@ -380,3 +381,57 @@ fn test_delivery_status_progression() {
         assert_eq!(&record.status, status);
     }
 }
+
+#[test]
+fn test_save_and_load_delivery_receipt() {
+    let storage = test_storage();
+    let relay_key = SigningKeyPair::generate();
+    let receipt = SimpleDeliveryReceipt::sign(&relay_key, "msg-001", "recipient-abc", now());
+
+    assert!(storage.load_delivery_receipt("msg-001").unwrap().is_none());
+    assert!(storage
+        .save_delivery_receipt(&receipt, &relay_key.public_key(), now())
+        .unwrap());
+
+    let loaded = storage.load_delivery_receipt("msg-001").unwrap().unwrap();
+    assert_eq!(loaded, receipt);
+    assert!(loaded.verify(&relay_key.public_key()));
+
+    // A second receipt for the same message does not replace the first
+    let replay = SimpleDeliveryReceipt::sign(&relay_key, "msg-001", "recipient-abc", 1);
+    assert!(!storage
+        .save_delivery_receipt(&replay, &relay_key.public_key(), now())
+        .unwrap());
+    assert_eq!(
+        storage.load_delivery_receipt("msg-001").unwrap().unwrap(),
+        receipt
+    );
+}
+
+#[test]
+fn test_forged_delivery_receipt_is_not_stored() {
+    let storage = test_storage();
+    let relay_key = SigningKeyPair::generate();
+    let forged = SimpleDeliveryReceipt::sign(
+        &SigningKeyPair::generate(),
+        "msg-001",
+        "recipient-abc",
+        now(),
+    );
+
+    assert!(matches!(
+        storage.save_delivery_receipt(&forged, &relay_key.public_key(), now()),
+        Err(StorageError::InvalidData(_))
+    ));
+    assert!(storage.load_delivery_receipt("msg-001").unwrap().is_none());
+
+    // The genuine receipt still takes the slot
+    let genuine = SimpleDeliveryReceipt::sign(&relay_key, "msg-001", "recipient-abc", now());
+    assert!(storage
+        .save_delivery_receipt(&genuine, &relay_key.public_key(), now())
+        .unwrap());
+    assert_eq!(
+        storage.load_delivery_receipt("msg-001").unwrap().unwrap(),
+        genuine
+    );
+}
@@ -6,6 +6,7 @@
 //! Extracted from encrypted_message.rs
 
 use vauchi_core::exchange::*;
+use vauchi_core::{decrypt, encrypt, SigningKeyPair, SymmetricKey};
 
 #[test]
 fn test_encrypted_message_basic_roundtrip() {
@@ -25,3 +26,168 @@ fn test_encrypted_message_basic_roundtrip() {
     assert_eq!(payload.exchange_key, *alice.public_key());
     assert_eq!(payload.display_name, alice_name);
 }
+
+#[test]
+fn test_encrypted_message_without_card() {
+    let alice = X3DHKeyPair::generate();
+    let bob = X3DHKeyPair::generate();
+
+    let (msg, _) =
+        EncryptedExchangeMessage::create(&alice, bob.public_key(), &[0x41u8; 32], "Alice").unwrap();
+
+    let (payload, _) = msg.decrypt(&bob).unwrap();
+    assert!(payload.card.is_none());
+}
+
+#[test]
+fn test_encrypted_message_carries_card() {
+    use vauchi_core::{ContactCard, ContactField, FieldType};
+
+    let alice = X3DHKeyPair::generate();
+    let bob = X3DHKeyPair::generate();
+
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "work",
+        "alice@example.com",
+    ))
+    .unwrap();
+
+    let (msg, _) = EncryptedExchangeMessage::create_with_card(
+        &alice,
+        bob.public_key(),
+        &SigningKeyPair::generate(),
+        "Alice",
+        Some(&card),
+    )
+    .unwrap();
+
+    // The card travels inside the ciphertext, not in the clear
+    let wire = String::from_utf8_lossy(&msg.to_bytes()).to_string();
+    assert!(!wire.contains("alice@example.com"));
+
+    let (payload, _) = msg.decrypt(&bob).unwrap();
+    let received = payload.card.unwrap();
+    assert_eq!(received.fields().len(), 1);
+    assert_eq!(received.fields()[0].value(), "alice@example.com");
+}
+
+/// Re-encrypts a message after applying `tamper` to its JSON payload,
+/// as an attacker holding the session key could.
+fn tamper_payload(
+    msg: &EncryptedExchangeMessage,
+    key: &SymmetricKey,
+    tamper: impl FnOnce(&mut serde_json::Value),
+) -> EncryptedExchangeMessage {
+    let plaintext = decrypt(key, &msg.ciphertext).unwrap();
+    let mut payload: serde_json::Value = serde_json::from_slice(&plaintext).unwrap();
+    tamper(&mut payload);
+
+    let mut tampered = msg.clone();
+    tampered.ciphertext = encrypt(key, &serde_json::to_vec(&payload).unwrap()).unwrap();
+    tampered
+}
+
+#[test]
+fn test_exchange_card_signature_verification() {
+    use vauchi_core::{ContactCard, ContactField, FieldType};
+
+    let alice = X3DHKeyPair::generate();
+    let bob = X3DHKeyPair::generate();
+    let alice_signing = SigningKeyPair::generate();
+
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(FieldType::Phone, "mobile", "+1 555 0100"))
+        .unwrap();
+
+    let (msg, key) = EncryptedExchangeMessage::create_with_card(
+        &alice,
+        bob.public_key(),
+        &alice_signing,
+        "Alice",
+        Some(&card),
+    )
+    .unwrap();
+
+    // Genuine card passes
+    let (payload, _) = msg.decrypt(&bob).unwrap();
+    assert_eq!(payload.identity_key, *alice_signing.public_key().as_bytes());
+    assert_eq!(payload.card.unwrap().fields()[0].value(), "+1 555 0100");
+
+    // Altered field value fails
+    let tampered = tamper_payload(&msg, &key, |payload| {
+        let bytes: Vec<u8> = serde_json::from_value(payload["card_bytes"].take()).unwrap();
+        let altered = String::from_utf8(bytes)
+            .unwrap()
+            .replace("+1 555 0100", "+1 555 0199");
+        payload["card_bytes"] = serde_json::to_value(altered.into_bytes()).unwrap();
+    });
+    assert!(matches!(
+        tampered.decrypt(&bob),
+        Err(ExchangeError::InvalidCardSignature)
+    ));
+
+    // Stripped signature fails
+    let unsigned = tamper_payload(&msg, &key, |payload| {
+        payload.as_object_mut().unwrap().remove("card_signature");
+    });
+    assert!(matches!(
+        unsigned.decrypt(&bob),
+        Err(ExchangeError::UnsignedCard)
+    ));
+}
+
+#[test]
+fn test_exchange_card_signature_covers_transmitted_bytes() {
+    use vauchi_core::ContactCard;
+
+    let alice = X3DHKeyPair::generate();
+    let bob = X3DHKeyPair::generate();
+    let alice_signing = SigningKeyPair::generate();
+    let identity_key = *alice_signing.public_key().as_bytes();
+
+    let (msg, key) = EncryptedExchangeMessage::create_with_card(
+        &alice,
+        bob.public_key(),
+        &alice_signing,
+        "Alice",
+        Some(&ContactCard::new("Alice")),
+    )
+    .unwrap();
+
+    // A card from a newer client, with a key this build does not know,
+    // verifies against the bytes it was signed as
+    let mut card = serde_json::to_value(ContactCard::new("Alice")).unwrap();
+    card["future_field"] = "ignored".into();
+    let card_bytes = serde_json::to_vec(&card).unwrap();
+    let mut message = b"vauchi-exchange-card-v1".to_vec();
+    message.extend_from_slice(&identity_key);
+    message.extend_from_slice(&card_bytes);
+    let signature = alice_signing.sign(&message).as_bytes().to_vec();
+
+    let newer = tamper_payload(&msg, &key, |payload| {
+        payload["card_bytes"] = serde_json::to_value(&card_bytes).unwrap();
+        payload["card_signature"] = serde_json::to_value(&signature).unwrap();
+    });
+    let (payload, _) = newer.decrypt(&bob).unwrap();
+    assert_eq!(payload.card.unwrap().display_name(), "Alice");
+}
+
+#[test]
+fn test_exchange_advertises_padding_support() {
+    let alice = X3DHKeyPair::generate();
+    let bob = X3DHKeyPair::generate();
+
+    let (msg, key) =
+        EncryptedExchangeMessage::create(&alice, bob.public_key(), &[0x41u8; 32], "Alice").unwrap();
+    let (payload, _) = msg.decrypt(&bob).unwrap();
+    assert!(payload.accepts_padding);
+
+    // Older clients leave the flag out
+    let legacy = tamper_payload(&msg, &key, |payload| {
+        payload.as_object_mut().unwrap().remove("accepts_padding");
+    });
+    let (payload, _) = legacy.decrypt(&bob).unwrap();
+    assert!(!payload.accepts_padding);
+}
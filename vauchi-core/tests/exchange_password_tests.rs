@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for exchange::password
+
+use vauchi_core::exchange::*;
+
+const ALICE_IDENTITY: [u8; 32] = [0x41u8; 32];
+const BOB_IDENTITY: [u8; 32] = [0x42u8; 32];
+
+/// Outcome of a password exchange on both sides.
+struct ExchangeRun {
+    /// What Alice got from opening Bob's answer.
+    opened: Result<(DecryptedExchangePayload, vauchi_core::SymmetricKey), ExchangeError>,
+    /// Bob's shared secret.
+    bob_secret: vauchi_core::SymmetricKey,
+    /// Bob's check of the confirmation Alice sent back.
+    confirmed: Result<(), ExchangeError>,
+}
+
+/// Runs a password exchange where Alice offers and Bob answers.
+fn run_exchange(alice_password: &str, bob_password: &str) -> ExchangeRun {
+    let alice_keys = X3DHKeyPair::generate();
+    let bob_keys = X3DHKeyPair::generate();
+
+    let offer = PasswordExchangeOffer::new(&ALICE_IDENTITY, alice_keys.public_key());
+    let alice_key = PasswordKey::derive(&offer, alice_password).unwrap();
+    let offer = PasswordExchangeOffer::from_data_string(&offer.to_data_string()).unwrap();
+
+    let (message, bob_secret, expected) =
+        PasswordExchangeMessage::create(&offer, bob_password, &bob_keys, &BOB_IDENTITY, "Bob")
+            .unwrap();
+    let message = PasswordExchangeMessage::from_bytes(&message.to_bytes()).unwrap();
+
+    let confirmation = message.confirm(&alice_key);
+    let confirmation = PasswordExchangeConfirmation::from_bytes(&confirmation.to_bytes()).unwrap();
+    let expected = PendingConfirmation::from_bytes(&expected.to_bytes()).unwrap();
+
+    ExchangeRun {
+        opened: message.open(&alice_key, &alice_keys),
+        bob_secret,
+        confirmed: expected.verify(&confirmation),
+    }
+}
+
+#[test]
+fn test_password_exchange_matching_passwords_agree() {
+    let run = run_exchange("tulip-42", "tulip-42");
+    let (payload, alice_secret) = run.opened.unwrap();
+
+    assert_eq!(payload.identity_key, BOB_IDENTITY);
+    assert_eq!(payload.display_name, "Bob");
+    assert_eq!(alice_secret.as_bytes(), run.bob_secret.as_bytes());
+    assert!(run.confirmed.is_ok());
+}
+
+#[test]
+fn test_password_exchange_mismatched_passwords_fail() {
+    let run = run_exchange("tulip-42", "tulip-43");
+
+    // Both sides detect the mismatch
+    assert!(matches!(run.opened, Err(ExchangeError::PasswordMismatch)));
+    assert!(matches!(
+        run.confirmed,
+        Err(ExchangeError::PasswordMismatch)
+    ));
+}
+
+#[test]
+fn test_password_exchange_confirmation_is_not_an_answer() {
+    let alice_keys = X3DHKeyPair::generate();
+    let offer = PasswordExchangeOffer::new(&ALICE_IDENTITY, alice_keys.public_key());
+    let (message, _, expected) = PasswordExchangeMessage::create(
+        &offer,
+        "tulip-42",
+        &X3DHKeyPair::generate(),
+        &BOB_IDENTITY,
+        "Bob",
+    )
+    .unwrap();
+
+    assert!(PasswordExchangeConfirmation::from_bytes(&message.to_bytes()).is_err());
+    assert!(PasswordExchangeMessage::from_bytes(&expected.to_bytes()).is_err());
+    assert!(PendingConfirmation::from_bytes(&message.to_bytes()).is_err());
+}
+
+#[test]
+fn test_password_exchange_rejects_substituted_exchange() {
+    let alice_keys = X3DHKeyPair::generate();
+    let offer = PasswordExchangeOffer::new(&ALICE_IDENTITY, alice_keys.public_key());
+    let alice_key = PasswordKey::derive(&offer, "tulip-42").unwrap();
+
+    let (mut message, _, _) = PasswordExchangeMessage::create(
+        &offer,
+        "tulip-42",
+        &X3DHKeyPair::generate(),
+        &BOB_IDENTITY,
+        "Bob",
+    )
+    .unwrap();
+
+    // A relay swaps in its own exchange message but keeps Bob's confirmation
+    let (mallory, _) = EncryptedExchangeMessage::create(
+        &X3DHKeyPair::generate(),
+        alice_keys.public_key(),
+        &[0x4du8; 32],
+        "Mallory",
+    )
+    .unwrap();
+    message.exchange = mallory.to_bytes();
+
+    assert!(matches!(
+        message.open(&alice_key, &alice_keys),
+        Err(ExchangeError::PasswordMismatch)
+    ));
+}
+
+#[test]
+fn test_password_exchange_offer_rejects_garbage() {
+    assert!(PasswordExchangeOffer::from_data_string("not an offer").is_err());
+    assert!(PasswordExchangeOffer::from_data_string("V0JQWA==").is_err());
+}
+
+#[test]
+fn test_password_exchange_offers_use_fresh_salts() {
+    let keys = X3DHKeyPair::generate();
+    let first = PasswordExchangeOffer::new(&ALICE_IDENTITY, keys.public_key());
+    let second = PasswordExchangeOffer::new(&ALICE_IDENTITY, keys.public_key());
+
+    assert_ne!(first.to_data_string(), second.to_data_string());
+}
@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for exchange::rekey
+
+use vauchi_core::exchange::*;
+use vauchi_core::Identity;
+
+const NOW: u64 = 1_800_000_000;
+
+#[test]
+fn test_rekey_request_response_derive_same_secret() {
+    let alice = Identity::create("Alice");
+    let bob = Identity::create("Bob");
+
+    let request = RekeyRequest::create_with_timestamp(&alice, NOW);
+    let request = match RekeyMessage::from_bytes(&RekeyMessage::Request(request).to_bytes()) {
+        Ok(RekeyMessage::Request(r)) => r,
+        other => panic!("expected request, got {:?}", other),
+    };
+    request.verify(alice.signing_public_key(), NOW).unwrap();
+
+    let (response, bob_secret) = request.accept(&bob).unwrap();
+    let alice_secret = response
+        .complete(&alice, bob.signing_public_key(), NOW)
+        .unwrap();
+
+    assert_eq!(alice_secret.as_bytes(), bob_secret.as_bytes());
+}
+
+#[test]
+fn test_rekey_request_rejects_wrong_sender_and_stale_timestamps() {
+    let alice = Identity::create("Alice");
+    let mallory = Identity::create("Mallory");
+    let request = RekeyRequest::create_with_timestamp(&alice, NOW);
+
+    assert!(matches!(
+        request.verify(mallory.signing_public_key(), NOW),
+        Err(ExchangeError::IdentityMismatch)
+    ));
+    assert!(matches!(
+        request.verify(
+            alice.signing_public_key(),
+            NOW + REKEY_REQUEST_MAX_AGE_SECS + 1
+        ),
+        Err(ExchangeError::TokenExpired)
+    ));
+    assert!(matches!(
+        request.verify(alice.signing_public_key(), NOW - 3600),
+        Err(ExchangeError::TokenExpired)
+    ));
+}
+
+#[test]
+fn test_rekey_response_rejects_other_request_or_responder() {
+    let alice = Identity::create("Alice");
+    let bob = Identity::create("Bob");
+    let mallory = Identity::create("Mallory");
+
+    let request = RekeyRequest::create_with_timestamp(&alice, NOW);
+    let (response, _) = request.accept(&bob).unwrap();
+    assert!(matches!(
+        response.complete(&alice, bob.signing_public_key(), NOW + 1),
+        Err(ExchangeError::InvalidState(_))
+    ));
+
+    // A third party cannot answer Alice's request on Bob's behalf
+    let (forged, _) = request.accept(&mallory).unwrap();
+    assert!(matches!(
+        forged.complete(&alice, bob.signing_public_key(), NOW),
+        Err(ExchangeError::IdentityMismatch)
+    ));
+
+    // A response signed for a different requester does not verify
+    let carol = Identity::create("Carol");
+    let carol_request = RekeyRequest::create_with_timestamp(&carol, NOW);
+    let (for_carol, _) = carol_request.accept(&bob).unwrap();
+    assert!(matches!(
+        for_carol.complete(&alice, bob.signing_public_key(), NOW),
+        Err(ExchangeError::InvalidSignature)
+    ));
+}
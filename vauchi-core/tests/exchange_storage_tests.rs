@@ -0,0 +1,186 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::exchange
+
+use vauchi_core::contact::Contact;
+use vauchi_core::contact_card::ContactCard;
+use vauchi_core::crypto::SymmetricKey;
+use vauchi_core::storage::{Storage, StorageError};
+
+fn create_contact(name: &str) -> Contact {
+    let mut public_key = [0u8; 32];
+    public_key[..name.len()].copy_from_slice(name.as_bytes());
+    Contact::from_exchange(public_key, ContactCard::new(name), SymmetricKey::generate())
+}
+
+#[test]
+fn test_pending_exchange_delivery_tracking() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+
+    storage
+        .save_pending_exchange("alice", b"hello alice")
+        .unwrap();
+    storage.save_pending_exchange("bob", b"hello bob").unwrap();
+
+    let pending = storage.load_pending_exchange("alice").unwrap().unwrap();
+    assert_eq!(pending.message, b"hello alice");
+    assert_eq!(pending.attempts, 0);
+    assert!(!pending.is_delivered());
+
+    storage.record_exchange_attempt("alice").unwrap();
+    storage.record_exchange_attempt("alice").unwrap();
+    storage.mark_exchange_delivered("alice").unwrap();
+
+    let delivered = storage.load_pending_exchange("alice").unwrap().unwrap();
+    assert_eq!(delivered.attempts, 2);
+    assert!(delivered.is_delivered());
+    assert!(delivered.message.is_empty());
+
+    let undelivered = storage.list_undelivered_exchanges().unwrap();
+    assert_eq!(undelivered.len(), 1);
+    assert_eq!(undelivered[0].contact_id, "bob");
+    assert_eq!(undelivered[0].message, b"hello bob");
+}
+
+#[test]
+fn test_pending_exchange_unknown_contact() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+
+    assert!(storage.load_pending_exchange("nobody").unwrap().is_none());
+    assert!(matches!(
+        storage.record_exchange_attempt("nobody"),
+        Err(StorageError::NotFound(_))
+    ));
+    assert!(matches!(
+        storage.mark_exchange_delivered("nobody"),
+        Err(StorageError::NotFound(_))
+    ));
+}
+
+#[test]
+fn test_delete_contact_drops_pending_exchange() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = create_contact("Alice");
+    storage.save_contact(&alice).unwrap();
+    storage
+        .save_pending_exchange(alice.id(), b"hello alice")
+        .unwrap();
+
+    storage.delete_contact(alice.id()).unwrap();
+
+    assert!(storage.load_pending_exchange(alice.id()).unwrap().is_none());
+}
+
+#[test]
+fn test_delivered_exchange_survives_archive_and_key_rotation() {
+    let old_key = SymmetricKey::generate();
+    let mut storage = Storage::in_memory(old_key.clone()).unwrap();
+    storage
+        .save_pending_exchange("alice", b"hello alice")
+        .unwrap();
+    storage.save_pending_exchange("bob", b"hello bob").unwrap();
+    storage.mark_exchange_delivered("alice").unwrap();
+
+    let archive = storage
+        .export_encrypted_archive("archive password")
+        .unwrap();
+    let restored = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    restored
+        .import_encrypted_archive(&archive, "archive password")
+        .unwrap();
+    assert!(restored
+        .load_pending_exchange("alice")
+        .unwrap()
+        .unwrap()
+        .is_delivered());
+    assert_eq!(
+        restored
+            .load_pending_exchange("bob")
+            .unwrap()
+            .unwrap()
+            .message,
+        b"hello bob"
+    );
+
+    let new_key = SymmetricKey::generate();
+    storage.rotate_encryption_key(&old_key, &new_key).unwrap();
+    assert!(storage
+        .load_pending_exchange("alice")
+        .unwrap()
+        .unwrap()
+        .is_delivered());
+    assert_eq!(
+        storage
+            .load_pending_exchange("bob")
+            .unwrap()
+            .unwrap()
+            .message,
+        b"hello bob"
+    );
+}
+
+#[test]
+fn test_unconfirmed_contact_discarded_without_tombstone() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = create_contact("alice");
+    let bob = create_contact("bob");
+    storage.save_contact(&alice).unwrap();
+    storage.save_contact(&bob).unwrap();
+    storage
+        .save_expected_confirmation(alice.id(), b"expected-a")
+        .unwrap();
+    storage
+        .save_expected_confirmation(bob.id(), b"expected-b")
+        .unwrap();
+
+    assert_eq!(
+        storage
+            .load_expected_confirmation(alice.id())
+            .unwrap()
+            .as_deref(),
+        Some(&b"expected-a"[..])
+    );
+
+    // Alice confirms; Bob's confirmation fails
+    assert!(storage.delete_expected_confirmation(alice.id()).unwrap());
+    assert!(storage.discard_unconfirmed_contact(bob.id()).unwrap());
+
+    assert!(storage.load_contact(alice.id()).unwrap().is_some());
+    assert!(!storage.discard_unconfirmed_contact(alice.id()).unwrap());
+    assert!(storage.load_contact(alice.id()).unwrap().is_some());
+
+    assert!(storage.load_contact(bob.id()).unwrap().is_none());
+    assert!(!storage.was_contact_deleted(bob.id()).unwrap());
+    assert_eq!(storage.load_expected_confirmation(bob.id()).unwrap(), None);
+}
+
+#[test]
+fn test_expired_confirmations_discard_unconfirmed_contacts() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = create_contact("alice");
+    storage.save_contact(&alice).unwrap();
+    storage
+        .save_expected_confirmation(alice.id(), b"expected-a")
+        .unwrap();
+
+    // Still waiting for the confirmation
+    assert_eq!(storage.discard_expired_confirmations(0).unwrap(), 0);
+    assert!(storage.load_contact(alice.id()).unwrap().is_some());
+
+    assert_eq!(
+        storage
+            .discard_expired_confirmations(i64::MAX as u64)
+            .unwrap(),
+        1
+    );
+    assert!(storage.load_contact(alice.id()).unwrap().is_none());
+    assert!(!storage.was_contact_deleted(alice.id()).unwrap());
+    assert_eq!(
+        storage
+            .discard_expired_confirmations(i64::MAX as u64)
+            .unwrap(),
+        0
+    );
+}
@@ -6,7 +6,10 @@
 //!
 //! These tests are written FIRST (RED phase) before implementation.
 
-use vauchi_core::exchange::{ExchangeQR, X3DHKeyPair, X3DH};
+use vauchi_core::exchange::{
+    render_qr_string, ExchangeError, ExchangeQR, QrErrorCorrection, X3DHKeyPair,
+    MAX_INTRO_NOTE_LEN, X3DH,
+};
 use vauchi_core::Identity;
 
 // =============================================================================
@@ -130,6 +133,20 @@ fn test_qr_expires_after_5_minutes() {
     assert!(old_qr.is_expired());
 }
 
+/// Tests the QR expiry boundary with and without clock skew tolerance
+#[test]
+fn test_qr_expiry_boundary_with_skew_tolerance() {
+    let identity = Identity::create("Alice");
+    let qr = ExchangeQR::generate_with_timestamp(&identity, 1_000_000);
+    let deadline = 1_000_000 + 300;
+
+    assert!(!qr.is_expired_at(deadline, 0));
+    assert!(qr.is_expired_at(deadline + 1, 0));
+
+    assert!(!qr.is_expired_at(deadline + 60, 60));
+    assert!(qr.is_expired_at(deadline + 61, 60));
+}
+
 /// Tests QR signature verification
 #[test]
 fn test_qr_signature_verification() {
@@ -139,6 +156,44 @@ fn test_qr_signature_verification() {
     assert!(qr.verify_signature());
 }
 
+/// Tests that an intro note survives encoding and is covered by the signature
+#[test]
+fn test_qr_intro_note_roundtrip() {
+    let identity = Identity::create("Alice");
+    let qr = ExchangeQR::generate_with_note(&identity, " Met at the Rust meetup ").unwrap();
+
+    let decoded = ExchangeQR::from_data_string(&qr.to_data_string()).unwrap();
+    assert_eq!(decoded.intro_note(), Some("Met at the Rust meetup"));
+    assert!(decoded.verify_signature());
+
+    // Without a note the encoding is unchanged
+    let plain = ExchangeQR::generate(&identity);
+    let decoded = ExchangeQR::from_data_string(&plain.to_data_string()).unwrap();
+    assert_eq!(decoded.intro_note(), None);
+
+    // Swapping the note invalidates the signature
+    use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+    let mut bytes = BASE64.decode(qr.to_data_string()).unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] = b'X';
+    assert!(matches!(
+        ExchangeQR::from_data_string(&BASE64.encode(&bytes)),
+        Err(ExchangeError::InvalidSignature)
+    ));
+}
+
+/// Tests that oversized intro notes are rejected
+#[test]
+fn test_qr_intro_note_too_long() {
+    let identity = Identity::create("Alice");
+    let note = "x".repeat(MAX_INTRO_NOTE_LEN + 1);
+
+    assert!(matches!(
+        ExchangeQR::generate_with_note(&identity, &note),
+        Err(ExchangeError::IntroNoteTooLong(_))
+    ));
+}
+
 /// Tests that malformed QR data is rejected
 #[test]
 fn test_malformed_qr_rejected() {
@@ -158,6 +213,46 @@ fn test_non_vauchi_qr_rejected() {
     assert!(result.is_err());
 }
 
+/// Tests that higher error correction renders a denser code of the same data
+#[test]
+fn test_qr_renders_at_high_error_correction() {
+    let identity = Identity::create("Alice");
+    let qr = ExchangeQR::generate(&identity);
+
+    let low = qr
+        .to_qr_image_string_with_level(QrErrorCorrection::Low)
+        .unwrap();
+    let high = qr
+        .to_qr_image_string_with_level(QrErrorCorrection::High)
+        .unwrap();
+
+    let size = |rendered: &str| rendered.lines().count();
+    assert!(size(&high) > size(&low));
+    assert!(high.lines().all(|row| row.chars().count() == size(&high)));
+
+    // The default matches Medium
+    assert_eq!(
+        qr.to_qr_image_string(),
+        qr.to_qr_image_string_with_level(QrErrorCorrection::Medium)
+            .unwrap()
+    );
+}
+
+/// Tests that data too large for the chosen level is rejected
+#[test]
+fn test_qr_data_too_long_for_level() {
+    // Fits in a version 40 code at L (2953 bytes) but not at H (1273 bytes)
+    let data = "x".repeat(2000);
+
+    assert!(render_qr_string(&data, QrErrorCorrection::Low).is_ok());
+    let result = render_qr_string(&data, QrErrorCorrection::High);
+    assert!(matches!(
+        result,
+        Err(ExchangeError::QRDataTooLong(QrErrorCorrection::High))
+    ));
+    assert!(result.unwrap_err().to_string().contains("lower level"));
+}
+
 // =============================================================================
 // BLE Proximity Tests (from contact_exchange.feature @ble scenarios)
 // =============================================================================
@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for contact_card::icon
+
+use vauchi_core::contact_card::*;
+use vauchi_core::social::{SocialNetwork, SocialNetworkRegistry};
+
+fn icon(field_type: FieldType, label: &str, value: &str) -> String {
+    ContactField::new(field_type, label, value).icon_key(&SocialNetworkRegistry::with_defaults())
+}
+
+#[test]
+fn test_icon_key_by_field_type() {
+    assert_eq!(
+        icon(FieldType::Email, "work", "alice@acme.example"),
+        "email"
+    );
+    assert_eq!(
+        icon(FieldType::Phone, "mobile", "+41 79 000 00 00"),
+        "phone"
+    );
+    assert_eq!(icon(FieldType::Website, "blog", "alice.example"), "website");
+    assert_eq!(icon(FieldType::Address, "home", "1 Main St"), "address");
+    assert_eq!(icon(FieldType::RichText, "bio", "Hello"), "text");
+
+    // Custom fields take the icon of what their value looks like
+    assert_eq!(icon(FieldType::Custom, "other", "bob@example.com"), "email");
+    assert_eq!(
+        icon(FieldType::Custom, "other", "ask at reception"),
+        "custom"
+    );
+}
+
+#[test]
+fn test_icon_key_for_known_social_network() {
+    assert_eq!(icon(FieldType::Social, "github", "alice"), "github");
+    assert_eq!(icon(FieldType::Social, " GitHub ", "alice"), "github");
+
+    // A network's own icon wins over its ID
+    let mut registry = SocialNetworkRegistry::new();
+    registry.add(
+        SocialNetwork::new("github", "GitHub", "https://github.com/{username}")
+            .with_icon("github-mark"),
+    );
+    let field = ContactField::new(FieldType::Social, "github", "alice");
+    assert_eq!(field.icon_key(&registry), "github-mark");
+}
+
+#[test]
+fn test_icon_key_for_unknown_social_network() {
+    assert_eq!(
+        icon(FieldType::Social, "friendster", "alice"),
+        GENERIC_SOCIAL_ICON
+    );
+    assert_eq!(
+        ContactField::new(FieldType::Social, "github", "alice")
+            .icon_key(&SocialNetworkRegistry::new()),
+        "generic-social"
+    );
+}
@@ -87,7 +87,14 @@ fn test_validation_status_from_validations_filters_blocked() {
     let mut blocked = HashSet::new();
     blocked.insert("mallory".to_string());
 
-    let status = ValidationStatus::from_validations(&validations, "@alice", None, &blocked);
+    let status = ValidationStatus::from_validations(
+        &validations,
+        "@alice",
+        None,
+        &blocked,
+        &HashSet::new(),
+        &TrustThresholds::default(),
+    );
 
     assert_eq!(status.count, 2);
     assert!(!status.validator_ids.contains(&"mallory".to_string()));
@@ -107,6 +114,8 @@ fn test_validation_status_invalidated_on_value_change() {
         "@alice_new", // Value changed
         None,
         &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
     );
 
     // Validation doesn't count because field value changed
@@ -172,8 +181,14 @@ fn test_email_validation_trust_levels() {
         })
         .collect();
 
-    let status =
-        ValidationStatus::from_validations(&validations, "bob@example.com", None, &HashSet::new());
+    let status = ValidationStatus::from_validations(
+        &validations,
+        "bob@example.com",
+        None,
+        &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
+    );
 
     assert_eq!(status.count, 3);
     assert_eq!(status.trust_level, TrustLevel::PartialConfidence);
@@ -223,6 +238,8 @@ fn test_phone_validation_independent_of_email() {
         "+1-555-123-4567",
         None,
         &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
     );
 
     let email_status = ValidationStatus::from_validations(
@@ -230,6 +247,8 @@ fn test_phone_validation_independent_of_email() {
         "bob@example.com",
         None,
         &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
     );
 
     assert_eq!(phone_status.count, 5);
@@ -263,8 +282,14 @@ fn test_website_validation_requires_exact_url_match() {
     )];
 
     // Same URL - validation counts
-    let status1 =
-        ValidationStatus::from_validations(&validations, "https://bob.dev", None, &HashSet::new());
+    let status1 = ValidationStatus::from_validations(
+        &validations,
+        "https://bob.dev",
+        None,
+        &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
+    );
     assert_eq!(status1.count, 1);
 
     // Different URL - validation doesn't count
@@ -273,6 +298,8 @@ fn test_website_validation_requires_exact_url_match() {
         "https://bob.dev/new",
         None,
         &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
     );
     assert_eq!(status2.count, 0);
 }
@@ -368,14 +395,26 @@ fn test_validation_reset_on_field_change() {
         .collect();
 
     // Old value has 5 validations
-    let status_old =
-        ValidationStatus::from_validations(&validations, "@bob_old", None, &HashSet::new());
+    let status_old = ValidationStatus::from_validations(
+        &validations,
+        "@bob_old",
+        None,
+        &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
+    );
     assert_eq!(status_old.count, 5);
     assert_eq!(status_old.trust_level, TrustLevel::HighConfidence);
 
     // New value has 0 validations (the old validations don't count)
-    let status_new =
-        ValidationStatus::from_validations(&validations, "@bob_new", None, &HashSet::new());
+    let status_new = ValidationStatus::from_validations(
+        &validations,
+        "@bob_new",
+        None,
+        &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
+    );
     assert_eq!(status_new.count, 0);
     assert_eq!(status_new.trust_level, TrustLevel::Unverified);
 }
@@ -424,7 +463,14 @@ fn test_multiple_validators_same_field() {
     }
 
     // Status should show all 5 validations
-    let status = ValidationStatus::from_validations(&validations, "@alice", None, &HashSet::new());
+    let status = ValidationStatus::from_validations(
+        &validations,
+        "@alice",
+        None,
+        &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
+    );
     assert_eq!(status.count, 5);
     assert_eq!(status.trust_level, TrustLevel::HighConfidence);
 }
@@ -443,8 +489,14 @@ fn test_validated_by_me_flag() {
     ];
 
     // When checking with my ID, validated_by_me should be true
-    let status =
-        ValidationStatus::from_validations(&validations, "@bob", Some(&my_id), &HashSet::new());
+    let status = ValidationStatus::from_validations(
+        &validations,
+        "@bob",
+        Some(&my_id),
+        &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
+    );
     assert!(status.validated_by_me);
     assert_eq!(status.count, 2);
 
@@ -454,6 +506,95 @@ fn test_validated_by_me_flag() {
         "@bob",
         Some("someone_else"),
         &HashSet::new(),
+        &HashSet::new(),
+        &TrustThresholds::default(),
     );
     assert!(!status.validated_by_me);
 }
+
+// === Trust Threshold Tests ===
+
+fn validations_by(validators: &[&str]) -> Vec<ProfileValidation> {
+    validators
+        .iter()
+        .map(|v| ProfileValidation::new("bob:email", "bob@example.com", v, [0u8; 64]))
+        .collect()
+}
+
+fn is_trusted(
+    validations: &[ProfileValidation],
+    in_person: &[&str],
+    thresholds: TrustThresholds,
+) -> bool {
+    let in_person: HashSet<String> = in_person.iter().map(|s| s.to_string()).collect();
+    ValidationStatus::from_validations(
+        validations,
+        "bob@example.com",
+        None,
+        &HashSet::new(),
+        &in_person,
+        &thresholds,
+    )
+    .trusted
+}
+
+#[test]
+fn test_trust_threshold_boundaries() {
+    let thresholds = TrustThresholds {
+        min_validations: 3,
+        in_person_counts_double: false,
+    };
+
+    assert!(!is_trusted(&validations_by(&[]), &[], thresholds));
+    assert!(!is_trusted(&validations_by(&["a", "b"]), &[], thresholds));
+    assert!(is_trusted(
+        &validations_by(&["a", "b", "c"]),
+        &[],
+        thresholds
+    ));
+    assert!(is_trusted(
+        &validations_by(&["a", "b", "c", "d"]),
+        &[],
+        thresholds
+    ));
+
+    // In-person validators only count once unless enabled
+    assert!(!is_trusted(
+        &validations_by(&["a", "b"]),
+        &["a"],
+        thresholds
+    ));
+}
+
+#[test]
+fn test_trust_threshold_in_person_counts_double() {
+    let thresholds = TrustThresholds {
+        min_validations: 3,
+        in_person_counts_double: true,
+    };
+
+    assert!(!is_trusted(&validations_by(&["a", "b"]), &[], thresholds));
+    assert!(is_trusted(&validations_by(&["a", "b"]), &["a"], thresholds));
+    assert!(!is_trusted(&validations_by(&["a"]), &["a"], thresholds));
+
+    // Validations of an old value do not contribute weight
+    let mut validations = validations_by(&["a"]);
+    validations.push(ProfileValidation::new(
+        "bob:email",
+        "old@example.com",
+        "b",
+        [0u8; 64],
+    ));
+    assert!(!is_trusted(&validations, &["a", "b"], thresholds));
+}
+
+#[test]
+fn test_trust_thresholds_default_matches_high_confidence() {
+    let validations = validations_by(&["a", "b", "c", "d", "e"]);
+    assert!(is_trusted(&validations, &[], TrustThresholds::default()));
+    assert!(!is_trusted(
+        &validations[..4],
+        &[],
+        TrustThresholds::default()
+    ));
+}
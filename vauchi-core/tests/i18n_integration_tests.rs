@@ -217,6 +217,28 @@ fn test_error_strings() {
     }
 }
 
+/// Test: Error messages for the user exist in every locale
+#[test]
+fn test_user_error_strings() {
+    for key in [
+        "error.offline",
+        "error.self_contact",
+        "error.self_vouching",
+        "error.storage",
+    ] {
+        let en = get_string(Locale::English, key);
+        assert!(!en.contains("Missing"), "Error {} should exist", key);
+        for locale in get_available_locales() {
+            assert!(!get_string(locale, key).contains("Missing"));
+        }
+    }
+
+    assert_eq!(
+        get_string_with_args(Locale::French, "error.too_many_contacts", &[("max", "500")]),
+        "Vous pouvez avoir au maximum 500 contacts."
+    );
+}
+
 // ============================================================
 // RTL Support
 // ============================================================
@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for identity::rotation
+
+use vauchi_core::crypto::ratchet::DoubleRatchetState;
+use vauchi_core::exchange::X3DHKeyPair;
+use vauchi_core::identity::{KeyRotationError, KeyRotationProof};
+use vauchi_core::*;
+
+#[test]
+fn test_rotation_proof_verifies_and_links_keys() {
+    let mut alice = Identity::create("Alice");
+    let old_key = *alice.signing_public_key();
+    let old_exchange_key = alice.exchange_public_key().to_vec();
+
+    let proof = alice.rotate_signing_key();
+    proof.verify().unwrap();
+
+    assert_eq!(proof.old_public_key(), &old_key);
+    assert_eq!(proof.new_public_key(), alice.signing_public_key());
+    assert_ne!(alice.signing_public_key(), &old_key);
+    assert_ne!(alice.exchange_public_key(), old_exchange_key.as_slice());
+    assert_eq!(alice.display_name(), "Alice");
+
+    let decoded = KeyRotationProof::from_bytes(&proof.to_bytes()).unwrap();
+    decoded.verify().unwrap();
+}
+
+#[test]
+fn test_tampered_rotation_proof_rejected() {
+    let mut alice = Identity::create("Alice");
+    let proof = alice.rotate_signing_key();
+
+    // Swap in an attacker's key as the new one
+    let mallory = Identity::create("Mallory");
+    let mut json: serde_json::Value = serde_json::from_slice(&proof.to_bytes()).unwrap();
+    json["new_public_key"] = serde_json::to_value(mallory.signing_public_key()).unwrap();
+    let forged = KeyRotationProof::from_bytes(&serde_json::to_vec(&json).unwrap()).unwrap();
+    assert_eq!(forged.verify(), Err(KeyRotationError::InvalidSignature));
+
+    assert_eq!(
+        KeyRotationProof::from_bytes(b"not a proof").unwrap_err(),
+        KeyRotationError::Malformed
+    );
+}
+
+#[test]
+fn test_contact_follows_rotation_keeping_secret_and_verification() {
+    let mut alice = Identity::create("Alice");
+    let shared_key = SymmetricKey::generate();
+    let mut contact = Contact::from_exchange(
+        *alice.signing_public_key(),
+        ContactCard::new("Alice"),
+        shared_key.clone(),
+    );
+    contact.mark_fingerprint_verified();
+
+    // A proof for someone else's key does not apply
+    let mut carol = Identity::create("Carol");
+    let other = carol.rotate_signing_key();
+    assert_eq!(
+        contact.apply_key_rotation(&other),
+        Err(KeyRotationError::KeyMismatch)
+    );
+
+    let proof = alice.rotate_signing_key();
+    contact.apply_key_rotation(&proof).unwrap();
+
+    assert_eq!(contact.public_key(), alice.signing_public_key());
+    assert_eq!(contact.id(), alice.public_id());
+    assert_eq!(contact.shared_key().as_bytes(), shared_key.as_bytes());
+    assert!(contact.is_fingerprint_verified());
+}
+
+#[test]
+fn test_move_contact_carries_its_data() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let mut alice = Identity::create("Alice");
+    let mut contact = Contact::from_exchange(
+        *alice.signing_public_key(),
+        ContactCard::new("Alice"),
+        SymmetricKey::generate(),
+    );
+    let old_id = contact.id().to_string();
+    storage.save_contact(&contact).unwrap();
+
+    let ratchet = DoubleRatchetState::initialize_initiator(
+        contact.shared_key(),
+        *X3DHKeyPair::generate().public_key(),
+    );
+    storage.save_ratchet_state(&old_id, &ratchet, true).unwrap();
+    let label = storage.create_label("Friends").unwrap();
+    storage.add_contact_to_label(label.id(), &old_id).unwrap();
+    storage.add_contact_tag(&old_id, "climbing").unwrap();
+
+    contact
+        .apply_key_rotation(&alice.rotate_signing_key())
+        .unwrap();
+    storage.move_contact(&old_id, &contact).unwrap();
+
+    let new_id = contact.id();
+    assert!(storage.load_contact(&old_id).unwrap().is_none());
+    assert_eq!(
+        storage.load_contact(new_id).unwrap().unwrap().public_key(),
+        alice.signing_public_key()
+    );
+    assert!(storage.load_ratchet_state(new_id).unwrap().is_some());
+    assert!(storage.load_ratchet_state(&old_id).unwrap().is_none());
+    assert!(storage
+        .load_label(label.id())
+        .unwrap()
+        .contains_contact(new_id));
+    assert_eq!(storage.get_contact_tags(new_id).unwrap(), ["climbing"]);
+    assert_eq!(storage.list_contacts().unwrap().len(), 1);
+}
@@ -53,3 +53,103 @@ fn test_device_id_deterministic() {
     // Different identities have different device IDs
     assert_ne!(identity1.device_id(), identity2.device_id());
 }
+
+#[test]
+fn test_backup_from_newer_version_is_refused() {
+    use vauchi_core::identity::IdentityError;
+
+    let original = Identity::create("Alice");
+    let password = "correct-horse-battery-staple";
+    let mut backup = original.export_backup(password).unwrap();
+    let restored = Identity::import_backup(&backup, password).unwrap();
+    assert_eq!(restored.public_id(), original.public_id());
+
+    // Same layout, but tagged with a format this version does not know
+    backup.as_bytes_mut()[0] = 0x04;
+    assert!(matches!(
+        Identity::import_backup(&backup, password),
+        Err(IdentityError::UnsupportedBackupVersion(0x04))
+    ));
+
+    // Outside the reserved range the first byte is a legacy salt byte
+    backup.as_bytes_mut()[0] = 0xA0;
+    assert!(matches!(
+        Identity::import_backup(&backup, password),
+        Err(IdentityError::RestoreFailed)
+    ));
+}
+
+#[test]
+fn test_deniable_backup_each_password_loads_its_identity() {
+    let real = Identity::create("Alice");
+    let decoy = Identity::create("Alex");
+    let real_password = "correct-horse-battery-staple";
+    let decoy_password = "purple-monkey-dishwasher-42";
+
+    let backup = real
+        .export_deniable_backup(real_password, Some((&decoy, decoy_password)))
+        .unwrap();
+
+    let restored = Identity::import_backup(&backup, real_password).unwrap();
+    assert_eq!(restored.public_id(), real.public_id());
+    assert_eq!(restored.display_name(), "Alice");
+
+    let restored = Identity::import_backup(&backup, decoy_password).unwrap();
+    assert_eq!(restored.public_id(), decoy.public_id());
+    assert_eq!(restored.display_name(), "Alex");
+
+    assert!(Identity::import_backup(&backup, "wrong-password-entirely").is_err());
+}
+
+#[test]
+fn test_deniable_backup_rejects_reused_decoy_password() {
+    use vauchi_core::identity::IdentityError;
+
+    let real = Identity::create("Alice");
+    let decoy = Identity::create("Alex");
+    let password = "correct-horse-battery-staple";
+
+    assert!(matches!(
+        real.export_deniable_backup(password, Some((&decoy, password))),
+        Err(IdentityError::DecoyPasswordReused)
+    ));
+}
+
+#[test]
+fn test_deniable_backup_size_does_not_reveal_decoy() {
+    let real = Identity::create("Alice");
+    let decoy = Identity::create("A decoy with a much longer display name");
+    let password = "correct-horse-battery-staple";
+
+    let without_decoy = real.export_deniable_backup(password, None).unwrap();
+    let with_decoy = real
+        .export_deniable_backup(password, Some((&decoy, "purple-monkey-dishwasher-42")))
+        .unwrap();
+
+    assert_eq!(without_decoy.as_bytes().len(), with_decoy.as_bytes().len());
+    assert_eq!(without_decoy.as_bytes()[0], with_decoy.as_bytes()[0]);
+
+    let restored = Identity::import_backup(&without_decoy, password).unwrap();
+    assert_eq!(restored.public_id(), real.public_id());
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_seeded_identity_is_deterministic() {
+    let seed = [7u8; 32];
+    let first = Identity::from_seed("Alice", seed);
+    let second = Identity::from_seed("Alice", seed);
+
+    assert_eq!(first.public_id(), second.public_id());
+    assert_eq!(first.exchange_public_key(), second.exchange_public_key());
+    assert_ne!(
+        first.public_id(),
+        Identity::from_seed("Alice", [8u8; 32]).public_id()
+    );
+
+    // Pinned, so a change in key derivation cannot go unnoticed between runs
+    assert_eq!(
+        first.public_id(),
+        "ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c"
+    );
+}
@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::inbox
+
+use vauchi_core::{Storage, StorageError, SymmetricKey};
+
+fn test_storage() -> Storage {
+    Storage::in_memory(SymmetricKey::generate()).unwrap()
+}
+
+#[test]
+fn test_mark_message_processed_only_once() {
+    let storage = test_storage();
+
+    assert!(!storage.is_message_processed("msg-1").unwrap());
+    assert!(storage.mark_message_processed("msg-1").unwrap());
+    assert!(storage.is_message_processed("msg-1").unwrap());
+
+    // Redelivery of the same ID is reported as already seen
+    assert!(!storage.mark_message_processed("msg-1").unwrap());
+    assert!(!storage.is_message_processed("msg-2").unwrap());
+}
+
+#[test]
+fn test_prune_processed_messages() {
+    let storage = test_storage();
+    storage.mark_message_processed("msg-1").unwrap();
+
+    assert_eq!(storage.prune_processed_messages(0).unwrap(), 0);
+    assert!(storage.is_message_processed("msg-1").unwrap());
+
+    assert_eq!(storage.prune_processed_messages(u64::MAX / 2).unwrap(), 1);
+    assert!(!storage.is_message_processed("msg-1").unwrap());
+}
+
+#[test]
+fn test_sync_cursor_per_relay() {
+    let storage = test_storage();
+    assert_eq!(storage.load_sync_cursor("wss://a").unwrap(), None);
+
+    storage.save_sync_cursor("wss://a", "msg-1").unwrap();
+    storage.save_sync_cursor("wss://a", "msg-2").unwrap();
+    storage.save_sync_cursor("wss://b", "msg-9").unwrap();
+
+    assert_eq!(
+        storage.load_sync_cursor("wss://a").unwrap().as_deref(),
+        Some("msg-2")
+    );
+    assert_eq!(
+        storage.load_sync_cursor("wss://b").unwrap().as_deref(),
+        Some("msg-9")
+    );
+}
+
+#[test]
+fn test_atomically_rolls_back_on_error() {
+    let storage = test_storage();
+
+    let result: Result<(), StorageError> = storage.atomically(|| {
+        storage.mark_message_processed("msg-1")?;
+        Err(StorageError::InvalidData("handling failed".into()))
+    });
+    assert!(result.is_err());
+    assert!(!storage.is_message_processed("msg-1").unwrap());
+
+    // A failed inner call is undone without touching the outer one
+    storage
+        .atomically(|| {
+            storage.mark_message_processed("msg-2")?;
+            let _ = storage.atomically(|| {
+                storage.mark_message_processed("msg-3")?;
+                Err::<(), _>(StorageError::InvalidData("inner".into()))
+            });
+            Ok::<_, StorageError>(())
+        })
+        .unwrap();
+    assert!(storage.is_message_processed("msg-2").unwrap());
+    assert!(!storage.is_message_processed("msg-3").unwrap());
+}
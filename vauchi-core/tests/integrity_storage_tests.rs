@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::integrity
+
+use rusqlite::Connection;
+use tempfile::TempDir;
+use vauchi_core::contact::Contact;
+use vauchi_core::contact_card::ContactCard;
+use vauchi_core::crypto::ratchet::DoubleRatchetState;
+use vauchi_core::crypto::SymmetricKey;
+use vauchi_core::exchange::X3DHKeyPair;
+use vauchi_core::storage::Storage;
+
+fn create_contact(name: &str) -> Contact {
+    let mut public_key = [0u8; 32];
+    public_key[..name.len()].copy_from_slice(name.as_bytes());
+    Contact::from_exchange(public_key, ContactCard::new(name), SymmetricKey::generate())
+}
+
+#[test]
+fn test_verify_integrity_healthy_storage() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let contact = create_contact("Alice");
+    storage.save_contact(&contact).unwrap();
+
+    let their_dh = X3DHKeyPair::generate();
+    let ratchet =
+        DoubleRatchetState::initialize_initiator(&SymmetricKey::generate(), *their_dh.public_key());
+    storage
+        .save_ratchet_state(contact.id(), &ratchet, true)
+        .unwrap();
+
+    let report = storage.verify_integrity().unwrap();
+
+    assert!(report.is_ok());
+    assert!(report.sqlite_ok());
+    // Card, shared key and ratchet state
+    assert_eq!(report.items_checked, 3);
+}
+
+#[test]
+fn test_verify_integrity_reports_corrupted_card() {
+    let dir = TempDir::new().unwrap();
+    let db_path = dir.path().join("vauchi.db");
+    let key = SymmetricKey::generate();
+
+    let alice = create_contact("Alice");
+    let bob = create_contact("Bob");
+    {
+        let storage = Storage::open(&db_path, key.clone()).unwrap();
+        storage.save_contact(&alice).unwrap();
+        storage.save_contact(&bob).unwrap();
+    }
+
+    // Simulate a partial write of Bob's card
+    let conn = Connection::open(&db_path).unwrap();
+    conn.execute(
+        "UPDATE contacts SET card_encrypted = substr(card_encrypted, 1, 20) WHERE id = ?1",
+        [bob.id()],
+    )
+    .unwrap();
+    drop(conn);
+
+    let storage = Storage::open(&db_path, key).unwrap();
+    let report = storage.verify_integrity().unwrap();
+
+    assert!(report.sqlite_ok());
+    assert!(!report.is_ok());
+    assert_eq!(report.items_checked, 4);
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].table, "contacts");
+    assert_eq!(report.failures[0].row_id, bob.id());
+    assert_eq!(report.failures[0].item, "card");
+}
@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for social::issuer
+
+use std::collections::{HashMap, HashSet};
+use vauchi_core::social::*;
+use vauchi_core::*;
+
+const WORK_EMAIL: &str = "alice@acme.example";
+
+fn status_with(
+    validations: &[ProfileValidation],
+    attestations: &[IssuerAttestation],
+    alice: &Identity,
+) -> ValidationStatus {
+    let contact_id = hex::encode(alice.signing_public_key());
+    let thresholds = TrustThresholds {
+        min_validations: 3,
+        in_person_counts_double: false,
+    };
+    ValidationStatus::from_validations(
+        validations,
+        WORK_EMAIL,
+        None,
+        &HashSet::new(),
+        &HashSet::new(),
+        &thresholds,
+    )
+    .with_issuer_attestations(attestations, &contact_id, "work", &thresholds)
+}
+
+#[test]
+fn test_valid_issuer_attestation_raises_trust() {
+    let alice = Identity::create("Alice");
+    let acme = Identity::create("ACME");
+    let contact_id = hex::encode(alice.signing_public_key());
+    let bob_validation =
+        ProfileValidation::create_signed(&Identity::create("Bob"), "work", WORK_EMAIL, &contact_id);
+
+    let without = status_with(std::slice::from_ref(&bob_validation), &[], &alice);
+    assert!(!without.trusted);
+    assert_eq!(without.trust_level, TrustLevel::LowConfidence);
+
+    let attestation = IssuerAttestation::create(
+        &acme,
+        "ACME Corp",
+        alice.signing_public_key(),
+        "work",
+        WORK_EMAIL,
+    );
+    let attestation = IssuerAttestation::from_bytes(&attestation.to_bytes()).unwrap();
+    assert!(attestation.verify());
+    assert_eq!(attestation.subject_id(), contact_id);
+
+    let with = status_with(
+        &[bob_validation],
+        &[attestation.clone(), attestation],
+        &alice,
+    );
+    assert!(with.trusted);
+    assert_eq!(with.issuers, vec!["ACME Corp".to_string()]);
+    assert_eq!(with.weight, 1 + ISSUER_ATTESTATION_WEIGHT);
+    assert_eq!(with.trust_level, TrustLevel::PartialConfidence);
+    assert_eq!(
+        with.display(&HashMap::new()),
+        "Attested by ACME Corp; Verified by 1 person"
+    );
+}
+
+#[test]
+fn test_bad_issuer_attestations_are_ignored() {
+    let alice = Identity::create("Alice");
+    let acme = Identity::create("ACME");
+
+    // Tampered after signing
+    let mut forged = IssuerAttestation::create(
+        &acme,
+        "ACME Corp",
+        alice.signing_public_key(),
+        "work",
+        WORK_EMAIL,
+    );
+    forged.set_issuer_name_for_testing("Government");
+    assert!(!forged.verify());
+
+    // Attests a value the field no longer has
+    let stale = IssuerAttestation::create(
+        &acme,
+        "ACME Corp",
+        alice.signing_public_key(),
+        "work",
+        "alice@old.example",
+    );
+
+    // Issued to someone else
+    let other = IssuerAttestation::create(
+        &acme,
+        "ACME Corp",
+        Identity::create("Mallory").signing_public_key(),
+        "work",
+        WORK_EMAIL,
+    );
+
+    // Self-issued
+    let own = IssuerAttestation::create(
+        &alice,
+        "Alice Inc",
+        alice.signing_public_key(),
+        "work",
+        WORK_EMAIL,
+    );
+    assert!(!own.verify());
+
+    let status = status_with(&[], &[forged, stale, other, own], &alice);
+    assert!(status.issuers.is_empty());
+    assert!(!status.trusted);
+    assert_eq!(status.trust_level, TrustLevel::Unverified);
+    assert_eq!(status.display(&HashMap::new()), "Not verified");
+}
+
+#[test]
+fn test_storage_issuer_attestations() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = Identity::create("Alice");
+    let acme = Identity::create("ACME");
+    let contact_id = hex::encode(alice.signing_public_key());
+
+    let attestation = IssuerAttestation::create(
+        &acme,
+        "ACME Corp",
+        alice.signing_public_key(),
+        "work",
+        WORK_EMAIL,
+    );
+    storage.save_issuer_attestation(&attestation).unwrap();
+    // Re-importing replaces rather than duplicates
+    storage.save_issuer_attestation(&attestation).unwrap();
+
+    let mut forged = attestation.clone();
+    forged.set_issuer_name_for_testing("Government");
+    assert!(matches!(
+        storage.save_issuer_attestation(&forged),
+        Err(StorageError::InvalidData(_))
+    ));
+
+    let loaded = storage
+        .load_issuer_attestations_for_field(&contact_id, "work")
+        .unwrap();
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded[0].issuer_name(), "ACME Corp");
+    assert!(loaded[0].verify());
+    assert!(storage
+        .load_issuer_attestations_for_field(&contact_id, "home")
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::key_rotation
+
+use tempfile::TempDir;
+use vauchi_core::contact::Contact;
+use vauchi_core::contact_card::{ContactCard, ContactField, FieldType};
+use vauchi_core::crypto::ratchet::DoubleRatchetState;
+use vauchi_core::crypto::{decrypt, encrypt, SymmetricKey};
+use vauchi_core::exchange::X3DHKeyPair;
+use vauchi_core::storage::{Storage, StorageError};
+
+fn alice() -> Contact {
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "Work",
+        "alice@work.example",
+    ))
+    .unwrap();
+    Contact::from_exchange([7u8; 32], card, SymmetricKey::generate())
+}
+
+#[test]
+fn test_rotate_encryption_key_reencrypts_all_data() {
+    let dir = TempDir::new().unwrap();
+    let db_path = dir.path().join("vauchi.db");
+    let old_key = SymmetricKey::generate();
+    let new_key = SymmetricKey::generate();
+    let alice = alice();
+
+    {
+        let mut storage = Storage::open(&db_path, old_key.clone()).unwrap();
+        storage.save_identity(b"identity backup", "Me").unwrap();
+        storage.save_contact(&alice).unwrap();
+        let ratchet = DoubleRatchetState::initialize_initiator(
+            alice.shared_key(),
+            *X3DHKeyPair::generate().public_key(),
+        );
+        storage
+            .save_ratchet_state(alice.id(), &ratchet, true)
+            .unwrap();
+        storage
+            .save_personal_notes(alice.id(), &encrypt(&old_key, b"Met in Bern").unwrap())
+            .unwrap();
+
+        storage.rotate_encryption_key(&old_key, &new_key).unwrap();
+
+        // The connection keeps working under the new key
+        assert!(storage.load_contact(alice.id()).unwrap().is_some());
+    }
+
+    let storage = Storage::open(&db_path, new_key.clone()).unwrap();
+    assert!(storage.verify_integrity().unwrap().is_ok());
+    let loaded = storage.load_contact(alice.id()).unwrap().unwrap();
+    assert_eq!(loaded.card().content_hash(), alice.card().content_hash());
+    assert_eq!(
+        loaded.shared_key().as_bytes(),
+        alice.shared_key().as_bytes()
+    );
+    assert!(storage.load_ratchet_state(alice.id()).unwrap().is_some());
+    assert_eq!(
+        storage.load_identity().unwrap().unwrap().0,
+        b"identity backup"
+    );
+    let note = storage.load_personal_notes(alice.id()).unwrap().unwrap();
+    assert_eq!(decrypt(&new_key, &note).unwrap(), b"Met in Bern");
+    drop(storage);
+
+    let storage = Storage::open(&db_path, old_key).unwrap();
+    assert!(storage.load_contact(alice.id()).is_err());
+    assert!(storage.load_identity().is_err());
+    assert!(!storage.verify_integrity().unwrap().is_ok());
+}
+
+#[test]
+fn test_rotate_encryption_key_rejects_wrong_old_key() {
+    let mut storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    let alice = alice();
+    storage.save_contact(&alice).unwrap();
+
+    let result =
+        storage.rotate_encryption_key(&SymmetricKey::generate(), &SymmetricKey::generate());
+    assert!(matches!(result, Err(StorageError::Encryption(_))));
+
+    // Nothing changed
+    assert!(storage.verify_integrity().unwrap().is_ok());
+    assert!(storage.load_contact(alice.id()).unwrap().is_some());
+}
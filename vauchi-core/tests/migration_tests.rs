@@ -523,3 +523,72 @@ fn test_default_column_values() {
     assert_eq!(loaded[0].retry_count, 0);
     assert!(matches!(loaded[0].status, UpdateStatus::Pending));
 }
+
+// =============================================================================
+// UPGRADE TESTS
+// =============================================================================
+
+#[test]
+fn test_opening_old_version_db_runs_pending_migrations() {
+    use vauchi_core::storage::migration::{all_migrations, MigrationRunner};
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let db_path = dir.path().join("vauchi.db");
+    let key = SymmetricKey::generate();
+
+    let migrations = all_migrations();
+    let latest = migrations.last().unwrap().version;
+    let old: Vec<_> = migrations
+        .into_iter()
+        .filter(|m| m.version < latest)
+        .collect();
+
+    // Build a database as an older release would have left it
+    {
+        let conn = Connection::open(&db_path).unwrap();
+        MigrationRunner::run(&conn, &key, &old).unwrap();
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), latest - 1);
+
+        conn.execute(
+            "INSERT INTO visibility_labels
+             (id, name, contacts_json, visible_fields_json, created_at, modified_at)
+             VALUES ('label-1', 'Family', '[\"contact-1\"]', '[\"phone\"]', 1700000000, 1700000000)",
+            [],
+        )
+        .unwrap();
+    }
+
+    let storage = Storage::open(&db_path, key).unwrap();
+    assert_eq!(storage.schema_version().unwrap(), latest);
+
+    let label = storage.load_label("label-1").unwrap();
+    assert_eq!(label.name(), "Family");
+    assert!(label.contains_contact("contact-1"));
+    assert!(label.is_field_visible("phone"));
+}
+
+#[test]
+fn test_upgrade_keeps_stored_contact_limit() {
+    use vauchi_core::storage::migration::{all_migrations, MigrationRunner};
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let db_path = dir.path().join("vauchi.db");
+    let key = SymmetricKey::generate();
+
+    let old: Vec<_> = all_migrations()
+        .into_iter()
+        .filter(|m| m.version < 18)
+        .collect();
+    {
+        let conn = Connection::open(&db_path).unwrap();
+        MigrationRunner::run(&conn, &key, &old).unwrap();
+        conn.execute(
+            "UPDATE contact_limits SET max_contacts = 500 WHERE id = 1",
+            [],
+        )
+        .unwrap();
+    }
+
+    let storage = Storage::open(&db_path, key).unwrap();
+    assert_eq!(storage.get_contact_limit().unwrap(), 500);
+}
@@ -13,7 +13,7 @@
 //! - Health tracking
 //! - Failover behavior
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use vauchi_core::network::{MultiRelayConfig, RelayHealth, RelaySelector};
 
 // ============================================================
@@ -189,6 +189,29 @@ fn test_exponential_backoff_on_failures() {
     assert!(cooldown > Duration::from_secs(0));
 }
 
+/// Test: Cooldown is measured from the recorded failure time
+#[test]
+fn test_cooldown_remaining_at_given_time() {
+    let mut health = RelayHealth::with_cooldown(Duration::from_secs(5));
+    let start = Instant::now();
+
+    health.record_failure_at("wss://relay.vauchi.app", start);
+    assert_eq!(
+        health.cooldown_remaining_at("wss://relay.vauchi.app", start + Duration::from_secs(2)),
+        Duration::from_secs(3)
+    );
+
+    health.record_failure_at("wss://relay.vauchi.app", start + Duration::from_secs(5));
+    assert_eq!(
+        health.cooldown_remaining_at("wss://relay.vauchi.app", start + Duration::from_secs(5)),
+        Duration::from_secs(10)
+    );
+    assert_eq!(
+        health.cooldown_remaining_at("wss://relay.vauchi.app", start + Duration::from_secs(20)),
+        Duration::ZERO
+    );
+}
+
 /// Test: Success resets failure count
 #[test]
 fn test_success_resets_failures() {
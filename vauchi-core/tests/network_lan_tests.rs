@@ -0,0 +1,210 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for network::lan
+//!
+//! Direct peer sync without a relay.
+
+use std::io::Write;
+use std::net::TcpListener;
+use std::thread;
+use std::time::Duration;
+
+use vauchi_core::crypto::ratchet::{DoubleRatchetState, RatchetMessage};
+use vauchi_core::exchange::X3DHKeyPair;
+use vauchi_core::network::*;
+use vauchi_core::SymmetricKey;
+
+fn update_envelope(message_id: &str, recipient_id: &str) -> MessageEnvelope {
+    MessageEnvelope {
+        version: PROTOCOL_VERSION,
+        message_id: message_id.to_string(),
+        timestamp: 12345,
+        payload: MessagePayload::EncryptedUpdate(EncryptedUpdate {
+            recipient_id: recipient_id.to_string(),
+            sender_id: "alice".to_string(),
+            ratchet_header: RatchetHeader {
+                dh_public: [0u8; 32],
+                dh_generation: 0,
+                message_index: 0,
+                previous_chain_length: 0,
+            },
+            ciphertext: vec![1, 2, 3],
+        }),
+    }
+}
+
+fn ack_statuses(transport: &MockTransport) -> Vec<(String, AckStatus)> {
+    transport
+        .sent_messages()
+        .iter()
+        .filter_map(|envelope| match &envelope.payload {
+            MessagePayload::Acknowledgment(ack) => Some((ack.message_id.clone(), ack.status)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_receive_direct_updates_acknowledges_each_update() {
+    let mut transport = MockTransport::new();
+    transport.connect(&TransportConfig::default()).unwrap();
+    transport.queue_receive(update_envelope("msg-1", "bob"));
+    transport.queue_receive(update_envelope("msg-2", "carol"));
+
+    let updates = receive_direct_updates(&mut transport, "bob").unwrap();
+
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].ciphertext, vec![1, 2, 3]);
+    assert_eq!(
+        ack_statuses(&transport),
+        vec![
+            ("msg-1".to_string(), AckStatus::ReceivedByRecipient),
+            ("msg-2".to_string(), AckStatus::Failed),
+        ]
+    );
+}
+
+#[test]
+fn test_direct_peer_sync_with_mock_transports() {
+    // Alice's relay client talks straight to Bob, who acknowledges
+    let mut bob_side = MockTransport::new();
+    bob_side.connect(&TransportConfig::default()).unwrap();
+
+    let mut alice = RelayClient::new(
+        MockTransport::new(),
+        RelayClientConfig::default(),
+        "alice".into(),
+    );
+    alice.connect().unwrap();
+
+    let shared = SymmetricKey::generate();
+    let bob_dh = X3DHKeyPair::generate();
+    let mut alice_ratchet = DoubleRatchetState::initialize_initiator(&shared, *bob_dh.public_key());
+    alice
+        .send_update("bob", &mut alice_ratchet, b"delta", "update-1")
+        .unwrap();
+
+    // Carry Alice's frames over to Bob
+    for envelope in alice.connection().transport().sent_messages() {
+        bob_side.queue_receive(envelope.clone());
+    }
+    let updates = receive_direct_updates(&mut bob_side, "bob").unwrap();
+    assert_eq!(updates.len(), 1);
+
+    // Carry Bob's acknowledgments back to Alice
+    for envelope in bob_side.sent_messages() {
+        alice
+            .connection_mut()
+            .transport_mut()
+            .queue_receive(envelope.clone());
+    }
+    assert_eq!(alice.process_incoming().unwrap(), vec!["update-1"]);
+    assert!(!alice.has_in_flight());
+}
+
+#[test]
+fn test_direct_peer_sync_over_tcp() {
+    // Bob waits longer than Alice so Alice reads the ack and hangs up first
+    let io_timeout = Duration::from_millis(2000);
+    let listener = LanListener::bind("127.0.0.1:0", io_timeout).unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let shared = SymmetricKey::generate();
+    let bob_dh = X3DHKeyPair::generate();
+    let bob_public = *bob_dh.public_key();
+    let mut bob_ratchet = DoubleRatchetState::initialize_responder(&shared, bob_dh);
+
+    let bob = thread::spawn(move || {
+        let mut transport = listener.accept().unwrap();
+        let updates = receive_direct_updates(&mut transport, "bob").unwrap();
+        updates
+            .into_iter()
+            .map(|update| {
+                let message = RatchetMessage {
+                    dh_public: update.ratchet_header.dh_public,
+                    dh_generation: update.ratchet_header.dh_generation,
+                    message_index: update.ratchet_header.message_index,
+                    previous_chain_length: update.ratchet_header.previous_chain_length,
+                    ciphertext: update.ciphertext,
+                };
+                bob_ratchet.decrypt(&message).unwrap()
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let config = RelayClientConfig {
+        transport: TransportConfig {
+            server_url: format!("tcp://{}", addr),
+            io_timeout_ms: 300,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut alice = RelayClient::new(LanTransport::new(), config, "alice".into());
+    alice.connect().unwrap();
+
+    let mut alice_ratchet = DoubleRatchetState::initialize_initiator(&shared, bob_public);
+    alice
+        .send_update("bob", &mut alice_ratchet, b"new phone number", "update-1")
+        .unwrap();
+
+    assert_eq!(alice.process_incoming().unwrap(), vec!["update-1"]);
+    alice.disconnect().unwrap();
+
+    let received = bob.join().unwrap();
+    assert_eq!(received, vec![b"new phone number".to_vec()]);
+}
+
+#[test]
+fn test_lan_transport_connect_failure() {
+    // Bind then drop to get a port nobody listens on
+    let addr = LanListener::bind("127.0.0.1:0", Duration::from_millis(100))
+        .unwrap()
+        .local_addr()
+        .unwrap();
+
+    let mut transport = LanTransport::new();
+    let config = TransportConfig {
+        server_url: format!("tcp://{}", addr),
+        connect_timeout_ms: 500,
+        ..Default::default()
+    };
+    assert!(matches!(
+        transport.connect(&config),
+        Err(NetworkError::ConnectionFailed(_))
+    ));
+    assert_eq!(transport.state(), ConnectionState::Disconnected);
+}
+
+#[test]
+fn test_lan_transport_resets_on_partial_frame() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let peer = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        // Half a frame header, then nothing until the reader gives up
+        stream.write_all(&[0, 0]).unwrap();
+        thread::sleep(Duration::from_millis(500));
+    });
+
+    let mut transport = LanTransport::new();
+    let config = TransportConfig {
+        server_url: format!("tcp://{}", addr),
+        io_timeout_ms: 200,
+        ..Default::default()
+    };
+    transport.connect(&config).unwrap();
+
+    assert!(matches!(
+        transport.receive(),
+        Err(NetworkError::ReceiveFailed(_))
+    ));
+    assert_eq!(transport.state(), ConnectionState::Disconnected);
+    assert!(matches!(
+        transport.receive(),
+        Err(NetworkError::NotConnected)
+    ));
+    peer.join().unwrap();
+}
@@ -5,6 +5,7 @@
 //! Tests for network::simple_message
 //! Extracted from simple_message.rs
 
+use vauchi_core::crypto::SigningKeyPair;
 use vauchi_core::network::simple_message::*;
 
 #[test]
@@ -12,6 +13,7 @@ fn test_encode_decode_roundtrip() {
     let handshake = SimpleHandshake {
         client_id: "test-client".to_string(),
         device_id: None,
+        since: None,
     };
     let envelope = create_simple_envelope(SimplePayload::Handshake(handshake));
 
@@ -78,3 +80,110 @@ fn test_encrypted_update() {
         _ => panic!("Wrong payload type"),
     }
 }
+
+#[test]
+fn test_recovery_proof_store_and_query() {
+    let store = create_recovery_proof_store("abcd", vec![1, 2, 3], 1_700_000_000);
+    let decoded = decode_simple_message(&encode_simple_message(&store).unwrap()).unwrap();
+    match decoded.payload {
+        SimplePayload::RecoveryProofStore(s) => {
+            assert_eq!(s.old_pk, "abcd");
+            assert_eq!(s.proof, vec![1, 2, 3]);
+            assert_eq!(s.expires_at, 1_700_000_000);
+        }
+        _ => panic!("Wrong payload type"),
+    }
+
+    let query = create_recovery_proof_query("abcd");
+    let decoded = decode_simple_message(&encode_simple_message(&query).unwrap()).unwrap();
+    match decoded.payload {
+        SimplePayload::RecoveryProofQuery(q) => assert_eq!(q.old_pk, "abcd"),
+        _ => panic!("Wrong payload type"),
+    }
+
+    let response = create_simple_envelope(SimplePayload::RecoveryProofResponse(
+        SimpleRecoveryProofResponse {
+            old_pk: "abcd".to_string(),
+            proofs: vec![vec![1, 2, 3]],
+        },
+    ));
+    let decoded = decode_simple_message(&encode_simple_message(&response).unwrap()).unwrap();
+    match decoded.payload {
+        SimplePayload::RecoveryProofResponse(r) => {
+            assert_eq!(r.old_pk, "abcd");
+            assert_eq!(r.proofs, vec![vec![1, 2, 3]]);
+        }
+        _ => panic!("Wrong payload type"),
+    }
+}
+
+#[test]
+fn test_delivery_receipt_verifies_against_relay_key() {
+    let relay_key = SigningKeyPair::generate();
+    let envelope = create_delivery_receipt(&relay_key, "msg-1", "bob", 1_700_000_000);
+
+    let decoded = decode_simple_message(&encode_simple_message(&envelope).unwrap()).unwrap();
+    let receipt = match decoded.payload {
+        SimplePayload::DeliveryReceipt(r) => r,
+        _ => panic!("Wrong payload type"),
+    };
+    assert_eq!(receipt.message_id, "msg-1");
+    assert_eq!(receipt.recipient_id, "bob");
+    assert_eq!(receipt.server_timestamp, 1_700_000_000);
+    assert!(receipt.verify(&relay_key.public_key()));
+
+    // Another relay's key does not verify
+    assert!(!receipt.verify(&SigningKeyPair::generate().public_key()));
+}
+
+#[test]
+fn test_delivery_receipt_rejects_tampering() {
+    let relay_key = SigningKeyPair::generate();
+    let receipt = SimpleDeliveryReceipt::sign(&relay_key, "msg-1", "bob", 1_700_000_000);
+
+    let mut later = receipt.clone();
+    later.server_timestamp += 1;
+    assert!(!later.verify(&relay_key.public_key()));
+
+    let mut other_recipient = receipt.clone();
+    other_recipient.recipient_id = "carol".to_string();
+    assert!(!other_recipient.verify(&relay_key.public_key()));
+
+    let mut truncated = receipt;
+    truncated.signature.pop();
+    assert!(!truncated.verify(&relay_key.public_key()));
+}
+
+#[test]
+fn test_delivery_status_query_roundtrip() {
+    let query = create_delivery_status_query(vec!["msg-1".to_string(), "msg-2".to_string()]);
+    let decoded = decode_simple_message(&encode_simple_message(&query).unwrap()).unwrap();
+    match decoded.payload {
+        SimplePayload::DeliveryStatusQuery(q) => assert_eq!(q.message_ids, ["msg-1", "msg-2"]),
+        _ => panic!("Wrong payload type"),
+    }
+
+    let response = create_simple_envelope(SimplePayload::DeliveryStatusResponse(
+        SimpleDeliveryStatusResponse {
+            statuses: vec![
+                SimpleMessageStatus {
+                    message_id: "msg-1".to_string(),
+                    state: SimpleMessageState::Delivered,
+                },
+                SimpleMessageStatus {
+                    message_id: "msg-2".to_string(),
+                    state: SimpleMessageState::Unknown,
+                },
+            ],
+        },
+    ));
+    let decoded = decode_simple_message(&encode_simple_message(&response).unwrap()).unwrap();
+    match decoded.payload {
+        SimplePayload::DeliveryStatusResponse(r) => {
+            assert_eq!(r.statuses.len(), 2);
+            assert_eq!(r.statuses[0].state, SimpleMessageState::Delivered);
+            assert_eq!(r.statuses[1].state, SimpleMessageState::Unknown);
+        }
+        _ => panic!("Wrong payload type"),
+    }
+}
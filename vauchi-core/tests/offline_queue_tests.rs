@@ -7,6 +7,8 @@
 //! Traces to: features/message_delivery.feature
 //! P14 Phase 5: Offline Queue
 
+use vauchi_core::contact::Contact;
+use vauchi_core::contact_card::ContactCard;
 use vauchi_core::crypto::SymmetricKey;
 use vauchi_core::storage::{OfflineQueue, PendingUpdate, Storage, UpdateStatus};
 
@@ -53,6 +55,28 @@ fn test_count_all_pending_updates() {
     assert_eq!(storage.count_all_pending_updates().unwrap(), 5);
 }
 
+#[test]
+fn test_count_deliverable_pending_updates() {
+    let storage = test_storage();
+    let alice =
+        Contact::from_exchange([1; 32], ContactCard::new("Alice"), SymmetricKey::generate());
+    let mut bob =
+        Contact::from_exchange([2; 32], ContactCard::new("Bob"), SymmetricKey::generate());
+    bob.block();
+    storage.save_contact(&alice).unwrap();
+    storage.save_contact(&bob).unwrap();
+
+    for (id, contact_id) in [("a", alice.id()), ("b", bob.id()), ("c", "unknown")] {
+        storage
+            .queue_update(&create_pending_update(id, contact_id))
+            .unwrap();
+    }
+
+    // Only the update for Alice would be sent
+    assert_eq!(storage.count_all_pending_updates().unwrap(), 3);
+    assert_eq!(storage.count_deliverable_pending_updates().unwrap(), 1);
+}
+
 #[test]
 fn test_offline_queue_default_limit() {
     let queue = OfflineQueue::new();
@@ -189,6 +213,49 @@ fn test_flush_pending_updates_for_contact() {
     assert_eq!(storage.get_pending_updates("bob").unwrap().len(), 2);
 }
 
+#[test]
+fn test_cancel_pending_update_removes_only_that_update() {
+    use vauchi_core::storage::{DeliveryRecord, DeliveryStatus, RetryEntry};
+
+    let storage = test_storage();
+    storage
+        .queue_update(&create_pending_update("keep", "alice"))
+        .unwrap();
+    storage
+        .queue_update(&create_pending_update("cancel", "alice"))
+        .unwrap();
+    storage
+        .create_delivery_record(&DeliveryRecord {
+            message_id: "cancel".to_string(),
+            recipient_id: "alice".to_string(),
+            status: DeliveryStatus::Queued,
+            created_at: now(),
+            updated_at: now(),
+            expires_at: None,
+        })
+        .unwrap();
+    storage
+        .create_retry_entry(&RetryEntry {
+            message_id: "cancel".to_string(),
+            recipient_id: "alice".to_string(),
+            payload: vec![1, 2, 3],
+            attempt: 1,
+            next_retry: now(),
+            created_at: now(),
+            max_attempts: 10,
+        })
+        .unwrap();
+
+    assert!(storage.cancel_pending_update("cancel").unwrap());
+    assert!(!storage.cancel_pending_update("cancel").unwrap());
+
+    let remaining = storage.get_pending_updates("alice").unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, "keep");
+    assert!(storage.get_delivery_record("cancel").unwrap().is_none());
+    assert!(storage.get_retry_entry("cancel").unwrap().is_none());
+}
+
 #[test]
 fn test_clear_all_pending_updates() {
     let storage = test_storage();
@@ -254,3 +321,43 @@ fn test_get_pending_by_status() {
     let failed = storage.get_pending_updates_by_status("failed").unwrap();
     assert_eq!(failed.len(), 1);
 }
+
+// === Stale Update Tests ===
+
+#[test]
+fn test_get_stale_updates_distinguishes_never_sent_from_undelivered() {
+    use vauchi_core::storage::{DeliveryRecord, DeliveryStatus, StaleUpdateState};
+
+    let storage = test_storage();
+    let three_weeks_ago = now() - 21 * 86400;
+
+    let mut old = create_pending_update("old", "bob");
+    old.created_at = three_weeks_ago;
+    storage.queue_update(&old).unwrap();
+    storage
+        .queue_update(&create_pending_update("fresh", "bob"))
+        .unwrap();
+
+    storage
+        .create_delivery_record(&DeliveryRecord {
+            message_id: "relayed".to_string(),
+            recipient_id: "bob".to_string(),
+            status: DeliveryStatus::Stored,
+            created_at: three_weeks_ago,
+            updated_at: three_weeks_ago,
+            expires_at: None,
+        })
+        .unwrap();
+
+    let stale = storage.get_stale_updates(14).unwrap();
+    assert_eq!(stale.len(), 2);
+
+    let old = stale.iter().find(|u| u.id == "old").unwrap();
+    assert_eq!(old.contact_id, "bob");
+    assert_eq!(old.state, StaleUpdateState::NeverSent);
+
+    let relayed = stale.iter().find(|u| u.id == "relayed").unwrap();
+    assert_eq!(relayed.state, StaleUpdateState::NotDelivered);
+
+    assert!(!stale.iter().any(|u| u.id == "fresh"));
+}
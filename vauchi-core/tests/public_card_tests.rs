@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for contact_card::public
+
+use vauchi_core::contact_card::{is_valid_public_handle, ContactCardError, MAX_HANDLE_LENGTH};
+use vauchi_core::storage::PublicCardPublication;
+use vauchi_core::*;
+
+fn business_card() -> ContactCard {
+    let mut card = ContactCard::new("Alice");
+    card.add_field(ContactField::new(
+        FieldType::Email,
+        "work",
+        "alice@acme.example",
+    ))
+    .unwrap();
+    card
+}
+
+#[test]
+fn test_public_card_roundtrip_and_verify() {
+    let alice = Identity::create("Alice");
+    let card = business_card();
+
+    let published = PublicCard::create(&alice, "alice-acme", &card, 1_700_000_000).unwrap();
+    let served = PublicCard::from_json(&published.to_json()).unwrap();
+
+    assert!(served.verify());
+    assert_eq!(served.handle(), "alice-acme");
+    assert_eq!(served.owner_id(), hex::encode(alice.signing_public_key()));
+    assert_eq!(served.published_at(), 1_700_000_000);
+    assert_eq!(served.card().unwrap().content_hash(), card.content_hash());
+}
+
+#[test]
+fn test_public_card_rejects_tampering() {
+    let alice = Identity::create("Alice");
+    let published = PublicCard::create(&alice, "alice-acme", &business_card(), 1).unwrap();
+
+    // Card altered by the relay
+    let mut forged = published.clone();
+    let mut card = forged.card().unwrap();
+    let field_id = card.fields()[0].id().to_string();
+    card.update_field_value(&field_id, "mallory@evil.example")
+        .unwrap();
+    forged.set_card_json_for_testing(&serde_json::to_string(&card).unwrap());
+    assert!(!forged.verify());
+
+    // Re-signed under another handle or by another key
+    let mut json: serde_json::Value = serde_json::from_slice(&published.to_json()).unwrap();
+    json["handle"] = "alice-inc".into();
+    let moved = PublicCard::from_json(json.to_string().as_bytes()).unwrap();
+    assert!(!moved.verify());
+
+    let mallory = Identity::create("Mallory");
+    let mut json: serde_json::Value = serde_json::from_slice(&published.to_json()).unwrap();
+    json["owner_id"] = hex::encode(mallory.signing_public_key()).into();
+    let claimed = PublicCard::from_json(json.to_string().as_bytes()).unwrap();
+    assert!(!claimed.verify());
+}
+
+#[test]
+fn test_public_card_handles() {
+    for handle in [
+        "abc",
+        "alice-acme",
+        "a1-b2-c3",
+        &"x".repeat(MAX_HANDLE_LENGTH),
+    ] {
+        assert!(is_valid_public_handle(handle), "{handle}");
+    }
+    for handle in [
+        "ab",
+        "Alice",
+        "-alice",
+        "alice-",
+        "al ice",
+        "аlice", // Cyrillic a
+        &"x".repeat(MAX_HANDLE_LENGTH + 1),
+    ] {
+        assert!(!is_valid_public_handle(handle), "{handle}");
+    }
+
+    let alice = Identity::create("Alice");
+    assert!(matches!(
+        PublicCard::create(&alice, "Alice", &business_card(), 1),
+        Err(ContactCardError::InvalidHandle(_))
+    ));
+}
+
+#[test]
+fn test_public_card_removal() {
+    let alice = Identity::create("Alice");
+    let removal = PublicCardRemoval::create(&alice, "alice-acme", 2);
+    let received = PublicCardRemoval::from_json(&removal.to_json()).unwrap();
+
+    assert!(received.verify());
+    assert_eq!(received.handle(), "alice-acme");
+    assert_eq!(received.owner_id(), hex::encode(alice.signing_public_key()));
+    assert_eq!(received.removed_at(), 2);
+
+    let mut json: serde_json::Value = serde_json::from_slice(&removal.to_json()).unwrap();
+    json["handle"] = "bob".into();
+    assert!(!PublicCardRemoval::from_json(json.to_string().as_bytes())
+        .unwrap()
+        .verify());
+}
+
+#[test]
+fn test_storage_public_card_publication() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    assert!(storage.load_public_card().unwrap().is_none());
+
+    let publication = PublicCardPublication {
+        handle: "alice-acme".to_string(),
+        label_id: "label-1".to_string(),
+        card_hash: business_card().content_hash(),
+        published_at: 10,
+    };
+    storage.save_public_card(&publication).unwrap();
+    assert_eq!(
+        storage.load_public_card().unwrap(),
+        Some(publication.clone())
+    );
+
+    let republished = PublicCardPublication {
+        published_at: 11,
+        ..publication
+    };
+    storage.save_public_card(&republished).unwrap();
+    assert_eq!(storage.load_public_card().unwrap(), Some(republished));
+
+    assert!(storage.clear_public_card().unwrap());
+    assert!(!storage.clear_public_card().unwrap());
+    assert!(storage.load_public_card().unwrap().is_none());
+}
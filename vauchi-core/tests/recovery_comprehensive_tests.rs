@@ -120,6 +120,27 @@ fn test_recovery_claim_expiration() {
     assert!(!recent_claim.is_expired());
 }
 
+/// Scenario: Claim and proof expiry boundaries with clock skew tolerance
+#[test]
+fn test_recovery_expiry_boundary_with_skew_tolerance() {
+    let old_pk = [0x01u8; 32];
+    let new_pk = [0x02u8; 32];
+    let claim = RecoveryClaim::new_with_timestamp(&old_pk, &new_pk, 1_000_000);
+    let deadline = 1_000_000 + RecoveryClaim::MAX_AGE_SECS;
+
+    assert!(!claim.is_expired_at(deadline, 0));
+    assert!(claim.is_expired_at(deadline + 1, 0));
+    assert!(!claim.is_expired_at(deadline + 120, 120));
+    assert!(claim.is_expired_at(deadline + 121, 120));
+
+    let proof = RecoveryProof::new(&old_pk, &new_pk, 3);
+    let expires_at = proof.expires_at();
+    assert!(!proof.is_expired_at(expires_at, 0));
+    assert!(proof.is_expired_at(expires_at + 1, 0));
+    assert!(!proof.is_expired_at(expires_at + 120, 120));
+    assert!(proof.is_expired_at(expires_at + 121, 120));
+}
+
 /// Scenario: Invalid claim format
 #[test]
 fn test_invalid_claim_format() {
@@ -379,6 +400,86 @@ fn test_proof_rejects_self_vouching() {
     assert!(matches!(result, Err(RecoveryError::SelfVouching)));
 }
 
+/// Scenario: An old voucher replayed into a new proof is rejected
+#[test]
+fn test_proof_rejects_stale_voucher() {
+    let old_pk = [0x01u8; 32];
+    let new_pk = [0x02u8; 32];
+    let voucher_keypair = SigningKeyPair::generate();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut proof = RecoveryProof::new(&old_pk, &new_pk, 3);
+
+    // Signed three days before the proof was created
+    let stale = vauchi_core::RecoveryVoucher::create_with_timestamp(
+        &old_pk,
+        &new_pk,
+        &voucher_keypair,
+        now - 72 * 3600,
+    );
+    assert!(stale.verify());
+    let result = proof.add_voucher(stale);
+    assert!(matches!(result, Err(RecoveryError::StaleVoucher)));
+    assert_eq!(proof.voucher_count(), 0);
+
+    // Within the window is fine
+    let fresh = vauchi_core::RecoveryVoucher::create_with_timestamp(
+        &old_pk,
+        &new_pk,
+        &voucher_keypair,
+        now - 24 * 3600,
+    );
+    proof.add_voucher(fresh).unwrap();
+}
+
+/// Scenario: Future-dated vouchers and claims are rejected beyond clock drift
+#[test]
+fn test_future_dated_voucher_and_claim_rejected() {
+    use vauchi_core::recovery::MAX_CLOCK_DRIFT_SECS;
+
+    let old_pk = [0x01u8; 32];
+    let new_pk = [0x02u8; 32];
+    let voucher_keypair = SigningKeyPair::generate();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut proof = RecoveryProof::new(&old_pk, &new_pk, 3);
+
+    // Signed a day ahead: within the recovery window, but from the future
+    let future = vauchi_core::RecoveryVoucher::create_with_timestamp(
+        &old_pk,
+        &new_pk,
+        &voucher_keypair,
+        now + 24 * 3600,
+    );
+    assert!(future.verify());
+    let result = proof.add_voucher(future);
+    assert!(matches!(result, Err(RecoveryError::FutureTimestamp)));
+    assert_eq!(proof.voucher_count(), 0);
+
+    // A little ahead is tolerated as clock drift
+    let drifted = vauchi_core::RecoveryVoucher::create_with_timestamp(
+        &old_pk,
+        &new_pk,
+        &voucher_keypair,
+        now + MAX_CLOCK_DRIFT_SECS / 2,
+    );
+    proof.add_voucher(drifted).unwrap();
+
+    // A future-dated claim would never expire, so it is refused too
+    let claim = RecoveryClaim::new_with_timestamp(&old_pk, &new_pk, now + 24 * 3600);
+    let result = vauchi_core::RecoveryVoucher::create_from_claim(&claim, &voucher_keypair);
+    assert!(matches!(result, Err(RecoveryError::FutureTimestamp)));
+
+    let claim = RecoveryClaim::new_with_timestamp(&old_pk, &new_pk, now + MAX_CLOCK_DRIFT_SECS / 2);
+    vauchi_core::RecoveryVoucher::create_from_claim(&claim, &voucher_keypair).unwrap();
+}
+
 /// Scenario: Recovery proof serialization roundtrip
 #[test]
 fn test_proof_roundtrip() {
@@ -32,3 +32,18 @@ fn test_recovery_voucher_roundtrip() {
 
     assert!(restored.verify());
 }
+
+#[test]
+fn test_recovery_proof_expiry() {
+    let proof = RecoveryProof::new(&[0x01u8; 32], &[0x02u8; 32], 3);
+    assert!(!proof.is_expired());
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    assert!(proof.expires_at() > now + 89 * 24 * 60 * 60);
+
+    let restored = RecoveryProof::from_bytes(&proof.to_bytes()).unwrap();
+    assert_eq!(restored.expires_at(), proof.expires_at());
+}
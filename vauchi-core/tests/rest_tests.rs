@@ -0,0 +1,208 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for rest
+
+use std::sync::{Arc, Mutex};
+
+use axum::body::Body;
+use axum::http::{header, Method, Request, StatusCode};
+use axum::Router;
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+use vauchi_core::api::CallbackHandler;
+use vauchi_core::network::MockTransport;
+use vauchi_core::rest::router;
+use vauchi_core::{Vauchi, VauchiEvent};
+
+const TOKEN: &str = "test-token";
+
+fn test_router() -> Router {
+    router(Vauchi::<MockTransport>::in_memory().unwrap(), TOKEN)
+}
+
+async fn call(app: &Router, method: Method, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(header::AUTHORIZATION, format!("Bearer {TOKEN}"))
+        .header(header::CONTENT_TYPE, "application/json");
+    let request = match body {
+        Some(body) => request.body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, body)
+}
+
+#[tokio::test]
+async fn test_rest_requires_bearer_token() {
+    let app = test_router();
+
+    for auth in [None, Some("Bearer wrong"), Some(TOKEN)] {
+        let mut request = Request::builder().uri("/card");
+        if let Some(auth) = auth {
+            request = request.header(header::AUTHORIZATION, auth);
+        }
+        let response = app
+            .clone()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
+
+#[tokio::test]
+async fn test_rest_card_lifecycle() {
+    let app = test_router();
+
+    let (status, _) = call(&app, Method::GET, "/card", None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, body) = call(
+        &app,
+        Method::POST,
+        "/identity",
+        Some(json!({ "display_name": "Alice" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let public_id = body["public_id"].as_str().unwrap().to_string();
+
+    let (status, body) = call(&app, Method::GET, "/identity", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["public_id"], public_id);
+
+    let (status, _) = call(
+        &app,
+        Method::POST,
+        "/identity",
+        Some(json!({ "display_name": "Again" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Create
+    let (status, field) = call(
+        &app,
+        Method::POST,
+        "/card/fields",
+        Some(json!({ "field_type": "Email", "label": "work", "value": "alice@acme.example" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let field_id = field["id"].as_str().unwrap().to_string();
+
+    let (status, _) = call(
+        &app,
+        Method::POST,
+        "/card/fields",
+        Some(json!({ "field_type": "Email", "label": "bad", "value": "not an email" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Read
+    let (status, card) = call(&app, Method::GET, "/card", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(card["display_name"], "Alice");
+    assert_eq!(card["fields"].as_array().unwrap().len(), 1);
+    assert_eq!(card["fields"][0]["value"], "alice@acme.example");
+
+    // Update
+    let (status, field) = call(
+        &app,
+        Method::PUT,
+        &format!("/card/fields/{field_id}"),
+        Some(json!({ "value": "alice@acme.test" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(field["label"], "work");
+    assert_eq!(field["value"], "alice@acme.test");
+
+    let (_, card) = call(&app, Method::GET, "/card", None).await;
+    assert_eq!(card["fields"][0]["value"], "alice@acme.test");
+
+    let (status, _) = call(
+        &app,
+        Method::PUT,
+        "/card/fields/missing",
+        Some(json!({ "label": "home" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Delete
+    let uri = format!("/card/fields/{field_id}");
+    let (status, _) = call(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = call(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, card) = call(&app, Method::GET, "/card", None).await;
+    assert!(card["fields"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_rest_remove_field_notifies_card_update() {
+    let updated = Arc::new(Mutex::new(Vec::new()));
+    let seen = updated.clone();
+    let mut vauchi = Vauchi::<MockTransport>::in_memory().unwrap();
+    vauchi.add_event_handler(Arc::new(CallbackHandler::new(move |event| {
+        if let VauchiEvent::OwnCardUpdated { changed_fields } = event {
+            seen.lock().unwrap().push(changed_fields);
+        }
+    })));
+    let app = router(vauchi, TOKEN);
+
+    call(
+        &app,
+        Method::POST,
+        "/identity",
+        Some(json!({ "display_name": "Alice" })),
+    )
+    .await;
+    let (_, field) = call(
+        &app,
+        Method::POST,
+        "/card/fields",
+        Some(json!({ "field_type": "Phone", "label": "mobile", "value": "+41 79 000 00 00" })),
+    )
+    .await;
+    updated.lock().unwrap().clear();
+
+    let uri = format!("/card/fields/{}", field["id"].as_str().unwrap());
+    let (status, _) = call(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(*updated.lock().unwrap(), vec![vec!["mobile".to_string()]]);
+}
+
+#[tokio::test]
+async fn test_rest_contacts_and_sync() {
+    let app = test_router();
+    call(
+        &app,
+        Method::POST,
+        "/identity",
+        Some(json!({ "display_name": "Alice" })),
+    )
+    .await;
+
+    let (status, contacts) = call(&app, Method::GET, "/contacts", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(contacts, json!([]));
+
+    let (status, result) = call(&app, Method::POST, "/sync", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["sent"], 0);
+    assert_eq!(result["failed"], 0);
+}
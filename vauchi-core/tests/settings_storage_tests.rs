@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::settings
+
+use vauchi_core::social::TrustThresholds;
+use vauchi_core::*;
+
+#[test]
+fn test_settings_roundtrip_and_replace() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    assert_eq!(
+        storage
+            .load_setting::<TrustThresholds>("trust_thresholds")
+            .unwrap(),
+        None
+    );
+
+    let thresholds = TrustThresholds {
+        min_validations: 4,
+        in_person_counts_double: false,
+    };
+    storage
+        .save_setting("trust_thresholds", &thresholds)
+        .unwrap();
+    storage.save_setting("other", &7u32).unwrap();
+    assert_eq!(
+        storage.load_setting("trust_thresholds").unwrap(),
+        Some(thresholds)
+    );
+
+    storage.save_setting("other", &9u32).unwrap();
+    assert_eq!(storage.load_setting::<u32>("other").unwrap(), Some(9));
+}
+
+#[test]
+fn test_settings_wrong_type_is_an_error() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    storage.save_setting("flag", &true).unwrap();
+
+    assert!(storage.load_setting::<u32>("flag").is_err());
+}
@@ -64,6 +64,36 @@ fn test_storage_list_contacts() {
     assert_eq!(contacts.len(), 2);
 }
 
+#[test]
+fn test_storage_pinned_contacts_listed_first() {
+    let storage = create_test_storage();
+    for (i, name) in ["Alice", "Bob", "Zoe"].iter().enumerate() {
+        let contact = Contact::from_exchange(
+            [i as u8 + 1; 32],
+            ContactCard::new(name),
+            SymmetricKey::generate(),
+        );
+        storage.save_contact(&contact).unwrap();
+    }
+
+    let mut zoe = storage
+        .load_contact(&hex::encode([3u8; 32]))
+        .unwrap()
+        .unwrap();
+    assert!(!zoe.is_pinned());
+    zoe.set_pinned(true);
+    storage.save_contact(&zoe).unwrap();
+
+    let names: Vec<_> = storage
+        .list_contacts()
+        .unwrap()
+        .iter()
+        .map(|c| c.display_name().to_string())
+        .collect();
+    assert_eq!(names, ["Zoe", "Alice", "Bob"]);
+    assert!(storage.load_contact(zoe.id()).unwrap().unwrap().is_pinned());
+}
+
 #[test]
 fn test_storage_delete_contact() {
     let storage = create_test_storage();
@@ -72,11 +102,13 @@ fn test_storage_delete_contact() {
 
     storage.save_contact(&contact).unwrap();
     assert!(storage.load_contact(&contact_id).unwrap().is_some());
+    assert!(!storage.was_contact_deleted(&contact_id).unwrap());
 
     let deleted = storage.delete_contact(&contact_id).unwrap();
     assert!(deleted);
 
     assert!(storage.load_contact(&contact_id).unwrap().is_none());
+    assert!(storage.was_contact_deleted(&contact_id).unwrap());
 }
 
 #[test]
@@ -86,6 +118,172 @@ fn test_storage_contact_not_found() {
     assert!(result.is_none());
 }
 
+#[test]
+fn test_storage_contact_limit() {
+    let storage = create_test_storage();
+    assert_eq!(
+        storage.get_contact_limit().unwrap(),
+        storage::DEFAULT_MAX_CONTACTS
+    );
+
+    storage.set_contact_limit(1).unwrap();
+    assert_eq!(storage.get_contact_limit().unwrap(), 1);
+
+    let alice = create_test_contact("Alice");
+    storage.save_contact(&alice).unwrap();
+
+    // A new contact beyond the limit is rejected
+    let bob = Contact::from_exchange([2u8; 32], ContactCard::new("Bob"), SymmetricKey::generate());
+    assert!(matches!(
+        storage.save_contact(&bob),
+        Err(StorageError::ContactLimitReached(1))
+    ));
+    assert_eq!(storage.count_contacts().unwrap(), 1);
+
+    // Updating an existing contact still works
+    let mut alice = storage.load_contact(alice.id()).unwrap().unwrap();
+    alice.set_blocked(true);
+    storage.save_contact(&alice).unwrap();
+    assert!(storage
+        .load_contact(alice.id())
+        .unwrap()
+        .unwrap()
+        .is_blocked());
+}
+
+#[test]
+fn test_storage_verified_at_roundtrip() {
+    let storage = create_test_storage();
+    let mut contact = create_test_contact("Alice");
+    contact.mark_fingerprint_verified_at(1_700_000_000);
+    storage.save_contact(&contact).unwrap();
+
+    let loaded = storage.load_contact(contact.id()).unwrap().unwrap();
+    assert!(loaded.is_fingerprint_verified());
+    assert_eq!(loaded.verified_at(), Some(1_700_000_000));
+}
+
+#[test]
+fn test_storage_verified_by_roundtrip() {
+    let storage = create_test_storage();
+    let mut contact = create_test_contact("Alice");
+    contact.mark_fingerprint_verified_by("organizer-id", 1_700_000_000);
+    storage.save_contact(&contact).unwrap();
+
+    let loaded = storage.load_contact(contact.id()).unwrap().unwrap();
+    assert!(loaded.is_fingerprint_verified());
+    assert_eq!(loaded.verified_by(), Some("organizer-id"));
+    assert_eq!(loaded.verified_at(), Some(1_700_000_000));
+}
+
+#[test]
+fn test_storage_contacts_needing_reverification() {
+    let storage = create_test_storage();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    // Verified 200 days ago
+    let mut stale = Contact::from_exchange(
+        [1u8; 32],
+        ContactCard::new("Stale"),
+        SymmetricKey::generate(),
+    );
+    stale.mark_fingerprint_verified_at(now - 200 * 86_400);
+    storage.save_contact(&stale).unwrap();
+
+    // Verified just now
+    let mut fresh = Contact::from_exchange(
+        [2u8; 32],
+        ContactCard::new("Fresh"),
+        SymmetricKey::generate(),
+    );
+    fresh.mark_fingerprint_verified();
+    storage.save_contact(&fresh).unwrap();
+
+    // Never verified
+    let unverified = Contact::from_exchange(
+        [3u8; 32],
+        ContactCard::new("Unverified"),
+        SymmetricKey::generate(),
+    );
+    storage.save_contact(&unverified).unwrap();
+
+    let due = storage.contacts_needing_reverification(90).unwrap();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].id(), stale.id());
+}
+
+#[test]
+fn test_storage_clock_watermark() {
+    let storage = create_test_storage();
+    assert_eq!(storage.load_clock_watermark().unwrap(), None);
+
+    assert_eq!(storage.observe_clock(1_000, 60).unwrap(), None);
+    assert_eq!(storage.observe_clock(2_000, 60).unwrap(), None);
+    // Small steps back stay within the tolerance and keep the watermark
+    assert_eq!(storage.observe_clock(1_950, 60).unwrap(), None);
+    assert_eq!(storage.load_clock_watermark().unwrap(), Some(2_000));
+
+    // Larger ones are reported and do not lower it
+    assert_eq!(storage.observe_clock(1_500, 60).unwrap(), Some(2_000));
+    assert_eq!(storage.load_clock_watermark().unwrap(), Some(2_000));
+
+    storage.reset_clock_watermark(1_500).unwrap();
+    assert_eq!(storage.observe_clock(1_500, 60).unwrap(), None);
+    assert_eq!(storage.load_clock_watermark().unwrap(), Some(1_500));
+}
+
+#[test]
+fn test_storage_list_contacts_by_verification() {
+    let storage = create_test_storage();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut stale = Contact::from_exchange(
+        [1u8; 32],
+        ContactCard::new("Stale"),
+        SymmetricKey::generate(),
+    );
+    stale.mark_fingerprint_verified_at(now - 200 * 86_400);
+    storage.save_contact(&stale).unwrap();
+
+    let mut fresh = Contact::from_exchange(
+        [2u8; 32],
+        ContactCard::new("Fresh"),
+        SymmetricKey::generate(),
+    );
+    fresh.mark_fingerprint_verified();
+    storage.save_contact(&fresh).unwrap();
+
+    let unverified = Contact::from_exchange(
+        [3u8; 32],
+        ContactCard::new("Unverified"),
+        SymmetricKey::generate(),
+    );
+    storage.save_contact(&unverified).unwrap();
+
+    let ids = |contacts: Vec<Contact>| -> Vec<String> {
+        contacts.iter().map(|c| c.id().to_string()).collect()
+    };
+
+    assert_eq!(
+        ids(storage.list_contacts_by_verification(true).unwrap()),
+        vec![fresh.id().to_string(), stale.id().to_string()]
+    );
+    assert_eq!(
+        ids(storage.list_contacts_by_verification(false).unwrap()),
+        vec![unverified.id().to_string()]
+    );
+    assert_eq!(
+        ids(storage.contacts_needing_attention(90).unwrap()),
+        vec![unverified.id().to_string(), stale.id().to_string()]
+    );
+}
+
 #[test]
 fn test_storage_save_load_own_card() {
     let storage = create_test_storage();
@@ -107,6 +305,47 @@ fn test_storage_own_card_not_found() {
     assert!(result.is_none());
 }
 
+#[test]
+fn test_storage_card_draft_publish_and_discard() {
+    let storage = create_test_storage();
+
+    let mut card = ContactCard::new("My Card");
+    let _ = card.add_field(ContactField::new(FieldType::Phone, "mobile", "+1234567890"));
+    storage.save_own_card(&card).unwrap();
+    assert!(storage.load_card_draft().unwrap().is_none());
+    assert!(storage.publish_card_draft().unwrap().is_none());
+
+    let mut draft = card.clone();
+    let _ = draft.add_field(ContactField::new(
+        FieldType::Email,
+        "work",
+        "me@work.example",
+    ));
+    storage.save_card_draft(&draft).unwrap();
+
+    // The live card is untouched until the draft is published
+    assert_eq!(storage.load_own_card().unwrap().unwrap().fields().len(), 1);
+    assert_eq!(
+        storage.load_card_draft().unwrap().unwrap().fields().len(),
+        2
+    );
+
+    let delta = storage.publish_card_draft().unwrap().unwrap();
+    assert_eq!(delta.changes.len(), 1);
+    assert_eq!(storage.load_own_card().unwrap().unwrap().fields().len(), 2);
+    assert!(storage.load_card_draft().unwrap().is_none());
+
+    storage
+        .save_card_draft(&ContactCard::new("Renamed"))
+        .unwrap();
+    assert!(storage.discard_card_draft().unwrap());
+    assert!(!storage.discard_card_draft().unwrap());
+    assert_eq!(
+        storage.load_own_card().unwrap().unwrap().display_name(),
+        "My Card"
+    );
+}
+
 #[test]
 fn test_storage_pending_updates() {
     let storage = create_test_storage();
@@ -499,3 +738,29 @@ fn test_storage_version_vector_update() {
     let loaded = storage.load_version_vector().unwrap().unwrap();
     assert_eq!(loaded.get(&device_a), 3);
 }
+
+#[test]
+fn test_read_only_storage_rejects_writes() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let db_path = dir.path().join("vauchi.db");
+    let key = SymmetricKey::generate();
+
+    let contact = create_test_contact("Alice");
+    {
+        let storage = Storage::open(&db_path, key.clone()).unwrap();
+        storage.save_contact(&contact).unwrap();
+    }
+
+    let storage = Storage::open_read_only(&db_path, key).unwrap();
+
+    let loaded = storage.load_contact(contact.id()).unwrap().unwrap();
+    assert_eq!(loaded.display_name(), "Alice");
+
+    let result = storage.save_contact(&create_test_contact("Bob"));
+    assert!(matches!(result, Err(StorageError::ReadOnly)));
+    assert!(matches!(
+        storage.create_label("Family"),
+        Err(StorageError::ReadOnly)
+    ));
+    assert_eq!(storage.list_contacts().unwrap().len(), 1);
+}
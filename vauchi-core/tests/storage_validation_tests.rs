@@ -235,3 +235,111 @@ fn test_validation_upsert() {
         .unwrap();
     assert_eq!(loaded[0].field_value(), "new@example.com");
 }
+
+#[test]
+fn test_validation_rate_limit_up_to_cap() {
+    use vauchi_core::social::ValidationRateLimiter;
+
+    let storage = create_test_storage();
+    let limiter = ValidationRateLimiter::new(3);
+
+    assert_eq!(
+        storage.check_validation_rate_limit("validator456").unwrap(),
+        (0, 0)
+    );
+
+    for _ in 0..3 {
+        let (count, window_start) = storage.check_validation_rate_limit("validator456").unwrap();
+        assert!(limiter.check_rate_limit(count, window_start));
+        let (count, window_start) = limiter.record(count, window_start);
+        storage
+            .update_validation_rate_limit("validator456", count, window_start)
+            .unwrap();
+    }
+
+    let (count, window_start) = storage.check_validation_rate_limit("validator456").unwrap();
+    assert_eq!(count, 3);
+    assert!(!limiter.check_rate_limit(count, window_start));
+
+    // Other validators have their own window, and an expired window resets
+    assert_eq!(
+        storage.check_validation_rate_limit("validator789").unwrap(),
+        (0, 0)
+    );
+    assert!(limiter.check_rate_limit(count, window_start - 86_400));
+}
+
+#[test]
+fn test_validation_daily_limit_persisted_and_capped() {
+    use vauchi_core::social::ValidationRateLimiter;
+
+    let storage = create_test_storage();
+    assert_eq!(storage.load_validation_daily_limit().unwrap(), None);
+
+    storage.save_validation_daily_limit(5).unwrap();
+    assert_eq!(storage.load_validation_daily_limit().unwrap(), Some(5));
+
+    // A stored value above the cap does not raise the limit
+    storage.save_validation_daily_limit(10_000).unwrap();
+    let stored = storage.load_validation_daily_limit().unwrap().unwrap();
+    assert_eq!(
+        ValidationRateLimiter::new(stored).max_validations_per_day,
+        ValidationRateLimiter::MAX_VALIDATIONS_PER_DAY
+    );
+}
+
+#[test]
+fn test_validation_bundle_roundtrip() {
+    use vauchi_core::social::ValidationBundle;
+    use vauchi_core::Identity;
+
+    let alice = Identity::create("Alice");
+    let alice_storage = create_test_storage();
+    for field in ["email", "phone"] {
+        let validation = ProfileValidation::create_signed(&alice, field, "value", "contact123");
+        alice_storage.save_validation(&validation).unwrap();
+    }
+
+    let bundle = alice_storage.export_my_validations_signed(&alice).unwrap();
+    assert_eq!(bundle.validator_pk(), alice.signing_public_key());
+    assert_eq!(bundle.validations().len(), 2);
+    assert!(bundle.verify());
+
+    let bob_storage = create_test_storage();
+    let bundle = ValidationBundle::from_bytes(&bundle.to_bytes()).unwrap();
+    assert_eq!(bob_storage.import_validations_bundle(&bundle).unwrap(), 2);
+
+    let alice_id = hex::encode(alice.signing_public_key());
+    assert!(bob_storage
+        .has_validated("contact123", "email", &alice_id)
+        .unwrap());
+    assert_eq!(
+        bob_storage
+            .load_validations_by_validator(&alice_id)
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_validation_bundle_rejects_tampering() {
+    use vauchi_core::Identity;
+
+    let alice = Identity::create("Alice");
+    let mallory = Identity::create("Mallory");
+    let storage = create_test_storage();
+    let validation = ProfileValidation::create_signed(&alice, "email", "value", "contact123");
+    storage.save_validation(&validation).unwrap();
+
+    // Dropping a validation breaks the bundle signature
+    let mut bundle = storage.export_my_validations_signed(&alice).unwrap();
+    bundle.set_validations_for_testing(Vec::new());
+    assert!(!bundle.verify());
+    assert!(storage.import_validations_bundle(&bundle).is_err());
+
+    // Re-signing someone else's validations does not make them yours
+    let forged = vauchi_core::social::ValidationBundle::create(&mallory, vec![validation]);
+    assert!(!forged.verify());
+    assert!(storage.import_validations_bundle(&forged).is_err());
+}
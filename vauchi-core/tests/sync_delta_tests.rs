@@ -215,6 +215,59 @@ fn test_delta_serialization_roundtrip() {
     assert_eq!(delta.changes.len(), restored.changes.len());
 }
 
+#[test]
+fn test_delta_wire_roundtrip() {
+    let identity = Identity::create("Alice");
+    let mut old = ContactCard::new("Alice");
+    let _ = old.add_field(ContactField::new(
+        FieldType::Email,
+        "email",
+        "old@example.com",
+    ));
+    let mut new = ContactCard::new("Alice Smith");
+    let _ = new.add_field(ContactField::new(FieldType::Phone, "phone", "+1234567890"));
+
+    let mut delta = CardDelta::compute(&old, &new);
+    delta.sign(&identity);
+
+    let bytes = delta.to_wire();
+    assert!(bytes.starts_with(format!("{{\"format\":{}", DELTA_FORMAT_VERSION).as_bytes()));
+    // Encoding is stable
+    assert_eq!(bytes, delta.to_wire());
+
+    let restored = CardDelta::from_wire(&bytes).unwrap();
+    assert_eq!(restored.changes, delta.changes);
+    assert_eq!(restored.nonce, delta.nonce);
+    assert!(restored.verify(identity.signing_public_key()));
+
+    // Untagged deltas from older peers still decode
+    let legacy = serde_json::to_vec(&delta).unwrap();
+    let restored = CardDelta::from_wire(&legacy).unwrap();
+    assert_eq!(restored.changes, delta.changes);
+    assert!(restored.verify(identity.signing_public_key()));
+}
+
+#[test]
+fn test_delta_wire_rejects_future_format() {
+    let delta = CardDelta::compute(&ContactCard::new("Alice"), &ContactCard::new("Bob"));
+    let mut wire: serde_json::Value = serde_json::from_slice(&delta.to_wire()).unwrap();
+    wire["format"] = serde_json::json!(DELTA_FORMAT_VERSION + 1);
+    let bytes = serde_json::to_vec(&wire).unwrap();
+
+    let err = CardDelta::from_wire(&bytes).unwrap_err();
+    assert!(matches!(
+        err,
+        DeltaError::UnsupportedFormat { supported, actual }
+            if supported == DELTA_FORMAT_VERSION && actual == DELTA_FORMAT_VERSION + 1
+    ));
+    assert!(err.to_string().contains("Unsupported delta format"));
+
+    assert!(matches!(
+        CardDelta::from_wire(b"not json"),
+        Err(DeltaError::Malformed(_))
+    ));
+}
+
 #[test]
 fn test_delta_multiple_changes() {
     let mut old = ContactCard::new("Alice");
@@ -249,6 +302,56 @@ fn test_delta_multiple_changes() {
     assert!(has_added);
 }
 
+#[test]
+fn test_delta_compute_is_deterministic() {
+    let mut old = ContactCard::new("Alice");
+    let mut new = ContactCard::new("Alice Smith");
+    for i in 0..8 {
+        let label = format!("email{}", i);
+        let old_field = ContactField::new(FieldType::Email, &label, "old@example.com");
+        let mut new_field = old_field.clone();
+        new_field.set_value(&format!("new{}@example.com", i));
+        let _ = old.add_field(old_field);
+        let _ = new.add_field(new_field);
+        let _ = new.add_field(ContactField::new(FieldType::Phone, &label, "+1234567890"));
+    }
+
+    let wire = |delta: CardDelta| {
+        let mut delta = delta;
+        delta.timestamp = 0;
+        delta.nonce = [0u8; 32];
+        delta.to_wire()
+    };
+    let first = wire(CardDelta::compute(&old, &new));
+    for _ in 0..10 {
+        assert_eq!(wire(CardDelta::compute(&old, &new)), first);
+    }
+}
+
+#[test]
+fn test_delta_from_changes() {
+    let field = ContactField::new(FieldType::Email, "work", "alice@example.com");
+    let changes = vec![
+        FieldChange::Removed {
+            field_id: field.id().to_string(),
+        },
+        FieldChange::Added {
+            field: field.clone(),
+        },
+    ];
+
+    let delta = CardDelta::from_changes(changes.clone(), 3, 1_700_000_000);
+    assert_eq!(delta.version, 3);
+    assert_eq!(delta.timestamp, 1_700_000_000);
+    assert_eq!(delta.changes, changes);
+    assert_ne!(delta.nonce, [0u8; 32]);
+
+    let mut card = ContactCard::new("Alice");
+    delta.apply(&mut card).unwrap();
+    assert_eq!(card.fields().len(), 1);
+    assert_eq!(card.fields()[0].value(), "alice@example.com");
+}
+
 #[test]
 fn test_delta_filter_for_contact_all_visible() {
     use vauchi_core::contact::VisibilityRules;
@@ -344,3 +447,64 @@ fn test_delta_filter_display_name_always_visible() {
         FieldChange::DisplayNameChanged { .. }
     ));
 }
+
+#[test]
+fn test_delta_project_drops_hidden_added_field() {
+    use std::collections::HashSet;
+
+    let old = ContactCard::new("Alice");
+    let mut new = ContactCard::new("Alice");
+    let _ = new.add_field(ContactField::new(
+        FieldType::Email,
+        "email",
+        "alice@example.com",
+    ));
+
+    let delta = CardDelta::compute(&old, &new);
+
+    // Nothing is visible to this contact
+    let projected = delta.project(&HashSet::new());
+
+    assert!(projected.is_empty());
+}
+
+#[test]
+fn test_delta_project_keeps_visible_change() {
+    use std::collections::HashSet;
+
+    let old = ContactCard::new("Alice");
+    let mut new = ContactCard::new("Alice");
+    let email_field = ContactField::new(FieldType::Email, "email", "alice@example.com");
+    let phone_field = ContactField::new(FieldType::Phone, "phone", "+1234567890");
+    let phone_id = phone_field.id().to_string();
+    let _ = new.add_field(email_field);
+    let _ = new.add_field(phone_field);
+
+    let delta = CardDelta::compute(&old, &new);
+
+    let visible: HashSet<String> = [phone_id].into_iter().collect();
+    let projected = delta.project(&visible);
+
+    assert_eq!(projected.changes.len(), 1);
+    assert!(
+        matches!(&projected.changes[0], FieldChange::Added { field } if field.label() == "phone")
+    );
+    assert_eq!(projected.nonce, delta.nonce);
+}
+
+#[test]
+fn test_delta_project_keeps_display_name_change() {
+    use std::collections::HashSet;
+
+    let old = ContactCard::new("Alice");
+    let new = ContactCard::new("Alice Smith");
+
+    let delta = CardDelta::compute(&old, &new);
+    let projected = delta.project(&HashSet::new());
+
+    assert_eq!(projected.changes.len(), 1);
+    assert!(matches!(
+        &projected.changes[0],
+        FieldChange::DisplayNameChanged { .. }
+    ));
+}
@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::tags
+
+use vauchi_core::contact::Contact;
+use vauchi_core::*;
+
+fn test_storage() -> Storage {
+    Storage::in_memory(SymmetricKey::generate()).unwrap()
+}
+
+fn save_contact(storage: &Storage, name: &str, key_byte: u8) -> Contact {
+    let contact = Contact::from_exchange(
+        [key_byte; 32],
+        ContactCard::new(name),
+        SymmetricKey::generate(),
+    );
+    storage.save_contact(&contact).unwrap();
+    contact
+}
+
+#[test]
+fn test_add_and_remove_contact_tags() {
+    let storage = test_storage();
+    let alice = save_contact(&storage, "Alice", 1);
+
+    assert!(storage.add_contact_tag(alice.id(), "family").unwrap());
+    assert!(storage.add_contact_tag(alice.id(), " clients ").unwrap());
+    assert!(!storage.add_contact_tag(alice.id(), "family").unwrap());
+    assert_eq!(
+        storage.get_contact_tags(alice.id()).unwrap(),
+        vec!["clients".to_string(), "family".to_string()]
+    );
+
+    assert!(storage.remove_contact_tag(alice.id(), "family").unwrap());
+    assert!(!storage.remove_contact_tag(alice.id(), "family").unwrap());
+    assert_eq!(
+        storage.get_contact_tags(alice.id()).unwrap(),
+        vec!["clients".to_string()]
+    );
+}
+
+#[test]
+fn test_invalid_tags_rejected() {
+    let storage = test_storage();
+    let alice = save_contact(&storage, "Alice", 1);
+
+    assert!(matches!(
+        storage.add_contact_tag(alice.id(), "   "),
+        Err(StorageError::InvalidData(_))
+    ));
+    assert!(matches!(
+        storage.add_contact_tag(alice.id(), &"x".repeat(51)),
+        Err(StorageError::InvalidData(_))
+    ));
+
+    // The limit counts characters, not bytes
+    assert!(storage
+        .add_contact_tag(alice.id(), &"ü".repeat(50))
+        .unwrap());
+    assert!(matches!(
+        storage.add_contact_tag(alice.id(), &"ü".repeat(51)),
+        Err(StorageError::InvalidData(_))
+    ));
+}
+
+#[test]
+fn test_list_contacts_by_tag() {
+    let storage = test_storage();
+    let carol = save_contact(&storage, "Carol", 3);
+    let alice = save_contact(&storage, "Alice", 1);
+    let bob = save_contact(&storage, "Bob", 2);
+
+    storage.add_contact_tag(carol.id(), "family").unwrap();
+    storage.add_contact_tag(alice.id(), "family").unwrap();
+    storage.add_contact_tag(bob.id(), "clients").unwrap();
+
+    let family = storage.list_contacts_by_tag("family").unwrap();
+    let names: Vec<&str> = family.iter().map(|c| c.display_name()).collect();
+    assert_eq!(names, vec!["Alice", "Carol"]);
+
+    assert_eq!(storage.list_contacts_by_tag("clients").unwrap().len(), 1);
+    assert!(storage.list_contacts_by_tag("unknown").unwrap().is_empty());
+}
+
+#[test]
+fn test_tags_removed_with_contact() {
+    let storage = test_storage();
+    let alice = save_contact(&storage, "Alice", 1);
+    storage.add_contact_tag(alice.id(), "family").unwrap();
+
+    storage.delete_contact(alice.id()).unwrap();
+
+    assert!(storage.get_contact_tags(alice.id()).unwrap().is_empty());
+    assert!(storage.list_contacts_by_tag("family").unwrap().is_empty());
+}
+
+#[test]
+fn test_tags_do_not_affect_visibility() {
+    let storage = test_storage();
+    let alice = save_contact(&storage, "Alice", 1);
+    storage.add_contact_tag(alice.id(), "family").unwrap();
+
+    assert!(storage
+        .get_labels_for_contact(alice.id())
+        .unwrap()
+        .is_empty());
+    assert!(storage
+        .load_contact_overrides(alice.id())
+        .unwrap()
+        .is_empty());
+}
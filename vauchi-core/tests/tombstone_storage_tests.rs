@@ -0,0 +1,198 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::tombstones
+
+use rusqlite::Connection;
+use tempfile::TempDir;
+use vauchi_core::contact::Contact;
+use vauchi_core::crypto::ratchet::DoubleRatchetState;
+use vauchi_core::exchange::X3DHKeyPair;
+use vauchi_core::storage::CONTACT_TOMBSTONE_RETENTION_SECS;
+use vauchi_core::*;
+
+fn test_storage() -> Storage {
+    Storage::in_memory(SymmetricKey::generate()).unwrap()
+}
+
+fn save_contact(storage: &Storage, name: &str, key_byte: u8) -> Contact {
+    let mut contact = Contact::from_exchange(
+        [key_byte; 32],
+        ContactCard::new(name),
+        SymmetricKey::generate(),
+    );
+    contact.mark_fingerprint_verified();
+    storage.save_contact(&contact).unwrap();
+
+    let their_dh = X3DHKeyPair::generate();
+    let ratchet =
+        DoubleRatchetState::initialize_initiator(contact.shared_key(), *their_dh.public_key());
+    storage
+        .save_ratchet_state(contact.id(), &ratchet, true)
+        .unwrap();
+    contact
+}
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[test]
+fn test_delete_contact_leaves_tombstone() {
+    let storage = test_storage();
+    let alice = save_contact(&storage, "Alice", 1);
+
+    assert!(storage.list_contact_tombstones().unwrap().is_empty());
+    assert!(storage.delete_contact(alice.id()).unwrap());
+
+    let tombstones = storage.list_contact_tombstones().unwrap();
+    assert_eq!(tombstones.len(), 1);
+    assert_eq!(tombstones[0].contact_id, alice.id());
+    assert_eq!(tombstones[0].display_name, "Alice");
+
+    // Deleting a missing contact leaves no tombstone
+    assert!(!storage.delete_contact("missing").unwrap());
+    assert!(!storage.was_contact_deleted("missing").unwrap());
+}
+
+#[test]
+fn test_restore_deleted_contact_within_window() {
+    let storage = test_storage();
+    let alice = save_contact(&storage, "Alice", 1);
+    storage.delete_contact(alice.id()).unwrap();
+    assert!(storage.load_ratchet_state(alice.id()).unwrap().is_none());
+
+    assert!(storage.restore_deleted_contact(alice.id()).unwrap());
+
+    let restored = storage.load_contact(alice.id()).unwrap().unwrap();
+    assert_eq!(restored.display_name(), "Alice");
+    assert!(restored.is_fingerprint_verified());
+    assert_eq!(
+        restored.shared_key().as_bytes(),
+        alice.shared_key().as_bytes()
+    );
+    assert!(storage.load_ratchet_state(alice.id()).unwrap().is_some());
+    assert!(!storage.was_contact_deleted(alice.id()).unwrap());
+
+    // Nothing left to restore
+    assert!(!storage.restore_deleted_contact(alice.id()).unwrap());
+}
+
+#[test]
+fn test_restore_keeps_every_contact_column() {
+    let key = SymmetricKey::generate();
+    let storage = Storage::in_memory(key.clone()).unwrap();
+    let mut alice = save_contact(&storage, "Alice", 1);
+    alice.set_muted(true);
+    alice.set_accepts_padding(true);
+    storage.save_contact(&alice).unwrap();
+    let notes = encrypt(&key, b"notes").unwrap();
+    storage.save_personal_notes(alice.id(), &notes).unwrap();
+
+    storage.delete_contact(alice.id()).unwrap();
+    assert!(storage.restore_deleted_contact(alice.id()).unwrap());
+
+    let restored = storage.load_contact(alice.id()).unwrap().unwrap();
+    assert!(restored.is_muted());
+    assert!(restored.accepts_padding());
+    let notes = storage.load_personal_notes(alice.id()).unwrap().unwrap();
+    assert_eq!(decrypt(&key, &notes).unwrap(), b"notes");
+}
+
+#[test]
+fn test_tombstone_survives_key_rotation() {
+    let old_key = SymmetricKey::generate();
+    let mut storage = Storage::in_memory(old_key.clone()).unwrap();
+    let alice = save_contact(&storage, "Alice", 1);
+    storage.delete_contact(alice.id()).unwrap();
+
+    storage
+        .rotate_encryption_key(&old_key, &SymmetricKey::generate())
+        .unwrap();
+
+    assert_eq!(
+        storage.list_contact_tombstones().unwrap()[0].display_name,
+        "Alice"
+    );
+    assert!(storage.restore_deleted_contact(alice.id()).unwrap());
+    let restored = storage.load_contact(alice.id()).unwrap().unwrap();
+    assert_eq!(
+        restored.shared_key().as_bytes(),
+        alice.shared_key().as_bytes()
+    );
+    assert!(storage.load_ratchet_state(alice.id()).unwrap().is_some());
+}
+
+#[test]
+fn test_restore_skips_re_added_contact() {
+    let storage = test_storage();
+    let alice = save_contact(&storage, "Alice", 1);
+    storage.delete_contact(alice.id()).unwrap();
+
+    save_contact(&storage, "Alice Again", 1);
+    assert!(storage.list_contact_tombstones().unwrap().is_empty());
+    assert!(!storage.restore_deleted_contact(alice.id()).unwrap());
+    assert_eq!(
+        storage
+            .load_contact(alice.id())
+            .unwrap()
+            .unwrap()
+            .display_name(),
+        "Alice Again"
+    );
+}
+
+#[test]
+fn test_purge_contact_tombstones_after_retention() {
+    let storage = test_storage();
+    let alice = save_contact(&storage, "Alice", 1);
+    storage.delete_contact(alice.id()).unwrap();
+
+    // Still within the retention period
+    assert_eq!(storage.purge_contact_tombstones(now()).unwrap(), 0);
+    assert!(storage.was_contact_deleted(alice.id()).unwrap());
+
+    let later = now() + CONTACT_TOMBSTONE_RETENTION_SECS + 60;
+    assert_eq!(storage.purge_contact_tombstones(later).unwrap(), 1);
+    assert!(storage.list_contact_tombstones().unwrap().is_empty());
+    assert!(!storage.restore_deleted_contact(alice.id()).unwrap());
+}
+
+#[test]
+fn test_expired_tombstones_purged_on_open_and_delete() {
+    let dir = TempDir::new().unwrap();
+    let db_path = dir.path().join("vauchi.db");
+    let key = SymmetricKey::generate();
+    let expired = (now() - CONTACT_TOMBSTONE_RETENTION_SECS - 60) as i64;
+    let backdate = |contact: &Contact| {
+        Connection::open(&db_path)
+            .unwrap()
+            .execute(
+                "UPDATE contact_tombstones SET deleted_at = ?1 WHERE contact_id = ?2",
+                (expired, contact.id()),
+            )
+            .unwrap();
+    };
+
+    let storage = Storage::open(&db_path, key.clone()).unwrap();
+    let alice = save_contact(&storage, "Alice", 1);
+    let bob = save_contact(&storage, "Bob", 2);
+    let carol = save_contact(&storage, "Carol", 3);
+    storage.delete_contact(alice.id()).unwrap();
+    storage.delete_contact(bob.id()).unwrap();
+    drop(storage);
+
+    backdate(&alice);
+    let storage = Storage::open(&db_path, key).unwrap();
+    assert!(!storage.was_contact_deleted(alice.id()).unwrap());
+    assert!(storage.was_contact_deleted(bob.id()).unwrap());
+
+    backdate(&bob);
+    storage.delete_contact(carol.id()).unwrap();
+    assert!(!storage.was_contact_deleted(bob.id()).unwrap());
+    assert!(storage.was_contact_deleted(carol.id()).unwrap());
+}
@@ -7,7 +7,7 @@
 //! TDD tests for contact field to URI conversion.
 //! Reference: features/contact_actions.feature
 
-use vauchi_core::contact_card::{ContactAction, ContactField, FieldType};
+use vauchi_core::contact_card::{is_safe_url, ContactAction, ContactField, FieldType};
 
 // ============================================================
 // Phone Number → tel: URI
@@ -591,6 +591,55 @@ fn test_custom_field_malicious_url_blocked() {
     assert!(uri.is_none(), "Malicious custom field should be blocked");
 }
 
+/// Security test: Safe link values are copied unchanged
+#[test]
+fn test_safe_display_value_passes_safe_values() {
+    let fields = vec![
+        ContactField::new(FieldType::Website, "Blog", "https://example.com/blog"),
+        ContactField::new(FieldType::Website, "Site", "example.com"),
+        ContactField::new(FieldType::Social, "GitHub", "@octocat"),
+        ContactField::new(FieldType::Custom, "Link", "https://example.com"),
+        ContactField::new(FieldType::Phone, "Mobile", "+41 79 123 45 67"),
+    ];
+
+    for field in fields {
+        let safe = field.safe_display_value();
+        assert!(safe.is_safe, "'{}' should be safe", field.value());
+        assert_eq!(safe.value, field.value());
+    }
+}
+
+/// Security test: Blocked schemes and deceptive characters are flagged when copied
+#[test]
+fn test_safe_display_value_flags_unsafe_links() {
+    let fields = vec![
+        ContactField::new(FieldType::Website, "Malicious", "javascript:alert(1)"),
+        ContactField::new(FieldType::Website, "Data", "DATA:text/html,test"),
+        ContactField::new(FieldType::Website, "App", "myapp://deeplink"),
+        ContactField::new(FieldType::Social, "Twitter", "javascript:alert(1)"),
+        // Right-to-left override hides the real extension
+        ContactField::new(
+            FieldType::Website,
+            "Spoof",
+            "https://example.com/\u{202E}fdp.exe",
+        ),
+    ];
+
+    for field in fields {
+        let safe = field.safe_display_value();
+        assert!(!safe.is_safe, "'{}' should be flagged", field.value());
+        assert!(
+            !is_safe_url(&safe.value),
+            "'{}' should be inert",
+            safe.value
+        );
+        assert!(!safe.value.contains('\u{202E}'));
+    }
+
+    let field = ContactField::new(FieldType::Website, "Malicious", "javascript:alert(1)");
+    assert_eq!(field.safe_display_value().value, "javascript[:]alert(1)");
+}
+
 // ============================================================
 // Edge Case Integration Tests
 // ============================================================
@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests for storage::usage
+
+use vauchi_core::storage::{UsageCounter, UsageStats};
+use vauchi_core::*;
+
+#[test]
+fn test_usage_counters_start_at_zero_and_increment() {
+    let storage = Storage::in_memory(SymmetricKey::generate()).unwrap();
+    assert_eq!(storage.load_usage_stats().unwrap(), UsageStats::default());
+
+    storage
+        .increment_usage_counter(UsageCounter::ExchangesCompleted, 1)
+        .unwrap();
+    storage
+        .increment_usage_counter(UsageCounter::ExchangesCompleted, 2)
+        .unwrap();
+    storage
+        .increment_usage_counter(UsageCounter::ContactsVerified, 1)
+        .unwrap();
+
+    assert_eq!(
+        storage.load_usage_stats().unwrap(),
+        UsageStats {
+            exchanges_completed: 3,
+            failed_qr_scans: 0,
+            contacts_verified: 1,
+        }
+    );
+}
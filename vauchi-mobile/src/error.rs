@@ -4,6 +4,10 @@
 
 //! Mobile-friendly error types.
 
+use vauchi_core::i18n;
+
+use crate::types::MobileLocale;
+
 /// Mobile-friendly error type.
 #[derive(Debug, thiserror::Error, uniffi::Error)]
 pub enum MobileError {
@@ -40,6 +44,45 @@ pub enum MobileError {
     #[error("Network error: {0}")]
     NetworkError(String),
 
+    #[error("Insecure relay connection: {0}")]
+    InsecureTransport(String),
+
+    #[error("Field not found: {0}")]
+    FieldNotFound(String),
+
+    #[error("Field already validated")]
+    AlreadyValidated,
+
+    #[error("Recovery claim has expired")]
+    ClaimExpired,
+
+    #[error("Device clock was set back by {0} seconds")]
+    ClockRolledBack(u64),
+
+    #[error("Rate limit exceeded: {0}")]
+    RateLimited(String),
+
+    #[error("Maximum number of labels reached ({0})")]
+    TooManyLabels(u32),
+
+    #[error("Maximum number of contacts reached ({0})")]
+    TooManyContacts(u32),
+
+    #[error("Label already exists: {0}")]
+    DuplicateLabelName(String),
+
+    #[error("Cannot add yourself as a contact")]
+    SelfContact,
+
+    #[error("Cannot vouch for your own recovery")]
+    SelfVouching,
+
+    #[error("This contact has already vouched for the claim")]
+    DuplicateVoucher,
+
+    #[error("Voucher is for a different recovery claim")]
+    VoucherMismatch,
+
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
@@ -47,8 +90,92 @@ pub enum MobileError {
     Internal(String),
 }
 
+impl MobileError {
+    /// Returns a short explanation of the error for the user, in `locale`.
+    ///
+    /// Unlike `Display`, which is meant for logs, this never includes
+    /// technical details. Falls back to English for untranslated text.
+    pub fn user_message(&self, locale: MobileLocale) -> String {
+        let locale = locale.into();
+        let key = match self {
+            MobileError::TooManyLabels(max) => {
+                return i18n::get_string_with_args(
+                    locale,
+                    "error.too_many_labels",
+                    &[("max", &max.to_string())],
+                );
+            }
+            MobileError::TooManyContacts(max) => {
+                return i18n::get_string_with_args(
+                    locale,
+                    "error.too_many_contacts",
+                    &[("max", &max.to_string())],
+                );
+            }
+            MobileError::IdentityNotFound => "error.not_set_up",
+            MobileError::AlreadyInitialized => "error.already_set_up",
+            MobileError::ContactNotFound(_) => "error.contact_not_found",
+            MobileError::InvalidQrCode => "error.invalid_qr",
+            MobileError::ExchangeFailed(_) => "error.exchange_failed",
+            MobileError::SyncFailed(_) => "error.sync_failed",
+            MobileError::StorageError(_) => "error.storage",
+            MobileError::NetworkError(_) => "error.offline",
+            MobileError::InsecureTransport(_) => "error.insecure_connection",
+            MobileError::FieldNotFound(_) => "error.field_not_found",
+            MobileError::AlreadyValidated => "error.already_verified",
+            MobileError::ClaimExpired => "error.claim_expired",
+            MobileError::ClockRolledBack(_) => "error.clock",
+            MobileError::RateLimited(_) => "error.rate_limited",
+            MobileError::DuplicateLabelName(_) => "error.duplicate_label",
+            MobileError::SelfContact => "error.self_contact",
+            MobileError::SelfVouching => "error.self_vouching",
+            MobileError::DuplicateVoucher => "error.duplicate_voucher",
+            MobileError::VoucherMismatch => "error.voucher_mismatch",
+            MobileError::InvalidInput(_) => "error.validation",
+            MobileError::NotInitialized
+            | MobileError::CryptoError(_)
+            | MobileError::SerializationError(_)
+            | MobileError::Internal(_) => "error.generic",
+        };
+        i18n::get_string(locale, key)
+    }
+}
+
+impl From<vauchi_core::ContactError> for MobileError {
+    fn from(err: vauchi_core::ContactError) -> Self {
+        match err {
+            vauchi_core::ContactError::SelfContact => MobileError::SelfContact,
+        }
+    }
+}
+
+impl From<vauchi_core::RecoveryError> for MobileError {
+    fn from(err: vauchi_core::RecoveryError) -> Self {
+        use vauchi_core::RecoveryError;
+        match err {
+            RecoveryError::ClaimExpired | RecoveryError::ProofExpired => MobileError::ClaimExpired,
+            RecoveryError::RateLimitExceeded => MobileError::RateLimited(err.to_string()),
+            RecoveryError::SelfVouching => MobileError::SelfVouching,
+            RecoveryError::DuplicateVoucher => MobileError::DuplicateVoucher,
+            RecoveryError::MismatchedKeys => MobileError::VoucherMismatch,
+            _ => MobileError::InvalidInput(err.to_string()),
+        }
+    }
+}
+
 impl From<vauchi_core::StorageError> for MobileError {
     fn from(err: vauchi_core::StorageError) -> Self {
-        MobileError::StorageError(err.to_string())
+        match err {
+            vauchi_core::StorageError::Label(vauchi_core::LabelError::MaxLabelsReached) => {
+                MobileError::TooManyLabels(vauchi_core::MAX_LABELS as u32)
+            }
+            vauchi_core::StorageError::Label(vauchi_core::LabelError::DuplicateName(name)) => {
+                MobileError::DuplicateLabelName(name)
+            }
+            vauchi_core::StorageError::ContactLimitReached(limit) => {
+                MobileError::TooManyContacts(limit as u32)
+            }
+            _ => MobileError::StorageError(err.to_string()),
+        }
     }
 }
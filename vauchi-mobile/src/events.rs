@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Mobile Events
+//!
+//! A single stream of state changes for the UI, so it does not have to
+//! poll the individual status getters. Unlike telemetry, events describe
+//! the user's own data and stay on the device.
+
+use crate::relay_health::MobileRelayStatus;
+use crate::types::MobileRecoveryProgress;
+
+/// Something the UI may want to react to.
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum MobileVauchiEvent {
+    /// An identity was created on this device.
+    IdentityCreated,
+    /// A sync with the relay started.
+    SyncStarted,
+    /// A sync with the relay finished.
+    SyncFinished {
+        success: bool,
+        contacts_added: u32,
+        cards_updated: u32,
+        updates_sent: u32,
+    },
+    /// A contact was added, by an exchange or during sync.
+    ContactAdded { contact_id: String },
+    /// A contact's card update was applied during sync.
+    UpdateApplied { contact_id: String },
+    /// A contact's card update could not be applied during sync.
+    UpdateFailed { contact_id: String, error: String },
+    /// A voucher was added to the recovery in progress.
+    RecoveryProgress { progress: MobileRecoveryProgress },
+    /// A relay health check found the relay's reachability changed.
+    RelayStatusChanged { status: MobileRelayStatus },
+}
+
+/// Callback interface for receiving events.
+///
+/// Implement this in Swift (iOS) or Kotlin (Android). Called inline from
+/// the method that caused the event, so implementations must not block.
+#[uniffi::export(callback_interface)]
+pub trait VauchiEventObserver: Send + Sync {
+    /// Called for every event.
+    fn on_event(&self, event: MobileVauchiEvent);
+}
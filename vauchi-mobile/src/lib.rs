@@ -10,20 +10,18 @@
 //! Note: Storage connections are created on-demand for thread safety,
 //! as rusqlite's Connection is not Sync.
 
-use std::net::TcpStream;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
-
-use tungstenite::stream::MaybeTlsStream;
-use tungstenite::{Message, WebSocket};
 
+use vauchi_core::contact_card::{is_valid_public_handle, MAX_HANDLE_LENGTH, MIN_HANDLE_LENGTH};
 use vauchi_core::crypto::ratchet::DoubleRatchetState;
 use vauchi_core::exchange::{DeviceLinkQR, EncryptedExchangeMessage};
-use vauchi_core::recovery::{RecoveryClaim, RecoveryProof, RecoveryVoucher};
+use vauchi_core::recovery::{RecoveryClaim, RecoveryConflict, RecoveryProof, RecoveryVoucher};
+use vauchi_core::storage::{PublicCardPublication, UsageCounter};
 use vauchi_core::{
-    Contact, ContactCard, ContactField, Identity, IdentityBackup, SocialNetworkRegistry, Storage,
-    SymmetricKey,
+    Contact, ContactCard, ContactField, FieldType, Identity, IdentityBackup, PublicCard,
+    PublicCardRemoval, SocialNetworkRegistry, Storage, SymmetricKey, TrustThresholds,
+    ValidationBundle, ValidationRateLimiter, VerificationAttestation,
 };
 
 #[cfg(feature = "content-updates")]
@@ -35,8 +33,14 @@ mod audio;
 mod cert_pinning;
 mod content;
 mod error;
+mod events;
+mod pool;
 mod protocol;
+mod relay_health;
 mod sync;
+mod sync_health;
+mod telemetry;
+mod transport;
 mod types;
 
 // Re-export public types
@@ -46,21 +50,58 @@ pub use content::{
     MobileUpdateStatus,
 };
 pub use error::MobileError;
+pub use events::{MobileVauchiEvent, VauchiEventObserver};
+use pool::{PooledStorage, StoragePool};
+pub use relay_health::{MobileRelayState, MobileRelayStatus};
+pub use sync_health::{MobileSyncHealth, MobileSyncHealthReason, MobileSyncHealthStatus};
+pub use telemetry::{MobileTelemetryEvent, MobileTelemetryHandler};
+use transport::{RelayConnector, WebSocketConnector};
 pub use types::{
-    MobileAhaMoment, MobileAhaMomentType, MobileContact, MobileContactCard, MobileContactField,
-    MobileDeliveryRecord, MobileDeliveryStatus, MobileDeliverySummary, MobileDemoContact,
-    MobileDemoContactState, MobileDeviceDeliveryRecord, MobileDeviceDeliveryStatus,
-    MobileDeviceInfo, MobileDeviceLinkData, MobileDeviceLinkInfo, MobileDeviceLinkResult,
-    MobileExchangeData, MobileExchangeResult, MobileFaqItem, MobileFieldType,
-    MobileFieldValidation, MobileHelpCategory, MobileHelpCategoryInfo, MobileLocale,
-    MobileLocaleInfo, MobileRecoveryClaim, MobileRecoveryProgress, MobileRecoveryVerification,
-    MobileRecoveryVoucher, MobileRetryEntry, MobileSocialNetwork, MobileSyncResult,
-    MobileSyncStatus, MobileTheme, MobileThemeColors, MobileThemeMode, MobileTrustLevel,
-    MobileValidationStatus, MobileVisibilityLabel, MobileVisibilityLabelDetail,
+    MobileAhaMoment, MobileAhaMomentType, MobileClockStatus, MobileCompletenessItem,
+    MobileCompletenessReport, MobileConflictingClaim, MobileContact, MobileContactCard,
+    MobileContactField, MobileContactSyncOutcome, MobileDefaultFieldVisibility,
+    MobileDeletedContact, MobileDeliveryReceipt, MobileDeliveryRecord, MobileDeliveryStatus,
+    MobileDeliverySummary, MobileDemoAction, MobileDemoContact, MobileDemoContactState,
+    MobileDeviceDeliveryRecord, MobileDeviceDeliveryStatus, MobileDeviceInfo, MobileDeviceLinkData,
+    MobileDeviceLinkInfo, MobileDeviceLinkResult, MobileExchangeData, MobileExchangeOutcomeKind,
+    MobileExchangeResult, MobileFaqItem, MobileFetchedRecoveryProof, MobileFieldType,
+    MobileFieldValidation, MobileHelpCategory, MobileHelpCategoryInfo, MobileIntegrityFailure,
+    MobileIntegrityReport, MobileInviteSuggestion, MobileLocale, MobileLocaleInfo,
+    MobileNativeContact, MobileQrErrorCorrection, MobileRecoveryClaim, MobileRecoveryConflict,
+    MobileRecoveryProgress, MobileRecoveryProofSet, MobileRecoveryVerification,
+    MobileRecoveryVoucher, MobileRetryEntry, MobileSafeFieldValue, MobileSocialNetwork,
+    MobileStaleUpdate, MobileStaleUpdateState, MobileSyncResult, MobileSyncStatus, MobileTheme,
+    MobileThemeColors, MobileThemeMode, MobileTrustLevel, MobileTrustThresholds,
+    MobileUpdateFailure, MobileUsageStats, MobileValidationStatus, MobileVisibilityLabel,
+    MobileVisibilityLabelDetail,
 };
 
 uniffi::setup_scaffolding!();
 
+/// Default clock difference tolerated when checking QR, claim, and proof expiry.
+const DEFAULT_CLOCK_SKEW_TOLERANCE_SECS: u64 = 60;
+
+/// Send attempts after which sync stops retrying an exchange message.
+const DEFAULT_EXCHANGE_RETRY_LIMIT: u32 = 10;
+
+/// Settings key for the requirements for showing a field as trusted.
+const SETTING_TRUST_THRESHOLDS: &str = "trust_thresholds";
+
+/// Settings key for the clock difference tolerated in expiry checks.
+const SETTING_CLOCK_SKEW_TOLERANCE: &str = "clock_skew_tolerance";
+
+/// Settings key for the send attempts sync makes for an exchange message.
+const SETTING_EXCHANGE_RETRY_LIMIT: &str = "exchange_retry_limit";
+
+/// Settings key for whether outgoing card updates are padded.
+const SETTING_PAD_UPDATES: &str = "pad_updates";
+
+/// Settings key for whether relay connections must use TLS.
+const SETTING_REQUIRE_TLS: &str = "require_tls";
+
+/// Settings key for the language code of generated content.
+const SETTING_LOCALE: &str = "locale";
+
 // === Password Strength ===
 
 /// Password strength level for display to users.
@@ -194,6 +235,19 @@ pub fn is_blocked_scheme(scheme: String) -> bool {
     vauchi_core::is_blocked_scheme(&scheme)
 }
 
+/// Render QR data (e.g. from `generate_exchange_qr`) as a block-character string.
+///
+/// Higher error-correction levels survive damage and glare on printed codes
+/// but hold less data; if the data does not fit, try a lower level.
+#[uniffi::export]
+pub fn render_qr_code(
+    qr_data: String,
+    level: MobileQrErrorCorrection,
+) -> Result<String, MobileError> {
+    vauchi_core::exchange::render_qr_string(&qr_data, level.into())
+        .map_err(|e| MobileError::InvalidInput(e.to_string()))
+}
+
 // ============================================================
 // Theme Functions
 // ============================================================
@@ -276,6 +330,15 @@ pub fn parse_locale_code(code: String) -> Option<MobileLocale> {
     vauchi_core::i18n::Locale::from_code(&code).map(MobileLocale::from)
 }
 
+/// Get a friendly, localized explanation of an error to show the user.
+///
+/// Use this instead of the error's description, which is meant for logs.
+/// Falls back to English if the message is not translated.
+#[uniffi::export]
+pub fn get_error_user_message(error: MobileError, locale: MobileLocale) -> String {
+    error.user_message(locale)
+}
+
 // ============================================================
 // Help Functions
 // ============================================================
@@ -337,28 +400,89 @@ pub fn search_faqs(query: String) -> Vec<MobileFaqItem> {
 #[derive(uniffi::Object)]
 pub struct VauchiMobile {
     storage_path: PathBuf,
-    storage_key: SymmetricKey,
-    relay_url: String,
+    storage_key: Mutex<SymmetricKey>,
+    /// Reusable connections to the database at `storage_path`.
+    storage_pool: StoragePool,
+    relay_url: Mutex<String>,
     /// Optional PEM-encoded certificate for TLS pinning.
     pinned_cert_pem: Mutex<Option<String>>,
+    /// Pinned relay public key for verifying delivery receipts.
+    relay_receipt_key: Mutex<Option<vauchi_core::crypto::PublicKey>>,
+    /// Reject plaintext connections to remote relays.
+    require_tls: Mutex<bool>,
     identity_data: Mutex<Option<IdentityData>>,
     social_registry: SocialNetworkRegistry,
     sync_status: Mutex<MobileSyncStatus>,
+    /// Locale for generated content (demo tips, aha moments).
+    locale: Mutex<vauchi_core::i18n::Locale>,
+    /// Relay connector override (tests inject an in-memory relay).
+    relay_connector: Option<Arc<dyn RelayConnector>>,
+    /// Last relay health check, reused while fresh or backing off.
+    relay_health: Mutex<relay_health::RelayHealthCache>,
+    /// Decoy identity backup and password for deniable exports.
+    /// Held in memory only, so nothing about it is written to disk.
+    decoy_backup: Mutex<Option<(Vec<u8>, String)>>,
+    /// Password key of the exchange we offered, if any.
+    /// Held in memory only; it accepts a single answer.
+    password_exchange: Mutex<Option<vauchi_core::exchange::PasswordKey>>,
+    /// Requirements for showing a contact's field as trusted.
+    trust_thresholds: Mutex<TrustThresholds>,
+    /// Seconds of clock difference tolerated in expiry checks.
+    clock_skew_tolerance: Mutex<u64>,
+    /// Send attempts after which sync stops retrying an exchange message.
+    exchange_retry_limit: Mutex<u32>,
+    /// Whether outgoing card updates are padded to size buckets.
+    pad_updates: Mutex<bool>,
+    /// Local time to use instead of the system clock, for tests.
+    clock_override: Mutex<Option<u64>>,
+    /// Receiver for non-identifying metrics (no-op until a handler is set).
+    telemetry: Mutex<Arc<dyn vauchi_core::api::Telemetry>>,
+    /// Receiver for UI events, if one is set.
+    event_observer: Mutex<Option<Arc<dyn VauchiEventObserver>>>,
 }
 
 impl VauchiMobile {
-    /// Opens a storage connection.
-    fn open_storage(&self) -> Result<Storage, MobileError> {
-        Storage::open(&self.storage_path, self.storage_key.clone())
+    /// Checks out a storage connection from the pool.
+    fn open_storage(&self) -> Result<PooledStorage<'_>, MobileError> {
+        self.storage_pool.get()
+    }
+
+    /// Get the key the database is encrypted with.
+    fn storage_key(&self) -> SymmetricKey {
+        self.storage_key.lock().unwrap().clone()
+    }
+
+    /// Open storage without write access, for diagnostics and exports.
+    fn open_storage_read_only(&self) -> Result<Storage, MobileError> {
+        Storage::open_read_only(&self.storage_path, self.storage_key())
             .map_err(|e| MobileError::StorageError(e.to_string()))
     }
 
-    /// Connect to relay with optional certificate pinning.
-    fn connect_to_relay(&self) -> Result<WebSocket<MaybeTlsStream<TcpStream>>, MobileError> {
-        let pinned_cert = self.pinned_cert_pem.lock().unwrap();
-        let cert_pem = pinned_cert.as_deref();
-        cert_pinning::connect_with_pinning(&self.relay_url, cert_pem)
-            .map_err(MobileError::NetworkError)
+    /// Get the connector used to reach the relay.
+    ///
+    /// Defaults to WebSocket with optional certificate pinning.
+    fn relay_connector(&self) -> Arc<dyn RelayConnector> {
+        match &self.relay_connector {
+            Some(connector) => connector.clone(),
+            None => Arc::new(WebSocketConnector::new(
+                &self.get_relay_url(),
+                self.get_pinned_cert(),
+                self.is_tls_required(),
+            )),
+        }
+    }
+
+    /// Create an instance that reaches the relay through the given connector.
+    #[cfg(test)]
+    fn new_with_transport(
+        data_dir: String,
+        connector: Arc<dyn RelayConnector>,
+    ) -> Result<Arc<Self>, MobileError> {
+        let mut instance = Self::new(data_dir, "mock://relay".to_string())?;
+        Arc::get_mut(&mut instance)
+            .expect("freshly created instance is not shared")
+            .relay_connector = Some(connector);
+        Ok(instance)
     }
 
     /// Gets the identity from stored data.
@@ -371,11 +495,304 @@ impl VauchiMobile {
             .map_err(|e| MobileError::CryptoError(e.to_string()))
     }
 
+    /// Completes an exchange from scanned QR data (see `complete_exchange`).
+    fn run_exchange(&self, qr_data: &str) -> Result<MobileExchangeResult, MobileError> {
+        use vauchi_core::ExchangeQR;
+
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+
+        let data_str = qr_data.strip_prefix("wb://").unwrap_or(qr_data);
+        let their_qr =
+            ExchangeQR::from_data_string(data_str).map_err(|_| MobileError::InvalidQrCode)?;
+
+        if their_qr.is_expired_at(self.trusted_now(), self.get_clock_skew_tolerance()) {
+            return Err(MobileError::ExchangeFailed("QR code expired".to_string()));
+        }
+
+        self.exchange_with(
+            &identity,
+            &storage,
+            their_qr.public_key(),
+            their_qr.exchange_key(),
+            their_qr.intro_note(),
+            |our_x3dh| {
+                EncryptedExchangeMessage::create(
+                    our_x3dh,
+                    their_qr.exchange_key(),
+                    identity.signing_public_key(),
+                    identity.display_name(),
+                )
+                .map(|(msg, secret)| (msg.to_bytes(), secret, None))
+            },
+        )
+    }
+
+    /// Completes a password exchange (see `complete_password_exchange`).
+    fn run_password_exchange(
+        &self,
+        their_data: &str,
+        password: &str,
+    ) -> Result<MobileExchangeResult, MobileError> {
+        use vauchi_core::exchange::{PasswordExchangeMessage, PasswordExchangeOffer};
+
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+
+        let data_str = their_data.strip_prefix("wbp://").unwrap_or(their_data);
+        let offer = PasswordExchangeOffer::from_data_string(data_str)
+            .map_err(|_| MobileError::InvalidQrCode)?;
+
+        self.exchange_with(
+            &identity,
+            &storage,
+            offer.identity_key(),
+            offer.exchange_key(),
+            None,
+            |our_x3dh| {
+                PasswordExchangeMessage::create(
+                    &offer,
+                    password,
+                    our_x3dh,
+                    identity.signing_public_key(),
+                    identity.display_name(),
+                )
+                .map(|(msg, secret, expected)| (msg.to_bytes(), secret, Some(expected.to_bytes())))
+            },
+        )
+    }
+
+    /// Saves the contact for an exchange we complete and sends it our
+    /// exchange message.
+    ///
+    /// `create_message` returns the serialized message, the contact's
+    /// shared secret and, for a password exchange, the confirmation the
+    /// contact must send back before it counts as confirmed.
+    ///
+    /// An existing contact is kept as is; only an undelivered exchange
+    /// message to them is sent again.
+    fn exchange_with<F>(
+        &self,
+        identity: &Identity,
+        storage: &Storage,
+        their_signing_key: &[u8; 32],
+        their_exchange_key: &[u8; 32],
+        intro_note: Option<&str>,
+        create_message: F,
+    ) -> Result<MobileExchangeResult, MobileError>
+    where
+        F: FnOnce(
+            &vauchi_core::exchange::X3DHKeyPair,
+        ) -> Result<
+            (Vec<u8>, SymmetricKey, Option<Vec<u8>>),
+            vauchi_core::exchange::ExchangeError,
+        >,
+    {
+        let their_public_id = hex::encode(their_signing_key);
+
+        vauchi_core::contact::check_not_self(their_signing_key, identity.signing_public_key())?;
+
+        // A reference contact is replaced by the exchanged one
+        if let Some(existing) = storage
+            .load_contact(&their_public_id)?
+            .filter(|c| !c.is_reference())
+        {
+            // Resend an exchange message that never reached the relay, so
+            // the contact can still complete their side
+            let error_message = match storage
+                .load_pending_exchange(&their_public_id)?
+                .filter(|pending| !pending.is_delivered())
+            {
+                Some(pending) => self
+                    .deliver_pending_exchange(storage, &identity.public_id(), &pending)
+                    .err()
+                    .map(|e| e.to_string()),
+                None => None,
+            };
+            return Ok(MobileExchangeResult {
+                contact_id: their_public_id,
+                contact_name: existing.display_name().to_string(),
+                success: error_message.is_none(),
+                error_message,
+                kind: MobileExchangeOutcomeKind::AlreadyExists,
+            });
+        }
+        let kind = if storage.was_contact_deleted(&their_public_id)? {
+            MobileExchangeOutcomeKind::Reconnected
+        } else {
+            MobileExchangeOutcomeKind::New
+        };
+
+        let (message, shared_secret, expected_confirmation) =
+            create_message(&identity.x3dh_keypair()).map_err(|e| {
+                MobileError::ExchangeFailed(format!("Key agreement failed: {:?}", e))
+            })?;
+
+        let their_card = ContactCard::new("New Contact");
+        let contact = Contact::from_exchange(*their_signing_key, their_card, shared_secret.clone());
+
+        let contact_id = contact.id().to_string();
+        let contact_name = contact.display_name().to_string();
+
+        storage.atomically(|| {
+            storage.save_contact(&contact)?;
+            if let Some(expected) = &expected_confirmation {
+                storage.save_expected_confirmation(&contact_id, expected)?;
+            }
+            if let Some(note) = intro_note {
+                let encrypted = vauchi_core::crypto::encrypt(&self.storage_key(), note.as_bytes())
+                    .map_err(|e| MobileError::CryptoError(e.to_string()))?;
+                storage.save_personal_notes(&contact_id, &encrypted)?;
+            }
+
+            let ratchet =
+                DoubleRatchetState::initialize_initiator(&shared_secret, *their_exchange_key);
+            storage.save_ratchet_state(&contact_id, &ratchet, true)?;
+
+            // Persist the exchange message first, so an interrupted send can
+            // be resumed with resume_pending_exchanges()
+            storage.save_pending_exchange(&contact_id, &message)?;
+            Ok::<_, MobileError>(())
+        })?;
+        let pending = storage
+            .load_pending_exchange(&contact_id)?
+            .ok_or_else(|| MobileError::Internal("Pending exchange not saved".to_string()))?;
+
+        let error_message = self
+            .deliver_pending_exchange(storage, &identity.public_id(), &pending)
+            .err()
+            .map(|e| e.to_string());
+
+        Ok(MobileExchangeResult {
+            contact_id,
+            contact_name,
+            success: error_message.is_none(),
+            error_message,
+            kind,
+        })
+    }
+
+    /// The stored daily validation limit, or the default.
+    fn validation_limiter(storage: &Storage) -> Result<ValidationRateLimiter, MobileError> {
+        Ok(storage
+            .load_validation_daily_limit()?
+            .map_or_else(ValidationRateLimiter::default, ValidationRateLimiter::new))
+    }
+
+    /// Restores the settings saved by earlier sessions.
+    ///
+    /// Settings never changed keep their defaults.
+    fn load_settings(&self) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+        if let Some(thresholds) = storage.load_setting(SETTING_TRUST_THRESHOLDS)? {
+            *self.trust_thresholds.lock().unwrap() = thresholds;
+        }
+        if let Some(seconds) = storage.load_setting(SETTING_CLOCK_SKEW_TOLERANCE)? {
+            *self.clock_skew_tolerance.lock().unwrap() = seconds;
+        }
+        if let Some(max_attempts) = storage.load_setting(SETTING_EXCHANGE_RETRY_LIMIT)? {
+            *self.exchange_retry_limit.lock().unwrap() = max_attempts;
+        }
+        if let Some(enabled) = storage.load_setting(SETTING_PAD_UPDATES)? {
+            *self.pad_updates.lock().unwrap() = enabled;
+        }
+        if let Some(require) = storage.load_setting(SETTING_REQUIRE_TLS)? {
+            *self.require_tls.lock().unwrap() = require;
+        }
+        if let Some(code) = storage.load_setting::<String>(SETTING_LOCALE)? {
+            *self.locale.lock().unwrap() =
+                vauchi_core::i18n::Locale::from_code(&code).unwrap_or_default();
+        }
+        Ok(())
+    }
+
+    /// Current time for expiry checks.
+    ///
+    /// Uses the local clock, moved forward when a verified relay receipt
+    /// shows it lagging by more than the skew tolerance.
+    fn trusted_now(&self) -> u64 {
+        let now = self.local_now();
+        let lag = self.relay_clock_lag().unwrap_or(0);
+        if lag > self.get_clock_skew_tolerance() {
+            now + lag
+        } else {
+            now
+        }
+    }
+
+    /// Current time according to the device clock.
+    fn local_now(&self) -> u64 {
+        self.clock_override.lock().unwrap().unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        })
+    }
+
+    /// Replaces the device clock, e.g. to simulate it being set back.
+    #[cfg(test)]
+    fn set_clock_for_testing(&self, now: Option<u64>) {
+        *self.clock_override.lock().unwrap() = now;
+    }
+
+    /// Fails if the device clock reads earlier than a time it reported
+    /// before, so nothing is timestamped before what was already issued.
+    fn check_clock(&self) -> Result<(), MobileError> {
+        let now = self.local_now();
+        match self
+            .open_storage()?
+            .observe_clock(now, self.get_clock_skew_tolerance())?
+        {
+            Some(latest_seen) => Err(MobileError::ClockRolledBack(latest_seen - now)),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the installed telemetry hook.
+    fn telemetry(&self) -> Arc<dyn vauchi_core::api::Telemetry> {
+        self.telemetry.lock().unwrap().clone()
+    }
+
+    /// Emit `ContactAdded` for an exchange that saved a contact.
+    fn emit_exchange_result(&self, result: &Result<MobileExchangeResult, MobileError>) {
+        if let Ok(r) = result {
+            if r.success && r.kind != MobileExchangeOutcomeKind::AlreadyExists {
+                self.count_usage(UsageCounter::ExchangesCompleted, 1);
+                self.emit(MobileVauchiEvent::ContactAdded {
+                    contact_id: r.contact_id.clone(),
+                });
+            }
+        }
+    }
+
+    /// Add to a local usage counter.
+    ///
+    /// Counting is best-effort and never fails the operation counted.
+    fn count_usage(&self, counter: UsageCounter, amount: u64) {
+        if let Ok(storage) = self.open_storage() {
+            let _ = storage.increment_usage_counter(counter, amount);
+        }
+    }
+
+    /// Deliver an event to the observer, if one is set.
+    fn emit(&self, event: MobileVauchiEvent) {
+        let observer = self.event_observer.lock().unwrap().clone();
+        if let Some(observer) = observer {
+            observer.on_event(event);
+        }
+    }
+
     /// Get pinned certificate if set.
     fn get_pinned_cert(&self) -> Option<String> {
         self.pinned_cert_pem.lock().unwrap().clone()
     }
 
+    /// Get the locale for generated content.
+    fn locale(&self) -> vauchi_core::i18n::Locale {
+        *self.locale.lock().unwrap()
+    }
+
     /// Get the path to the recovery proof file.
     fn recovery_proof_path(&self) -> PathBuf {
         self.storage_path
@@ -384,6 +801,266 @@ impl VauchiMobile {
             .join(".recovery_proof")
     }
 
+    /// Get the path to the last successful sync timestamp file.
+    fn last_sync_path(&self) -> PathBuf {
+        self.storage_path
+            .parent()
+            .unwrap_or(&self.storage_path)
+            .join(".last_sync")
+    }
+
+    /// Load the Unix timestamp of the last successful sync, if any.
+    fn load_last_sync(&self) -> Option<u64> {
+        std::fs::read_to_string(self.last_sync_path())
+            .ok()
+            .and_then(|data| data.trim().parse().ok())
+    }
+
+    /// Record the Unix timestamp of a successful sync.
+    fn save_last_sync(&self, timestamp: u64) -> Result<(), MobileError> {
+        std::fs::write(self.last_sync_path(), timestamp.to_string())
+            .map_err(|e| MobileError::StorageError(e.to_string()))
+    }
+
+    // === Field Validation (internal helpers) ===
+
+    /// Aggregates validations of a field, applying the trust thresholds.
+    fn field_validation_status(
+        &self,
+        storage: &Storage,
+        contact_id: &str,
+        field_id: &str,
+        field_value: &str,
+    ) -> Result<vauchi_core::social::ValidationStatus, MobileError> {
+        let validations = storage.load_validations_for_field(contact_id, field_id)?;
+
+        // Get current user's ID if available
+        let my_id = self
+            .get_identity()
+            .ok()
+            .map(|identity| hex::encode(identity.signing_public_key()));
+
+        // Validators whose fingerprint we checked ourselves, not via an attester
+        let in_person: std::collections::HashSet<String> = storage
+            .list_contacts()?
+            .iter()
+            .filter(|c| c.is_fingerprint_verified() && c.verified_by().is_none())
+            .map(|c| c.id().to_string())
+            .collect();
+
+        let blocked = std::collections::HashSet::new();
+        let thresholds = *self.trust_thresholds.lock().unwrap();
+        let attestations = storage.load_issuer_attestations_for_field(contact_id, field_id)?;
+        Ok(vauchi_core::social::ValidationStatus::from_validations(
+            &validations,
+            field_value,
+            my_id.as_deref(),
+            &blocked,
+            &in_person,
+            &thresholds,
+        )
+        .with_issuer_attestations(&attestations, contact_id, field_id, &thresholds))
+    }
+
+    /// Send a contact's stored exchange message and record its delivery.
+    ///
+    /// The attempt is counted before sending, so failures show up in the
+    /// attempt count even if the app is killed mid-send.
+    fn deliver_pending_exchange(
+        &self,
+        storage: &Storage,
+        our_id: &str,
+        pending: &vauchi_core::PendingExchange,
+    ) -> Result<(), MobileError> {
+        storage.record_exchange_attempt(&pending.contact_id)?;
+
+        let mut transport = self.relay_connector().connect()?;
+        sync::send_handshake(transport.as_mut(), our_id, None, None)?;
+        let result = sync::send_pending_exchange(storage, our_id, pending, transport.as_mut());
+        transport.close();
+        result
+    }
+
+    /// Sync with the relay, processing at most `budget` inbound messages.
+    fn run_sync(&self, budget: Option<sync::SyncBudget>) -> Result<MobileSyncResult, MobileError> {
+        *self.sync_status.lock().unwrap() = MobileSyncStatus::Syncing;
+
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+        let connector = self.relay_connector();
+
+        // Remember the latest clock reading; going back only blocks recovery
+        let _ = storage.observe_clock(self.local_now(), self.get_clock_skew_tolerance());
+
+        let telemetry = self.telemetry();
+        self.emit(MobileVauchiEvent::SyncStarted);
+        let cards_before: std::collections::HashMap<String, [u8; 32]> = storage
+            .list_contacts()?
+            .iter()
+            .map(|c| (c.id().to_string(), c.card().content_hash()))
+            .collect();
+
+        let relay_receipt_key = self.relay_receipt_key.lock().unwrap().clone();
+        let started = std::time::Instant::now();
+        let result = sync::do_sync(
+            &identity,
+            &storage,
+            connector.as_ref(),
+            &self.get_relay_url(),
+            &mut self.password_exchange.lock().unwrap(),
+            *self.exchange_retry_limit.lock().unwrap(),
+            budget,
+            relay_receipt_key.as_ref(),
+            self.local_now(),
+            telemetry.as_ref(),
+        );
+        let duration_ms = started.elapsed().as_millis() as u64;
+        telemetry.record(match &result {
+            Ok(r) => vauchi_core::api::TelemetryEvent::SyncCompleted {
+                duration_ms,
+                messages_sent: r.updates_sent,
+                messages_received: r.contacts_added + r.cards_updated,
+            },
+            Err(_) => vauchi_core::api::TelemetryEvent::SyncFailed { duration_ms },
+        });
+
+        if let Ok(r) = &result {
+            // A failed republish is retried on the next sync
+            let _ = self.republish_public_card(&identity, &storage);
+            // Exchanges started by the other party complete here
+            if r.contacts_added > 0 {
+                self.count_usage(UsageCounter::ExchangesCompleted, r.contacts_added as u64);
+            }
+
+            for contact in storage.list_contacts()? {
+                match cards_before.get(contact.id()) {
+                    None => self.emit(MobileVauchiEvent::ContactAdded {
+                        contact_id: contact.id().to_string(),
+                    }),
+                    Some(hash) if *hash != contact.card().content_hash() => {
+                        self.emit(MobileVauchiEvent::UpdateApplied {
+                            contact_id: contact.id().to_string(),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+            for failure in &r.update_failures {
+                self.emit(MobileVauchiEvent::UpdateFailed {
+                    contact_id: failure.contact_id.clone(),
+                    error: failure.error.clone(),
+                });
+            }
+        }
+        self.emit(match &result {
+            Ok(r) => MobileVauchiEvent::SyncFinished {
+                success: true,
+                contacts_added: r.contacts_added,
+                cards_updated: r.cards_updated,
+                updates_sent: r.updates_sent,
+            },
+            Err(_) => MobileVauchiEvent::SyncFinished {
+                success: false,
+                contacts_added: 0,
+                cards_updated: 0,
+                updates_sent: 0,
+            },
+        });
+
+        match &result {
+            Ok(_) => {
+                *self.sync_status.lock().unwrap() = MobileSyncStatus::Idle;
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs();
+                // The sync itself succeeded; without the timestamp the next
+                // `sync_if_due` merely syncs early
+                let _ = self.save_last_sync(now);
+            }
+            Err(_) => *self.sync_status.lock().unwrap() = MobileSyncStatus::Error,
+        }
+
+        result
+    }
+
+    // === Public Card (internal helpers) ===
+
+    /// Signs the own card's fields shared with `label_id` and publishes
+    /// them at `handle`.
+    fn send_public_card(
+        &self,
+        identity: &Identity,
+        storage: &Storage,
+        handle: &str,
+        label_id: &str,
+    ) -> Result<(), MobileError> {
+        let card = public_card_projection(storage, label_id)?;
+
+        // The relay only accepts a publication newer than the last one
+        let previous = storage.load_public_card()?;
+        let published_at = match &previous {
+            Some(previous) => self.local_now().max(previous.published_at + 1),
+            None => self.local_now(),
+        };
+        let public_card = PublicCard::create(identity, handle, &card, published_at)
+            .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+
+        let connector = self.relay_connector();
+        if let Some(previous) = previous.filter(|p| p.handle != handle) {
+            self.send_public_card_removal(identity, &previous)?;
+        }
+        sync::publish_public_card(&identity.public_id(), &public_card, connector.as_ref())?;
+
+        storage.save_public_card(&PublicCardPublication {
+            handle: handle.to_string(),
+            label_id: label_id.to_string(),
+            card_hash: card.content_hash(),
+            published_at,
+        })?;
+        Ok(())
+    }
+
+    /// Asks the relay to take down a publication.
+    fn send_public_card_removal(
+        &self,
+        identity: &Identity,
+        publication: &PublicCardPublication,
+    ) -> Result<(), MobileError> {
+        let removed_at = self.local_now().max(publication.published_at + 1);
+        let removal = PublicCardRemoval::create(identity, &publication.handle, removed_at);
+        sync::unpublish_public_card(
+            &identity.public_id(),
+            &removal,
+            self.relay_connector().as_ref(),
+        )
+    }
+
+    /// Republishes the public card if the fields it shows have changed.
+    ///
+    /// Returns true if it was republished.
+    fn republish_public_card(
+        &self,
+        identity: &Identity,
+        storage: &Storage,
+    ) -> Result<bool, MobileError> {
+        let Some(publication) = storage.load_public_card()? else {
+            return Ok(false);
+        };
+        let card = public_card_projection(storage, &publication.label_id)?;
+        if card.content_hash() == publication.card_hash {
+            return Ok(false);
+        }
+
+        self.send_public_card(
+            identity,
+            storage,
+            &publication.handle,
+            &publication.label_id,
+        )?;
+        Ok(true)
+    }
+
     // === Aha Moments (internal helpers) ===
 
     /// Get the path to the aha moments state file.
@@ -471,18 +1148,36 @@ impl VauchiMobile {
         })?;
         let storage_key = SymmetricKey::from_bytes(key_array);
 
-        let _storage = Storage::open(&storage_path, storage_key.clone())
-            .map_err(|e| MobileError::StorageError(e.to_string()))?;
+        // Opening once up front runs migrations and surfaces errors early
+        let storage_pool = StoragePool::new(storage_path.clone(), storage_key.clone());
+        drop(storage_pool.get()?);
 
-        Ok(Arc::new(VauchiMobile {
+        let instance = Arc::new(VauchiMobile {
             storage_path,
-            storage_key,
-            relay_url,
+            storage_key: Mutex::new(storage_key),
+            storage_pool,
+            relay_url: Mutex::new(relay_url),
             pinned_cert_pem: Mutex::new(None),
+            relay_receipt_key: Mutex::new(None),
+            require_tls: Mutex::new(!cfg!(debug_assertions)),
             identity_data: Mutex::new(None),
             social_registry: SocialNetworkRegistry::with_defaults(),
             sync_status: Mutex::new(MobileSyncStatus::Idle),
-        }))
+            locale: Mutex::new(vauchi_core::i18n::Locale::default()),
+            relay_connector: None,
+            relay_health: Mutex::new(relay_health::RelayHealthCache::default()),
+            decoy_backup: Mutex::new(None),
+            password_exchange: Mutex::new(None),
+            trust_thresholds: Mutex::new(TrustThresholds::default()),
+            clock_skew_tolerance: Mutex::new(DEFAULT_CLOCK_SKEW_TOLERANCE_SECS),
+            exchange_retry_limit: Mutex::new(DEFAULT_EXCHANGE_RETRY_LIMIT),
+            pad_updates: Mutex::new(false),
+            clock_override: Mutex::new(None),
+            telemetry: Mutex::new(vauchi_core::api::noop_telemetry()),
+            event_observer: Mutex::new(None),
+        });
+        instance.load_settings()?;
+        Ok(instance)
     }
 
     /// Create a new VauchiMobile instance (legacy constructor).
@@ -513,52 +1208,207 @@ impl VauchiMobile {
             key
         };
 
-        let _storage = Storage::open(&storage_path, storage_key.clone())
-            .map_err(|e| MobileError::StorageError(e.to_string()))?;
+        // Opening once up front runs migrations and surfaces errors early
+        let storage_pool = StoragePool::new(storage_path.clone(), storage_key.clone());
+        drop(storage_pool.get()?);
 
-        Ok(Arc::new(VauchiMobile {
+        let instance = Arc::new(VauchiMobile {
             storage_path,
-            storage_key,
-            relay_url,
+            storage_key: Mutex::new(storage_key),
+            storage_pool,
+            relay_url: Mutex::new(relay_url),
             pinned_cert_pem: Mutex::new(None),
+            relay_receipt_key: Mutex::new(None),
+            require_tls: Mutex::new(!cfg!(debug_assertions)),
             identity_data: Mutex::new(None),
             social_registry: SocialNetworkRegistry::with_defaults(),
             sync_status: Mutex::new(MobileSyncStatus::Idle),
-        }))
+            locale: Mutex::new(vauchi_core::i18n::Locale::default()),
+            relay_connector: None,
+            relay_health: Mutex::new(relay_health::RelayHealthCache::default()),
+            decoy_backup: Mutex::new(None),
+            password_exchange: Mutex::new(None),
+            trust_thresholds: Mutex::new(TrustThresholds::default()),
+            clock_skew_tolerance: Mutex::new(DEFAULT_CLOCK_SKEW_TOLERANCE_SECS),
+            exchange_retry_limit: Mutex::new(DEFAULT_EXCHANGE_RETRY_LIMIT),
+            pad_updates: Mutex::new(false),
+            clock_override: Mutex::new(None),
+            telemetry: Mutex::new(vauchi_core::api::noop_telemetry()),
+            event_observer: Mutex::new(None),
+        });
+        instance.load_settings()?;
+        Ok(instance)
     }
 
     /// Export the current storage key bytes for migration to secure storage.
     pub fn export_storage_key(&self) -> Vec<u8> {
-        self.storage_key.as_bytes().to_vec()
+        self.storage_key().as_bytes().to_vec()
     }
 
-    /// Set the pinned certificate for relay TLS connections.
+    /// Re-encrypt all stored data under a new storage key.
     ///
-    /// The certificate should be in PEM format. Once set, only connections
-    /// to relay servers presenting this exact certificate will be allowed.
-    pub fn set_pinned_certificate(&self, cert_pem: String) {
-        let mut pinned = self.pinned_cert_pem.lock().unwrap();
-        if cert_pem.is_empty() {
-            *pinned = None;
-        } else {
-            *pinned = Some(cert_pem);
+    /// For when the current key may be compromised; get a fresh key from
+    /// `generate_storage_key`. A key kept in the legacy `storage.key` file
+    /// is replaced there. Otherwise the platform must save the new key in
+    /// secure storage once this returns, and open with it from then on.
+    pub fn rotate_storage_key(&self, new_key_bytes: Vec<u8>) -> Result<(), MobileError> {
+        let key_array: [u8; 32] = new_key_bytes.try_into().map_err(|_| {
+            MobileError::InvalidInput("Storage key must be exactly 32 bytes".to_string())
+        })?;
+        let new_key = SymmetricKey::from_bytes(key_array);
+        let old_key = self.storage_key();
+
+        // Stage the legacy key file first, so the new key is on disk
+        // before the database depends on it
+        let data_dir = self.storage_path.parent().unwrap_or(&self.storage_path);
+        let key_path = data_dir.join("storage.key");
+        let staged_path = data_dir.join("storage.key.new");
+        let legacy = key_path.exists();
+        if legacy {
+            std::fs::write(&staged_path, new_key.as_bytes())
+                .map_err(|e| MobileError::StorageError(format!("Failed to save key: {}", e)))?;
+        }
+
+        let rotated = self
+            .open_storage()
+            .and_then(|mut storage| Ok(storage.rotate_encryption_key(&old_key, &new_key)?));
+        if let Err(e) = rotated {
+            let _ = std::fs::remove_file(&staged_path);
+            return Err(e);
+        }
+
+        self.storage_pool.set_key(new_key.clone());
+        *self.storage_key.lock().unwrap() = new_key;
+
+        if legacy {
+            std::fs::rename(&staged_path, &key_path)
+                .map_err(|e| MobileError::StorageError(format!("Failed to save key: {}", e)))?;
         }
+        Ok(())
     }
 
-    /// Check if certificate pinning is enabled.
-    pub fn is_certificate_pinning_enabled(&self) -> bool {
-        self.pinned_cert_pem.lock().unwrap().is_some()
+    /// Get the relay URL used for subsequent syncs.
+    pub fn get_relay_url(&self) -> String {
+        self.relay_url.lock().unwrap().clone()
     }
 
-    // === Identity Operations ===
+    /// Change the relay server without recreating the instance.
+    ///
+    /// Only `ws://` and `wss://` URLs are accepted. Syncs already in
+    /// progress finish against the previous relay.
+    pub fn set_relay_url(&self, url: String) -> Result<(), MobileError> {
+        let parsed = url::Url::parse(&url)
+            .map_err(|e| MobileError::InvalidInput(format!("Invalid relay URL: {}", e)))?;
 
-    /// Check if identity exists.
-    pub fn has_identity(&self) -> bool {
-        {
-            let data = self.identity_data.lock().unwrap();
-            if data.is_some() {
-                return true;
-            }
+        if !matches!(parsed.scheme(), "ws" | "wss") || parsed.host_str().is_none() {
+            return Err(MobileError::InvalidInput(
+                "Relay URL must use ws:// or wss://".to_string(),
+            ));
+        }
+
+        *self.relay_url.lock().unwrap() = url;
+        Ok(())
+    }
+
+    /// Check whether the relay can be reached.
+    ///
+    /// Reuses the last result while it is fresh, and after a failure waits
+    /// out a cooldown that doubles with each consecutive failure, so apps
+    /// can call this as often as they like without hammering the relay.
+    pub fn check_relay_health(&self) -> MobileRelayStatus {
+        let relay = self.get_relay_url();
+        if self.relay_health.lock().unwrap().cached(&relay).is_some() {
+            return self.get_cached_relay_status();
+        }
+
+        let previous = self.get_cached_relay_status().state;
+        let reachable = match self.relay_connector().connect() {
+            Ok(mut transport) => {
+                transport.close();
+                true
+            }
+            Err(_) => false,
+        };
+        self.relay_health.lock().unwrap().record(&relay, reachable);
+
+        let status = self.get_cached_relay_status();
+        if status.state != previous {
+            self.emit(MobileVauchiEvent::RelayStatusChanged {
+                status: status.clone(),
+            });
+        }
+        status
+    }
+
+    /// Get the result of the last relay health check without contacting
+    /// the relay.
+    pub fn get_cached_relay_status(&self) -> MobileRelayStatus {
+        let relay = self.get_relay_url();
+        self.relay_health.lock().unwrap().status(&relay)
+    }
+
+    /// Set the pinned certificate for relay TLS connections.
+    ///
+    /// The certificate should be in PEM format. Once set, only connections
+    /// to relay servers presenting this exact certificate will be allowed.
+    pub fn set_pinned_certificate(&self, cert_pem: String) {
+        let mut pinned = self.pinned_cert_pem.lock().unwrap();
+        if cert_pem.is_empty() {
+            *pinned = None;
+        } else {
+            *pinned = Some(cert_pem);
+        }
+    }
+
+    /// Check if certificate pinning is enabled.
+    pub fn is_certificate_pinning_enabled(&self) -> bool {
+        self.pinned_cert_pem.lock().unwrap().is_some()
+    }
+
+    /// Pin the relay's receipt signing key (hex-encoded Ed25519 public key).
+    ///
+    /// Delivery receipts are only exposed once they verify against this key.
+    /// Pass an empty string to clear the pinned key.
+    pub fn set_relay_receipt_key(&self, public_key_hex: String) -> Result<(), MobileError> {
+        let mut pinned = self.relay_receipt_key.lock().unwrap();
+        if public_key_hex.is_empty() {
+            *pinned = None;
+            return Ok(());
+        }
+        let bytes: [u8; 32] = hex::decode(&public_key_hex)
+            .ok()
+            .and_then(|b| b.try_into().ok())
+            .ok_or_else(|| MobileError::InvalidInput("Invalid relay public key".to_string()))?;
+        *pinned = Some(vauchi_core::crypto::PublicKey::from_bytes(bytes));
+        Ok(())
+    }
+
+    /// Require TLS for relay connections.
+    ///
+    /// When enabled, syncing against a `ws://` relay fails unless it runs on
+    /// localhost. Enabled by default in release builds; disable only for
+    /// development against a plaintext relay. Kept across restarts.
+    pub fn set_require_tls(&self, require: bool) -> Result<(), MobileError> {
+        self.open_storage()?
+            .save_setting(SETTING_REQUIRE_TLS, &require)?;
+        *self.require_tls.lock().unwrap() = require;
+        Ok(())
+    }
+
+    /// Check if TLS is required for relay connections.
+    pub fn is_tls_required(&self) -> bool {
+        *self.require_tls.lock().unwrap()
+    }
+
+    // === Identity Operations ===
+
+    /// Check if identity exists.
+    pub fn has_identity(&self) -> bool {
+        {
+            let data = self.identity_data.lock().unwrap();
+            if data.is_some() {
+                return true;
+            }
         }
 
         if let Ok(storage) = self.open_storage() {
@@ -604,9 +1454,38 @@ impl VauchiMobile {
         let card = ContactCard::new(&display_name);
         storage.save_own_card(&card)?;
 
+        self.emit(MobileVauchiEvent::IdentityCreated);
         Ok(())
     }
 
+    /// Rotate to a new identity key, keeping contacts.
+    ///
+    /// Queues a proof signed by both the old and new key to every contact,
+    /// who follow the rotation on their next sync. Returns the new public
+    /// ID. Linked devices must be linked again, and messages contacts send
+    /// to the old ID before applying the proof are not collected.
+    pub fn rotate_identity_key(&self) -> Result<String, MobileError> {
+        let mut identity = self.get_identity()?;
+        let proof = identity.rotate_signing_key();
+
+        let backup_data = identity
+            .export_backup("__internal_storage_key__")
+            .map_err(|e| MobileError::CryptoError(e.to_string()))?
+            .as_bytes()
+            .to_vec();
+        let display_name = identity.display_name().to_string();
+
+        let storage = self.open_storage()?;
+        storage.save_identity(&backup_data, &display_name)?;
+        *self.identity_data.lock().unwrap() = Some(IdentityData {
+            backup_data,
+            display_name,
+        });
+
+        sync::queue_key_rotation(&storage, &proof)?;
+        Ok(identity.public_id())
+    }
+
     /// Get public ID.
     pub fn get_public_id(&self) -> Result<String, MobileError> {
         let identity = self.get_identity()?;
@@ -633,6 +1512,15 @@ impl VauchiMobile {
         Ok(MobileContactCard::from(&card))
     }
 
+    /// Get how complete the own card is, for onboarding progress.
+    pub fn get_card_completeness(&self) -> Result<MobileCompletenessReport, MobileError> {
+        let storage = self.open_storage()?;
+        let card = storage
+            .load_own_card()?
+            .ok_or(MobileError::IdentityNotFound)?;
+        Ok(MobileCompletenessReport::from(&card.completeness()))
+    }
+
     /// Add field to own card.
     pub fn add_field(
         &self,
@@ -647,14 +1535,19 @@ impl VauchiMobile {
             .ok_or(MobileError::IdentityNotFound)?;
 
         let field = ContactField::new(field_type.into(), &label, &value);
+        let field_id = field.id().to_string();
         card.add_field(field)
             .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
 
         storage.save_own_card(&card)?;
+        storage.apply_default_field_visibility(&field_id)?;
         Ok(())
     }
 
     /// Update field value.
+    ///
+    /// Matches the first field with this label; labels may repeat, so prefer
+    /// `update_field_by_id`.
     pub fn update_field(&self, label: String, new_value: String) -> Result<(), MobileError> {
         let storage = self.open_storage()?;
 
@@ -666,7 +1559,7 @@ impl VauchiMobile {
             .fields()
             .iter()
             .find(|f| f.label() == label)
-            .ok_or_else(|| MobileError::InvalidInput(format!("Field '{}' not found", label)))?
+            .ok_or_else(|| MobileError::FieldNotFound(label.clone()))?
             .id()
             .to_string();
 
@@ -677,11 +1570,49 @@ impl VauchiMobile {
         Ok(())
     }
 
+    /// Set the own-card field with this type and label, adding it if absent.
+    ///
+    /// Returns true if the field was created. New fields get the default
+    /// visibility, like `add_field`.
+    pub fn upsert_field(
+        &self,
+        field_type: MobileFieldType,
+        label: String,
+        value: String,
+    ) -> Result<bool, MobileError> {
+        let storage = self.open_storage()?;
+
+        let mut card = storage
+            .load_own_card()?
+            .ok_or(MobileError::IdentityNotFound)?;
+
+        let field_type: FieldType = field_type.into();
+        let created = card
+            .upsert_field(field_type.clone(), &label, &value)
+            .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+
+        storage.save_own_card(&card)?;
+        if created {
+            if let Some(field) = card
+                .fields()
+                .iter()
+                .find(|f| f.field_type() == field_type && f.label() == label)
+            {
+                storage.apply_default_field_visibility(field.id())?;
+            }
+        }
+        Ok(created)
+    }
+
     /// Remove field from card.
+    ///
+    /// Matches the first field with this label; labels may repeat, so prefer
+    /// `remove_field_by_id`. The field's label and per-contact visibility
+    /// entries are removed with it.
     pub fn remove_field(&self, label: String) -> Result<bool, MobileError> {
         let storage = self.open_storage()?;
 
-        let mut card = storage
+        let card = storage
             .load_own_card()?
             .ok_or(MobileError::IdentityNotFound)?;
 
@@ -690,11 +1621,75 @@ impl VauchiMobile {
             None => return Ok(false),
         };
 
-        card.remove_field(&field_id)
+        Ok(storage.remove_own_field(&field_id)?)
+    }
+
+    /// Get a field of own card by ID.
+    pub fn get_field_by_id(
+        &self,
+        field_id: String,
+    ) -> Result<Option<MobileContactField>, MobileError> {
+        let storage = self.open_storage()?;
+        let card = storage
+            .load_own_card()?
+            .ok_or(MobileError::IdentityNotFound)?;
+        Ok(card.field(&field_id).map(MobileContactField::from))
+    }
+
+    /// Update the value of the own-card field with this ID.
+    pub fn update_field_by_id(
+        &self,
+        field_id: String,
+        new_value: String,
+    ) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+
+        let mut card = storage
+            .load_own_card()?
+            .ok_or(MobileError::IdentityNotFound)?;
+        if card.field(&field_id).is_none() {
+            return Err(MobileError::FieldNotFound(field_id));
+        }
+
+        card.update_field_value(&field_id, &new_value)
             .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+
         storage.save_own_card(&card)?;
+        Ok(())
+    }
 
-        Ok(true)
+    /// Make the own-card field with this ID the main one of its type.
+    ///
+    /// The previous primary field of that type, if any, is unset.
+    pub fn set_primary_field(&self, field_id: String) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+
+        let mut card = storage
+            .load_own_card()?
+            .ok_or(MobileError::IdentityNotFound)?;
+        if card.field(&field_id).is_none() {
+            return Err(MobileError::FieldNotFound(field_id));
+        }
+
+        card.set_primary_field(&field_id)
+            .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+
+        storage.save_own_card(&card)?;
+        Ok(())
+    }
+
+    /// Remove the own-card field with this ID, along with its visibility
+    /// entries.
+    ///
+    /// Returns false if no field has this ID.
+    pub fn remove_field_by_id(&self, field_id: String) -> Result<bool, MobileError> {
+        let storage = self.open_storage()?;
+
+        if storage.load_own_card()?.is_none() {
+            return Err(MobileError::IdentityNotFound);
+        }
+
+        Ok(storage.remove_own_field(&field_id)?)
     }
 
     /// Set display name.
@@ -712,6 +1707,131 @@ impl VauchiMobile {
         Ok(())
     }
 
+    /// Get the unpublished own-card draft, if there is one.
+    pub fn get_card_draft(&self) -> Result<Option<MobileContactCard>, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage
+            .load_card_draft()?
+            .map(|card| MobileContactCard::from(&card)))
+    }
+
+    /// Stage edits to the own card without publishing them.
+    ///
+    /// `card` is the whole edited card. Fields are matched to the current
+    /// draft (or the own card, if there is no draft) by ID; fields with an
+    /// unknown or empty ID are added, and fields left out are removed. The
+    /// field type of an existing field cannot change. Contacts see nothing
+    /// until `publish_card_draft`.
+    pub fn save_card_draft(&self, card: MobileContactCard) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+
+        let mut draft = match storage.load_card_draft()? {
+            Some(draft) => draft,
+            None => storage
+                .load_own_card()?
+                .ok_or(MobileError::IdentityNotFound)?,
+        };
+
+        draft
+            .set_display_name(&card.display_name)
+            .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+
+        let kept: std::collections::HashSet<&str> =
+            card.fields.iter().map(|f| f.id.as_str()).collect();
+        let removed: Vec<String> = draft
+            .fields()
+            .iter()
+            .filter(|f| !kept.contains(f.id()))
+            .map(|f| f.id().to_string())
+            .collect();
+        for field_id in &removed {
+            draft
+                .remove_field(field_id)
+                .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+        }
+
+        let mut order = Vec::with_capacity(card.fields.len());
+        let mut primary = Vec::new();
+        for field in card.fields {
+            if draft.field(&field.id).is_some() {
+                draft
+                    .update_field_label(&field.id, &field.label)
+                    .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+                draft
+                    .update_field_value(&field.id, &field.value)
+                    .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+                order.push(field.id);
+            } else {
+                let new_field =
+                    ContactField::new(field.field_type.into(), &field.label, &field.value);
+                order.push(new_field.id().to_string());
+                draft
+                    .add_field(new_field)
+                    .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+            }
+            if field.is_primary {
+                primary.push(order.last().cloned().unwrap_or_default());
+            }
+        }
+
+        // Primary flags as given; the last one of a type wins
+        let field_types: Vec<FieldType> = draft.fields().iter().map(|f| f.field_type()).collect();
+        for field_type in &field_types {
+            draft.clear_primary_field(field_type);
+        }
+        for field_id in &primary {
+            draft
+                .set_primary_field(field_id)
+                .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+        }
+        let order: Vec<&str> = order.iter().map(String::as_str).collect();
+        draft
+            .reorder_fields(&order)
+            .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+
+        storage.save_card_draft(&draft)?;
+        Ok(())
+    }
+
+    /// Throw away the own-card draft.
+    ///
+    /// Returns false if there was no draft.
+    pub fn discard_card_draft(&self) -> Result<bool, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.discard_card_draft()?)
+    }
+
+    /// Make the own-card draft the own card and send the changes.
+    ///
+    /// Added fields get the default visibility, like `add_field`, and
+    /// removed fields lose their visibility entries. Each contact is queued
+    /// the changes it may see. Returns the number of contacts queued, or 0
+    /// if there was no draft.
+    pub fn publish_card_draft(&self) -> Result<u32, MobileError> {
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+
+        let live = storage
+            .load_own_card()?
+            .ok_or(MobileError::IdentityNotFound)?;
+        let Some(delta) = storage.publish_card_draft()? else {
+            return Ok(0);
+        };
+
+        for change in &delta.changes {
+            if let vauchi_core::FieldChange::Added { field } = change {
+                if live.field(field.id()).is_none() {
+                    storage.apply_default_field_visibility(field.id())?;
+                }
+            }
+        }
+        let pad = *self.pad_updates.lock().unwrap();
+        let queued = sync::queue_card_delta(&identity, &storage, &delta, None, pad)?;
+        storage.prune_orphaned_visibility()?;
+
+        Ok(queued)
+    }
+
     // === Contact Operations ===
 
     /// List all contacts.
@@ -728,6 +1848,27 @@ impl VauchiMobile {
         Ok(contact.as_ref().map(MobileContact::from))
     }
 
+    /// Get a contact's field value for copying to the clipboard.
+    ///
+    /// Website and social values that hide a blocked scheme or deceptive
+    /// characters come back defanged with `is_safe` false, so the UI can
+    /// warn instead of copying a live link.
+    pub fn get_field_safe_value(
+        &self,
+        contact_id: String,
+        field_id: String,
+    ) -> Result<MobileSafeFieldValue, MobileError> {
+        let storage = self.open_storage()?;
+        let contact = storage
+            .load_contact(&contact_id)?
+            .ok_or(MobileError::ContactNotFound(contact_id))?;
+        let field = contact
+            .card()
+            .field(&field_id)
+            .ok_or(MobileError::FieldNotFound(field_id))?;
+        Ok(field.safe_display_value().into())
+    }
+
     /// Search contacts.
     pub fn search_contacts(&self, query: String) -> Result<Vec<MobileContact>, MobileError> {
         let storage = self.open_storage()?;
@@ -750,80 +1891,298 @@ impl VauchiMobile {
         Ok(contacts.len() as u32)
     }
 
+    /// Get the maximum number of contacts.
+    pub fn get_contact_limit(&self) -> Result<u32, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.get_contact_limit()? as u32)
+    }
+
+    /// Set the maximum number of contacts.
+    ///
+    /// A safety valve against exchange floods on constrained devices.
+    /// Existing contacts are kept; only new contacts beyond the limit fail
+    /// with `MobileError::TooManyContacts`.
+    pub fn set_contact_limit(&self, limit: u32) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+        storage.set_contact_limit(limit as usize)?;
+        Ok(())
+    }
+
     /// Remove contact.
+    ///
+    /// The contact can be restored with `undo_delete_contact` for 30 days.
     pub fn remove_contact(&self, id: String) -> Result<bool, MobileError> {
         let storage = self.open_storage()?;
         let removed = storage.delete_contact(&id)?;
         Ok(removed)
     }
 
-    /// Verify contact fingerprint.
-    pub fn verify_contact(&self, id: String) -> Result<(), MobileError> {
+    /// List contacts deleted within the last 30 days, most recent first.
+    ///
+    /// Older tombstones are purged first.
+    pub fn list_recently_deleted(&self) -> Result<Vec<MobileDeletedContact>, MobileError> {
+        let storage = self.open_storage()?;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        storage.purge_contact_tombstones(now)?;
+        let tombstones = storage.list_contact_tombstones()?;
+        Ok(tombstones.iter().map(MobileDeletedContact::from).collect())
+    }
+
+    /// Restore a recently deleted contact by public key (hex contact ID).
+    ///
+    /// Returns false if the contact cannot be restored, e.g. because the
+    /// retention period has passed or it was re-added since.
+    pub fn undo_delete_contact(&self, public_key: String) -> Result<bool, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.restore_deleted_contact(&public_key)?)
+    }
+
+    /// Block or unblock a contact.
+    ///
+    /// Blocking drops any updates still queued for the contact, so nothing
+    /// more is sent to them on the next sync.
+    pub fn set_contact_blocked(&self, id: String, blocked: bool) -> Result<(), MobileError> {
         let storage = self.open_storage()?;
 
         let mut contact = storage
             .load_contact(&id)?
             .ok_or_else(|| MobileError::ContactNotFound(id.clone()))?;
 
-        contact.mark_fingerprint_verified();
+        if blocked {
+            contact.block();
+            storage.delete_pending_updates_for_contact(&id)?;
+        } else {
+            contact.unblock();
+        }
         storage.save_contact(&contact)?;
 
         Ok(())
     }
 
-    // === Visibility Operations ===
-
-    /// Hide field from contact.
-    pub fn hide_field_from_contact(
-        &self,
-        contact_id: String,
-        field_label: String,
-    ) -> Result<(), MobileError> {
+    /// Pin or unpin a contact to the top of the contact list.
+    ///
+    /// Local to this device; never synced.
+    pub fn set_contact_pinned(&self, contact_id: String, pinned: bool) -> Result<(), MobileError> {
         let storage = self.open_storage()?;
 
         let mut contact = storage
             .load_contact(&contact_id)?
             .ok_or_else(|| MobileError::ContactNotFound(contact_id.clone()))?;
 
-        let card = storage
-            .load_own_card()?
-            .ok_or(MobileError::IdentityNotFound)?;
-        let field = card
-            .fields()
-            .iter()
-            .find(|f| f.label() == field_label)
-            .ok_or_else(|| {
-                MobileError::InvalidInput(format!("Field not found: {}", field_label))
-            })?;
-
-        contact.visibility_rules_mut().set_nobody(field.id());
+        contact.set_pinned(pinned);
         storage.save_contact(&contact)?;
 
         Ok(())
     }
 
-    /// Show field to contact.
-    pub fn show_field_to_contact(
-        &self,
-        contact_id: String,
-        field_label: String,
-    ) -> Result<(), MobileError> {
+    /// Mute or unmute change notifications for a contact.
+    ///
+    /// Updates from a muted contact are still received and applied.
+    /// Local to this device; never synced.
+    pub fn set_contact_muted(&self, contact_id: String, muted: bool) -> Result<(), MobileError> {
         let storage = self.open_storage()?;
 
         let mut contact = storage
             .load_contact(&contact_id)?
             .ok_or_else(|| MobileError::ContactNotFound(contact_id.clone()))?;
 
-        let card = storage
-            .load_own_card()?
-            .ok_or(MobileError::IdentityNotFound)?;
-        let field = card
-            .fields()
-            .iter()
-            .find(|f| f.label() == field_label)
-            .ok_or_else(|| {
-                MobileError::InvalidInput(format!("Field not found: {}", field_label))
-            })?;
+        contact.set_muted(muted);
+        storage.save_contact(&contact)?;
+
+        Ok(())
+    }
+
+    /// Replace the shared secret with a contact by running a fresh X3DH.
+    ///
+    /// Queues a re-key request; the new key takes effect once the contact
+    /// has synced and answered, and the current key stays in use until
+    /// then. Card, labels and verification are kept. Use after accepting a
+    /// recovery, or whenever the old key may have leaked.
+    pub fn rekey_contact(&self, contact_id: String) -> Result<(), MobileError> {
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+
+        let contact = storage
+            .load_contact(&contact_id)?
+            .ok_or_else(|| MobileError::ContactNotFound(contact_id.clone()))?;
+        if contact.is_blocked() {
+            return Err(MobileError::InvalidInput(
+                "Cannot re-key a blocked contact".to_string(),
+            ));
+        }
+
+        sync::queue_rekey_request(&identity, &storage, &contact_id)
+    }
+
+    /// Whether a re-key with this contact is waiting for their answer.
+    pub fn is_rekey_pending(&self, contact_id: String) -> Result<bool, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.load_pending_rekey(&contact_id)?.is_some())
+    }
+
+    /// Verify contact fingerprint.
+    pub fn verify_contact(&self, id: String) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+
+        let mut contact = storage
+            .load_contact(&id)?
+            .ok_or_else(|| MobileError::ContactNotFound(id.clone()))?;
+
+        let newly_verified = !contact.is_fingerprint_verified();
+        contact.mark_fingerprint_verified();
+        storage.save_contact(&contact)?;
+        if newly_verified {
+            storage.increment_usage_counter(UsageCounter::ContactsVerified, 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get verified contacts whose verification is older than `days`.
+    ///
+    /// Use this to prompt the user to periodically re-verify fingerprints.
+    pub fn get_contacts_needing_reverification(
+        &self,
+        days: u32,
+    ) -> Result<Vec<MobileContact>, MobileError> {
+        let storage = self.open_storage()?;
+        let contacts = storage.contacts_needing_reverification(days)?;
+        Ok(contacts.iter().map(MobileContact::from).collect())
+    }
+
+    /// List contacts whose fingerprint has never been verified.
+    pub fn list_unverified_contacts(&self) -> Result<Vec<MobileContact>, MobileError> {
+        let storage = self.open_storage()?;
+        let contacts = storage.list_contacts_by_verification(false)?;
+        Ok(contacts.iter().map(MobileContact::from).collect())
+    }
+
+    /// List contacts that need verifying: those never verified, followed
+    /// by those whose verification is older than `days`.
+    pub fn list_contacts_needing_attention(
+        &self,
+        days: u32,
+    ) -> Result<Vec<MobileContact>, MobileError> {
+        let storage = self.open_storage()?;
+        let contacts = storage.contacts_needing_attention(days)?;
+        Ok(contacts.iter().map(MobileContact::from).collect())
+    }
+
+    /// Get the numeric safety number shared with a contact.
+    ///
+    /// Both parties see the same 60-digit number, so it can be compared
+    /// in person or across apps that use Signal-style safety numbers.
+    pub fn get_safety_number(&self, contact_id: String) -> Result<String, MobileError> {
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+
+        let contact = storage
+            .load_contact(&contact_id)?
+            .ok_or(MobileError::ContactNotFound(contact_id))?;
+
+        Ok(contact.safety_number(identity.signing_public_key()))
+    }
+
+    /// Import a signed verification bundle (base64) from a trusted attester.
+    ///
+    /// The attester must be a contact whose fingerprint you have verified
+    /// yourself; contacts verified through another attester do not count.
+    /// Every listed contact that is not yet verified is marked as verified
+    /// by the attester, at the attestation time or now if that is earlier.
+    /// Returns the number of contacts marked.
+    pub fn import_verification_bundle(&self, bundle_b64: String) -> Result<u32, MobileError> {
+        use base64::Engine;
+        let storage = self.open_storage()?;
+
+        let bundle_bytes = base64::engine::general_purpose::STANDARD
+            .decode(&bundle_b64)
+            .map_err(|e| MobileError::InvalidInput(format!("Invalid base64: {}", e)))?;
+
+        let attestation = VerificationAttestation::from_bytes(&bundle_bytes)
+            .map_err(|e| MobileError::InvalidInput(format!("Invalid bundle: {}", e)))?;
+
+        let trusted_keys: Vec<[u8; 32]> = storage
+            .list_contacts()?
+            .iter()
+            .filter(|c| c.is_fingerprint_verified() && c.verified_by().is_none())
+            .filter(|c| !c.is_blocked())
+            .map(|c| *c.public_key())
+            .collect();
+        attestation
+            .verify_trusted(&trusted_keys)
+            .map_err(|e| MobileError::CryptoError(e.to_string()))?;
+
+        let attester_id = hex::encode(attestation.attester_pk());
+        let verified_at = attestation.timestamp().min(self.local_now());
+        let mut marked = 0u32;
+        for key in attestation.subject_keys() {
+            let Some(mut contact) = storage.load_contact(&hex::encode(key))? else {
+                continue;
+            };
+            if contact.is_fingerprint_verified() {
+                continue;
+            }
+            contact.mark_fingerprint_verified_by(&attester_id, verified_at);
+            storage.save_contact(&contact)?;
+            storage.increment_usage_counter(UsageCounter::ContactsVerified, 1)?;
+            marked += 1;
+        }
+
+        Ok(marked)
+    }
+
+    // === Visibility Operations ===
+
+    /// Hide field from contact.
+    pub fn hide_field_from_contact(
+        &self,
+        contact_id: String,
+        field_label: String,
+    ) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+
+        let mut contact = storage
+            .load_contact(&contact_id)?
+            .ok_or_else(|| MobileError::ContactNotFound(contact_id.clone()))?;
+
+        let card = storage
+            .load_own_card()?
+            .ok_or(MobileError::IdentityNotFound)?;
+        let field = card
+            .fields()
+            .iter()
+            .find(|f| f.label() == field_label)
+            .ok_or_else(|| MobileError::FieldNotFound(field_label.clone()))?;
+
+        contact.visibility_rules_mut().set_nobody(field.id());
+        storage.save_contact(&contact)?;
+
+        Ok(())
+    }
+
+    /// Show field to contact.
+    pub fn show_field_to_contact(
+        &self,
+        contact_id: String,
+        field_label: String,
+    ) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+
+        let mut contact = storage
+            .load_contact(&contact_id)?
+            .ok_or_else(|| MobileError::ContactNotFound(contact_id.clone()))?;
+
+        let card = storage
+            .load_own_card()?
+            .ok_or(MobileError::IdentityNotFound)?;
+        let field = card
+            .fields()
+            .iter()
+            .find(|f| f.label() == field_label)
+            .ok_or_else(|| MobileError::FieldNotFound(field_label.clone()))?;
 
         contact.visibility_rules_mut().set_everyone(field.id());
         storage.save_contact(&contact)?;
@@ -850,11 +2209,9 @@ impl VauchiMobile {
             .fields()
             .iter()
             .find(|f| f.label() == field_label)
-            .ok_or_else(|| {
-                MobileError::InvalidInput(format!("Field not found: {}", field_label))
-            })?;
+            .ok_or_else(|| MobileError::FieldNotFound(field_label.clone()))?;
 
-        Ok(contact.visibility_rules().can_see(field.id(), &contact_id))
+        Ok(storage.load_field_visibility(&contact)?.can_see(field.id()))
     }
 
     // === Visibility Labels ===
@@ -866,13 +2223,53 @@ impl VauchiMobile {
         Ok(labels.iter().map(MobileVisibilityLabel::from).collect())
     }
 
+    /// Get the number of visibility labels.
+    pub fn get_label_count(&self) -> Result<u32, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.count_labels()? as u32)
+    }
+
+    /// Get the maximum number of visibility labels.
+    pub fn get_max_labels(&self) -> u32 {
+        vauchi_core::MAX_LABELS as u32
+    }
+
     /// Create a new visibility label.
+    ///
+    /// Fails with `TooManyLabels` once `get_max_labels()` labels exist, or
+    /// `DuplicateLabelName` if the name is already in use (case-insensitive).
     pub fn create_label(&self, name: String) -> Result<MobileVisibilityLabel, MobileError> {
         let storage = self.open_storage()?;
         let label = storage.create_label(&name)?;
         Ok(MobileVisibilityLabel::from(&label))
     }
 
+    /// Create a new visibility label with a display color and icon.
+    ///
+    /// The color must be a hex string such as `#FF8800`.
+    pub fn create_label_with_style(
+        &self,
+        name: String,
+        color: Option<String>,
+        icon: Option<String>,
+    ) -> Result<MobileVisibilityLabel, MobileError> {
+        let storage = self.open_storage()?;
+        let label = storage.create_label_with_style(&name, color.as_deref(), icon.as_deref())?;
+        Ok(MobileVisibilityLabel::from(&label))
+    }
+
+    /// Update a label's display color and icon; `None` clears them.
+    pub fn update_label_style(
+        &self,
+        label_id: String,
+        color: Option<String>,
+        icon: Option<String>,
+    ) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+        storage.update_label_style(&label_id, color.as_deref(), icon.as_deref())?;
+        Ok(())
+    }
+
     /// Get a label by ID with full details.
     pub fn get_label(&self, label_id: String) -> Result<MobileVisibilityLabelDetail, MobileError> {
         let storage = self.open_storage()?;
@@ -881,6 +2278,9 @@ impl VauchiMobile {
     }
 
     /// Rename a label.
+    ///
+    /// Returns `DuplicateLabelName` if another label already uses the name
+    /// (case-insensitive).
     pub fn rename_label(&self, label_id: String, new_name: String) -> Result<(), MobileError> {
         let storage = self.open_storage()?;
         storage.rename_label(&label_id, &new_name)?;
@@ -916,6 +2316,32 @@ impl VauchiMobile {
         Ok(())
     }
 
+    /// Add several contacts to a label at once.
+    ///
+    /// Fails without changing the label if any contact does not exist.
+    /// Returns the number of contacts newly added.
+    pub fn add_contacts_to_label(
+        &self,
+        label_id: String,
+        contact_ids: Vec<String>,
+    ) -> Result<u32, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.add_contacts_to_label(&label_id, &contact_ids)? as u32)
+    }
+
+    /// Remove several contacts from a label at once.
+    ///
+    /// Fails without changing the label if any contact does not exist.
+    /// Returns the number of contacts removed.
+    pub fn remove_contacts_from_label(
+        &self,
+        label_id: String,
+        contact_ids: Vec<String>,
+    ) -> Result<u32, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.remove_contacts_from_label(&label_id, &contact_ids)? as u32)
+    }
+
     /// Get all labels that contain a contact.
     pub fn get_labels_for_contact(
         &self,
@@ -943,14 +2369,75 @@ impl VauchiMobile {
             .fields()
             .iter()
             .find(|f| f.label() == field_label)
-            .ok_or_else(|| {
-                MobileError::InvalidInput(format!("Field not found: {}", field_label))
-            })?;
+            .ok_or_else(|| MobileError::FieldNotFound(field_label.clone()))?;
 
         storage.set_label_field_visibility(&label_id, field.id(), is_visible)?;
         Ok(())
     }
 
+    /// Get the labels of own card fields shared with a label's members.
+    ///
+    /// Fields no longer on the card are skipped.
+    pub fn get_fields_shared_with_label(
+        &self,
+        label_id: String,
+    ) -> Result<Vec<String>, MobileError> {
+        let storage = self.open_storage()?;
+        let field_ids = storage.fields_shared_with_label(&label_id)?;
+        let card = storage
+            .load_own_card()?
+            .ok_or(MobileError::IdentityNotFound)?;
+
+        Ok(card
+            .fields()
+            .iter()
+            .filter(|f| field_ids.iter().any(|id| id == f.id()))
+            .map(|f| f.label().to_string())
+            .collect())
+    }
+
+    // === Public Card ===
+
+    /// Publish the own card's fields shared with a label at a public
+    /// permalink.
+    ///
+    /// The relay serves the card read-only under `handle`, signed so
+    /// viewers can check it is genuine. Later changes to the card or to
+    /// the label's fields are republished on sync. Publishing under a new
+    /// handle takes down the old one.
+    pub fn publish_public_card(&self, handle: String, label_id: String) -> Result<(), MobileError> {
+        if !is_valid_public_handle(&handle) {
+            return Err(MobileError::InvalidInput(format!(
+                "Handle must be {}-{} lowercase letters, digits or inner hyphens",
+                MIN_HANDLE_LENGTH, MAX_HANDLE_LENGTH
+            )));
+        }
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+        self.send_public_card(&identity, &storage, &handle, &label_id)
+    }
+
+    /// Take down the card published with `publish_public_card`.
+    ///
+    /// The handle stays reserved for this identity on the relay.
+    pub fn unpublish_public_card(&self) -> Result<(), MobileError> {
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+        let publication = storage
+            .load_public_card()?
+            .ok_or_else(|| MobileError::InvalidInput("No public card published".to_string()))?;
+
+        self.send_public_card_removal(&identity, &publication)?;
+        storage.clear_public_card()?;
+        Ok(())
+    }
+
+    /// Get the handle the card is published under, if any.
+    pub fn get_public_card_handle(&self) -> Result<Option<String>, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.load_public_card()?.map(|p| p.handle))
+    }
+
     /// Set a per-contact override for field visibility.
     ///
     /// Per-contact overrides take precedence over label-based visibility.
@@ -969,9 +2456,7 @@ impl VauchiMobile {
             .fields()
             .iter()
             .find(|f| f.label() == field_label)
-            .ok_or_else(|| {
-                MobileError::InvalidInput(format!("Field not found: {}", field_label))
-            })?;
+            .ok_or_else(|| MobileError::FieldNotFound(field_label.clone()))?;
 
         storage.save_contact_override(&contact_id, field.id(), is_visible)?;
         Ok(())
@@ -992,14 +2477,39 @@ impl VauchiMobile {
             .fields()
             .iter()
             .find(|f| f.label() == field_label)
-            .ok_or_else(|| {
-                MobileError::InvalidInput(format!("Field not found: {}", field_label))
-            })?;
+            .ok_or_else(|| MobileError::FieldNotFound(field_label.clone()))?;
 
         storage.delete_contact_override(&contact_id, field.id())?;
         Ok(())
     }
 
+    /// Set the visibility given to fields added to your card.
+    pub fn set_default_field_visibility(
+        &self,
+        policy: MobileDefaultFieldVisibility,
+    ) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+        storage.set_default_field_visibility(&policy.into())?;
+        Ok(())
+    }
+
+    /// Get the visibility given to fields added to your card.
+    pub fn get_default_field_visibility(
+        &self,
+    ) -> Result<MobileDefaultFieldVisibility, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.load_default_field_visibility()?.into())
+    }
+
+    /// Delete visibility entries for fields no longer on the own card.
+    ///
+    /// Maintenance for entries left behind by older field removals.
+    /// Returns the number of entries deleted.
+    pub fn prune_orphaned_visibility(&self) -> Result<u32, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.prune_orphaned_visibility()? as u32)
+    }
+
     /// Get suggested default labels.
     pub fn get_suggested_labels(&self) -> Vec<String> {
         vauchi_core::SUGGESTED_LABELS
@@ -1008,123 +2518,337 @@ impl VauchiMobile {
             .collect()
     }
 
-    // === Exchange Operations ===
+    // === Tag Operations ===
 
-    /// Generate exchange QR data.
-    pub fn generate_exchange_qr(&self) -> Result<MobileExchangeData, MobileError> {
-        let identity = self.get_identity()?;
+    /// Tag a contact with a free-text category.
+    ///
+    /// Tags are for organizing and filtering only; they never affect
+    /// visibility or sync.
+    pub fn add_contact_tag(&self, contact_id: String, tag: String) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+        if storage.load_contact(&contact_id)?.is_none() {
+            return Err(MobileError::ContactNotFound(contact_id));
+        }
+        storage.add_contact_tag(&contact_id, &tag)?;
+        Ok(())
+    }
 
-        let qr = vauchi_core::ExchangeQR::generate(&identity);
-        let qr_data = format!("wb://{}", qr.to_data_string());
+    /// Remove a tag from a contact.
+    pub fn remove_contact_tag(&self, contact_id: String, tag: String) -> Result<(), MobileError> {
+        let storage = self.open_storage()?;
+        storage.remove_contact_tag(&contact_id, &tag)?;
+        Ok(())
+    }
 
-        let expires_at = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs()
-            + 300;
+    /// Get a contact's tags.
+    pub fn get_contact_tags(&self, contact_id: String) -> Result<Vec<String>, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.get_contact_tags(&contact_id)?)
+    }
 
-        Ok(MobileExchangeData {
-            qr_data,
-            public_id: identity.public_id(),
-            expires_at,
-        })
+    /// List contacts with the given tag.
+    pub fn list_contacts_by_tag(&self, tag: String) -> Result<Vec<MobileContact>, MobileError> {
+        let storage = self.open_storage()?;
+        let contacts = storage.list_contacts_by_tag(&tag)?;
+        Ok(contacts.iter().map(MobileContact::from).collect())
     }
 
-    /// Complete exchange with scanned QR data.
-    pub fn complete_exchange(&self, qr_data: String) -> Result<MobileExchangeResult, MobileError> {
-        use vauchi_core::ExchangeQR;
+    // === Address Book ===
 
-        let identity = self.get_identity()?;
+    /// Import contacts from the platform's native address book.
+    ///
+    /// Entries are stored apart from exchanged contacts and never appear in
+    /// `list_contacts`. Re-importing an entry with the same `native_id`
+    /// replaces it. Returns the number of entries imported.
+    pub fn import_native_contacts(
+        &self,
+        entries: Vec<MobileNativeContact>,
+    ) -> Result<u32, MobileError> {
         let storage = self.open_storage()?;
+        let entries: Vec<vauchi_core::AddressBookEntry> =
+            entries.into_iter().map(Into::into).collect();
+        let imported = storage.save_address_book_entries(&entries)?;
+        Ok(imported as u32)
+    }
 
-        let data_str = qr_data.strip_prefix("wb://").unwrap_or(&qr_data);
-        let their_qr =
-            ExchangeQR::from_data_string(data_str).map_err(|_| MobileError::InvalidQrCode)?;
+    /// List imported native address book entries.
+    pub fn list_native_contacts(&self) -> Result<Vec<MobileNativeContact>, MobileError> {
+        let storage = self.open_storage()?;
+        let entries = storage.list_address_book_entries()?;
+        Ok(entries.iter().map(MobileNativeContact::from).collect())
+    }
 
-        if their_qr.is_expired() {
-            return Err(MobileError::ExchangeFailed("QR code expired".to_string()));
-        }
+    /// Suggest address book entries to invite, best matches first.
+    ///
+    /// Entries sharing an email or phone number with a contact's card are
+    /// left out; those whose social handles appear on your contacts' cards
+    /// rank highest. Matching happens on the device.
+    pub fn get_invite_suggestions(&self) -> Result<Vec<MobileInviteSuggestion>, MobileError> {
+        let storage = self.open_storage()?;
+        let entries = storage.list_address_book_entries()?;
+        let contacts = storage.list_contacts()?;
+        let suggestions = vauchi_core::suggest_invites(&entries, &contacts);
+        Ok(suggestions
+            .iter()
+            .map(MobileInviteSuggestion::from)
+            .collect())
+    }
 
-        let their_signing_key = their_qr.public_key();
-        let their_exchange_key = their_qr.exchange_key();
-        let their_public_id = hex::encode(their_signing_key);
+    // === Exchange Operations ===
 
-        if storage.load_contact(&their_public_id)?.is_some() {
-            return Err(MobileError::ExchangeFailed(
-                "Contact already exists".to_string(),
-            ));
-        }
+    /// Generate exchange QR data.
+    pub fn generate_exchange_qr(&self) -> Result<MobileExchangeData, MobileError> {
+        let identity = self.get_identity()?;
+        let qr = vauchi_core::ExchangeQR::generate(&identity);
+        Ok(exchange_data(&identity, &qr))
+    }
 
-        let our_x3dh = identity.x3dh_keypair();
-        let (encrypted_msg, shared_secret) = EncryptedExchangeMessage::create(
-            &our_x3dh,
-            their_exchange_key,
-            identity.signing_public_key(),
-            identity.display_name(),
-        )
-        .map_err(|e| MobileError::ExchangeFailed(format!("Key agreement failed: {:?}", e)))?;
-
-        let their_card = ContactCard::new("New Contact");
-        let contact = Contact::from_exchange(*their_signing_key, their_card, shared_secret.clone());
+    /// Generate exchange QR data carrying a short intro note.
+    ///
+    /// The scanning side stores the note as its personal note for the new
+    /// contact. Notes are limited to 140 bytes.
+    pub fn generate_exchange_qr_with_note(
+        &self,
+        note: String,
+    ) -> Result<MobileExchangeData, MobileError> {
+        let identity = self.get_identity()?;
+        let qr = vauchi_core::ExchangeQR::generate_with_note(&identity, &note)
+            .map_err(|e| MobileError::InvalidInput(e.to_string()))?;
+        Ok(exchange_data(&identity, &qr))
+    }
 
-        let contact_id = contact.id().to_string();
-        let contact_name = contact.display_name().to_string();
+    /// Complete exchange with scanned QR data.
+    ///
+    /// The result's `kind` tells the UI whether this is a new contact, a
+    /// reconnection with a previously deleted one, or an existing contact.
+    pub fn complete_exchange(&self, qr_data: String) -> Result<MobileExchangeResult, MobileError> {
+        use vauchi_core::api::TelemetryEvent;
+
+        let result = self.run_exchange(&qr_data);
+        self.telemetry().record(match &result {
+            Ok(r) if r.success => TelemetryEvent::ExchangeSucceeded,
+            _ => TelemetryEvent::ExchangeFailed,
+        });
+        if matches!(result, Err(MobileError::InvalidQrCode)) {
+            self.count_usage(UsageCounter::FailedQrScans, 1);
+        }
+        self.emit_exchange_result(&result);
+        result
+    }
 
-        storage.save_contact(&contact)?;
+    /// Start a remote exchange protected by a shared password.
+    ///
+    /// Returns data to send to the other party over any channel; they
+    /// enter it with the same password in `complete_password_exchange`.
+    /// Their answer arrives on the next sync and is only accepted if the
+    /// passwords match. Starting again replaces the previous offer, and
+    /// each offer accepts a single answer.
+    pub fn start_password_exchange(&self, password: String) -> Result<String, MobileError> {
+        use vauchi_core::exchange::{PasswordExchangeOffer, PasswordKey};
+
+        if password.is_empty() {
+            return Err(MobileError::InvalidInput(
+                "Exchange password is empty".to_string(),
+            ));
+        }
 
-        let ratchet = DoubleRatchetState::initialize_initiator(&shared_secret, *their_exchange_key);
-        storage.save_ratchet_state(&contact_id, &ratchet, true)?;
+        let identity = self.get_identity()?;
+        let offer = PasswordExchangeOffer::new(
+            identity.signing_public_key(),
+            identity.x3dh_keypair().public_key(),
+        );
+        let key = PasswordKey::derive(&offer, &password)
+            .map_err(|e| MobileError::ExchangeFailed(e.to_string()))?;
+        *self.password_exchange.lock().unwrap() = Some(key);
 
-        // Send encrypted exchange message
-        {
-            let mut socket = self.connect_to_relay()?;
+        Ok(format!("wbp://{}", offer.to_data_string()))
+    }
 
-            let our_id = identity.public_id();
-            sync::send_handshake(&mut socket, &our_id, None)?;
+    /// Complete a password exchange started by the other party.
+    ///
+    /// The contact is saved right away but stays unconfirmed until the
+    /// other party's confirmation arrives on a later sync. If the
+    /// passwords differ, the confirmation fails and the contact is removed.
+    pub fn complete_password_exchange(
+        &self,
+        their_data: String,
+        password: String,
+    ) -> Result<MobileExchangeResult, MobileError> {
+        use vauchi_core::api::TelemetryEvent;
+
+        let result = self.run_password_exchange(&their_data, &password);
+        self.telemetry().record(match &result {
+            Ok(r) if r.success => TelemetryEvent::ExchangeSucceeded,
+            _ => TelemetryEvent::ExchangeFailed,
+        });
+        self.emit_exchange_result(&result);
+        result
+    }
 
-            let update = protocol::EncryptedUpdate {
-                recipient_id: their_public_id.clone(),
-                sender_id: our_id,
-                ciphertext: encrypted_msg.to_bytes(),
-            };
+    /// Get the personal note for a contact, if any.
+    pub fn get_contact_note(&self, contact_id: String) -> Result<Option<String>, MobileError> {
+        let storage = self.open_storage()?;
+        let Some(encrypted) = storage.load_personal_notes(&contact_id)? else {
+            return Ok(None);
+        };
+        let note = vauchi_core::crypto::decrypt(&self.storage_key(), &encrypted)
+            .map_err(|e| MobileError::CryptoError(e.to_string()))?;
+        String::from_utf8(note)
+            .map(Some)
+            .map_err(|e| MobileError::SerializationError(e.to_string()))
+    }
 
-            let envelope =
-                protocol::create_envelope(protocol::MessagePayload::EncryptedUpdate(update));
-            let data = protocol::encode_message(&envelope).map_err(MobileError::SyncFailed)?;
-            socket
-                .send(Message::Binary(data))
-                .map_err(|e| MobileError::NetworkError(e.to_string()))?;
+    /// Retry sending exchange messages that did not reach the relay.
+    ///
+    /// Covers exchanges interrupted after the contact was saved, e.g. by a
+    /// network drop. Returns the number of exchange messages delivered;
+    /// messages that fail again stay pending for the next call.
+    pub fn resume_pending_exchanges(&self) -> Result<u32, MobileError> {
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+        let our_id = identity.public_id();
 
-            std::thread::sleep(Duration::from_millis(100));
-            let _ = socket.close(None);
+        let mut delivered = 0;
+        for pending in storage.list_undelivered_exchanges()? {
+            if storage.load_contact(&pending.contact_id)?.is_none() {
+                storage.delete_pending_exchange(&pending.contact_id)?;
+                continue;
+            }
+            if self
+                .deliver_pending_exchange(&storage, &our_id, &pending)
+                .is_ok()
+            {
+                delivered += 1;
+            }
+        }
+        if delivered > 0 {
+            storage.increment_usage_counter(UsageCounter::ExchangesCompleted, delivered as u64)?;
         }
 
-        Ok(MobileExchangeResult {
-            contact_id,
-            contact_name,
-            success: true,
-            error_message: None,
-        })
+        Ok(delivered)
+    }
+
+    /// Get the number of exchange messages that have not reached the relay.
+    pub fn get_pending_exchange_count(&self) -> Result<u32, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.list_undelivered_exchanges()?.len() as u32)
     }
 
     // === Sync Operations ===
 
+    /// Queue an update carrying only one field of the own card.
+    ///
+    /// Sent to the members of `label_id`, or to all contacts when `None`,
+    /// skipping contacts the field is hidden from. Other card changes are
+    /// not included. Returns the number of contacts queued; the updates go
+    /// out on the next sync.
+    pub fn push_field_update(
+        &self,
+        field_label: String,
+        label_id: Option<String>,
+    ) -> Result<u32, MobileError> {
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+
+        let card = storage
+            .load_own_card()?
+            .ok_or(MobileError::IdentityNotFound)?;
+        let field = card
+            .fields()
+            .iter()
+            .find(|f| f.label() == field_label)
+            .ok_or_else(|| MobileError::FieldNotFound(field_label.clone()))?;
+
+        let members = match label_id {
+            Some(label_id) => Some(storage.load_label(&label_id)?.contacts().clone()),
+            None => None,
+        };
+        let pad = *self.pad_updates.lock().unwrap();
+        sync::queue_field_update(
+            &identity,
+            &storage,
+            field,
+            members.as_ref(),
+            pad,
+            self.local_now(),
+        )
+    }
+
     /// Sync with relay server.
     pub fn sync(&self) -> Result<MobileSyncResult, MobileError> {
-        *self.sync_status.lock().unwrap() = MobileSyncStatus::Syncing;
+        self.run_sync(None)
+    }
 
-        let identity = self.get_identity()?;
-        let storage = self.open_storage()?;
-        let pinned_cert = self.get_pinned_cert();
+    /// Sync in a bounded batch, for short OS background windows.
+    ///
+    /// Stops receiving after `max_messages` inbound messages or once
+    /// `max_duration_ms` has passed, whichever comes first. Processed
+    /// messages advance the sync cursor, so the next call picks up where
+    /// this one stopped; call again while `has_more` is true. Outbound
+    /// updates are sent as in a full sync.
+    pub fn sync_batch(
+        &self,
+        max_messages: u32,
+        max_duration_ms: u64,
+    ) -> Result<MobileSyncResult, MobileError> {
+        self.run_sync(Some(sync::SyncBudget::new(
+            max_messages,
+            std::time::Duration::from_millis(max_duration_ms),
+        )))
+    }
 
-        let result = sync::do_sync(&identity, &storage, &self.relay_url, pinned_cert.as_deref());
+    /// Sync only if it is due.
+    ///
+    /// Syncs when there are pending outbound updates it could send, or when
+    /// at least `min_interval_seconds` have passed since the last successful
+    /// sync. Updates frozen for blocked contacts do not count. Safe to call
+    /// frequently from a background task. Returns whether it synced.
+    pub fn sync_if_due(&self, min_interval_seconds: u64) -> Result<bool, MobileError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let has_pending = self.open_storage()?.count_deliverable_pending_updates()? > 0;
+        let interval_elapsed = match self.load_last_sync() {
+            Some(last) => now.saturating_sub(last) >= min_interval_seconds,
+            None => true,
+        };
 
-        match &result {
-            Ok(_) => *self.sync_status.lock().unwrap() = MobileSyncStatus::Idle,
-            Err(_) => *self.sync_status.lock().unwrap() = MobileSyncStatus::Error,
+        if !has_pending && !interval_elapsed {
+            return Ok(false);
         }
 
-        result
+        self.sync()?;
+        Ok(true)
+    }
+
+    /// Get the Unix timestamp of the last successful sync, if any.
+    pub fn get_last_sync_time(&self) -> Option<u64> {
+        self.load_last_sync()
+    }
+
+    /// Install a handler for non-identifying telemetry (sync timings,
+    /// exchange and decryption outcomes). Events never carry personal data.
+    pub fn set_telemetry_handler(&self, handler: Box<dyn MobileTelemetryHandler>) {
+        *self.telemetry.lock().unwrap() =
+            Arc::new(telemetry::PlatformTelemetry::new(Arc::from(handler)));
+    }
+
+    /// Remove the telemetry handler; events are discarded again.
+    pub fn clear_telemetry_handler(&self) {
+        *self.telemetry.lock().unwrap() = vauchi_core::api::noop_telemetry();
+    }
+
+    /// Install an observer for sync, contact, recovery and relay events.
+    ///
+    /// Replaces any previous observer. The status getters keep working.
+    pub fn set_event_observer(&self, observer: Box<dyn VauchiEventObserver>) {
+        *self.event_observer.lock().unwrap() = Some(Arc::from(observer));
+    }
+
+    /// Remove the event observer; events are discarded again.
+    pub fn clear_event_observer(&self) {
+        *self.event_observer.lock().unwrap() = None;
     }
 
     /// Get sync status.
@@ -1144,6 +2868,148 @@ impl VauchiMobile {
         Ok(total)
     }
 
+    /// Sum up at a glance whether updates are flowing.
+    ///
+    /// Combines pending updates, due retries, failed deliveries, the last
+    /// successful sync and the cached relay status into `Healthy`,
+    /// `Degraded` (retries due, failed deliveries or relay unreachable) or
+    /// `Stalled` (work waiting and no successful sync for a day), with the
+    /// reasons. Does not contact the relay.
+    pub fn get_sync_health(&self) -> Result<MobileSyncHealth, MobileError> {
+        let storage = self.open_storage()?;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let failed_deliveries =
+            storage.count_deliveries_by_status(&vauchi_core::storage::DeliveryStatus::Failed {
+                reason: String::new(),
+            })?;
+
+        Ok(sync_health::assess(
+            storage.count_all_pending_updates()? as u32,
+            storage.get_due_retries(now)?.len() as u32,
+            failed_deliveries as u32,
+            self.load_last_sync(),
+            self.get_cached_relay_status(),
+            now,
+        ))
+    }
+
+    /// Get outbound updates that have waited longer than `days` for delivery.
+    ///
+    /// Lets the UI prompt the user to resend or cancel updates to contacts
+    /// that have not come online in a while.
+    pub fn get_stale_updates(&self, days: u32) -> Result<Vec<MobileStaleUpdate>, MobileError> {
+        let storage = self.open_storage()?;
+        let updates = storage.get_stale_updates(days)?;
+        Ok(updates.iter().map(MobileStaleUpdate::from).collect())
+    }
+
+    // === Clock Skew ===
+
+    /// Set the clock difference, in seconds, tolerated when checking whether
+    /// QR codes, recovery claims, and recovery proofs have expired. Kept
+    /// across restarts.
+    pub fn set_clock_skew_tolerance(&self, seconds: u64) -> Result<(), MobileError> {
+        self.open_storage()?
+            .save_setting(SETTING_CLOCK_SKEW_TOLERANCE, &seconds)?;
+        *self.clock_skew_tolerance.lock().unwrap() = seconds;
+        Ok(())
+    }
+
+    /// Get the clock difference tolerated in expiry checks, in seconds.
+    pub fn get_clock_skew_tolerance(&self) -> u64 {
+        *self.clock_skew_tolerance.lock().unwrap()
+    }
+
+    /// Check whether the device clock has been set back.
+    ///
+    /// The latest time the clock reported is remembered on every sync and
+    /// before creating recovery claims or vouchers. While the clock reads
+    /// earlier than that by more than the skew tolerance, creating them
+    /// fails with `ClockRolledBack`.
+    pub fn get_clock_status(&self) -> Result<MobileClockStatus, MobileError> {
+        let storage = self.open_storage()?;
+        let latest_seen = storage.load_clock_watermark()?;
+        let behind_secs = latest_seen.map_or(0, |latest| latest.saturating_sub(self.local_now()));
+
+        Ok(MobileClockStatus {
+            rolled_back: behind_secs > self.get_clock_skew_tolerance(),
+            behind_secs,
+            latest_seen,
+        })
+    }
+
+    /// Accept the current device clock after it was set back.
+    ///
+    /// Only call this once the user has confirmed the clock is correct now,
+    /// e.g. because it had been set far into the future before.
+    pub fn reset_clock_watermark(&self) -> Result<(), MobileError> {
+        Ok(self
+            .open_storage()?
+            .reset_clock_watermark(self.local_now())?)
+    }
+
+    // === Exchange Retry ===
+
+    /// Set how many send attempts sync makes for an undelivered exchange
+    /// message before giving up on it.
+    ///
+    /// Exchanges past the limit stay pending and can still be sent with
+    /// `resume_pending_exchanges`, which ignores the limit. Kept across
+    /// restarts.
+    pub fn set_exchange_retry_limit(&self, max_attempts: u32) -> Result<(), MobileError> {
+        self.open_storage()?
+            .save_setting(SETTING_EXCHANGE_RETRY_LIMIT, &max_attempts)?;
+        *self.exchange_retry_limit.lock().unwrap() = max_attempts;
+        Ok(())
+    }
+
+    /// Get how many send attempts sync makes for an exchange message.
+    pub fn get_exchange_retry_limit(&self) -> u32 {
+        *self.exchange_retry_limit.lock().unwrap()
+    }
+
+    // === Update Padding ===
+
+    /// Pad outgoing card updates to size buckets, so the relay cannot tell
+    /// how much of the card changed.
+    ///
+    /// Off by default, since it costs bandwidth. Only contacts whose
+    /// exchange advertised that they strip padding get padded updates.
+    /// Kept across restarts.
+    pub fn set_pad_updates(&self, enabled: bool) -> Result<(), MobileError> {
+        self.open_storage()?
+            .save_setting(SETTING_PAD_UPDATES, &enabled)?;
+        *self.pad_updates.lock().unwrap() = enabled;
+        Ok(())
+    }
+
+    /// Get whether outgoing card updates are padded.
+    pub fn get_pad_updates(&self) -> bool {
+        *self.pad_updates.lock().unwrap()
+    }
+
+    /// Get how many seconds the local clock is known to lag the relay's.
+    ///
+    /// Based on the latest delivery receipt that verifies against the pinned
+    /// relay key: a receipt cannot be received before the relay signed it.
+    /// Returns 0 when there is no such receipt or no lag is detected; a
+    /// clock running ahead of the relay cannot be detected this way.
+    pub fn relay_clock_lag(&self) -> Result<u64, MobileError> {
+        let Some(relay_key) = self.relay_receipt_key.lock().unwrap().clone() else {
+            return Ok(0);
+        };
+        let storage = self.open_storage()?;
+        Ok(match storage.load_latest_delivery_receipt()? {
+            Some((receipt, received_at)) if receipt.verify(&relay_key) => {
+                receipt.server_timestamp.saturating_sub(received_at)
+            }
+            _ => 0,
+        })
+    }
+
     // === Delivery Status Operations ===
 
     /// Get delivery record for a message.
@@ -1156,6 +3022,32 @@ impl VauchiMobile {
         Ok(record.as_ref().map(MobileDeliveryRecord::from))
     }
 
+    /// Get the relay-signed receipt for a sent message.
+    ///
+    /// Returns `None` if the relay has not sent a receipt for the message.
+    /// Fails if no relay receipt key is pinned or the receipt does not verify.
+    pub fn get_delivery_receipt(
+        &self,
+        message_id: String,
+    ) -> Result<Option<MobileDeliveryReceipt>, MobileError> {
+        let relay_key = self
+            .relay_receipt_key
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| MobileError::CryptoError("No relay receipt key pinned".to_string()))?;
+        let storage = self.open_storage()?;
+        let Some(receipt) = storage.load_delivery_receipt(&message_id)? else {
+            return Ok(None);
+        };
+        if !receipt.verify(&relay_key) {
+            return Err(MobileError::CryptoError(
+                "Delivery receipt signature invalid".to_string(),
+            ));
+        }
+        Ok(Some(MobileDeliveryReceipt::from(&receipt)))
+    }
+
     /// Get all delivery records.
     pub fn get_all_delivery_records(&self) -> Result<Vec<MobileDeliveryRecord>, MobileError> {
         let storage = self.open_storage()?;
@@ -1212,6 +3104,21 @@ impl VauchiMobile {
         Ok(records.iter().map(MobileDeliveryRecord::from).collect())
     }
 
+    /// Ask the relay for the status of sent messages awaiting confirmation.
+    ///
+    /// Lighter than `sync`: nothing is received or sent besides the query.
+    /// Returns how many delivery records advanced.
+    pub fn poll_delivery_status(&self) -> Result<u32, MobileError> {
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let connector = self.relay_connector();
+        sync::poll_delivery_status(&identity.public_id(), &storage, connector.as_ref(), now)
+    }
+
     /// Get delivery count by status.
     pub fn get_delivery_count_by_status(
         &self,
@@ -1323,6 +3230,14 @@ impl VauchiMobile {
         Ok(count as u32)
     }
 
+    /// Cancel one queued update, along with its retry and delivery records.
+    ///
+    /// Returns false if the update was already sent or never existed.
+    pub fn cancel_pending_update(&self, message_id: String) -> Result<bool, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.cancel_pending_update(&message_id)?)
+    }
+
     // === Multi-Device Delivery Operations ===
 
     /// Get delivery summary for a message (X of Y devices delivered).
@@ -1360,15 +3275,45 @@ impl VauchiMobile {
             .collect())
     }
 
+    // === Diagnostics ===
+
+    /// Check that stored contact data decrypts and the database is intact.
+    ///
+    /// Failing rows are reported individually rather than aborting the check.
+    pub fn check_storage_integrity(&self) -> Result<MobileIntegrityReport, MobileError> {
+        let storage = self.open_storage_read_only()?;
+        let report = storage.verify_integrity()?;
+        Ok(MobileIntegrityReport::from(&report))
+    }
+
     // === Backup Operations ===
 
     /// Export encrypted backup.
+    ///
+    /// Backups always use the deniable two-slot format. If a decoy was set
+    /// with `set_decoy_backup`, its password unlocks the decoy identity
+    /// instead; otherwise the second slot is random filler. Fails with
+    /// `InvalidInput` if the password is the decoy's.
     pub fn export_backup(&self, password: String) -> Result<String, MobileError> {
         let identity = self.get_identity()?;
 
+        let decoy = match self.decoy_backup.lock().unwrap().as_ref() {
+            Some((data, decoy_password)) => Some((
+                Identity::import_backup(&IdentityBackup::new(data.clone()), decoy_password)
+                    .map_err(|e| MobileError::CryptoError(e.to_string()))?,
+                decoy_password.clone(),
+            )),
+            None => None,
+        };
+
         let backup = identity
-            .export_backup(&password)
-            .map_err(|e| MobileError::CryptoError(e.to_string()))?;
+            .export_deniable_backup(&password, decoy.as_ref().map(|(id, pw)| (id, pw.as_str())))
+            .map_err(|e| match e {
+                vauchi_core::identity::IdentityError::DecoyPasswordReused => {
+                    MobileError::InvalidInput(e.to_string())
+                }
+                e => MobileError::CryptoError(e.to_string()),
+            })?;
 
         use base64::Engine;
         let encoded = base64::engine::general_purpose::STANDARD.encode(backup.as_bytes());
@@ -1376,7 +3321,45 @@ impl VauchiMobile {
         Ok(encoded)
     }
 
+    /// Set a decoy identity to embed in subsequent backup exports.
+    ///
+    /// `decoy_backup` is a backup of the decoy identity (e.g. a profile with
+    /// innocuous contacts) and `decoy_password` its passphrase. Importing an
+    /// exported backup with the decoy password loads the decoy instead of
+    /// the real identity. The decoy is kept in memory for this session only.
+    pub fn set_decoy_backup(
+        &self,
+        decoy_backup: String,
+        decoy_password: String,
+    ) -> Result<(), MobileError> {
+        use base64::Engine;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(&decoy_backup)
+            .map_err(|_| MobileError::InvalidInput("Invalid base64".to_string()))?;
+
+        let decoy = Identity::import_backup(&IdentityBackup::new(bytes), &decoy_password)
+            .map_err(|e| MobileError::CryptoError(e.to_string()))?;
+
+        let identity = self.get_identity()?;
+        if decoy.signing_public_key() == identity.signing_public_key() {
+            return Err(MobileError::InvalidInput(
+                "Decoy must be a different identity".to_string(),
+            ));
+        }
+
+        // Store the decoy in a normalized, single-slot form
+        let normalized = decoy
+            .export_backup(&decoy_password)
+            .map_err(|e| MobileError::CryptoError(e.to_string()))?;
+        *self.decoy_backup.lock().unwrap() = Some((normalized.as_bytes().to_vec(), decoy_password));
+
+        Ok(())
+    }
+
     /// Import backup.
+    ///
+    /// Loads whichever identity the password unlocks: the real one, or the
+    /// decoy embedded by `set_decoy_backup`.
     pub fn import_backup(&self, backup_data: String, password: String) -> Result<(), MobileError> {
         {
             let data = self.identity_data.lock().unwrap();
@@ -1418,6 +3401,65 @@ impl VauchiMobile {
         Ok(())
     }
 
+    /// Export the entire database as an encrypted archive for device migration.
+    ///
+    /// Includes contacts, ratchet states, labels and queues, encrypted with
+    /// a key derived from `password` rather than this device's storage key.
+    pub fn export_full_archive(&self, password: String) -> Result<Vec<u8>, MobileError> {
+        let storage = self.open_storage_read_only()?;
+        Ok(storage.export_encrypted_archive(&password)?)
+    }
+
+    /// Import an archive produced by `export_full_archive`.
+    ///
+    /// Only allowed before an identity exists on this device.
+    pub fn import_full_archive(&self, data: Vec<u8>, password: String) -> Result<(), MobileError> {
+        if self.identity_data.lock().unwrap().is_some() {
+            return Err(MobileError::AlreadyInitialized);
+        }
+
+        let storage = self.open_storage()?;
+        storage.import_encrypted_archive(&data, &password)?;
+
+        if let Some((backup_data, display_name)) = storage.load_identity()? {
+            *self.identity_data.lock().unwrap() = Some(IdentityData {
+                backup_data,
+                display_name,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Export one contact's card and note, encrypted with `password`.
+    ///
+    /// The shared key and ratchet are not included, so the recipient gets
+    /// a reference contact that does not sync.
+    pub fn export_single_contact(
+        &self,
+        contact_id: String,
+        password: String,
+    ) -> Result<Vec<u8>, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.export_contact(&contact_id, &password)?)
+    }
+
+    /// Import a contact produced by `export_single_contact` as a reference
+    /// contact.
+    ///
+    /// A later exchange with the same person replaces it with a regular
+    /// contact.
+    pub fn import_single_contact(
+        &self,
+        data: Vec<u8>,
+        password: String,
+    ) -> Result<MobileContact, MobileError> {
+        let identity = self.get_identity()?;
+        let storage = self.open_storage()?;
+        let contact = storage.import_contact(&data, &password, identity.signing_public_key())?;
+        Ok(MobileContact::from(&contact))
+    }
+
     // === Social Networks ===
 
     /// List available social networks.
@@ -1451,6 +3493,20 @@ impl VauchiMobile {
         self.social_registry.profile_url(&network_id, &username)
     }
 
+    /// Get the identifier of the icon to show next to a field.
+    ///
+    /// Identifiers are stable names such as "email", "phone" or a social
+    /// network's ID; unknown social networks get "generic-social". Apps map
+    /// them to their own assets.
+    pub fn get_field_icon_key(
+        &self,
+        field_type: MobileFieldType,
+        label: String,
+        value: String,
+    ) -> String {
+        ContactField::new(field_type.into(), &label, &value).icon_key(&self.social_registry)
+    }
+
     // === Recovery ===
 
     /// Create a recovery claim for a lost identity.
@@ -1464,6 +3520,7 @@ impl VauchiMobile {
     ) -> Result<MobileRecoveryClaim, MobileError> {
         use base64::Engine;
         let identity = self.get_identity()?;
+        self.check_clock()?;
 
         // Parse old public key
         let old_pk_bytes = hex::decode(&old_pk_hex)
@@ -1511,7 +3568,7 @@ impl VauchiMobile {
             old_public_key: hex::encode(claim.old_pk()),
             new_public_key: hex::encode(claim.new_pk()),
             claim_data: claim_b64,
-            is_expired: claim.is_expired(),
+            is_expired: claim.is_expired_at(self.trusted_now(), self.get_clock_skew_tolerance()),
         })
     }
 
@@ -1533,9 +3590,10 @@ impl VauchiMobile {
         let claim = RecoveryClaim::from_bytes(&claim_bytes)
             .map_err(|e| MobileError::InvalidInput(format!("Invalid claim: {}", e)))?;
 
-        if claim.is_expired() {
-            return Err(MobileError::InvalidInput("Claim has expired".to_string()));
+        if claim.is_expired_at(self.trusted_now(), self.get_clock_skew_tolerance()) {
+            return Err(MobileError::ClaimExpired);
         }
+        self.check_clock()?;
 
         let voucher = RecoveryVoucher::create_from_claim(&claim, identity.signing_keypair())
             .map_err(|e| MobileError::CryptoError(e.to_string()))?;
@@ -1583,9 +3641,7 @@ impl VauchiMobile {
         };
 
         // Add voucher
-        proof
-            .add_voucher(voucher)
-            .map_err(|e| MobileError::InvalidInput(format!("Cannot add voucher: {}", e)))?;
+        proof.add_voucher(voucher).map_err(MobileError::from)?;
 
         // Save updated proof
         std::fs::write(&proof_path, proof.to_bytes())
@@ -1593,13 +3649,17 @@ impl VauchiMobile {
 
         let is_complete = proof.voucher_count() >= proof.threshold() as usize;
 
-        Ok(MobileRecoveryProgress {
+        let progress = MobileRecoveryProgress {
             old_public_key: hex::encode(proof.old_pk()),
             new_public_key: hex::encode(proof.new_pk()),
             vouchers_collected: proof.voucher_count() as u32,
             vouchers_needed: proof.threshold(),
             is_complete,
-        })
+        };
+        self.emit(MobileVauchiEvent::RecoveryProgress {
+            progress: progress.clone(),
+        });
+        Ok(progress)
     }
 
     /// Get the current recovery progress.
@@ -1629,6 +3689,30 @@ impl VauchiMobile {
         }))
     }
 
+    /// Check whether a recovery claim created here is still in progress.
+    ///
+    /// False once the claim has collected enough vouchers.
+    pub fn has_active_recovery(&self) -> Result<bool, MobileError> {
+        Ok(self
+            .get_recovery_status()?
+            .is_some_and(|progress| !progress.is_complete))
+    }
+
+    /// Abandon the recovery in progress.
+    ///
+    /// Deletes the claim's proof and the vouchers collected for it, so
+    /// `get_recovery_status` returns None again. Contacts are kept,
+    /// including those that already accepted the new identity. A proof
+    /// already published to the relay stays there until it expires.
+    /// Returns false if no recovery was in progress.
+    pub fn cancel_recovery(&self) -> Result<bool, MobileError> {
+        match std::fs::remove_file(self.recovery_proof_path()) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(MobileError::StorageError(e.to_string())),
+        }
+    }
+
     /// Get the completed recovery proof as base64.
     ///
     /// Returns None if recovery is not complete.
@@ -1654,12 +3738,97 @@ impl VauchiMobile {
         }
     }
 
-    /// Verify a recovery proof from a contact.
+    /// Publish the completed recovery proof to the relay.
     ///
-    /// This checks if the proof is valid and provides a recommendation
-    /// on whether to accept the recovered identity.
-    pub fn verify_recovery_proof(
-        &self,
+    /// Contacts can then fetch it by the lost identity's public key with
+    /// `fetch_recovery_proofs` instead of receiving it out of band.
+    pub fn publish_recovery_proof(&self) -> Result<(), MobileError> {
+        let identity = self.get_identity()?;
+        let proof_path = self.recovery_proof_path();
+
+        if !proof_path.exists() {
+            return Err(MobileError::InvalidInput(
+                "No recovery in progress".to_string(),
+            ));
+        }
+
+        let proof_bytes =
+            std::fs::read(&proof_path).map_err(|e| MobileError::StorageError(e.to_string()))?;
+        let proof = RecoveryProof::from_bytes(&proof_bytes)
+            .map_err(|e| MobileError::InvalidInput(format!("Invalid proof: {}", e)))?;
+
+        if proof.is_expired_at(self.trusted_now(), self.get_clock_skew_tolerance()) {
+            return Err(MobileError::ClaimExpired);
+        }
+        proof.validate()?;
+
+        let connector = self.relay_connector();
+        sync::publish_recovery_proof(&identity.public_id(), &proof, connector.as_ref())
+    }
+
+    /// Fetch the recovery proofs stored on the relay for a lost identity.
+    ///
+    /// Every valid, unexpired proof is returned and scored like
+    /// `verify_recovery_proof`. If the proofs claim different new identities,
+    /// `conflict` is set and the app should warn before accepting any of them.
+    /// `recommended_index` points at the proof with the most vouchers from
+    /// our contacts.
+    pub fn fetch_recovery_proofs(
+        &self,
+        old_pk_hex: String,
+    ) -> Result<MobileRecoveryProofSet, MobileError> {
+        use base64::Engine;
+        let identity = self.get_identity()?;
+        let old_pk = hex::decode(&old_pk_hex)
+            .map_err(|e| MobileError::InvalidInput(format!("Invalid hex: {}", e)))?;
+        if old_pk.len() != 32 {
+            return Err(MobileError::InvalidInput(
+                "Public key must be 32 bytes".to_string(),
+            ));
+        }
+
+        let connector = self.relay_connector();
+        let proofs: Vec<RecoveryProof> = sync::fetch_recovery_proofs(
+            &identity.public_id(),
+            &hex::encode(old_pk),
+            connector.as_ref(),
+            self.trusted_now(),
+            self.get_clock_skew_tolerance(),
+        )?
+        .into_iter()
+        .filter(|proof| proof.validate().is_ok())
+        .collect();
+
+        let conflict = RecoveryConflict::detect(&proofs).map(|c| MobileRecoveryConflict::from(&c));
+
+        let contacts = self.open_storage()?.list_contacts()?;
+        let proofs: Vec<MobileFetchedRecoveryProof> = proofs
+            .iter()
+            .map(|proof| MobileFetchedRecoveryProof {
+                proof_data: base64::engine::general_purpose::STANDARD.encode(proof.to_bytes()),
+                verification: score_recovery_proof(proof, &contacts),
+            })
+            .collect();
+
+        let recommended_index = proofs
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, p)| (p.verification.known_vouchers, p.verification.voucher_count))
+            .map(|(i, _)| i as u32);
+
+        Ok(MobileRecoveryProofSet {
+            proofs,
+            conflict,
+            recommended_index,
+        })
+    }
+
+    /// Verify a recovery proof from a contact.
+    ///
+    /// This checks if the proof is valid and provides a recommendation
+    /// on whether to accept the recovered identity.
+    pub fn verify_recovery_proof(
+        &self,
         proof_b64: String,
     ) -> Result<MobileRecoveryVerification, MobileError> {
         use base64::Engine;
@@ -1677,47 +3846,12 @@ impl VauchiMobile {
             .validate()
             .map_err(|e| MobileError::InvalidInput(format!("Proof validation failed: {}", e)))?;
 
-        // Count known vouchers (vouchers from our contacts)
-        let contacts = storage
-            .list_contacts()
-            .map_err(|e| MobileError::StorageError(e.to_string()))?;
-
-        let contact_pks: std::collections::HashSet<[u8; 32]> =
-            contacts.iter().map(|c| *c.public_key()).collect();
-
-        let known_voucher_count = proof
-            .vouchers()
-            .iter()
-            .filter(|v| contact_pks.contains(v.voucher_pk()))
-            .count();
-
-        // Determine confidence
-        let (confidence, recommendation) = if known_voucher_count >= 2 {
-            (
-                "high".to_string(),
-                "Multiple contacts you know have vouched. Safe to accept.".to_string(),
-            )
-        } else if known_voucher_count == 1 {
-            (
-                "medium".to_string(),
-                "One contact you know has vouched. Consider verifying in person.".to_string(),
-            )
-        } else {
-            (
-                "low".to_string(),
-                "No known contacts have vouched. Verify identity carefully before accepting."
-                    .to_string(),
-            )
-        };
+        // Accepting it would turn the recovering contact into ourselves
+        let identity = self.get_identity()?;
+        vauchi_core::contact::check_not_self(proof.new_pk(), identity.signing_public_key())?;
 
-        Ok(MobileRecoveryVerification {
-            old_public_key: hex::encode(proof.old_pk()),
-            new_public_key: hex::encode(proof.new_pk()),
-            voucher_count: proof.voucher_count() as u32,
-            known_vouchers: known_voucher_count as u32,
-            confidence,
-            recommendation,
-        })
+        let contacts = storage.list_contacts()?;
+        Ok(score_recovery_proof(&proof, &contacts))
     }
 
     // === Content Updates ===
@@ -1803,9 +3937,17 @@ impl VauchiMobile {
         // Check we haven't already validated this field
         let validator_id = hex::encode(identity.signing_public_key());
         if storage.has_validated(&contact_id, &field_id, &validator_id)? {
-            return Err(MobileError::InvalidInput(
-                "You have already validated this field".to_string(),
-            ));
+            return Err(MobileError::AlreadyValidated);
+        }
+
+        // Check the daily validation limit
+        let limiter = Self::validation_limiter(&storage)?;
+        let (count, window_start) = storage.check_validation_rate_limit(&validator_id)?;
+        if !limiter.check_rate_limit(count, window_start) {
+            return Err(MobileError::RateLimited(format!(
+                "At most {} validations per day",
+                limiter.max_validations_per_day
+            )));
         }
 
         // Create signed validation
@@ -1818,10 +3960,122 @@ impl VauchiMobile {
 
         // Store it
         storage.save_validation(&validation)?;
+        let (count, window_start) = limiter.record(count, window_start);
+        storage.update_validation_rate_limit(&validator_id, count, window_start)?;
 
         Ok(MobileFieldValidation::from(&validation))
     }
 
+    /// Export all validations you have made as a signed bundle (base64).
+    ///
+    /// Others can import the bundle to see which fields you have verified.
+    pub fn export_my_validations(&self) -> Result<String, MobileError> {
+        use base64::Engine;
+        let identity = self.get_identity()?;
+        let storage = self.open_storage_read_only()?;
+
+        let bundle = storage.export_my_validations_signed(&identity)?;
+        Ok(base64::engine::general_purpose::STANDARD.encode(bundle.to_bytes()))
+    }
+
+    /// Import another user's signed validation bundle (base64).
+    ///
+    /// The bundle and every validation in it must be signed by the same
+    /// validator. Returns the number of validations stored.
+    pub fn import_validations_bundle(&self, bundle_b64: String) -> Result<u32, MobileError> {
+        use base64::Engine;
+        let storage = self.open_storage()?;
+
+        let bundle_bytes = base64::engine::general_purpose::STANDARD
+            .decode(&bundle_b64)
+            .map_err(|e| MobileError::InvalidInput(format!("Invalid base64: {}", e)))?;
+
+        let bundle = ValidationBundle::from_bytes(&bundle_bytes)
+            .ok_or_else(|| MobileError::InvalidInput("Invalid validation bundle".to_string()))?;
+        if !bundle.verify() {
+            return Err(MobileError::CryptoError(
+                "Validation bundle signature is invalid".to_string(),
+            ));
+        }
+
+        let imported = storage.import_validations_bundle(&bundle)?;
+        Ok(imported as u32)
+    }
+
+    /// Import an issuer's attestation of a contact's field (base64).
+    ///
+    /// Issuers such as employers or universities sign that a field belongs
+    /// to its holder; the holder shares the attestation with their contacts.
+    /// Returns the ID of the contact it is about.
+    pub fn import_issuer_attestation(
+        &self,
+        attestation_b64: String,
+    ) -> Result<String, MobileError> {
+        use base64::Engine;
+        let storage = self.open_storage()?;
+
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(&attestation_b64)
+            .map_err(|e| MobileError::InvalidInput(format!("Invalid base64: {}", e)))?;
+
+        let attestation = vauchi_core::IssuerAttestation::from_bytes(&bytes)
+            .ok_or_else(|| MobileError::InvalidInput("Invalid issuer attestation".to_string()))?;
+        if !attestation.verify() {
+            return Err(MobileError::CryptoError(
+                "Issuer attestation signature is invalid".to_string(),
+            ));
+        }
+
+        let contact_id = attestation.subject_id();
+        if storage.load_contact(&contact_id)?.is_none() {
+            return Err(MobileError::ContactNotFound(contact_id));
+        }
+
+        storage.save_issuer_attestation(&attestation)?;
+        Ok(contact_id)
+    }
+
+    /// List a contact's fields backed by an issuer attestation.
+    ///
+    /// Only attestations of a field's current value count.
+    pub fn get_issuer_backed_fields(&self, contact_id: String) -> Result<Vec<String>, MobileError> {
+        let storage = self.open_storage()?;
+        let contact = storage
+            .load_contact(&contact_id)?
+            .ok_or_else(|| MobileError::ContactNotFound(contact_id.clone()))?;
+
+        let mut backed = Vec::new();
+        for field in contact.card().fields() {
+            let attestations =
+                storage.load_issuer_attestations_for_field(&contact_id, field.label())?;
+            if attestations
+                .iter()
+                .any(|a| a.matches(&contact_id, field.label(), field.value()) && a.verify())
+            {
+                backed.push(field.label().to_string());
+            }
+        }
+
+        Ok(backed)
+    }
+
+    /// Set the maximum number of field validations you can create per day.
+    ///
+    /// The limit can only be lowered from the default; higher values are
+    /// capped. Revoking a validation does not give back its slot.
+    pub fn set_validation_daily_limit(&self, max_per_day: u32) -> Result<(), MobileError> {
+        let limiter = ValidationRateLimiter::new(max_per_day);
+        self.open_storage()?
+            .save_validation_daily_limit(limiter.max_validations_per_day)?;
+        Ok(())
+    }
+
+    /// Get the maximum number of field validations you can create per day.
+    pub fn get_validation_daily_limit(&self) -> Result<u32, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(Self::validation_limiter(&storage)?.max_validations_per_day)
+    }
+
     /// Get validation status for a contact's field.
     ///
     /// Returns aggregated validation information including count, trust level,
@@ -1833,30 +4087,60 @@ impl VauchiMobile {
         field_value: String,
     ) -> Result<MobileValidationStatus, MobileError> {
         let storage = self.open_storage()?;
-        let validations = storage.load_validations_for_field(&contact_id, &field_id)?;
+        let status =
+            self.field_validation_status(&storage, &contact_id, &field_id, &field_value)?;
 
-        // Get current user's ID if available
-        let my_id = {
-            let data = self.identity_data.lock().unwrap();
-            if data.is_some() {
-                match self.get_identity() {
-                    Ok(identity) => Some(hex::encode(identity.signing_public_key())),
-                    Err(_) => None,
-                }
-            } else {
-                None
-            }
+        Ok(MobileValidationStatus::from(&status))
+    }
+
+    /// Set how many validations a field needs before it is shown as trusted.
+    ///
+    /// If `in_person_counts_double` is set, a validation from a contact
+    /// whose fingerprint you verified in person counts as two. Kept
+    /// across restarts.
+    pub fn set_trust_thresholds(
+        &self,
+        min_validations: u32,
+        in_person_counts_double: bool,
+    ) -> Result<(), MobileError> {
+        let thresholds = TrustThresholds {
+            min_validations: min_validations as usize,
+            in_person_counts_double,
         };
+        self.open_storage()?
+            .save_setting(SETTING_TRUST_THRESHOLDS, &thresholds)?;
+        *self.trust_thresholds.lock().unwrap() = thresholds;
+        Ok(())
+    }
 
-        let blocked = std::collections::HashSet::new();
-        let status = vauchi_core::social::ValidationStatus::from_validations(
-            &validations,
-            &field_value,
-            my_id.as_deref(),
-            &blocked,
-        );
+    /// Get the current trust thresholds.
+    pub fn get_trust_thresholds(&self) -> MobileTrustThresholds {
+        MobileTrustThresholds::from(&*self.trust_thresholds.lock().unwrap())
+    }
 
-        Ok(MobileValidationStatus::from(&status))
+    /// Check whether a contact's field meets the trust thresholds.
+    ///
+    /// Only validations of the field's current value count.
+    pub fn get_field_trust(
+        &self,
+        contact_id: String,
+        field_id: String,
+    ) -> Result<bool, MobileError> {
+        let storage = self.open_storage()?;
+        let contact = storage
+            .load_contact(&contact_id)?
+            .ok_or_else(|| MobileError::ContactNotFound(contact_id.clone()))?;
+        let field_value = contact
+            .card()
+            .fields()
+            .iter()
+            .find(|f| f.label() == field_id)
+            .map(|f| f.value().to_string())
+            .ok_or_else(|| MobileError::FieldNotFound(field_id.clone()))?;
+
+        let status =
+            self.field_validation_status(&storage, &contact_id, &field_id, &field_value)?;
+        Ok(status.trusted)
     }
 
     /// Revoke your validation of a contact's field.
@@ -1919,6 +4203,32 @@ impl VauchiMobile {
         Ok(count as u32)
     }
 
+    // === Localization ===
+
+    /// Set the locale for demo tips and aha moment messages.
+    ///
+    /// Accepts a language code (e.g. "de", "fr-CA"). Unknown codes fall back to English.
+    /// Kept across restarts.
+    pub fn set_locale(&self, lang: String) -> Result<(), MobileError> {
+        let locale = vauchi_core::i18n::Locale::from_code(&lang).unwrap_or_default();
+        self.open_storage()?
+            .save_setting(SETTING_LOCALE, &locale.code())?;
+        *self.locale.lock().unwrap() = locale;
+        Ok(())
+    }
+
+    // === Usage Stats ===
+
+    /// Get local usage statistics: exchanges completed, failed QR scans
+    /// and contacts verified.
+    ///
+    /// The counters are private to this device; they are never synced or
+    /// sent anywhere.
+    pub fn get_usage_stats(&self) -> Result<MobileUsageStats, MobileError> {
+        let storage = self.open_storage()?;
+        Ok(storage.load_usage_stats()?.into())
+    }
+
     // === Aha Moments (public API) ===
 
     /// Check if an aha moment has been seen.
@@ -1939,8 +4249,8 @@ impl VauchiMobile {
             self.save_aha_tracker(&tracker)?;
             Ok(Some(MobileAhaMoment {
                 moment_type,
-                title: moment.title().to_string(),
-                message: moment.message(),
+                title: moment.title_for_locale(self.locale()),
+                message: moment.message_for_locale(self.locale()),
                 has_animation: moment.has_animation(),
             }))
         } else {
@@ -1961,8 +4271,8 @@ impl VauchiMobile {
             self.save_aha_tracker(&tracker)?;
             Ok(Some(MobileAhaMoment {
                 moment_type,
-                title: moment.title().to_string(),
-                message: moment.message(),
+                title: moment.title_for_locale(self.locale()),
+                message: moment.message_for_locale(self.locale()),
                 has_animation: moment.has_animation(),
             }))
         } else {
@@ -1970,6 +4280,42 @@ impl VauchiMobile {
         }
     }
 
+    /// Check contact milestones and trigger any newly reached aha moments.
+    ///
+    /// Reads the current contact and verified-contact counts from storage;
+    /// contacts verified before being deleted count towards the verified
+    /// milestone through the usage counters.
+    /// Each milestone fires only once; returns the moments unlocked by this call.
+    pub fn check_milestone_aha_moments(&self) -> Result<Vec<MobileAhaMoment>, MobileError> {
+        let storage = self.open_storage()?;
+        let contacts = storage.list_contacts()?;
+        let verified_count = contacts
+            .iter()
+            .filter(|c| c.is_fingerprint_verified())
+            .count()
+            .max(storage.load_usage_stats()?.contacts_verified as usize);
+
+        let mut tracker = self.load_aha_tracker();
+        let mut unlocked = tracker.check_milestones(contacts.len(), verified_count);
+        if let Some(card) = storage.load_own_card()? {
+            unlocked.extend(tracker.check_card_completeness(&card.completeness()));
+        }
+        if unlocked.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.save_aha_tracker(&tracker)?;
+
+        Ok(unlocked
+            .into_iter()
+            .map(|moment| MobileAhaMoment {
+                moment_type: moment.moment_type.into(),
+                title: moment.title_for_locale(self.locale()),
+                message: moment.message_for_locale(self.locale()),
+                has_animation: moment.has_animation(),
+            })
+            .collect())
+    }
+
     /// Get the count of seen aha moments.
     pub fn aha_moments_seen_count(&self) -> u32 {
         let tracker = self.load_aha_tracker();
@@ -2012,15 +4358,27 @@ impl VauchiMobile {
             return Ok(None);
         }
 
-        // Activate demo contact if not already
+        // User already practiced everything the demo teaches
+        if state.has_completed_core_actions() {
+            state.auto_remove();
+            self.save_demo_state(&state)?;
+            return Ok(None);
+        }
+
+        // Activate demo contact if not already, keeping recorded actions
         if !state.is_active {
-            state = vauchi_core::DemoContactState::new_active();
+            state = vauchi_core::DemoContactState {
+                exchanged: state.exchanged,
+                verified: state.verified,
+                hid_a_field: state.hid_a_field,
+                ..vauchi_core::DemoContactState::new_active()
+            };
             self.save_demo_state(&state)?;
         }
 
         // Get current demo card
         if let Some(tip) = state.current_tip() {
-            let card = vauchi_core::generate_demo_contact_card(&tip);
+            let card = vauchi_core::generate_demo_contact_card_for_locale(&tip, self.locale());
             Ok(Some(card.into()))
         } else {
             Ok(None)
@@ -2035,7 +4393,7 @@ impl VauchiMobile {
         }
 
         if let Some(tip) = state.current_tip() {
-            let card = vauchi_core::generate_demo_contact_card(&tip);
+            let card = vauchi_core::generate_demo_contact_card_for_locale(&tip, self.locale());
             Ok(Some(card.into()))
         } else {
             Ok(None)
@@ -2050,6 +4408,9 @@ impl VauchiMobile {
             was_dismissed: state.was_dismissed,
             auto_removed: state.auto_removed,
             update_count: state.update_count,
+            exchanged: state.exchanged,
+            verified: state.verified,
+            hid_a_field: state.hid_a_field,
         }
     }
 
@@ -2068,13 +4429,24 @@ impl VauchiMobile {
 
         if let Some(tip) = state.advance_to_next_tip() {
             self.save_demo_state(&state)?;
-            let card = vauchi_core::generate_demo_contact_card(&tip);
+            let card = vauchi_core::generate_demo_contact_card_for_locale(&tip, self.locale());
             Ok(Some(card.into()))
         } else {
             Ok(None)
         }
     }
 
+    /// Record that the user performed a core action.
+    ///
+    /// The demo contact is auto-removed once all core actions are done.
+    /// Returns true if this call removed the demo contact.
+    pub fn mark_demo_action(&self, action: MobileDemoAction) -> Result<bool, MobileError> {
+        let mut state = self.load_demo_state();
+        let removed = state.mark_action(action.into());
+        self.save_demo_state(&state)?;
+        Ok(removed)
+    }
+
     /// Dismiss the demo contact.
     pub fn dismiss_demo_contact(&self) -> Result<(), MobileError> {
         let mut state = self.load_demo_state();
@@ -2102,7 +4474,7 @@ impl VauchiMobile {
         self.save_demo_state(&state)?;
 
         if let Some(tip) = state.current_tip() {
-            let card = vauchi_core::generate_demo_contact_card(&tip);
+            let card = vauchi_core::generate_demo_contact_card_for_locale(&tip, self.locale());
             Ok(Some(card.into()))
         } else {
             Ok(None)
@@ -2196,7 +4568,7 @@ impl VauchiMobile {
         Ok(MobileDeviceLinkInfo {
             identity_public_key: hex::encode(qr.identity_public_key()),
             timestamp: qr.timestamp(),
-            is_expired: qr.is_expired(),
+            is_expired: qr.is_expired_at(self.trusted_now(), self.get_clock_skew_tolerance()),
         })
     }
 
@@ -2357,6 +4729,74 @@ impl VauchiMobile {
     }
 }
 
+/// Builds the QR payload shown to the other party.
+fn exchange_data(identity: &Identity, qr: &vauchi_core::ExchangeQR) -> MobileExchangeData {
+    let qr_data = format!("wb://{}", qr.to_data_string());
+
+    let expires_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 300;
+
+    MobileExchangeData {
+        qr_data,
+        public_id: identity.public_id(),
+        expires_at,
+    }
+}
+
+/// The own card reduced to the fields shared with a label, for publishing.
+fn public_card_projection(storage: &Storage, label_id: &str) -> Result<ContactCard, MobileError> {
+    let shared = storage.fields_shared_with_label(label_id)?;
+    let mut card = storage
+        .load_own_card()?
+        .ok_or(MobileError::IdentityNotFound)?;
+    card.fields_mut()
+        .retain(|field| shared.iter().any(|id| id == field.id()));
+    Ok(card)
+}
+
+/// Scores a recovery proof by how many of our contacts vouched for it.
+fn score_recovery_proof(proof: &RecoveryProof, contacts: &[Contact]) -> MobileRecoveryVerification {
+    let contact_pks: std::collections::HashSet<[u8; 32]> =
+        contacts.iter().map(|c| *c.public_key()).collect();
+
+    let known_voucher_count = proof
+        .vouchers()
+        .iter()
+        .filter(|v| contact_pks.contains(v.voucher_pk()))
+        .count();
+
+    // Determine confidence
+    let (confidence, recommendation) = if known_voucher_count >= 2 {
+        (
+            "high".to_string(),
+            "Multiple contacts you know have vouched. Safe to accept.".to_string(),
+        )
+    } else if known_voucher_count == 1 {
+        (
+            "medium".to_string(),
+            "One contact you know has vouched. Consider verifying in person.".to_string(),
+        )
+    } else {
+        (
+            "low".to_string(),
+            "No known contacts have vouched. Verify identity carefully before accepting."
+                .to_string(),
+        )
+    };
+
+    MobileRecoveryVerification {
+        old_public_key: hex::encode(proof.old_pk()),
+        new_public_key: hex::encode(proof.new_pk()),
+        voucher_count: proof.voucher_count() as u32,
+        known_vouchers: known_voucher_count as u32,
+        confidence,
+        recommendation,
+    }
+}
+
 // INLINE_TEST_REQUIRED: Tests require tempfile for VauchiMobile instance creation
 // and access to internal Arc<VauchiMobile> which cannot be accessed from external tests.
 #[cfg(test)]
@@ -2405,9 +4845,18 @@ mod tests {
     }
 
     #[test]
-    fn test_update_field() {
+    fn test_nobody_default_hides_new_fields() {
         let (wb, _dir) = create_test_instance();
         wb.create_identity("Alice".to_string()).unwrap();
+        let bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+        let carol = save_test_contact(&wb, &Identity::create("Carol"), false);
+
+        wb.set_default_field_visibility(MobileDefaultFieldVisibility::Nobody)
+            .unwrap();
+        assert_eq!(
+            wb.get_default_field_visibility().unwrap(),
+            MobileDefaultFieldVisibility::Nobody
+        );
 
         wb.add_field(
             MobileFieldType::Phone,
@@ -2416,48 +4865,3290 @@ mod tests {
         )
         .unwrap();
 
-        wb.update_field("mobile".to_string(), "+0987654321".to_string())
+        for contact in [&bob, &carol] {
+            assert!(!wb
+                .is_field_visible_to_contact(contact.id().to_string(), "mobile".to_string())
+                .unwrap());
+        }
+
+        // Sharing explicitly makes it visible to that contact only
+        wb.show_field_to_contact(bob.id().to_string(), "mobile".to_string())
             .unwrap();
+        assert!(wb
+            .is_field_visible_to_contact(bob.id().to_string(), "mobile".to_string())
+            .unwrap());
+        assert!(!wb
+            .is_field_visible_to_contact(carol.id().to_string(), "mobile".to_string())
+            .unwrap());
 
-        let card = wb.get_own_card().unwrap();
-        assert_eq!(card.fields[0].value, "+0987654321");
+        // Contacts added later do not see it either
+        let dave = save_test_contact(&wb, &Identity::create("Dave"), false);
+        assert!(!wb
+            .is_field_visible_to_contact(dave.id().to_string(), "mobile".to_string())
+            .unwrap());
     }
 
     #[test]
-    fn test_remove_field() {
+    fn test_label_limit() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let max = wb.get_max_labels();
+        for i in 0..max {
+            wb.create_label(format!("Label {}", i)).unwrap();
+        }
+        assert_eq!(wb.get_label_count().unwrap(), max);
+
+        assert!(matches!(
+            wb.create_label("One Too Many".to_string()),
+            Err(MobileError::TooManyLabels(limit)) if limit == max
+        ));
+        assert_eq!(wb.get_label_count().unwrap(), max);
+    }
+
+    #[test]
+    fn test_duplicate_label_name_rejected() {
         let (wb, _dir) = create_test_instance();
         wb.create_identity("Alice".to_string()).unwrap();
 
+        wb.create_label("Friends".to_string()).unwrap();
+        let work = wb.create_label("Work".to_string()).unwrap();
+
+        assert!(matches!(
+            wb.create_label("friends".to_string()),
+            Err(MobileError::DuplicateLabelName(_))
+        ));
+        assert!(matches!(
+            wb.rename_label(work.id.clone(), "FRIENDS".to_string()),
+            Err(MobileError::DuplicateLabelName(_))
+        ));
+        wb.rename_label(work.id.clone(), "Colleagues".to_string())
+            .unwrap();
+        assert_eq!(wb.get_label(work.id).unwrap().name, "Colleagues");
+    }
+
+    #[test]
+    fn test_get_fields_shared_with_label() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
         wb.add_field(
             MobileFieldType::Email,
             "work".to_string(),
-            "alice@company.com".to_string(),
+            "alice@example.com".to_string(),
+        )
+        .unwrap();
+        wb.add_field(
+            MobileFieldType::Phone,
+            "mobile".to_string(),
+            "+1234567890".to_string(),
         )
         .unwrap();
+        let label = wb.create_label("Family".to_string()).unwrap();
 
-        let removed = wb.remove_field("work".to_string()).unwrap();
-        assert!(removed);
+        wb.set_label_field_visibility(label.id.clone(), "work".to_string(), true)
+            .unwrap();
+        wb.set_label_field_visibility(label.id.clone(), "mobile".to_string(), true)
+            .unwrap();
 
-        let card = wb.get_own_card().unwrap();
-        assert!(card.fields.is_empty());
+        let shared = wb.get_fields_shared_with_label(label.id).unwrap();
+        assert_eq!(shared, vec!["work".to_string(), "mobile".to_string()]);
     }
 
     #[test]
-    fn test_social_networks() {
-        let (wb, _dir) = create_test_instance();
+    fn test_full_archive_roundtrip() {
+        let (alice, _alice_dir) = create_test_instance();
+        alice.create_identity("Alice".to_string()).unwrap();
+        let bob = Identity::create("Bob");
+        let contact_id = save_test_contact(&alice, &bob, true).id().to_string();
+        let label = alice.create_label("Friends".to_string()).unwrap();
+        alice
+            .add_contact_to_label(label.id.clone(), contact_id.clone())
+            .unwrap();
+        let public_id = alice.get_public_id().unwrap();
 
-        let networks = wb.list_social_networks();
-        assert!(!networks.is_empty());
+        let password = "correct-horse-battery-staple-migration".to_string();
+        let archive = alice.export_full_archive(password.clone()).unwrap();
 
-        let github = networks.iter().find(|n| n.id == "github");
-        assert!(github.is_some());
+        let (new_device, _new_dir) = create_test_instance();
+        new_device
+            .import_full_archive(archive.clone(), password.clone())
+            .unwrap();
 
-        let url = wb.get_profile_url("github".to_string(), "octocat".to_string());
-        assert_eq!(url, Some("https://github.com/octocat".to_string()));
+        assert!(new_device.has_identity());
+        assert_eq!(new_device.get_public_id().unwrap(), public_id);
+        assert_eq!(
+            new_device
+                .get_contact(contact_id.clone())
+                .unwrap()
+                .unwrap()
+                .display_name,
+            "Bob"
+        );
+        let labels = new_device.get_labels_for_contact(contact_id).unwrap();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].name, "Friends");
+
+        assert!(matches!(
+            new_device.import_full_archive(archive, password),
+            Err(MobileError::AlreadyInitialized)
+        ));
     }
 
     #[test]
-    fn test_exchange_qr_generation() {
+    fn test_storage_pool_reuses_connections() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let idle_before = wb.storage_pool.idle_count();
+        assert!(idle_before >= 1);
+
+        for i in 0..20 {
+            wb.add_field(
+                MobileFieldType::Custom,
+                format!("field-{}", i),
+                format!("value {}", i),
+            )
+            .unwrap();
+            assert_eq!(wb.get_own_card().unwrap().fields.len(), i + 1);
+            wb.list_contacts().unwrap();
+        }
+
+        // Sequential calls check the same connection out and back in
+        assert_eq!(wb.storage_pool.idle_count(), idle_before);
+
+        // Connections held at once are returned when dropped
+        {
+            let _a = wb.open_storage().unwrap();
+            let _b = wb.open_storage().unwrap();
+            assert_eq!(wb.storage_pool.idle_count(), idle_before.saturating_sub(2));
+        }
+        assert!(wb.storage_pool.idle_count() >= 2);
+    }
+
+    #[test]
+    fn test_get_card_completeness_and_aha_moment() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let report = wb.get_card_completeness().unwrap();
+        assert_eq!(report.percent, 25);
+        assert_eq!(
+            report.missing,
+            vec![
+                MobileCompletenessItem::ContactMethod,
+                MobileCompletenessItem::Photo,
+                MobileCompletenessItem::SocialLink,
+            ]
+        );
+        assert!(wb.check_milestone_aha_moments().unwrap().is_empty());
+
+        wb.add_field(
+            MobileFieldType::Email,
+            "Email".to_string(),
+            "alice@example.com".to_string(),
+        )
+        .unwrap();
+        wb.add_field(
+            MobileFieldType::Social,
+            "GitHub".to_string(),
+            "alice".to_string(),
+        )
+        .unwrap();
+        {
+            let storage = wb.open_storage().unwrap();
+            let mut card = storage.load_own_card().unwrap().unwrap();
+            card.set_avatar(vec![0x89, 0x50, 0x4e, 0x47]).unwrap();
+            storage.save_own_card(&card).unwrap();
+        }
+
+        let report = wb.get_card_completeness().unwrap();
+        assert_eq!(report.percent, 100);
+        assert!(report.missing.is_empty());
+
+        let unlocked = wb.check_milestone_aha_moments().unwrap();
+        assert_eq!(unlocked.len(), 1);
+        assert_eq!(unlocked[0].moment_type, MobileAhaMomentType::CardComplete);
+        assert!(wb.check_milestone_aha_moments().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_render_qr_code_levels() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let qr_data = wb.generate_exchange_qr().unwrap().qr_data;
+
+        let high = render_qr_code(qr_data.clone(), MobileQrErrorCorrection::High).unwrap();
+        let low = render_qr_code(qr_data, MobileQrErrorCorrection::Low).unwrap();
+        assert!(high.lines().count() > low.lines().count());
+
+        let result = render_qr_code("x".repeat(2000), MobileQrErrorCorrection::High);
+        assert!(matches!(result, Err(MobileError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_exchange_intro_note_arrives_with_contact() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+
+        let qr = alice
+            .generate_exchange_qr_with_note("Met at the Rust meetup".to_string())
+            .unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+
+        assert_eq!(
+            bob.get_contact_note(alice_id).unwrap().as_deref(),
+            Some("Met at the Rust meetup")
+        );
+
+        // Without a note the contact starts with none
+        let (carol, _carol_dir) = relay_instance(&relay, "Carol");
+        let qr = carol.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        assert!(bob
+            .get_contact_note(carol.get_public_id().unwrap())
+            .unwrap()
+            .is_none());
+
+        let too_long = "x".repeat(vauchi_core::exchange::MAX_INTRO_NOTE_LEN + 1);
+        assert!(matches!(
+            alice.generate_exchange_qr_with_note(too_long),
+            Err(MobileError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_update_field() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        wb.add_field(
+            MobileFieldType::Phone,
+            "mobile".to_string(),
+            "+1234567890".to_string(),
+        )
+        .unwrap();
+
+        wb.update_field("mobile".to_string(), "+0987654321".to_string())
+            .unwrap();
+
+        let card = wb.get_own_card().unwrap();
+        assert_eq!(card.fields[0].value, "+0987654321");
+    }
+
+    #[test]
+    fn test_upsert_field_creates_then_updates() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let created = wb
+            .upsert_field(
+                MobileFieldType::Phone,
+                "mobile".to_string(),
+                "+1234567890".to_string(),
+            )
+            .unwrap();
+        assert!(created);
+
+        let created = wb
+            .upsert_field(
+                MobileFieldType::Phone,
+                "mobile".to_string(),
+                "+0987654321".to_string(),
+            )
+            .unwrap();
+        assert!(!created);
+
+        let card = wb.get_own_card().unwrap();
+        assert_eq!(card.fields.len(), 1);
+        assert_eq!(card.fields[0].value, "+0987654321");
+    }
+
+    #[test]
+    fn test_remove_field() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        wb.add_field(
+            MobileFieldType::Email,
+            "work".to_string(),
+            "alice@company.com".to_string(),
+        )
+        .unwrap();
+
+        let removed = wb.remove_field("work".to_string()).unwrap();
+        assert!(removed);
+
+        let card = wb.get_own_card().unwrap();
+        assert!(card.fields.is_empty());
+    }
+
+    #[test]
+    fn test_get_field_safe_value() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let mut bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+
+        let mut card = bob.card().clone();
+        let site = ContactField::new(FieldType::Website, "Site", "https://bob.example");
+        let trap = ContactField::new(FieldType::Website, "Promo", "javascript:alert(1)");
+        let (site_id, trap_id) = (site.id().to_string(), trap.id().to_string());
+        card.add_field(site).unwrap();
+        card.add_field(trap).unwrap();
+        bob.update_card(card);
+        wb.open_storage().unwrap().save_contact(&bob).unwrap();
+
+        let safe = wb
+            .get_field_safe_value(bob.id().to_string(), site_id)
+            .unwrap();
+        assert!(safe.is_safe);
+        assert_eq!(safe.value, "https://bob.example");
+
+        let flagged = wb
+            .get_field_safe_value(bob.id().to_string(), trap_id)
+            .unwrap();
+        assert!(!flagged.is_safe);
+        assert!(!is_safe_url(flagged.value));
+
+        assert!(matches!(
+            wb.get_field_safe_value(bob.id().to_string(), "missing".to_string()),
+            Err(MobileError::FieldNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_field_labels_addressed_by_id() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        for value in ["+41791111111", "+41792222222"] {
+            wb.add_field(
+                MobileFieldType::Phone,
+                "mobile".to_string(),
+                value.to_string(),
+            )
+            .unwrap();
+        }
+        let card = wb.get_own_card().unwrap();
+        let first_id = card.fields[0].id.clone();
+        let second_id = card.fields[1].id.clone();
+
+        wb.update_field_by_id(second_id.clone(), "+41793333333".to_string())
+            .unwrap();
+        assert_eq!(
+            wb.get_field_by_id(second_id.clone())
+                .unwrap()
+                .unwrap()
+                .value,
+            "+41793333333"
+        );
+        assert_eq!(
+            wb.get_field_by_id(first_id.clone()).unwrap().unwrap().value,
+            "+41791111111"
+        );
+
+        assert!(wb.remove_field_by_id(second_id.clone()).unwrap());
+        assert!(!wb.remove_field_by_id(second_id.clone()).unwrap());
+        assert!(wb.get_field_by_id(second_id.clone()).unwrap().is_none());
+        assert!(matches!(
+            wb.update_field_by_id(second_id, "+41794444444".to_string()),
+            Err(MobileError::FieldNotFound(_))
+        ));
+
+        let card = wb.get_own_card().unwrap();
+        assert_eq!(card.fields.len(), 1);
+        assert_eq!(card.fields[0].id, first_id);
+    }
+
+    #[test]
+    fn test_get_safety_number() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let bob = Identity::create("Bob");
+        let contact = Contact::from_exchange(
+            *bob.signing_public_key(),
+            ContactCard::new("Bob"),
+            SymmetricKey::generate(),
+        );
+        wb.open_storage().unwrap().save_contact(&contact).unwrap();
+
+        let number = wb.get_safety_number(contact.id().to_string()).unwrap();
+        assert_eq!(number.len(), 60);
+        assert!(number.chars().all(|c| c.is_ascii_digit()));
+
+        let result = wb.get_safety_number("unknown".to_string());
+        assert!(matches!(result, Err(MobileError::ContactNotFound(_))));
+    }
+
+    /// Queues a ratchet-encrypted card update adding an email field.
+    fn queue_email_update(wb: &VauchiMobile, contact_id: &str, name: &str, email: &str) {
+        let old_card = ContactCard::new(name);
+        let mut new_card = old_card.clone();
+        new_card
+            .add_field(ContactField::new(
+                vauchi_core::FieldType::Email,
+                "email",
+                email,
+            ))
+            .unwrap();
+        let delta = vauchi_core::CardDelta::compute(&old_card, &new_card);
+        queue_raw_update(wb, contact_id, &serde_json::to_vec(&delta).unwrap());
+    }
+
+    /// Queues `plaintext` as a ratchet-encrypted card update.
+    fn queue_raw_update(wb: &VauchiMobile, contact_id: &str, plaintext: &[u8]) {
+        let storage = wb.open_storage().unwrap();
+        let (mut ratchet, is_initiator) = storage.load_ratchet_state(contact_id).unwrap().unwrap();
+        let message = ratchet.encrypt(plaintext).unwrap();
+        storage
+            .save_ratchet_state(contact_id, &ratchet, is_initiator)
+            .unwrap();
+        storage
+            .queue_update(&vauchi_core::PendingUpdate {
+                id: uuid::Uuid::new_v4().to_string(),
+                contact_id: contact_id.to_string(),
+                update_type: "card_delta".to_string(),
+                payload: serde_json::to_vec(&message).unwrap(),
+                created_at: 0,
+                retry_count: 0,
+                status: vauchi_core::UpdateStatus::Pending,
+            })
+            .unwrap();
+    }
+
+    fn save_test_contact(wb: &VauchiMobile, identity: &Identity, verified: bool) -> Contact {
+        let mut contact = Contact::from_exchange(
+            *identity.signing_public_key(),
+            ContactCard::new(identity.display_name()),
+            SymmetricKey::generate(),
+        );
+        if verified {
+            contact.mark_fingerprint_verified();
+        }
+        wb.open_storage().unwrap().save_contact(&contact).unwrap();
+        contact
+    }
+
+    #[test]
+    fn test_rotate_storage_key() {
+        let (wb, dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+        let note = vauchi_core::crypto::encrypt(&wb.storage_key(), b"Met in Bern").unwrap();
+        wb.open_storage()
+            .unwrap()
+            .save_personal_notes(bob.id(), &note)
+            .unwrap();
+        let old_key = wb.export_storage_key();
+
+        assert!(wb.rotate_storage_key(vec![0u8; 16]).is_err());
+        let new_key = generate_storage_key();
+        wb.rotate_storage_key(new_key.clone()).unwrap();
+
+        assert_eq!(wb.export_storage_key(), new_key);
+        assert!(wb.get_contact(bob.id().to_string()).unwrap().is_some());
+        assert_eq!(
+            wb.get_contact_note(bob.id().to_string())
+                .unwrap()
+                .as_deref(),
+            Some("Met in Bern")
+        );
+        let data_dir = dir.path().to_string_lossy().to_string();
+        drop(wb);
+
+        // The legacy key file now holds the new key
+        let reopened =
+            VauchiMobile::new(data_dir.clone(), "ws://localhost:8080".to_string()).unwrap();
+        assert_eq!(reopened.export_storage_key(), new_key);
+        assert!(reopened.has_identity());
+        assert_eq!(reopened.list_contacts().unwrap().len(), 1);
+        drop(reopened);
+
+        let stale =
+            VauchiMobile::new_with_secure_key(data_dir, "ws://localhost:8080".to_string(), old_key)
+                .unwrap();
+        assert!(stale.list_contacts().is_err());
+    }
+
+    #[test]
+    fn test_pinned_contact_listed_first() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        save_test_contact(&wb, &Identity::create("Bob"), false);
+        save_test_contact(&wb, &Identity::create("Carol"), false);
+        let zed = save_test_contact(&wb, &Identity::create("Zed"), false);
+
+        let names = |wb: &VauchiMobile| -> Vec<String> {
+            wb.list_contacts()
+                .unwrap()
+                .into_iter()
+                .map(|c| c.display_name)
+                .collect()
+        };
+        assert_eq!(names(&wb), ["Bob", "Carol", "Zed"]);
+
+        wb.set_contact_pinned(zed.id().to_string(), true).unwrap();
+        assert_eq!(names(&wb), ["Zed", "Bob", "Carol"]);
+        assert!(
+            wb.get_contact(zed.id().to_string())
+                .unwrap()
+                .unwrap()
+                .is_pinned
+        );
+
+        wb.set_contact_pinned(zed.id().to_string(), false).unwrap();
+        assert_eq!(names(&wb), ["Bob", "Carol", "Zed"]);
+        assert!(matches!(
+            wb.set_contact_pinned("missing".to_string(), true),
+            Err(MobileError::ContactNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_contact_limit_rejects_new_contacts() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        wb.set_contact_limit(1).unwrap();
+        assert_eq!(wb.get_contact_limit().unwrap(), 1);
+
+        let bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+        let carol = Contact::from_exchange(
+            *Identity::create("Carol").signing_public_key(),
+            ContactCard::new("Carol"),
+            SymmetricKey::generate(),
+        );
+        let result = wb.open_storage().unwrap().save_contact(&carol);
+        assert!(matches!(
+            result.map_err(MobileError::from),
+            Err(MobileError::TooManyContacts(1))
+        ));
+
+        wb.set_contact_blocked(bob.id().to_string(), true).unwrap();
+        assert_eq!(wb.contact_count().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_import_verification_bundle() {
+        use base64::Engine;
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let organizer = Identity::create("Organizer");
+        let bob = Identity::create("Bob");
+        let carol = Identity::create("Carol");
+        let organizer_contact = save_test_contact(&wb, &organizer, true);
+        let bob_contact = save_test_contact(&wb, &bob, false);
+        let carol_contact = save_test_contact(&wb, &carol, false);
+
+        let stranger = Identity::create("Stranger");
+        let attestation = VerificationAttestation::create(
+            vec![
+                *bob.signing_public_key(),
+                *carol.signing_public_key(),
+                *stranger.signing_public_key(),
+            ],
+            organizer.signing_keypair(),
+        );
+        let bundle = base64::engine::general_purpose::STANDARD.encode(attestation.to_bytes());
+
+        let marked = wb.import_verification_bundle(bundle).unwrap();
+        assert_eq!(marked, 2);
+
+        for id in [bob_contact.id(), carol_contact.id()] {
+            let contact = wb.get_contact(id.to_string()).unwrap().unwrap();
+            assert!(contact.is_verified);
+            assert_eq!(contact.verified_by.as_deref(), Some(organizer_contact.id()));
+        }
+    }
+
+    #[test]
+    fn test_import_verification_bundle_invalid_signature() {
+        use base64::Engine;
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let organizer = Identity::create("Organizer");
+        let bob = Identity::create("Bob");
+        let mallory = Identity::create("Mallory");
+        save_test_contact(&wb, &organizer, true);
+        let bob_contact = save_test_contact(&wb, &bob, false);
+        save_test_contact(&wb, &mallory, false);
+
+        let mut attestation = VerificationAttestation::create(
+            vec![*bob.signing_public_key()],
+            organizer.signing_keypair(),
+        );
+        attestation.set_subject_keys_for_testing(vec![*mallory.signing_public_key()]);
+        let bundle = base64::engine::general_purpose::STANDARD.encode(attestation.to_bytes());
+
+        let result = wb.import_verification_bundle(bundle);
+        assert!(matches!(result, Err(MobileError::CryptoError(_))));
+
+        let contact = wb
+            .get_contact(bob_contact.id().to_string())
+            .unwrap()
+            .unwrap();
+        assert!(!contact.is_verified);
+    }
+
+    #[test]
+    fn test_import_verification_bundle_untrusted_attester() {
+        use base64::Engine;
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let organizer = Identity::create("Organizer");
+        let bob = Identity::create("Bob");
+        // Organizer is a contact, but not verified by us
+        save_test_contact(&wb, &organizer, false);
+        save_test_contact(&wb, &bob, false);
+
+        let attestation = VerificationAttestation::create(
+            vec![*bob.signing_public_key()],
+            organizer.signing_keypair(),
+        );
+        let bundle = base64::engine::general_purpose::STANDARD.encode(attestation.to_bytes());
+
+        let result = wb.import_verification_bundle(bundle);
+        assert!(matches!(result, Err(MobileError::CryptoError(_))));
+    }
+
+    #[test]
+    fn test_import_verification_bundle_is_not_transitive() {
+        use base64::Engine;
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let encode = |attestation: VerificationAttestation| {
+            base64::engine::general_purpose::STANDARD.encode(attestation.to_bytes())
+        };
+
+        let organizer = Identity::create("Organizer");
+        let bob = Identity::create("Bob");
+        let carol = Identity::create("Carol");
+        save_test_contact(&wb, &organizer, true);
+        save_test_contact(&wb, &bob, false);
+        let carol_contact = save_test_contact(&wb, &carol, false);
+
+        // The attester's clock is ahead; verified_at is not in the future
+        wb.set_clock_for_testing(Some(1_000));
+        let bundle = encode(VerificationAttestation::create(
+            vec![*bob.signing_public_key()],
+            organizer.signing_keypair(),
+        ));
+        assert_eq!(wb.import_verification_bundle(bundle).unwrap(), 1);
+        let bob_id = hex::encode(bob.signing_public_key());
+        let bob_contact = wb.get_contact(bob_id).unwrap().unwrap();
+        assert_eq!(bob_contact.verified_at, Some(1_000));
+
+        // Bob was only verified through the organizer, so he can't vouch
+        let bundle = encode(VerificationAttestation::create(
+            vec![*carol.signing_public_key()],
+            bob.signing_keypair(),
+        ));
+        assert!(matches!(
+            wb.import_verification_bundle(bundle),
+            Err(MobileError::CryptoError(_))
+        ));
+        let carol = wb
+            .get_contact(carol_contact.id().to_string())
+            .unwrap()
+            .unwrap();
+        assert!(!carol.is_verified);
+    }
+
+    #[test]
+    fn test_check_milestone_aha_moments_contact_boundary() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        for i in 0..9 {
+            save_test_contact(&wb, &Identity::create(&format!("Contact {}", i)), false);
+        }
+        assert!(wb.check_milestone_aha_moments().unwrap().is_empty());
+
+        save_test_contact(&wb, &Identity::create("Contact 9"), false);
+        let unlocked = wb.check_milestone_aha_moments().unwrap();
+        assert_eq!(unlocked.len(), 1);
+        assert_eq!(
+            unlocked[0].moment_type,
+            MobileAhaMomentType::ContactMilestone
+        );
+
+        // Already fired, including after adding more contacts
+        save_test_contact(&wb, &Identity::create("Contact 10"), false);
+        assert!(wb.check_milestone_aha_moments().unwrap().is_empty());
+        assert!(wb.has_seen_aha_moment(MobileAhaMomentType::ContactMilestone));
+    }
+
+    #[test]
+    fn test_usage_stats_count_exchanges_and_verifications() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+        assert_eq!(
+            bob.get_usage_stats().unwrap(),
+            MobileUsageStats {
+                exchanges_completed: 0,
+                failed_qr_scans: 0,
+                contacts_verified: 0,
+            }
+        );
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        assert!(bob.complete_exchange(qr.qr_data).unwrap().success);
+        assert!(bob.complete_exchange("not a qr code".to_string()).is_err());
+        assert_eq!(bob.get_usage_stats().unwrap().exchanges_completed, 1);
+        assert_eq!(bob.get_usage_stats().unwrap().failed_qr_scans, 1);
+
+        // The scanned side completes the exchange when it syncs
+        alice.sync().unwrap();
+        assert_eq!(alice.get_usage_stats().unwrap().exchanges_completed, 1);
+
+        // Verifying the same contact again is not counted twice
+        bob.verify_contact(alice_id.clone()).unwrap();
+        bob.verify_contact(alice_id.clone()).unwrap();
+        assert_eq!(bob.get_usage_stats().unwrap().contacts_verified, 1);
+
+        // The verified milestone counts contacts verified before deletion
+        bob.remove_contact(alice_id).unwrap();
+        let unlocked = bob.check_milestone_aha_moments().unwrap();
+        assert!(unlocked
+            .iter()
+            .any(|m| m.moment_type == MobileAhaMomentType::FirstVerifiedContact));
+        assert_eq!(alice.get_usage_stats().unwrap().contacts_verified, 0);
+    }
+
+    #[test]
+    fn test_mark_demo_actions_auto_removes_demo() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        assert!(wb.init_demo_contact_if_needed().unwrap().is_some());
+
+        assert!(!wb.mark_demo_action(MobileDemoAction::Exchanged).unwrap());
+        assert!(!wb.mark_demo_action(MobileDemoAction::Verified).unwrap());
+        assert!(wb.get_demo_contact().unwrap().is_some());
+
+        assert!(wb.mark_demo_action(MobileDemoAction::HidField).unwrap());
+        let state = wb.get_demo_contact_state();
+        assert!(!state.is_active);
+        assert!(state.auto_removed);
+        assert!(wb.get_demo_contact().unwrap().is_none());
+        assert!(wb.init_demo_contact_if_needed().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_set_locale_localizes_demo_and_aha() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let english = wb.init_demo_contact_if_needed().unwrap().unwrap();
+
+        wb.set_locale("xx".to_string()).unwrap();
+        let fallback = wb.get_demo_contact().unwrap().unwrap();
+        assert_eq!(fallback.tip_title, english.tip_title);
+
+        wb.set_locale("de".to_string()).unwrap();
+        let german = wb.get_demo_contact().unwrap().unwrap();
+        assert_eq!(german.tip_title, "Teile deine Karte");
+
+        let moment = wb
+            .try_trigger_aha_moment(MobileAhaMomentType::CardCreationComplete)
+            .unwrap()
+            .unwrap();
+        assert_eq!(moment.title, "Deine Karte ist bereit");
+    }
+
+    #[test]
+    fn test_error_user_messages() {
+        let detail = "SQLITE_BUSY at pool.rs:42".to_string();
+        let errors = vec![
+            MobileError::NotInitialized,
+            MobileError::AlreadyInitialized,
+            MobileError::IdentityNotFound,
+            MobileError::ContactNotFound(detail.clone()),
+            MobileError::InvalidQrCode,
+            MobileError::ExchangeFailed(detail.clone()),
+            MobileError::SyncFailed(detail.clone()),
+            MobileError::StorageError(detail.clone()),
+            MobileError::CryptoError(detail.clone()),
+            MobileError::SerializationError(detail.clone()),
+            MobileError::NetworkError(detail.clone()),
+            MobileError::InsecureTransport(detail.clone()),
+            MobileError::FieldNotFound(detail.clone()),
+            MobileError::AlreadyValidated,
+            MobileError::ClaimExpired,
+            MobileError::ClockRolledBack(3600),
+            MobileError::RateLimited(detail.clone()),
+            MobileError::TooManyLabels(20),
+            MobileError::TooManyContacts(500),
+            MobileError::DuplicateLabelName(detail.clone()),
+            MobileError::SelfContact,
+            MobileError::SelfVouching,
+            MobileError::DuplicateVoucher,
+            MobileError::VoucherMismatch,
+            MobileError::InvalidInput(detail.clone()),
+            MobileError::Internal(detail.clone()),
+        ];
+
+        for error in &errors {
+            for locale in [
+                MobileLocale::English,
+                MobileLocale::German,
+                MobileLocale::French,
+                MobileLocale::Spanish,
+            ] {
+                let message = error.user_message(locale);
+                assert!(!message.is_empty(), "{error:?} in {locale:?}");
+                assert!(!message.contains("Missing"), "{error:?} in {locale:?}");
+                assert!(!message.contains(&detail), "{error:?} in {locale:?}");
+            }
+        }
+
+        assert_eq!(
+            get_error_user_message(
+                MobileError::NetworkError(detail.clone()),
+                MobileLocale::English
+            ),
+            "Couldn't connect. Check your internet."
+        );
+        assert_eq!(
+            MobileError::TooManyLabels(20).user_message(MobileLocale::German),
+            "Sie können höchstens 20 Labels haben."
+        );
+        // Display stays the developer text for logs
+        assert_eq!(
+            MobileError::StorageError(detail.clone()).to_string(),
+            format!("Storage error: {detail}")
+        );
+    }
+
+    #[test]
+    fn test_sync_if_due_skips_when_recent() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        wb.save_last_sync(now).unwrap();
+
+        // Recent sync and nothing pending: no network access attempted
+        assert!(!wb.sync_if_due(3600).unwrap());
+        assert_eq!(wb.get_last_sync_time(), Some(now));
+        assert_eq!(wb.get_sync_status(), MobileSyncStatus::Idle);
+    }
+
+    #[test]
+    fn test_cancel_pending_update() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+
+        let storage = wb.open_storage().unwrap();
+        for id in ["update-1", "update-2"] {
+            storage
+                .queue_update(&vauchi_core::PendingUpdate {
+                    id: id.to_string(),
+                    contact_id: bob.id().to_string(),
+                    update_type: "card_delta".to_string(),
+                    payload: vec![1, 2, 3],
+                    created_at: 0,
+                    retry_count: 0,
+                    status: vauchi_core::UpdateStatus::Pending,
+                })
+                .unwrap();
+        }
+
+        assert!(wb.cancel_pending_update("update-1".to_string()).unwrap());
+        assert!(!wb.cancel_pending_update("update-1".to_string()).unwrap());
+
+        let remaining = storage.get_pending_updates(bob.id()).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, "update-2");
+    }
+
+    #[test]
+    fn test_sync_if_due_forces_when_pending() {
+        let dir = TempDir::new().unwrap();
+        // Nothing listens on port 1, so an attempted sync fails fast
+        let wb = VauchiMobile::new(
+            dir.path().to_string_lossy().to_string(),
+            "ws://127.0.0.1:1".to_string(),
+        )
+        .unwrap();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        wb.save_last_sync(now).unwrap();
+
+        let bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+        wb.open_storage()
+            .unwrap()
+            .queue_update(&vauchi_core::PendingUpdate {
+                id: "update-1".to_string(),
+                contact_id: bob.id().to_string(),
+                update_type: "card_delta".to_string(),
+                payload: vec![1, 2, 3],
+                created_at: now,
+                retry_count: 0,
+                status: vauchi_core::UpdateStatus::Pending,
+            })
+            .unwrap();
+
+        // Pending updates override the interval, so a sync is attempted
+        assert!(wb.sync_if_due(3600).is_err());
+        assert_eq!(wb.get_sync_status(), MobileSyncStatus::Error);
+        assert_eq!(wb.get_last_sync_time(), Some(now));
+    }
+
+    #[test]
+    fn test_sync_if_due_ignores_updates_for_blocked_contacts() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        wb.save_last_sync(now).unwrap();
+
+        let mut bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+        bob.block();
+        let storage = wb.open_storage().unwrap();
+        storage.save_contact(&bob).unwrap();
+        storage
+            .queue_update(&vauchi_core::PendingUpdate {
+                id: "update-1".to_string(),
+                contact_id: bob.id().to_string(),
+                update_type: "card_delta".to_string(),
+                payload: vec![1, 2, 3],
+                created_at: now,
+                retry_count: 0,
+                status: vauchi_core::UpdateStatus::Pending,
+            })
+            .unwrap();
+
+        // Frozen updates do not force a sync before the interval
+        assert!(!wb.sync_if_due(3600).unwrap());
+        assert_eq!(storage.count_all_pending_updates().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_get_sync_health() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+        let storage = wb.open_storage().unwrap();
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        // Nothing waiting, even before the first sync
+        let health = wb.get_sync_health().unwrap();
+        assert_eq!(health.status, MobileSyncHealthStatus::Healthy);
+        assert!(health.reasons.is_empty());
+
+        // A retry is due after a recent sync
+        wb.save_last_sync(now).unwrap();
+        storage
+            .create_retry_entry(&vauchi_core::storage::RetryEntry {
+                message_id: "update-1".to_string(),
+                recipient_id: bob.id().to_string(),
+                payload: vec![1, 2, 3],
+                attempt: 1,
+                next_retry: now - 1,
+                created_at: now - 10,
+                max_attempts: 5,
+            })
+            .unwrap();
+        let health = wb.get_sync_health().unwrap();
+        assert_eq!(health.status, MobileSyncHealthStatus::Degraded);
+        assert_eq!(health.due_retries, 1);
+        assert_eq!(
+            health.reasons,
+            vec![MobileSyncHealthReason::RetriesDue { count: 1 }]
+        );
+
+        // Pending work and no successful sync for two days
+        storage.delete_retry_entry("update-1").unwrap();
+        storage
+            .queue_update(&vauchi_core::PendingUpdate {
+                id: "update-2".to_string(),
+                contact_id: bob.id().to_string(),
+                update_type: "card_delta".to_string(),
+                payload: vec![1, 2, 3],
+                created_at: now,
+                retry_count: 0,
+                status: vauchi_core::UpdateStatus::Pending,
+            })
+            .unwrap();
+        assert_eq!(
+            wb.get_sync_health().unwrap().status,
+            MobileSyncHealthStatus::Healthy
+        );
+        wb.save_last_sync(now - 2 * 24 * 60 * 60).unwrap();
+        let health = wb.get_sync_health().unwrap();
+        assert_eq!(health.status, MobileSyncHealthStatus::Stalled);
+        assert_eq!(health.pending_updates, 1);
+        assert!(matches!(
+            health.reasons.as_slice(),
+            [MobileSyncHealthReason::SyncOverdue { since_secs: Some(secs) }]
+                if *secs >= 2 * 24 * 60 * 60
+        ));
+    }
+
+    #[test]
+    fn test_get_stale_updates_skips_fresh_updates() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let storage = wb.open_storage().unwrap();
+        for (id, created_at) in [("old", now - 15 * 86400), ("fresh", now)] {
+            storage
+                .queue_update(&vauchi_core::PendingUpdate {
+                    id: id.to_string(),
+                    contact_id: bob.id().to_string(),
+                    update_type: "card_delta".to_string(),
+                    payload: vec![1, 2, 3],
+                    created_at,
+                    retry_count: 0,
+                    status: vauchi_core::UpdateStatus::Pending,
+                })
+                .unwrap();
+        }
+
+        let stale = wb.get_stale_updates(14).unwrap();
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].id, "old");
+        assert_eq!(stale[0].contact_id, bob.id());
+        assert_eq!(stale[0].state, MobileStaleUpdateState::NeverSent);
+    }
+
+    #[test]
+    fn test_send_pending_updates_reports_per_contact_outcomes() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let identity = wb.get_identity().unwrap();
+        let storage = wb.open_storage().unwrap();
+
+        let contacts: Vec<Contact> = ["Bob", "Carol", "Dave"]
+            .iter()
+            .map(|name| save_test_contact(&wb, &Identity::create(name), false))
+            .collect();
+        for (i, contact) in contacts.iter().enumerate() {
+            storage
+                .queue_update(&vauchi_core::PendingUpdate {
+                    id: format!("update-{}", i),
+                    contact_id: contact.id().to_string(),
+                    update_type: "card_delta".to_string(),
+                    payload: vec![i as u8],
+                    created_at: 0,
+                    retry_count: 0,
+                    status: vauchi_core::UpdateStatus::Pending,
+                })
+                .unwrap();
+        }
+
+        // Simulate a relay that rejects Carol's update
+        let failing_id = contacts[1].id().to_string();
+        let result = sync::send_pending_updates_with(&identity, &storage, |data| {
+            let envelope = protocol::decode_message(&data).unwrap();
+            match envelope.payload {
+                protocol::MessagePayload::EncryptedUpdate(u) if u.recipient_id == failing_id => {
+                    Err("connection reset".to_string())
+                }
+                _ => Ok(()),
+            }
+        })
+        .unwrap();
+
+        assert_eq!(result.sent, 2);
+        assert_eq!(result.outcomes.len(), 3);
+        for outcome in &result.outcomes {
+            if outcome.contact_id == failing_id {
+                assert!(!outcome.success);
+                assert_eq!(outcome.error.as_deref(), Some("connection reset"));
+            } else {
+                assert!(outcome.success);
+                assert!(outcome.error.is_none());
+            }
+        }
+
+        // The failed update stays queued for the next sync
+        assert_eq!(storage.count_pending_updates(&failing_id).unwrap(), 1);
+        assert_eq!(storage.count_all_pending_updates().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_resume_exchange_after_send_failure() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+
+        // The network drops after Bob scans Alice's QR
+        let qr = alice.generate_exchange_qr().unwrap();
+        relay.set_offline(true);
+        let exchange = bob.complete_exchange(qr.qr_data).unwrap();
+        assert!(!exchange.success);
+        assert!(exchange.error_message.is_some());
+        assert!(bob.get_contact(alice_id.clone()).unwrap().is_some());
+        assert_eq!(relay.pending_for(&alice_id), 0);
+        assert_eq!(bob.get_pending_exchange_count().unwrap(), 1);
+
+        // Still offline: the exchange stays pending
+        assert_eq!(bob.resume_pending_exchanges().unwrap(), 0);
+        assert_eq!(bob.get_pending_exchange_count().unwrap(), 1);
+
+        relay.set_offline(false);
+        assert_eq!(bob.resume_pending_exchanges().unwrap(), 1);
+        assert_eq!(bob.get_pending_exchange_count().unwrap(), 0);
+        assert_eq!(relay.pending_for(&alice_id), 1);
+
+        let pending = bob
+            .open_storage()
+            .unwrap()
+            .load_pending_exchange(&alice_id)
+            .unwrap()
+            .unwrap();
+        assert!(pending.is_delivered());
+        assert_eq!(pending.attempts, 3);
+
+        // Alice now learns about the exchange
+        let result = alice.sync().unwrap();
+        assert_eq!(result.contacts_added, 1);
+        assert!(alice.get_contact(bob_id).unwrap().is_some());
+
+        // Nothing left to resend
+        assert_eq!(bob.resume_pending_exchanges().unwrap(), 0);
+        assert_eq!(relay.pending_for(&alice_id), 0);
+    }
+
+    #[test]
+    fn test_sync_retries_exchange_up_to_limit() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+        assert_eq!(bob.get_exchange_retry_limit(), DEFAULT_EXCHANGE_RETRY_LIMIT);
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        relay.set_offline(true);
+        assert!(!bob.complete_exchange(qr.qr_data).unwrap().success);
+        assert_eq!(bob.get_pending_exchange_count().unwrap(), 1);
+        relay.set_offline(false);
+
+        // The failed send already used up the only attempt
+        bob.set_exchange_retry_limit(1).unwrap();
+        assert_eq!(bob.sync().unwrap().updates_sent, 0);
+        assert_eq!(bob.get_pending_exchange_count().unwrap(), 1);
+        assert_eq!(relay.pending_for(&alice_id), 0);
+
+        bob.set_exchange_retry_limit(5).unwrap();
+        assert_eq!(bob.sync().unwrap().updates_sent, 1);
+        assert_eq!(bob.get_pending_exchange_count().unwrap(), 0);
+        assert_eq!(
+            bob.get_delivery_records_for_contact(alice_id.clone())
+                .unwrap()
+                .len(),
+            1
+        );
+
+        assert_eq!(alice.sync().unwrap().contacts_added, 1);
+
+        // Delivered exchanges are not sent again
+        assert_eq!(bob.sync().unwrap().updates_sent, 0);
+    }
+
+    #[test]
+    fn test_sync_batch_stops_at_limit_and_resumes() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+
+        // Four updates from strangers, then Bob's exchange message
+        for i in 0..4u8 {
+            let update = protocol::EncryptedUpdate {
+                recipient_id: alice_id.clone(),
+                sender_id: hex::encode([i; 32]),
+                ciphertext: vec![i; 48],
+            };
+            let envelope =
+                protocol::create_envelope(protocol::MessagePayload::EncryptedUpdate(update));
+            relay.deliver(&alice_id, protocol::encode_message(&envelope).unwrap());
+        }
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        assert_eq!(relay.pending_for(&alice_id), 5);
+
+        // No time left: nothing is read
+        let result = alice.sync_batch(10, 0).unwrap();
+        assert!(result.has_more);
+        assert_eq!(relay.pending_for(&alice_id), 5);
+
+        let result = alice.sync_batch(2, 60_000).unwrap();
+        assert!(result.has_more);
+        assert_eq!(result.contacts_added, 0);
+        assert_eq!(relay.pending_for(&alice_id), 3);
+
+        let result = alice.sync_batch(2, 60_000).unwrap();
+        assert!(result.has_more);
+        assert_eq!(relay.pending_for(&alice_id), 1);
+
+        // The last batch reaches the exchange and drains the relay
+        let result = alice.sync_batch(2, 60_000).unwrap();
+        assert!(!result.has_more);
+        assert_eq!(result.contacts_added, 1);
+        assert_eq!(relay.pending_for(&alice_id), 0);
+        assert_eq!(alice.contact_count().unwrap(), 1);
+
+        assert!(!alice.sync().unwrap().has_more);
+    }
+
+    #[test]
+    fn test_exchange_and_card_update_over_mock_relay() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let alice_dir = TempDir::new().unwrap();
+        let bob_dir = TempDir::new().unwrap();
+        let alice = VauchiMobile::new_with_transport(
+            alice_dir.path().to_string_lossy().to_string(),
+            Arc::new(relay.clone()),
+        )
+        .unwrap();
+        let bob = VauchiMobile::new_with_transport(
+            bob_dir.path().to_string_lossy().to_string(),
+            Arc::new(relay.clone()),
+        )
+        .unwrap();
+        alice.create_identity("Alice".to_string()).unwrap();
+        bob.create_identity("Bob".to_string()).unwrap();
+        let alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+
+        // Bob scans Alice's QR; the exchange message waits at the relay
+        let qr = alice.generate_exchange_qr().unwrap();
+        let exchange = bob.complete_exchange(qr.qr_data).unwrap();
+        assert_eq!(exchange.contact_id, alice_id);
+        assert_eq!(relay.pending_for(&alice_id), 1);
+
+        // Alice receives the exchange and responds
+        let result = alice.sync().unwrap();
+        assert_eq!(result.contacts_added, 1);
+        let bob_contact = alice.get_contact(bob_id.clone()).unwrap().unwrap();
+        assert_eq!(bob_contact.display_name, "Bob");
+        assert_eq!(relay.pending_for(&bob_id), 1);
+
+        // Bob learns Alice's name from the response
+        bob.sync().unwrap();
+        let alice_contact = bob.get_contact(alice_id.clone()).unwrap().unwrap();
+        assert_eq!(alice_contact.display_name, "Alice");
+
+        // Bob queues a ratchet-encrypted card update for Alice
+        {
+            let storage = bob.open_storage().unwrap();
+            let (mut ratchet, is_initiator) =
+                storage.load_ratchet_state(&alice_id).unwrap().unwrap();
+            let old_card = ContactCard::new("Bob");
+            let mut new_card = old_card.clone();
+            new_card
+                .add_field(ContactField::new(
+                    vauchi_core::FieldType::Email,
+                    "email",
+                    "bob@example.com",
+                ))
+                .unwrap();
+            let delta = vauchi_core::CardDelta::compute(&old_card, &new_card);
+            let message = ratchet
+                .encrypt(&serde_json::to_vec(&delta).unwrap())
+                .unwrap();
+            storage
+                .save_ratchet_state(&alice_id, &ratchet, is_initiator)
+                .unwrap();
+            storage
+                .queue_update(&vauchi_core::PendingUpdate {
+                    id: "update-1".to_string(),
+                    contact_id: alice_id.clone(),
+                    update_type: "card_delta".to_string(),
+                    payload: serde_json::to_vec(&message).unwrap(),
+                    created_at: 0,
+                    retry_count: 0,
+                    status: vauchi_core::UpdateStatus::Pending,
+                })
+                .unwrap();
+        }
+        let result = bob.sync().unwrap();
+        assert_eq!(result.updates_sent, 1);
+
+        // Alice applies the update
+        let result = alice.sync().unwrap();
+        assert_eq!(result.cards_updated, 1);
+        let bob_contact = alice.get_contact(bob_id).unwrap().unwrap();
+        assert!(bob_contact
+            .card
+            .fields
+            .iter()
+            .any(|f| f.value == "bob@example.com"));
+    }
+
+    #[test]
+    fn test_redelivered_exchange_processed_once() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let alice_dir = TempDir::new().unwrap();
+        let bob_dir = TempDir::new().unwrap();
+        let alice = VauchiMobile::new_with_transport(
+            alice_dir.path().to_string_lossy().to_string(),
+            Arc::new(relay.clone()),
+        )
+        .unwrap();
+        let bob = VauchiMobile::new_with_transport(
+            bob_dir.path().to_string_lossy().to_string(),
+            Arc::new(relay.clone()),
+        )
+        .unwrap();
+        alice.create_identity("Alice".to_string()).unwrap();
+        bob.create_identity("Bob".to_string()).unwrap();
+        let alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        let frames = relay.frames_for(&alice_id);
+        assert_eq!(frames.len(), 1);
+
+        // The relay delivers the same exchange message twice
+        relay.deliver(&alice_id, frames[0].clone());
+        let result = alice.sync().unwrap();
+        assert_eq!(result.contacts_added, 1);
+        assert_eq!(alice.contact_count().unwrap(), 1);
+        assert_eq!(relay.pending_for(&bob_id), 1);
+
+        // A later redelivery does not resurrect a removed contact
+        assert!(alice.remove_contact(bob_id.clone()).unwrap());
+        relay.deliver(&alice_id, frames[0].clone());
+        let result = alice.sync().unwrap();
+        assert_eq!(result.contacts_added, 0);
+        assert_eq!(alice.contact_count().unwrap(), 0);
+        assert_eq!(relay.pending_for(&bob_id), 1);
+    }
+
+    #[test]
+    fn test_sync_cursor_skips_processed_messages() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        relay.set_retain_delivered(true);
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        assert_eq!(alice.sync().unwrap().contacts_added, 1);
+        assert_eq!(relay.served_to(&alice_id), 1);
+
+        // The relay still holds the message, but the cursor skips it
+        assert_eq!(relay.pending_for(&alice_id), 1);
+        alice.sync().unwrap();
+        assert_eq!(relay.served_to(&alice_id), 1);
+
+        // Messages queued after the cursor are still delivered
+        let frames = relay.frames_for(&alice_id);
+        relay.deliver(&alice_id, frames[0].clone());
+        alice.sync().unwrap();
+        assert_eq!(relay.served_to(&alice_id), 2);
+
+        // Without a usable cursor everything is delivered again, and
+        // redelivered messages are skipped by ID
+        alice
+            .set_relay_url("wss://other.example.com".to_string())
+            .unwrap();
+        assert_eq!(alice.sync().unwrap().contacts_added, 0);
+        assert_eq!(relay.served_to(&alice_id), 4);
+        assert_eq!(alice.contact_count().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_unhandled_message_is_redelivered() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        relay.set_retain_delivered(true);
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        alice.sync().unwrap();
+        bob.sync().unwrap();
+
+        // The update, then a replay under a new ID the ratchet cannot open
+        queue_email_update(&bob, &alice_id, "Bob", "bob@example.com");
+        bob.sync().unwrap();
+        let frame = relay.frames_for(&alice_id).pop().unwrap();
+        let mut envelope = protocol::decode_message(&frame).unwrap();
+        envelope.message_id = "replayed".to_string();
+        relay.deliver(&alice_id, protocol::encode_message(&envelope).unwrap());
+
+        assert_eq!(alice.sync().unwrap().cards_updated, 1);
+        let served = relay.served_to(&alice_id);
+
+        // The cursor stops before the failed message, so it comes again
+        assert_eq!(alice.sync().unwrap().cards_updated, 0);
+        assert_eq!(relay.served_to(&alice_id), served + 1);
+        let storage = alice.open_storage().unwrap();
+        assert!(!storage.is_message_processed("replayed").unwrap());
+    }
+
+    #[test]
+    fn test_padded_updates() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        alice.sync().unwrap();
+        bob.sync().unwrap();
+
+        assert!(!bob.get_pad_updates());
+        bob.set_pad_updates(true).unwrap();
+        bob.add_field(
+            MobileFieldType::Email,
+            "email".to_string(),
+            "bob@example.com".to_string(),
+        )
+        .unwrap();
+        assert_eq!(bob.push_field_update("email".to_string(), None).unwrap(), 1);
+        let queued = bob
+            .open_storage()
+            .unwrap()
+            .get_pending_updates(&alice_id)
+            .unwrap();
+        let message: vauchi_core::crypto::ratchet::RatchetMessage =
+            serde_json::from_slice(&queued[0].payload).unwrap();
+        assert!(message.ciphertext.len() >= vauchi_core::crypto::padding::MIN_PADDING_BUCKET);
+
+        bob.sync().unwrap();
+        let result = alice.sync().unwrap();
+        assert_eq!(result.cards_updated, 1);
+        assert!(result.update_failures.is_empty());
+
+        // Both sides learned from the exchange that the other strips padding
+        let bob_storage = bob.open_storage().unwrap();
+        let mut alice_contact = bob_storage.load_contact(&alice_id).unwrap().unwrap();
+        assert!(alice_contact.accepts_padding());
+        assert!(alice
+            .open_storage()
+            .unwrap()
+            .load_contact(&bob_id)
+            .unwrap()
+            .unwrap()
+            .accepts_padding());
+
+        // A contact that never advertised it gets unpadded updates
+        alice_contact.set_accepts_padding(false);
+        bob_storage.save_contact(&alice_contact).unwrap();
+        assert_eq!(bob.push_field_update("email".to_string(), None).unwrap(), 1);
+        let queued = bob_storage.get_pending_updates(&alice_id).unwrap();
+        let unpadded: vauchi_core::crypto::ratchet::RatchetMessage =
+            serde_json::from_slice(&queued[0].payload).unwrap();
+        assert!(unpadded.ciphertext.len() < message.ciphertext.len());
+        bob.sync().unwrap();
+        assert_eq!(alice.sync().unwrap().cards_updated, 1);
+
+        // A broken padding header is reported rather than read as empty
+        queue_raw_update(&bob, &alice_id, &[0, 0xff, 0xff, 0xff, 0xff]);
+        bob.sync().unwrap();
+        let result = alice.sync().unwrap();
+        assert_eq!(result.cards_updated, 0);
+        assert_eq!(result.update_failures.len(), 1);
+        assert_eq!(result.update_failures[0].contact_id, bob_id);
+    }
+
+    #[test]
+    fn test_update_in_newer_format_is_retried() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        relay.set_retain_delivered(true);
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        alice.sync().unwrap();
+        bob.sync().unwrap();
+
+        let delta =
+            vauchi_core::CardDelta::compute(&ContactCard::new("Bob"), &ContactCard::new("Robert"));
+        let mut wire: serde_json::Value = serde_json::from_slice(&delta.to_wire()).unwrap();
+        wire["format"] = serde_json::json!(vauchi_core::sync::DELTA_FORMAT_VERSION + 1);
+        queue_raw_update(&bob, &alice_id, &serde_json::to_vec(&wire).unwrap());
+        bob.sync().unwrap();
+
+        // Reported on every sync until it can be read, without advancing
+        // the ratchet past it
+        for _ in 0..2 {
+            let result = alice.sync().unwrap();
+            assert_eq!(result.cards_updated, 0);
+            assert_eq!(result.update_failures.len(), 1);
+            assert_eq!(result.update_failures[0].contact_id, bob_id);
+            assert!(result.update_failures[0]
+                .error
+                .contains("Unsupported delta format"));
+        }
+    }
+
+    #[test]
+    fn test_rotate_identity_key_moves_contacts() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let old_alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        alice.sync().unwrap();
+        bob.sync().unwrap();
+        bob.verify_contact(old_alice_id.clone()).unwrap();
+
+        let new_alice_id = alice.rotate_identity_key().unwrap();
+        assert_ne!(new_alice_id, old_alice_id);
+        assert_eq!(alice.get_public_id().unwrap(), new_alice_id);
+        assert_eq!(alice.sync().unwrap().updates_sent, 1);
+        bob.sync().unwrap();
+
+        // Bob follows the rotation, keeping verification
+        assert!(bob.get_contact(old_alice_id).unwrap().is_none());
+        let alice_contact = bob.get_contact(new_alice_id.clone()).unwrap().unwrap();
+        assert_eq!(alice_contact.display_name, "Alice");
+        assert!(alice_contact.is_verified);
+        assert_eq!(bob.list_contacts().unwrap().len(), 1);
+
+        // Updates reach Alice at her new ID over the same ratchet
+        queue_email_update(&bob, &new_alice_id, "Bob", "bob@example.com");
+        bob.sync().unwrap();
+        assert_eq!(alice.sync().unwrap().cards_updated, 1);
+        assert!(alice
+            .get_contact(bob_id)
+            .unwrap()
+            .unwrap()
+            .card
+            .fields
+            .iter()
+            .any(|f| f.value == "bob@example.com"));
+    }
+
+    #[test]
+    fn test_push_field_update_queues_only_named_field() {
+        use vauchi_core::crypto::ratchet::DoubleRatchetState;
+        use vauchi_core::exchange::X3DHKeyPair;
+        use vauchi_core::sync::{CardDelta, FieldChange};
+
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        wb.add_field(
+            MobileFieldType::Email,
+            "work".to_string(),
+            "old@work.example".to_string(),
+        )
+        .unwrap();
+        wb.add_field(
+            MobileFieldType::Phone,
+            "mobile".to_string(),
+            "+41 79 000 00 00".to_string(),
+        )
+        .unwrap();
+
+        // Each contact gets a ratchet whose other end we keep to read the update
+        let mut ends = Vec::new();
+        for name in ["Bob", "Carol", "Dave"] {
+            let contact = save_test_contact(&wb, &Identity::create(name), false);
+            let their_dh = X3DHKeyPair::generate();
+            let ratchet = DoubleRatchetState::initialize_initiator(
+                contact.shared_key(),
+                *their_dh.public_key(),
+            );
+            let storage = wb.open_storage().unwrap();
+            storage
+                .save_ratchet_state(contact.id(), &ratchet, true)
+                .unwrap();
+            ends.push((
+                contact.id().to_string(),
+                DoubleRatchetState::initialize_responder(contact.shared_key(), their_dh),
+            ));
+        }
+        let (bob_id, carol_id, dave_id) = (ends[0].0.clone(), ends[1].0.clone(), ends[2].0.clone());
+
+        let label = wb.create_label("Work".to_string()).unwrap();
+        wb.add_contacts_to_label(label.id.clone(), vec![bob_id.clone(), carol_id.clone()])
+            .unwrap();
+        wb.hide_field_from_contact(carol_id.clone(), "work".to_string())
+            .unwrap();
+
+        wb.update_field("work".to_string(), "new@work.example".to_string())
+            .unwrap();
+        wb.update_field("mobile".to_string(), "+41 79 111 11 11".to_string())
+            .unwrap();
+
+        // Carol is in the label but cannot see the field; Dave is not in it
+        let queued = wb
+            .push_field_update("work".to_string(), Some(label.id.clone()))
+            .unwrap();
+        assert_eq!(queued, 1);
+
+        let storage = wb.open_storage().unwrap();
+        assert!(storage.get_pending_updates(&carol_id).unwrap().is_empty());
+        assert!(storage.get_pending_updates(&dave_id).unwrap().is_empty());
+        let pending = storage.get_pending_updates(&bob_id).unwrap();
+        assert_eq!(pending.len(), 1);
+
+        let message = serde_json::from_slice(&pending[0].payload).unwrap();
+        let plaintext = ends[0].1.decrypt(&message).unwrap();
+        let delta: CardDelta = serde_json::from_slice(&plaintext).unwrap();
+        let work_id = wb.get_own_card().unwrap().fields[0].id.clone();
+        assert!(delta.changes.iter().all(|change| match change {
+            FieldChange::Removed { field_id } => *field_id == work_id,
+            FieldChange::Added { field } => field.value() == "new@work.example",
+            _ => false,
+        }));
+
+        // The update applies to a card that already has the old value
+        let mut known = storage.load_own_card().unwrap().unwrap();
+        known
+            .update_field_value(&work_id, "old@work.example")
+            .unwrap();
+        delta.apply(&mut known).unwrap();
+        let work: Vec<_> = known
+            .fields()
+            .iter()
+            .filter(|f| f.id() == work_id)
+            .collect();
+        assert_eq!(work.len(), 1);
+        assert_eq!(work[0].value(), "new@work.example");
+        assert_eq!(known.fields().len(), 2);
+
+        // Without a label, everyone who can see the field gets it
+        assert_eq!(wb.push_field_update("work".to_string(), None).unwrap(), 2);
+        assert!(matches!(
+            wb.push_field_update("missing".to_string(), None),
+            Err(MobileError::FieldNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_card_draft_stays_private_until_published() {
+        use vauchi_core::crypto::ratchet::DoubleRatchetState;
+        use vauchi_core::exchange::X3DHKeyPair;
+        use vauchi_core::sync::{CardDelta, FieldChange};
+
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        wb.add_field(
+            MobileFieldType::Email,
+            "work".to_string(),
+            "old@work.example".to_string(),
+        )
+        .unwrap();
+
+        let bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+        let their_dh = X3DHKeyPair::generate();
+        let storage = wb.open_storage().unwrap();
+        storage
+            .save_ratchet_state(
+                bob.id(),
+                &DoubleRatchetState::initialize_initiator(bob.shared_key(), *their_dh.public_key()),
+                true,
+            )
+            .unwrap();
+        let mut bob_end = DoubleRatchetState::initialize_responder(bob.shared_key(), their_dh);
+
+        let live = wb.get_own_card().unwrap();
+        let mut edited = live.clone();
+        edited.fields[0].value = "new@work.example".to_string();
+        edited.fields.insert(
+            0,
+            MobileContactField {
+                id: String::new(),
+                field_type: MobileFieldType::Phone,
+                label: "mobile".to_string(),
+                value: "+41 79 000 00 00".to_string(),
+                is_primary: false,
+            },
+        );
+        wb.save_card_draft(edited).unwrap();
+
+        // The draft is stored but nothing is visible or queued yet
+        let draft = wb.get_card_draft().unwrap().unwrap();
+        assert_eq!(draft.fields.len(), 2);
+        assert_eq!(draft.fields[0].label, "mobile");
+        assert_eq!(draft.fields[1].id, live.fields[0].id);
+        assert_eq!(
+            wb.get_own_card().unwrap().fields[0].value,
+            "old@work.example"
+        );
+        assert!(storage.get_pending_updates(bob.id()).unwrap().is_empty());
+
+        assert_eq!(wb.publish_card_draft().unwrap(), 1);
+        assert!(wb.get_card_draft().unwrap().is_none());
+        let own = wb.get_own_card().unwrap();
+        assert_eq!(own.fields.len(), 2);
+        assert_eq!(own.fields[1].value, "new@work.example");
+
+        let pending = storage.get_pending_updates(bob.id()).unwrap();
+        assert_eq!(pending.len(), 1);
+        let message = serde_json::from_slice(&pending[0].payload).unwrap();
+        let delta: CardDelta = serde_json::from_slice(&bob_end.decrypt(&message).unwrap()).unwrap();
+        assert!(delta.verify(wb.get_identity().unwrap().signing_public_key()));
+        assert_eq!(delta.changes.len(), 2);
+        assert!(delta.changes.iter().any(|change| matches!(
+            change,
+            FieldChange::Modified { new_value, .. } if new_value == "new@work.example"
+        )));
+        assert!(delta.changes.iter().any(|change| matches!(
+            change,
+            FieldChange::Added { field } if field.label() == "mobile"
+        )));
+
+        // Nothing to publish, and a discarded draft never reaches the card
+        assert_eq!(wb.publish_card_draft().unwrap(), 0);
+        let mut edited = wb.get_own_card().unwrap();
+        edited.display_name = "Alice B.".to_string();
+        wb.save_card_draft(edited).unwrap();
+        assert!(wb.discard_card_draft().unwrap());
+        assert!(!wb.discard_card_draft().unwrap());
+        assert_eq!(wb.get_own_card().unwrap().display_name, "Alice");
+    }
+
+    #[test]
+    fn test_single_contact_export_import_as_reference() {
+        use crate::transport::MockRelay;
+        const PASSWORD: &str = "correct-horse-battery-staple-handoff";
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let (carol, _carol_dir) = create_test_instance();
+        carol.create_identity("Carol".to_string()).unwrap();
+
+        // Carol knows Bob and hands his contact to Alice
+        let mut bob_card = ContactCard::new("Bob");
+        bob_card
+            .add_field(ContactField::new(
+                FieldType::Email,
+                "work",
+                "bob@work.example",
+            ))
+            .unwrap();
+        let bob_contact = Contact::from_exchange(
+            *bob.get_identity().unwrap().signing_public_key(),
+            bob_card,
+            SymmetricKey::generate(),
+        );
+        carol
+            .open_storage()
+            .unwrap()
+            .save_contact(&bob_contact)
+            .unwrap();
+        let data = carol
+            .export_single_contact(bob_contact.id().to_string(), PASSWORD.to_string())
+            .unwrap();
+
+        let imported = alice
+            .import_single_contact(data, PASSWORD.to_string())
+            .unwrap();
+        assert_eq!(imported.id, bob_contact.id());
+        assert!(imported.is_reference);
+        let listed = alice.list_contacts().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert!(listed[0].is_reference);
+        assert_eq!(listed[0].card.fields[0].value, "bob@work.example");
+
+        // Exchanging with Bob replaces the reference contact
+        let qr = bob.generate_exchange_qr().unwrap();
+        let exchange = alice.complete_exchange(qr.qr_data).unwrap();
+        assert!(exchange.success);
+        assert_eq!(exchange.kind, MobileExchangeOutcomeKind::New);
+        let contact = alice
+            .get_contact(bob_contact.id().to_string())
+            .unwrap()
+            .unwrap();
+        assert!(!contact.is_reference);
+    }
+
+    #[test]
+    fn test_poll_delivery_status_after_disconnect() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        alice.sync().unwrap();
+        bob.sync().unwrap();
+        // Settle anything sent during the exchange
+        bob.poll_delivery_status().unwrap();
+
+        queue_email_update(&bob, &alice_id, "Bob", "bob@example.com");
+        assert_eq!(bob.sync().unwrap().updates_sent, 1);
+        let awaiting = bob.get_pending_deliveries().unwrap();
+        assert_eq!(awaiting.len(), 1);
+        let message_id = awaiting[0].message_id.clone();
+        assert_eq!(awaiting[0].recipient_id, alice_id);
+        assert_eq!(awaiting[0].status, MobileDeliveryStatus::Sent);
+
+        let status = |wb: &VauchiMobile| {
+            wb.get_delivery_record(message_id.clone())
+                .unwrap()
+                .unwrap()
+                .status
+        };
+
+        // Offline, nothing changes
+        relay.set_offline(true);
+        assert!(bob.poll_delivery_status().is_err());
+        assert_eq!(status(&bob), MobileDeliveryStatus::Sent);
+        relay.set_offline(false);
+
+        assert_eq!(bob.poll_delivery_status().unwrap(), 1);
+        assert_eq!(status(&bob), MobileDeliveryStatus::Stored);
+
+        // Alice picks it up while Bob is away; he learns on his next poll
+        assert_eq!(alice.sync().unwrap().cards_updated, 1);
+        assert_eq!(bob.poll_delivery_status().unwrap(), 1);
+        assert_eq!(status(&bob), MobileDeliveryStatus::Delivered);
+        assert_eq!(bob.poll_delivery_status().unwrap(), 0);
+
+        // Only the sender may ask about a message
+        alice.poll_delivery_status().unwrap();
+        alice
+            .open_storage()
+            .unwrap()
+            .create_delivery_record(&vauchi_core::storage::DeliveryRecord {
+                message_id: message_id.clone(),
+                recipient_id: bob_id,
+                status: vauchi_core::storage::DeliveryStatus::Sent,
+                created_at: 0,
+                updated_at: 0,
+                expires_at: None,
+            })
+            .unwrap();
+        assert_eq!(alice.poll_delivery_status().unwrap(), 0);
+        assert_eq!(status(&alice), MobileDeliveryStatus::Sent);
+    }
+
+    #[test]
+    fn test_rekey_contact_replaces_shared_key() {
+        use crate::transport::MockRelay;
+
+        let shared_key = |wb: &VauchiMobile, contact_id: &str| {
+            let storage = wb.open_storage().unwrap();
+            let contact = storage.load_contact(contact_id).unwrap().unwrap();
+            *contact.shared_key().as_bytes()
+        };
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        alice.sync().unwrap();
+        bob.sync().unwrap();
+        alice.verify_contact(bob_id.clone()).unwrap();
+        let old_key = shared_key(&alice, &bob_id);
+        assert_eq!(old_key, shared_key(&bob, &alice_id));
+
+        // Alice asks for a re-key; it completes once both have synced
+        alice.rekey_contact(bob_id.clone()).unwrap();
+        assert!(alice.is_rekey_pending(bob_id.clone()).unwrap());
+        assert_eq!(alice.sync().unwrap().updates_sent, 1);
+        assert_eq!(bob.sync().unwrap().updates_sent, 1);
+        alice.sync().unwrap();
+        assert!(!alice.is_rekey_pending(bob_id.clone()).unwrap());
+
+        let new_key = shared_key(&alice, &bob_id);
+        assert_ne!(new_key, old_key);
+        assert_eq!(new_key, shared_key(&bob, &alice_id));
+        let bob_contact = alice.get_contact(bob_id.clone()).unwrap().unwrap();
+        assert_eq!(bob_contact.display_name, "Bob");
+        assert!(bob_contact.is_verified);
+
+        // Updates flow both ways under the new ratchet
+        queue_email_update(&bob, &alice_id, "Bob", "bob@example.com");
+        bob.sync().unwrap();
+        assert_eq!(alice.sync().unwrap().cards_updated, 1);
+        let bob_contact = alice.get_contact(bob_id.clone()).unwrap().unwrap();
+        assert!(bob_contact
+            .card
+            .fields
+            .iter()
+            .any(|f| f.value == "bob@example.com"));
+
+        queue_email_update(&alice, &bob_id, "Alice", "alice@example.com");
+        alice.sync().unwrap();
+        assert_eq!(bob.sync().unwrap().cards_updated, 1);
+        let alice_contact = bob.get_contact(alice_id).unwrap().unwrap();
+        assert!(alice_contact
+            .card
+            .fields
+            .iter()
+            .any(|f| f.value == "alice@example.com"));
+
+        assert!(matches!(
+            alice.rekey_contact("missing".to_string()),
+            Err(MobileError::ContactNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_blocked_contact_receives_no_queued_updates() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _dir) = relay_instance(&relay, "Alice");
+        let bob = save_test_contact(&alice, &Identity::create("Bob"), false);
+        let queue = |id: &str| {
+            alice
+                .open_storage()
+                .unwrap()
+                .queue_update(&vauchi_core::PendingUpdate {
+                    id: id.to_string(),
+                    contact_id: bob.id().to_string(),
+                    update_type: "card_delta".to_string(),
+                    payload: vec![1, 2, 3],
+                    created_at: 0,
+                    retry_count: 0,
+                    status: vauchi_core::UpdateStatus::Pending,
+                })
+                .unwrap();
+        };
+
+        queue("update-1");
+        alice
+            .set_contact_blocked(bob.id().to_string(), true)
+            .unwrap();
+        assert!(
+            alice
+                .get_contact(bob.id().to_string())
+                .unwrap()
+                .unwrap()
+                .is_blocked
+        );
+        assert_eq!(
+            alice
+                .open_storage()
+                .unwrap()
+                .count_pending_updates(bob.id())
+                .unwrap(),
+            0
+        );
+
+        // Updates queued after blocking stay frozen rather than sent
+        queue("update-2");
+        let result = alice.sync().unwrap();
+        assert_eq!(result.updates_sent, 0);
+        assert_eq!(relay.pending_for(bob.id()), 0);
+
+        alice
+            .set_contact_blocked(bob.id().to_string(), false)
+            .unwrap();
+        let result = alice.sync().unwrap();
+        assert_eq!(result.updates_sent, 1);
+        assert_eq!(relay.pending_for(bob.id()), 1);
+    }
+
+    #[test]
+    fn test_exchange_response_carries_shared_fields() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let alice_dir = TempDir::new().unwrap();
+        let bob_dir = TempDir::new().unwrap();
+        let alice = VauchiMobile::new_with_transport(
+            alice_dir.path().to_string_lossy().to_string(),
+            Arc::new(relay.clone()),
+        )
+        .unwrap();
+        let bob = VauchiMobile::new_with_transport(
+            bob_dir.path().to_string_lossy().to_string(),
+            Arc::new(relay.clone()),
+        )
+        .unwrap();
+        alice.create_identity("Alice".to_string()).unwrap();
+        bob.create_identity("Bob".to_string()).unwrap();
+        let alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+
+        alice
+            .add_field(
+                MobileFieldType::Email,
+                "email".to_string(),
+                "alice@example.com".to_string(),
+            )
+            .unwrap();
+        alice
+            .add_field(
+                MobileFieldType::Phone,
+                "phone".to_string(),
+                "+41791234567".to_string(),
+            )
+            .unwrap();
+
+        // Alice keeps her phone number from Bob
+        {
+            let storage = alice.open_storage().unwrap();
+            let card = storage.load_own_card().unwrap().unwrap();
+            let phone = card.fields().iter().find(|f| f.label() == "phone").unwrap();
+            storage
+                .save_contact_override(&bob_id, phone.id(), false)
+                .unwrap();
+        }
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        let placeholder = bob.get_contact(alice_id.clone()).unwrap().unwrap();
+        assert!(placeholder.card.fields.is_empty());
+
+        alice.sync().unwrap();
+        bob.sync().unwrap();
+
+        let alice_contact = bob.get_contact(alice_id).unwrap().unwrap();
+        assert_eq!(alice_contact.display_name, "Alice");
+        assert_eq!(alice_contact.card.fields.len(), 1);
+        assert_eq!(alice_contact.card.fields[0].value, "alice@example.com");
+    }
+
+    #[test]
+    fn test_set_relay_url() {
+        let (wb, _dir) = create_test_instance();
+        assert_eq!(wb.get_relay_url(), "ws://localhost:8080");
+
+        wb.set_relay_url("wss://relay.example.com".to_string())
+            .unwrap();
+        assert_eq!(wb.get_relay_url(), "wss://relay.example.com");
+
+        // Invalid URLs leave the current relay untouched
+        for url in ["https://relay.example.com", "not a url", "ws://"] {
+            assert!(matches!(
+                wb.set_relay_url(url.to_string()),
+                Err(MobileError::InvalidInput(_))
+            ));
+        }
+        assert_eq!(wb.get_relay_url(), "wss://relay.example.com");
+    }
+
+    #[test]
+    fn test_sync_uses_updated_relay_url() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let accepted = std::thread::spawn(move || listener.accept().is_ok());
+
+        wb.set_relay_url(format!("ws://127.0.0.1:{}", port))
+            .unwrap();
+        // The listener is not a relay, so the sync itself fails
+        assert!(wb.sync().is_err());
+        assert!(accepted.join().unwrap());
+    }
+
+    #[test]
+    fn test_transport_security_policy() {
+        use crate::transport::check_transport_security;
+
+        assert!(check_transport_security("wss://relay.example.com", true).is_ok());
+        assert!(check_transport_security("ws://localhost:8080", true).is_ok());
+        assert!(check_transport_security("ws://127.0.0.1:8080", true).is_ok());
+        assert!(check_transport_security("ws://[::1]:8080", true).is_ok());
+        assert!(matches!(
+            check_transport_security("ws://relay.example.com", true),
+            Err(MobileError::InsecureTransport(_))
+        ));
+        assert!(check_transport_security("ws://relay.example.com", false).is_ok());
+    }
+
+    #[test]
+    fn test_sync_rejects_remote_plaintext_relay() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        wb.set_relay_url("ws://relay.example.com".to_string())
+            .unwrap();
+
+        wb.set_require_tls(true).unwrap();
+        assert!(wb.is_tls_required());
+        assert!(matches!(wb.sync(), Err(MobileError::InsecureTransport(_))));
+    }
+
+    #[test]
+    fn test_contact_tags() {
+        let (wb, _dir) = create_test_instance();
+        let alice = save_test_contact(&wb, &Identity::create("Alice"), false);
+        let bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+
+        wb.add_contact_tag(alice.id().to_string(), "family".to_string())
+            .unwrap();
+        wb.add_contact_tag(bob.id().to_string(), "clients".to_string())
+            .unwrap();
+        assert_eq!(
+            wb.get_contact_tags(alice.id().to_string()).unwrap(),
+            vec!["family".to_string()]
+        );
+
+        let family = wb.list_contacts_by_tag("family".to_string()).unwrap();
+        assert_eq!(family.len(), 1);
+        assert_eq!(family[0].id, alice.id());
+
+        wb.remove_contact_tag(alice.id().to_string(), "family".to_string())
+            .unwrap();
+        assert!(wb
+            .list_contacts_by_tag("family".to_string())
+            .unwrap()
+            .is_empty());
+
+        assert!(matches!(
+            wb.add_contact_tag("missing".to_string(), "family".to_string()),
+            Err(MobileError::ContactNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_specific_error_variants() {
+        use base64::Engine;
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+
+        assert!(matches!(
+            wb.update_field("missing".to_string(), "value".to_string()),
+            Err(MobileError::FieldNotFound(label)) if label == "missing"
+        ));
+
+        let bob = Identity::create("Bob");
+        let bob_id = hex::encode(bob.signing_public_key());
+        save_test_contact(&wb, &bob, false);
+        wb.validate_field(
+            bob_id.clone(),
+            "email".to_string(),
+            "bob@example.com".to_string(),
+        )
+        .unwrap();
+        assert!(matches!(
+            wb.validate_field(bob_id, "email".to_string(), "bob@example.com".to_string()),
+            Err(MobileError::AlreadyValidated)
+        ));
+
+        let claim = RecoveryClaim::new_with_timestamp(&[1u8; 32], &[2u8; 32], 0);
+        let claim_b64 = base64::engine::general_purpose::STANDARD.encode(claim.to_bytes());
+        assert!(matches!(
+            wb.create_recovery_voucher(claim_b64),
+            Err(MobileError::ClaimExpired)
+        ));
+
+        assert!(matches!(
+            MobileError::from(vauchi_core::RecoveryError::RateLimitExceeded),
+            MobileError::RateLimited(_)
+        ));
+    }
+
+    #[test]
+    fn test_validation_daily_limit() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        assert_eq!(
+            wb.get_validation_daily_limit().unwrap(),
+            ValidationRateLimiter::MAX_VALIDATIONS_PER_DAY
+        );
+        wb.set_validation_daily_limit(10_000).unwrap();
+        assert_eq!(
+            wb.get_validation_daily_limit().unwrap(),
+            ValidationRateLimiter::MAX_VALIDATIONS_PER_DAY
+        );
+        wb.set_validation_daily_limit(2).unwrap();
+        assert_eq!(wb.get_validation_daily_limit().unwrap(), 2);
+
+        let bob = Identity::create("Bob");
+        let bob_id = hex::encode(bob.signing_public_key());
+        save_test_contact(&wb, &bob, false);
+
+        for field in ["email", "phone"] {
+            wb.validate_field(bob_id.clone(), field.to_string(), "value".to_string())
+                .unwrap();
+        }
+        assert!(matches!(
+            wb.validate_field(bob_id.clone(), "github".to_string(), "bob".to_string()),
+            Err(MobileError::RateLimited(_))
+        ));
+
+        // Revoking still works and does not free up a slot
+        assert!(wb
+            .revoke_field_validation(bob_id.clone(), "email".to_string())
+            .unwrap());
+        assert!(matches!(
+            wb.validate_field(bob_id, "email".to_string(), "value".to_string()),
+            Err(MobileError::RateLimited(_))
+        ));
+    }
+
+    #[test]
+    fn test_validations_bundle_export_import() {
+        let (alice, _alice_dir) = create_test_instance();
+        alice.create_identity("Alice".to_string()).unwrap();
+        let (carol, _carol_dir) = create_test_instance();
+        carol.create_identity("Carol".to_string()).unwrap();
+
+        let bob = Identity::create("Bob");
+        let bob_id = hex::encode(bob.signing_public_key());
+        save_test_contact(&alice, &bob, false);
+        alice
+            .validate_field(
+                bob_id.clone(),
+                "email".to_string(),
+                "bob@example.com".to_string(),
+            )
+            .unwrap();
+
+        let bundle = alice.export_my_validations().unwrap();
+        assert_eq!(carol.import_validations_bundle(bundle).unwrap(), 1);
+        assert_eq!(
+            carol
+                .get_field_validation_count(bob_id, "email".to_string())
+                .unwrap(),
+            1
+        );
+
+        assert!(matches!(
+            carol.import_validations_bundle("not a bundle".to_string()),
+            Err(MobileError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_field_trust_thresholds() {
+        let (wb, _dir) = create_test_instance();
+        let bob = Identity::create("Bob");
+        let carol = Identity::create("Carol");
+        let dave = Identity::create("Dave");
+        let bob_id = hex::encode(bob.signing_public_key());
+
+        let mut bob_contact = save_test_contact(&wb, &bob, false);
+        let mut card = bob_contact.card().clone();
+        card.add_field(ContactField::new(
+            vauchi_core::FieldType::Email,
+            "email",
+            "bob@example.com",
+        ))
+        .unwrap();
+        bob_contact.update_card(card);
+        let storage = wb.open_storage().unwrap();
+        storage.save_contact(&bob_contact).unwrap();
+        save_test_contact(&wb, &carol, true);
+        save_test_contact(&wb, &dave, false);
+
+        let validate = |validator: &Identity| {
+            let validation = vauchi_core::social::ProfileValidation::create_signed(
+                validator,
+                "email",
+                "bob@example.com",
+                &bob_id,
+            );
+            storage.save_validation(&validation).unwrap();
+        };
+
+        wb.set_trust_thresholds(2, false).unwrap();
+        validate(&carol);
+        assert!(!wb
+            .get_field_trust(bob_id.clone(), "email".to_string())
+            .unwrap());
+
+        // Carol was verified in person, so her validation alone reaches 2
+        wb.set_trust_thresholds(2, true).unwrap();
+        assert!(wb.get_trust_thresholds().in_person_counts_double);
+        assert!(wb
+            .get_field_trust(bob_id.clone(), "email".to_string())
+            .unwrap());
+
+        wb.set_trust_thresholds(3, true).unwrap();
+        assert!(!wb
+            .get_field_trust(bob_id.clone(), "email".to_string())
+            .unwrap());
+        validate(&dave);
+        assert!(wb
+            .get_field_trust(bob_id.clone(), "email".to_string())
+            .unwrap());
+        assert!(
+            wb.get_field_validation_status(
+                bob_id.clone(),
+                "email".to_string(),
+                "bob@example.com".to_string()
+            )
+            .unwrap()
+            .trusted
+        );
+
+        assert!(matches!(
+            wb.get_field_trust(bob_id, "phone".to_string()),
+            Err(MobileError::FieldNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_settings_survive_restart() {
+        let (wb, dir) = create_test_instance();
+        wb.set_trust_thresholds(4, false).unwrap();
+        wb.set_clock_skew_tolerance(300).unwrap();
+        wb.set_exchange_retry_limit(3).unwrap();
+        wb.set_pad_updates(true).unwrap();
+        wb.set_require_tls(true).unwrap();
+        wb.set_locale("de".to_string()).unwrap();
+        drop(wb);
+
+        let reopened = VauchiMobile::new(
+            dir.path().to_string_lossy().to_string(),
+            "ws://localhost:8080".to_string(),
+        )
+        .unwrap();
+        let thresholds = reopened.get_trust_thresholds();
+        assert_eq!(thresholds.min_validations, 4);
+        assert!(!thresholds.in_person_counts_double);
+        assert_eq!(reopened.get_clock_skew_tolerance(), 300);
+        assert_eq!(reopened.get_exchange_retry_limit(), 3);
+        assert!(reopened.get_pad_updates());
+        assert!(reopened.is_tls_required());
+        assert_eq!(reopened.locale().code(), "de");
+    }
+
+    #[test]
+    fn test_import_issuer_attestation() {
+        use base64::Engine;
+        use vauchi_core::IssuerAttestation;
+
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let bob = Identity::create("Bob");
+        let acme = Identity::create("ACME");
+        let bob_id = hex::encode(bob.signing_public_key());
+
+        let mut bob_contact = save_test_contact(&wb, &bob, false);
+        let mut card = bob_contact.card().clone();
+        card.add_field(ContactField::new(
+            vauchi_core::FieldType::Email,
+            "work",
+            "bob@acme.example",
+        ))
+        .unwrap();
+        bob_contact.update_card(card);
+        wb.open_storage()
+            .unwrap()
+            .save_contact(&bob_contact)
+            .unwrap();
+        wb.set_trust_thresholds(3, false).unwrap();
+
+        let encode = |attestation: &IssuerAttestation| {
+            base64::engine::general_purpose::STANDARD.encode(attestation.to_bytes())
+        };
+        let attestation = IssuerAttestation::create(
+            &acme,
+            "ACME Corp",
+            bob.signing_public_key(),
+            "work",
+            "bob@acme.example",
+        );
+
+        // A bad signature is rejected and changes nothing
+        let mut forged = attestation.clone();
+        forged.set_issuer_name_for_testing("Government");
+        assert!(matches!(
+            wb.import_issuer_attestation(encode(&forged)),
+            Err(MobileError::CryptoError(_))
+        ));
+        assert!(wb
+            .get_issuer_backed_fields(bob_id.clone())
+            .unwrap()
+            .is_empty());
+        assert!(!wb
+            .get_field_trust(bob_id.clone(), "work".to_string())
+            .unwrap());
+
+        assert_eq!(
+            wb.import_issuer_attestation(encode(&attestation)).unwrap(),
+            bob_id
+        );
+        assert_eq!(
+            wb.get_issuer_backed_fields(bob_id.clone()).unwrap(),
+            vec!["work".to_string()]
+        );
+        let status = wb
+            .get_field_validation_status(
+                bob_id.clone(),
+                "work".to_string(),
+                "bob@acme.example".to_string(),
+            )
+            .unwrap();
+        assert!(status.trusted);
+        assert_eq!(status.issuers, vec!["ACME Corp".to_string()]);
+        assert_eq!(status.display_text, "Attested by ACME Corp");
+
+        // Attestations for people who are not contacts are refused
+        let stranger = IssuerAttestation::create(
+            &acme,
+            "ACME Corp",
+            Identity::create("Mallory").signing_public_key(),
+            "work",
+            "mallory@acme.example",
+        );
+        assert!(matches!(
+            wb.import_issuer_attestation(encode(&stranger)),
+            Err(MobileError::ContactNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_relay_health_checks_hit_cache() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (wb, _dir) = relay_instance(&relay, "Alice");
+        assert_eq!(
+            wb.get_cached_relay_status().state,
+            MobileRelayState::Unknown
+        );
+
+        for _ in 0..5 {
+            assert_eq!(wb.check_relay_health().state, MobileRelayState::Reachable);
+        }
+        assert_eq!(relay.connect_count(), 1);
+
+        // The cached status never contacts the relay
+        relay.set_offline(true);
+        let status = wb.get_cached_relay_status();
+        assert_eq!(status.state, MobileRelayState::Reachable);
+        assert!(status.retry_after_secs > 0);
+        assert_eq!(status.checked_secs_ago, Some(0));
+        assert_eq!(relay.connect_count(), 1);
+    }
+
+    #[test]
+    fn test_relay_health_backoff_grows_after_failures() {
+        use crate::relay_health::RelayHealthCache;
+        use crate::transport::MockRelay;
+        use std::time::{Duration, Instant};
+
+        let relay = MockRelay::new();
+        let (wb, _dir) = relay_instance(&relay, "Alice");
+        let relay_url = wb.get_relay_url();
+        let clock = Arc::new(Mutex::new(Instant::now()));
+        let now = clock.clone();
+        *wb.relay_health.lock().unwrap() = RelayHealthCache::with_clock(
+            Duration::from_secs(30),
+            Duration::from_secs(5),
+            move || *now.lock().unwrap(),
+        );
+        relay.set_offline(true);
+
+        let status = wb.check_relay_health();
+        assert_eq!(status.state, MobileRelayState::Unreachable);
+        assert_eq!(status.consecutive_failures, 1);
+        let first_backoff = wb.relay_health.lock().unwrap().retry_after(&relay_url);
+
+        // Within the cooldown the relay is left alone
+        wb.check_relay_health();
+        assert_eq!(relay.connect_count(), 1);
+
+        *clock.lock().unwrap() += Duration::from_secs(6);
+        assert_eq!(wb.check_relay_health().consecutive_failures, 2);
+        assert_eq!(relay.connect_count(), 2);
+        let second_backoff = wb.relay_health.lock().unwrap().retry_after(&relay_url);
+        assert_eq!(first_backoff, Duration::from_secs(5));
+        assert_eq!(second_backoff, Duration::from_secs(10));
+
+        // Recovery resets the failure count
+        relay.set_offline(false);
+        *clock.lock().unwrap() += Duration::from_secs(11);
+        let status = wb.check_relay_health();
+        assert_eq!(status.state, MobileRelayState::Reachable);
+        assert_eq!(status.consecutive_failures, 0);
+        assert_eq!(relay.connect_count(), 3);
+    }
+
+    fn native_contact(native_id: &str, name: &str, email: &str) -> MobileNativeContact {
+        MobileNativeContact {
+            native_id: native_id.to_string(),
+            display_name: name.to_string(),
+            emails: vec![email.to_string()],
+            phones: Vec::new(),
+            social_handles: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_import_native_contacts() {
+        let (wb, _dir) = create_test_instance();
+        save_test_contact(&wb, &Identity::create("Bob"), false);
+
+        let imported = wb
+            .import_native_contacts(vec![
+                native_contact("n2", "Dave", "dave@example.com"),
+                native_contact("n1", "Carol", "carol@example.com"),
+            ])
+            .unwrap();
+        assert_eq!(imported, 2);
+
+        // Re-importing the same native ID replaces the entry
+        wb.import_native_contacts(vec![native_contact("n1", "Carol", "carol@work.com")])
+            .unwrap();
+
+        let native = wb.list_native_contacts().unwrap();
+        assert_eq!(native.len(), 2);
+        assert_eq!(native[0].display_name, "Carol");
+        assert_eq!(native[0].emails, vec!["carol@work.com".to_string()]);
+        assert_eq!(native[1].display_name, "Dave");
+
+        // Exchanged contacts are unaffected
+        let contacts = wb.list_contacts().unwrap();
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(contacts[0].display_name, "Bob");
+    }
+
+    #[test]
+    fn test_invite_suggestions_rank_shared_handle_first() {
+        let (wb, _dir) = create_test_instance();
+        let mut bob = save_test_contact(&wb, &Identity::create("Bob"), false);
+        let mut card = bob.card().clone();
+        card.add_field(ContactField::new(
+            vauchi_core::FieldType::Email,
+            "email",
+            "Bob@Example.com",
+        ))
+        .unwrap();
+        card.add_field(ContactField::new(
+            vauchi_core::FieldType::Social,
+            "team",
+            "@acme_team",
+        ))
+        .unwrap();
+        bob.update_card(card);
+        wb.open_storage().unwrap().save_contact(&bob).unwrap();
+
+        let mut carol = native_contact("n2", "Carol", "carol@example.com");
+        carol.social_handles = vec!["acme_team".to_string()];
+        wb.import_native_contacts(vec![
+            native_contact("n1", "Adam", "adam@example.com"),
+            carol,
+            native_contact("n3", "Robert", "bob@example.com"),
+        ])
+        .unwrap();
+
+        let suggestions = wb.get_invite_suggestions().unwrap();
+        // Robert shares Bob's email, so only Carol and Adam are suggested
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0].contact.display_name, "Carol");
+        assert_eq!(suggestions[0].score, 1);
+        assert_eq!(
+            suggestions[0].matched_contact_ids,
+            vec![bob.id().to_string()]
+        );
+        assert_eq!(suggestions[1].contact.display_name, "Adam");
+        assert_eq!(suggestions[1].score, 0);
+    }
+
+    #[test]
+    fn test_exchange_with_existing_contact_is_duplicate() {
+        let (alice, _alice_dir) = create_test_instance();
+        alice.create_identity("Alice".to_string()).unwrap();
+        let (bob, _bob_dir) = create_test_instance();
+        bob.create_identity("Bob".to_string()).unwrap();
+
+        let bob_identity = bob.get_identity().unwrap();
+        save_test_contact(&alice, &bob_identity, false);
+
+        let qr = bob.generate_exchange_qr().unwrap();
+        let result = alice.complete_exchange(qr.qr_data).unwrap();
+        assert_eq!(result.kind, MobileExchangeOutcomeKind::AlreadyExists);
+        assert_eq!(result.contact_id, qr.public_id);
+        assert_eq!(alice.contact_count().unwrap(), 1);
+        assert_eq!(alice.get_pending_exchange_count().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_rescan_resends_undelivered_exchange() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        relay.set_offline(true);
+        assert!(!bob.complete_exchange(qr.qr_data).unwrap().success);
+        relay.set_offline(false);
+
+        // Scanning Alice again sends the exchange she never received
+        let qr = alice.generate_exchange_qr().unwrap();
+        let result = bob.complete_exchange(qr.qr_data).unwrap();
+        assert_eq!(result.kind, MobileExchangeOutcomeKind::AlreadyExists);
+        assert!(result.success);
+        assert_eq!(relay.pending_for(&alice_id), 1);
+        assert_eq!(bob.get_pending_exchange_count().unwrap(), 0);
+
+        assert_eq!(alice.sync().unwrap().contacts_added, 1);
+    }
+
+    #[test]
+    fn test_exchange_with_deleted_contact_is_reconnected() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+
+        let qr = bob.generate_exchange_qr().unwrap();
+        let result = alice.complete_exchange(qr.qr_data).unwrap();
+        assert_eq!(result.kind, MobileExchangeOutcomeKind::New);
+
+        assert!(alice.remove_contact(result.contact_id).unwrap());
+
+        let qr = bob.generate_exchange_qr().unwrap();
+        let result = alice.complete_exchange(qr.qr_data).unwrap();
+        assert_eq!(result.kind, MobileExchangeOutcomeKind::Reconnected);
+        assert!(result.success);
+    }
+
+    #[test]
+    fn test_delivery_receipt_verified_against_pinned_relay_key() {
+        use crate::transport::MockRelay;
+        use vauchi_core::crypto::SigningKeyPair;
+
+        let relay = MockRelay::new();
+        let relay_key = SigningKeyPair::generate();
+        let relay_pk = hex::encode(relay_key.public_key().as_bytes());
+        relay.set_receipt_key(relay_key);
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let alice_id = alice.get_public_id().unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        let frame = relay.frames_for(&alice_id).remove(0);
+        let message_id = protocol::decode_message(&frame).unwrap().message_id;
+
+        // Unverifiable until the relay key is pinned
+        assert!(bob.get_delivery_receipt(message_id.clone()).is_err());
+
+        bob.set_relay_receipt_key(relay_pk.clone()).unwrap();
+        bob.sync().unwrap();
+        let receipt = bob
+            .get_delivery_receipt(message_id.clone())
+            .unwrap()
+            .unwrap();
+        assert_eq!(receipt.message_id, message_id);
+        assert_eq!(receipt.recipient_id, alice_id);
+        assert!(bob
+            .get_delivery_receipt("unknown".to_string())
+            .unwrap()
+            .is_none());
+
+        // Receipts that arrive while no relay key is pinned are dropped
+        let (carol, _carol_dir) = relay_instance(&relay, "Carol");
+        let qr = alice.generate_exchange_qr().unwrap();
+        carol.complete_exchange(qr.qr_data).unwrap();
+        let frame = relay.frames_for(&alice_id).pop().unwrap();
+        let carol_message_id = protocol::decode_message(&frame).unwrap().message_id;
+        carol.sync().unwrap();
+        carol.set_relay_receipt_key(relay_pk).unwrap();
+        assert!(carol
+            .get_delivery_receipt(carol_message_id)
+            .unwrap()
+            .is_none());
+
+        // A receipt never verifies against a different relay's key
+        let other_pk = hex::encode(SigningKeyPair::generate().public_key().as_bytes());
+        bob.set_relay_receipt_key(other_pk).unwrap();
+        assert!(bob.get_delivery_receipt(message_id).is_err());
+        assert!(bob.set_relay_receipt_key("zz".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_clock_rollback_blocks_recovery_artifacts() {
+        use base64::Engine;
+
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let old_pk = hex::encode([0x01; 32]);
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        // Someone else's claim, for vouching
+        let claim_b64 = base64::engine::general_purpose::STANDARD
+            .encode(RecoveryClaim::new(&[0x02; 32], &[0x03; 32]).to_bytes());
+
+        wb.create_recovery_claim(old_pk.clone()).unwrap();
+        let status = wb.get_clock_status().unwrap();
+        assert!(!status.rolled_back);
+        assert!(status.latest_seen.unwrap() >= now);
+
+        // Within the skew tolerance is fine
+        wb.set_clock_for_testing(Some(now - 30));
+        assert!(!wb.get_clock_status().unwrap().rolled_back);
+        wb.create_recovery_voucher(claim_b64.clone()).unwrap();
+
+        // An hour back is refused
+        wb.set_clock_for_testing(Some(now - 3600));
+        let status = wb.get_clock_status().unwrap();
+        assert!(status.rolled_back);
+        assert!(status.behind_secs >= 3600);
+        assert!(matches!(
+            wb.create_recovery_claim(old_pk.clone()),
+            Err(MobileError::ClockRolledBack(behind)) if behind >= 3600
+        ));
+        assert!(matches!(
+            wb.create_recovery_voucher(claim_b64.clone()),
+            Err(MobileError::ClockRolledBack(_))
+        ));
+
+        // Resolved once the clock is past the watermark again
+        wb.set_clock_for_testing(Some(now + 60));
+        assert!(!wb.get_clock_status().unwrap().rolled_back);
+        wb.create_recovery_claim(old_pk.clone()).unwrap();
+
+        // Or when the user accepts the current clock
+        wb.set_clock_for_testing(Some(now - 3600));
+        assert!(wb.create_recovery_claim(old_pk.clone()).is_err());
+        wb.reset_clock_watermark().unwrap();
+        assert!(!wb.get_clock_status().unwrap().rolled_back);
+        wb.create_recovery_claim(old_pk).unwrap();
+    }
+
+    #[test]
+    fn test_publish_public_card() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        alice
+            .add_field(
+                MobileFieldType::Email,
+                "work".to_string(),
+                "alice@acme.example".to_string(),
+            )
+            .unwrap();
+        alice
+            .add_field(
+                MobileFieldType::Phone,
+                "mobile".to_string(),
+                "+41 79 000 00 00".to_string(),
+            )
+            .unwrap();
+        let label = alice.create_label("Business".to_string()).unwrap();
+        alice
+            .set_label_field_visibility(label.id.clone(), "work".to_string(), true)
+            .unwrap();
+
+        assert!(alice
+            .publish_public_card("Alice!".to_string(), label.id.clone())
+            .is_err());
+        alice
+            .publish_public_card("alice-acme".to_string(), label.id.clone())
+            .unwrap();
+        assert_eq!(
+            alice.get_public_card_handle().unwrap().as_deref(),
+            Some("alice-acme")
+        );
+
+        // Served signed, with only the label's fields
+        let served = PublicCard::from_json(&relay.public_card("alice-acme").unwrap()).unwrap();
+        assert!(served.verify());
+        let card = served.card().unwrap();
+        assert_eq!(card.display_name(), "Alice");
+        let values: Vec<&str> = card.fields().iter().map(|f| f.value()).collect();
+        assert_eq!(values, vec!["alice@acme.example"]);
+
+        // Changes are republished on sync
+        alice
+            .update_field("work".to_string(), "alice@acme.test".to_string())
+            .unwrap();
+        alice.sync().unwrap();
+        let served = PublicCard::from_json(&relay.public_card("alice-acme").unwrap()).unwrap();
+        assert!(served.verify());
+        assert_eq!(
+            served.card().unwrap().fields()[0].value(),
+            "alice@acme.test"
+        );
+
+        // Someone else cannot take over the handle
+        let (mallory, _mallory_dir) = relay_instance(&relay, "Mallory");
+        let mallory_label = mallory.create_label("Public".to_string()).unwrap();
+        mallory
+            .publish_public_card("alice-acme".to_string(), mallory_label.id)
+            .unwrap();
+        let served = PublicCard::from_json(&relay.public_card("alice-acme").unwrap()).unwrap();
+        assert_eq!(served.card().unwrap().display_name(), "Alice");
+
+        alice.unpublish_public_card().unwrap();
+        assert!(relay.public_card("alice-acme").is_none());
+        assert!(alice.get_public_card_handle().unwrap().is_none());
+        assert!(alice.unpublish_public_card().is_err());
+    }
+
+    #[test]
+    fn test_clock_skew_tolerance_and_relay_clock_lag() {
+        use base64::Engine;
+        use vauchi_core::crypto::SigningKeyPair;
+        use vauchi_core::network::simple_message::SimpleDeliveryReceipt;
+
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        // A claim 30 seconds past its 48-hour lifetime
+        let claim = RecoveryClaim::new_with_timestamp(
+            &[0x01; 32],
+            &[0x02; 32],
+            now - RecoveryClaim::MAX_AGE_SECS - 30,
+        );
+        let claim_b64 = base64::engine::general_purpose::STANDARD.encode(claim.to_bytes());
+
+        assert_eq!(
+            wb.get_clock_skew_tolerance(),
+            DEFAULT_CLOCK_SKEW_TOLERANCE_SECS
+        );
+        assert!(
+            !wb.parse_recovery_claim(claim_b64.clone())
+                .unwrap()
+                .is_expired
+        );
+        wb.set_clock_skew_tolerance(0).unwrap();
+        assert!(
+            wb.parse_recovery_claim(claim_b64.clone())
+                .unwrap()
+                .is_expired
+        );
+
+        // A verified receipt shows the local clock lagging the relay by an hour
+        wb.set_clock_skew_tolerance(120).unwrap();
+        let relay_key = SigningKeyPair::generate();
+        let fresh = RecoveryClaim::new_with_timestamp(
+            &[0x01; 32],
+            &[0x02; 32],
+            now - RecoveryClaim::MAX_AGE_SECS + 600,
+        );
+        let fresh_b64 = base64::engine::general_purpose::STANDARD.encode(fresh.to_bytes());
+        wb.open_storage()
+            .unwrap()
+            .save_delivery_receipt(
+                &SimpleDeliveryReceipt::sign(&relay_key, "msg-1", "bob", now + 3600),
+                &relay_key.public_key(),
+                now,
+            )
+            .unwrap();
+
+        // Ignored until the relay key is pinned
+        assert_eq!(wb.relay_clock_lag().unwrap(), 0);
+        assert!(
+            !wb.parse_recovery_claim(fresh_b64.clone())
+                .unwrap()
+                .is_expired
+        );
+
+        wb.set_relay_receipt_key(hex::encode(relay_key.public_key().as_bytes()))
+            .unwrap();
+        assert_eq!(wb.relay_clock_lag().unwrap(), 3600);
+        assert!(wb.parse_recovery_claim(fresh_b64).unwrap().is_expired);
+    }
+
+    /// Telemetry handler that keeps every event for inspection.
+    #[derive(Default)]
+    struct RecordingTelemetry {
+        events: Arc<Mutex<Vec<MobileTelemetryEvent>>>,
+    }
+
+    impl MobileTelemetryHandler for RecordingTelemetry {
+        fn on_event(&self, event: MobileTelemetryEvent) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    #[test]
+    fn test_telemetry_handler_receives_sync_and_exchange_events() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let recorder = RecordingTelemetry::default();
+        let events = recorder.events.clone();
+        alice.set_telemetry_handler(Box::new(recorder));
+
+        let qr = bob.generate_exchange_qr().unwrap();
+        alice.complete_exchange(qr.qr_data).unwrap();
+        bob.sync().unwrap();
+        alice.sync().unwrap();
+
+        let recorded = events.lock().unwrap().clone();
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[0], MobileTelemetryEvent::ExchangeSucceeded);
+        assert!(matches!(
+            recorded[1],
+            MobileTelemetryEvent::SyncCompleted { .. }
+        ));
+
+        // Clearing the handler stops further events
+        alice.clear_telemetry_handler();
+        alice.sync().unwrap();
+        assert_eq!(events.lock().unwrap().len(), 2);
+    }
+
+    /// Event observer that keeps every event for inspection.
+    #[derive(Default)]
+    struct RecordingObserver {
+        events: Arc<Mutex<Vec<MobileVauchiEvent>>>,
+    }
+
+    impl VauchiEventObserver for RecordingObserver {
+        fn on_event(&self, event: MobileVauchiEvent) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    #[test]
+    fn test_event_observer_receives_identity_and_sync_events() {
+        use crate::transport::MockRelay;
+
+        let relay = MockRelay::new();
+        let alice_dir = TempDir::new().unwrap();
+        let alice = VauchiMobile::new_with_transport(
+            alice_dir.path().to_string_lossy().to_string(),
+            Arc::new(relay.clone()),
+        )
+        .unwrap();
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let observer = RecordingObserver::default();
+        let events = observer.events.clone();
+        alice.set_event_observer(Box::new(observer));
+
+        alice.create_identity("Alice".to_string()).unwrap();
+        let alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+        assert!(matches!(
+            std::mem::take(&mut *events.lock().unwrap())[..],
+            [MobileVauchiEvent::IdentityCreated]
+        ));
+
+        // Bob's answer to Alice's QR arrives on her sync
+        let qr = alice.generate_exchange_qr().unwrap();
+        bob.complete_exchange(qr.qr_data).unwrap();
+        alice.sync().unwrap();
+        let recorded = std::mem::take(&mut *events.lock().unwrap());
+        assert_eq!(recorded.len(), 3);
+        assert!(matches!(recorded[0], MobileVauchiEvent::SyncStarted));
+        assert!(matches!(
+            &recorded[1],
+            MobileVauchiEvent::ContactAdded { contact_id } if *contact_id == bob_id
+        ));
+        assert!(matches!(
+            recorded[2],
+            MobileVauchiEvent::SyncFinished {
+                success: true,
+                contacts_added: 1,
+                ..
+            }
+        ));
+
+        bob.sync().unwrap();
+        queue_email_update(&bob, &alice_id, "Bob", "bob@example.com");
+        bob.sync().unwrap();
+        alice.sync().unwrap();
+        let recorded = std::mem::take(&mut *events.lock().unwrap());
+        assert_eq!(recorded.len(), 3);
+        assert!(matches!(
+            &recorded[1],
+            MobileVauchiEvent::UpdateApplied { contact_id } if *contact_id == bob_id
+        ));
+        assert!(matches!(
+            recorded[2],
+            MobileVauchiEvent::SyncFinished {
+                success: true,
+                cards_updated: 1,
+                ..
+            }
+        ));
+
+        // A failed sync still reports that it finished
+        relay.set_offline(true);
+        assert!(alice.sync().is_err());
+        let recorded = std::mem::take(&mut *events.lock().unwrap());
+        assert!(matches!(
+            recorded[..],
+            [
+                MobileVauchiEvent::SyncStarted,
+                MobileVauchiEvent::SyncFinished { success: false, .. }
+            ]
+        ));
+
+        // Relay reachability changes are reported too
+        assert_eq!(
+            alice.check_relay_health().state,
+            MobileRelayState::Unreachable
+        );
+        alice.clear_event_observer();
+        relay.set_offline(false);
+        alice.sync().unwrap();
+        let recorded = events.lock().unwrap().clone();
+        assert_eq!(recorded.len(), 1);
+        assert!(matches!(
+            &recorded[0],
+            MobileVauchiEvent::RelayStatusChanged { status }
+                if status.state == MobileRelayState::Unreachable
+        ));
+    }
+
+    #[test]
+    fn test_undo_delete_contact() {
+        let (alice, _alice_dir) = create_test_instance();
+        alice.create_identity("Alice".to_string()).unwrap();
+        let bob = save_test_contact(&alice, &Identity::create("Bob"), false);
+
+        assert!(alice.remove_contact(bob.id().to_string()).unwrap());
+        let deleted = alice.list_recently_deleted().unwrap();
+        assert_eq!(deleted.len(), 1);
+        assert_eq!(deleted[0].display_name, "Bob");
+
+        assert!(alice.undo_delete_contact(bob.id().to_string()).unwrap());
+        assert_eq!(alice.contact_count().unwrap(), 1);
+        assert!(alice.list_recently_deleted().unwrap().is_empty());
+        assert!(!alice.undo_delete_contact(bob.id().to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_password_exchange_requires_matching_passwords() {
+        let relay = crate::transport::MockRelay::new();
+        let (alice, _alice_dir) = relay_instance(&relay, "Alice");
+        let (bob, _bob_dir) = relay_instance(&relay, "Bob");
+        let (carol, _carol_dir) = relay_instance(&relay, "Carol");
+        let alice_id = alice.get_public_id().unwrap();
+        let bob_id = bob.get_public_id().unwrap();
+        let carol_id = carol.get_public_id().unwrap();
+
+        assert!(alice.start_password_exchange(String::new()).is_err());
+
+        // Matching passwords: Alice accepts Bob and both derive the same keys
+        let offer = alice
+            .start_password_exchange("tulip-42".to_string())
+            .unwrap();
+        let result = bob
+            .complete_password_exchange(offer, "tulip-42".to_string())
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.contact_id, alice_id);
+        let bob_storage = bob.open_storage().unwrap();
+        assert!(bob_storage
+            .load_expected_confirmation(&alice_id)
+            .unwrap()
+            .is_some());
+        assert_eq!(alice.sync().unwrap().contacts_added, 1);
+
+        // Alice's confirmation settles Bob's side
+        bob.sync().unwrap();
+        assert!(bob_storage
+            .load_expected_confirmation(&alice_id)
+            .unwrap()
+            .is_none());
+        assert!(bob.get_contact(alice_id.clone()).unwrap().is_some());
+        assert_eq!(
+            alice
+                .get_contact(bob_id.clone())
+                .unwrap()
+                .unwrap()
+                .display_name,
+            "Bob"
+        );
+
+        queue_email_update(&bob, &alice_id, "Bob", "bob@example.com");
+        bob.sync().unwrap();
+        assert_eq!(alice.sync().unwrap().cards_updated, 1);
+        assert!(alice
+            .get_contact(bob_id)
+            .unwrap()
+            .unwrap()
+            .card
+            .fields
+            .iter()
+            .any(|f| f.value == "bob@example.com"));
+
+        // Mismatched password: both sides reject, and the offer is spent
+        let offer = alice
+            .start_password_exchange("tulip-42".to_string())
+            .unwrap();
+        carol
+            .complete_password_exchange(offer.clone(), "tulip-43".to_string())
+            .unwrap();
+        assert_eq!(alice.sync().unwrap().contacts_added, 0);
+        assert!(alice.get_contact(carol_id.clone()).unwrap().is_none());
+
+        // Alice's failing confirmation is ignored; Carol drops the contact
+        // only once it has waited too long for a valid one
+        carol.sync().unwrap();
+        assert!(carol.get_contact(alice_id.clone()).unwrap().is_some());
+        let later = carol.local_now() + 8 * 24 * 60 * 60;
+        carol.set_clock_for_testing(Some(later));
+        carol.sync().unwrap();
+        assert!(carol.get_contact(alice_id.clone()).unwrap().is_none());
+        assert!(carol.list_recently_deleted().unwrap().is_empty());
+
+        carol
+            .complete_password_exchange(offer, "tulip-42".to_string())
+            .unwrap();
+        assert_eq!(alice.sync().unwrap().contacts_added, 0);
+        assert!(alice.get_contact(carol_id).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_exchange_with_self_is_rejected() {
+        let (alice, _alice_dir) = create_test_instance();
+        alice.create_identity("Alice".to_string()).unwrap();
+
+        let qr = alice.generate_exchange_qr().unwrap();
+        assert!(matches!(
+            alice.complete_exchange(qr.qr_data),
+            Err(MobileError::SelfContact)
+        ));
+        assert!(alice.list_contacts().unwrap().is_empty());
+        assert_eq!(alice.get_pending_exchange_count().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_check_storage_integrity() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        save_test_contact(&wb, &Identity::create("Bob"), false);
+
+        let report = wb.check_storage_integrity().unwrap();
+        assert!(report.is_ok);
+        assert!(report.database_ok);
+        assert_eq!(report.items_checked, 2);
+        assert!(report.failures.is_empty());
+    }
+
+    #[test]
+    fn test_social_networks() {
+        let (wb, _dir) = create_test_instance();
+
+        let networks = wb.list_social_networks();
+        assert!(!networks.is_empty());
+
+        let github = networks.iter().find(|n| n.id == "github");
+        assert!(github.is_some());
+
+        let url = wb.get_profile_url("github".to_string(), "octocat".to_string());
+        assert_eq!(url, Some("https://github.com/octocat".to_string()));
+    }
+
+    #[test]
+    fn test_get_field_icon_key() {
+        let (wb, _dir) = create_test_instance();
+
+        let icon = |field_type, label: &str| {
+            wb.get_field_icon_key(field_type, label.to_string(), "alice".to_string())
+        };
+        assert_eq!(icon(MobileFieldType::Email, "work"), "email");
+        assert_eq!(icon(MobileFieldType::Social, "github"), "github");
+        assert_eq!(
+            icon(MobileFieldType::Social, "friendster"),
+            "generic-social"
+        );
+    }
+
+    #[test]
+    fn test_set_primary_field() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        for label in ["work", "home"] {
+            wb.add_field(
+                MobileFieldType::Email,
+                label.to_string(),
+                format!("alice@{label}.example"),
+            )
+            .unwrap();
+        }
+        let ids: Vec<String> = wb
+            .get_own_card()
+            .unwrap()
+            .fields
+            .into_iter()
+            .map(|f| f.id)
+            .collect();
+        let primaries = || -> Vec<bool> {
+            wb.get_own_card()
+                .unwrap()
+                .fields
+                .iter()
+                .map(|f| f.is_primary)
+                .collect()
+        };
+        assert_eq!(primaries(), vec![false, false]);
+
+        wb.set_primary_field(ids[0].clone()).unwrap();
+        assert_eq!(primaries(), vec![true, false]);
+        wb.set_primary_field(ids[1].clone()).unwrap();
+        assert_eq!(primaries(), vec![false, true]);
+
+        assert!(matches!(
+            wb.set_primary_field("missing".to_string()),
+            Err(MobileError::FieldNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_exchange_qr_generation() {
         let (wb, _dir) = create_test_instance();
         wb.create_identity("Alice".to_string()).unwrap();
 
@@ -2502,6 +8193,306 @@ mod tests {
         assert_eq!(name, "Alice");
     }
 
+    #[test]
+    fn test_backup_with_decoy_passphrase() {
+        let (decoy_wb, _decoy_dir) = create_test_instance();
+        decoy_wb.create_identity("Alex".to_string()).unwrap();
+        let decoy_id = decoy_wb.get_public_id().unwrap();
+        let decoy_backup = decoy_wb
+            .export_backup("purple-monkey-dishwasher-42".to_string())
+            .unwrap();
+
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let real_id = wb.get_public_id().unwrap();
+        wb.set_decoy_backup(decoy_backup, "purple-monkey-dishwasher-42".to_string())
+            .unwrap();
+        assert!(matches!(
+            wb.export_backup("purple-monkey-dishwasher-42".to_string()),
+            Err(MobileError::InvalidInput(_))
+        ));
+        let backup = wb
+            .export_backup("correct-horse-battery-staple".to_string())
+            .unwrap();
+
+        let (real_wb, _real_dir) = create_test_instance();
+        real_wb
+            .import_backup(backup.clone(), "correct-horse-battery-staple".to_string())
+            .unwrap();
+        assert_eq!(real_wb.get_public_id().unwrap(), real_id);
+        assert_eq!(real_wb.get_display_name().unwrap(), "Alice");
+
+        let (coerced_wb, _coerced_dir) = create_test_instance();
+        coerced_wb
+            .import_backup(backup, "purple-monkey-dishwasher-42".to_string())
+            .unwrap();
+        assert_eq!(coerced_wb.get_public_id().unwrap(), decoy_id);
+        assert_eq!(coerced_wb.get_display_name().unwrap(), "Alex");
+    }
+
+    #[test]
+    fn test_set_decoy_backup_rejects_wrong_password() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice".to_string()).unwrap();
+        let own_backup = wb
+            .export_backup("correct-horse-battery-staple".to_string())
+            .unwrap();
+
+        assert!(matches!(
+            wb.set_decoy_backup(own_backup.clone(), "wrong-password-entirely".to_string()),
+            Err(MobileError::CryptoError(_))
+        ));
+        assert!(matches!(
+            wb.set_decoy_backup(own_backup, "correct-horse-battery-staple".to_string()),
+            Err(MobileError::InvalidInput(_))
+        ));
+    }
+
+    /// Creates a mobile instance with an identity, connected to the given relay.
+    fn relay_instance(
+        relay: &crate::transport::MockRelay,
+        name: &str,
+    ) -> (Arc<VauchiMobile>, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let wb = VauchiMobile::new_with_transport(
+            dir.path().to_string_lossy().to_string(),
+            Arc::new(relay.clone()),
+        )
+        .unwrap();
+        wb.create_identity(name.to_string()).unwrap();
+        (wb, dir)
+    }
+
+    /// Starts a recovery for `old_pk_hex` and collects a voucher from each identity.
+    fn complete_recovery(wb: &VauchiMobile, old_pk_hex: &str, vouchers: &[&Identity]) {
+        use base64::Engine;
+        let claim = wb.create_recovery_claim(old_pk_hex.to_string()).unwrap();
+        let claim_bytes = base64::engine::general_purpose::STANDARD
+            .decode(&claim.claim_data)
+            .unwrap();
+        let claim = RecoveryClaim::from_bytes(&claim_bytes).unwrap();
+        for voucher in vouchers {
+            let voucher =
+                RecoveryVoucher::create_from_claim(&claim, voucher.signing_keypair()).unwrap();
+            wb.add_recovery_voucher(
+                base64::engine::general_purpose::STANDARD.encode(voucher.to_bytes()),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_cancel_recovery() {
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice (new device)".to_string())
+            .unwrap();
+        let bob = Identity::create("Bob");
+        let bob_contact = save_test_contact(&wb, &bob, false);
+        let old_pk_hex = hex::encode([0xAAu8; 32]);
+
+        assert!(!wb.has_active_recovery().unwrap());
+        assert!(!wb.cancel_recovery().unwrap());
+
+        complete_recovery(&wb, &old_pk_hex, &[&bob]);
+        assert!(wb.has_active_recovery().unwrap());
+        assert_eq!(
+            wb.get_recovery_status()
+                .unwrap()
+                .unwrap()
+                .vouchers_collected,
+            1
+        );
+
+        assert!(wb.cancel_recovery().unwrap());
+        assert!(!wb.has_active_recovery().unwrap());
+        assert!(wb.get_recovery_status().unwrap().is_none());
+        assert!(wb.get_recovery_proof().unwrap().is_none());
+        assert!(wb.publish_recovery_proof().is_err());
+
+        // Contacts are untouched
+        assert!(wb
+            .get_contact(bob_contact.id().to_string())
+            .unwrap()
+            .is_some());
+
+        // A claim with enough vouchers is no longer in progress
+        let (carol, dave) = (Identity::create("Carol"), Identity::create("Dave"));
+        complete_recovery(&wb, &old_pk_hex, &[&bob, &carol, &dave]);
+        assert!(wb.get_recovery_status().unwrap().unwrap().is_complete);
+        assert!(!wb.has_active_recovery().unwrap());
+    }
+
+    #[test]
+    fn test_recovery_errors_map_to_distinct_mobile_errors() {
+        use vauchi_core::RecoveryError;
+        assert!(matches!(
+            MobileError::from(RecoveryError::SelfVouching),
+            MobileError::SelfVouching
+        ));
+        assert!(matches!(
+            MobileError::from(RecoveryError::DuplicateVoucher),
+            MobileError::DuplicateVoucher
+        ));
+        assert!(matches!(
+            MobileError::from(RecoveryError::MismatchedKeys),
+            MobileError::VoucherMismatch
+        ));
+        assert_eq!(
+            MobileError::SelfVouching.user_message(MobileLocale::English),
+            "You can't vouch for your own recovery."
+        );
+        assert_eq!(
+            MobileError::VoucherMismatch.user_message(MobileLocale::English),
+            "This voucher is for a different claim."
+        );
+
+        use base64::Engine;
+        let (wb, _dir) = create_test_instance();
+        wb.create_identity("Alice (new device)".to_string())
+            .unwrap();
+        let bob = Identity::create("Bob");
+        let old_pk_hex = hex::encode([0xAAu8; 32]);
+        complete_recovery(&wb, &old_pk_hex, &[&bob]);
+
+        let claim = wb.create_recovery_claim(old_pk_hex).unwrap();
+        let claim_bytes = base64::engine::general_purpose::STANDARD
+            .decode(&claim.claim_data)
+            .unwrap();
+        let claim = RecoveryClaim::from_bytes(&claim_bytes).unwrap();
+        let voucher = RecoveryVoucher::create_from_claim(&claim, bob.signing_keypair()).unwrap();
+        let voucher_b64 = base64::engine::general_purpose::STANDARD.encode(voucher.to_bytes());
+        wb.add_recovery_voucher(voucher_b64.clone()).unwrap();
+        assert!(matches!(
+            wb.add_recovery_voucher(voucher_b64),
+            Err(MobileError::DuplicateVoucher)
+        ));
+
+        let other_claim = RecoveryClaim::new(&[0xBBu8; 32], &[0xCCu8; 32]);
+        let other_voucher = RecoveryVoucher::create_from_claim(
+            &other_claim,
+            Identity::create("Carol").signing_keypair(),
+        )
+        .unwrap();
+        assert!(matches!(
+            wb.add_recovery_voucher(
+                base64::engine::general_purpose::STANDARD.encode(other_voucher.to_bytes())
+            ),
+            Err(MobileError::VoucherMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_recovery_proof_via_relay() {
+        use crate::transport::MockRelay;
+        let relay = MockRelay::new();
+        let old_pk_hex = hex::encode([0xAAu8; 32]);
+
+        let (recovering, _recovering_dir) = relay_instance(&relay, "Alice (new device)");
+        recovering
+            .create_recovery_claim(old_pk_hex.clone())
+            .unwrap();
+        // Incomplete proofs are not published
+        assert!(recovering.publish_recovery_proof().is_err());
+
+        let (bob, carol, dave) = (
+            Identity::create("Bob"),
+            Identity::create("Carol"),
+            Identity::create("Dave"),
+        );
+        complete_recovery(&recovering, &old_pk_hex, &[&bob, &carol, &dave]);
+        recovering.publish_recovery_proof().unwrap();
+
+        let (contact, _contact_dir) = relay_instance(&relay, "Eve");
+        let fetched = contact.fetch_recovery_proofs(old_pk_hex.clone()).unwrap();
+        assert_eq!(fetched.proofs.len(), 1);
+        assert!(fetched.conflict.is_none());
+        assert_eq!(fetched.recommended_index, Some(0));
+
+        let verification = contact
+            .verify_recovery_proof(fetched.proofs[0].proof_data.clone())
+            .unwrap();
+        assert_eq!(verification.old_public_key, old_pk_hex);
+        assert_eq!(
+            verification.new_public_key,
+            recovering.get_public_id().unwrap()
+        );
+        assert_eq!(verification.voucher_count, 3);
+
+        assert!(contact
+            .fetch_recovery_proofs(hex::encode([0xBBu8; 32]))
+            .unwrap()
+            .proofs
+            .is_empty());
+    }
+
+    #[test]
+    fn test_fetch_conflicting_recovery_proofs_recommends_mutual_vouchers() {
+        use crate::transport::MockRelay;
+        let relay = MockRelay::new();
+        let old_pk_hex = hex::encode([0xAAu8; 32]);
+
+        let (bob, carol, dave) = (
+            Identity::create("Bob"),
+            Identity::create("Carol"),
+            Identity::create("Dave"),
+        );
+        let strangers: Vec<Identity> = (0..3)
+            .map(|i| Identity::create(&format!("Sybil {}", i)))
+            .collect();
+
+        let (genuine, _genuine_dir) = relay_instance(&relay, "Alice");
+        complete_recovery(&genuine, &old_pk_hex, &[&bob, &carol, &dave]);
+        genuine.publish_recovery_proof().unwrap();
+
+        let (attacker, _attacker_dir) = relay_instance(&relay, "Mallory");
+        complete_recovery(
+            &attacker,
+            &old_pk_hex,
+            &strangers.iter().collect::<Vec<_>>(),
+        );
+        attacker.publish_recovery_proof().unwrap();
+
+        // Eve knows Bob and Carol
+        let (eve, _eve_dir) = relay_instance(&relay, "Eve");
+        save_test_contact(&eve, &bob, false);
+        save_test_contact(&eve, &carol, false);
+
+        let fetched = eve.fetch_recovery_proofs(old_pk_hex).unwrap();
+        assert_eq!(fetched.proofs.len(), 2);
+
+        let conflict = fetched.conflict.expect("conflict should be detected");
+        assert_eq!(conflict.claims.len(), 2);
+
+        let recommended = &fetched.proofs[fetched.recommended_index.unwrap() as usize];
+        assert_eq!(
+            recommended.verification.new_public_key,
+            genuine.get_public_id().unwrap()
+        );
+        assert_eq!(recommended.verification.known_vouchers, 2);
+        assert_eq!(recommended.verification.confidence, "high");
+    }
+
+    #[test]
+    fn test_fetch_recovery_proof_skips_expired() {
+        use crate::transport::MockRelay;
+        let relay = MockRelay::new();
+        let (wb, _dir) = relay_instance(&relay, "Eve");
+
+        // The proof's expiry is the last field of its serialization
+        let old_pk = [0xAAu8; 32];
+        let mut bytes = RecoveryProof::new(&old_pk, &[0xBBu8; 32], 1).to_bytes();
+        let len = bytes.len();
+        bytes[len - 8..].copy_from_slice(&1u64.to_le_bytes());
+        assert!(RecoveryProof::from_bytes(&bytes).unwrap().is_expired());
+        relay.store_recovery_proof(&hex::encode(old_pk), "publisher", bytes);
+
+        assert!(wb
+            .fetch_recovery_proofs(hex::encode(old_pk))
+            .unwrap()
+            .proofs
+            .is_empty());
+    }
+
     #[test]
     fn test_get_devices_no_registry() {
         let (wb, _dir) = create_test_instance();
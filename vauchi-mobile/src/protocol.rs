@@ -10,17 +10,23 @@
 
 // Re-export types from vauchi-core's simple_message module
 pub use vauchi_core::network::simple_message::{
-    create_device_sync_ack, create_device_sync_message, create_simple_ack as create_ack,
+    create_delivery_status_query, create_device_sync_ack, create_device_sync_message,
+    create_public_card_publish, create_public_card_unpublish, create_recovery_proof_query,
+    create_recovery_proof_store, create_simple_ack as create_ack,
     create_simple_envelope as create_envelope, decode_simple_message as decode_message,
     encode_simple_message as encode_message, LegacyExchangeMessage as ExchangeMessage,
     SimpleAckStatus as AckStatus, SimpleDeviceSyncMessage as DeviceSyncMessage,
     SimpleEncryptedUpdate as EncryptedUpdate, SimpleHandshake as Handshake,
-    SimplePayload as MessagePayload,
+    SimpleMessageState as MessageState, SimplePayload as MessagePayload,
 };
 
 // Re-export for tests
 #[cfg(test)]
-pub use vauchi_core::network::simple_message::SIMPLE_PROTOCOL_VERSION as PROTOCOL_VERSION;
+pub use vauchi_core::network::simple_message::{
+    SimpleDeliveryStatusResponse as DeliveryStatusResponse, SimpleMessageStatus as MessageStatus,
+    SimpleRecoveryProofResponse as RecoveryProofResponse,
+    SIMPLE_PROTOCOL_VERSION as PROTOCOL_VERSION,
+};
 
 // INLINE_TEST_REQUIRED: Tests re-export compatibility layer - small module kept inline
 #[cfg(test)]
@@ -32,6 +38,7 @@ mod tests {
         let handshake = Handshake {
             client_id: "test-client".to_string(),
             device_id: None,
+            since: None,
         };
         let envelope = create_envelope(MessagePayload::Handshake(handshake));
 
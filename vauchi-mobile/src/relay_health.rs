@@ -0,0 +1,133 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Relay Health Checks
+//!
+//! Caches the result of the last relay health check so apps can poll it
+//! freely. A reachable relay is re-checked at most once per cache window;
+//! an unreachable one only after a cooldown that doubles with each
+//! consecutive failure (see `vauchi_core::network::RelayHealth`).
+
+use std::time::{Duration, Instant};
+
+use vauchi_core::network::RelayHealth;
+
+/// How long a successful health check is reused.
+const CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Cooldown after the first failure; doubles with each further failure.
+const BASE_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Reachability of the relay as of the last health check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum MobileRelayState {
+    /// No health check has run against the current relay.
+    Unknown,
+    /// The last check connected to the relay.
+    Reachable,
+    /// The last check could not connect to the relay.
+    Unreachable,
+}
+
+/// Cached result of relay health checks.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileRelayStatus {
+    pub state: MobileRelayState,
+    /// Failed checks since the last successful one.
+    pub consecutive_failures: u32,
+    /// Seconds until a health check will contact the relay again.
+    pub retry_after_secs: u64,
+    /// Seconds since the last check, if one has run.
+    pub checked_secs_ago: Option<u64>,
+}
+
+/// The last health check result, with backoff after failures.
+pub(crate) struct RelayHealthCache {
+    health: RelayHealth,
+    cache_ttl: Duration,
+    /// Relay URL, time and outcome of the last check.
+    last_check: Option<(String, Instant, bool)>,
+    /// Source of the current time, replaceable in tests.
+    clock: Box<dyn Fn() -> Instant + Send>,
+}
+
+impl Default for RelayHealthCache {
+    fn default() -> Self {
+        Self::new(CACHE_TTL, BASE_BACKOFF)
+    }
+}
+
+impl RelayHealthCache {
+    /// Creates a cache with the given window and initial failure cooldown.
+    pub(crate) fn new(cache_ttl: Duration, base_backoff: Duration) -> Self {
+        Self::with_clock(cache_ttl, base_backoff, Instant::now)
+    }
+
+    /// Creates a cache that reads the current time from `clock`.
+    pub(crate) fn with_clock(
+        cache_ttl: Duration,
+        base_backoff: Duration,
+        clock: impl Fn() -> Instant + Send + 'static,
+    ) -> Self {
+        Self {
+            health: RelayHealth::with_cooldown(base_backoff),
+            cache_ttl,
+            last_check: None,
+            clock: Box::new(clock),
+        }
+    }
+
+    /// Returns the last result for `relay` if it should be reused instead
+    /// of contacting the relay.
+    pub(crate) fn cached(&self, relay: &str) -> Option<bool> {
+        if self.retry_after(relay) > Duration::ZERO {
+            self.last_check.as_ref().map(|(_, _, reachable)| *reachable)
+        } else {
+            None
+        }
+    }
+
+    /// Records the outcome of a check against `relay`.
+    pub(crate) fn record(&mut self, relay: &str, reachable: bool) {
+        let now = (self.clock)();
+        if reachable {
+            self.health.record_success_at(relay, now);
+        } else {
+            self.health.record_failure_at(relay, now);
+        }
+        self.last_check = Some((relay.to_string(), now, reachable));
+    }
+
+    /// Time until a check against `relay` will contact it again.
+    pub(crate) fn retry_after(&self, relay: &str) -> Duration {
+        let now = (self.clock)();
+        match &self.last_check {
+            Some((url, _, false)) if url == relay => self.health.cooldown_remaining_at(relay, now),
+            Some((url, checked_at, true)) if url == relay => self
+                .cache_ttl
+                .saturating_sub(now.saturating_duration_since(*checked_at)),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Summarizes the cached state of `relay`.
+    pub(crate) fn status(&self, relay: &str) -> MobileRelayStatus {
+        let last = self.last_check.as_ref().filter(|(url, _, _)| url == relay);
+
+        MobileRelayStatus {
+            state: match last {
+                None => MobileRelayState::Unknown,
+                Some((_, _, true)) => MobileRelayState::Reachable,
+                Some((_, _, false)) => MobileRelayState::Unreachable,
+            },
+            consecutive_failures: self.health.failure_count(relay),
+            retry_after_secs: self.retry_after(relay).as_secs(),
+            checked_secs_ago: last.map(|(_, checked_at, _)| {
+                (self.clock)()
+                    .saturating_duration_since(*checked_at)
+                    .as_secs()
+            }),
+        }
+    }
+}
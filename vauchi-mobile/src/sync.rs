@@ -7,54 +7,128 @@
 //! This module handles sending and receiving messages through the relay,
 //! including exchange messages and card updates.
 
-use std::net::TcpStream;
-use std::time::Duration;
-
-use tungstenite::stream::MaybeTlsStream;
-use tungstenite::{Message, WebSocket};
+use std::collections::HashSet;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use vauchi_core::api::{Telemetry, TelemetryEvent};
 use vauchi_core::crypto::ratchet::DoubleRatchetState;
-use vauchi_core::exchange::{EncryptedExchangeMessage, X3DHKeyPair};
-use vauchi_core::sync::{ContactSyncData, DeviceSyncOrchestrator, SyncItem};
-use vauchi_core::{Contact, ContactCard, Identity, Storage};
+use vauchi_core::crypto::PublicKey;
+use vauchi_core::exchange::{
+    DecryptedExchangePayload, EncryptedExchangeMessage, PasswordExchangeConfirmation,
+    PasswordExchangeMessage, PasswordKey, PendingConfirmation, RekeyMessage, RekeyRequest,
+    X3DHKeyPair,
+};
+use vauchi_core::identity::KeyRotationProof;
+use vauchi_core::network::simple_message::SimpleEnvelope;
+use vauchi_core::recovery::RecoveryProof;
+use vauchi_core::storage::{DeliveryRecord, DeliveryStatus, StorageError};
+use vauchi_core::sync::{
+    CardDelta, ContactSyncData, DeltaError, DeviceSyncOrchestrator, FieldChange, SyncItem,
+};
+use vauchi_core::{
+    Contact, ContactCard, ContactField, Identity, PendingExchange, PendingUpdate, PublicCard,
+    PublicCardRemoval, Storage, SymmetricKey, UpdateStatus,
+};
 
-use crate::cert_pinning;
 use crate::error::MobileError;
 use crate::protocol::{
-    self, create_device_sync_ack, create_device_sync_message, AckStatus, DeviceSyncMessage,
-    EncryptedUpdate, ExchangeMessage, Handshake, MessagePayload,
+    self, create_delivery_status_query, create_device_sync_ack, create_device_sync_message,
+    create_public_card_publish, create_public_card_unpublish, create_recovery_proof_query,
+    create_recovery_proof_store, AckStatus, DeviceSyncMessage, EncryptedUpdate, ExchangeMessage,
+    Handshake, MessagePayload, MessageState,
 };
-use crate::types::MobileSyncResult;
+use crate::transport::{RelayConnector, RelayTransport};
+use crate::types::{MobileContactSyncOutcome, MobileSyncResult, MobileUpdateFailure};
+
+/// How long processed message IDs are remembered for redelivery detection.
+const PROCESSED_MESSAGE_RETENTION_SECS: u64 = 90 * 24 * 60 * 60;
+
+/// How long a contact from a password exchange we answered waits for the
+/// initiator's confirmation before it is dropped.
+const PASSWORD_CONFIRMATION_TIMEOUT_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// A relay message waiting to be processed.
+pub struct Inbound<T> {
+    /// Relay message ID; recorded as processed once the message is handled.
+    pub message_id: String,
+    /// The message content.
+    pub payload: T,
+}
+
+/// How a received message is acknowledged once it has been handled.
+pub enum InboundAck {
+    /// Acknowledged as received by the recipient.
+    Update,
+    /// Acknowledged to the sending device with the sync version.
+    DeviceSync { version: u64 },
+    /// Needs no acknowledgment and is handled on arrival.
+    None,
+}
 
 /// Result of receiving pending messages from relay.
 pub struct ReceivedMessages {
     /// Legacy plaintext exchange messages (backward compatibility).
-    pub legacy_exchange: Vec<ExchangeMessage>,
+    pub legacy_exchange: Vec<Inbound<ExchangeMessage>>,
     /// Encrypted exchange messages (new format).
-    pub encrypted_exchange: Vec<Vec<u8>>,
+    pub encrypted_exchange: Vec<Inbound<Vec<u8>>>,
+    /// Answers to our password exchange offer: (sender_id, message).
+    pub password_exchange: Vec<Inbound<(String, Vec<u8>)>>,
+    /// Confirmations of password exchanges we answered: (sender_id, message).
+    pub password_confirmations: Vec<Inbound<(String, Vec<u8>)>>,
     /// Card updates from existing contacts: (sender_id, ciphertext).
-    pub card_updates: Vec<(String, Vec<u8>)>,
+    pub card_updates: Vec<Inbound<(String, Vec<u8>)>>,
     /// Device sync messages (inter-device synchronization).
-    pub device_sync_messages: Vec<DeviceSyncMessage>,
+    pub device_sync_messages: Vec<Inbound<DeviceSyncMessage>>,
+    /// Every message received, in arrival order, with how to acknowledge
+    /// it. Used to acknowledge handled messages and advance the cursor.
+    pub arrivals: Vec<(String, InboundAck)>,
+    /// Whether receiving stopped at the budget, so messages may remain.
+    pub more_pending: bool,
+}
+
+/// Bounds on how much a sync receives, for short background windows.
+#[derive(Debug, Clone, Copy)]
+pub struct SyncBudget {
+    /// Most relay messages to read.
+    pub max_messages: u32,
+    /// Stop reading once this instant passes.
+    pub deadline: Instant,
+}
+
+impl SyncBudget {
+    /// A budget of `max_messages` messages or `duration`, starting now.
+    pub fn new(max_messages: u32, duration: Duration) -> Self {
+        Self {
+            max_messages,
+            deadline: Instant::now() + duration,
+        }
+    }
+
+    /// Time left before the deadline.
+    fn remaining(&self) -> Duration {
+        self.deadline.saturating_duration_since(Instant::now())
+    }
 }
 
 /// Sends handshake to relay.
+///
+/// `since` is the sync cursor: relays that support it only deliver
+/// messages queued after that message ID.
 pub fn send_handshake(
-    socket: &mut WebSocket<MaybeTlsStream<TcpStream>>,
+    transport: &mut dyn RelayTransport,
     client_id: &str,
     device_id: Option<&str>,
+    since: Option<&str>,
 ) -> Result<(), MobileError> {
     let handshake = Handshake {
         client_id: client_id.to_string(),
         device_id: device_id.map(|s| s.to_string()),
+        since: since.map(|s| s.to_string()),
     };
     let envelope = protocol::create_envelope(MessagePayload::Handshake(handshake));
     let data = protocol::encode_message(&envelope)
         .map_err(|e| MobileError::SyncFailed(format!("Encode error: {}", e)))?;
-    socket
-        .send(Message::Binary(data))
-        .map_err(|e| MobileError::NetworkError(e.to_string()))?;
-    Ok(())
+    transport.send(data)
 }
 
 /// Receives pending messages from relay.
@@ -62,102 +136,208 @@ pub fn send_handshake(
 /// Classifies incoming messages into:
 /// - Legacy plaintext exchange messages
 /// - Encrypted exchange messages
+/// - Password exchange answers and confirmations
 /// - Card updates (ratchet-encrypted)
 /// - Device sync messages (inter-device synchronization)
-#[allow(clippy::type_complexity)]
+///
+/// Relay-signed delivery receipts are stored if they verify against
+/// `relay_receipt_key`; without a pinned key they are dropped.
+///
+/// Nothing is acknowledged here: messages are acknowledged once they have
+/// been handled. Messages whose ID was already processed (relay
+/// redelivery) are not classified again.
+///
+/// With a `budget`, stops reading once it is used up. Unread messages
+/// stay with the relay for the next sync.
 pub fn receive_pending(
-    socket: &mut WebSocket<MaybeTlsStream<TcpStream>>,
+    transport: &mut dyn RelayTransport,
+    storage: &Storage,
+    budget: Option<&SyncBudget>,
+    relay_receipt_key: Option<&PublicKey>,
 ) -> Result<ReceivedMessages, MobileError> {
-    let mut legacy_exchange_messages = Vec::new();
-    let mut encrypted_exchange_messages = Vec::new();
-    let mut card_updates = Vec::new();
-    let mut device_sync_messages = Vec::new();
+    let mut received = ReceivedMessages {
+        legacy_exchange: Vec::new(),
+        encrypted_exchange: Vec::new(),
+        password_exchange: Vec::new(),
+        password_confirmations: Vec::new(),
+        card_updates: Vec::new(),
+        device_sync_messages: Vec::new(),
+        arrivals: Vec::new(),
+        more_pending: false,
+    };
+    let mut count = 0u32;
 
     loop {
-        match socket.read() {
-            Ok(Message::Binary(data)) => {
-                if let Ok(envelope) = protocol::decode_message(&data) {
-                    match envelope.payload {
-                        MessagePayload::EncryptedUpdate(update) => {
-                            classify_and_store_message(
-                                update,
-                                &mut legacy_exchange_messages,
-                                &mut encrypted_exchange_messages,
-                                &mut card_updates,
-                            );
-
-                            // Send acknowledgment
-                            send_ack(socket, &envelope.message_id);
-                        }
-                        MessagePayload::DeviceSyncMessage(msg) => {
-                            // Get version before moving msg
-                            let version = msg.version;
-                            device_sync_messages.push(msg);
-
-                            // Send device sync ack
-                            let ack = create_device_sync_ack(&envelope.message_id, version);
-                            if let Ok(ack_data) = protocol::encode_message(&ack) {
-                                let _ = socket.send(Message::Binary(ack_data));
-                            }
-                        }
-                        _ => {}
-                    }
+        if let Some(budget) = budget {
+            if count >= budget.max_messages || Instant::now() >= budget.deadline {
+                received.more_pending = true;
+                break;
+            }
+        }
+        let Some(data) = transport.receive()? else {
+            break;
+        };
+        count += 1;
+
+        let Ok(envelope) = protocol::decode_message(&data) else {
+            continue;
+        };
+        let message_id = envelope.message_id;
+        match envelope.payload {
+            MessagePayload::EncryptedUpdate(update) => {
+                if !storage.is_message_processed(&message_id)? {
+                    classify_and_store_message(&message_id, update, &mut received);
                 }
+                received.arrivals.push((message_id, InboundAck::Update));
             }
-            Ok(Message::Ping(data)) => {
-                let _ = socket.send(Message::Pong(data));
+            MessagePayload::DeviceSyncMessage(msg) => {
+                let version = msg.version;
+                if !storage.is_message_processed(&message_id)? {
+                    received.device_sync_messages.push(Inbound {
+                        message_id: message_id.clone(),
+                        payload: msg,
+                    });
+                }
+                received
+                    .arrivals
+                    .push((message_id, InboundAck::DeviceSync { version }));
             }
-            Ok(Message::Close(_)) => break,
-            Ok(_) => { /* Ignore other message types */ }
-            Err(tungstenite::Error::Io(ref e))
-                if e.kind() == std::io::ErrorKind::WouldBlock
-                    || e.kind() == std::io::ErrorKind::TimedOut =>
-            {
-                // No more messages
-                break;
+            MessagePayload::DeliveryReceipt(receipt) => {
+                received.arrivals.push((message_id, InboundAck::None));
+                let Some(relay_key) = relay_receipt_key else {
+                    continue;
+                };
+                let received_at = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+                match storage.save_delivery_receipt(&receipt, relay_key, received_at) {
+                    // Forged receipts are dropped
+                    Ok(_) | Err(StorageError::InvalidData(_)) => {}
+                    Err(e) => return Err(e.into()),
+                }
             }
-            Err(_) => break,
+            _ => received.arrivals.push((message_id, InboundAck::None)),
         }
     }
 
-    Ok(ReceivedMessages {
-        legacy_exchange: legacy_exchange_messages,
-        encrypted_exchange: encrypted_exchange_messages,
-        card_updates,
-        device_sync_messages,
-    })
+    Ok(received)
 }
 
 /// Classifies an incoming message and stores it in the appropriate collection.
 fn classify_and_store_message(
+    message_id: &str,
     update: EncryptedUpdate,
-    legacy_exchange: &mut Vec<ExchangeMessage>,
-    encrypted_exchange: &mut Vec<Vec<u8>>,
-    card_updates: &mut Vec<(String, Vec<u8>)>,
+    received: &mut ReceivedMessages,
 ) {
+    let message_id = message_id.to_string();
+
     // Try legacy plaintext exchange format first
     if ExchangeMessage::is_exchange(&update.ciphertext) {
         if let Some(exchange) = ExchangeMessage::from_bytes(&update.ciphertext) {
-            legacy_exchange.push(exchange);
+            received.legacy_exchange.push(Inbound {
+                message_id,
+                payload: exchange,
+            });
             return;
         }
     }
 
     // Try encrypted exchange format
     if EncryptedExchangeMessage::from_bytes(&update.ciphertext).is_ok() {
-        encrypted_exchange.push(update.ciphertext);
+        received.encrypted_exchange.push(Inbound {
+            message_id,
+            payload: update.ciphertext,
+        });
+        return;
+    }
+
+    // Try password exchange format
+    if PasswordExchangeMessage::from_bytes(&update.ciphertext).is_ok() {
+        received.password_exchange.push(Inbound {
+            message_id,
+            payload: (update.sender_id, update.ciphertext),
+        });
+        return;
+    }
+
+    if PasswordExchangeConfirmation::from_bytes(&update.ciphertext).is_ok() {
+        received.password_confirmations.push(Inbound {
+            message_id,
+            payload: (update.sender_id, update.ciphertext),
+        });
         return;
     }
 
     // Otherwise it's a card update
-    card_updates.push((update.sender_id, update.ciphertext));
+    received.card_updates.push(Inbound {
+        message_id,
+        payload: (update.sender_id, update.ciphertext),
+    });
+}
+
+/// Handles an inbound message and records it as processed, atomically.
+///
+/// If `handle` fails, nothing it wrote is kept and the message stays
+/// unprocessed, so a later sync retries it. `handle` returns `None` to
+/// leave the message unprocessed on purpose. It must not talk to the
+/// relay: replies are queued and sent once the transaction commits.
+fn handle_inbound<T>(
+    storage: &Storage,
+    message_id: &str,
+    handle: impl FnOnce() -> Result<Option<T>, MobileError>,
+) -> Result<Option<T>, MobileError> {
+    storage.atomically(|| {
+        let result = handle()?;
+        if result.is_some() {
+            storage.mark_message_processed(message_id)?;
+        }
+        Ok(result)
+    })
+}
+
+/// Acknowledges the handled messages among `arrivals`.
+///
+/// Returns the ID of the last message before the first unhandled one,
+/// which is as far as the sync cursor may move.
+fn acknowledge_handled(
+    storage: &Storage,
+    transport: &mut dyn RelayTransport,
+    arrivals: &[(String, InboundAck)],
+) -> Result<Option<String>, MobileError> {
+    let mut cursor = None;
+    let mut all_handled = true;
+    for (message_id, ack) in arrivals {
+        let handled = match ack {
+            InboundAck::None => true,
+            _ => storage.is_message_processed(message_id)?,
+        };
+        if !handled {
+            all_handled = false;
+            continue;
+        }
+        match ack {
+            InboundAck::Update => send_ack(transport, message_id),
+            InboundAck::DeviceSync { version } => {
+                let ack = create_device_sync_ack(message_id, *version);
+                if let Ok(ack_data) = protocol::encode_message(&ack) {
+                    let _ = transport.send(ack_data);
+                }
+            }
+            InboundAck::None => {}
+        }
+        if all_handled {
+            cursor = Some(message_id.clone());
+        }
+    }
+    Ok(cursor)
 }
 
 /// Sends an acknowledgment for a received message.
-fn send_ack(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, message_id: &str) {
+fn send_ack(transport: &mut dyn RelayTransport, message_id: &str) {
     let ack = protocol::create_ack(message_id, AckStatus::ReceivedByRecipient);
     if let Ok(ack_data) = protocol::encode_message(&ack) {
-        let _ = socket.send(Message::Binary(ack_data));
+        let _ = transport.send(ack_data);
     }
 }
 
@@ -165,237 +345,810 @@ fn send_ack(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, message_id: &str)
 pub fn process_legacy_exchange_messages(
     identity: &Identity,
     storage: &Storage,
-    messages: Vec<ExchangeMessage>,
-    relay_url: &str,
-    pinned_cert: Option<&str>,
+    messages: Vec<Inbound<ExchangeMessage>>,
 ) -> Result<u32, MobileError> {
     let mut added = 0u32;
-    let our_x3dh = identity.x3dh_keypair();
 
-    for exchange in messages {
-        // Parse identity key
-        let identity_key = match parse_hex_key(&exchange.identity_public_key) {
-            Some(key) => key,
-            None => continue,
-        };
+    for Inbound {
+        message_id,
+        payload,
+    } in messages
+    {
+        let accepted = handle_inbound(storage, &message_id, || {
+            accept_legacy_exchange(identity, storage, payload).map(Some)
+        })?;
+        if accepted == Some(true) {
+            added += 1;
+        }
+    }
 
-        let public_id = hex::encode(identity_key);
+    Ok(added)
+}
 
-        // Handle response to our exchange (update contact name)
-        if exchange.is_response {
-            update_contact_name_if_needed(storage, &public_id, &exchange.display_name);
-            continue;
-        }
+/// Saves the contact from a legacy exchange message and queues our response.
+///
+/// Returns whether a contact was added.
+fn accept_legacy_exchange(
+    identity: &Identity,
+    storage: &Storage,
+    exchange: ExchangeMessage,
+) -> Result<bool, MobileError> {
+    let our_x3dh = identity.x3dh_keypair();
 
-        // Check if contact already exists
-        if storage.load_contact(&public_id)?.is_some() {
-            continue;
-        }
+    // Parse identity key
+    let identity_key = match parse_hex_key(&exchange.identity_public_key) {
+        Some(key) => key,
+        None => return Ok(false),
+    };
 
-        // Parse ephemeral key
-        let ephemeral_key = match parse_hex_key(&exchange.ephemeral_public_key) {
-            Some(key) => key,
-            None => continue,
-        };
+    let public_id = hex::encode(identity_key);
 
-        // Perform X3DH as responder
-        let shared_secret =
-            match vauchi_core::exchange::X3DH::respond(&our_x3dh, &identity_key, &ephemeral_key) {
-                Ok(secret) => secret,
-                Err(_) => continue,
-            };
+    // Handle response to our exchange (update contact name)
+    if exchange.is_response {
+        update_contact_name_if_needed(storage, &public_id, &exchange.display_name);
+        return Ok(false);
+    }
+
+    // Check if contact already exists; reference contacts are replaced
+    if storage
+        .load_contact(&public_id)?
+        .is_some_and(|c| !c.is_reference())
+    {
+        return Ok(false);
+    }
 
-        // Create and save contact
-        let card = ContactCard::new(&exchange.display_name);
-        let contact = Contact::from_exchange(identity_key, card, shared_secret.clone());
-        let contact_id = contact.id().to_string();
-        storage.save_contact(&contact)?;
+    // Parse ephemeral key
+    let ephemeral_key = match parse_hex_key(&exchange.ephemeral_public_key) {
+        Some(key) => key,
+        None => return Ok(false),
+    };
 
-        // Record for inter-device sync
-        let _ = record_contact_for_device_sync(identity, storage, &contact);
+    // Perform X3DH as responder
+    let shared_secret =
+        match vauchi_core::exchange::X3DH::respond(&our_x3dh, &identity_key, &ephemeral_key) {
+            Ok(secret) => secret,
+            Err(_) => return Ok(false),
+        };
+
+    // Create and save contact
+    let card = ContactCard::new(&exchange.display_name);
+    let contact = match Contact::try_from_exchange(
+        identity_key,
+        card,
+        shared_secret.clone(),
+        identity.signing_public_key(),
+    ) {
+        Ok(contact) => contact,
+        Err(_) => return Ok(false),
+    };
+    let contact_id = contact.id().to_string();
+    storage.save_contact(&contact)?;
 
-        // Initialize ratchet as responder
-        let ratchet_dh = X3DHKeyPair::from_bytes(our_x3dh.secret_bytes());
-        let ratchet = DoubleRatchetState::initialize_responder(&shared_secret, ratchet_dh);
-        let _ = storage.save_ratchet_state(&contact_id, &ratchet, true);
+    // Record for inter-device sync
+    let _ = record_contact_for_device_sync(identity, storage, &contact);
 
-        added += 1;
+    // Initialize ratchet as responder
+    let ratchet_dh = X3DHKeyPair::from_bytes(our_x3dh.secret_bytes());
+    let ratchet = DoubleRatchetState::initialize_responder(&shared_secret, ratchet_dh);
+    storage.save_ratchet_state(&contact_id, &ratchet, true)?;
 
-        // Send encrypted exchange response
-        let _ =
-            send_exchange_response(identity, &public_id, &ephemeral_key, relay_url, pinned_cert);
-    }
+    // Queue encrypted exchange response
+    let card = project_own_card(storage, &contact).ok().flatten();
+    queue_exchange_response(identity, storage, &public_id, &ephemeral_key, card.as_ref())?;
 
-    Ok(added)
+    Ok(true)
 }
 
 /// Processes encrypted exchange messages (new format with proper encryption).
 pub fn process_encrypted_exchange_messages(
     identity: &Identity,
     storage: &Storage,
-    encrypted_data: Vec<Vec<u8>>,
-    relay_url: &str,
-    pinned_cert: Option<&str>,
+    encrypted_data: Vec<Inbound<Vec<u8>>>,
 ) -> Result<u32, MobileError> {
     let mut added = 0u32;
     let our_x3dh = identity.x3dh_keypair();
 
-    for data in encrypted_data {
-        // Try to parse as EncryptedExchangeMessage
-        let encrypted_msg = match EncryptedExchangeMessage::from_bytes(&data) {
-            Ok(msg) => msg,
-            Err(_) => continue,
-        };
+    for Inbound {
+        message_id,
+        payload: data,
+    } in encrypted_data
+    {
+        let accepted = handle_inbound(storage, &message_id, || {
+            // Try to parse as EncryptedExchangeMessage
+            let encrypted_msg = match EncryptedExchangeMessage::from_bytes(&data) {
+                Ok(msg) => msg,
+                Err(_) => return Ok(Some(false)),
+            };
 
-        // Decrypt to get sender's info
-        let (payload, shared_secret) = match encrypted_msg.decrypt(&our_x3dh) {
-            Ok(result) => result,
-            Err(_) => continue,
-        };
+            // Decrypt to get sender's info
+            let (payload, shared_secret) = match encrypted_msg.decrypt(&our_x3dh) {
+                Ok(result) => result,
+                Err(_) => return Ok(Some(false)),
+            };
+
+            accept_exchange(identity, storage, payload, shared_secret).map(Some)
+        })?;
+        if accepted == Some(true) {
+            added += 1;
+        }
+    }
 
-        let public_id = hex::encode(payload.identity_key);
+    Ok(added)
+}
 
-        // Check if contact already exists
-        if storage.load_contact(&public_id)?.is_some() {
-            // Contact exists - might be a response, update name if needed
-            update_contact_name_if_needed(storage, &public_id, &payload.display_name);
-            continue;
+/// Processes answers to our password exchange offer.
+///
+/// The first answer consumes `pending`, whether or not its password
+/// matched, so each offer allows a single guess. Answers arriving without
+/// an open offer are dropped. Each answer gets our confirmation back once
+/// it is handled, so the responder learns whether the passwords matched.
+pub fn process_password_exchange_messages(
+    identity: &Identity,
+    storage: &Storage,
+    messages: Vec<Inbound<(String, Vec<u8>)>>,
+    pending: &mut Option<PasswordKey>,
+    transport: &mut dyn RelayTransport,
+) -> Result<u32, MobileError> {
+    let mut added = 0u32;
+    let our_id = identity.public_id();
+    let our_x3dh = identity.x3dh_keypair();
+
+    for Inbound {
+        message_id,
+        payload: (sender_id, data),
+    } in messages
+    {
+        let mut confirmation = None;
+        let accepted = handle_inbound(storage, &message_id, || {
+            let Ok(message) = PasswordExchangeMessage::from_bytes(&data) else {
+                return Ok(Some(false));
+            };
+            let Some(key) = pending.take() else {
+                return Ok(Some(false));
+            };
+
+            let accepted = match message.open(&key, &our_x3dh) {
+                Ok((payload, shared_secret)) => {
+                    accept_exchange(identity, storage, payload, shared_secret)?
+                }
+                Err(_) => false,
+            };
+            confirmation = Some(message.confirm(&key));
+            Ok(Some(accepted))
+        })?;
+        if let Some(confirmation) = confirmation {
+            let _ = send_password_confirmation(&our_id, &sender_id, &confirmation, transport);
         }
+        if accepted == Some(true) {
+            added += 1;
+        }
+    }
 
-        // Create new contact
-        let card = ContactCard::new(&payload.display_name);
-        let contact = Contact::from_exchange(payload.identity_key, card, shared_secret.clone());
-        let contact_id = contact.id().to_string();
-        storage.save_contact(&contact)?;
+    Ok(added)
+}
 
-        // Record for inter-device sync
-        let _ = record_contact_for_device_sync(identity, storage, &contact);
+/// Sends our confirmation for a password exchange answer to its responder.
+fn send_password_confirmation(
+    our_id: &str,
+    recipient_id: &str,
+    confirmation: &PasswordExchangeConfirmation,
+    transport: &mut dyn RelayTransport,
+) -> Result<(), MobileError> {
+    let update = EncryptedUpdate {
+        recipient_id: recipient_id.to_string(),
+        sender_id: our_id.to_string(),
+        ciphertext: confirmation.to_bytes(),
+    };
+    let envelope = protocol::create_envelope(MessagePayload::EncryptedUpdate(update));
+    let data = protocol::encode_message(&envelope).map_err(MobileError::SyncFailed)?;
+    transport.send(data)
+}
 
-        // Initialize ratchet as responder
-        let ratchet_dh = X3DHKeyPair::from_bytes(our_x3dh.secret_bytes());
-        let ratchet = DoubleRatchetState::initialize_responder(&shared_secret, ratchet_dh);
-        let _ = storage.save_ratchet_state(&contact_id, &ratchet, false);
+/// Settles password exchanges we answered, using the initiators'
+/// confirmations.
+///
+/// A contact whose confirmation verifies is confirmed. A confirmation that
+/// fails is ignored and recorded as a failed exchange: its sender is not
+/// authenticated, so it cannot be allowed to remove the contact. Contacts
+/// still unconfirmed after [`PASSWORD_CONFIRMATION_TIMEOUT_SECS`] are
+/// dropped, which also settles exchanges made with a different password.
+pub fn process_password_confirmations(
+    storage: &Storage,
+    messages: Vec<Inbound<(String, Vec<u8>)>>,
+    now: u64,
+    telemetry: &dyn Telemetry,
+) -> Result<(), MobileError> {
+    for Inbound {
+        message_id,
+        payload: (sender_id, data),
+    } in messages
+    {
+        handle_inbound(storage, &message_id, || {
+            let Some(expected) = storage.load_expected_confirmation(&sender_id)? else {
+                return Ok(Some(()));
+            };
+            let expected = PendingConfirmation::from_bytes(&expected)
+                .map_err(|e| MobileError::SerializationError(e.to_string()))?;
+
+            let confirmed = PasswordExchangeConfirmation::from_bytes(&data)
+                .and_then(|received| expected.verify(&received));
+            if confirmed.is_ok() {
+                storage.delete_expected_confirmation(&sender_id)?;
+            } else {
+                telemetry.record(TelemetryEvent::ExchangeFailed);
+            }
+            Ok(Some(()))
+        })?;
+    }
 
-        added += 1;
+    storage
+        .discard_expired_confirmations(now.saturating_sub(PASSWORD_CONFIRMATION_TIMEOUT_SECS))?;
+    Ok(())
+}
 
-        // Send encrypted exchange response
-        let _ = send_exchange_response(
-            identity,
-            &public_id,
-            &payload.exchange_key,
-            relay_url,
-            pinned_cert,
-        );
+/// Saves the contact from a decrypted exchange message and queues our
+/// response.
+///
+/// If the contact already exists the message is taken as a response to
+/// our own exchange. Returns whether a contact was added.
+fn accept_exchange(
+    identity: &Identity,
+    storage: &Storage,
+    payload: DecryptedExchangePayload,
+    shared_secret: SymmetricKey,
+) -> Result<bool, MobileError> {
+    let public_id = hex::encode(payload.identity_key);
+
+    // Check if contact already exists; reference contacts are replaced
+    if storage
+        .load_contact(&public_id)?
+        .is_some_and(|c| !c.is_reference())
+    {
+        // Contact exists - might be a response, update name if needed
+        update_contact_name_if_needed(storage, &public_id, &payload.display_name);
+        if payload.accepts_padding {
+            mark_accepts_padding(storage, &public_id);
+        }
+        if let Some(card) = payload.card {
+            fill_placeholder_card(storage, &public_id, card);
+        }
+        return Ok(false);
     }
 
-    Ok(added)
+    // Create new contact
+    let card = ContactCard::new(&payload.display_name);
+    let mut contact = match Contact::try_from_exchange(
+        payload.identity_key,
+        card,
+        shared_secret.clone(),
+        identity.signing_public_key(),
+    ) {
+        Ok(contact) => contact,
+        Err(_) => return Ok(false),
+    };
+    contact.set_accepts_padding(payload.accepts_padding);
+    let contact_id = contact.id().to_string();
+    storage.save_contact(&contact)?;
+
+    // Record for inter-device sync
+    let _ = record_contact_for_device_sync(identity, storage, &contact);
+
+    // Initialize ratchet as responder
+    let ratchet_dh = X3DHKeyPair::from_bytes(identity.x3dh_keypair().secret_bytes());
+    let ratchet = DoubleRatchetState::initialize_responder(&shared_secret, ratchet_dh);
+    storage.save_ratchet_state(&contact_id, &ratchet, false)?;
+
+    // Queue encrypted exchange response
+    let card = project_own_card(storage, &contact).ok().flatten();
+    queue_exchange_response(
+        identity,
+        storage,
+        &public_id,
+        &payload.exchange_key,
+        card.as_ref(),
+    )?;
+
+    Ok(true)
 }
 
-/// Sends encrypted exchange response with our identity and name.
-pub fn send_exchange_response(
+/// Queues an encrypted exchange response with our identity, name and
+/// shared card.
+///
+/// The response goes out with the pending exchanges once the contact is
+/// saved, and is retried like them if the relay does not take it.
+fn queue_exchange_response(
     identity: &Identity,
+    storage: &Storage,
     recipient_id: &str,
     recipient_exchange_key: &[u8; 32],
-    relay_url: &str,
-    pinned_cert: Option<&str>,
+    card: Option<&ContactCard>,
 ) -> Result<(), MobileError> {
-    let mut socket = cert_pinning::connect_with_pinning(relay_url, pinned_cert)
-        .map_err(MobileError::NetworkError)?;
-
-    let our_id = identity.public_id();
-    send_handshake(&mut socket, &our_id, None)?;
-
     // Create encrypted exchange message using X3DH
-    let our_x3dh = identity.x3dh_keypair();
-    let (encrypted_msg, _shared_secret) = EncryptedExchangeMessage::create(
-        &our_x3dh,
+    let (encrypted_msg, _shared_secret) = EncryptedExchangeMessage::create_with_card(
+        &identity.x3dh_keypair(),
         recipient_exchange_key,
-        identity.signing_public_key(),
+        identity.signing_keypair(),
         identity.display_name(),
+        card,
     )
     .map_err(|e| MobileError::CryptoError(format!("Failed to encrypt exchange: {:?}", e)))?;
 
-    let update = EncryptedUpdate {
-        recipient_id: recipient_id.to_string(),
-        sender_id: our_id,
-        ciphertext: encrypted_msg.to_bytes(),
-    };
-
-    let envelope = protocol::create_envelope(MessagePayload::EncryptedUpdate(update));
-    let data = protocol::encode_message(&envelope).map_err(MobileError::SyncFailed)?;
-    socket
-        .send(Message::Binary(data))
-        .map_err(|e| MobileError::NetworkError(e.to_string()))?;
-
-    std::thread::sleep(Duration::from_millis(100));
-    let _ = socket.close(None);
-
+    storage.save_pending_exchange(recipient_id, &encrypted_msg.to_bytes())?;
     Ok(())
 }
 
+/// Result of processing inbound card updates.
+pub struct ReceivedUpdates {
+    /// Number of updates that changed a contact's card.
+    pub applied: u32,
+    /// Updates that could not be applied.
+    pub failures: Vec<MobileUpdateFailure>,
+}
+
 /// Processes incoming card updates from existing contacts.
+///
+/// Updates that fail to decrypt are reported to `telemetry` and left
+/// unprocessed, so a later sync retries them. Updates that decrypt but
+/// cannot be read are returned as failures; those in a newer delta format
+/// are left unprocessed too. Re-key messages travel the
+/// same way and are handled in arrival order, so updates sent under the
+/// old key are decrypted before the switch.
 pub fn process_card_updates(
+    identity: &Identity,
     storage: &Storage,
-    updates: Vec<(String, Vec<u8>)>,
-) -> Result<u32, MobileError> {
+    updates: Vec<Inbound<(String, Vec<u8>)>>,
+    telemetry: &dyn Telemetry,
+) -> Result<ReceivedUpdates, MobileError> {
     let mut processed = 0u32;
+    let mut failures = Vec::new();
+
+    // Key rotations first: the contact's other updates in this batch are
+    // already sent under its new ID.
+    let mut remaining = Vec::with_capacity(updates.len());
+    for update in updates {
+        let (sender_id, ciphertext) = &update.payload;
+        match KeyRotationProof::from_bytes(ciphertext) {
+            Ok(proof) => {
+                handle_inbound(storage, &update.message_id, || {
+                    process_key_rotation(identity, storage, sender_id, &proof).map(Some)
+                })?;
+            }
+            Err(_) => remaining.push(update),
+        }
+    }
 
-    for (sender_id, ciphertext) in updates {
-        // Get contact
-        let mut contact = match storage.load_contact(&sender_id)? {
-            Some(c) => c,
-            None => continue,
-        };
+    for Inbound {
+        message_id,
+        payload: (sender_id, ciphertext),
+    } in remaining
+    {
+        let applied = handle_inbound(storage, &message_id, || {
+            apply_card_update(
+                identity,
+                storage,
+                &sender_id,
+                &ciphertext,
+                telemetry,
+                &mut failures,
+            )
+        })?;
+        if applied == Some(true) {
+            processed += 1;
+        }
+    }
+
+    Ok(ReceivedUpdates {
+        applied: processed,
+        failures,
+    })
+}
+
+/// Decrypts and applies one card update from `sender_id`.
+///
+/// Returns whether the contact's card changed, or `None` if the update
+/// could not be decrypted or read yet. Updates that cannot be read are
+/// added to `failures`.
+fn apply_card_update(
+    identity: &Identity,
+    storage: &Storage,
+    sender_id: &str,
+    ciphertext: &[u8],
+    telemetry: &dyn Telemetry,
+    failures: &mut Vec<MobileUpdateFailure>,
+) -> Result<Option<bool>, MobileError> {
+    // Get contact
+    let mut contact = match storage.load_contact(sender_id)? {
+        Some(c) => c,
+        None => return Ok(Some(false)),
+    };
 
-        // Get ratchet state
-        let (mut ratchet, _is_initiator) = match storage.load_ratchet_state(&sender_id)? {
-            Some(state) => state,
-            None => continue,
+    if let Ok(message) = RekeyMessage::from_bytes(ciphertext) {
+        process_rekey_message(identity, storage, &mut contact, message)?;
+        return Ok(Some(false));
+    }
+
+    // Get ratchet state
+    let (mut ratchet, _is_initiator) = match storage.load_ratchet_state(sender_id)? {
+        Some(state) => state,
+        None => return Ok(Some(false)),
+    };
+
+    // Try to parse as a RatchetMessage from JSON
+    let ratchet_msg: vauchi_core::crypto::ratchet::RatchetMessage =
+        match serde_json::from_slice(ciphertext) {
+            Ok(msg) => msg,
+            Err(_) => return Ok(Some(false)),
         };
 
-        // Try to parse as a RatchetMessage from JSON
-        let ratchet_msg: vauchi_core::crypto::ratchet::RatchetMessage =
-            match serde_json::from_slice(&ciphertext) {
-                Ok(msg) => msg,
-                Err(_) => continue,
+    // Decrypt the card delta
+    let plaintext = match ratchet.decrypt(&ratchet_msg) {
+        Ok(pt) => pt,
+        Err(_) => {
+            telemetry.record(TelemetryEvent::DecryptionFailed);
+            return Ok(None);
+        }
+    };
+
+    // Parse and apply delta (senders may pad updates to hide their size)
+    let mut applied = false;
+    match vauchi_core::crypto::padding::strip_padding(plaintext) {
+        Ok(plaintext) => match CardDelta::from_wire(&plaintext) {
+            Ok(delta) => {
+                let mut card = contact.card().clone();
+                if delta.apply(&mut card).is_ok() {
+                    contact.update_card(card);
+                    storage.save_contact(&contact)?;
+                    applied = true;
+                }
+            }
+            // Sent by a newer app: leave the ratchet and the message as
+            // they are, so the update applies once this app is upgraded
+            Err(e @ DeltaError::UnsupportedFormat { .. }) => {
+                failures.push(MobileUpdateFailure {
+                    contact_id: sender_id.to_string(),
+                    error: e.to_string(),
+                });
+                return Ok(None);
+            }
+            Err(_) => {}
+        },
+        Err(e) => failures.push(MobileUpdateFailure {
+            contact_id: sender_id.to_string(),
+            error: e.to_string(),
+        }),
+    }
+
+    // Save updated ratchet state
+    storage.save_ratchet_state(sender_id, &ratchet, false)?;
+
+    Ok(Some(applied))
+}
+
+/// Queues a signing key rotation proof to every contact we send updates to.
+///
+/// Goes out with the other queued updates, now sent under the new ID;
+/// contacts apply the proof before anything else from the same sync.
+/// Returns the number of contacts queued.
+pub fn queue_key_rotation(storage: &Storage, proof: &KeyRotationProof) -> Result<u32, MobileError> {
+    let created_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let mut queued = 0u32;
+    for contact in storage.list_contacts()? {
+        if !contact.should_send_updates() {
+            continue;
+        }
+        storage.queue_update(&PendingUpdate {
+            id: uuid::Uuid::new_v4().to_string(),
+            contact_id: contact.id().to_string(),
+            update_type: "key_rotation".to_string(),
+            payload: proof.to_bytes(),
+            created_at,
+            retry_count: 0,
+            status: UpdateStatus::Pending,
+        })?;
+        queued += 1;
+    }
+    Ok(queued)
+}
+
+/// Follows a contact's signing key rotation.
+///
+/// The proof must come from the new key and move a known contact; anything
+/// else is dropped. Blocked contacts are moved too, so the block follows
+/// the new key.
+fn process_key_rotation(
+    identity: &Identity,
+    storage: &Storage,
+    sender_id: &str,
+    proof: &KeyRotationProof,
+) -> Result<(), MobileError> {
+    if sender_id != hex::encode(proof.new_public_key())
+        || vauchi_core::contact::check_not_self(
+            proof.new_public_key(),
+            identity.signing_public_key(),
+        )
+        .is_err()
+    {
+        return Ok(());
+    }
+    let old_id = hex::encode(proof.old_public_key());
+    let Some(mut contact) = storage.load_contact(&old_id)? else {
+        return Ok(());
+    };
+    if contact.apply_key_rotation(proof).is_err() {
+        return Ok(());
+    }
+    storage.move_contact(&old_id, &contact)?;
+    Ok(())
+}
+
+/// Queues a re-key request to a contact, behind any updates already queued.
+///
+/// The contact answers on its next sync; the current key stays in use
+/// until the answer arrives.
+pub fn queue_rekey_request(
+    identity: &Identity,
+    storage: &Storage,
+    contact_id: &str,
+) -> Result<(), MobileError> {
+    let request = RekeyRequest::create(identity);
+    storage.save_pending_rekey(contact_id, request.timestamp())?;
+    queue_rekey_message(
+        storage,
+        contact_id,
+        "rekey_request",
+        &RekeyMessage::Request(request),
+    )
+}
+
+/// Handles a re-key request or response from a contact.
+///
+/// Invalid, stale or unexpected messages are dropped.
+fn process_rekey_message(
+    identity: &Identity,
+    storage: &Storage,
+    contact: &mut Contact,
+    message: RekeyMessage,
+) -> Result<(), MobileError> {
+    if contact.is_blocked() {
+        return Ok(());
+    }
+    let contact_id = contact.id().to_string();
+
+    match message {
+        RekeyMessage::Request(request) => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            if request.verify(contact.public_key(), now).is_err() {
+                return Ok(());
+            }
+
+            // Both sides asked at once: the lower identity key's request wins
+            if storage.load_pending_rekey(&contact_id)?.is_some() {
+                if identity.signing_public_key() < contact.public_key() {
+                    return Ok(());
+                }
+                storage.delete_pending_rekey(&contact_id)?;
+            }
+
+            let (response, shared_secret) = request
+                .accept(identity)
+                .map_err(|e| MobileError::CryptoError(format!("Re-key failed: {:?}", e)))?;
+            contact.rekey(shared_secret.clone());
+            storage.save_contact(contact)?;
+            let ratchet =
+                DoubleRatchetState::initialize_initiator(&shared_secret, *request.exchange_key());
+            storage.save_ratchet_state(&contact_id, &ratchet, true)?;
+
+            queue_rekey_message(
+                storage,
+                &contact_id,
+                "rekey_response",
+                &RekeyMessage::Response(response),
+            )
+        }
+        RekeyMessage::Response(response) => {
+            let Some(requested_at) = storage.load_pending_rekey(&contact_id)? else {
+                return Ok(());
+            };
+            let Ok(shared_secret) = response.complete(identity, contact.public_key(), requested_at)
+            else {
+                return Ok(());
             };
 
-        // Decrypt the card delta
-        let plaintext = match ratchet.decrypt(&ratchet_msg) {
-            Ok(pt) => pt,
-            Err(_) => continue,
-        };
+            contact.rekey(shared_secret.clone());
+            storage.save_contact(contact)?;
+            let ratchet =
+                DoubleRatchetState::initialize_responder(&shared_secret, identity.x3dh_keypair());
+            storage.save_ratchet_state(&contact_id, &ratchet, false)?;
+            storage.delete_pending_rekey(&contact_id)?;
+            Ok(())
+        }
+    }
+}
 
-        // Parse and apply delta
-        if let Ok(delta) = serde_json::from_slice::<vauchi_core::sync::CardDelta>(&plaintext) {
-            let mut card = contact.card().clone();
-            if delta.apply(&mut card).is_ok() {
-                contact.update_card(card);
-                storage.save_contact(&contact)?;
-                processed += 1;
+/// Queues a re-key message for a contact like any other update.
+fn queue_rekey_message(
+    storage: &Storage,
+    contact_id: &str,
+    update_type: &str,
+    message: &RekeyMessage,
+) -> Result<(), MobileError> {
+    let created_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    storage.queue_update(&PendingUpdate {
+        id: uuid::Uuid::new_v4().to_string(),
+        contact_id: contact_id.to_string(),
+        update_type: update_type.to_string(),
+        payload: message.to_bytes(),
+        created_at,
+        retry_count: 0,
+        status: UpdateStatus::Pending,
+    })?;
+    Ok(())
+}
+
+/// Queues an update carrying only one field of our card.
+///
+/// The delta replaces the field outright (a removal followed by its
+/// current value), so it applies whether or not the contact already has
+/// the field. It goes to `recipients` when given, otherwise to every
+/// contact; contacts that may not see the field, that we do not send
+/// updates to, or that have no usable ratchet yet are skipped.
+///
+/// Returns the number of contacts the update was queued for. The delta is
+/// stamped with `now`.
+pub fn queue_field_update(
+    identity: &Identity,
+    storage: &Storage,
+    field: &ContactField,
+    recipients: Option<&HashSet<String>>,
+    pad: bool,
+    now: u64,
+) -> Result<u32, MobileError> {
+    let changes = vec![
+        FieldChange::Removed {
+            field_id: field.id().to_string(),
+        },
+        FieldChange::Added {
+            field: field.clone(),
+        },
+    ];
+    let delta = CardDelta::from_changes(changes, 1, now);
+
+    queue_card_delta(identity, storage, &delta, recipients, pad)
+}
+
+/// Queues a signed card delta for every contact that receives updates.
+///
+/// Each contact gets the delta projected onto the fields visible to it,
+/// and contacts with nothing visible are skipped. Limited to `recipients`
+/// when given. With `pad`, deltas to contacts that advertised they read
+/// padded updates are padded to size buckets before encryption. Returns
+/// the number of contacts queued.
+pub fn queue_card_delta(
+    identity: &Identity,
+    storage: &Storage,
+    delta: &CardDelta,
+    recipients: Option<&HashSet<String>>,
+    pad: bool,
+) -> Result<u32, MobileError> {
+    let field_ids: HashSet<&str> = delta
+        .changes
+        .iter()
+        .filter_map(|change| match change {
+            FieldChange::Added { field } => Some(field.id()),
+            FieldChange::Modified { field_id, .. } | FieldChange::Removed { field_id } => {
+                Some(field_id.as_str())
             }
+            FieldChange::DisplayNameChanged { .. } => None,
+        })
+        .collect();
+
+    let mut queued = 0u32;
+    for contact in storage.list_contacts()? {
+        if !contact.should_send_updates()
+            || recipients.is_some_and(|ids| !ids.contains(contact.id()))
+        {
+            continue;
         }
 
-        // Save updated ratchet state
-        let _ = storage.save_ratchet_state(&sender_id, &ratchet, false);
+        let visibility = storage.load_field_visibility(&contact)?;
+        let visible: HashSet<String> = field_ids
+            .iter()
+            .filter(|id| visibility.can_see(id))
+            .map(|id| id.to_string())
+            .collect();
+        let mut delta = delta.project(&visible);
+        if delta.is_empty() {
+            continue;
+        }
+        let Some((mut ratchet, is_initiator)) = storage.load_ratchet_state(contact.id())? else {
+            continue;
+        };
+
+        delta.sign(identity);
+        let mut plaintext = delta.to_wire();
+        if pad && contact.accepts_padding() {
+            plaintext = vauchi_core::crypto::padding::pad(&plaintext);
+        }
+        let Ok(message) = ratchet.encrypt(&plaintext) else {
+            continue;
+        };
+        let payload = serde_json::to_vec(&message)
+            .map_err(|e| MobileError::SerializationError(e.to_string()))?;
+        storage.save_ratchet_state(contact.id(), &ratchet, is_initiator)?;
+
+        storage.queue_update(&PendingUpdate {
+            id: uuid::Uuid::new_v4().to_string(),
+            contact_id: contact.id().to_string(),
+            update_type: "card_delta".to_string(),
+            payload,
+            created_at: delta.timestamp,
+            retry_count: 0,
+            status: UpdateStatus::Pending,
+        })?;
+        queued += 1;
     }
 
-    Ok(processed)
+    Ok(queued)
+}
+
+/// Result of sending pending outbound updates.
+pub struct SentUpdates {
+    /// Number of updates sent.
+    pub sent: u32,
+    /// Outcome for each contact that had pending updates.
+    pub outcomes: Vec<MobileContactSyncOutcome>,
 }
 
 /// Sends pending outbound updates to contacts.
 pub fn send_pending_updates(
     identity: &Identity,
     storage: &Storage,
-    socket: &mut WebSocket<MaybeTlsStream<TcpStream>>,
-) -> Result<u32, MobileError> {
+    transport: &mut dyn RelayTransport,
+) -> Result<SentUpdates, MobileError> {
+    send_pending_updates_with(identity, storage, |data| {
+        transport.send(data).map_err(|e| e.to_string())
+    })
+}
+
+/// Sends pending outbound updates using the given send function.
+///
+/// Stops sending to a contact at its first failure so that its updates
+/// are never delivered out of order; the remaining ones stay queued.
+pub fn send_pending_updates_with<F>(
+    identity: &Identity,
+    storage: &Storage,
+    mut send: F,
+) -> Result<SentUpdates, MobileError>
+where
+    F: FnMut(Vec<u8>) -> Result<(), String>,
+{
     let contacts = storage.list_contacts()?;
     let our_id = identity.public_id();
     let mut sent = 0u32;
+    let mut outcomes = Vec::new();
 
     for contact in contacts {
+        // Re-checked at send time: anything queued for a blocked contact
+        // stays frozen in the queue.
+        if !contact.should_send_updates() {
+            continue;
+        }
+
         let pending = storage.get_pending_updates(contact.id())?;
+        if pending.is_empty() {
+            continue;
+        }
 
+        let mut error = None;
         for update in pending {
             let msg = EncryptedUpdate {
                 recipient_id: contact.id().to_string(),
@@ -404,13 +1157,100 @@ pub fn send_pending_updates(
             };
 
             let envelope = protocol::create_envelope(MessagePayload::EncryptedUpdate(msg));
-            if let Ok(data) = protocol::encode_message(&envelope) {
-                if socket.send(Message::Binary(data)).is_ok() {
+            let result = protocol::encode_message(&envelope)
+                .map_err(|e| format!("Encode error: {}", e))
+                .and_then(&mut send);
+            match result {
+                Ok(()) => {
                     let _ = storage.delete_pending_update(&update.id);
+                    let _ = storage.create_delivery_record(&DeliveryRecord {
+                        message_id: envelope.message_id,
+                        recipient_id: contact.id().to_string(),
+                        status: DeliveryStatus::Sent,
+                        created_at: envelope.timestamp,
+                        updated_at: envelope.timestamp,
+                        expires_at: None,
+                    });
                     sent += 1;
                 }
+                Err(e) => {
+                    error = Some(e);
+                    break;
+                }
             }
         }
+
+        outcomes.push(MobileContactSyncOutcome {
+            contact_id: contact.id().to_string(),
+            success: error.is_none(),
+            error,
+        });
+    }
+
+    Ok(SentUpdates { sent, outcomes })
+}
+
+/// Sends a stored exchange message over an open relay connection.
+///
+/// The message counts as delivered once the connection accepted it, and
+/// gets a delivery record like any other update. Callers count the
+/// attempt first, so that failures show up even if the app is killed
+/// mid-send.
+pub fn send_pending_exchange(
+    storage: &Storage,
+    our_id: &str,
+    pending: &PendingExchange,
+    transport: &mut dyn RelayTransport,
+) -> Result<(), MobileError> {
+    let update = EncryptedUpdate {
+        recipient_id: pending.contact_id.clone(),
+        sender_id: our_id.to_string(),
+        ciphertext: pending.message.clone(),
+    };
+    let envelope = protocol::create_envelope(MessagePayload::EncryptedUpdate(update));
+    let data = protocol::encode_message(&envelope).map_err(MobileError::SyncFailed)?;
+    transport.send(data)?;
+
+    storage.mark_exchange_delivered(&pending.contact_id)?;
+    let _ = storage.create_delivery_record(&DeliveryRecord {
+        message_id: envelope.message_id,
+        recipient_id: pending.contact_id.clone(),
+        status: DeliveryStatus::Sent,
+        created_at: envelope.timestamp,
+        updated_at: envelope.timestamp,
+        expires_at: None,
+    });
+    Ok(())
+}
+
+/// Retries exchange messages that did not reach the relay.
+///
+/// Exchanges already tried `max_attempts` times are left for
+/// `resume_pending_exchanges`; those with contacts deleted since are
+/// dropped. Stops at the first failure. Returns the number delivered.
+pub fn send_pending_exchanges(
+    identity: &Identity,
+    storage: &Storage,
+    transport: &mut dyn RelayTransport,
+    max_attempts: u32,
+) -> Result<u32, MobileError> {
+    let our_id = identity.public_id();
+    let mut sent = 0;
+
+    for pending in storage.list_undelivered_exchanges()? {
+        if storage.load_contact(&pending.contact_id)?.is_none() {
+            storage.delete_pending_exchange(&pending.contact_id)?;
+            continue;
+        }
+        if pending.attempts >= max_attempts {
+            continue;
+        }
+
+        storage.record_exchange_attempt(&pending.contact_id)?;
+        if send_pending_exchange(storage, &our_id, &pending, transport).is_err() {
+            break;
+        }
+        sent += 1;
     }
 
     Ok(sent)
@@ -420,16 +1260,21 @@ pub fn send_pending_updates(
 pub fn process_device_sync_messages(
     identity: &Identity,
     storage: &Storage,
-    messages: Vec<DeviceSyncMessage>,
+    messages: Vec<Inbound<DeviceSyncMessage>>,
 ) -> Result<u32, MobileError> {
     if messages.is_empty() {
         return Ok(0);
     }
 
-    // Try to load device registry - if none exists, skip
+    // Try to load device registry - if none exists, drop the messages
     let registry = match storage.load_device_registry()? {
         Some(r) if r.device_count() > 1 => r,
-        _ => return Ok(0),
+        _ => {
+            for msg in &messages {
+                storage.mark_message_processed(&msg.message_id)?;
+            }
+            return Ok(0);
+        }
     };
 
     let mut orchestrator =
@@ -437,49 +1282,56 @@ pub fn process_device_sync_messages(
 
     let mut processed = 0u32;
 
-    for msg in messages {
-        // Parse sender device ID
-        let sender_device_id: [u8; 32] = match hex::decode(&msg.sender_device_id) {
-            Ok(bytes) if bytes.len() == 32 => {
-                let mut arr = [0u8; 32];
-                arr.copy_from_slice(&bytes);
-                arr
-            }
-            _ => continue,
-        };
+    for Inbound {
+        message_id,
+        payload: msg,
+    } in messages
+    {
+        let applied = handle_inbound(storage, &message_id, || {
+            // Parse sender device ID
+            let sender_device_id: [u8; 32] = match hex::decode(&msg.sender_device_id) {
+                Ok(bytes) if bytes.len() == 32 => {
+                    let mut arr = [0u8; 32];
+                    arr.copy_from_slice(&bytes);
+                    arr
+                }
+                _ => return Ok(Some(false)),
+            };
 
-        // Find sender in registry
-        let sender_device = match registry.find_device(&sender_device_id) {
-            Some(d) => d,
-            None => continue,
-        };
+            // Find sender in registry
+            let sender_device = match registry.find_device(&sender_device_id) {
+                Some(d) => d,
+                None => return Ok(Some(false)),
+            };
 
-        // Decrypt payload
-        let plaintext = match orchestrator
-            .decrypt_from_device(&sender_device.exchange_public_key, &msg.encrypted_payload)
-        {
-            Ok(pt) => pt,
-            Err(_) => continue,
-        };
+            // Decrypt payload
+            let plaintext = match orchestrator
+                .decrypt_from_device(&sender_device.exchange_public_key, &msg.encrypted_payload)
+            {
+                Ok(pt) => pt,
+                Err(_) => return Ok(Some(false)),
+            };
 
-        // Parse SyncItems
-        let items: Vec<SyncItem> = match serde_json::from_slice(&plaintext) {
-            Ok(items) => items,
-            Err(_) => continue,
-        };
+            // Parse SyncItems
+            let items: Vec<SyncItem> = match serde_json::from_slice(&plaintext) {
+                Ok(items) => items,
+                Err(_) => return Ok(Some(false)),
+            };
 
-        // Process items with conflict resolution
-        let applied = match orchestrator.process_incoming(items) {
-            Ok(applied) => applied,
-            Err(_) => continue,
-        };
+            // Process items with conflict resolution
+            let applied = match orchestrator.process_incoming(items) {
+                Ok(applied) => applied,
+                Err(_) => return Ok(Some(false)),
+            };
 
-        // Apply the items
-        for item in &applied {
-            let _ = apply_sync_item(storage, item);
-        }
+            // Apply the items
+            for item in &applied {
+                let _ = apply_sync_item(identity, storage, item);
+            }
 
-        if !applied.is_empty() {
+            Ok(Some(!applied.is_empty()))
+        })?;
+        if applied == Some(true) {
             processed += 1;
         }
     }
@@ -488,9 +1340,17 @@ pub fn process_device_sync_messages(
 }
 
 /// Applies a single sync item to local storage.
-fn apply_sync_item(storage: &Storage, item: &SyncItem) -> Result<(), MobileError> {
+fn apply_sync_item(
+    identity: &Identity,
+    storage: &Storage,
+    item: &SyncItem,
+) -> Result<(), MobileError> {
     match item {
         SyncItem::ContactAdded { contact_data, .. } => {
+            vauchi_core::contact::check_not_self(
+                &contact_data.public_key,
+                identity.signing_public_key(),
+            )?;
             if let Ok(contact) = contact_data.to_contact() {
                 storage.save_contact(&contact)?;
             }
@@ -535,7 +1395,7 @@ fn apply_sync_item(storage: &Storage, item: &SyncItem) -> Result<(), MobileError
 pub fn send_device_sync(
     identity: &Identity,
     storage: &Storage,
-    socket: &mut WebSocket<MaybeTlsStream<TcpStream>>,
+    transport: &mut dyn RelayTransport,
 ) -> Result<u32, MobileError> {
     // Try to load device registry - if none exists, skip
     let registry = match storage.load_device_registry()? {
@@ -592,7 +1452,7 @@ pub fn send_device_sync(
         );
 
         if let Ok(data) = protocol::encode_message(&envelope) {
-            if socket.send(Message::Binary(data)).is_ok() {
+            if transport.send(data).is_ok() {
                 sent += 1;
             }
         }
@@ -602,76 +1462,306 @@ pub fn send_device_sync(
 }
 
 /// Performs a complete sync operation.
+///
+/// Resumes from the sync cursor stored for `relay_url`, and advances it
+/// over the received messages that were handled. Messages are marked
+/// processed in the same transaction that applies them; anything that
+/// fails stays unacknowledged and is retried on a later sync. An answer to the
+/// password exchange offer in `password_exchange` consumes it. Exchange
+/// responses queued while handling messages are sent after they commit,
+/// and those that did not reach the relay earlier are retried, up to
+/// `exchange_retry_limit` attempts each. A `budget` limits how many
+/// inbound messages this sync processes; the rest wait for the next one.
+/// Delivery receipts are kept only if they verify against the pinned
+/// `relay_receipt_key`. `now` (Unix seconds) decides which processed
+/// message IDs and unconfirmed password exchanges have expired.
+#[allow(clippy::too_many_arguments)]
 pub fn do_sync(
     identity: &Identity,
     storage: &Storage,
+    connector: &dyn RelayConnector,
     relay_url: &str,
-    pinned_cert: Option<&str>,
+    password_exchange: &mut Option<PasswordKey>,
+    exchange_retry_limit: u32,
+    budget: Option<SyncBudget>,
+    relay_receipt_key: Option<&PublicKey>,
+    now: u64,
+    telemetry: &dyn Telemetry,
 ) -> Result<MobileSyncResult, MobileError> {
     let client_id = identity.public_id();
     let device_id_hex = hex::encode(identity.device_id());
+    let cursor = storage.load_sync_cursor(relay_url)?;
 
     // Connect to relay
-    let mut socket = cert_pinning::connect_with_pinning(relay_url, pinned_cert)
-        .map_err(MobileError::NetworkError)?;
-
-    // Set read timeout for non-blocking receive
-    if let MaybeTlsStream::Plain(ref stream) = socket.get_ref() {
-        let _ = stream.set_read_timeout(Some(Duration::from_millis(1000)));
-    }
+    let mut transport = connector.connect()?;
 
     // Send handshake with device_id for inter-device sync
-    send_handshake(&mut socket, &client_id, Some(&device_id_hex))?;
+    send_handshake(
+        transport.as_mut(),
+        &client_id,
+        Some(&device_id_hex),
+        cursor.as_deref(),
+    )?;
 
     // Wait briefly for server to send pending messages
-    std::thread::sleep(Duration::from_millis(500));
+    let wait = Duration::from_millis(500);
+    std::thread::sleep(budget.map_or(wait, |budget| wait.min(budget.remaining())));
 
     // Receive and classify pending messages
-    let received = receive_pending(&mut socket)?;
-
-    // Process legacy plaintext exchange messages
-    let legacy_added = process_legacy_exchange_messages(
-        identity,
+    let received = receive_pending(
+        transport.as_mut(),
         storage,
-        received.legacy_exchange,
-        relay_url,
-        pinned_cert,
+        budget.as_ref(),
+        relay_receipt_key,
     )?;
+    let arrivals = received.arrivals;
+    let more_pending = received.more_pending;
+
+    // Forget processed message IDs the relay can no longer redeliver
+    let _ = storage.prune_processed_messages(now.saturating_sub(PROCESSED_MESSAGE_RETENTION_SECS));
+
+    // Process legacy plaintext exchange messages
+    let legacy_added =
+        process_legacy_exchange_messages(identity, storage, received.legacy_exchange)?;
 
     // Process encrypted exchange messages
-    let encrypted_added = process_encrypted_exchange_messages(
+    let encrypted_added =
+        process_encrypted_exchange_messages(identity, storage, received.encrypted_exchange)?;
+
+    // Process answers to our password exchange offer
+    let password_added = process_password_exchange_messages(
         identity,
         storage,
-        received.encrypted_exchange,
-        relay_url,
-        pinned_cert,
+        received.password_exchange,
+        password_exchange,
+        transport.as_mut(),
     )?;
 
-    let contacts_added = legacy_added + encrypted_added;
+    let contacts_added = legacy_added + encrypted_added + password_added;
+
+    // Settle password exchanges we answered
+    process_password_confirmations(storage, received.password_confirmations, now, telemetry)?;
 
     // Process card updates
-    let cards_updated = process_card_updates(storage, received.card_updates)?;
+    let card_updates = process_card_updates(identity, storage, received.card_updates, telemetry)?;
 
     // Process device sync messages (inter-device synchronization)
     let device_synced =
         process_device_sync_messages(identity, storage, received.device_sync_messages)?;
 
+    // Acknowledge what was handled; the cursor stops before anything left
+    // for retry, so the relay delivers it again
+    if let Some(message_id) = acknowledge_handled(storage, transport.as_mut(), &arrivals)? {
+        storage.save_sync_cursor(relay_url, &message_id)?;
+    }
+
+    // Retry exchange messages first; contacts cannot read updates without them
+    let exchanges_sent =
+        send_pending_exchanges(identity, storage, transport.as_mut(), exchange_retry_limit)?;
+
     // Send pending device sync items to other devices
-    let device_sync_sent = send_device_sync(identity, storage, &mut socket)?;
+    let device_sync_sent = send_device_sync(identity, storage, transport.as_mut())?;
 
     // Send pending outbound updates
-    let updates_sent = send_pending_updates(identity, storage, &mut socket)?;
+    let sent_updates = send_pending_updates(identity, storage, transport.as_mut())?;
 
     // Close connection
-    let _ = socket.close(None);
+    transport.close();
 
     Ok(MobileSyncResult {
         contacts_added,
-        cards_updated: cards_updated + device_synced,
-        updates_sent: updates_sent + device_sync_sent,
+        cards_updated: card_updates.applied + device_synced,
+        updates_sent: exchanges_sent + sent_updates.sent + device_sync_sent,
+        contact_outcomes: sent_updates.outcomes,
+        update_failures: card_updates.failures,
+        has_more: more_pending,
     })
 }
 
+/// Publishes a recovery proof to the relay's recovery storage.
+///
+/// The relay keys the proof by the lost identity's public key and keeps it
+/// until the proof expires.
+pub fn publish_recovery_proof(
+    client_id: &str,
+    proof: &RecoveryProof,
+    connector: &dyn RelayConnector,
+) -> Result<(), MobileError> {
+    let envelope = create_recovery_proof_store(
+        &hex::encode(proof.old_pk()),
+        proof.to_bytes(),
+        proof.expires_at(),
+    );
+    let data = protocol::encode_message(&envelope)
+        .map_err(|e| MobileError::SyncFailed(format!("Encode error: {}", e)))?;
+
+    let mut transport = connector.connect()?;
+    send_handshake(transport.as_mut(), client_id, None, None)?;
+    let result = transport.send(data);
+    transport.close();
+    result
+}
+
+/// Fetches the recovery proofs stored on the relay for a lost identity.
+///
+/// Proofs that are expired at `now` (beyond `skew_tolerance_secs`),
+/// malformed, or for a different identity are dropped. Other frames the
+/// relay pushes are left unacknowledged so they are redelivered on the
+/// next sync.
+pub fn fetch_recovery_proofs(
+    client_id: &str,
+    old_pk_hex: &str,
+    connector: &dyn RelayConnector,
+    now: u64,
+    skew_tolerance_secs: u64,
+) -> Result<Vec<RecoveryProof>, MobileError> {
+    let envelope = create_recovery_proof_query(old_pk_hex);
+    let data = protocol::encode_message(&envelope)
+        .map_err(|e| MobileError::SyncFailed(format!("Encode error: {}", e)))?;
+
+    let mut transport = connector.connect()?;
+    send_handshake(transport.as_mut(), client_id, None, None)?;
+    transport.send(data)?;
+
+    let mut stored = Vec::new();
+    while let Some(frame) = transport.receive()? {
+        let Ok(envelope) = protocol::decode_message(&frame) else {
+            continue;
+        };
+        if let MessagePayload::RecoveryProofResponse(response) = envelope.payload {
+            if response.old_pk == old_pk_hex {
+                stored = response.proofs;
+                break;
+            }
+        }
+    }
+    transport.close();
+
+    Ok(stored
+        .iter()
+        .filter_map(|bytes| RecoveryProof::from_bytes(bytes).ok())
+        .filter(|proof| {
+            hex::encode(proof.old_pk()) == old_pk_hex
+                && !proof.is_expired_at(now, skew_tolerance_secs)
+        })
+        .collect())
+}
+
+/// Publishes a signed card to the relay, to serve at its handle's permalink.
+///
+/// The relay checks the signature and that the handle is not someone
+/// else's; it does not answer either way.
+pub fn publish_public_card(
+    client_id: &str,
+    card: &PublicCard,
+    connector: &dyn RelayConnector,
+) -> Result<(), MobileError> {
+    send_single(
+        client_id,
+        create_public_card_publish(card.to_json()),
+        connector,
+    )
+}
+
+/// Asks the relay to stop serving the card at a handle's permalink.
+pub fn unpublish_public_card(
+    client_id: &str,
+    removal: &PublicCardRemoval,
+    connector: &dyn RelayConnector,
+) -> Result<(), MobileError> {
+    send_single(
+        client_id,
+        create_public_card_unpublish(removal.to_json()),
+        connector,
+    )
+}
+
+/// Connects, sends one envelope that expects no answer, and disconnects.
+fn send_single(
+    client_id: &str,
+    envelope: SimpleEnvelope,
+    connector: &dyn RelayConnector,
+) -> Result<(), MobileError> {
+    let data = protocol::encode_message(&envelope)
+        .map_err(|e| MobileError::SyncFailed(format!("Encode error: {}", e)))?;
+
+    let mut transport = connector.connect()?;
+    send_handshake(transport.as_mut(), client_id, None, None)?;
+    let result = transport.send(data);
+    transport.close();
+    result
+}
+
+/// Asks the relay what became of sent messages still awaiting
+/// confirmation, without running a full sync.
+///
+/// Delivery records only move forward: `Sent` to `Stored` or `Delivered`,
+/// and `Stored` to `Delivered`. Messages the relay doesn't know are left
+/// as they are. Other frames the relay pushes are left unacknowledged so
+/// they are redelivered on the next sync.
+///
+/// Returns the number of records that advanced.
+pub fn poll_delivery_status(
+    client_id: &str,
+    storage: &Storage,
+    connector: &dyn RelayConnector,
+    now: u64,
+) -> Result<u32, MobileError> {
+    let awaiting: Vec<DeliveryRecord> = storage
+        .get_pending_deliveries()?
+        .into_iter()
+        .filter(|record| matches!(record.status, DeliveryStatus::Sent | DeliveryStatus::Stored))
+        .collect();
+    if awaiting.is_empty() {
+        return Ok(0);
+    }
+
+    let envelope = create_delivery_status_query(
+        awaiting
+            .iter()
+            .map(|record| record.message_id.clone())
+            .collect(),
+    );
+    let data = protocol::encode_message(&envelope)
+        .map_err(|e| MobileError::SyncFailed(format!("Encode error: {}", e)))?;
+
+    let mut transport = connector.connect()?;
+    send_handshake(transport.as_mut(), client_id, None, None)?;
+    transport.send(data)?;
+
+    let mut statuses = Vec::new();
+    while let Some(frame) = transport.receive()? {
+        let Ok(envelope) = protocol::decode_message(&frame) else {
+            continue;
+        };
+        if let MessagePayload::DeliveryStatusResponse(response) = envelope.payload {
+            statuses = response.statuses;
+            break;
+        }
+    }
+    transport.close();
+
+    let mut advanced = 0u32;
+    for status in statuses {
+        let Some(record) = awaiting
+            .iter()
+            .find(|record| record.message_id == status.message_id)
+        else {
+            continue;
+        };
+        let next = match (&record.status, status.state) {
+            (DeliveryStatus::Sent, MessageState::Stored) => DeliveryStatus::Stored,
+            (_, MessageState::Delivered) => DeliveryStatus::Delivered,
+            _ => continue,
+        };
+        if storage.update_delivery_status(&record.message_id, &next, now)? {
+            advanced += 1;
+        }
+    }
+
+    Ok(advanced)
+}
+
 // === Helper Functions ===
 
 /// Parse a hex-encoded 32-byte key.
@@ -694,6 +1784,48 @@ fn update_contact_name_if_needed(storage: &Storage, contact_id: &str, new_name:
     }
 }
 
+/// Records that a contact reads padded updates, as its response advertised.
+fn mark_accepts_padding(storage: &Storage, contact_id: &str) {
+    if let Ok(Some(mut contact)) = storage.load_contact(contact_id) {
+        if !contact.accepts_padding() {
+            contact.set_accepts_padding(true);
+            let _ = storage.save_contact(&contact);
+        }
+    }
+}
+
+/// Returns our own card with only the fields visible to `contact`.
+fn project_own_card(
+    storage: &Storage,
+    contact: &Contact,
+) -> Result<Option<ContactCard>, MobileError> {
+    let Some(mut card) = storage.load_own_card()? else {
+        return Ok(None);
+    };
+
+    let visibility = storage.load_field_visibility(contact)?;
+    card.fields_mut()
+        .retain(|field| visibility.can_see(field.id()));
+
+    Ok(Some(card))
+}
+
+/// Replaces a contact's placeholder card with the card from their response.
+///
+/// Cards that already have fields are left alone so a late or redelivered
+/// response cannot roll back newer card updates.
+fn fill_placeholder_card(storage: &Storage, contact_id: &str, card: ContactCard) {
+    if card.validate_size().is_err() {
+        return;
+    }
+    if let Ok(Some(mut contact)) = storage.load_contact(contact_id) {
+        if contact.card().fields().is_empty() {
+            contact.update_card(card);
+            let _ = storage.save_contact(&contact);
+        }
+    }
+}
+
 /// Records a contact addition for inter-device sync.
 fn record_contact_for_device_sync(
     identity: &Identity,
@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Sync Health
+//!
+//! Sums up the outbound queue, the retry queue, failed deliveries, the
+//! last successful sync and the cached relay reachability into a single
+//! status, so apps can show at a glance whether updates are flowing.
+
+use crate::relay_health::{MobileRelayState, MobileRelayStatus};
+
+/// How long pending work may wait for a successful sync before sync is
+/// considered stalled.
+pub(crate) const STALLED_AFTER_SECS: u64 = 24 * 60 * 60;
+
+/// Overall state of sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum MobileSyncHealthStatus {
+    /// Nothing is waiting or failing.
+    Healthy,
+    /// Updates still get through, but some need attention.
+    Degraded,
+    /// Updates are waiting and no sync has succeeded for a long time.
+    Stalled,
+}
+
+/// Why sync is not healthy.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum MobileSyncHealthReason {
+    /// Failed sends are due to be retried.
+    RetriesDue { count: u32 },
+    /// Deliveries failed for good.
+    DeliveriesFailed { count: u32 },
+    /// The last relay health check could not reach the relay.
+    RelayUnreachable,
+    /// Updates are waiting but no sync succeeded in `STALLED_AFTER_SECS`.
+    SyncOverdue {
+        /// Seconds since the last successful sync, if there ever was one.
+        since_secs: Option<u64>,
+    },
+}
+
+/// One-glance summary of whether updates are flowing.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileSyncHealth {
+    pub status: MobileSyncHealthStatus,
+    /// What makes the status other than healthy; empty when healthy.
+    pub reasons: Vec<MobileSyncHealthReason>,
+    /// Outbound updates waiting to be sent.
+    pub pending_updates: u32,
+    /// Retries whose time has come.
+    pub due_retries: u32,
+    /// Deliveries that failed for good.
+    pub failed_deliveries: u32,
+    /// Unix timestamp of the last successful sync, if any.
+    pub last_sync: Option<u64>,
+    /// Cached relay reachability; the relay is not contacted.
+    pub relay: MobileRelayStatus,
+}
+
+/// Derives the status and its reasons from the counts.
+pub(crate) fn assess(
+    pending_updates: u32,
+    due_retries: u32,
+    failed_deliveries: u32,
+    last_sync: Option<u64>,
+    relay: MobileRelayStatus,
+    now: u64,
+) -> MobileSyncHealth {
+    let mut reasons = Vec::new();
+    if due_retries > 0 {
+        reasons.push(MobileSyncHealthReason::RetriesDue { count: due_retries });
+    }
+    if failed_deliveries > 0 {
+        reasons.push(MobileSyncHealthReason::DeliveriesFailed {
+            count: failed_deliveries,
+        });
+    }
+    if relay.state == MobileRelayState::Unreachable {
+        reasons.push(MobileSyncHealthReason::RelayUnreachable);
+    }
+
+    let since_secs = last_sync.map(|last| now.saturating_sub(last));
+    let has_work = pending_updates > 0 || due_retries > 0;
+    let overdue = since_secs.is_none_or(|since| since >= STALLED_AFTER_SECS);
+    let stalled = has_work && overdue;
+    if stalled {
+        reasons.push(MobileSyncHealthReason::SyncOverdue { since_secs });
+    }
+
+    let status = if stalled {
+        MobileSyncHealthStatus::Stalled
+    } else if reasons.is_empty() {
+        MobileSyncHealthStatus::Healthy
+    } else {
+        MobileSyncHealthStatus::Degraded
+    };
+
+    MobileSyncHealth {
+        status,
+        reasons,
+        pending_updates,
+        due_retries,
+        failed_deliveries,
+        last_sync,
+        relay,
+    }
+}
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Mobile Telemetry
+//!
+//! Forwards core's privacy-preserving telemetry events to a platform
+//! callback. Events carry counts and timings only, never personal data.
+
+use std::sync::Arc;
+
+use vauchi_core::api::{Telemetry, TelemetryEvent};
+
+/// A non-identifying telemetry event.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum MobileTelemetryEvent {
+    /// A sync cycle finished.
+    SyncCompleted {
+        duration_ms: u64,
+        messages_sent: u32,
+        messages_received: u32,
+    },
+    /// A sync cycle failed.
+    SyncFailed { duration_ms: u64 },
+    /// A contact exchange completed.
+    ExchangeSucceeded,
+    /// A contact exchange failed.
+    ExchangeFailed,
+    /// An incoming message could not be decrypted.
+    DecryptionFailed,
+}
+
+impl From<TelemetryEvent> for MobileTelemetryEvent {
+    fn from(event: TelemetryEvent) -> Self {
+        match event {
+            TelemetryEvent::SyncCompleted {
+                duration_ms,
+                messages_sent,
+                messages_received,
+            } => MobileTelemetryEvent::SyncCompleted {
+                duration_ms,
+                messages_sent,
+                messages_received,
+            },
+            TelemetryEvent::SyncFailed { duration_ms } => {
+                MobileTelemetryEvent::SyncFailed { duration_ms }
+            }
+            TelemetryEvent::ExchangeSucceeded => MobileTelemetryEvent::ExchangeSucceeded,
+            TelemetryEvent::ExchangeFailed => MobileTelemetryEvent::ExchangeFailed,
+            TelemetryEvent::DecryptionFailed => MobileTelemetryEvent::DecryptionFailed,
+        }
+    }
+}
+
+/// Callback interface for receiving telemetry events.
+///
+/// Implement this in Swift (iOS) or Kotlin (Android) to feed the app's
+/// own metrics pipeline. Called inline, so implementations must not block.
+#[uniffi::export(callback_interface)]
+pub trait MobileTelemetryHandler: Send + Sync {
+    /// Called for every telemetry event.
+    fn on_event(&self, event: MobileTelemetryEvent);
+}
+
+/// Core telemetry that forwards events to a platform handler.
+pub(crate) struct PlatformTelemetry {
+    handler: Arc<dyn MobileTelemetryHandler>,
+}
+
+impl PlatformTelemetry {
+    pub(crate) fn new(handler: Arc<dyn MobileTelemetryHandler>) -> Self {
+        PlatformTelemetry { handler }
+    }
+}
+
+impl Telemetry for PlatformTelemetry {
+    fn record(&self, event: TelemetryEvent) {
+        self.handler.on_event(event.into());
+    }
+}
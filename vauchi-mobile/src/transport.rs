@@ -0,0 +1,497 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Relay transport abstraction.
+//!
+//! Mirrors `vauchi_core::network::Transport`, but carries raw frames of the
+//! simple relay protocol used by mobile clients instead of full
+//! `MessageEnvelope`s. Production code uses WebSocket; tests inject an
+//! in-memory relay so sync can run without a live server.
+
+use std::net::TcpStream;
+use std::time::Duration;
+
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{Message, WebSocket};
+
+use crate::cert_pinning;
+use crate::error::MobileError;
+
+/// A connection to the relay that exchanges binary frames.
+pub trait RelayTransport: Send {
+    /// Sends a binary frame to the relay.
+    fn send(&mut self, data: Vec<u8>) -> Result<(), MobileError>;
+
+    /// Receives the next binary frame.
+    ///
+    /// Returns `Ok(None)` once no more frames are pending.
+    fn receive(&mut self) -> Result<Option<Vec<u8>>, MobileError>;
+
+    /// Closes the connection. Safe to call more than once.
+    fn close(&mut self);
+}
+
+/// Opens new relay connections.
+pub trait RelayConnector: Send + Sync {
+    /// Connects to the relay.
+    fn connect(&self) -> Result<Box<dyn RelayTransport>, MobileError>;
+}
+
+/// WebSocket connection to a relay server.
+pub struct WebSocketRelayTransport {
+    socket: WebSocket<MaybeTlsStream<TcpStream>>,
+}
+
+impl RelayTransport for WebSocketRelayTransport {
+    fn send(&mut self, data: Vec<u8>) -> Result<(), MobileError> {
+        self.socket
+            .send(Message::Binary(data))
+            .map_err(|e| MobileError::NetworkError(e.to_string()))
+    }
+
+    fn receive(&mut self) -> Result<Option<Vec<u8>>, MobileError> {
+        loop {
+            match self.socket.read() {
+                Ok(Message::Binary(data)) => return Ok(Some(data)),
+                Ok(Message::Ping(data)) => {
+                    let _ = self.socket.send(Message::Pong(data));
+                }
+                Ok(Message::Close(_)) => return Ok(None),
+                Ok(_) => { /* Ignore other message types */ }
+                // Read timeout (no more messages) or connection error
+                Err(_) => return Ok(None),
+            }
+        }
+    }
+
+    fn close(&mut self) {
+        let _ = self.socket.close(None);
+    }
+}
+
+/// Checks that a relay URL is allowed under the TLS policy.
+///
+/// With `require_tls`, only `wss://` URLs and plaintext `ws://` to a
+/// loopback address (local development relays) are accepted.
+pub fn check_transport_security(relay_url: &str, require_tls: bool) -> Result<(), MobileError> {
+    if !require_tls {
+        return Ok(());
+    }
+
+    let url = url::Url::parse(relay_url)
+        .map_err(|e| MobileError::InvalidInput(format!("Invalid relay URL: {}", e)))?;
+    if url.scheme() == "wss" {
+        return Ok(());
+    }
+
+    let is_loopback = match url.host() {
+        Some(url::Host::Domain(domain)) => domain == "localhost",
+        Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    };
+    if url.scheme() == "ws" && is_loopback {
+        return Ok(());
+    }
+
+    Err(MobileError::InsecureTransport(format!(
+        "{} is not a wss:// URL",
+        relay_url
+    )))
+}
+
+/// Connects to a relay over WebSocket, with optional certificate pinning.
+pub struct WebSocketConnector {
+    relay_url: String,
+    pinned_cert: Option<String>,
+    require_tls: bool,
+}
+
+impl WebSocketConnector {
+    /// Creates a connector for the given relay URL.
+    ///
+    /// With `require_tls`, connecting to a remote `ws://` relay fails with
+    /// `MobileError::InsecureTransport`.
+    pub fn new(relay_url: &str, pinned_cert: Option<String>, require_tls: bool) -> Self {
+        WebSocketConnector {
+            relay_url: relay_url.to_string(),
+            pinned_cert,
+            require_tls,
+        }
+    }
+}
+
+impl RelayConnector for WebSocketConnector {
+    fn connect(&self) -> Result<Box<dyn RelayTransport>, MobileError> {
+        check_transport_security(&self.relay_url, self.require_tls)?;
+
+        let socket =
+            cert_pinning::connect_with_pinning(&self.relay_url, self.pinned_cert.as_deref())
+                .map_err(MobileError::NetworkError)?;
+
+        // Set read timeout for non-blocking receive
+        if let MaybeTlsStream::Plain(ref stream) = socket.get_ref() {
+            let _ = stream.set_read_timeout(Some(Duration::from_millis(1000)));
+        }
+
+        Ok(Box::new(WebSocketRelayTransport { socket }))
+    }
+}
+
+#[cfg(test)]
+pub use mock::MockRelay;
+
+#[cfg(test)]
+mod mock {
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use vauchi_core::crypto::SigningKeyPair;
+    use vauchi_core::network::simple_message::create_delivery_receipt;
+    use vauchi_core::{PublicCard, PublicCardRemoval};
+
+    use super::{RelayConnector, RelayTransport};
+    use crate::error::MobileError;
+    use crate::protocol::{
+        self, AckStatus, DeliveryStatusResponse, MessagePayload, MessageState, MessageStatus,
+        RecoveryProofResponse,
+    };
+
+    /// Recovery proofs by old_pk, then publisher: (proof, expires_at).
+    type RecoveryProofMap = HashMap<String, HashMap<String, (Vec<u8>, u64)>>;
+
+    /// Confirmation log by message ID: (sender, acknowledged by recipient).
+    type ConfirmationLog = HashMap<String, (String, bool)>;
+
+    /// Public cards by handle: (owner, latest timestamp, served JSON).
+    type PublicCardMap = HashMap<String, (String, u64, Option<Vec<u8>>)>;
+
+    /// In-memory relay that routes encrypted updates by recipient ID.
+    #[derive(Clone, Default)]
+    pub struct MockRelay {
+        mailboxes: Arc<Mutex<HashMap<String, VecDeque<Vec<u8>>>>>,
+        recovery_proofs: Arc<Mutex<RecoveryProofMap>>,
+        confirmations: Arc<Mutex<ConfirmationLog>>,
+        public_cards: Arc<Mutex<PublicCardMap>>,
+        offline: Arc<AtomicBool>,
+        receipt_key: Arc<Mutex<Option<SigningKeyPair>>>,
+        retain_delivered: Arc<AtomicBool>,
+        served: Arc<Mutex<HashMap<String, usize>>>,
+        connects: Arc<AtomicUsize>,
+    }
+
+    impl MockRelay {
+        /// Creates an empty relay.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Number of frames waiting for a client.
+        pub fn pending_for(&self, client_id: &str) -> usize {
+            self.mailboxes
+                .lock()
+                .unwrap()
+                .get(client_id)
+                .map_or(0, VecDeque::len)
+        }
+
+        /// Copies of the frames waiting for a client.
+        pub fn frames_for(&self, client_id: &str) -> Vec<Vec<u8>> {
+            self.mailboxes
+                .lock()
+                .unwrap()
+                .get(client_id)
+                .map_or_else(Vec::new, |frames| frames.iter().cloned().collect())
+        }
+
+        /// Stores a recovery proof directly, bypassing the client's checks.
+        pub fn store_recovery_proof(&self, old_pk: &str, publisher: &str, proof: Vec<u8>) {
+            self.recovery_proofs
+                .lock()
+                .unwrap()
+                .entry(old_pk.to_string())
+                .or_default()
+                .insert(publisher.to_string(), (proof, u64::MAX));
+        }
+
+        /// What the relay serves at a handle's permalink, if anything.
+        pub fn public_card(&self, handle: &str) -> Option<Vec<u8>> {
+            self.public_cards
+                .lock()
+                .unwrap()
+                .get(handle)
+                .and_then(|(_, _, card)| card.clone())
+        }
+
+        /// Signs a delivery receipt for every stored update with `key`.
+        pub fn set_receipt_key(&self, key: SigningKeyPair) {
+            *self.receipt_key.lock().unwrap() = Some(key);
+        }
+
+        /// Keeps delivered frames queued, like a relay that only purges on
+        /// expiry, and honours the handshake's `since` cursor.
+        pub fn set_retain_delivered(&self, retain: bool) {
+            self.retain_delivered.store(retain, Ordering::SeqCst);
+        }
+
+        /// Number of frames handed to a client so far.
+        pub fn served_to(&self, client_id: &str) -> usize {
+            self.served
+                .lock()
+                .unwrap()
+                .get(client_id)
+                .copied()
+                .unwrap_or(0)
+        }
+
+        /// Number of connection attempts, including failed ones.
+        pub fn connect_count(&self) -> usize {
+            self.connects.load(Ordering::SeqCst)
+        }
+
+        /// Makes connection attempts fail, to simulate a network drop.
+        pub fn set_offline(&self, offline: bool) {
+            self.offline.store(offline, Ordering::SeqCst);
+        }
+
+        /// Queues a raw frame for a client, e.g. to simulate redelivery.
+        pub fn deliver(&self, client_id: &str, frame: Vec<u8>) {
+            self.mailboxes
+                .lock()
+                .unwrap()
+                .entry(client_id.to_string())
+                .or_default()
+                .push_back(frame);
+        }
+    }
+
+    impl RelayConnector for MockRelay {
+        fn connect(&self) -> Result<Box<dyn RelayTransport>, MobileError> {
+            self.connects.fetch_add(1, Ordering::SeqCst);
+            if self.offline.load(Ordering::SeqCst) {
+                return Err(MobileError::NetworkError("relay unreachable".to_string()));
+            }
+            Ok(Box::new(MockRelayTransport {
+                relay: self.clone(),
+                client_id: None,
+                position: 0,
+            }))
+        }
+    }
+
+    struct MockRelayTransport {
+        relay: MockRelay,
+        client_id: Option<String>,
+        /// Next frame to hand out when the relay retains delivered frames.
+        position: usize,
+    }
+
+    impl RelayTransport for MockRelayTransport {
+        fn send(&mut self, data: Vec<u8>) -> Result<(), MobileError> {
+            let envelope = protocol::decode_message(&data).map_err(MobileError::SyncFailed)?;
+            match envelope.payload {
+                MessagePayload::Handshake(handshake) => {
+                    // Resume after the cursor; unknown cursors get everything
+                    self.position = handshake
+                        .since
+                        .filter(|_| self.relay.retain_delivered.load(Ordering::SeqCst))
+                        .and_then(|since| {
+                            self.relay
+                                .frames_for(&handshake.client_id)
+                                .iter()
+                                .position(|frame| {
+                                    protocol::decode_message(frame)
+                                        .is_ok_and(|envelope| envelope.message_id == since)
+                                })
+                        })
+                        .map_or(0, |index| index + 1);
+                    self.client_id = Some(handshake.client_id);
+                }
+                MessagePayload::EncryptedUpdate(update) => {
+                    let receipt = match (&*self.relay.receipt_key.lock().unwrap(), &self.client_id)
+                    {
+                        (Some(key), Some(sender)) => {
+                            let receipt = create_delivery_receipt(
+                                key,
+                                &envelope.message_id,
+                                &update.recipient_id,
+                                envelope.timestamp,
+                            );
+                            let frame = protocol::encode_message(&receipt)
+                                .map_err(MobileError::SyncFailed)?;
+                            Some((sender.clone(), frame))
+                        }
+                        _ => None,
+                    };
+                    if let Some(sender) = &self.client_id {
+                        self.relay
+                            .confirmations
+                            .lock()
+                            .unwrap()
+                            .insert(envelope.message_id.clone(), (sender.clone(), false));
+                    }
+                    self.relay
+                        .mailboxes
+                        .lock()
+                        .unwrap()
+                        .entry(update.recipient_id)
+                        .or_default()
+                        .push_back(data);
+                    if let Some((sender, frame)) = receipt {
+                        self.relay.deliver(&sender, frame);
+                    }
+                }
+                MessagePayload::Acknowledgment(ack) => {
+                    if matches!(ack.status, AckStatus::ReceivedByRecipient) {
+                        if let Some((_, confirmed)) = self
+                            .relay
+                            .confirmations
+                            .lock()
+                            .unwrap()
+                            .get_mut(&ack.message_id)
+                        {
+                            *confirmed = true;
+                        }
+                    }
+                }
+                MessagePayload::DeliveryStatusQuery(query) => {
+                    let Some(client_id) = self.client_id.clone() else {
+                        return Ok(());
+                    };
+                    let log = self.relay.confirmations.lock().unwrap();
+                    let statuses = query
+                        .message_ids
+                        .into_iter()
+                        .map(|message_id| {
+                            // Only the sender may learn about a message
+                            let state = match log.get(&message_id) {
+                                Some((sender, true)) if *sender == client_id => {
+                                    MessageState::Delivered
+                                }
+                                Some((sender, false)) if *sender == client_id => {
+                                    MessageState::Stored
+                                }
+                                _ => MessageState::Unknown,
+                            };
+                            MessageStatus { message_id, state }
+                        })
+                        .collect();
+                    drop(log);
+                    let response = protocol::create_envelope(
+                        MessagePayload::DeliveryStatusResponse(DeliveryStatusResponse { statuses }),
+                    );
+                    let frame =
+                        protocol::encode_message(&response).map_err(MobileError::SyncFailed)?;
+                    self.relay.deliver(&client_id, frame);
+                }
+                MessagePayload::RecoveryProofStore(store) => {
+                    let publisher = self.client_id.clone().unwrap_or_default();
+                    self.relay
+                        .recovery_proofs
+                        .lock()
+                        .unwrap()
+                        .entry(store.old_pk)
+                        .or_default()
+                        .insert(publisher, (store.proof, store.expires_at));
+                }
+                MessagePayload::PublicCardPublish(publish) => {
+                    let Some(card) = PublicCard::from_json(&publish.card) else {
+                        return Ok(());
+                    };
+                    let mut cards = self.relay.public_cards.lock().unwrap();
+                    // The first key to publish a handle owns it
+                    let accepted = card.verify()
+                        && cards.get(card.handle()).is_none_or(|(owner, latest, _)| {
+                            owner == card.owner_id() && *latest < card.published_at()
+                        });
+                    if accepted {
+                        cards.insert(
+                            card.handle().to_string(),
+                            (
+                                card.owner_id().to_string(),
+                                card.published_at(),
+                                Some(publish.card),
+                            ),
+                        );
+                    }
+                }
+                MessagePayload::PublicCardUnpublish(unpublish) => {
+                    let Some(removal) = PublicCardRemoval::from_json(&unpublish.removal) else {
+                        return Ok(());
+                    };
+                    let mut cards = self.relay.public_cards.lock().unwrap();
+                    if let Some(entry) = cards.get_mut(removal.handle()) {
+                        if removal.verify()
+                            && entry.0 == removal.owner_id()
+                            && entry.1 < removal.removed_at()
+                        {
+                            entry.1 = removal.removed_at();
+                            entry.2 = None;
+                        }
+                    }
+                }
+                MessagePayload::RecoveryProofQuery(query) => {
+                    let now = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap()
+                        .as_secs();
+                    let proofs = self
+                        .relay
+                        .recovery_proofs
+                        .lock()
+                        .unwrap()
+                        .get(&query.old_pk)
+                        .map_or_else(Vec::new, |stored| {
+                            stored
+                                .values()
+                                .filter(|(_, expires_at)| *expires_at >= now)
+                                .map(|(proof, _)| proof.clone())
+                                .collect()
+                        });
+                    let response = protocol::create_envelope(
+                        MessagePayload::RecoveryProofResponse(RecoveryProofResponse {
+                            old_pk: query.old_pk,
+                            proofs,
+                        }),
+                    );
+                    let frame =
+                        protocol::encode_message(&response).map_err(MobileError::SyncFailed)?;
+                    if let Some(client_id) = &self.client_id {
+                        self.relay.deliver(client_id, frame);
+                    }
+                }
+                _ => {}
+            }
+            Ok(())
+        }
+
+        fn receive(&mut self) -> Result<Option<Vec<u8>>, MobileError> {
+            let Some(client_id) = &self.client_id else {
+                return Ok(None);
+            };
+            let mut mailboxes = self.relay.mailboxes.lock().unwrap();
+            let mailbox = mailboxes.get_mut(client_id);
+            let frame = if self.relay.retain_delivered.load(Ordering::SeqCst) {
+                let frame = mailbox.and_then(|frames| frames.get(self.position).cloned());
+                self.position += usize::from(frame.is_some());
+                frame
+            } else {
+                mailbox.and_then(VecDeque::pop_front)
+            };
+            if frame.is_some() {
+                *self
+                    .relay
+                    .served
+                    .lock()
+                    .unwrap()
+                    .entry(client_id.clone())
+                    .or_default() += 1;
+            }
+            Ok(frame)
+        }
+
+        fn close(&mut self) {}
+    }
+}
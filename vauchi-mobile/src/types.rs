@@ -19,6 +19,7 @@ pub enum MobileFieldType {
     Address,
     Social,
     Custom,
+    RichText,
 }
 
 impl From<FieldType> for MobileFieldType {
@@ -30,6 +31,7 @@ impl From<FieldType> for MobileFieldType {
             FieldType::Address => MobileFieldType::Address,
             FieldType::Social => MobileFieldType::Social,
             FieldType::Custom => MobileFieldType::Custom,
+            FieldType::RichText => MobileFieldType::RichText,
         }
     }
 }
@@ -43,6 +45,7 @@ impl From<MobileFieldType> for FieldType {
             MobileFieldType::Address => FieldType::Address,
             MobileFieldType::Social => FieldType::Social,
             MobileFieldType::Custom => FieldType::Custom,
+            MobileFieldType::RichText => FieldType::RichText,
         }
     }
 }
@@ -54,6 +57,8 @@ pub struct MobileContactField {
     pub field_type: MobileFieldType,
     pub label: String,
     pub value: String,
+    /// Whether this is the main field of its type, e.g. the main email.
+    pub is_primary: bool,
 }
 
 impl From<&ContactField> for MobileContactField {
@@ -63,6 +68,25 @@ impl From<&ContactField> for MobileContactField {
             field_type: field.field_type().into(),
             label: field.label().to_string(),
             value: field.value().to_string(),
+            is_primary: field.is_primary(),
+        }
+    }
+}
+
+/// A field value checked before copying, see `get_field_safe_value`.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileSafeFieldValue {
+    /// The value to copy or show; defanged when `is_safe` is false.
+    pub value: String,
+    /// False if the value hides a dangerous or deceptive link.
+    pub is_safe: bool,
+}
+
+impl From<vauchi_core::contact_card::SafeFieldValue> for MobileSafeFieldValue {
+    fn from(value: vauchi_core::contact_card::SafeFieldValue) -> Self {
+        MobileSafeFieldValue {
+            value: value.value,
+            is_safe: value.is_safe,
         }
     }
 }
@@ -89,6 +113,19 @@ pub struct MobileContact {
     pub id: String,
     pub display_name: String,
     pub is_verified: bool,
+    /// Unix timestamp of the last fingerprint verification, if known.
+    pub verified_at: Option<u64>,
+    /// ID of the attester who vouched for this contact, if verified via a bundle.
+    pub verified_by: Option<String>,
+    /// Whether updates to and from this contact are blocked.
+    pub is_blocked: bool,
+    /// Whether this contact is pinned to the top of the contact list.
+    pub is_pinned: bool,
+    /// Whether change notifications for this contact are muted.
+    pub is_muted: bool,
+    /// Whether this contact was imported from an export rather than
+    /// exchanged; reference contacts never sync.
+    pub is_reference: bool,
     pub card: MobileContactCard,
     pub added_at: u64,
 }
@@ -99,12 +136,98 @@ impl From<&Contact> for MobileContact {
             id: contact.id().to_string(),
             display_name: contact.display_name().to_string(),
             is_verified: contact.is_fingerprint_verified(),
+            verified_at: contact.verified_at(),
+            verified_by: contact.verified_by().map(str::to_string),
+            is_blocked: contact.is_blocked(),
+            is_pinned: contact.is_pinned(),
+            is_muted: contact.is_muted(),
+            is_reference: contact.is_reference(),
             card: MobileContactCard::from(contact.card()),
             added_at: contact.exchange_timestamp(),
         }
     }
 }
 
+/// A recently deleted contact that may still be restored.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileDeletedContact {
+    /// The deleted contact's ID (hex public key).
+    pub contact_id: String,
+    /// Display name at the time of deletion.
+    pub display_name: String,
+    /// Unix timestamp of the deletion.
+    pub deleted_at: u64,
+}
+
+impl From<&vauchi_core::storage::ContactTombstone> for MobileDeletedContact {
+    fn from(tombstone: &vauchi_core::storage::ContactTombstone) -> Self {
+        MobileDeletedContact {
+            contact_id: tombstone.contact_id.clone(),
+            display_name: tombstone.display_name.clone(),
+            deleted_at: tombstone.deleted_at,
+        }
+    }
+}
+
+/// A contact from the platform's native address book.
+///
+/// Not an exchanged contact: there is no key material, only what is needed
+/// to suggest inviting the person to Vauchi.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileNativeContact {
+    /// Platform identifier of the entry.
+    pub native_id: String,
+    pub display_name: String,
+    pub emails: Vec<String>,
+    pub phones: Vec<String>,
+    /// Social network handles (e.g. "@alice").
+    pub social_handles: Vec<String>,
+}
+
+impl From<&vauchi_core::AddressBookEntry> for MobileNativeContact {
+    fn from(entry: &vauchi_core::AddressBookEntry) -> Self {
+        MobileNativeContact {
+            native_id: entry.native_id.clone(),
+            display_name: entry.display_name.clone(),
+            emails: entry.emails.clone(),
+            phones: entry.phones.clone(),
+            social_handles: entry.social_handles.clone(),
+        }
+    }
+}
+
+impl From<MobileNativeContact> for vauchi_core::AddressBookEntry {
+    fn from(contact: MobileNativeContact) -> Self {
+        vauchi_core::AddressBookEntry {
+            native_id: contact.native_id,
+            display_name: contact.display_name,
+            emails: contact.emails,
+            phones: contact.phones,
+            social_handles: contact.social_handles,
+        }
+    }
+}
+
+/// A native address book entry suggested for an invite.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileInviteSuggestion {
+    pub contact: MobileNativeContact,
+    /// Number of social handles shared with your contacts' cards.
+    pub score: u32,
+    /// IDs of your contacts whose cards share a handle.
+    pub matched_contact_ids: Vec<String>,
+}
+
+impl From<&vauchi_core::InviteSuggestion> for MobileInviteSuggestion {
+    fn from(suggestion: &vauchi_core::InviteSuggestion) -> Self {
+        MobileInviteSuggestion {
+            contact: MobileNativeContact::from(&suggestion.entry),
+            score: suggestion.score,
+            matched_contact_ids: suggestion.matched_contact_ids.clone(),
+        }
+    }
+}
+
 /// Exchange QR data.
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct MobileExchangeData {
@@ -113,6 +236,17 @@ pub struct MobileExchangeData {
     pub expires_at: u64,
 }
 
+/// How an exchange relates to the user's existing contacts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum MobileExchangeOutcomeKind {
+    /// First exchange with this person.
+    New,
+    /// Exchange with someone who was previously deleted.
+    Reconnected,
+    /// Exchange with someone who is already a contact; nothing changed.
+    AlreadyExists,
+}
+
 /// Exchange result.
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct MobileExchangeResult {
@@ -120,6 +254,7 @@ pub struct MobileExchangeResult {
     pub contact_name: String,
     pub success: bool,
     pub error_message: Option<String>,
+    pub kind: MobileExchangeOutcomeKind,
 }
 
 /// Sync status.
@@ -139,6 +274,33 @@ pub struct MobileSyncResult {
     pub cards_updated: u32,
     /// Number of outbound updates sent.
     pub updates_sent: u32,
+    /// Per-contact outcome of sending outbound updates.
+    pub contact_outcomes: Vec<MobileContactSyncOutcome>,
+    /// Inbound card updates that could not be applied.
+    pub update_failures: Vec<MobileUpdateFailure>,
+    /// Whether inbound messages may remain because a batch limit was
+    /// reached (see `sync_batch`). Always false for a full sync.
+    pub has_more: bool,
+}
+
+/// Outcome of sending pending updates to one contact.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileContactSyncOutcome {
+    /// Contact ID.
+    pub contact_id: String,
+    /// Whether all pending updates were sent.
+    pub success: bool,
+    /// Error message if sending failed.
+    pub error: Option<String>,
+}
+
+/// An inbound card update that could not be applied.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct MobileUpdateFailure {
+    /// Contact that sent the update.
+    pub contact_id: String,
+    /// Why the update could not be applied.
+    pub error: String,
 }
 
 /// Social network info.
@@ -173,6 +335,18 @@ pub struct MobileRecoveryVoucher {
     pub voucher_data: String,
 }
 
+/// Whether the device clock has been set back.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileClockStatus {
+    /// The clock reads earlier than a time it reported before, beyond the
+    /// skew tolerance. Recovery claims and vouchers are refused meanwhile.
+    pub rolled_back: bool,
+    /// How far the clock is behind the latest time seen, in seconds.
+    pub behind_secs: u64,
+    /// Latest local time seen (Unix seconds), if any.
+    pub latest_seen: Option<u64>,
+}
+
 /// Recovery progress status.
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct MobileRecoveryProgress {
@@ -205,6 +379,60 @@ pub struct MobileRecoveryVerification {
     pub recommendation: String,
 }
 
+/// One side of a recovery conflict.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileConflictingClaim {
+    /// New identity's public key (hex).
+    pub new_public_key: String,
+    /// Number of vouchers supporting this claim.
+    pub voucher_count: u32,
+}
+
+/// Conflicting recovery claims for the same lost identity.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileRecoveryConflict {
+    /// Old identity's public key (hex).
+    pub old_public_key: String,
+    /// The competing claims.
+    pub claims: Vec<MobileConflictingClaim>,
+}
+
+impl From<&vauchi_core::RecoveryConflict> for MobileRecoveryConflict {
+    fn from(conflict: &vauchi_core::RecoveryConflict) -> Self {
+        MobileRecoveryConflict {
+            old_public_key: hex::encode(conflict.old_pk()),
+            claims: conflict
+                .claims()
+                .iter()
+                .map(|c| MobileConflictingClaim {
+                    new_public_key: hex::encode(c.new_pk()),
+                    voucher_count: c.voucher_count() as u32,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A recovery proof fetched from the relay.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileFetchedRecoveryProof {
+    /// Base64-encoded proof data.
+    pub proof_data: String,
+    /// Verification against our contacts.
+    pub verification: MobileRecoveryVerification,
+}
+
+/// Recovery proofs fetched from the relay for one lost identity.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileRecoveryProofSet {
+    /// All valid, unexpired proofs.
+    pub proofs: Vec<MobileFetchedRecoveryProof>,
+    /// Set when the proofs claim different new identities.
+    pub conflict: Option<MobileRecoveryConflict>,
+    /// Index of the proof with the most vouchers from our contacts.
+    pub recommended_index: Option<u32>,
+}
+
 // === Visibility Label Types ===
 
 /// Visibility label for organizing contacts.
@@ -222,6 +450,10 @@ pub struct MobileVisibilityLabel {
     pub created_at: u64,
     /// Timestamp when last modified.
     pub modified_at: u64,
+    /// Display color as a hex string, if set.
+    pub color: Option<String>,
+    /// Icon key, if set.
+    pub icon: Option<String>,
 }
 
 impl From<&vauchi_core::VisibilityLabel> for MobileVisibilityLabel {
@@ -233,6 +465,45 @@ impl From<&vauchi_core::VisibilityLabel> for MobileVisibilityLabel {
             visible_field_count: label.visible_fields().len() as u32,
             created_at: label.created_at(),
             modified_at: label.modified_at(),
+            color: label.color().map(str::to_string),
+            icon: label.icon().map(str::to_string),
+        }
+    }
+}
+
+/// Visibility given to fields when they are added to your card.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum MobileDefaultFieldVisibility {
+    /// New fields are visible to everyone.
+    Everyone,
+    /// New fields stay hidden until shared explicitly.
+    Nobody,
+    /// New fields are visible to contacts in these labels.
+    Labels { label_ids: Vec<String> },
+}
+
+impl From<MobileDefaultFieldVisibility> for vauchi_core::DefaultFieldVisibility {
+    fn from(policy: MobileDefaultFieldVisibility) -> Self {
+        match policy {
+            MobileDefaultFieldVisibility::Everyone => vauchi_core::DefaultFieldVisibility::Everyone,
+            MobileDefaultFieldVisibility::Nobody => vauchi_core::DefaultFieldVisibility::Nobody,
+            MobileDefaultFieldVisibility::Labels { label_ids } => {
+                vauchi_core::DefaultFieldVisibility::Labels(label_ids.into_iter().collect())
+            }
+        }
+    }
+}
+
+impl From<vauchi_core::DefaultFieldVisibility> for MobileDefaultFieldVisibility {
+    fn from(policy: vauchi_core::DefaultFieldVisibility) -> Self {
+        match policy {
+            vauchi_core::DefaultFieldVisibility::Everyone => MobileDefaultFieldVisibility::Everyone,
+            vauchi_core::DefaultFieldVisibility::Nobody => MobileDefaultFieldVisibility::Nobody,
+            vauchi_core::DefaultFieldVisibility::Labels(label_ids) => {
+                let mut label_ids: Vec<String> = label_ids.into_iter().collect();
+                label_ids.sort();
+                MobileDefaultFieldVisibility::Labels { label_ids }
+            }
         }
     }
 }
@@ -249,6 +520,8 @@ pub struct MobileVisibilityLabelDetail {
     pub visible_field_ids: Vec<String>,
     pub created_at: u64,
     pub modified_at: u64,
+    pub color: Option<String>,
+    pub icon: Option<String>,
 }
 
 impl From<&vauchi_core::VisibilityLabel> for MobileVisibilityLabelDetail {
@@ -260,6 +533,8 @@ impl From<&vauchi_core::VisibilityLabel> for MobileVisibilityLabelDetail {
             visible_field_ids: label.visible_fields().iter().cloned().collect(),
             created_at: label.created_at(),
             modified_at: label.modified_at(),
+            color: label.color().map(str::to_string),
+            icon: label.icon().map(str::to_string),
         }
     }
 }
@@ -391,6 +666,30 @@ impl From<&vauchi_core::storage::DeliveryRecord> for MobileDeliveryRecord {
     }
 }
 
+/// A relay-signed receipt proving when the relay accepted a message.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileDeliveryReceipt {
+    /// ID of the accepted message.
+    pub message_id: String,
+    /// Recipient the relay stored the message for.
+    pub recipient_id: String,
+    /// Relay clock at acceptance (Unix timestamp).
+    pub server_timestamp: u64,
+    /// Relay signature over the receipt (hex-encoded).
+    pub signature: String,
+}
+
+impl From<&vauchi_core::network::simple_message::SimpleDeliveryReceipt> for MobileDeliveryReceipt {
+    fn from(receipt: &vauchi_core::network::simple_message::SimpleDeliveryReceipt) -> Self {
+        MobileDeliveryReceipt {
+            message_id: receipt.message_id.clone(),
+            recipient_id: receipt.recipient_id.clone(),
+            server_timestamp: receipt.server_timestamp,
+            signature: hex::encode(&receipt.signature),
+        }
+    }
+}
+
 // === Retry Queue Types ===
 
 /// A retry queue entry for failed message deliveries.
@@ -426,6 +725,45 @@ impl From<&vauchi_core::storage::RetryEntry> for MobileRetryEntry {
     }
 }
 
+// === Stale Update Types ===
+
+/// Where a stale outbound update got stuck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum MobileStaleUpdateState {
+    /// Still in the local queue; never reached the relay.
+    NeverSent,
+    /// Reached the relay but the recipient never confirmed receipt.
+    NotDelivered,
+}
+
+/// An outbound update that has been waiting for delivery too long.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileStaleUpdate {
+    /// Pending update ID or delivery record message ID.
+    pub id: String,
+    /// Recipient's contact ID.
+    pub contact_id: String,
+    /// When the update was created (Unix timestamp).
+    pub created_at: u64,
+    /// Whether the update was sent at all.
+    pub state: MobileStaleUpdateState,
+}
+
+impl From<&vauchi_core::storage::StaleUpdate> for MobileStaleUpdate {
+    fn from(update: &vauchi_core::storage::StaleUpdate) -> Self {
+        use vauchi_core::storage::StaleUpdateState;
+        MobileStaleUpdate {
+            id: update.id.clone(),
+            contact_id: update.contact_id.clone(),
+            created_at: update.created_at,
+            state: match update.state {
+                StaleUpdateState::NeverSent => MobileStaleUpdateState::NeverSent,
+                StaleUpdateState::NotDelivered => MobileStaleUpdateState::NotDelivered,
+            },
+        }
+    }
+}
+
 // === Multi-Device Delivery Types ===
 
 /// Delivery status for a specific device.
@@ -480,6 +818,55 @@ impl From<&vauchi_core::storage::DeviceDeliveryRecord> for MobileDeviceDeliveryR
     }
 }
 
+/// A stored value that failed the integrity check.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileIntegrityFailure {
+    /// Table the row belongs to.
+    pub table: String,
+    /// Row identifier (contact ID for contact data).
+    pub row_id: String,
+    /// Which value failed (e.g. "card", "shared_key", "ratchet_state").
+    pub item: String,
+    /// Why it failed.
+    pub reason: String,
+}
+
+/// Result of a storage integrity check.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileIntegrityReport {
+    /// Whether the database and every checked value are intact.
+    pub is_ok: bool,
+    /// Whether SQLite's own integrity check passed.
+    pub database_ok: bool,
+    /// Messages from SQLite's integrity check.
+    pub database_messages: Vec<String>,
+    /// Number of encrypted values checked.
+    pub items_checked: u32,
+    /// Values that failed to decrypt or parse.
+    pub failures: Vec<MobileIntegrityFailure>,
+}
+
+impl From<&vauchi_core::storage::IntegrityReport> for MobileIntegrityReport {
+    fn from(report: &vauchi_core::storage::IntegrityReport) -> Self {
+        MobileIntegrityReport {
+            is_ok: report.is_ok(),
+            database_ok: report.sqlite_ok(),
+            database_messages: report.sqlite_messages.clone(),
+            items_checked: report.items_checked as u32,
+            failures: report
+                .failures
+                .iter()
+                .map(|f| MobileIntegrityFailure {
+                    table: f.table.clone(),
+                    row_id: f.row_id.clone(),
+                    item: f.item.clone(),
+                    reason: f.reason.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
 /// Summary of delivery status across all devices.
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct MobileDeliverySummary {
@@ -528,6 +915,12 @@ pub enum MobileAhaMomentType {
     FirstUpdateReceived,
     /// Shown when first outbound update is delivered
     FirstOutboundDelivered,
+    /// Shown when the contact list first reaches 10 contacts
+    ContactMilestone,
+    /// Shown when the first contact's fingerprint is verified
+    FirstVerifiedContact,
+    /// Shown when the own card first reaches 100% completeness
+    CardComplete,
 }
 
 impl From<vauchi_core::AhaMomentType> for MobileAhaMomentType {
@@ -544,6 +937,11 @@ impl From<vauchi_core::AhaMomentType> for MobileAhaMomentType {
             vauchi_core::AhaMomentType::FirstOutboundDelivered => {
                 MobileAhaMomentType::FirstOutboundDelivered
             }
+            vauchi_core::AhaMomentType::ContactMilestone => MobileAhaMomentType::ContactMilestone,
+            vauchi_core::AhaMomentType::FirstVerifiedContact => {
+                MobileAhaMomentType::FirstVerifiedContact
+            }
+            vauchi_core::AhaMomentType::CardComplete => MobileAhaMomentType::CardComplete,
         }
     }
 }
@@ -562,6 +960,69 @@ impl From<MobileAhaMomentType> for vauchi_core::AhaMomentType {
             MobileAhaMomentType::FirstOutboundDelivered => {
                 vauchi_core::AhaMomentType::FirstOutboundDelivered
             }
+            MobileAhaMomentType::ContactMilestone => vauchi_core::AhaMomentType::ContactMilestone,
+            MobileAhaMomentType::FirstVerifiedContact => {
+                vauchi_core::AhaMomentType::FirstVerifiedContact
+            }
+            MobileAhaMomentType::CardComplete => vauchi_core::AhaMomentType::CardComplete,
+        }
+    }
+}
+
+/// QR error-correction level for rendered codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum MobileQrErrorCorrection {
+    Low,
+    Medium,
+    Quartile,
+    High,
+}
+
+impl From<MobileQrErrorCorrection> for vauchi_core::QrErrorCorrection {
+    fn from(level: MobileQrErrorCorrection) -> Self {
+        match level {
+            MobileQrErrorCorrection::Low => vauchi_core::QrErrorCorrection::Low,
+            MobileQrErrorCorrection::Medium => vauchi_core::QrErrorCorrection::Medium,
+            MobileQrErrorCorrection::Quartile => vauchi_core::QrErrorCorrection::Quartile,
+            MobileQrErrorCorrection::High => vauchi_core::QrErrorCorrection::High,
+        }
+    }
+}
+
+/// Something a complete card has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum MobileCompletenessItem {
+    DisplayName,
+    ContactMethod,
+    Photo,
+    SocialLink,
+}
+
+impl From<vauchi_core::CompletenessItem> for MobileCompletenessItem {
+    fn from(item: vauchi_core::CompletenessItem) -> Self {
+        match item {
+            vauchi_core::CompletenessItem::DisplayName => MobileCompletenessItem::DisplayName,
+            vauchi_core::CompletenessItem::ContactMethod => MobileCompletenessItem::ContactMethod,
+            vauchi_core::CompletenessItem::Photo => MobileCompletenessItem::Photo,
+            vauchi_core::CompletenessItem::SocialLink => MobileCompletenessItem::SocialLink,
+        }
+    }
+}
+
+/// How complete the own card is, for onboarding progress.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileCompletenessReport {
+    /// Share of items present, 0-100.
+    pub percent: u8,
+    /// Items still missing.
+    pub missing: Vec<MobileCompletenessItem>,
+}
+
+impl From<&vauchi_core::CompletenessReport> for MobileCompletenessReport {
+    fn from(report: &vauchi_core::CompletenessReport) -> Self {
+        MobileCompletenessReport {
+            percent: report.percent,
+            missing: report.missing.iter().map(|&item| item.into()).collect(),
         }
     }
 }
@@ -579,6 +1040,27 @@ pub struct MobileAhaMoment {
     pub has_animation: bool,
 }
 
+/// Local usage statistics. Kept on this device only, never synced.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct MobileUsageStats {
+    /// Exchanges that saved a contact.
+    pub exchanges_completed: u64,
+    /// Scanned QR codes that could not be read.
+    pub failed_qr_scans: u64,
+    /// Contacts whose fingerprint was verified.
+    pub contacts_verified: u64,
+}
+
+impl From<vauchi_core::storage::UsageStats> for MobileUsageStats {
+    fn from(stats: vauchi_core::storage::UsageStats) -> Self {
+        MobileUsageStats {
+            exchanges_completed: stats.exchanges_completed,
+            failed_qr_scans: stats.failed_qr_scans,
+            contacts_verified: stats.contacts_verified,
+        }
+    }
+}
+
 // === Demo Contact Types ===
 
 /// Demo contact card representation for display.
@@ -622,6 +1104,33 @@ pub struct MobileDemoContactState {
     pub auto_removed: bool,
     /// Number of updates sent
     pub update_count: u32,
+    /// Whether the user has exchanged cards
+    pub exchanged: bool,
+    /// Whether the user has verified a contact
+    pub verified: bool,
+    /// Whether the user has hidden a field from a contact
+    pub hid_a_field: bool,
+}
+
+/// Core action practiced while the demo contact is active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum MobileDemoAction {
+    /// Exchanged cards with someone
+    Exchanged,
+    /// Verified a contact's fingerprint
+    Verified,
+    /// Hid a field from a contact
+    HidField,
+}
+
+impl From<MobileDemoAction> for vauchi_core::DemoAction {
+    fn from(action: MobileDemoAction) -> Self {
+        match action {
+            MobileDemoAction::Exchanged => vauchi_core::DemoAction::Exchanged,
+            MobileDemoAction::Verified => vauchi_core::DemoAction::Verified,
+            MobileDemoAction::HidField => vauchi_core::DemoAction::HidField,
+        }
+    }
 }
 
 // === Field Validation Types ===
@@ -667,6 +1176,10 @@ pub struct MobileValidationStatus {
     pub validated_by_me: bool,
     /// Display text (e.g., "Verified by Bob and 2 others").
     pub display_text: String,
+    /// Whether the validations meet your trust thresholds.
+    pub trusted: bool,
+    /// Names of issuers attesting the field (e.g., "ACME Corp").
+    pub issuers: Vec<String>,
 }
 
 impl From<&vauchi_core::social::ValidationStatus> for MobileValidationStatus {
@@ -679,6 +1192,26 @@ impl From<&vauchi_core::social::ValidationStatus> for MobileValidationStatus {
             color: status.trust_level.color().to_string(),
             validated_by_me: status.validated_by_me,
             display_text: status.display(&known_names),
+            trusted: status.trusted,
+            issuers: status.issuers.clone(),
+        }
+    }
+}
+
+/// Requirements for showing a field as trusted.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileTrustThresholds {
+    /// Validations required for a field to be trusted.
+    pub min_validations: u32,
+    /// Whether a validator verified in person counts as two validations.
+    pub in_person_counts_double: bool,
+}
+
+impl From<&vauchi_core::social::TrustThresholds> for MobileTrustThresholds {
+    fn from(thresholds: &vauchi_core::social::TrustThresholds) -> Self {
+        MobileTrustThresholds {
+            min_validations: thresholds.min_validations as u32,
+            in_person_counts_double: thresholds.in_person_counts_double,
         }
     }
 }